@@ -0,0 +1,198 @@
+// src/api.rs - Library façade for embedding the reclaim logic
+
+use solana_sdk::pubkey::Pubkey;
+use chrono::{Duration, Utc};
+use crate::{
+    config::Config,
+    error::Result,
+    kora::{types::SponsoredAccountInfo, AccountType, FinalityChecker, KoraMonitor},
+    reclaim::{engine::ReclaimResult, signer, CloseAdapters, EligibilityChecker, ReclaimEngine},
+    solana::{ParserRegistry, SolanaRpcClient},
+    storage::{
+        db::DatabaseStats,
+        models::{AccountStatus, ReclaimOperation, ReclaimReason, SponsoredAccount},
+        Database,
+    },
+};
+use tracing::{info, warn};
+
+/// Outcome of a discovery scan
+#[derive(Debug, Clone)]
+pub struct ScanReport {
+    /// Sponsored accounts found in this scan (already persisted)
+    pub accounts: Vec<SponsoredAccountInfo>,
+    /// How many of them were not tracked before
+    pub new_accounts: usize,
+    /// Whether the scan resumed from a stored checkpoint
+    pub incremental: bool,
+}
+
+/// Eligibility verdict for a single account
+#[derive(Debug, Clone)]
+pub struct EligibilityReport {
+    pub pubkey: Pubkey,
+    pub eligible: bool,
+    pub reason: String,
+}
+
+/// High-level entry point bundling RPC, database and policy.
+///
+/// Mirrors what the CLI does for `scan`, `reclaim` and `stats` without
+/// printing anything, so services can drive the bot programmatically.
+pub struct KoraReclaim {
+    config: Config,
+    rpc_client: SolanaRpcClient,
+    db: Database,
+}
+
+impl KoraReclaim {
+    /// Build from a loaded configuration, opening the configured database
+    pub fn new(config: Config) -> Result<Self> {
+        let rpc_client = SolanaRpcClient::from_config(&config);
+        let db = Database::new(&config.database.path)?;
+
+        Ok(Self {
+            config,
+            rpc_client,
+            db,
+        })
+    }
+
+    /// Build around an already open database, e.g. one shared with the bot
+    pub fn with_database(config: Config, db: Database) -> Self {
+        let rpc_client = SolanaRpcClient::from_config(&config);
+        Self { config, rpc_client, db }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn database(&self) -> &Database {
+        &self.db
+    }
+
+    pub fn rpc_client(&self) -> &SolanaRpcClient {
+        &self.rpc_client
+    }
+
+    /// Discover sponsored accounts and persist them.
+    ///
+    /// Resumes from the last checkpoint when one exists and advances it.
+    pub async fn scan(&self, max_transactions: usize) -> Result<ScanReport> {
+        let operator = self.config.operator_pubkey()?;
+        let monitor = KoraMonitor::new(self.rpc_client.clone(), operator)
+            .with_parsers(ParserRegistry::from_config(&self.config))
+            .with_floor_slot(self.config.reclaim.scan_floor_slot)
+            .with_cache(self.db.clone());
+        self.db.bind_checkpoint_operator(&operator.to_string())?;
+        let since_signature = self.db.get_last_processed_signature()?;
+        let incremental = since_signature.is_some();
+
+        let accounts = monitor.scan_new_accounts(since_signature, max_transactions).await?;
+
+        let mut new_accounts = 0;
+        let mut db_accounts = Vec::with_capacity(accounts.len());
+        for account_info in &accounts {
+            if !self.db.account_exists(&account_info.pubkey.to_string())? {
+                new_accounts += 1;
+            }
+            db_accounts.push(SponsoredAccount::from(account_info));
+        }
+
+        self.db.save_accounts_batch(&db_accounts)?;
+
+        if let Some(latest) = accounts.first() {
+            self.db.save_last_processed_signature(&latest.creation_signature.to_string())?;
+            self.db.save_last_processed_slot(latest.creation_slot)?;
+        }
+        FinalityChecker::new(self.rpc_client.clone(), self.db.clone()).confirm_saved().await;
+
+        info!("Scan found {} accounts ({} new)", accounts.len(), new_accounts);
+
+        Ok(ScanReport {
+            accounts,
+            new_accounts,
+            incremental,
+        })
+    }
+
+    /// Evaluate whether an account can be reclaimed right now
+    pub async fn check_eligibility(&self, pubkey: &Pubkey) -> Result<EligibilityReport> {
+        let checker = EligibilityChecker::new(self.rpc_client.clone(), self.config.clone())
+            .with_holds(self.db.clone());
+
+        // Untracked accounts are assumed old enough, as the CLI does
+        let created_at = self
+            .db
+            .get_account_by_pubkey(&pubkey.to_string())?
+            .map(|a| a.created_at)
+            .unwrap_or_else(|| Utc::now() - Duration::days(365));
+
+        // One fetch for both the verdict and the reason
+        let account = self.rpc_client.get_account(pubkey).await?;
+        let eligible = match &account {
+            Some(account) => checker.is_eligible_account(pubkey, account, created_at).await?,
+            None => false,
+        };
+        let reason = checker.eligibility_reason_for(pubkey, account.as_ref(), created_at).await?;
+
+        Ok(EligibilityReport {
+            pubkey: *pubkey,
+            eligible,
+            reason,
+        })
+    }
+
+    /// Reclaim rent from an account and record the operation.
+    ///
+    /// Honors `reclaim.dry_run`; the signers are loaded per call.
+    pub async fn reclaim(&self, pubkey: &Pubkey) -> Result<ReclaimResult> {
+        self.reclaim_as(pubkey, ReclaimReason::Library).await
+    }
+
+    /// [`reclaim`](Self::reclaim), recording `reason` on the memo and operation
+    pub async fn reclaim_as(&self, pubkey: &Pubkey, reason: ReclaimReason) -> Result<ReclaimResult> {
+        let engine = ReclaimEngine::new(
+            self.rpc_client.clone(),
+            self.config.reclaim_destination()?,
+            signer::from_config(&self.config)?,
+            self.config.reclaim.dry_run,
+        )
+        .with_fee_payer(signer::fee_payer_from_config(&self.config)?)
+        .with_close_adapters(CloseAdapters::from_config(&self.config))
+        .with_fees(self.config.reclaim.fees.clone())
+        .with_memo(self.config.reclaim.memo.clone(), reason);
+
+        let account_type = match self.rpc_client.get_account(pubkey).await? {
+            Some(account) => AccountType::from_account(&account),
+            None => AccountType::SplToken,
+        };
+
+        let result = engine.reclaim_account(pubkey, &account_type).await?;
+
+        if let Some(sig) = result.signature {
+            if let Err(e) = self.db.update_account_status(&pubkey.to_string(), AccountStatus::Reclaimed) {
+                warn!("{}", e);
+            }
+            self.db.save_reclaim_operation(&ReclaimOperation {
+                id: 0,
+                account_pubkey: pubkey.to_string(),
+                reclaimed_amount: result.amount_reclaimed,
+                tx_signature: sig.to_string(),
+                timestamp: Utc::now(),
+                reason,
+                credited_amount: result.credited_amount(),
+                destination: Some(result.destination.to_string()),
+                fee_lamports: result.fee_lamports(),
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    /// Aggregate statistics from the database
+    pub fn stats(&self) -> Result<DatabaseStats> {
+        self.db.get_stats()
+    }
+}
@@ -11,6 +11,22 @@ pub struct Cli {
     /// Path to configuration file
     #[arg(short, long, global = true, default_value = "config.toml")]
     pub config: String,
+
+    /// Use this database file instead of `database.path` (or the tenant's database)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub db: Option<String>,
+
+    /// Keep the database in memory and discard it on exit; `database.path` is not touched
+    #[arg(long, global = true, conflicts_with = "db")]
+    pub ephemeral: bool,
+
+    /// Operate on one tenant from the `[[tenants]]` configuration
+    #[arg(long, global = true)]
+    pub tenant: Option<String>,
+
+    /// Plain output without colors or box-drawing characters (also set by NO_COLOR)
+    #[arg(long, global = true)]
+    pub no_color: bool,
 }
 
 #[derive(Subcommand)]
@@ -34,8 +50,67 @@ pub enum Commands {
         /// Limit number of accounts to scan
         #[arg(short, long)]
         limit: Option<usize>,
+
+        /// Only scan transactions since a date (YYYY-MM-DD, UTC) or RFC 3339 time
+        #[arg(long, value_parser = crate::utils::parse_since, conflicts_with = "last")]
+        since: Option<chrono::DateTime<chrono::Utc>>,
+
+        /// Only scan transactions from a recent window, e.g. 12h, 30d or 2w
+        #[arg(long, value_parser = crate::utils::parse_duration)]
+        last: Option<chrono::Duration>,
+
+        /// Exit with status 3 if the scan checkpoint ends more than this many slots behind the network
+        #[arg(long, value_name = "SLOTS")]
+        fail_if_behind: Option<u64>,
+
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
     },
     
+    /// Show what a reclaim run would do now: batches, expected recovery, fees
+    /// and blockers. Checks tracked accounts only; nothing is scanned or sent
+    Plan {
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
+
+        /// Look up why each skipped account is not eligible (more RPC calls)
+        #[arg(short, long)]
+        verbose: bool,
+    },
+
+    /// Sponsorship proof of one account: creation, fee payer, close authority and reclaims
+    Prove {
+        /// Account public key
+        pubkey: String,
+
+        /// Output format: markdown or json
+        #[arg(short, long, default_value = "markdown")]
+        format: String,
+
+        /// Write the proof to this file instead of printing it
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
+
+    /// Track sponsored accounts listed in a CSV file (first column: pubkey)
+    Import {
+        /// CSV file of account pubkeys
+        file: String,
+
+        /// Validate and classify without writing to the database
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Solana Pay link (and QR code) asking an account's owner to close it
+    #[command(name = "request-close")]
+    RequestClose {
+        /// Token account to close
+        pubkey: String,
+    },
+
     /// Reclaim rent from specific account
     Reclaim {
         /// Account public key to reclaim
@@ -48,9 +123,66 @@ pub enum Commands {
         /// Dry run mode (simulate without sending transactions)
         #[arg(long)]
         dry_run: bool,
+
+        /// Send the rent to this wallet instead of the treasury (overrides reclaim.destination)
+        #[arg(long)]
+        destination: Option<solana_sdk::pubkey::Pubkey>,
+
+        /// Output format: table or json (json requires --yes unless --dry-run)
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+
+    /// Look for rent returned to the treasury by users closing their own accounts
+    PassiveCheck {
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
     },
 
-    PassiveCheck,
+    /// Review low-confidence passive reclaim attributions
+    #[command(name = "passive-review")]
+    PassiveReview {
+        #[command(subcommand)]
+        action: ReviewAction,
+    },
+
+    /// Review accounts that stopped being retried after repeated deterministic failures
+    #[command(name = "dead-letter")]
+    DeadLetter {
+        #[command(subcommand)]
+        action: DeadLetterAction,
+    },
+
+    /// Hold accounts longer than reclaim.min_inactive_days
+    Hold {
+        #[command(subcommand)]
+        action: HoldAction,
+    },
+
+    /// Review high-value reclaims staged by the auto service (reclaim.staging)
+    Staged {
+        #[command(subcommand)]
+        action: StagedAction,
+    },
+
+    /// Record contacts with users about closing their passively monitored accounts
+    Outreach {
+        #[command(subcommand)]
+        action: OutreachAction,
+    },
+
+    /// Inspect or replay the instructions behind account attributions
+    Evidence {
+        #[command(subcommand)]
+        action: EvidenceAction,
+    },
+
+    /// Devnet helpers for a local sandbox
+    Devnet {
+        #[command(subcommand)]
+        action: DevnetAction,
+    },
     
     /// Run automated reclaim service
     Auto {
@@ -61,7 +193,31 @@ pub enum Commands {
         /// Dry run mode (don't actually reclaim)
         #[arg(long)]
         dry_run: bool,
+
+        /// Send the rent to this wallet instead of the treasury (overrides reclaim.destination)
+        #[arg(long)]
+        destination: Option<solana_sdk::pubkey::Pubkey>,
+
+        /// Stop a cycle after this many RPC calls (overrides solana.cycle_rpc_budget)
+        #[arg(long, value_name = "CALLS")]
+        budget: Option<u64>,
     },
+
+    /// Run the auto service, Telegram bot, passive monitor and HTTP API in one process
+    Daemon {
+        /// Check interval in seconds (0 uses reclaim.scan_interval_seconds)
+        #[arg(short, long, default_value = "0")]
+        interval: u64,
+
+        /// Dry run mode (don't actually reclaim)
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Stop a cycle after this many RPC calls (overrides solana.cycle_rpc_budget)
+        #[arg(long, value_name = "CALLS")]
+        budget: Option<u64>,
+    },
+
     List {
         /// Filter by status (active, closed, reclaimed, all)
         #[arg(short, long, default_value = "all")]
@@ -74,6 +230,10 @@ pub enum Commands {
         /// Show detailed information including creation details
         #[arg(short, long)]
         detailed: bool,
+
+        /// Only Active accounts that become old enough for reclaim within this many days
+        #[arg(long, value_name = "DAYS", conflicts_with_all = ["status", "detailed"])]
+        soon: Option<u64>,
     },
     
     /// Reset scanning checkpoints (force full rescan on next run)
@@ -81,10 +241,57 @@ pub enum Commands {
         /// Skip confirmation prompt
         #[arg(short, long)]
         yes: bool,
+        
+        /// Also drop cached transaction lookups (refetch everything on rescan)
+        #[arg(long)]
+        clear_cache: bool,
+
+        /// Output format: table or json (json requires --yes)
+        #[arg(short, long, default_value = "table")]
+        format: String,
     },
     
     /// Show checkpoint information and scanning state
-    Checkpoints,
+    Checkpoints {
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+
+    /// Check the database for corruption and inconsistent records (also done daily by `auto`)
+    Integrity {
+        /// Fix orphaned operations and account statuses that disagree with them
+        #[arg(long)]
+        repair: bool,
+    },
+
+    /// Check the hash chain over reclaim operations for edited or deleted entries
+    VerifyLedger,
+
+    /// Record today's totals for trend reporting (also done daily by `auto`)
+    Snapshot {
+        /// List the last N snapshots instead of recording one
+        #[arg(long, value_name = "DAYS")]
+        history: Option<usize>,
+    },
+
+    /// Refresh on-chain balances of tracked active accounts
+    Refresh {
+        /// List every account whose balance differs from the recorded estimate
+        #[arg(short, long)]
+        verbose: bool,
+    },
+
+    /// Classify the reclaim strategy of tracked active accounts again
+    Classify {
+        /// Only accounts with this strategy; unknown includes never classified ones
+        #[arg(long, value_name = "STRATEGY", value_parser = ["unknown", "active", "passive", "unrecoverable"])]
+        only: Option<String>,
+
+        /// Accounts classified at once
+        #[arg(long, value_name = "N", default_value_t = 8)]
+        concurrency: usize,
+    },
     
     
     /// Show statistics and reports
@@ -96,11 +303,214 @@ pub enum Commands {
         /// Show only total reclaimed amount (faster)
         #[arg(long)]
         total: bool,
+
+        /// Compare current totals with the snapshot from this long ago (e.g. 7d, 4w; whole days)
+        #[arg(long, value_name = "PERIOD", value_parser = crate::utils::parse_duration, conflicts_with = "total")]
+        compare: Option<chrono::Duration>,
+
+        /// Exit with status 3 if tracked accounts eligible now hold more than this (e.g. 5SOL); checks them on-chain
+        #[arg(long, value_name = "AMOUNT", value_parser = crate::utils::parse_sol)]
+        fail_if_eligible_over: Option<u64>,
+    },
+    
+    /// Analytics reports over the account history
+    Report {
+        /// How long accounts live from creation to close or reclaim
+        #[arg(long)]
+        lifetime: bool,
+
+        /// Conversion from user outreach to passive reclaim
+        #[arg(long, conflicts_with = "lifetime")]
+        outreach: bool,
+
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
     },
     
+    /// Interactive first-run setup that writes a validated config file
+    Setup {
+        /// Overwrite an existing config file without asking
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// List configured tenants with the stats of each tenant's database
+    Tenants,
+
     /// Initialize database and configuration
     Init,
 
     /// Start Telegram bot interface
     Telegram,
-}
\ No newline at end of file
+}
+
+impl Commands {
+    /// The command prints a JSON document, so everything else goes to stderr
+    pub fn json_output(&self) -> bool {
+        let format = match self {
+            Commands::Scan { format, .. }
+            | Commands::Plan { format, .. }
+            | Commands::Prove { format, .. }
+            | Commands::Reclaim { format, .. }
+            | Commands::PassiveCheck { format }
+            | Commands::List { format, .. }
+            | Commands::Reset { format, .. }
+            | Commands::Checkpoints { format }
+            | Commands::Stats { format, .. }
+            | Commands::Report { format, .. } => format,
+            _ => return false,
+        };
+        format == "json"
+    }
+}
+
+#[derive(Subcommand)]
+pub enum ReviewAction {
+    /// List passive reclaims awaiting review
+    List,
+
+    /// Confirm an attribution so it counts towards totals
+    Confirm {
+        /// Passive reclaim id
+        id: i64,
+    },
+
+    /// Reject an attribution so it is excluded from totals
+    Reject {
+        /// Passive reclaim id
+        id: i64,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DeadLetterAction {
+    /// List dead-lettered accounts and their last error
+    List,
+
+    /// Clear an account's failures so it is retried again
+    Restore {
+        /// Account public key
+        pubkey: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum HoldAction {
+    /// List held accounts and when their holds end
+    List,
+
+    /// Require an account to stay inactive for DAYS instead of reclaim.min_inactive_days
+    Set {
+        /// Account public key
+        pubkey: String,
+
+        /// Days of inactivity before the account can be reclaimed
+        days: u64,
+    },
+
+    /// Release a hold so the account follows reclaim.min_inactive_days again
+    Clear {
+        /// Account public key
+        pubkey: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum StagedAction {
+    /// List staged reclaims with their simulation results
+    List,
+
+    /// Release a staged reclaim for the next auto cycle
+    Approve {
+        /// Account public key
+        pubkey: String,
+    },
+
+    /// Cancel a staged reclaim; the account is not reclaimed automatically
+    Deny {
+        /// Account public key
+        pubkey: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum OutreachAction {
+    /// Record that a user was contacted about closing an account
+    Record {
+        /// Account public key
+        pubkey: String,
+
+        /// How the user was contacted, e.g. email, telegram or solana-pay
+        #[arg(long)]
+        channel: String,
+
+        /// no_response, agreed, declined or unreachable
+        #[arg(short, long, default_value = "no_response")]
+        response: String,
+
+        /// Free-form notes
+        #[arg(short, long)]
+        notes: Option<String>,
+
+        /// When the contact happened (YYYY-MM-DD or RFC 3339), defaults to now
+        #[arg(long, value_parser = crate::utils::parse_since)]
+        at: Option<chrono::DateTime<chrono::Utc>>,
+    },
+
+    /// Update the user's response to a recorded contact
+    Respond {
+        /// Outreach record ID, from `outreach list`
+        id: i64,
+
+        /// no_response, agreed, declined or unreachable
+        response: String,
+    },
+
+    /// List recorded contacts, newest first
+    List {
+        /// Only contacts about this account
+        #[arg(short, long)]
+        pubkey: Option<String>,
+    },
+
+    /// Passively monitored token accounts to contact, active owners first;
+    /// owners idle for `reclaim.churn_after_days` are marked churned
+    Candidates {
+        /// Show at most this many accounts
+        #[arg(short, long, default_value_t = 50)]
+        limit: usize,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum EvidenceAction {
+    /// Show the instructions an account was attributed from
+    Show {
+        /// Account public key
+        pubkey: String,
+    },
+
+    /// Re-run the current parsers over stored evidence (no RPC calls) and
+    /// list attributions that changed
+    Replay {
+        /// Update the stored accounts with the replayed attributions
+        #[arg(long)]
+        apply: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DevnetAction {
+    /// Airdrop SOL to the operator and treasury, create token accounts the
+    /// operator sponsors and can close, and seed the database with them
+    Bootstrap {
+        /// Number of sponsored token accounts to create
+        #[arg(long, default_value = "5")]
+        accounts: usize,
+
+        /// SOL to request from the faucet for each wallet
+        #[arg(long, default_value = "1")]
+        airdrop_sol: f64,
+    },
+}
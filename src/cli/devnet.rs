@@ -0,0 +1,239 @@
+// src/cli/devnet.rs - Devnet sandbox for trying the bot end to end
+
+use colored::*;
+use solana_sdk::{
+    native_token::{lamports_to_sol, sol_to_lamports},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use spl_token::instruction::AuthorityType;
+use std::time::Duration;
+use crate::{
+    config::{Config, Network},
+    error::{ReclaimError, Result},
+    kora::{FinalityChecker, KoraMonitor},
+    reclaim::EligibilityChecker,
+    solana::{ParserRegistry, SolanaRpcClient},
+    storage::{models::SponsoredAccount, Database},
+    utils,
+};
+
+/// How long to wait for the faucet's transfer to land
+const AIRDROP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long to wait for the new accounts' creations to finalize
+const FINALITY_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Rough cost of one sponsored token account: rent plus fees
+const LAMPORTS_PER_ACCOUNT: u64 = 2_500_000;
+
+/// Fund the operator, create `accounts` token accounts it sponsors and can
+/// close, then discover them into the database
+pub async fn bootstrap(config: &Config, accounts: usize, airdrop_sol: f64) -> Result<()> {
+    if !matches!(config.solana.network, Network::Devnet) {
+        return Err(ReclaimError::ConfigError(format!(
+            "devnet bootstrap only runs with solana.network = \"devnet\" (configured: {:?})",
+            config.solana.network
+        )));
+    }
+
+    println!("{}", "=== Devnet Bootstrap ===".cyan().bold());
+    println!("RPC: {}\n", config.solana.rpc_url);
+
+    let rpc_client = SolanaRpcClient::from_config(config);
+    let operator = operator_keypair(config)?;
+    let treasury = config.treasury_wallet()?;
+
+    // Airdrop
+    let lamports = sol_to_lamports(airdrop_sol);
+    airdrop(&rpc_client, &operator.pubkey(), lamports).await;
+    if treasury != operator.pubkey() {
+        airdrop(&rpc_client, &treasury, lamports).await;
+    }
+
+    let needed = LAMPORTS_PER_ACCOUNT * (accounts as u64 + 1);
+    let balance = rpc_client.get_balance(&operator.pubkey()).await?;
+    if balance < needed {
+        return Err(ReclaimError::ConfigError(format!(
+            "operator holds {} SOL but about {} SOL is needed; fund {} and retry",
+            lamports_to_sol(balance),
+            lamports_to_sol(needed),
+            operator.pubkey()
+        )));
+    }
+
+    // Sponsored accounts
+    let mint = create_mint(&rpc_client, &operator).await?;
+    println!("{} Created mint {}", "✓".green(), mint);
+
+    let mut created = Vec::with_capacity(accounts);
+    for _ in 0..accounts {
+        let token_account = create_sponsored_account(&rpc_client, &operator, &mint).await?;
+        println!("{} Created token account {}", "✓".green(), token_account);
+        created.push(token_account);
+    }
+
+    // Seed the database
+    println!("\n{}", "Discovering the new accounts...".cyan());
+    let db = Database::new(&config.database.path)?;
+    let monitor = KoraMonitor::new(rpc_client.clone(), operator.pubkey())
+        .with_parsers(ParserRegistry::from_config(config))
+        .with_cache(db.clone());
+    // Mint creation plus one transaction per account, with room for earlier activity
+    let discovered = monitor.get_sponsored_accounts(accounts * 2 + 20).await?;
+    let records: Vec<SponsoredAccount> = discovered.iter().map(SponsoredAccount::from).collect();
+    db.save_accounts_batch(&records)?;
+
+    // Tracked accounts stay Provisional until their creation is finalized
+    let finality = FinalityChecker::new(rpc_client.clone(), db.clone());
+    let started = std::time::Instant::now();
+    while finality.confirm_saved().await.pending > 0 && started.elapsed() < FINALITY_TIMEOUT {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+    }
+
+    let checker = EligibilityChecker::new(rpc_client.clone(), config.clone());
+    for pubkey in &created {
+        match checker.determine_reclaim_strategy(pubkey).await {
+            Ok((strategy, authority)) => {
+                db.update_account_authority(&pubkey.to_string(), authority, &strategy.to_string())?;
+            }
+            Err(e) => println!("{} Could not classify {}: {}", "⚠".yellow(), pubkey, e),
+        }
+    }
+
+    let found = created.iter().filter(|pubkey| discovered.iter().any(|a| a.pubkey == **pubkey)).count();
+    println!(
+        "{} {} of {} bootstrap accounts tracked ({} sponsored accounts saved)",
+        "✓".green(),
+        found,
+        created.len(),
+        records.len()
+    );
+    if found < created.len() {
+        println!("  Run {} once the RPC node has indexed the transactions", "kora-reclaim scan".yellow());
+    }
+
+    println!("\n{}", "Next steps:".cyan());
+    if config.reclaim.min_inactive_days > 0 {
+        println!(
+            "  Set {} in your config so fresh accounts are eligible",
+            "reclaim.min_inactive_days = 0".yellow()
+        );
+    }
+    println!("  {} to see the sandbox accounts", "kora-reclaim list --detailed".yellow());
+    println!("  {} to reclaim their rent", "kora-reclaim auto --dry-run".yellow());
+    Ok(())
+}
+
+/// The keypair that signs as the operator: the treasury keypair or the
+/// separate fee payer, whichever matches `kora.operator_pubkey`
+fn operator_keypair(config: &Config) -> Result<Keypair> {
+    let operator = config.operator_pubkey()?;
+    let treasury = config.load_treasury_keypair()?;
+    if treasury.pubkey() == operator {
+        return Ok(treasury);
+    }
+    match config.load_fee_payer_keypair()? {
+        Some(fee_payer) if fee_payer.pubkey() == operator => Ok(fee_payer),
+        _ => Err(ReclaimError::ConfigError(format!(
+            "no configured keypair matches operator {}; set kora.treasury_keypair_path or kora.fee_payer_keypair_path to its keypair",
+            operator
+        ))),
+    }
+}
+
+/// Request an airdrop and wait for it; the public faucet is often rate
+/// limited, so failures are reported and left to the balance check
+async fn airdrop(rpc_client: &SolanaRpcClient, pubkey: &Pubkey, lamports: u64) {
+    let result = match rpc_client.request_airdrop(pubkey, lamports).await {
+        Ok(signature) => wait_for_confirmation(rpc_client, &signature).await,
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(()) => println!(
+            "{} Airdropped {} SOL to {}",
+            "✓".green(),
+            lamports_to_sol(lamports),
+            utils::format_pubkey(&pubkey.to_string())
+        ),
+        Err(e) => println!(
+            "{} Airdrop to {} failed: {}",
+            "⚠".yellow(),
+            utils::format_pubkey(&pubkey.to_string()),
+            e
+        ),
+    }
+}
+
+async fn wait_for_confirmation(rpc_client: &SolanaRpcClient, signature: &Signature) -> Result<()> {
+    let deadline = tokio::time::Instant::now() + AIRDROP_TIMEOUT;
+    while tokio::time::Instant::now() < deadline {
+        match rpc_client.get_signature_status(signature).await? {
+            Some(Ok(())) => return Ok(()),
+            Some(Err(e)) => return Err(ReclaimError::TransactionFailed(e.to_string())),
+            None => tokio::time::sleep(Duration::from_secs(1)).await,
+        }
+    }
+    Err(ReclaimError::TransactionFailed(format!("{} not confirmed within {:?}", signature, AIRDROP_TIMEOUT)))
+}
+
+/// A fresh zero-decimal mint owned by the operator
+async fn create_mint(rpc_client: &SolanaRpcClient, operator: &Keypair) -> Result<Pubkey> {
+    let mint = Keypair::new();
+    let rent = rpc_client.get_minimum_balance_for_rent_exemption(spl_token::state::Mint::LEN)?;
+    let instructions = [
+        system_instruction::create_account(
+            &operator.pubkey(),
+            &mint.pubkey(),
+            rent,
+            spl_token::state::Mint::LEN as u64,
+            &spl_token::id(),
+        ),
+        spl_token::instruction::initialize_mint2(&spl_token::id(), &mint.pubkey(), &operator.pubkey(), None, 0)?,
+    ];
+
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&operator.pubkey()),
+        &[operator, &mint],
+        rpc_client.get_latest_blockhash()?,
+    );
+    rpc_client.send_and_confirm_transaction(&transaction).await?;
+    Ok(mint.pubkey())
+}
+
+/// An associated token account for a throwaway owner, paid for by the
+/// operator, with the operator as its close authority
+async fn create_sponsored_account(rpc_client: &SolanaRpcClient, operator: &Keypair, mint: &Pubkey) -> Result<Pubkey> {
+    let owner = Keypair::new();
+    let token_account = spl_associated_token_account::get_associated_token_address(&owner.pubkey(), mint);
+    let instructions = [
+        spl_associated_token_account::instruction::create_associated_token_account(
+            &operator.pubkey(),
+            &owner.pubkey(),
+            mint,
+            &spl_token::id(),
+        ),
+        spl_token::instruction::set_authority(
+            &spl_token::id(),
+            &token_account,
+            Some(&operator.pubkey()),
+            AuthorityType::CloseAccount,
+            &owner.pubkey(),
+            &[],
+        )?,
+    ];
+
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&operator.pubkey()),
+        &[operator, &owner],
+        rpc_client.get_latest_blockhash()?,
+    );
+    rpc_client.send_and_confirm_transaction(&transaction).await?;
+    Ok(token_account)
+}
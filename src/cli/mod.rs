@@ -1,3 +1,14 @@
 pub mod commands;
+pub mod devnet;
+pub mod plan;
+pub mod prove;
+pub mod setup;
 
 pub use commands::{Cli, Commands};
+
+/// Exit status of a command that failed
+pub const EXIT_FAILURE: i32 = 1;
+/// Exit status when a confirmation was declined or the operation cancelled
+pub const EXIT_CANCELLED: i32 = 2;
+/// Exit status when a `--fail-if-*` threshold was breached
+pub const EXIT_THRESHOLD: i32 = 3;
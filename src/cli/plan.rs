@@ -0,0 +1,259 @@
+// src/cli/plan.rs - What a reclaim run would do right now, without scanning or sending
+
+use colored::*;
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+use std::{str::FromStr, sync::Arc};
+use crate::{
+    config::Config,
+    error::{ReclaimError, Result},
+    reclaim::{schedule::ReclaimSchedule, signer, CostPlan, EligibilityChecker},
+    solana::{budget::RpcBudget, SolanaRpcClient},
+    storage::Database,
+    utils,
+};
+use tracing::warn;
+
+/// An account the run would close, at its current balance
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedAccount {
+    pub pubkey: String,
+    pub balance_lamports: u64,
+}
+
+/// A tracked account the run would leave alone
+#[derive(Debug, Clone, Serialize)]
+pub struct SkippedAccount {
+    pub pubkey: String,
+    /// Only looked up with `--verbose`; it costs more RPC calls
+    pub reason: Option<String>,
+}
+
+/// Outcome of evaluating every tracked Active account against the chain
+#[derive(Debug, Clone, Serialize)]
+pub struct ReclaimPlan {
+    pub checked: usize,
+    pub dead_lettered: usize,
+    pub ineligible: Vec<SkippedAccount>,
+    pub batches: Vec<Vec<PlannedAccount>>,
+    pub cost: CostPlan,
+    /// Why a run started now would not reclaim, or only partly
+    pub blockers: Vec<String>,
+    pub rpc_calls: u64,
+}
+
+/// What stands in the way of a run, given the evaluated costs
+fn blockers(
+    config: &Config,
+    signer_error: Option<String>,
+    auto_paused: bool,
+    window_open: bool,
+    cost: &CostPlan,
+) -> Vec<String> {
+    let mut blockers = Vec::new();
+
+    if let Some(error) = signer_error {
+        blockers.push(format!("Signer not loaded, nothing can be sent: {}", error));
+    }
+    if config.reclaim.dry_run {
+        blockers.push("reclaim.dry_run is set: runs only simulate".to_string());
+    }
+    if auto_paused {
+        blockers.push("Auto reclaim is turned off in runtime settings: `auto` skips reclaiming".to_string());
+    }
+    if !window_open {
+        blockers.push("Outside the reclaim windows: `auto` holds eligible accounts until one opens".to_string());
+    }
+    if cost.exceeds(config.reclaim.fees.max_cost_percent) {
+        blockers.push(format!(
+            "Estimated fees are {:.2}% of the expected recovery, over reclaim.fees.max_cost_percent ({}%): the batch would abort",
+            cost.cost_percent(),
+            config.reclaim.fees.max_cost_percent.unwrap_or_default()
+        ));
+    }
+
+    blockers
+}
+
+/// Evaluate the database's Active accounts on-chain. Reads only: checkpoints,
+/// statuses and balances in the database are left as they are.
+pub async fn build(config: &Config, verbose: bool) -> Result<ReclaimPlan> {
+    let budget = Arc::new(RpcBudget::new(None));
+    let rpc_client = SolanaRpcClient::from_config(config).with_budget(budget.clone());
+    let db = Database::new(&config.database.path)?;
+
+    // Provisional and Unrecoverable accounts are never part of a routine run
+    let accounts = db.get_routine_active_accounts()?;
+    let dead_lettered = db.get_dead_lettered_pubkeys()?;
+    let checker = EligibilityChecker::new(rpc_client.clone(), config.clone()).with_holds(db.clone());
+
+    let mut eligible = Vec::new();
+    let mut ineligible = Vec::new();
+    let mut dead_lettered_count = 0;
+
+    for account in &accounts {
+        if dead_lettered.contains(&account.pubkey) {
+            dead_lettered_count += 1;
+            continue;
+        }
+        let Ok(pubkey) = Pubkey::from_str(&account.pubkey) else {
+            warn!("Skipping account with invalid pubkey {}", account.pubkey);
+            continue;
+        };
+
+        match checker.is_eligible(&pubkey, account.created_at).await {
+            Ok(true) => eligible.push(pubkey),
+            Ok(false) => {
+                let reason = if verbose {
+                    Some(
+                        checker
+                            .get_eligibility_reason(&pubkey, account.created_at)
+                            .await
+                            .unwrap_or_else(|e| e.to_string()),
+                    )
+                } else {
+                    None
+                };
+                ineligible.push(SkippedAccount { pubkey: account.pubkey.clone(), reason });
+            }
+            Err(e) => ineligible.push(SkippedAccount {
+                pubkey: account.pubkey.clone(),
+                reason: Some(e.to_string()),
+            }),
+        }
+    }
+
+    // getMultipleAccounts accepts at most 100 keys per request
+    let mut planned = Vec::with_capacity(eligible.len());
+    for chunk in eligible.chunks(100) {
+        let fetched = rpc_client.get_multiple_accounts(chunk).await?;
+        planned.extend(chunk.iter().zip(&fetched).map(|(pubkey, account)| PlannedAccount {
+            pubkey: pubkey.to_string(),
+            balance_lamports: account.as_ref().map_or(0, |a| a.lamports),
+        }));
+    }
+
+    // Signatures per transaction depend on a separate fee payer, as in the engine
+    let (signer_error, signatures) = match signer::from_config(config) {
+        Ok(signer) => match signer::fee_payer_from_config(config) {
+            Ok(payer) => (None, 1 + payer.is_some_and(|payer| payer.pubkey() != signer.pubkey()) as u64),
+            Err(e) => (Some(format!("fee payer: {}", e)), 2),
+        },
+        Err(e) => (Some(e.to_string()), 1 + config.kora.fee_payer_keypair_path.is_some() as u64),
+    };
+
+    let balances: Vec<u64> = planned.iter().map(|account| account.balance_lamports).collect();
+    let mut cost = CostPlan::new(&balances, &config.reclaim.fees, signatures);
+    if !eligible.is_empty() {
+        // getRecentPrioritizationFees accepts at most 128 addresses
+        match rpc_client.get_recent_priority_fee(&eligible[..eligible.len().min(128)]).await {
+            Ok(fee) => cost.recent_priority_fee_micro_lamports = Some(fee),
+            Err(e) => warn!("Failed to fetch recent priority fees: {}", e),
+        }
+    }
+
+    let schedule = ReclaimSchedule::from_config(&config.reclaim.schedule)?;
+    let auto_paused = db.get_runtime_settings()?.auto_reclaim_enabled == Some(false);
+    let blockers = blockers(config, signer_error, auto_paused, schedule.is_open_now(), &cost);
+
+    // Empty accounts are skipped by the engine, so they take no batch slot
+    planned.retain(|account| account.balance_lamports > 0);
+    let batches = planned
+        .chunks(config.reclaim.batch_size.max(1))
+        .map(<[PlannedAccount]>::to_vec)
+        .collect();
+
+    Ok(ReclaimPlan {
+        checked: accounts.len(),
+        dead_lettered: dead_lettered_count,
+        ineligible,
+        batches,
+        cost,
+        blockers,
+        rpc_calls: budget.total(),
+    })
+}
+
+/// `plan`: print what a reclaim run started now would do
+pub async fn show(config: &Config, format: &str, verbose: bool) -> Result<()> {
+    let plan = build(config, verbose).await?;
+
+    if format == "json" {
+        let json = serde_json::to_string_pretty(&plan)
+            .map_err(|e| ReclaimError::Other(anyhow::anyhow!("Failed to serialize plan: {}", e)))?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    println!("{}", "=== Reclaim Plan ===".cyan().bold());
+    println!(
+        "Checked {} tracked Active accounts: {} eligible, {} not eligible, {} dead-lettered",
+        plan.checked,
+        plan.batches.iter().map(Vec::len).sum::<usize>().to_string().green(),
+        plan.ineligible.len(),
+        plan.dead_lettered
+    );
+
+    if verbose && !plan.ineligible.is_empty() {
+        println!("\n{}", "Not eligible:".bold());
+        for account in &plan.ineligible {
+            println!(
+                "  {}  {}",
+                utils::format_pubkey(&account.pubkey),
+                account.reason.as_deref().unwrap_or("not eligible")
+            );
+        }
+    }
+
+    if plan.batches.is_empty() {
+        println!("\nNothing to reclaim.");
+    } else {
+        println!();
+        if config.reclaim.adaptive.enabled {
+            println!("Batches of reclaim.batch_size; adaptive batching resizes them during the run");
+        }
+        for (i, batch) in plan.batches.iter().enumerate() {
+            let total: u64 = batch.iter().map(|account| account.balance_lamports).sum();
+            println!("{} {} ({} accounts, {})", "Batch".bold(), i + 1, batch.len(), utils::format_sol(total));
+            for account in batch {
+                println!("  {}  {}", account.pubkey, utils::format_sol(account.balance_lamports));
+            }
+        }
+        println!("\n{}", plan.cost);
+    }
+
+    if plan.blockers.is_empty() {
+        println!("\n{} No blockers: a run now would proceed", "✓".green());
+    } else {
+        println!("\n{}", "Blockers:".red().bold());
+        for blocker in &plan.blockers {
+            println!("  {} {}", "✗".red(), blocker);
+        }
+    }
+
+    println!("\nPlanning used {} RPC calls; nothing was sent or saved", plan.rpc_calls);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::FeeConfig;
+
+    #[test]
+    fn test_blockers() {
+        let mut config: Config = toml::from_str(include_str!("../../config.toml")).unwrap();
+        config.reclaim.dry_run = false;
+        config.reclaim.fees.max_cost_percent = Some(1.0);
+
+        let cheap = CostPlan::new(&[2_039_280], &FeeConfig::default(), 1);
+        assert!(blockers(&config, None, false, true, &cheap).is_empty());
+
+        // Dust costs more to close than it returns
+        let dust = CostPlan::new(&[10_000], &FeeConfig::default(), 2);
+        let found = blockers(&config, Some("No such file or directory".to_string()), true, false, &dust);
+        assert_eq!(found.len(), 4);
+        assert!(found[0].starts_with("Signer not loaded"));
+        assert!(found[3].contains("max_cost_percent"));
+    }
+}
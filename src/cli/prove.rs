@@ -0,0 +1,313 @@
+// src/cli/prove.rs - Evidence that the operator sponsored an account, for partner disputes
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use solana_sdk::{program_pack::Pack, pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction, UiMessage};
+use spl_token::state::Account as TokenAccount;
+use std::{fmt::Write, str::FromStr};
+use crate::{
+    config::Config,
+    error::{ReclaimError, Result},
+    solana::SolanaRpcClient,
+    storage::{
+        models::{AccountEpoch, ReclaimOperation},
+        Database,
+    },
+    utils,
+};
+
+/// The transaction that created the account, as the chain reports it now
+#[derive(Debug, Clone, Serialize)]
+pub struct CreationProof {
+    pub signature: String,
+    pub slot: Option<u64>,
+    pub block_time: Option<DateTime<Utc>>,
+    /// First account key, which paid the fee and the rent
+    pub fee_payer: Option<String>,
+    /// `None` if the transaction could not be fetched
+    pub succeeded: Option<bool>,
+    /// Instructions stored when the account was discovered (see `evidence show`)
+    pub instructions: Vec<serde_json::Value>,
+}
+
+/// Who can close the account today
+#[derive(Debug, Clone, Serialize)]
+pub struct CloseAuthorityState {
+    pub exists: bool,
+    pub owner_program: Option<String>,
+    pub lamports: Option<u64>,
+    /// Token owner, for SPL Token accounts
+    pub token_owner: Option<String>,
+    /// Explicit close authority, for SPL Token accounts that set one
+    pub close_authority: Option<String>,
+    pub operator_can_close: bool,
+}
+
+/// A recorded reclaim and whether its transaction is on chain
+#[derive(Debug, Clone, Serialize)]
+pub struct ReclaimProof {
+    #[serde(flatten)]
+    pub operation: ReclaimOperation,
+    /// `None` if the transaction could not be fetched
+    pub confirmed: Option<bool>,
+}
+
+/// Everything behind the operator's claim to have sponsored one account
+#[derive(Debug, Clone, Serialize)]
+pub struct OwnershipProof {
+    pub account: String,
+    pub operator: String,
+    pub generated_at: DateTime<Utc>,
+    /// The creation transaction succeeded with the operator as fee payer
+    pub sponsored: bool,
+    pub creation: Option<CreationProof>,
+    pub close_authority: CloseAuthorityState,
+    pub reclaims: Vec<ReclaimProof>,
+    /// Earlier lifetimes of the address, when it was closed and created again
+    pub earlier_creations: Vec<AccountEpoch>,
+}
+
+/// First account key of a transaction: the fee payer
+fn fee_payer(tx: &EncodedConfirmedTransactionWithStatusMeta) -> Option<String> {
+    let EncodedTransaction::Json(ui_tx) = &tx.transaction.transaction else {
+        return None;
+    };
+    match &ui_tx.message {
+        UiMessage::Parsed(parsed) => parsed.account_keys.first().map(|key| key.pubkey.clone()),
+        UiMessage::Raw(raw) => raw.account_keys.first().cloned(),
+    }
+}
+
+fn succeeded(tx: &EncodedConfirmedTransactionWithStatusMeta) -> Option<bool> {
+    tx.transaction.meta.as_ref().map(|meta| meta.err.is_none())
+}
+
+/// Assemble the proof from the database and the chain. Only reads.
+pub async fn build(config: &Config, pubkey: &str) -> Result<OwnershipProof> {
+    let account_pubkey = Pubkey::from_str(pubkey)?;
+    let rpc_client = SolanaRpcClient::from_config(config);
+    let db = Database::new(&config.database.path)?;
+
+    let Some(account) = db.get_account_by_pubkey(pubkey)? else {
+        return Err(ReclaimError::AccountNotFound(format!("{} is not tracked; scan or import it first", pubkey)));
+    };
+    // Accounts of retired operator keys are claimed by the key that created them
+    let operator = match &account.operator {
+        Some(operator) => operator.clone(),
+        None => config.operator_pubkey()?.to_string(),
+    };
+
+    let evidence = db.get_discovery_evidence(Some(pubkey))?.into_iter().next();
+    let signature = account.creation_signature.clone().or_else(|| evidence.as_ref().map(|e| e.signature.clone()));
+    let creation = match signature {
+        Some(signature) => {
+            let tx = rpc_client.get_transaction(&Signature::from_str(&signature)?).await?;
+            Some(CreationProof {
+                slot: tx.as_ref().map(|tx| tx.slot).or(account.creation_slot),
+                block_time: tx
+                    .as_ref()
+                    .and_then(|tx| tx.block_time)
+                    .and_then(|time| DateTime::from_timestamp(time, 0))
+                    .or(Some(account.created_at)),
+                fee_payer: tx.as_ref().and_then(fee_payer),
+                succeeded: tx.as_ref().and_then(succeeded),
+                instructions: evidence.map(|e| e.instructions).unwrap_or_default(),
+                signature,
+            })
+        }
+        None => None,
+    };
+
+    let on_chain = rpc_client.get_account(&account_pubkey).await?;
+    let token = on_chain
+        .as_ref()
+        .filter(|account| account.owner == spl_token::id())
+        .and_then(|account| TokenAccount::unpack(&account.data).ok());
+    let token_owner = token.map(|token| token.owner.to_string());
+    let close_authority = token.and_then(|token| Option::<Pubkey>::from(token.close_authority)).map(|key| key.to_string());
+    let close_authority = CloseAuthorityState {
+        exists: on_chain.is_some(),
+        owner_program: on_chain.as_ref().map(|account| account.owner.to_string()),
+        lamports: on_chain.as_ref().map(|account| account.lamports),
+        operator_can_close: close_authority.as_ref().or(token_owner.as_ref()) == Some(&operator),
+        token_owner,
+        close_authority,
+    };
+
+    let mut reclaims = Vec::new();
+    for operation in db.get_reclaim_operations_for(pubkey)? {
+        let confirmed = match Signature::from_str(&operation.tx_signature) {
+            Ok(signature) => rpc_client.get_transaction(&signature).await?.as_ref().and_then(succeeded),
+            Err(_) => None,
+        };
+        reclaims.push(ReclaimProof { operation, confirmed });
+    }
+
+    Ok(OwnershipProof {
+        account: pubkey.to_string(),
+        sponsored: creation
+            .as_ref()
+            .is_some_and(|c| c.succeeded == Some(true) && c.fee_payer.as_ref() == Some(&operator)),
+        operator,
+        generated_at: Utc::now(),
+        creation,
+        close_authority,
+        reclaims,
+        earlier_creations: db.get_account_epochs(pubkey)?,
+    })
+}
+
+impl OwnershipProof {
+    /// Markdown dossier for sharing with an integrated app
+    pub fn to_markdown(&self) -> String {
+        let yes_no = |value: Option<bool>| match value {
+            Some(true) => "yes",
+            Some(false) => "no",
+            None => "unknown",
+        };
+        let mut md = String::new();
+
+        let _ = writeln!(md, "# Sponsorship proof: `{}`\n", self.account);
+        let _ = writeln!(md, "- Operator: `{}`", self.operator);
+        let _ = writeln!(md, "- Generated: {}", utils::format_timestamp(&self.generated_at));
+        let _ = writeln!(
+            md,
+            "- Verdict: {}\n",
+            if self.sponsored {
+                "the operator paid for the account's creation"
+            } else {
+                "the creation transaction does not show the operator as fee payer"
+            }
+        );
+
+        let _ = writeln!(md, "## Creation\n");
+        match &self.creation {
+            Some(creation) => {
+                let _ = writeln!(md, "- Signature: `{}`", creation.signature);
+                if let Some(slot) = creation.slot {
+                    let _ = writeln!(md, "- Slot: {}", slot);
+                }
+                if let Some(time) = &creation.block_time {
+                    let _ = writeln!(md, "- Time: {}", utils::format_timestamp(time));
+                }
+                let _ = writeln!(md, "- Fee payer: `{}`", creation.fee_payer.as_deref().unwrap_or("unknown"));
+                let _ = writeln!(md, "- Succeeded: {}", yes_no(creation.succeeded));
+                if !creation.instructions.is_empty() {
+                    let instructions = serde_json::to_string_pretty(&creation.instructions).unwrap_or_default();
+                    let _ = writeln!(md, "\nInstructions recorded at discovery:\n\n```json\n{}\n```", instructions);
+                }
+            }
+            None => {
+                let _ = writeln!(md, "No creation transaction is recorded for this account.");
+            }
+        }
+
+        let state = &self.close_authority;
+        let _ = writeln!(md, "\n## Close authority\n");
+        if state.exists {
+            let _ = writeln!(md, "- Owner program: `{}`", state.owner_program.as_deref().unwrap_or("unknown"));
+            if let Some(lamports) = state.lamports {
+                let _ = writeln!(md, "- Balance: {}", utils::format_sol(lamports));
+            }
+            if let Some(owner) = &state.token_owner {
+                let _ = writeln!(md, "- Token owner: `{}`", owner);
+            }
+            let _ = writeln!(md, "- Close authority: `{}`", state.close_authority.as_deref().unwrap_or("not set (token owner)"));
+            let _ = writeln!(md, "- Operator can close: {}", yes_no(Some(state.operator_can_close)));
+        } else {
+            let _ = writeln!(md, "The account no longer exists on chain.");
+        }
+
+        let _ = writeln!(md, "\n## Reclaims\n");
+        if self.reclaims.is_empty() {
+            let _ = writeln!(md, "No reclaims recorded.");
+        }
+        for reclaim in &self.reclaims {
+            let operation = &reclaim.operation;
+            let _ = writeln!(
+                md,
+                "- {}: {} to `{}`, signature `{}` (confirmed: {})",
+                utils::format_timestamp(&operation.timestamp),
+                utils::format_sol(operation.reclaimed_amount),
+                operation.destination.as_deref().unwrap_or("unknown"),
+                operation.tx_signature,
+                yes_no(reclaim.confirmed)
+            );
+        }
+
+        if !self.earlier_creations.is_empty() {
+            let _ = writeln!(md, "\n## Earlier creations\n");
+            for epoch in &self.earlier_creations {
+                let _ = writeln!(
+                    md,
+                    "- {}: created {}, signature `{}`",
+                    epoch.epoch,
+                    utils::format_timestamp(&epoch.created_at),
+                    epoch.creation_signature
+                );
+            }
+        }
+
+        md
+    }
+}
+
+/// `prove`: print or write the sponsorship dossier of one account
+pub async fn show(config: &Config, pubkey: &str, format: &str, output: Option<&std::path::Path>) -> Result<()> {
+    let proof = build(config, pubkey).await?;
+    let dossier = match format {
+        "json" => serde_json::to_string_pretty(&proof)?,
+        "markdown" => proof.to_markdown(),
+        other => return Err(ReclaimError::ConfigError(format!("Unknown format '{}' (use markdown or json)", other))),
+    };
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, dossier)?;
+            println!("Wrote the sponsorship proof of {} to {}", pubkey, path.display());
+        }
+        None => println!("{}", dossier),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_dossier() {
+        let proof = OwnershipProof {
+            account: "Acc".to_string(),
+            operator: "Op".to_string(),
+            generated_at: Utc::now(),
+            sponsored: true,
+            creation: Some(CreationProof {
+                signature: "Sig".to_string(),
+                slot: Some(42),
+                block_time: None,
+                fee_payer: Some("Op".to_string()),
+                succeeded: Some(true),
+                instructions: vec![serde_json::json!({"program": "spl-associated-token-account"})],
+            }),
+            close_authority: CloseAuthorityState {
+                exists: false,
+                owner_program: None,
+                lamports: None,
+                token_owner: None,
+                close_authority: None,
+                operator_can_close: false,
+            },
+            reclaims: Vec::new(),
+            earlier_creations: Vec::new(),
+        };
+
+        let md = proof.to_markdown();
+        assert!(md.contains("- Verdict: the operator paid for the account's creation"));
+        assert!(md.contains("- Fee payer: `Op`"));
+        assert!(md.contains("\"program\": \"spl-associated-token-account\""));
+        assert!(md.contains("The account no longer exists on chain."));
+        assert!(!md.contains("## Earlier creations"));
+    }
+}
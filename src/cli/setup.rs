@@ -0,0 +1,321 @@
+// src/cli/setup.rs - Interactive first-run configuration wizard
+
+use colored::*;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Signer},
+};
+use std::path::Path;
+use std::str::FromStr;
+use teloxide::prelude::*;
+use crate::{
+    error::Result,
+    solana::SolanaRpcClient,
+    utils,
+};
+
+/// Values collected by the wizard
+struct SetupAnswers {
+    rpc_url: String,
+    network: String,
+    commitment: String,
+    operator_pubkey: Pubkey,
+    treasury_wallet: Pubkey,
+    treasury_keypair_path: String,
+    min_inactive_days: u64,
+    dry_run: bool,
+    database_path: String,
+    telegram: Option<TelegramAnswers>,
+}
+
+struct TelegramAnswers {
+    bot_token: String,
+    authorized_users: Vec<u64>,
+}
+
+/// Walk the operator through every setting, validating each one live,
+/// and write the result to `output`
+pub async fn run_setup(output: &str, force: bool) -> Result<()> {
+    println!("{}", "=== Kora Rent Reclaim Bot Setup ===".cyan().bold());
+    println!("Press Enter to accept the value in brackets.\n");
+
+    if Path::new(output).exists()
+        && !force
+        && !utils::confirm_action(&format!("{} already exists. Overwrite it?", output))
+    {
+        println!("Cancelled");
+        return Ok(());
+    }
+
+    let (rpc_url, network) = prompt_rpc().await;
+    let commitment = prompt_commitment();
+
+    println!("\n{}", "Kora".cyan().bold());
+    let operator_pubkey = prompt_pubkey("Kora fee payer (operator) pubkey", None);
+    let treasury_wallet = prompt_pubkey("Treasury wallet pubkey", None);
+    let treasury_keypair_path = prompt_keypair_path(&treasury_wallet);
+
+    println!("\n{}", "Reclaim policy".cyan().bold());
+    let min_inactive_days = loop {
+        match utils::prompt_input("Minimum inactive days before reclaim", Some("30")).parse() {
+            Ok(days) => break days,
+            Err(_) => println!("{}", "Enter a whole number of days".red()),
+        }
+    };
+    let dry_run = !utils::confirm_action("Send real transactions? (answer No to start in dry-run mode)");
+    let database_path = utils::prompt_input("SQLite database path", Some("./kora_reclaim.db"));
+
+    println!("\n{}", "Telegram".cyan().bold());
+    let telegram = if utils::confirm_action("Configure Telegram notifications?") {
+        Some(prompt_telegram().await)
+    } else {
+        None
+    };
+
+    let answers = SetupAnswers {
+        rpc_url,
+        network,
+        commitment,
+        operator_pubkey,
+        treasury_wallet,
+        treasury_keypair_path,
+        min_inactive_days,
+        dry_run,
+        database_path,
+        telegram,
+    };
+
+    std::fs::write(output, render_config(&answers))?;
+
+    println!("\n{} {}", "✓ Configuration written to".green(), output);
+    println!("Next steps:");
+    println!("  kora-reclaim init     # create the database");
+    println!("  kora-reclaim scan     # discover sponsored accounts");
+    if answers.dry_run {
+        println!("{}", "Dry-run is enabled; set reclaim.dry_run = false once you have verified the results.".yellow());
+    }
+
+    Ok(())
+}
+
+/// Ask for an RPC URL until one answers, then pick the matching network
+async fn prompt_rpc() -> (String, String) {
+    println!("{}", "Solana".cyan().bold());
+
+    let rpc_url = loop {
+        let url = utils::prompt_input("RPC URL", Some("https://api.devnet.solana.com"));
+        let client = SolanaRpcClient::new(&url, CommitmentConfig::confirmed(), 0);
+
+        match client.get_slot().await {
+            Ok(slot) => {
+                println!("{} (current slot {})", "✓ RPC reachable".green(), slot);
+                break url;
+            }
+            Err(e) => println!("{} {}", "✗ RPC check failed:".red(), e),
+        }
+    };
+
+    let guess = if rpc_url.contains("devnet") {
+        "Devnet"
+    } else if rpc_url.contains("testnet") {
+        "Testnet"
+    } else {
+        "Mainnet"
+    };
+
+    let network = loop {
+        let input = utils::prompt_input("Network (Mainnet, Devnet, Testnet)", Some(guess));
+        match input.to_lowercase().as_str() {
+            "mainnet" => break "Mainnet",
+            "devnet" => break "Devnet",
+            "testnet" => break "Testnet",
+            _ => println!("{}", "Choose Mainnet, Devnet or Testnet".red()),
+        }
+    };
+
+    (rpc_url, network.to_string())
+}
+
+fn prompt_commitment() -> String {
+    loop {
+        let input = utils::prompt_input("Commitment (processed, confirmed, finalized)", Some("confirmed"));
+        match input.to_lowercase().as_str() {
+            c @ ("processed" | "confirmed" | "finalized") => return c.to_string(),
+            _ => println!("{}", "Choose processed, confirmed or finalized".red()),
+        }
+    }
+}
+
+fn prompt_pubkey(prompt: &str, default: Option<&str>) -> Pubkey {
+    loop {
+        match Pubkey::from_str(&utils::prompt_input(prompt, default)) {
+            Ok(pubkey) => return pubkey,
+            Err(e) => println!("{} {}", "✗ Invalid pubkey:".red(), e),
+        }
+    }
+}
+
+/// Keypair path, checked against the treasury wallet when the file exists
+fn prompt_keypair_path(treasury_wallet: &Pubkey) -> String {
+    loop {
+        let path = utils::prompt_input("Treasury keypair path", Some("./treasury-keypair.json"));
+
+        if !Path::new(&path).exists() {
+            println!("{}", "⚠️  Keypair file not found; reclaims will fail until it exists".yellow());
+            if utils::confirm_action("Use this path anyway?") {
+                return path;
+            }
+            continue;
+        }
+
+        match read_keypair_file(&path) {
+            Ok(keypair) if keypair.pubkey() == *treasury_wallet => {
+                println!("{}", "✓ Keypair matches treasury wallet".green());
+                return path;
+            }
+            Ok(keypair) => {
+                println!(
+                    "{} keypair is {}, treasury wallet is {}",
+                    "✗ Mismatch:".red(),
+                    keypair.pubkey(),
+                    treasury_wallet
+                );
+            }
+            Err(e) => println!("{} {}", "✗ Could not read keypair:".red(), e),
+        }
+    }
+}
+
+/// Bot token verified with getMe, plus the authorized user IDs
+async fn prompt_telegram() -> TelegramAnswers {
+    let bot_token = loop {
+        let token = utils::prompt_input("Bot token (from @BotFather)", None);
+        if token.is_empty() {
+            continue;
+        }
+
+        match Bot::new(&token).get_me().await {
+            Ok(me) => {
+                println!("{} @{}", "✓ Connected as".green(), me.username());
+                break token;
+            }
+            Err(e) => println!("{} {}", "✗ getMe failed:".red(), e),
+        }
+    };
+
+    let authorized_users = loop {
+        let input = utils::prompt_input("Authorized user IDs, comma separated (from @userinfobot)", None);
+        let ids: std::result::Result<Vec<u64>, _> = input
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse::<u64>())
+            .collect();
+
+        match ids {
+            Ok(ids) if !ids.is_empty() => break ids,
+            Ok(_) => println!("{}", "Enter at least one user ID".red()),
+            Err(_) => println!("{}", "User IDs must be numbers".red()),
+        }
+    };
+
+    TelegramAnswers {
+        bot_token,
+        authorized_users,
+    }
+}
+
+fn quote(value: &str) -> String {
+    toml::Value::String(value.to_string()).to_string()
+}
+
+fn render_config(answers: &SetupAnswers) -> String {
+    let mut out = format!(
+        r#"# Kora Rent Reclaim Bot Configuration (generated by `kora-reclaim setup`)
+
+[solana]
+rpc_url = {rpc_url}
+network = {network}
+commitment = {commitment}
+rate_limit_delay_ms = 100
+
+[kora]
+operator_pubkey = {operator}
+treasury_wallet = {treasury}
+treasury_keypair_path = {keypair}
+
+[reclaim]
+min_inactive_days = {days}
+auto_reclaim_enabled = false
+batch_size = 10
+batch_delay_ms = 1000
+scan_interval_seconds = 3600
+dry_run = {dry_run}
+whitelist = []
+blacklist = []
+
+[database]
+path = {db}
+"#,
+        rpc_url = quote(&answers.rpc_url),
+        network = quote(&answers.network),
+        commitment = quote(&answers.commitment),
+        operator = quote(&answers.operator_pubkey.to_string()),
+        treasury = quote(&answers.treasury_wallet.to_string()),
+        keypair = quote(&answers.treasury_keypair_path),
+        days = answers.min_inactive_days,
+        dry_run = answers.dry_run,
+        db = quote(&answers.database_path),
+    );
+
+    if let Some(telegram) = &answers.telegram {
+        let users = telegram.authorized_users.iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        out.push_str(&format!(
+            r#"
+[telegram]
+bot_token = {token}
+authorized_users = [{users}]
+notifications_enabled = true
+alert_threshold_sol = 0.1
+"#,
+            token = quote(&telegram.bot_token),
+            users = users,
+        ));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rendered_config_parses() {
+        let answers = SetupAnswers {
+            rpc_url: "https://api.devnet.solana.com".to_string(),
+            network: "Devnet".to_string(),
+            commitment: "confirmed".to_string(),
+            operator_pubkey: Pubkey::new_unique(),
+            treasury_wallet: Pubkey::new_unique(),
+            treasury_keypair_path: "./keys/\"treasury\".json".to_string(),
+            min_inactive_days: 30,
+            dry_run: true,
+            database_path: "./kora_reclaim.db".to_string(),
+            telegram: Some(TelegramAnswers {
+                bot_token: "123:abc".to_string(),
+                authorized_users: vec![1, 2],
+            }),
+        };
+
+        let config: crate::config::Config = toml::from_str(&render_config(&answers)).unwrap();
+        assert_eq!(config.kora.treasury_keypair_path, "./keys/\"treasury\".json");
+        assert_eq!(config.telegram.unwrap().authorized_users, vec![1, 2]);
+        assert!(config.reclaim.dry_run);
+    }
+}
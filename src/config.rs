@@ -1,3 +1,6 @@
+use crate::i18n::Language;
+use crate::price::PriceSource;
+use crate::solana::RpcProvider;
 use serde::Deserialize;
 use solana_sdk::{pubkey::Pubkey, signature::Keypair};
 use std::str::FromStr;
@@ -10,6 +13,258 @@ pub struct Config {
     pub reclaim: ReclaimConfig,
     pub database: DatabaseConfig,
     pub telegram: Option<TelegramConfig>,
+    /// HTTP endpoints that receive pipeline events as JSON
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+    #[serde(default)]
+    pub tui: TuiConfig,
+    #[serde(default)]
+    pub discovery: DiscoveryConfig,
+    /// Language of Telegram messages and CLI summaries
+    #[serde(default)]
+    pub language: Language,
+    /// Read-only HTTP API served by `kora-reclaim daemon`
+    #[serde(default)]
+    pub api: ApiConfig,
+    /// Restart policy for the services `kora-reclaim daemon` runs
+    #[serde(default)]
+    pub daemon: DaemonConfig,
+    /// Kora operator clients served from this deployment, selected with `--tenant`
+    #[serde(default)]
+    pub tenants: Vec<TenantConfig>,
+    /// SOL/USD price used to value rent
+    #[serde(default)]
+    pub price: PriceConfig,
+}
+
+/// One operator client in multi-tenant mode. Selecting it replaces the
+/// operator, treasury, database and notification settings of the top-level
+/// configuration; everything else is shared.
+#[derive(Debug, Deserialize, Clone)]
+pub struct TenantConfig {
+    /// Short identifier used with `--tenant`: letters, digits, `-` and `_`
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub operator_pubkey: String,
+    /// This tenant's earlier operator keys; the top-level ones are not inherited
+    #[serde(default)]
+    pub retired_operator_pubkeys: Vec<String>,
+    pub treasury_wallet: String,
+    pub treasury_keypair_path: String,
+    /// Defaults to the top-level fee payer
+    #[serde(default)]
+    pub fee_payer_keypair_path: Option<String>,
+    /// Signing service for this tenant's close authority; the top-level one is not inherited
+    #[serde(default)]
+    pub remote_signer: Option<RemoteSignerConfig>,
+    /// Defaults to the top-level database path with `-<id>` appended to the file name
+    #[serde(default)]
+    pub database_path: Option<String>,
+    /// Telegram bot for this tenant; none when unset
+    #[serde(default)]
+    pub telegram: Option<TelegramConfig>,
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+    /// HTTP API for this tenant's daemon; disabled when unset
+    #[serde(default)]
+    pub api: Option<ApiConfig>,
+    /// Passive matching for this tenant's treasury; defaults to `reclaim.passive_matching`
+    #[serde(default)]
+    pub passive_matching: Option<PassiveMatchingConfig>,
+}
+
+impl TenantConfig {
+    /// Database file of this tenant, given the top-level database path
+    pub fn database_path(&self, root_path: &str) -> String {
+        if let Some(path) = &self.database_path {
+            return path.clone();
+        }
+        if crate::storage::db::is_memory_path(root_path) {
+            return format!("{}{}", crate::storage::db::MEMORY_PATH, self.id);
+        }
+
+        let root = std::path::Path::new(root_path);
+        let stem = root.file_stem().and_then(|s| s.to_str()).unwrap_or("kora_reclaim");
+        let file_name = match root.extension().and_then(|e| e.to_str()) {
+            Some(extension) => format!("{}-{}.{}", stem, self.id, extension),
+            None => format!("{}-{}", stem, self.id),
+        };
+        root.with_file_name(file_name).to_string_lossy().into_owned()
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ApiConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Address the API listens on; keep it on loopback unless fronted by a proxy
+    #[serde(default = "default_api_bind")]
+    pub bind: String,
+    /// Public HTTPS address of the API, used in Solana Pay close request links
+    #[serde(default)]
+    pub public_url: Option<String>,
+    /// Name wallets show for close requests
+    #[serde(default = "default_close_request_label")]
+    pub close_request_label: String,
+    /// Square SVG or PNG wallets show next to the label
+    #[serde(default)]
+    pub close_request_icon: Option<String>,
+    /// `/healthz` fails once no scan has completed for this long; defaults to three scan intervals
+    #[serde(default)]
+    pub max_scan_age_seconds: Option<u64>,
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind: default_api_bind(),
+            public_url: None,
+            close_request_label: default_close_request_label(),
+            close_request_icon: None,
+            max_scan_age_seconds: None,
+        }
+    }
+}
+
+fn default_api_bind() -> String {
+    "127.0.0.1:8787".to_string()
+}
+
+fn default_close_request_label() -> String {
+    "Kora Rent Reclaim".to_string()
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DaemonConfig {
+    /// Wait before the first restart of a failed service; doubles on each
+    /// failure in a row
+    #[serde(default = "default_restart_initial_backoff_seconds")]
+    pub restart_initial_backoff_seconds: u64,
+    /// Longest wait between restarts. A service that ran this long before
+    /// failing starts again from the initial wait.
+    #[serde(default = "default_restart_max_backoff_seconds")]
+    pub restart_max_backoff_seconds: u64,
+}
+
+impl Default for DaemonConfig {
+    fn default() -> Self {
+        Self {
+            restart_initial_backoff_seconds: default_restart_initial_backoff_seconds(),
+            restart_max_backoff_seconds: default_restart_max_backoff_seconds(),
+        }
+    }
+}
+
+fn default_restart_initial_backoff_seconds() -> u64 {
+    5
+}
+
+fn default_restart_max_backoff_seconds() -> u64 {
+    300
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct DiscoveryConfig {
+    /// How the auto service learns about new operator transactions
+    #[serde(default)]
+    pub source: DiscoverySource,
+    /// Websocket endpoint for `source = "websocket"`; derived from `solana.rpc_url` when unset
+    pub ws_url: Option<String>,
+    /// Yellowstone gRPC endpoint for `source = "grpc"`, e.g. `https://grpc.example.com:443`
+    #[serde(default)]
+    pub grpc_url: Option<String>,
+    /// Sent as the `x-token` header to the gRPC endpoint
+    #[serde(default)]
+    pub grpc_x_token: Option<String>,
+    /// Programs whose transactions are not parsed when they invoke nothing
+    /// else, on top of the built-in Compute Budget and Memo programs
+    #[serde(default)]
+    pub skip_programs: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DiscoverySource {
+    /// Periodic `getSignaturesForAddress` scans only
+    #[default]
+    Polling,
+    /// Also subscribe to operator transactions over the RPC websocket and
+    /// record new accounts as they land; polling remains the backstop
+    Websocket,
+    /// The same, subscribing through a Yellowstone gRPC (Geyser) endpoint
+    Grpc,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TuiConfig {
+    /// Seconds between automatic reloads of stats and account status
+    #[serde(default = "default_tui_refresh_interval")]
+    pub refresh_interval_seconds: u64,
+    /// Age after which the header flags the displayed data as stale
+    #[serde(default = "default_tui_stale_after")]
+    pub stale_after_seconds: u64,
+    /// Treasury balance (SOL) below which the dashboard raises an alert
+    #[serde(default)]
+    pub low_treasury_sol: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PriceConfig {
+    /// `coingecko` or `pyth`; USD values are hidden when unset
+    #[serde(default)]
+    pub source: PriceSource,
+    /// How long a fetched price is reused
+    #[serde(default = "default_price_refresh")]
+    pub refresh_seconds: u64,
+    /// Replaces the CoinGecko price URL or the Pyth Hermes base URL
+    #[serde(default)]
+    pub url: Option<String>,
+    /// CoinGecko demo API key
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Pyth price feed ID for SOL/USD
+    #[serde(default = "default_pyth_feed_id")]
+    pub pyth_feed_id: String,
+}
+
+impl Default for PriceConfig {
+    fn default() -> Self {
+        Self {
+            source: PriceSource::default(),
+            refresh_seconds: default_price_refresh(),
+            url: None,
+            api_key: None,
+            pyth_feed_id: default_pyth_feed_id(),
+        }
+    }
+}
+
+fn default_price_refresh() -> u64 {
+    300
+}
+
+fn default_pyth_feed_id() -> String {
+    crate::price::PYTH_SOL_USD_FEED.to_string()
+}
+
+impl Default for TuiConfig {
+    fn default() -> Self {
+        Self {
+            refresh_interval_seconds: default_tui_refresh_interval(),
+            stale_after_seconds: default_tui_stale_after(),
+            low_treasury_sol: None,
+        }
+    }
+}
+
+fn default_tui_refresh_interval() -> u64 {
+    15
+}
+
+fn default_tui_stale_after() -> u64 {
+    300
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -20,6 +275,34 @@ pub struct TelegramConfig {
     pub notifications_enabled: bool,
     #[serde(default = "default_alert_threshold")]
     pub alert_threshold_sol: f64,
+    /// Per-type notification switches (errors are always sent)
+    #[serde(default = "default_notifications_enabled")]
+    pub notify_scans: bool,
+    #[serde(default = "default_notifications_enabled")]
+    pub notify_reclaims: bool,
+    #[serde(default = "default_notifications_enabled")]
+    pub notify_passive: bool,
+    #[serde(default = "default_notifications_enabled")]
+    pub notify_summaries: bool,
+    /// Hold non-critical notifications for a digest during these hours
+    #[serde(default)]
+    pub quiet_hours: Option<QuietHoursConfig>,
+    /// Seconds each user waits between runs of /scan, and of /eligible (0 none)
+    #[serde(default = "default_command_cooldown")]
+    pub command_cooldown_seconds: u64,
+    /// /scan and /eligible runs allowed at once across all users
+    #[serde(default = "default_max_concurrent_scans")]
+    pub max_concurrent_scans: usize,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct QuietHoursConfig {
+    /// Local start time, "HH:MM"
+    pub start: String,
+    /// Local end time, "HH:MM"; may be earlier than `start` to span midnight
+    pub end: String,
+    /// "local" (default), "UTC" or a fixed offset such as "-03:00"
+    pub timezone: Option<String>,
 }
 
 fn default_notifications_enabled() -> bool {
@@ -30,19 +313,80 @@ fn default_alert_threshold() -> f64 {
     0.1 // Alert for reclaims above 0.1 SOL
 }
 
+fn default_command_cooldown() -> u64 {
+    60
+}
+
+fn default_max_concurrent_scans() -> usize {
+    1
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WebhookConfig {
+    pub url: String,
+    /// Event names to deliver (e.g. "reclaim_succeeded"); empty means all
+    #[serde(default)]
+    pub events: Vec<String>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct SolanaConfig {
     pub rpc_url: String,
     pub network: Network,
     pub commitment: String,
+    /// Minimum spacing between RPC calls without a rate or provider preset
     #[serde(default = "default_rate_limit")]
     pub rate_limit_delay_ms: u64,
+    /// Provider preset for the rate limit; detected from `rpc_url` by default
+    #[serde(default)]
+    pub provider: RpcProvider,
+    /// Average RPC request rate shared by every component in the process
+    #[serde(default)]
+    pub requests_per_second: Option<f64>,
+    /// Requests allowed at once before the rate applies
+    #[serde(default)]
+    pub burst: Option<u32>,
+    /// Slow down on sustained rate-limit responses and speed back up once they stop
+    #[serde(default = "default_adaptive_rate_limit")]
+    pub adaptive_rate_limit: bool,
+    /// RPC calls one auto-service cycle may make; the rest of the cycle is
+    /// skipped once they are spent
+    #[serde(default)]
+    pub cycle_rpc_budget: Option<u64>,
+    /// Minutes the endpoint may fail health checks before the one degraded-mode
+    /// alert; the auto service skips cycles quietly until then
+    #[serde(default = "default_degraded_after_minutes")]
+    pub degraded_after_minutes: u64,
+}
+
+impl SolanaConfig {
+    /// Limiter from `requests_per_second`, else the provider preset, else
+    /// `rate_limit_delay_ms`
+    pub fn rate_limiter(&self) -> crate::utils::RateLimiter {
+        use crate::utils::RateLimiter;
+
+        let preset = self.provider.resolve(&self.rpc_url).preset();
+        let limiter = match (self.requests_per_second, preset) {
+            (Some(rps), _) => RateLimiter::new(rps, self.burst.unwrap_or(1)),
+            (None, Some((rps, burst))) => RateLimiter::new(rps, self.burst.unwrap_or(burst)),
+            (None, None) => RateLimiter::from_delay_ms(self.rate_limit_delay_ms),
+        };
+        limiter.with_adaptive(self.adaptive_rate_limit)
+    }
 }
 
 fn default_rate_limit() -> u64 {
     100
 }
 
+fn default_adaptive_rate_limit() -> bool {
+    true
+}
+
+fn default_degraded_after_minutes() -> u64 {
+    10
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub enum Network {
     Mainnet,
@@ -50,18 +394,86 @@ pub enum Network {
     Testnet,
 }
 
+impl Network {
+    /// Solana Explorer page for `path`, e.g. `address/<pubkey>` or `tx/<signature>`
+    pub fn explorer_url(&self, path: &str) -> String {
+        match self {
+            Network::Mainnet => format!("https://explorer.solana.com/{}", path),
+            Network::Devnet => format!("https://explorer.solana.com/{}?cluster=devnet", path),
+            Network::Testnet => format!("https://explorer.solana.com/{}?cluster=testnet", path),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct KoraConfig {
     pub operator_pubkey: String,
     pub treasury_wallet: String,
     #[serde(default = "default_keypair_path")]
     pub treasury_keypair_path: String,
+    /// Keypair paying reclaim transaction fees, so the close authority only
+    /// signs; the treasury keypair pays when unset
+    #[serde(default)]
+    pub fee_payer_keypair_path: Option<String>,
+    /// Signing service holding the close authority key; replaces the treasury keypair
+    #[serde(default)]
+    pub remote_signer: Option<RemoteSignerConfig>,
+    /// Additional programs whose account creations should be tracked
+    #[serde(default)]
+    pub custom_programs: Vec<CustomProgramConfig>,
+    /// Operator keys used before a rotation; scanned so their accounts stay
+    /// attributed to them, never used to sign
+    #[serde(default)]
+    pub retired_operator_pubkeys: Vec<String>,
 }
 
 fn default_keypair_path() -> String {
     "./treasury-keypair.json".to_string()
 }
 
+/// HTTP service that signs for the close authority, so its key never
+/// leaves the service
+#[derive(Debug, Deserialize, Clone)]
+pub struct RemoteSignerConfig {
+    /// Endpoint receiving `POST {"pubkey", "message"}` with a base64 message
+    /// and answering `{"signature"}` in base58
+    pub url: String,
+    /// Public key the service signs with
+    pub pubkey: String,
+    /// Sent as a bearer token with every request
+    #[serde(default)]
+    pub api_token: Option<String>,
+    #[serde(default = "default_remote_signer_timeout")]
+    pub timeout_seconds: u64,
+}
+
+fn default_remote_signer_timeout() -> u64 {
+    10
+}
+
+/// Discovery rule for a program without a built-in parser
+#[derive(Debug, Deserialize, Clone)]
+pub struct CustomProgramConfig {
+    pub program_id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// jsonParsed instruction types that create an account
+    #[serde(default = "default_creation_types")]
+    pub instruction_types: Vec<String>,
+    /// Leading instruction data bytes identifying a creation (raw instructions)
+    #[serde(default)]
+    pub discriminator: Vec<u8>,
+    /// Position of the created account in a raw instruction's account list
+    #[serde(default)]
+    pub account_index: usize,
+    #[serde(default)]
+    pub data_size: usize,
+}
+
+fn default_creation_types() -> Vec<String> {
+    vec!["create".to_string(), "initialize".to_string()]
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct ReclaimConfig {
     pub min_inactive_days: u64,
@@ -79,6 +491,325 @@ pub struct ReclaimConfig {
     pub whitelist: Vec<String>,
     #[serde(default)]
     pub blacklist: Vec<String>,
+    /// Close instruction templates for program-owned accounts
+    #[serde(default)]
+    pub close_adapters: Vec<CloseAdapterConfig>,
+    /// Kinds of accounts the bot may close
+    #[serde(default = "default_account_types")]
+    pub account_types: Vec<ReclaimableType>,
+    /// Priority fees and the per-batch cost limit
+    #[serde(default)]
+    pub fees: FeeConfig,
+    /// Grow and shrink `batch_size` and `batch_delay_ms` from observed failures and latency
+    #[serde(default)]
+    pub adaptive: AdaptiveBatchConfig,
+    /// Hours when the auto service may send reclaim transactions; any time when empty
+    #[serde(default)]
+    pub schedule: ReclaimScheduleConfig,
+    /// Stop retrying an account after this many deterministic failures (0 never)
+    #[serde(default = "default_dead_letter_after")]
+    pub dead_letter_after: u32,
+    /// Wallet receiving reclaimed rent instead of `kora.treasury_wallet`
+    #[serde(default)]
+    pub destination: Option<String>,
+    /// Discovery never pages back past this slot, e.g. the operator's first transaction
+    #[serde(default)]
+    pub scan_floor_slot: Option<u64>,
+    /// Consecutive scan failures before they are alerted as errors (0 never)
+    #[serde(default = "default_scan_failure_alert_after")]
+    pub scan_failure_alert_after: u32,
+    /// Whether eligibility looks up each account's last transaction
+    #[serde(default)]
+    pub inactivity_check: InactivityCheck,
+    /// Share of accounts looked up with `inactivity_check = "sample"` (0.0 to 1.0)
+    #[serde(default = "default_inactivity_sample_rate")]
+    pub inactivity_sample_rate: f64,
+    /// A token account owner idle this long is treated as a churned user
+    #[serde(default = "default_churn_after_days")]
+    pub churn_after_days: u64,
+    /// Days between audits of Unrecoverable accounts, which routine cycles skip (0 never)
+    #[serde(default = "default_unrecoverable_audit_days")]
+    pub unrecoverable_audit_days: u64,
+    /// Memo appended to reclaim transactions; `{account}` and `{reason}` are filled in
+    #[serde(default)]
+    pub memo: Option<String>,
+    /// Simulate and announce high-value reclaims before the auto service sends them
+    #[serde(default)]
+    pub staging: StagingConfig,
+    /// Seconds between treasury checks for passive reclaims; `scan_interval_seconds` when unset
+    #[serde(default)]
+    pub passive_check_interval_seconds: Option<u64>,
+    /// Seconds between checks of PassiveMonitoring accounts for user closes (0 never)
+    #[serde(default = "default_closure_watch_interval")]
+    pub closure_watch_interval_seconds: u64,
+    /// How treasury balance increases are matched to closed accounts
+    #[serde(default)]
+    pub passive_matching: PassiveMatchingConfig,
+}
+
+/// `reclaim.inactivity_check`: the last-activity lookup costs one
+/// `getSignaturesForAddress` per account
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum InactivityCheck {
+    /// Look up every account
+    #[default]
+    Always,
+    /// Look up an `inactivity_sample_rate` share, drawn per account each week;
+    /// the rest rely on creation age
+    Sample,
+    /// Rely on creation age only
+    Never,
+}
+
+fn default_closure_watch_interval() -> u64 {
+    300
+}
+
+fn default_inactivity_sample_rate() -> f64 {
+    0.1
+}
+
+/// One entry of `reclaim.account_types`
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub enum ReclaimableType {
+    /// `spl-token`: token accounts the operator is the close authority of
+    SplToken,
+    /// `token-2022`: the same for Token-2022 accounts
+    Token2022,
+    /// `nonce`: durable nonce accounts the operator is the nonce authority of
+    Nonce,
+    /// `close-adapters`: accounts of every program with a close adapter
+    CloseAdapters,
+    /// A program ID: accounts of that program, closed through its close adapter
+    Program(Pubkey),
+}
+
+impl TryFrom<String> for ReclaimableType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "spl-token" => Ok(ReclaimableType::SplToken),
+            "token-2022" => Ok(ReclaimableType::Token2022),
+            "nonce" => Ok(ReclaimableType::Nonce),
+            "close-adapters" => Ok(ReclaimableType::CloseAdapters),
+            other => Pubkey::from_str(other).map(ReclaimableType::Program).map_err(|_| {
+                format!(
+                    "Unknown account type '{}' (expected spl-token, token-2022, nonce, close-adapters or a program ID)",
+                    other
+                )
+            }),
+        }
+    }
+}
+
+fn default_account_types() -> Vec<ReclaimableType> {
+    vec![ReclaimableType::SplToken, ReclaimableType::Nonce, ReclaimableType::CloseAdapters]
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct PassiveMatchingConfig {
+    /// Largest difference between a balance increase and the rent it is matched to
+    #[serde(default = "default_match_tolerance")]
+    pub tolerance_lamports: u64,
+    /// Accounts closed within this many hours are candidates for a match
+    #[serde(default = "default_closed_window")]
+    pub closed_window_hours: u64,
+}
+
+impl Default for PassiveMatchingConfig {
+    fn default() -> Self {
+        Self {
+            tolerance_lamports: default_match_tolerance(),
+            closed_window_hours: default_closed_window(),
+        }
+    }
+}
+
+fn default_match_tolerance() -> u64 {
+    5_000
+}
+
+fn default_closed_window() -> u64 {
+    24
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct StagingConfig {
+    /// Accounts holding at least this much are staged; unset turns staging off
+    #[serde(default)]
+    pub threshold_sol: Option<f64>,
+    /// Minutes between the announcement and the reclaim
+    #[serde(default = "default_staging_delay")]
+    pub delay_minutes: u64,
+    /// Wait for `/approve` or `staged approve` instead of the delay
+    #[serde(default)]
+    pub require_approval: bool,
+}
+
+impl StagingConfig {
+    /// Balance from which accounts are staged, if staging is on
+    pub fn threshold_lamports(&self) -> Option<u64> {
+        self.threshold_sol.map(|sol| (sol * 1_000_000_000.0) as u64)
+    }
+}
+
+impl Default for StagingConfig {
+    fn default() -> Self {
+        Self {
+            threshold_sol: None,
+            delay_minutes: default_staging_delay(),
+            require_approval: false,
+        }
+    }
+}
+
+fn default_staging_delay() -> u64 {
+    60
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct FeeConfig {
+    /// Compute units requested per reclaim transaction when paying a priority fee
+    #[serde(default = "default_compute_unit_limit")]
+    pub compute_unit_limit: u32,
+    /// Priority fee in micro-lamports per compute unit; 0 sends no ComputeBudget instructions
+    #[serde(default)]
+    pub priority_fee_micro_lamports: u64,
+    /// Abort a batch whose estimated fees exceed this percentage of the SOL it recovers
+    #[serde(default)]
+    pub max_cost_percent: Option<f64>,
+    /// Also send each batch's cost plan to Telegram and webhooks
+    #[serde(default)]
+    pub notify_plan: bool,
+    /// Times an expired reclaim transaction is rebuilt with a fresh blockhash (0 never)
+    #[serde(default = "default_max_rebuilds")]
+    pub max_rebuilds: u32,
+    /// Priority fee increase per rebuild, in percent of the previous fee
+    #[serde(default = "default_fee_bump_percent")]
+    pub fee_bump_percent: u64,
+}
+
+impl Default for FeeConfig {
+    fn default() -> Self {
+        Self {
+            compute_unit_limit: default_compute_unit_limit(),
+            priority_fee_micro_lamports: 0,
+            max_cost_percent: None,
+            notify_plan: false,
+            max_rebuilds: default_max_rebuilds(),
+            fee_bump_percent: default_fee_bump_percent(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ReclaimScheduleConfig {
+    /// "local" (default), "UTC" or a fixed offset such as "-03:00"
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub windows: Vec<ReclaimWindowConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ReclaimWindowConfig {
+    /// Days the window starts on, e.g. "mon" or "saturday"; every day when empty
+    #[serde(default)]
+    pub days: Vec<String>,
+    /// "HH:MM"
+    pub start: String,
+    /// "HH:MM"; earlier than `start` to run past midnight, equal to it for the whole day
+    pub end: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AdaptiveBatchConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_min_batch_size")]
+    pub min_batch_size: usize,
+    #[serde(default = "default_max_batch_size")]
+    pub max_batch_size: usize,
+    #[serde(default = "default_min_batch_delay")]
+    pub min_delay_ms: u64,
+    #[serde(default = "default_max_batch_delay")]
+    pub max_delay_ms: u64,
+    /// Average time per account, sends and confirmations included, above which batches shrink
+    #[serde(default = "default_target_latency")]
+    pub target_latency_ms: u64,
+    /// Share of transient failures in a batch above which batches shrink
+    #[serde(default = "default_max_error_rate")]
+    pub max_error_rate: f64,
+}
+
+impl Default for AdaptiveBatchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_batch_size: default_min_batch_size(),
+            max_batch_size: default_max_batch_size(),
+            min_delay_ms: default_min_batch_delay(),
+            max_delay_ms: default_max_batch_delay(),
+            target_latency_ms: default_target_latency(),
+            max_error_rate: default_max_error_rate(),
+        }
+    }
+}
+
+fn default_min_batch_size() -> usize {
+    1
+}
+
+fn default_max_batch_size() -> usize {
+    50
+}
+
+fn default_min_batch_delay() -> u64 {
+    250
+}
+
+fn default_max_batch_delay() -> u64 {
+    30_000
+}
+
+fn default_target_latency() -> u64 {
+    3_000
+}
+
+fn default_max_error_rate() -> f64 {
+    0.1
+}
+
+fn default_compute_unit_limit() -> u32 {
+    50_000
+}
+
+fn default_max_rebuilds() -> u32 {
+    2
+}
+
+fn default_fee_bump_percent() -> u64 {
+    100
+}
+
+/// How to close accounts owned by a custom program
+#[derive(Debug, Deserialize, Clone)]
+pub struct CloseAdapterConfig {
+    pub program_id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Instruction data for the close instruction (e.g. an Anchor discriminator)
+    pub discriminator: Vec<u8>,
+    /// Account list in program order: "account", "destination", "authority" or a fixed pubkey
+    #[serde(default = "default_close_accounts")]
+    pub accounts: Vec<String>,
+}
+
+fn default_close_accounts() -> Vec<String> {
+    vec!["account".to_string(), "destination".to_string(), "authority".to_string()]
 }
 
 fn default_batch_size() -> usize {
@@ -93,43 +824,162 @@ fn default_scan_interval() -> u64 {
     3600
 }
 
+fn default_dead_letter_after() -> u32 {
+    3
+}
+
+fn default_scan_failure_alert_after() -> u32 {
+    3
+}
+
+fn default_churn_after_days() -> u64 {
+    365
+}
+
+fn default_unrecoverable_audit_days() -> u64 {
+    7
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct DatabaseConfig {
     pub path: String,
+    /// Check and repair the database on the first auto cycle of each UTC day
+    #[serde(default = "default_integrity_check")]
+    pub integrity_check: bool,
+    /// Profile every query and log those taking at least this long; unset disables profiling
+    #[serde(default)]
+    pub slow_query_ms: Option<u64>,
+}
+
+fn default_integrity_check() -> bool {
+    true
 }
 
 impl Config {
-    pub fn load() -> anyhow::Result<Self> {
+    /// Load `path`, with `KORA_*` environment variables taking precedence
+    pub fn load(path: &str) -> anyhow::Result<Self> {
         dotenv::dotenv().ok();
         
         let config = config::Config::builder()
-            .add_source(config::File::with_name("config"))
+            .add_source(config::File::from(std::path::Path::new(path)))
             .add_source(config::Environment::with_prefix("KORA"))
             .build()?;
         
-        Ok(config.try_deserialize()?)
+        let config: Self = config.try_deserialize()?;
+        config.validate_close_adapters()?;
+        Ok(config)
     }
     
+    /// Configuration scoped to tenant `id`
+    pub fn for_tenant(&self, id: &str) -> anyhow::Result<Self> {
+        self.validate_tenants()?;
+
+        let tenant = self.tenants.iter().find(|t| t.id == id).ok_or_else(|| {
+            let known: Vec<&str> = self.tenants.iter().map(|t| t.id.as_str()).collect();
+            match known.is_empty() {
+                true => anyhow::anyhow!("Unknown tenant '{}': no [[tenants]] are configured", id),
+                false => anyhow::anyhow!("Unknown tenant '{}' (configured: {})", id, known.join(", ")),
+            }
+        })?;
+
+        let mut config = self.clone();
+        config.kora.operator_pubkey = tenant.operator_pubkey.clone();
+        config.kora.retired_operator_pubkeys = tenant.retired_operator_pubkeys.clone();
+        config.kora.treasury_wallet = tenant.treasury_wallet.clone();
+        config.kora.treasury_keypair_path = tenant.treasury_keypair_path.clone();
+        config.kora.remote_signer = tenant.remote_signer.clone();
+        if let Some(path) = &tenant.fee_payer_keypair_path {
+            config.kora.fee_payer_keypair_path = Some(path.clone());
+        }
+        config.database.path = tenant.database_path(&self.database.path);
+        config.telegram = tenant.telegram.clone();
+        config.webhooks = tenant.webhooks.clone();
+        config.api = tenant.api.clone().unwrap_or_default();
+        if let Some(matching) = &tenant.passive_matching {
+            config.reclaim.passive_matching = matching.clone();
+        }
+        // The top-level destination belongs to the top-level treasury
+        config.reclaim.destination = None;
+        Ok(config)
+    }
+
+    /// Tenant IDs must be unique and usable in file names, and no two
+    /// tenants (or a tenant and the top level) may share a database
+    pub fn validate_tenants(&self) -> anyhow::Result<()> {
+        let mut ids = std::collections::HashSet::new();
+        let mut paths = std::collections::HashSet::from([self.database.path.clone()]);
+
+        for tenant in &self.tenants {
+            let valid = !tenant.id.is_empty()
+                && tenant.id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+            if !valid {
+                anyhow::bail!("Invalid tenant id '{}': use letters, digits, '-' and '_'", tenant.id);
+            }
+            if !ids.insert(tenant.id.as_str()) {
+                anyhow::bail!("Tenant '{}' is configured twice", tenant.id);
+            }
+            if !paths.insert(tenant.database_path(&self.database.path)) {
+                anyhow::bail!(
+                    "Tenant '{}' shares its database with another tenant or the top level",
+                    tenant.id
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Every `[[reclaim.close_adapters]]` entry must build, so the adapters
+    /// used for reclaims match the programs eligibility accepts
+    pub fn validate_close_adapters(&self) -> anyhow::Result<()> {
+        for entry in &self.reclaim.close_adapters {
+            crate::reclaim::CloseAdapter::from_config(entry)
+                .map_err(|e| anyhow::anyhow!("Invalid close adapter for {}: {}", entry.program_id, e))?;
+        }
+        Ok(())
+    }
+
     pub fn operator_pubkey(&self) -> anyhow::Result<Pubkey> {
         Pubkey::from_str(&self.kora.operator_pubkey)
             .map_err(|e| anyhow::anyhow!("Invalid operator pubkey: {}", e))
     }
     
+    /// Earlier operator keys (`kora.retired_operator_pubkeys`), scanned but never signed with
+    pub fn retired_operator_pubkeys(&self) -> anyhow::Result<Vec<Pubkey>> {
+        self.kora
+            .retired_operator_pubkeys
+            .iter()
+            .map(|key| {
+                Pubkey::from_str(key).map_err(|e| anyhow::anyhow!("Invalid retired operator pubkey {}: {}", key, e))
+            })
+            .collect()
+    }
+    
     pub fn treasury_wallet(&self) -> anyhow::Result<Pubkey> {
         Pubkey::from_str(&self.kora.treasury_wallet)
             .map_err(|e| anyhow::anyhow!("Invalid treasury wallet: {}", e))
     }
     
+    /// Where reclaimed rent goes: `reclaim.destination`, or the treasury wallet
+    pub fn reclaim_destination(&self) -> anyhow::Result<Pubkey> {
+        match &self.reclaim.destination {
+            Some(destination) => Pubkey::from_str(destination)
+                .map_err(|e| anyhow::anyhow!("Invalid reclaim destination: {}", e)),
+            None => self.treasury_wallet(),
+        }
+    }
+    
     /// Load treasury keypair from file
     pub fn load_treasury_keypair(&self) -> anyhow::Result<Keypair> {
-        let keypair_bytes = fs::read(&self.kora.treasury_keypair_path)
-            .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
-        
-        let keypair: Vec<u8> = serde_json::from_slice(&keypair_bytes)
-            .map_err(|e| anyhow::anyhow!("Failed to parse keypair JSON: {}", e))?;
-        
-        Keypair::from_bytes(&keypair)
-            .map_err(|e| anyhow::anyhow!("Invalid keypair bytes: {}", e))
+        read_keypair(&self.kora.treasury_keypair_path)
+    }
+    
+    /// Load the separate fee payer keypair, if one is configured
+    pub fn load_fee_payer_keypair(&self) -> anyhow::Result<Option<Keypair>> {
+        self.kora
+            .fee_payer_keypair_path
+            .as_deref()
+            .map(|path| read_keypair(path).map_err(|e| anyhow::anyhow!("Fee payer: {}", e)))
+            .transpose()
     }
     
     /// Get Solana commitment config
@@ -145,4 +995,164 @@ impl Config {
         
         CommitmentConfig { commitment: level }
     }
-}
\ No newline at end of file
+    
+    /// Websocket endpoint for live discovery (http -> ws, https -> wss)
+    pub fn ws_url(&self) -> String {
+        if let Some(url) = &self.discovery.ws_url {
+            return url.clone();
+        }
+        
+        let rpc_url = &self.solana.rpc_url;
+        let (scheme, rest) = if let Some(rest) = rpc_url.strip_prefix("https://") {
+            ("wss", rest)
+        } else if let Some(rest) = rpc_url.strip_prefix("http://") {
+            ("ws", rest)
+        } else {
+            return rpc_url.clone();
+        };
+        
+        // Like the Solana CLI: an explicit RPC port maps to port + 1
+        let (authority, path) = rest.split_once('/').map_or((rest, None), |(a, p)| (a, Some(p)));
+        let authority = authority
+            .rsplit_once(':')
+            .and_then(|(host, port)| Some(format!("{}:{}", host, port.parse::<u16>().ok()?.checked_add(1)?)))
+            .unwrap_or_else(|| authority.to_string());
+        
+        match path {
+            Some(path) => format!("{}://{}/{}", scheme, authority, path),
+            None => format!("{}://{}", scheme, authority),
+        }
+    }
+}
+
+/// Keypair stored as a JSON byte array, as written by `solana-keygen`
+fn read_keypair(path: &str) -> anyhow::Result<Keypair> {
+    let keypair_bytes = fs::read(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read keypair file: {}", e))?;
+    
+    let keypair: Vec<u8> = serde_json::from_slice(&keypair_bytes)
+        .map_err(|e| anyhow::anyhow!("Failed to parse keypair JSON: {}", e))?;
+    
+    Keypair::from_bytes(&keypair)
+        .map_err(|e| anyhow::anyhow!("Invalid keypair bytes: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_rpc_url(rpc_url: &str) -> Config {
+        let mut config: Config = toml::from_str(include_str!("../config.toml")).unwrap();
+        config.solana.rpc_url = rpc_url.to_string();
+        config.discovery.ws_url = None;
+        config
+    }
+
+    #[test]
+    fn test_ws_url_derived_from_rpc_url() {
+        assert_eq!(
+            with_rpc_url("https://api.devnet.solana.com").ws_url(),
+            "wss://api.devnet.solana.com"
+        );
+        assert_eq!(with_rpc_url("http://127.0.0.1:8899").ws_url(), "ws://127.0.0.1:8900");
+        assert_eq!(
+            with_rpc_url("https://rpc.example.com/?api-key=abc").ws_url(),
+            "wss://rpc.example.com/?api-key=abc"
+        );
+    }
+
+    #[test]
+    fn test_tenant_overrides_and_database_isolation() {
+        let tenant = |id: &str, database_path: Option<&str>| TenantConfig {
+            id: id.to_string(),
+            name: None,
+            operator_pubkey: Pubkey::new_unique().to_string(),
+            retired_operator_pubkeys: Vec::new(),
+            treasury_wallet: Pubkey::new_unique().to_string(),
+            treasury_keypair_path: format!("./{}-treasury.json", id),
+            fee_payer_keypair_path: None,
+            remote_signer: None,
+            database_path: database_path.map(str::to_string),
+            telegram: None,
+            webhooks: Vec::new(),
+            api: None,
+            passive_matching: None,
+        };
+
+        let mut config = with_rpc_url("https://api.devnet.solana.com");
+        config.database.path = "./data/kora_reclaim.db".to_string();
+        config.reclaim.destination = Some(Pubkey::new_unique().to_string());
+        config.kora.retired_operator_pubkeys = vec![Pubkey::new_unique().to_string()];
+        config.tenants = vec![tenant("acme", None), tenant("globex", Some("/srv/globex.db"))];
+
+        let acme = config.for_tenant("acme").unwrap();
+        assert_eq!(acme.database.path, "./data/kora_reclaim-acme.db");
+        // Rent goes to the tenant's own treasury, not the top-level destination
+        assert_eq!(acme.reclaim_destination().unwrap().to_string(), config.tenants[0].treasury_wallet);
+        assert_eq!(acme.kora.operator_pubkey, config.tenants[0].operator_pubkey);
+        // Retired keys sponsored the top level's accounts, not the tenant's
+        assert!(acme.retired_operator_pubkeys().unwrap().is_empty());
+        assert_eq!(config.retired_operator_pubkeys().unwrap().len(), 1);
+        assert!(acme.telegram.is_none() && !acme.api.enabled);
+        assert_eq!(config.for_tenant("globex").unwrap().database.path, "/srv/globex.db");
+        assert!(config.for_tenant("initech").is_err());
+
+        // Matching parameters can be tuned per treasury
+        assert_eq!(acme.reclaim.passive_matching, PassiveMatchingConfig::default());
+        config.tenants[1].passive_matching = Some(PassiveMatchingConfig { tolerance_lamports: 10_000, closed_window_hours: 48 });
+        assert_eq!(config.for_tenant("globex").unwrap().reclaim.passive_matching.closed_window_hours, 48);
+
+        config.tenants.push(tenant("initech", Some("./data/kora_reclaim.db")));
+        assert!(config.validate_tenants().is_err());
+        config.tenants[2] = tenant("acme", Some("/srv/other.db"));
+        assert!(config.validate_tenants().is_err());
+        config.tenants[2] = tenant("in/itech", None);
+        assert!(config.validate_tenants().is_err());
+
+        // Each tenant of an in-memory configuration gets its own database
+        config.database.path = crate::storage::db::MEMORY_PATH.to_string();
+        assert_eq!(config.tenants[0].database_path(&config.database.path), ":memory:acme");
+    }
+
+    #[test]
+    fn test_invalid_close_adapter_rejected() {
+        let mut config = with_rpc_url("https://api.devnet.solana.com");
+        config.reclaim.close_adapters = vec![CloseAdapterConfig {
+            program_id: Pubkey::new_unique().to_string(),
+            name: None,
+            discriminator: vec![9],
+            accounts: default_close_accounts(),
+        }];
+        assert!(config.validate_close_adapters().is_ok());
+
+        config.reclaim.close_adapters[0].accounts.push("owner".to_string());
+        assert!(config.validate_close_adapters().is_err());
+    }
+
+    #[test]
+    fn test_account_types() {
+        let config = with_rpc_url("https://api.devnet.solana.com");
+        assert_eq!(config.reclaim.account_types, default_account_types());
+
+        let program = Pubkey::new_unique();
+        let parsed: Vec<ReclaimableType> =
+            toml::from_str::<toml::Value>(&format!("types = [\"token-2022\", \"{}\"]", program))
+                .unwrap()["types"]
+                .clone()
+                .try_into()
+                .unwrap();
+        assert_eq!(parsed, vec![ReclaimableType::Token2022, ReclaimableType::Program(program)]);
+        assert!(ReclaimableType::try_from("spl-token-2022".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_inactivity_check_mode() {
+        let config = with_rpc_url("https://api.devnet.solana.com");
+        assert_eq!(config.reclaim.inactivity_check, InactivityCheck::Always);
+        assert_eq!(config.reclaim.inactivity_sample_rate, 0.1);
+
+        let parsed: InactivityCheck = toml::Value::String("sample".to_string()).try_into().unwrap();
+        assert_eq!(parsed, InactivityCheck::Sample);
+        assert!(toml::Value::String("sometimes".to_string()).try_into::<InactivityCheck>().is_err());
+    }
+}
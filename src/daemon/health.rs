@@ -0,0 +1,116 @@
+// src/daemon/health.rs - Liveness and readiness checks behind /healthz and /readyz
+
+use crate::events::delivery::{self, ChannelStatus};
+use crate::events::EventMetrics;
+use crate::solana::SolanaRpcClient;
+use crate::storage::Database;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// What the health endpoints check besides the database
+#[derive(Clone)]
+pub struct HealthChecks {
+    pub rpc_client: SolanaRpcClient,
+    /// Liveness fails once the last scan, or startup if none finished yet, is older than this
+    pub max_scan_age: Duration,
+    /// Configured notification channels, reported by `/readyz`
+    pub channels: Vec<&'static str>,
+}
+
+/// Result of one dependency check
+#[derive(Debug, Serialize)]
+pub struct Check {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Check {
+    fn from_result<T, E: std::fmt::Display>(result: Result<T, E>) -> Self {
+        match result {
+            Ok(_) => Self { ok: true, error: None },
+            Err(e) => Self {
+                ok: false,
+                error: Some(e.to_string()),
+            },
+        }
+    }
+}
+
+/// Body of `/healthz` and `/readyz`
+#[derive(Debug, Serialize)]
+pub struct HealthReport {
+    /// "ok" or "fail"; failing reports are served with 503
+    pub status: &'static str,
+    pub uptime_seconds: u64,
+    pub last_scan_at: Option<DateTime<Utc>>,
+    /// Seconds since the last scan, or since startup before the first one
+    pub scan_age_seconds: u64,
+    pub max_scan_age_seconds: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database: Option<Check>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rpc: Option<Check>,
+    /// Latest delivery outcome per channel; informational only
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub notifications: BTreeMap<&'static str, ChannelStatus>,
+}
+
+impl HealthReport {
+    pub fn is_ok(&self) -> bool {
+        self.status == "ok"
+    }
+}
+
+/// Time since the last scan, or since `started_at` before the first one
+pub fn scan_age(last_scan_at: Option<DateTime<Utc>>, started_at: Instant, now: DateTime<Utc>) -> Duration {
+    match last_scan_at {
+        Some(at) => (now - at).to_std().unwrap_or_default(),
+        None => started_at.elapsed(),
+    }
+}
+
+/// Liveness: the reclaim loop has completed a scan recently enough. A
+/// restart is the right fix for a wedged loop, so nothing external is checked.
+pub fn liveness(checks: &HealthChecks, metrics: &EventMetrics, started_at: Instant) -> HealthReport {
+    let last_scan_at = metrics.last_scan_at();
+    let age = scan_age(last_scan_at, started_at, Utc::now());
+
+    HealthReport {
+        status: if age <= checks.max_scan_age { "ok" } else { "fail" },
+        uptime_seconds: started_at.elapsed().as_secs(),
+        last_scan_at,
+        scan_age_seconds: age.as_secs(),
+        max_scan_age_seconds: checks.max_scan_age.as_secs(),
+        database: None,
+        rpc: None,
+        notifications: BTreeMap::new(),
+    }
+}
+
+/// Readiness: liveness plus a database query and an RPC round trip, with
+/// the notification channels' latest deliveries for context
+pub async fn readiness(
+    checks: &HealthChecks,
+    database: &Database,
+    metrics: &EventMetrics,
+    started_at: Instant,
+) -> HealthReport {
+    let mut report = liveness(checks, metrics, started_at);
+    let database = Check::from_result(database.ping());
+    let rpc = Check::from_result(checks.rpc_client.get_slot().await);
+
+    if !database.ok || !rpc.ok {
+        report.status = "fail";
+    }
+    report.database = Some(database);
+    report.rpc = Some(rpc);
+    report.notifications = checks
+        .channels
+        .iter()
+        .map(|channel| (*channel, delivery::channel_status(channel)))
+        .collect();
+    report
+}
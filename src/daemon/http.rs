@@ -0,0 +1,285 @@
+// src/daemon/http.rs - Read-only HTTP API served alongside the daemon
+
+use super::health::{self, HealthChecks, HealthReport};
+use super::Shutdown;
+use crate::error::ReclaimError;
+use crate::events::EventMetrics;
+use crate::reclaim::close_request::{CloseRequestBuilder, CLOSE_REQUEST_PATH};
+use crate::storage::Database;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{header, Body, Method, Request, Response, Server, StatusCode};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{info, warn};
+
+/// Handles the API reads from; shared with the rest of the daemon
+#[derive(Clone)]
+pub struct ApiState {
+    pub database: Database,
+    pub metrics: Arc<EventMetrics>,
+    pub started_at: Instant,
+    /// Solana Pay close requests; `None` unless `api.public_url` is set
+    pub close_requests: Option<CloseRequests>,
+    pub health: HealthChecks,
+}
+
+/// What the close request endpoint needs besides the database
+#[derive(Clone)]
+pub struct CloseRequests {
+    pub builder: CloseRequestBuilder,
+    pub label: String,
+    pub icon: Option<String>,
+}
+
+/// Serve `/health`, `/healthz`, `/readyz`, `/stats`, `/metrics` and close
+/// requests as JSON until shutdown
+pub async fn serve(bind: &str, state: ApiState, shutdown: Shutdown) -> crate::error::Result<()> {
+    let addr: SocketAddr = bind
+        .parse()
+        .map_err(|e| crate::error::ReclaimError::ConfigError(format!("Invalid api.bind '{}': {}", bind, e)))?;
+
+    let make_service = make_service_fn(move |_| {
+        let state = state.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let state = state.clone();
+                async move { Ok::<_, Infallible>(route(request, &state).await) }
+            }))
+        }
+    });
+
+    let server = Server::try_bind(&addr)
+        .map_err(|e| anyhow::anyhow!("Failed to bind API to {}: {}", addr, e))?
+        .serve(make_service);
+    info!("HTTP API listening on http://{}", addr);
+
+    server
+        .with_graceful_shutdown(async move { shutdown.triggered().await })
+        .await
+        .map_err(|e| anyhow::anyhow!("HTTP API failed: {}", e))?;
+
+    info!("HTTP API stopped");
+    Ok(())
+}
+
+async fn route(request: Request<Body>, state: &ApiState) -> Response<Body> {
+    if let Some(account) = request.uri().path().strip_prefix(CLOSE_REQUEST_PATH) {
+        let account = account.to_string();
+        return close_request(request, &account, state).await;
+    }
+
+    if request.method() != Method::GET {
+        return json(StatusCode::METHOD_NOT_ALLOWED, &ErrorBody { error: "method not allowed".to_string() });
+    }
+
+    match request.uri().path() {
+        "/health" => json(
+            StatusCode::OK,
+            &Health {
+                status: "ok",
+                uptime_seconds: state.started_at.elapsed().as_secs(),
+            },
+        ),
+        "/healthz" => health_report(health::liveness(&state.health, &state.metrics, state.started_at)),
+        "/readyz" => health_report(
+            health::readiness(&state.health, &state.database, &state.metrics, state.started_at).await,
+        ),
+        "/stats" => match state.database.get_stats() {
+            Ok(stats) => json(StatusCode::OK, &stats),
+            Err(e) => {
+                warn!("API failed to load stats: {}", e);
+                json(StatusCode::INTERNAL_SERVER_ERROR, &ErrorBody { error: e.to_string() })
+            }
+        },
+        "/metrics" => json(StatusCode::OK, &state.metrics.snapshot()),
+        _ => not_found(),
+    }
+}
+
+/// Solana Pay transaction request: GET describes it, POST returns the transaction
+async fn close_request(request: Request<Body>, account: &str, state: &ApiState) -> Response<Body> {
+    let Some(close_requests) = &state.close_requests else {
+        return not_found();
+    };
+    let error = |status: StatusCode, error: String| json(status, &ErrorBody { error });
+
+    let Ok(account) = Pubkey::from_str(account) else {
+        return error(StatusCode::BAD_REQUEST, format!("invalid account: {}", account));
+    };
+
+    match *request.method() {
+        // Browser wallets send a CORS preflight before the POST
+        Method::OPTIONS => Response::builder()
+            .status(StatusCode::NO_CONTENT)
+            .header(header::ACCESS_CONTROL_ALLOW_ORIGIN, "*")
+            .header(header::ACCESS_CONTROL_ALLOW_METHODS, "GET, POST, OPTIONS")
+            .header(header::ACCESS_CONTROL_ALLOW_HEADERS, "Content-Type")
+            .body(Body::empty())
+            .unwrap_or_default(),
+        Method::GET => json(
+            StatusCode::OK,
+            &CloseRequestLabel {
+                label: &close_requests.label,
+                icon: close_requests.icon.as_deref(),
+            },
+        ),
+        Method::POST => {
+            let body = match hyper::body::to_bytes(request.into_body()).await {
+                Ok(body) => body,
+                Err(e) => return error(StatusCode::BAD_REQUEST, e.to_string()),
+            };
+            let signer = match serde_json::from_slice::<CloseRequestBody>(&body)
+                .map_err(|e| e.to_string())
+                .and_then(|body| Pubkey::from_str(&body.account).map_err(|e| e.to_string()))
+            {
+                Ok(signer) => signer,
+                Err(e) => return error(StatusCode::BAD_REQUEST, format!("invalid request: {}", e)),
+            };
+
+            match close_requests.builder.transaction(&account, &signer).await {
+                Ok(transaction) => {
+                    info!("Built close request transaction for {} signed by {}", account, signer);
+                    json(
+                        StatusCode::OK,
+                        &CloseRequestTransaction {
+                            transaction: transaction.transaction,
+                            message: transaction.message,
+                        },
+                    )
+                }
+                Err(e @ (ReclaimError::Ineligible(_) | ReclaimError::AccountNotFound(_))) => {
+                    error(StatusCode::BAD_REQUEST, e.to_string())
+                }
+                Err(e) => {
+                    warn!("API failed to build close request for {}: {}", account, e);
+                    error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+                }
+            }
+        }
+        _ => error(StatusCode::METHOD_NOT_ALLOWED, "method not allowed".to_string()),
+    }
+}
+
+/// 200 for a passing report, 503 so orchestrators act on a failing one
+fn health_report(report: HealthReport) -> Response<Body> {
+    if !report.is_ok() {
+        warn!("Health check failing: {}", serde_json::to_string(&report).unwrap_or_default());
+    }
+    let status = if report.is_ok() { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE };
+    json(status, &report)
+}
+
+fn not_found() -> Response<Body> {
+    json(StatusCode::NOT_FOUND, &ErrorBody { error: "not found".to_string() })
+}
+
+#[derive(Serialize)]
+struct Health {
+    status: &'static str,
+    uptime_seconds: u64,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+#[derive(Serialize)]
+struct CloseRequestLabel<'a> {
+    label: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    icon: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+struct CloseRequestBody {
+    account: String,
+}
+
+#[derive(Serialize)]
+struct CloseRequestTransaction {
+    transaction: String,
+    message: String,
+}
+
+fn json<T: Serialize>(status: StatusCode, body: &T) -> Response<Body> {
+    let body = serde_json::to_vec(body).unwrap_or_default();
+    Response::builder()
+        .status(status)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header(header::ACCESS_CONTROL_ALLOW_ORIGIN, "*")
+        .body(Body::from(body))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(max_scan_age: std::time::Duration) -> ApiState {
+        ApiState {
+            database: Database::in_memory().unwrap(),
+            metrics: Arc::new(EventMetrics::default()),
+            started_at: Instant::now(),
+            close_requests: None,
+            health: HealthChecks {
+                // Nothing listens here, so the RPC check fails
+                rpc_client: crate::solana::SolanaRpcClient::new(
+                    "http://127.0.0.1:9",
+                    solana_sdk::commitment_config::CommitmentConfig::confirmed(),
+                    0,
+                ),
+                max_scan_age,
+                channels: vec!["telegram"],
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_routes() {
+        let state = state(std::time::Duration::from_secs(60));
+        let get = |path: &str| Request::get(path).body(Body::empty()).unwrap();
+
+        assert_eq!(route(get("/health"), &state).await.status(), StatusCode::OK);
+        assert_eq!(route(get("/stats"), &state).await.status(), StatusCode::OK);
+        assert_eq!(route(get("/metrics"), &state).await.status(), StatusCode::OK);
+        assert_eq!(route(get("/nope"), &state).await.status(), StatusCode::NOT_FOUND);
+
+        let post = Request::post("/stats").body(Body::empty()).unwrap();
+        assert_eq!(route(post, &state).await.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+        // Close requests only exist once a public URL is configured
+        let close = format!("{}{}", CLOSE_REQUEST_PATH, Pubkey::new_unique());
+        assert_eq!(route(get(&close), &state).await.status(), StatusCode::NOT_FOUND);
+    }
+
+    // The blocking RPC client needs a multi-threaded runtime
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_health_endpoints() {
+        let get = |path: &str| Request::get(path).body(Body::empty()).unwrap();
+
+        let fresh = state(std::time::Duration::from_secs(60));
+        assert_eq!(route(get("/healthz"), &fresh).await.status(), StatusCode::OK);
+        let ready = route(get("/readyz"), &fresh).await;
+        assert_eq!(ready.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body: serde_json::Value =
+            serde_json::from_slice(&hyper::body::to_bytes(ready.into_body()).await.unwrap()).unwrap();
+        assert_eq!(body["database"]["ok"], true);
+        assert_eq!(body["rpc"]["ok"], false);
+        assert!(body["notifications"]["telegram"].is_object());
+
+        // No scan within the allowed age: the loop counts as wedged
+        let wedged = state(std::time::Duration::ZERO);
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        assert_eq!(route(get("/healthz"), &wedged).await.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let now = chrono::Utc::now();
+        let age = health::scan_age(Some(now - chrono::Duration::seconds(30)), Instant::now(), now);
+        assert_eq!(age, std::time::Duration::from_secs(30));
+    }
+}
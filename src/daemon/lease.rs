@@ -0,0 +1,113 @@
+// src/daemon/lease.rs - Database lease keeping one reclaim service per database
+
+use super::ShutdownTrigger;
+use crate::error::{ReclaimError, Result};
+use crate::storage::Database;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+/// Lease shared by `auto` and `daemon`
+pub const SERVICE_LEASE: &str = "reclaim_service";
+
+/// A holder that misses heartbeats for this long is presumed dead
+const LEASE_TTL: Duration = Duration::from_secs(90);
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Proof that this process is the only reclaim service on its database
+pub struct InstanceLease {
+    database: Database,
+    holder: String,
+}
+
+impl InstanceLease {
+    /// Take the service lease, failing if another live instance holds it
+    pub fn acquire(database: &Database) -> Result<Self> {
+        let holder = holder_id();
+        let ttl = chrono::Duration::from_std(LEASE_TTL).unwrap_or_default();
+
+        if let Some(current) = database.try_acquire_lease(SERVICE_LEASE, &holder, ttl)? {
+            let seconds_ago = (chrono::Utc::now() - current.heartbeat_at).num_seconds().max(0);
+            return Err(ReclaimError::InstanceLocked(format!(
+                "reclaim service {} is using this database (last heartbeat {}s ago). \
+                 Stop it first; a crashed instance's lock expires after {}s",
+                current.holder,
+                seconds_ago,
+                LEASE_TTL.as_secs()
+            )));
+        }
+
+        info!("Acquired service lease as {}", holder);
+        Ok(Self {
+            database: database.clone(),
+            holder,
+        })
+    }
+
+    /// Renew the lease until shutdown. Losing it to another instance (after a
+    /// stall longer than the TTL) triggers shutdown rather than risk two
+    /// services reclaiming the same accounts.
+    pub fn spawn_heartbeat(&self, trigger: ShutdownTrigger) -> JoinHandle<()> {
+        let database = self.database.clone();
+        let holder = self.holder.clone();
+        let shutdown = trigger.subscribe();
+
+        tokio::spawn(async move {
+            while !shutdown.sleep(HEARTBEAT_INTERVAL).await {
+                match database.renew_lease(SERVICE_LEASE, &holder) {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        error!("Service lease was taken over by another instance, shutting down");
+                        trigger.trigger();
+                        return;
+                    }
+                    Err(e) => warn!("Failed to renew service lease: {}", e),
+                }
+            }
+        })
+    }
+
+    pub fn release(self) {
+        match self.database.release_lease(SERVICE_LEASE, &self.holder) {
+            Ok(()) => info!("Released service lease"),
+            Err(e) => warn!("Failed to release service lease: {}", e),
+        }
+    }
+}
+
+/// `pid 1234 on host`, so a lock error says where the other instance runs
+fn holder_id() -> String {
+    let host = std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())
+        .map(|h| h.trim().to_string())
+        .filter(|h| !h.is_empty())
+        .unwrap_or_else(|| "unknown host".to_string());
+    format!("pid {} on {}", std::process::id(), host)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lease_blocks_second_holder_until_expired_or_released() {
+        let database = Database::in_memory().unwrap();
+        let ttl = chrono::Duration::seconds(90);
+
+        assert!(database.try_acquire_lease(SERVICE_LEASE, "a", ttl).unwrap().is_none());
+        // Re-acquiring your own lease is fine
+        assert!(database.try_acquire_lease(SERVICE_LEASE, "a", ttl).unwrap().is_none());
+
+        let blocked = database.try_acquire_lease(SERVICE_LEASE, "b", ttl).unwrap();
+        assert_eq!(blocked.map(|h| h.holder), Some("a".to_string()));
+        assert!(!database.renew_lease(SERVICE_LEASE, "b").unwrap());
+
+        // A stale heartbeat can be taken over, after which the old holder loses it
+        assert!(database.try_acquire_lease(SERVICE_LEASE, "b", chrono::Duration::zero()).unwrap().is_none());
+        assert!(!database.renew_lease(SERVICE_LEASE, "a").unwrap());
+
+        database.release_lease(SERVICE_LEASE, "b").unwrap();
+        assert!(database.try_acquire_lease(SERVICE_LEASE, "c", ttl).unwrap().is_none());
+    }
+}
@@ -0,0 +1,104 @@
+// src/daemon/mod.rs - Coordinated shutdown for long-running services
+
+pub mod health;
+pub mod http;
+pub mod lease;
+pub mod supervisor;
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// Sending half of a shutdown signal
+#[derive(Clone)]
+pub struct ShutdownTrigger(Arc<watch::Sender<bool>>);
+
+/// Cloneable shutdown listener handed to each task
+#[derive(Clone)]
+pub struct Shutdown(watch::Receiver<bool>);
+
+pub fn shutdown_channel() -> (ShutdownTrigger, Shutdown) {
+    let (sender, receiver) = watch::channel(false);
+    (ShutdownTrigger(Arc::new(sender)), Shutdown(receiver))
+}
+
+impl ShutdownTrigger {
+    pub fn trigger(&self) {
+        let _ = self.0.send(true);
+    }
+
+    pub fn subscribe(&self) -> Shutdown {
+        Shutdown(self.0.subscribe())
+    }
+}
+
+impl Shutdown {
+    pub fn is_triggered(&self) -> bool {
+        *self.0.borrow()
+    }
+
+    /// Resolve once shutdown has been requested
+    pub async fn triggered(&self) {
+        let mut receiver = self.0.clone();
+        let _ = receiver.wait_for(|stop| *stop).await;
+    }
+
+    /// Sleep for `duration`; returns `true` if shutdown cut the sleep short
+    pub async fn sleep(&self, duration: Duration) -> bool {
+        tokio::select! {
+            _ = self.triggered() => true,
+            _ = tokio::time::sleep(duration) => false,
+        }
+    }
+}
+
+/// Trigger shutdown on Ctrl-C or SIGTERM. Tasks get to finish what they are
+/// doing; a second Ctrl-C exits immediately.
+pub fn trigger_on_signal(trigger: ShutdownTrigger) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        wait_for_signal().await;
+        info!("Shutdown requested, finishing in-flight work (Ctrl-C again to force)");
+        trigger.trigger();
+
+        if tokio::signal::ctrl_c().await.is_ok() {
+            warn!("Forced shutdown");
+            std::process::exit(130);
+        }
+    })
+}
+
+async fn wait_for_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        if let Ok(mut terminate) = signal(SignalKind::terminate()) {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = terminate.recv() => {}
+            }
+            return;
+        }
+    }
+
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sleep_is_cut_short_by_shutdown() {
+        let (trigger, shutdown) = shutdown_channel();
+        assert!(!shutdown.sleep(Duration::from_millis(1)).await);
+
+        let listener = shutdown.clone();
+        let sleeper = tokio::spawn(async move { listener.sleep(Duration::from_secs(3600)).await });
+        trigger.trigger();
+
+        assert!(sleeper.await.unwrap());
+        assert!(shutdown.is_triggered());
+    }
+}
@@ -0,0 +1,157 @@
+// src/daemon/supervisor.rs - Restarts daemon services that fail or panic
+
+use super::Shutdown;
+use crate::config::DaemonConfig;
+use crate::error::Result;
+use std::future::Future;
+use std::time::{Duration, Instant};
+use tokio::task::{JoinError, JoinHandle};
+use tracing::{error, info};
+
+/// Capped exponential backoff between restarts of one service
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RestartPolicy {
+    pub initial: Duration,
+    pub max: Duration,
+}
+
+impl RestartPolicy {
+    pub fn from_config(config: &DaemonConfig) -> Self {
+        Self {
+            initial: Duration::from_secs(config.restart_initial_backoff_seconds.max(1)),
+            max: Duration::from_secs(config.restart_max_backoff_seconds.max(1)),
+        }
+    }
+
+    /// Delay before the `consecutive`-th restart in a row, counting from 1
+    pub fn backoff(&self, consecutive: u32) -> Duration {
+        let factor = 2u32.saturating_pow(consecutive.saturating_sub(1));
+        self.initial.saturating_mul(factor).min(self.max)
+    }
+}
+
+/// A restart about to happen, for notifications and metrics
+#[derive(Debug, Clone)]
+pub struct Restart {
+    pub service: &'static str,
+    /// Restarts in a row without a healthy run in between
+    pub consecutive: u32,
+    pub error: String,
+    pub backoff: Duration,
+}
+
+fn describe(error: JoinError) -> String {
+    if !error.is_panic() {
+        return error.to_string();
+    }
+    let payload = error.into_panic();
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown cause".to_string());
+    format!("panicked: {}", message)
+}
+
+/// Run `start()` in the background and run it again after it fails or
+/// panics, until shutdown. A service that returns `Ok` has stopped for good.
+/// A run that outlasts the longest backoff counts as healthy, so the next
+/// failure starts from the initial delay again.
+pub fn supervise<S, F>(
+    name: &'static str,
+    policy: RestartPolicy,
+    shutdown: Shutdown,
+    mut start: S,
+    on_restart: impl Fn(&Restart) + Send + 'static,
+) -> JoinHandle<()>
+where
+    S: FnMut() -> F + Send + 'static,
+    F: Future<Output = Result<()>> + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut consecutive = 0;
+        loop {
+            let started = Instant::now();
+            // Its own task, so a panic ends the run instead of the supervisor
+            let error = match tokio::spawn(start()).await {
+                Ok(Ok(())) => {
+                    info!("{} stopped", name);
+                    return;
+                }
+                Ok(Err(e)) => e.to_string(),
+                Err(e) => describe(e),
+            };
+            if shutdown.is_triggered() {
+                error!("{} stopped with error: {}", name, error);
+                return;
+            }
+
+            if started.elapsed() >= policy.max {
+                consecutive = 0;
+            }
+            consecutive += 1;
+            let restart = Restart { service: name, consecutive, error, backoff: policy.backoff(consecutive) };
+            error!(
+                "{} failed ({} in a row), restarting in {}s: {}",
+                name,
+                consecutive,
+                restart.backoff.as_secs(),
+                restart.error
+            );
+            on_restart(&restart);
+
+            if shutdown.sleep(restart.backoff).await {
+                return;
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_cap() {
+        let policy = RestartPolicy { initial: Duration::from_secs(5), max: Duration::from_secs(60) };
+        let delays: Vec<u64> = (1..=6).map(|n| policy.backoff(n).as_secs()).collect();
+        assert_eq!(delays, vec![5, 10, 20, 40, 60, 60]);
+        assert_eq!(policy.backoff(100), Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn test_restarts_after_panic_and_error_until_success() {
+        let policy = RestartPolicy { initial: Duration::from_millis(1), max: Duration::from_millis(50) };
+        let (_trigger, shutdown) = super::super::shutdown_channel();
+        let runs = Arc::new(AtomicU32::new(0));
+        let restarts = Arc::new(Mutex::new(Vec::new()));
+
+        let counter = runs.clone();
+        let seen = restarts.clone();
+        let handle = supervise(
+            "test service",
+            policy,
+            shutdown,
+            move || {
+                let run = counter.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    match run {
+                        0 => panic!("dispatcher crashed"),
+                        1 => Err(crate::error::ReclaimError::ConfigError("bad".to_string())),
+                        _ => Ok(()),
+                    }
+                }
+            },
+            move |restart| seen.lock().unwrap().push((restart.consecutive, restart.error.clone())),
+        );
+        handle.await.unwrap();
+
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+        let restarts = restarts.lock().unwrap();
+        assert_eq!(restarts[0], (1, "panicked: dispatcher crashed".to_string()));
+        assert_eq!(restarts[1].0, 2);
+        assert!(restarts[1].1.contains("bad"));
+    }
+}
@@ -1,21 +1,66 @@
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_request::{RpcError, RpcResponseErrorData},
+};
+use solana_sdk::transaction::TransactionError;
 use thiserror::Error;
 
+/// JSON-RPC codes for node-side conditions that clear up on their own:
+/// rate limited, node unhealthy or behind, slot skipped or not yet available
+const TRANSIENT_RPC_CODES: [i64; 6] = [-32429, -32005, -32004, -32007, -32009, -32014];
+
 #[derive(Error, Debug)]
 pub enum ReclaimError {
+    /// Network failures, timeouts, rate limits and lagging nodes; the same
+    /// request is expected to succeed later
+    #[error("Solana RPC error: {0}")]
+    RpcTransient(ClientError),
+    
+    /// Requests the node rejected outright, such as invalid params or a
+    /// failed simulation
     #[error("Solana RPC error: {0}")]
-    SolanaRpc(#[from] solana_client::client_error::ClientError),
+    RpcFatal(ClientError),
     
     #[error("Database error: {0}")]
-    Database(#[from] rusqlite::Error),
+    DbError(#[from] rusqlite::Error),
     
     #[error("Account not found: {0}")]
     AccountNotFound(String),
+
+    /// A record other than an account, such as a passive reclaim, does not exist
+    #[error("Not found: {0}")]
+    NotFound(String),
     
     #[error("Account not eligible for reclaim: {0}")]
-    NotEligible(String),
+    Ineligible(String),
+    
+    /// Account state that no retry can fix, such as a frozen account or one
+    /// owned by an unexpected program
+    #[error("Account cannot be reclaimed: {0}")]
+    Unreclaimable(String),
+    
+    /// The operator is neither the close authority nor the owner
+    #[error("Authority mismatch: {0}")]
+    AuthorityMismatch(String),
     
     #[error("Invalid configuration: {0}")]
-    Config(String),
+    ConfigError(String),
+    
+    #[error("Another instance is running: {0}")]
+    InstanceLocked(String),
+    
+    /// The operator cancelled a scan or batch before it finished
+    #[error("Operation cancelled")]
+    Cancelled,
+
+    /// A guarded status change found the account in an unexpected state,
+    /// usually because another process changed it first
+    #[error("Status conflict: {0}")]
+    StatusConflict(String),
+
+    /// A `--fail-if-*` monitoring threshold was exceeded
+    #[error("Threshold breached: {0}")]
+    ThresholdBreached(String),
     
     #[error("Transaction failed: {0}")]
     TransactionFailed(String),
@@ -39,4 +84,126 @@ pub enum ReclaimError {
     Other(#[from] anyhow::Error),
 }
 
-pub type Result<T> = std::result::Result<T, ReclaimError>;
\ No newline at end of file
+impl From<ClientError> for ReclaimError {
+    fn from(error: ClientError) -> Self {
+        if is_transient(&error) {
+            ReclaimError::RpcTransient(error)
+        } else {
+            ReclaimError::RpcFatal(error)
+        }
+    }
+}
+
+impl ReclaimError {
+    /// Whether retrying the same reclaim would fail the same way
+    pub fn is_deterministic(&self) -> bool {
+        matches!(self, ReclaimError::Unreclaimable(_) | ReclaimError::AuthorityMismatch(_))
+    }
+    
+    /// Whether the same operation may succeed if simply tried again later
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ReclaimError::RpcTransient(_) | ReclaimError::InstanceLocked(_) | ReclaimError::IoError(_) => true,
+            ReclaimError::DbError(rusqlite::Error::SqliteFailure(e, _)) => matches!(
+                e.code,
+                rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked
+            ),
+            _ => false,
+        }
+    }
+    
+    /// Whether a transaction failed because its blockhash expired, so only
+    /// a rebuilt transaction with a fresh blockhash can land
+    pub fn is_blockhash_expired(&self) -> bool {
+        let (ReclaimError::RpcTransient(error) | ReclaimError::RpcFatal(error)) = self else {
+            return false;
+        };
+        match error.kind() {
+            ClientErrorKind::TransactionError(TransactionError::BlockhashNotFound) => true,
+            ClientErrorKind::RpcError(RpcError::RpcResponseError {
+                data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+                ..
+            }) => result.err == Some(TransactionError::BlockhashNotFound),
+            // Confirmation gave up once the blockhash passed its last valid block height
+            ClientErrorKind::RpcError(RpcError::ForUser(message)) => {
+                let message = message.to_lowercase();
+                message.contains("unable to confirm transaction") || message.contains("block height exceeded")
+            }
+            _ => false,
+        }
+    }
+}
+
+fn is_transient(error: &ClientError) -> bool {
+    match error.kind() {
+        ClientErrorKind::Io(_) => true,
+        ClientErrorKind::Reqwest(e) => {
+            e.is_timeout()
+                || e.is_connect()
+                || e.status().is_some_and(|s| s.as_u16() == 429 || s.is_server_error())
+        }
+        ClientErrorKind::RpcError(RpcError::RpcRequestError(_)) => true,
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, data, .. }) => {
+            TRANSIENT_RPC_CODES.contains(code)
+                || matches!(data, RpcResponseErrorData::NodeUnhealthy { .. })
+                || matches!(
+                    data,
+                    RpcResponseErrorData::SendTransactionPreflightFailure(result)
+                        if result.err == Some(TransactionError::BlockhashNotFound)
+                )
+        }
+        ClientErrorKind::TransactionError(TransactionError::BlockhashNotFound) => true,
+        _ => false,
+    }
+}
+
+pub type Result<T> = std::result::Result<T, ReclaimError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rpc_response_error(code: i64) -> ReclaimError {
+        ClientError::from(RpcError::RpcResponseError {
+            code,
+            message: "error".to_string(),
+            data: RpcResponseErrorData::Empty,
+        })
+        .into()
+    }
+
+    #[test]
+    fn test_retryability_classification() {
+        assert!(matches!(rpc_response_error(-32429), ReclaimError::RpcTransient(_)));
+        assert!(rpc_response_error(-32005).is_retryable());
+        assert!(matches!(rpc_response_error(-32602), ReclaimError::RpcFatal(_)));
+
+        let timeout = ClientError::from(std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out"));
+        assert!(ReclaimError::from(timeout).is_retryable());
+        let expired = ClientError::from(TransactionError::BlockhashNotFound);
+        assert!(ReclaimError::from(expired).is_retryable());
+        let rejected = ClientError::from(TransactionError::InsufficientFundsForFee);
+        assert!(!ReclaimError::from(rejected).is_retryable());
+
+        let busy = rusqlite::Error::SqliteFailure(rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY), None);
+        assert!(ReclaimError::from(busy).is_retryable());
+        assert!(!ReclaimError::Ineligible("has tokens".to_string()).is_retryable());
+        assert!(!ReclaimError::Cancelled.is_retryable());
+        assert!(!ReclaimError::AuthorityMismatch("not the owner".to_string()).is_retryable());
+        assert!(ReclaimError::AuthorityMismatch("not the owner".to_string()).is_deterministic());
+    }
+
+    #[test]
+    fn test_blockhash_expiry_detection() {
+        let expired = ClientError::from(TransactionError::BlockhashNotFound);
+        assert!(ReclaimError::from(expired).is_blockhash_expired());
+        let unconfirmed = ClientError::from(RpcError::ForUser(
+            "unable to confirm transaction. This can happen in situations such as transaction expiration".to_string(),
+        ));
+        assert!(ReclaimError::from(unconfirmed).is_blockhash_expired());
+
+        let rejected = ClientError::from(TransactionError::InsufficientFundsForFee);
+        assert!(!ReclaimError::from(rejected).is_blockhash_expired());
+        assert!(!rpc_response_error(-32429).is_blockhash_expired());
+    }
+}
@@ -0,0 +1,45 @@
+// src/events/delivery.rs - Latest outcome of each notification channel's deliveries
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// Deliveries by channel name ("telegram", "webhook") in this process
+static CHANNELS: Mutex<BTreeMap<&'static str, ChannelStatus>> = Mutex::new(BTreeMap::new());
+
+/// When a channel last delivered and last failed
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct ChannelStatus {
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub last_error_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+impl ChannelStatus {
+    /// The most recent delivery attempt failed
+    pub fn is_failing(&self) -> bool {
+        match (self.last_error_at, self.last_success_at) {
+            (Some(failed), Some(succeeded)) => failed > succeeded,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+}
+
+pub fn record_delivery(channel: &'static str, result: Result<(), String>) {
+    let mut channels = CHANNELS.lock().unwrap();
+    let status = channels.entry(channel).or_default();
+    match result {
+        Ok(()) => status.last_success_at = Some(Utc::now()),
+        Err(error) => {
+            status.last_error_at = Some(Utc::now());
+            status.last_error = Some(error);
+        }
+    }
+}
+
+/// Status of `channel`; default if nothing was delivered on it yet
+pub fn channel_status(channel: &str) -> ChannelStatus {
+    CHANNELS.lock().unwrap().get(channel).cloned().unwrap_or_default()
+}
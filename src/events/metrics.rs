@@ -0,0 +1,149 @@
+// src/events/metrics.rs - Counters maintained from the event bus
+
+use super::{next_event, ReclaimEvent};
+use std::fmt;
+use chrono::{DateTime, Utc};
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// Running totals of pipeline events since process start
+#[derive(Debug, Default)]
+pub struct EventMetrics {
+    scans: AtomicU64,
+    accounts_eligible: AtomicU64,
+    reclaims_succeeded: AtomicU64,
+    reclaims_failed: AtomicU64,
+    lamports_reclaimed: AtomicU64,
+    passive_reclaims: AtomicU64,
+    passive_lamports: AtomicU64,
+    errors: AtomicU64,
+    service_restarts: AtomicU64,
+    /// Unix time of the latest completed scan; 0 before the first
+    last_scan_at: AtomicI64,
+    /// RPC calls made by the latest finished auto-service cycle
+    last_cycle_rpc_calls: AtomicU64,
+}
+
+/// Point-in-time copy of [`EventMetrics`]
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct MetricsSnapshot {
+    pub scans: u64,
+    pub accounts_eligible: u64,
+    pub reclaims_succeeded: u64,
+    pub reclaims_failed: u64,
+    pub lamports_reclaimed: u64,
+    pub passive_reclaims: u64,
+    pub passive_lamports: u64,
+    pub errors: u64,
+    /// Daemon service restarts after a failure or panic
+    pub service_restarts: u64,
+    /// RPC rate-limit responses across all clients in the process
+    pub rpc_rate_limited: u64,
+    /// RPC requests across all clients in the process, by method
+    pub rpc_calls: std::collections::BTreeMap<String, u64>,
+    /// RPC requests made by the latest finished auto-service cycle
+    pub last_cycle_rpc_calls: u64,
+    /// Query latencies by statement when `database.slow_query_ms` is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub db_queries: Option<Vec<crate::storage::profile::QueryProfile>>,
+}
+
+impl EventMetrics {
+    pub fn record(&self, event: &ReclaimEvent) {
+        let add = |counter: &AtomicU64, n: u64| {
+            counter.fetch_add(n, Ordering::Relaxed);
+        };
+
+        match event {
+            ReclaimEvent::ScanCompleted { .. } => {
+                add(&self.scans, 1);
+                self.last_scan_at.store(Utc::now().timestamp(), Ordering::Relaxed);
+            }
+            ReclaimEvent::AccountEligible { .. } => add(&self.accounts_eligible, 1),
+            ReclaimEvent::ReclaimSucceeded { amount, .. } => {
+                add(&self.reclaims_succeeded, 1);
+                add(&self.lamports_reclaimed, *amount);
+            }
+            ReclaimEvent::ReclaimFailed { .. } => add(&self.reclaims_failed, 1),
+            ReclaimEvent::PassiveReclaimDetected { amount, .. } => {
+                add(&self.passive_reclaims, 1);
+                add(&self.passive_lamports, *amount);
+            }
+            ReclaimEvent::Error { .. } | ReclaimEvent::ScanFailed { .. } | ReclaimEvent::RpcDegraded { .. } => {
+                add(&self.errors, 1)
+            }
+            ReclaimEvent::ServiceRestarted { .. } => {
+                add(&self.errors, 1);
+                add(&self.service_restarts, 1);
+            }
+            ReclaimEvent::CreditMismatch { .. }
+            | ReclaimEvent::BatchPlanned { .. }
+            | ReclaimEvent::PassiveAccountClosed { .. }
+            | ReclaimEvent::ReclaimStaged { .. }
+            | ReclaimEvent::BatchCompleted { .. }
+            | ReclaimEvent::RpcRecovered { .. }
+            | ReclaimEvent::DailySummary { .. } => {}
+        }
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let get = |counter: &AtomicU64| counter.load(Ordering::Relaxed);
+        MetricsSnapshot {
+            scans: get(&self.scans),
+            accounts_eligible: get(&self.accounts_eligible),
+            reclaims_succeeded: get(&self.reclaims_succeeded),
+            reclaims_failed: get(&self.reclaims_failed),
+            lamports_reclaimed: get(&self.lamports_reclaimed),
+            passive_reclaims: get(&self.passive_reclaims),
+            passive_lamports: get(&self.passive_lamports),
+            errors: get(&self.errors),
+            service_restarts: get(&self.service_restarts),
+            rpc_rate_limited: crate::solana::client::rate_limited_count(),
+            rpc_calls: crate::solana::budget::call_counts(),
+            last_cycle_rpc_calls: get(&self.last_cycle_rpc_calls),
+            db_queries: crate::storage::profile::snapshot(),
+        }
+    }
+
+    /// Record the RPC calls an auto-service cycle made
+    pub fn record_cycle_rpc_calls(&self, calls: u64) {
+        self.last_cycle_rpc_calls.store(calls, Ordering::Relaxed);
+    }
+
+    /// When the latest scan completed, if any has since process start
+    pub fn last_scan_at(&self) -> Option<DateTime<Utc>> {
+        match self.last_scan_at.load(Ordering::Relaxed) {
+            0 => None,
+            timestamp => DateTime::from_timestamp(timestamp, 0),
+        }
+    }
+
+    /// Count events until the bus closes
+    pub async fn run(self: Arc<Self>, mut rx: broadcast::Receiver<ReclaimEvent>) {
+        while let Some(event) = next_event(&mut rx, "metrics").await {
+            self.record(&event);
+        }
+    }
+}
+
+impl fmt::Display for MetricsSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sol = crate::solana::rent::RentCalculator::lamports_to_sol;
+        write!(
+            f,
+            "scans={} eligible={} reclaimed={} ({:.9} SOL) failed={} passive={} ({:.9} SOL) errors={} restarts={} rpc_calls={} rpc_429={}",
+            self.scans,
+            self.accounts_eligible,
+            self.reclaims_succeeded,
+            sol(self.lamports_reclaimed),
+            self.reclaims_failed,
+            self.passive_reclaims,
+            sol(self.passive_lamports),
+            self.errors,
+            self.service_restarts,
+            self.rpc_calls.values().sum::<u64>(),
+            self.rpc_rate_limited
+        )
+    }
+}
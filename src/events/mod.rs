@@ -0,0 +1,339 @@
+// src/events/mod.rs - In-process event bus between the pipeline and its observers
+
+pub mod delivery;
+pub mod metrics;
+pub mod webhook;
+
+pub use metrics::EventMetrics;
+pub use webhook::WebhookNotifier;
+
+use crate::config::Config;
+use crate::storage::models::DailyActivity;
+use serde::Serialize;
+use std::fmt;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::debug;
+
+/// Events buffered per subscriber before the slowest one starts lagging
+const EVENT_BUS_CAPACITY: usize = 256;
+
+/// Something the scan/eligibility/reclaim pipeline did
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ReclaimEvent {
+    ScanCompleted {
+        total: usize,
+        eligible: usize,
+    },
+    AccountEligible {
+        pubkey: String,
+    },
+    ReclaimSucceeded {
+        pubkey: String,
+        amount: u64,
+        signature: String,
+        /// Part of a batch, which gets its own summary notification
+        batched: bool,
+    },
+    ReclaimFailed {
+        pubkey: String,
+        error: String,
+        /// Transient failure, e.g. an RPC timeout; the next run may succeed
+        retryable: bool,
+    },
+    /// A confirmed reclaim credited the treasury a different amount than expected
+    CreditMismatch {
+        pubkey: String,
+        signature: String,
+        expected: u64,
+        credited: i64,
+    },
+    /// Fee estimate published before a batch runs
+    BatchPlanned {
+        transactions: usize,
+        estimated_cost: u64,
+        expected_recovery: u64,
+        /// The estimate broke `reclaim.fees.max_cost_percent`, so nothing is sent
+        aborted: bool,
+    },
+    BatchCompleted {
+        successful: usize,
+        failed: usize,
+        total_reclaimed: u64,
+    },
+    /// A PassiveMonitoring account was closed by its user, returning its rent
+    PassiveAccountClosed {
+        pubkey: String,
+        amount: u64,
+    },
+    PassiveReclaimDetected {
+        id: Option<i64>,
+        amount: u64,
+        accounts: Vec<String>,
+        confidence: String,
+    },
+    /// A high-value reclaim was simulated and waits for `reclaim.staging`
+    ReclaimStaged {
+        pubkey: String,
+        amount: u64,
+        /// With `requires_approval` the reclaim also waits for an approval
+        execute_after: chrono::DateTime<chrono::Utc>,
+        requires_approval: bool,
+        units_consumed: Option<u64>,
+        /// Why the simulated transaction failed, if it did
+        simulation_error: Option<String>,
+    },
+    /// Account discovery failed in the auto service
+    ScanFailed {
+        error: String,
+        consecutive_failures: u32,
+        /// The streak reached `reclaim.scan_failure_alert_after`
+        escalated: bool,
+    },
+    /// The RPC endpoint has failed health checks for
+    /// `solana.degraded_after_minutes`; cycles are skipped until it recovers
+    RpcDegraded {
+        error: String,
+        down_minutes: i64,
+    },
+    /// The endpoint passed a health check again after an `RpcDegraded`
+    RpcRecovered {
+        down_minutes: i64,
+    },
+    /// A daemon service failed or panicked and is restarted after `backoff_seconds`
+    ServiceRestarted {
+        service: String,
+        error: String,
+        /// Restarts in a row without a healthy run in between
+        consecutive: u32,
+        backoff_seconds: u64,
+    },
+    DailySummary {
+        /// The last 24 hours
+        activity: DailyActivity,
+        /// The 24 hours before that, for day-over-day changes
+        previous: DailyActivity,
+        /// Rent still locked in active accounts
+        locked_lamports: u64,
+        /// Change in locked rent since the previous day's snapshot, if any
+        locked_change: Option<i64>,
+        /// SOL/USD price, when a price source is configured
+        sol_usd: Option<f64>,
+    },
+    Error {
+        message: String,
+    },
+}
+
+impl ReclaimEvent {
+    /// Stable name used in webhook payloads and filters
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ScanCompleted { .. } => "scan_completed",
+            Self::AccountEligible { .. } => "account_eligible",
+            Self::ReclaimSucceeded { .. } => "reclaim_succeeded",
+            Self::ReclaimFailed { .. } => "reclaim_failed",
+            Self::CreditMismatch { .. } => "credit_mismatch",
+            Self::BatchPlanned { .. } => "batch_planned",
+            Self::BatchCompleted { .. } => "batch_completed",
+            Self::PassiveAccountClosed { .. } => "passive_account_closed",
+            Self::PassiveReclaimDetected { .. } => "passive_reclaim_detected",
+            Self::ScanFailed { .. } => "scan_failed",
+            Self::ReclaimStaged { .. } => "reclaim_staged",
+            Self::RpcDegraded { .. } => "rpc_degraded",
+            Self::RpcRecovered { .. } => "rpc_recovered",
+            Self::ServiceRestarted { .. } => "service_restarted",
+            Self::DailySummary { .. } => "daily_summary",
+            Self::Error { .. } => "error",
+        }
+    }
+}
+
+impl fmt::Display for ReclaimEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sol = crate::solana::rent::RentCalculator::lamports_to_sol;
+        match self {
+            Self::ScanCompleted { total, eligible } => {
+                write!(f, "Scan complete: {} accounts, {} eligible", total, eligible)
+            }
+            Self::AccountEligible { pubkey } => write!(f, "Eligible: {}", pubkey),
+            Self::ReclaimSucceeded { pubkey, amount, .. } => {
+                write!(f, "✓ Reclaimed {:.9} SOL from {}", sol(*amount), pubkey)
+            }
+            Self::ReclaimFailed { pubkey, error, .. } => {
+                write!(f, "✗ Reclaim failed for {}: {}", pubkey, error)
+            }
+            Self::CreditMismatch { pubkey, expected, credited, .. } => write!(
+                f,
+                "⚠ Treasury credited {} lamports for {}, expected {}",
+                credited, pubkey, expected
+            ),
+            Self::BatchPlanned { transactions, estimated_cost, expected_recovery, aborted } => write!(
+                f,
+                "Batch planned: {} transactions, {:.9} SOL fees for {:.9} SOL{}",
+                transactions,
+                sol(*estimated_cost),
+                sol(*expected_recovery),
+                if *aborted { " (aborted)" } else { "" }
+            ),
+            Self::BatchCompleted { successful, failed, total_reclaimed } => write!(
+                f,
+                "Batch complete: {} succeeded, {} failed, {:.9} SOL",
+                successful,
+                failed,
+                sol(*total_reclaimed)
+            ),
+            Self::PassiveAccountClosed { pubkey, amount } => write!(
+                f,
+                "User closed {}, {:.9} SOL returned",
+                pubkey,
+                sol(*amount)
+            ),
+            Self::PassiveReclaimDetected { amount, confidence, .. } => write!(
+                f,
+                "Passive reclaim detected: {:.9} SOL ({} confidence)",
+                sol(*amount),
+                confidence
+            ),
+            Self::ScanFailed { error, consecutive_failures, .. } => write!(
+                f,
+                "Scan failed ({} in a row): {}",
+                consecutive_failures, error
+            ),
+            Self::ReclaimStaged { pubkey, amount, execute_after, requires_approval, simulation_error, .. } => write!(
+                f,
+                "Staged {:.9} SOL from {}: {}{}",
+                sol(*amount),
+                pubkey,
+                if *requires_approval { "awaiting approval".to_string() } else { format!("runs after {}", crate::utils::format_timestamp(execute_after)) },
+                simulation_error.as_ref().map_or(String::new(), |error| format!(" (simulation failed: {})", error))
+            ),
+            Self::RpcDegraded { error, down_minutes } => write!(
+                f,
+                "RPC endpoint down for {} minutes, cycles paused: {}",
+                down_minutes, error
+            ),
+            Self::RpcRecovered { down_minutes } => {
+                write!(f, "RPC endpoint recovered after {} minutes", down_minutes)
+            }
+            Self::ServiceRestarted { service, error, consecutive, backoff_seconds } => write!(
+                f,
+                "{} failed ({} in a row), restarting in {}s: {}",
+                service, consecutive, backoff_seconds, error
+            ),
+            Self::DailySummary { activity, locked_lamports, .. } => write!(
+                f,
+                "Daily summary: {} operations, {:.9} SOL net after {:.9} SOL fees ({:.9} SOL still locked)",
+                activity.operations,
+                activity.net_lamports() as f64 / 1_000_000_000.0,
+                sol(activity.fee_lamports),
+                sol(*locked_lamports)
+            ),
+            Self::Error { message } => write!(f, "Error: {}", message),
+        }
+    }
+}
+
+/// Cloneable publishing handle; every subscriber sees every event
+#[derive(Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<ReclaimEvent>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_BUS_CAPACITY);
+        Self { sender }
+    }
+
+    /// Publish an event; having no subscribers is not an error
+    pub fn publish(&self, event: ReclaimEvent) {
+        debug!("Event: {}", event.name());
+        let _ = self.sender.send(event);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ReclaimEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Start the configured notification subscribers (Telegram, webhooks)
+    /// and the metrics counter
+    pub fn spawn_subscribers(&self, config: &Config, metrics: Arc<EventMetrics>) -> Vec<JoinHandle<()>> {
+        let mut handles = vec![
+            tokio::spawn(metrics.run(self.subscribe())),
+            tokio::spawn(crate::telegram::auto_notify::run_subscriber(
+                config.clone(),
+                self.subscribe(),
+            )),
+        ];
+
+        if let Some(webhooks) = WebhookNotifier::new(config) {
+            handles.push(tokio::spawn(webhooks.run(self.subscribe())));
+        }
+
+        handles
+    }
+
+    /// Close the bus and wait for subscribers to deliver what was published.
+    ///
+    /// One-shot commands call this before exiting so notifications aren't
+    /// lost; every clone of the bus must be dropped for it to return.
+    pub async fn finish(self, handles: Vec<JoinHandle<()>>) {
+        drop(self);
+        for handle in handles {
+            let _ = handle.await;
+        }
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Receive the next event, skipping over any the subscriber lagged behind on.
+/// Returns `None` once the bus is closed.
+pub async fn next_event(
+    rx: &mut broadcast::Receiver<ReclaimEvent>,
+    subscriber: &str,
+) -> Option<ReclaimEvent> {
+    loop {
+        match rx.recv().await {
+            Ok(event) => return Some(event),
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing::warn!("{} subscriber lagged, {} events dropped", subscriber, skipped);
+            }
+            Err(broadcast::error::RecvError::Closed) => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_subscribers_drain_before_close() {
+        let bus = EventBus::new();
+        let mut rx = bus.subscribe();
+
+        bus.publish(ReclaimEvent::ScanCompleted { total: 3, eligible: 1 });
+        bus.publish(ReclaimEvent::Error { message: "boom".to_string() });
+        drop(bus);
+
+        assert_eq!(next_event(&mut rx, "test").await.unwrap().name(), "scan_completed");
+        assert_eq!(next_event(&mut rx, "test").await.unwrap().name(), "error");
+        assert!(next_event(&mut rx, "test").await.is_none());
+    }
+
+    #[test]
+    fn test_event_serializes_with_tag() {
+        let event = ReclaimEvent::BatchCompleted { successful: 2, failed: 0, total_reclaimed: 10 };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["event"], event.name());
+        assert_eq!(json["successful"], 2);
+    }
+}
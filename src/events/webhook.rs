@@ -0,0 +1,63 @@
+// src/events/webhook.rs - POST pipeline events to configured HTTP endpoints
+
+use super::{next_event, ReclaimEvent};
+use crate::config::{Config, WebhookConfig};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    webhooks: Vec<WebhookConfig>,
+}
+
+impl WebhookNotifier {
+    /// `None` when no webhooks are configured
+    pub fn new(config: &Config) -> Option<Self> {
+        if config.webhooks.is_empty() {
+            return None;
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .ok()?;
+
+        info!("Webhook notifier initialized for {} endpoints", config.webhooks.len());
+
+        Some(Self {
+            client,
+            webhooks: config.webhooks.clone(),
+        })
+    }
+
+    async fn deliver(&self, event: &ReclaimEvent) {
+        for webhook in &self.webhooks {
+            if !webhook.events.is_empty() && !webhook.events.iter().any(|e| e == event.name()) {
+                continue;
+            }
+
+            let result = self.client
+                .post(&webhook.url)
+                .json(event)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+
+            match result {
+                Ok(_) => super::delivery::record_delivery("webhook", Ok(())),
+                Err(e) => {
+                    warn!("Webhook delivery to {} failed: {}", webhook.url, e);
+                    super::delivery::record_delivery("webhook", Err(e.without_url().to_string()));
+                }
+            }
+        }
+    }
+
+    /// Deliver events until the bus closes
+    pub async fn run(self, mut rx: broadcast::Receiver<ReclaimEvent>) {
+        while let Some(event) = next_event(&mut rx, "webhook").await {
+            self.deliver(&event).await;
+        }
+    }
+}
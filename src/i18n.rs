@@ -0,0 +1,668 @@
+// src/i18n.rs - Per-locale string tables for Telegram and CLI output
+//
+// Strings are looked up by `Text` key in the process-wide language, which is
+// set once from `language` in the config. Placeholders are positional `{}`.
+
+use serde::Deserialize;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static CURRENT: AtomicU8 = AtomicU8::new(Language::En as u8);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    En,
+    Es,
+    Pt,
+}
+
+impl Language {
+    pub const ALL: [Language; 3] = [Language::En, Language::Es, Language::Pt];
+
+    /// Language used by [`t`] and [`tf`]
+    pub fn current() -> Self {
+        match CURRENT.load(Ordering::Relaxed) {
+            1 => Language::Es,
+            2 => Language::Pt,
+            _ => Language::En,
+        }
+    }
+
+    pub fn set_current(self) {
+        CURRENT.store(self as u8, Ordering::Relaxed);
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::Es => "es",
+            Language::Pt => "pt",
+        }
+    }
+
+    pub fn text(self, key: Text) -> &'static str {
+        key.table()[self as usize]
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Language::ALL
+            .into_iter()
+            .find(|language| language.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| format!("Unsupported language: {} (expected en, es or pt)", s))
+    }
+}
+
+/// Text for `key` in the current language
+pub fn t(key: Text) -> &'static str {
+    Language::current().text(key)
+}
+
+/// Text for `key` in the current language with `{}` placeholders filled in order
+pub fn tf(key: Text, args: &[&dyn Display]) -> String {
+    fill(t(key), args)
+}
+
+fn fill(template: &str, args: &[&dyn Display]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut rest = template;
+
+    while let Some(pos) = rest.find("{}") {
+        out.push_str(&rest[..pos]);
+        match args.next() {
+            Some(arg) => out.push_str(&arg.to_string()),
+            None => out.push_str("{}"),
+        }
+        rest = &rest[pos + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+macro_rules! texts {
+    ($($key:ident => [$en:expr, $es:expr, $pt:expr $(,)?],)*) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Text {
+            $($key,)*
+        }
+
+        impl Text {
+            pub const ALL: &'static [Text] = &[$(Text::$key,)*];
+
+            /// Translations in [`Language`] order
+            fn table(self) -> [&'static str; 3] {
+                match self {
+                    $(Text::$key => [$en, $es, $pt],)*
+                }
+            }
+        }
+    };
+}
+
+texts! {
+    // Notification titles
+    PassiveReclaimDetected => ["Passive Reclaim Detected", "Recuperación pasiva detectada", "Recuperação passiva detectada"],
+    UserClosedAccount => ["User Closed Account", "El usuario cerró la cuenta", "O usuário fechou a conta"],
+    ScanComplete => ["Scan Complete", "Escaneo completado", "Varredura concluída"],
+    ReclaimSuccessful => ["Reclaim Successful", "Recuperación exitosa", "Recuperação bem-sucedida"],
+    ReclaimFailed => ["Reclaim Failed", "Recuperación fallida", "Falha na recuperação"],
+    BatchReclaimComplete => ["Batch Reclaim Complete", "Recuperación por lotes completada", "Recuperação em lote concluída"],
+    CreditMismatch => ["Treasury Credit Mismatch", "Crédito a tesorería no coincide", "Crédito na tesouraria não confere"],
+    Signature => ["Signature", "Firma", "Assinatura"],
+    ExpectedCredit => ["Expected", "Esperado", "Esperado"],
+    ActualCredit => ["Credited", "Acreditado", "Creditado"],
+    CreditMismatchFooter => [
+        "Check the transaction's destination before reclaiming further",
+        "Verifica el destino de la transacción antes de seguir recuperando",
+        "Verifique o destino da transação antes de continuar recuperando",
+    ],
+    ErrorOccurred => ["Error Occurred", "Se produjo un error", "Ocorreu um erro"],
+    ScanFailedTitle => ["Scan Failed", "Escaneo fallido", "Falha na varredura"],
+    ScansFailing => ["Scans Keep Failing", "Los escaneos siguen fallando", "As varreduras continuam falhando"],
+    RpcDegraded => ["RPC Endpoint Down", "Endpoint RPC caído", "Endpoint RPC fora do ar"],
+    RpcRecovered => ["RPC Endpoint Recovered", "Endpoint RPC recuperado", "Endpoint RPC recuperado"],
+    Down => ["Down for", "Caído durante", "Fora do ar por"],
+    Minutes => ["{} minutes", "{} minutos", "{} minutos"],
+    RpcDegradedFooter => [
+        "Cycles are paused without further alerts until the endpoint answers again",
+        "Los ciclos quedan en pausa sin más alertas hasta que el endpoint vuelva a responder",
+        "Os ciclos ficam pausados sem novos alertas até o endpoint responder novamente",
+    ],
+    RpcRecoveredFooter => [
+        "The auto service has resumed its cycles",
+        "El servicio automático ha reanudado sus ciclos",
+        "O serviço automático retomou seus ciclos",
+    ],
+    ServiceRestarted => ["Daemon Service Restarted", "Servicio del daemon reiniciado", "Serviço do daemon reiniciado"],
+    Service => ["Service", "Servicio", "Serviço"],
+    Restarts => ["Restarts", "Reinicios", "Reinícios"],
+    RestartingIn => ["Restarting in", "Reinicio en", "Reinício em"],
+    Seconds => ["{} seconds", "{} segundos", "{} segundos"],
+    ServiceRestartedFooter => [
+        "The other services keep running; the wait doubles while failures continue",
+        "Los demás servicios siguen funcionando; la espera se duplica mientras sigan los fallos",
+        "Os demais serviços continuam rodando; a espera dobra enquanto as falhas continuarem",
+    ],
+    HighValueReclaim => ["High-Value Reclaim", "Recuperación de alto valor", "Recuperação de alto valor"],
+    ReclaimStaged => ["High-Value Reclaim Staged", "Recuperación de alto valor en espera", "Recuperação de alto valor em espera"],
+    RunsAfter => ["Runs after", "Se ejecuta después de", "Executa após"],
+    ComputeUnits => ["Compute units", "Unidades de cómputo", "Unidades de computação"],
+    SimulationFailed => ["Simulation failed", "Simulación fallida", "Simulação falhou"],
+    AwaitingApproval => ["Awaiting approval", "Pendiente de aprobación", "Aguardando aprovação"],
+    Approved => ["Approved by {}", "Aprobada por {}", "Aprovada por {}"],
+    StagedApprovalFooter => [
+        "Not sent until approved: /approve {} to release it, /deny {} to cancel",
+        "No se envía hasta aprobarla: /approve {} para liberarla, /deny {} para cancelarla",
+        "Não é enviada até ser aprovada: /approve {} para liberá-la, /deny {} para cancelá-la",
+    ],
+    StagedDelayFooter => [
+        "Sent by the first cycle after the delay; /deny {} cancels it, /approve {} sends it sooner",
+        "La envía el primer ciclo tras la espera; /deny {} la cancela, /approve {} la adelanta",
+        "Enviada pelo primeiro ciclo após a espera; /deny {} a cancela, /approve {} a antecipa",
+    ],
+    DailySummary => ["Daily Summary", "Resumen diario", "Resumo diário"],
+    BatchPlanned => ["Batch Cost Plan", "Plan de costes del lote", "Plano de custos do lote"],
+    BatchAborted => ["Batch Aborted", "Lote cancelado", "Lote cancelado"],
+
+    // Notification bodies
+    Amount => ["Amount", "Monto", "Valor"],
+    Confidence => ["Confidence", "Confianza", "Confiança"],
+    LikelyFrom => ["Likely from", "Probablemente de", "Provavelmente de"],
+    AccountCount => ["{} accounts", "{} cuentas", "{} contas"],
+    Returned => ["Returned", "Devuelto", "Devolvido"],
+    ClosureCorrelation => [
+        "The treasury is checked now to attribute the returned rent.",
+        "Se revisa la tesorería ahora para atribuir la renta devuelta.",
+        "A tesouraria é verificada agora para atribuir o aluguel devolvido.",
+    ],
+    PassiveExplanation => [
+        "This rent returned to treasury when the user closed their account.",
+        "Esta renta volvió a la tesorería cuando el usuario cerró su cuenta.",
+        "Este aluguel voltou para a tesouraria quando o usuário fechou a conta.",
+    ],
+    LowConfidenceReview => [
+        "⚠️ Low confidence: not counted until reviewed. Use /confirm {} or /reject {}",
+        "⚠️ Confianza baja: no se contabiliza hasta revisarse. Usa /confirm {} o /reject {}",
+        "⚠️ Confiança baixa: não é contabilizado até ser revisado. Use /confirm {} ou /reject {}",
+    ],
+    TotalSponsoredAccounts => ["Total sponsored accounts", "Total de cuentas patrocinadas", "Total de contas patrocinadas"],
+    EligibleForReclaim => ["Eligible for reclaim", "Elegibles para recuperar", "Elegíveis para recuperação"],
+    ScanFooter => [
+        "Automated scan completed successfully",
+        "Escaneo automático completado correctamente",
+        "Varredura automática concluída com sucesso",
+    ],
+    Account => ["Account", "Cuenta", "Conta"],
+    ReclaimFooter => [
+        "Rent successfully reclaimed to treasury",
+        "Renta recuperada a la tesorería",
+        "Aluguel recuperado para a tesouraria",
+    ],
+    Error => ["Error", "Error", "Erro"],
+    RetryFooter => [
+        "Temporary error, the account will be retried on the next run",
+        "Error temporal, la cuenta se reintentará en la próxima ejecución",
+        "Erro temporário, a conta será tentada novamente na próxima execução",
+    ],
+    CheckLogsFooter => ["Check logs for more details", "Revisa los registros para más detalles", "Verifique os logs para mais detalhes"],
+    Successful => ["Successful", "Exitosas", "Bem-sucedidas"],
+    Failed => ["Failed", "Fallidas", "Falhas"],
+    TotalReclaimed => ["Total reclaimed", "Total recuperado", "Total recuperado"],
+    BatchFooter => [
+        "Automated batch processing completed",
+        "Procesamiento automático por lotes completado",
+        "Processamento automático em lote concluído",
+    ],
+    SystemLogsFooter => ["Please check the system logs", "Revisa los registros del sistema", "Verifique os logs do sistema"],
+    FailuresInARow => ["{} in a row", "{} seguidos", "{} seguidas"],
+    ScanRetryFooter => [
+        "The auto service will retry on its next cycle",
+        "El servicio automático lo reintentará en el próximo ciclo",
+        "O serviço automático tentará novamente no próximo ciclo",
+    ],
+    ScansFailingFooter => [
+        "No new accounts are being discovered until this is fixed",
+        "No se descubren cuentas nuevas hasta que se solucione",
+        "Nenhuma conta nova é descoberta até que isso seja corrigido",
+    ],
+    ExceedsThreshold => [
+        "This exceeds your alert threshold of {} SOL",
+        "Supera tu umbral de alerta de {} SOL",
+        "Excede seu limite de alerta de {} SOL",
+    ],
+    Operations => ["Operations", "Operaciones", "Operações"],
+    StillLocked => ["Still locked", "Aún bloqueado", "Ainda bloqueado"],
+    VsYesterday => ["{} ({} vs yesterday)", "{} ({} respecto a ayer)", "{} ({} em relação a ontem)"],
+    PassiveReclaimsField => ["Passive reclaims", "Recuperaciones pasivas", "Recuperações passivas"],
+    FeesPaid => ["Fees paid", "Comisiones pagadas", "Taxas pagas"],
+    NetRecovery => ["Net recovery", "Recuperación neta", "Recuperação líquida"],
+    UsdValue => ["USD value", "Valor en USD", "Valor em USD"],
+    UsdNetLocked => ["{} net, {} locked", "{} netos, {} bloqueados", "{} líquidos, {} bloqueados"],
+    Transactions => ["Transactions", "Transacciones", "Transações"],
+    EstimatedFees => ["Estimated fees", "Comisiones estimadas", "Taxas estimadas"],
+    ExpectedRecovery => ["Expected recovery", "Recuperación esperada", "Recuperação esperada"],
+    BatchAbortedFooter => [
+        "Fees exceed reclaim.fees.max_cost_percent; nothing was sent",
+        "Las comisiones superan reclaim.fees.max_cost_percent; no se envió nada",
+        "As taxas excedem reclaim.fees.max_cost_percent; nada foi enviado",
+    ],
+    DailyFooter => ["Last 24 hours of activity", "Actividad de las últimas 24 horas", "Atividade das últimas 24 horas"],
+    DigestTitle => ["Quiet Hours Digest", "Resumen de horas de silencio", "Resumo do horário de silêncio"],
+    DigestBody => [
+        "{} notifications were held during quiet hours:",
+        "{} notificaciones se retuvieron durante las horas de silencio:",
+        "{} notificações foram retidas durante o horário de silêncio:",
+    ],
+
+    // Command replies
+    WelcomeTitle => ["Welcome to Kora Rent Reclaim Bot", "Bienvenido a Kora Rent Reclaim Bot", "Bem-vindo ao Kora Rent Reclaim Bot"],
+    WelcomeBody => [
+        "I can help you monitor and reclaim rent from sponsored accounts.",
+        "Puedo ayudarte a monitorear y recuperar la renta de cuentas patrocinadas.",
+        "Posso ajudar você a monitorar e recuperar o aluguel de contas patrocinadas.",
+    ],
+    HelpHint => [
+        "Use /help to see available commands.",
+        "Usa /help para ver los comandos disponibles.",
+        "Use /help para ver os comandos disponíveis.",
+    ],
+    BotOnline => ["Bot Status: Online", "Estado del bot: en línea", "Status do bot: online"],
+    Network => ["Network", "Red", "Rede"],
+    Mode => ["Mode", "Modo", "Modo"],
+    ModeAuto => ["Auto", "Automático", "Automático"],
+    ModeManual => ["Manual", "Manual", "Manual"],
+    DryRun => ["Dry Run", "Simulación", "Simulação"],
+    Operator => ["Operator", "Operador", "Operador"],
+    ScanFailures => ["Scan failures", "Fallos de escaneo", "Falhas de varredura"],
+    LastScanError => ["Last scan error", "Último error de escaneo", "Último erro de varredura"],
+    LedgerTampered => [
+        "Reclaim ledger: {} entries fail verification (run {})",
+        "Registro de recuperaciones: {} entradas no superan la verificación (ejecuta {})",
+        "Registro de recuperações: {} entradas falham na verificação (execute {})",
+    ],
+    Unauthorized => [
+        "Authorization failed. You are not authorized to use this bot.",
+        "Autorización fallida. No tienes permiso para usar este bot.",
+        "Falha na autorização. Você não tem permissão para usar este bot.",
+    ],
+    CommandCooldown => [
+        "/{} was run recently. Try again in {}s.",
+        "/{} se ejecutó hace poco. Inténtalo de nuevo en {} s.",
+        "/{} foi executado há pouco. Tente novamente em {} s.",
+    ],
+    ScansBusy => [
+        "Another scan is already running. Try again when it finishes.",
+        "Ya hay otro escaneo en curso. Inténtalo de nuevo cuando termine.",
+        "Outra varredura já está em andamento. Tente novamente quando ela terminar.",
+    ],
+    ScanInProgress => ["Scanning for sponsored accounts...", "Buscando cuentas patrocinadas...", "Procurando contas patrocinadas..."],
+    SignaturesProcessed => ["Signatures processed", "Firmas procesadas", "Assinaturas processadas"],
+    Elapsed => ["Elapsed", "Transcurrido", "Decorrido"],
+    CancellingScan => ["Cancelling scan...", "Cancelando escaneo...", "Cancelando varredura..."],
+    ScanAlreadyFinished => ["Scan already finished", "El escaneo ya terminó", "A varredura já terminou"],
+    ScanCancelled => ["Scan cancelled", "Escaneo cancelado", "Varredura cancelada"],
+    ScanCancelledHint => [
+        "Nothing was saved. Transactions already fetched are cached, so the next /scan resumes faster.",
+        "No se guardó nada. Las transacciones ya obtenidas están en caché, así que el próximo /scan será más rápido.",
+        "Nada foi salvo. As transações já obtidas ficam em cache, então o próximo /scan será mais rápido.",
+    ],
+    ScanSavedTitle => ["Scan complete!", "¡Escaneo completado!", "Varredura concluída!"],
+    Found => ["Found", "Encontradas", "Encontradas"],
+    Saved => ["Saved", "Guardadas", "Salvas"],
+    SavedToDatabase => ["{} to database", "{} en la base de datos", "{} no banco de dados"],
+    ViewAccountsHint => ["Use /accounts to view them.", "Usa /accounts para verlas.", "Use /accounts para vê-las."],
+    SaveFailed => [
+        "Found {} accounts but failed to save to database: {}",
+        "Se encontraron {} cuentas pero no se pudieron guardar en la base de datos: {}",
+        "Foram encontradas {} contas, mas não foi possível salvá-las no banco de dados: {}",
+    ],
+    NotPersisted => ["Accounts were not persisted.", "Las cuentas no se guardaron.", "As contas não foram salvas."],
+    ScanFailed => ["Scan failed", "El escaneo falló", "A varredura falhou"],
+    DatabaseError => ["Database error", "Error de base de datos", "Erro no banco de dados"],
+    FetchingAccounts => ["Fetching account list...", "Obteniendo la lista de cuentas...", "Obtendo a lista de contas..."],
+    FetchingClosed => ["Fetching closed accounts...", "Obteniendo cuentas cerradas...", "Obtendo contas fechadas..."],
+    FetchingReclaimed => ["Fetching reclaimed accounts...", "Obteniendo cuentas recuperadas...", "Obtendo contas recuperadas..."],
+    NoActiveAccounts => [
+        "No active accounts found in database. Run /scan first.",
+        "No hay cuentas activas en la base de datos. Ejecuta /scan primero.",
+        "Nenhuma conta ativa no banco de dados. Execute /scan primeiro.",
+    ],
+    NoClosedAccounts => [
+        "No closed accounts found in database.",
+        "No hay cuentas cerradas en la base de datos.",
+        "Nenhuma conta fechada no banco de dados.",
+    ],
+    NoReclaimedAccounts => [
+        "No reclaimed accounts found in database.",
+        "No hay cuentas recuperadas en la base de datos.",
+        "Nenhuma conta recuperada no banco de dados.",
+    ],
+    ActiveAccounts => ["Active Accounts", "Cuentas activas", "Contas ativas"],
+    ClosedAccounts => ["Closed Accounts", "Cuentas cerradas", "Contas fechadas"],
+    ReclaimedAccounts => ["Reclaimed Accounts", "Cuentas recuperadas", "Contas recuperadas"],
+    RentLamports => ["Rent: {} lamports", "Renta: {} lamports", "Aluguel: {} lamports"],
+    AndMore => ["...and {} more", "...y {} más", "...e mais {}"],
+    CheckingEligibility => ["Checking eligibility...", "Verificando elegibilidad...", "Verificando elegibilidade..."],
+    EligibilityCheck => ["Eligibility Check", "Verificación de elegibilidad", "Verificação de elegibilidade"],
+    FoundEligible => [
+        "Found {} eligible accounts.",
+        "Se encontraron {} cuentas elegibles.",
+        "Foram encontradas {} contas elegíveis.",
+    ],
+    EstReclaimable => ["Est. reclaimable", "Recuperable estimado", "Recuperável estimado"],
+    EligibilityFailed => ["Error checking eligibility", "Error al verificar la elegibilidad", "Erro ao verificar elegibilidade"],
+    BotStatistics => ["Kora Bot Statistics", "Estadísticas de Kora Bot", "Estatísticas do Kora Bot"],
+    Accounts => ["Accounts", "Cuentas", "Contas"],
+    Total => ["Total", "Total", "Total"],
+    Active => ["Active", "Activas", "Ativas"],
+    Closed => ["Closed", "Cerradas", "Fechadas"],
+    Reclaimed => ["Reclaimed", "Recuperadas", "Recuperadas"],
+    TotalOps => ["Total Ops", "Operaciones totales", "Operações totais"],
+    Avg => ["Avg", "Promedio", "Média"],
+    Lamports => ["{} lamports", "{} lamports", "{} lamports"],
+    StatsFailed => ["Error fetching stats", "Error al obtener estadísticas", "Erro ao obter estatísticas"],
+    Settings => ["Settings", "Configuración", "Configurações"],
+    MinInactive => ["Min Inactive", "Inactividad mínima", "Inatividade mínima"],
+    Days => ["{} days", "{} días", "{} dias"],
+    BatchSize => ["Batch Size", "Tamaño de lote", "Tamanho do lote"],
+    Database => ["Database", "Base de datos", "Banco de dados"],
+    AlertThreshold => ["Alert Threshold", "Umbral de alerta", "Limite de alerta"],
+    AlertAtLeast => ["Alert ≥ {} SOL", "Alerta ≥ {} SOL", "Alerta ≥ {} SOL"],
+    AlertThresholdValue => ["Alert threshold: {} SOL", "Umbral de alerta: {} SOL", "Limite de alerta: {} SOL"],
+    SettingsFooter => [
+        "Tap a button to change a setting. Changes are stored in the database and override the config file.",
+        "Toca un botón para cambiar un ajuste. Los cambios se guardan en la base de datos y sustituyen al archivo de configuración.",
+        "Toque em um botão para alterar uma configuração. As alterações ficam no banco de dados e substituem o arquivo de configuração.",
+    ],
+    NoPendingReviews => [
+        "No passive reclaims awaiting review.",
+        "No hay recuperaciones pasivas pendientes de revisión.",
+        "Nenhuma recuperação passiva aguardando revisão.",
+    ],
+    PassiveReclaimId => ["Passive reclaim #{}", "Recuperación pasiva #{}", "Recuperação passiva #{}"],
+    Detected => ["Detected", "Detectado", "Detectado"],
+    NoCandidateAccounts => ["no candidate accounts", "sin cuentas candidatas", "nenhuma conta candidata"],
+    MorePending => [
+        "...and {} more. Resolve these first or use the CLI.",
+        "...y {} más. Resuélvelas primero o usa la CLI.",
+        "...e mais {}. Resolva estas primeiro ou use a CLI.",
+    ],
+    ReviewMarked => ["Passive reclaim #{} marked as {}", "Recuperación pasiva #{} marcada como {}", "Recuperação passiva #{} marcada como {}"],
+    ReviewAlreadyResolved => ["Passive reclaim #{} is already {}", "La recuperación pasiva #{} ya está {}", "A recuperação passiva #{} já está {}"],
+    ReviewConflict => ["Passive reclaim #{} was already resolved and cannot be marked {}", "La recuperación pasiva #{} ya se resolvió y no puede marcarse como {}", "A recuperação passiva #{} já foi resolvida e não pode ser marcada como {}"],
+    ReviewNotFound => ["No passive reclaim with id #{}", "No existe una recuperación pasiva con id #{}", "Não existe recuperação passiva com id #{}"],
+    InvalidReviewId => ["Invalid review id", "Id de revisión no válido", "Id de revisão inválido"],
+    CallbackReceived => ["Received: {}", "Recibido: {}", "Recebido: {}"],
+    SubscribedEverything => [
+        "You will receive all notifications.",
+        "Recibirás todas las notificaciones.",
+        "Você receberá todas as notificações.",
+    ],
+    SubscribedHighValue => [
+        "You will only receive high-value reclaim alerts.",
+        "Solo recibirás alertas de recuperaciones de alto valor.",
+        "Você receberá apenas alertas de recuperações de alto valor.",
+    ],
+    SubscribedErrors => [
+        "You will only receive errors and failed reclaims.",
+        "Solo recibirás errores y recuperaciones fallidas.",
+        "Você receberá apenas erros e recuperações com falha.",
+    ],
+    Unsubscribed => [
+        "You will no longer receive notifications. Use /subscribe to turn them back on.",
+        "Ya no recibirás notificaciones. Usa /subscribe para volver a activarlas.",
+        "Você não receberá mais notificações. Use /subscribe para reativá-las.",
+    ],
+    UnknownNotificationLevel => [
+        "Unknown notification level: {}. Use everything, high_value or errors.",
+        "Nivel de notificación desconocido: {}. Usa everything, high_value o errors.",
+        "Nível de notificação desconhecido: {}. Use everything, high_value ou errors.",
+    ],
+    UpcomingTitle => ["Eligible Within {} Days", "Elegibles en {} días", "Elegíveis em {} dias"],
+    EligibleInDays => ["Old enough in {} days", "Con antigüedad suficiente en {} días", "Com idade suficiente em {} dias"],
+    UpcomingNote => [
+        "Accounts must still be inactive on-chain by then.",
+        "Las cuentas deben seguir inactivas en cadena para entonces.",
+        "As contas ainda precisam estar inativas na blockchain até lá.",
+    ],
+    NoUpcomingAccounts => [
+        "No active accounts become eligible in the next {} days.",
+        "Ninguna cuenta activa será elegible en los próximos {} días.",
+        "Nenhuma conta ativa se tornará elegível nos próximos {} dias.",
+    ],
+    UpcomingUsage => [
+        "Usage: /upcoming [days], e.g. /upcoming 14",
+        "Uso: /upcoming [días], p. ej. /upcoming 14",
+        "Uso: /upcoming [dias], ex.: /upcoming 14",
+    ],
+    TopTitle => ["Top {} Reclaimable Accounts", "Las {} cuentas más recuperables", "As {} contas mais recuperáveis"],
+    TopNote => [
+        "Tap a button to reclaim that account now.",
+        "Pulsa un botón para recuperar esa cuenta ahora.",
+        "Toque em um botão para recuperar essa conta agora.",
+    ],
+    NoTopAccounts => [
+        "No tracked accounts can be actively reclaimed right now.",
+        "Ninguna cuenta registrada se puede recuperar activamente ahora.",
+        "Nenhuma conta registrada pode ser recuperada ativamente agora.",
+    ],
+    TopUsage => [
+        "Usage: /top [n], e.g. /top 5 (at most {})",
+        "Uso: /top [n], p. ej. /top 5 (como máximo {})",
+        "Uso: /top [n], ex.: /top 5 (no máximo {})",
+    ],
+    ReclaimingAccount => ["Reclaiming {}...", "Recuperando {}...", "Recuperando {}..."],
+    NotEligible => ["{} is not eligible: {}", "{} no es elegible: {}", "{} não é elegível: {}"],
+    DryRunReclaim => [
+        "Dry run: would reclaim {} from {}",
+        "Simulación: se recuperarían {} de {}",
+        "Simulação: seriam recuperados {} de {}",
+    ],
+    HoldUsage => [
+        "Usage: /hold <pubkey> <days> to hold an account, /hold <pubkey> off to release it, /hold to list held accounts",
+        "Uso: /hold <pubkey> <días> para retener una cuenta, /hold <pubkey> off para liberarla, /hold para listar las retenidas",
+        "Uso: /hold <pubkey> <dias> para reter uma conta, /hold <pubkey> off para liberá-la, /hold para listar as retidas",
+    ],
+    HeldAccounts => ["Held Accounts", "Cuentas retenidas", "Contas retidas"],
+    Hold => ["Hold", "Retención", "Retenção"],
+    HeldUntil => ["{} days, until {}", "{} días, hasta {}", "{} dias, até {}"],
+    NoHolds => [
+        "No accounts are held; all wait reclaim.min_inactive_days ({} days).",
+        "No hay cuentas retenidas; todas esperan reclaim.min_inactive_days ({} días).",
+        "Nenhuma conta retida; todas aguardam reclaim.min_inactive_days ({} dias).",
+    ],
+    HoldSet => [
+        "{} is held for {} days, until {}.",
+        "{} queda retenida {} días, hasta {}.",
+        "{} fica retida por {} dias, até {}.",
+    ],
+    HoldCleared => [
+        "{} waits reclaim.min_inactive_days ({} days) again.",
+        "{} vuelve a esperar reclaim.min_inactive_days ({} días).",
+        "{} volta a aguardar reclaim.min_inactive_days ({} dias).",
+    ],
+    HoldNotTracked => [
+        "{} is not tracked; scan it before setting a hold.",
+        "{} no está registrada; escanéala antes de retenerla.",
+        "{} não está registrada; escaneie-a antes de retê-la.",
+    ],
+    StagedReclaims => ["Staged Reclaims", "Recuperaciones en espera", "Recuperações em espera"],
+    NoStagedReclaims => [
+        "No high-value reclaims are staged.",
+        "No hay recuperaciones de alto valor en espera.",
+        "Nenhuma recuperação de alto valor em espera.",
+    ],
+    ApproveUsage => ["Usage: /approve <pubkey>", "Uso: /approve <pubkey>", "Uso: /approve <pubkey>"],
+    DenyUsage => ["Usage: /deny <pubkey>", "Uso: /deny <pubkey>", "Uso: /deny <pubkey>"],
+    StagedApproved => [
+        "{} is released for the next reclaim cycle.",
+        "{} queda liberada para el próximo ciclo de recuperación.",
+        "{} está liberada para o próximo ciclo de recuperação.",
+    ],
+    StagedDenied => [
+        "The staged reclaim of {} is cancelled.",
+        "La recuperación en espera de {} queda cancelada.",
+        "A recuperação em espera de {} foi cancelada.",
+    ],
+    NotStaged => ["{} has no staged reclaim.", "{} no tiene recuperación en espera.", "{} não tem recuperação em espera."],
+    AccountUsage => [
+        "Usage: /account <pubkey>",
+        "Uso: /account <pubkey>",
+        "Uso: /account <pubkey>",
+    ],
+    InvalidPubkey => ["Invalid pubkey: {}", "Pubkey no válida: {}", "Pubkey inválida: {}"],
+    LookingUpAccount => ["Looking up account...", "Buscando la cuenta...", "Buscando a conta..."],
+    AccountDetails => ["Account Details", "Detalles de la cuenta", "Detalhes da conta"],
+    NotTracked => ["Not tracked in the database", "No registrada en la base de datos", "Não registrada no banco de dados"],
+    OnChain => ["On-chain", "En cadena", "Na blockchain"],
+    NotOnChain => ["Not found on-chain (closed)", "No encontrada en cadena (cerrada)", "Não encontrada na blockchain (fechada)"],
+    Status => ["Status", "Estado", "Status"],
+    Strategy => ["Strategy", "Estrategia", "Estratégia"],
+    Created => ["Created", "Creada", "Criada"],
+    Rent => ["Rent", "Renta", "Aluguel"],
+    Balance => ["Balance", "Saldo", "Saldo"],
+    Owner => ["Owner", "Propietario", "Proprietário"],
+    Type => ["Type", "Tipo", "Tipo"],
+    Eligibility => ["Eligibility", "Elegibilidad", "Elegibilidade"],
+    LastActivity => ["Last activity", "Última actividad", "Última atividade"],
+    NoActivity => ["none found", "ninguna", "nenhuma"],
+    Unknown => ["unknown", "desconocido", "desconhecido"],
+    FailureCount => ["{} failures", "{} fallos", "{} falhas"],
+    DeadLettered => ["dead-lettered, not retried", "en lista muerta, sin reintentos", "na lista morta, sem novas tentativas"],
+    NoOperations => ["No reclaim operations recorded", "No hay operaciones de recuperación", "Nenhuma operação de recuperação registrada"],
+    ViewOnExplorer => ["View on Explorer", "Ver en el explorador", "Ver no explorador"],
+    RequestCloseUsage => [
+        "Usage: /requestclose <pubkey>",
+        "Uso: /requestclose <pubkey>",
+        "Uso: /requestclose <pubkey>",
+    ],
+    CloseRequestsDisabled => [
+        "Close requests need api.public_url in the config.",
+        "Las solicitudes de cierre requieren api.public_url en la configuración.",
+        "As solicitações de fechamento exigem api.public_url na configuração.",
+    ],
+    ExportUsage => [
+        "Usage: /export accounts|operations",
+        "Uso: /export accounts|operations",
+        "Uso: /export accounts|operations",
+    ],
+    ExportRestricted => [
+        "Exports need telegram.authorized_users in the config.",
+        "Las exportaciones requieren telegram.authorized_users en la configuración.",
+        "As exportações exigem telegram.authorized_users na configuração.",
+    ],
+    CloseRequestFailed => ["Cannot request a close", "No se puede solicitar el cierre", "Não é possível solicitar o fechamento"],
+    CloseRequest => ["Close Request", "Solicitud de cierre", "Solicitação de fechamento"],
+    Signer => ["Signer", "Firmante", "Assinante"],
+    CloseRequestFooter => [
+        "Send this Solana Pay link to the signer. Opening it in their wallet closes the account and returns the rent.",
+        "Envía este enlace de Solana Pay al firmante. Al abrirlo en su billetera se cierra la cuenta y se devuelve la renta.",
+        "Envie este link do Solana Pay ao assinante. Ao abri-lo na carteira, a conta é fechada e o aluguel é devolvido.",
+    ],
+
+    // CLI summaries
+    StatsTitle => ["Kora Rent Reclaim Statistics", "Estadísticas de Kora Rent Reclaim", "Estatísticas do Kora Rent Reclaim"],
+    StrategyAnalysis => ["Reclaim Strategy Analysis", "Análisis de estrategias de recuperación", "Análise de estratégias de recuperação"],
+    ActiveReclaimPossible => ["Active Reclaim Possible", "Recuperación activa posible", "Recuperação ativa possível"],
+    PassiveMonitoring => ["Passive Monitoring", "Monitoreo pasivo", "Monitoramento passivo"],
+    Unrecoverable => ["Unrecoverable", "Irrecuperables", "Irrecuperáveis"],
+    AccountsLocked => ["{} accounts | {} locked", "{} cuentas | {} bloqueados", "{} contas | {} bloqueados"],
+    ActiveReclaimHint => [
+        "Operator has close authority, can reclaim anytime",
+        "El operador tiene autoridad de cierre y puede recuperar en cualquier momento",
+        "O operador tem autoridade de fechamento e pode recuperar a qualquer momento",
+    ],
+    PassiveMonitoringHint => [
+        "User controls account, monitor for when they close it",
+        "El usuario controla la cuenta; se vigila hasta que la cierre",
+        "O usuário controla a conta; é monitorada até que ele a feche",
+    ],
+    UnrecoverableHint => [
+        "System accounts or permanently locked",
+        "Cuentas del sistema o bloqueadas permanentemente",
+        "Contas do sistema ou bloqueadas permanentemente",
+    ],
+    LockedByMint => ["Locked Rent by Mint", "Renta bloqueada por mint", "Aluguel bloqueado por mint"],
+    MintPassiveShare => ["{} without close authority", "{} sin autoridad de cierre", "{} sem autoridade de fechamento"],
+    OtherMints => ["{} other mints: {}", "{} mints más: {}", "{} outros mints: {}"],
+    ByOperatorKey => ["By Operator Key", "Por clave de operador", "Por chave de operador"],
+    OperatorCurrent => ["current", "actual", "atual"],
+    OperatorRetired => ["retired", "retirada", "aposentada"],
+    OperatorUnrecorded => ["not recorded", "sin registrar", "não registrada"],
+    OperatorTotalsLine => [
+        "{} accounts ({} active), {} locked, {} reclaimed",
+        "{} cuentas ({} activas), {} bloqueados, {} recuperados",
+        "{} contas ({} ativas), {} bloqueados, {} recuperados",
+    ],
+    EarlierCreations => ["Earlier Creations", "Creaciones anteriores", "Criações anteriores"],
+    ReclaimOperations => ["Reclaim Operations", "Operaciones de recuperación", "Operações de recuperação"],
+    ActiveReclaims => ["Active Reclaims", "Recuperaciones activas", "Recuperações ativas"],
+    TotalSol => ["Total SOL", "Total SOL", "Total SOL"],
+    Average => ["Average", "Promedio", "Média"],
+    BySource => ["By source", "Por origen", "Por origem"],
+    PassiveReclaims => ["Passive Reclaims", "Recuperaciones pasivas", "Recuperações passivas"],
+    PassiveReclaimsNote => [
+        "(Rent that returned to treasury when users closed accounts)",
+        "(Renta que volvió a la tesorería cuando los usuarios cerraron sus cuentas)",
+        "(Aluguel que voltou para a tesouraria quando os usuários fecharam as contas)",
+    ],
+    AwaitingReview => ["Awaiting Review", "Pendiente de revisión", "Aguardando revisão"],
+    NotCountedUntilConfirmed => ["(not counted until confirmed)", "(no se contabiliza hasta confirmarse)", "(não contabilizado até ser confirmado)"],
+    TotalRecovered => ["Total Recovered", "Total recuperado", "Total recuperado"],
+    AccountLifetime => ["Account Lifetime", "Vida útil de las cuentas", "Tempo de vida das contas"],
+    Trend => ["Trend", "Tendencia", "Tendência"],
+    ComparedWith => ["Compared with {} ago ({})", "Comparado con hace {} ({})", "Comparado com {} atrás ({})"],
+    NoSnapshotToCompare => [
+        "No snapshot from {} or earlier; the auto service and `snapshot` record one daily",
+        "No hay ninguna instantánea del {} o anterior; el servicio automático y `snapshot` registran una al día",
+        "Não há snapshot de {} ou anterior; o serviço automático e `snapshot` registram um por dia",
+    ],
+    Then => ["Then", "Antes", "Antes"],
+    Now => ["Now", "Ahora", "Agora"],
+    Change => ["Change", "Cambio", "Variação"],
+    ScanningProgress => ["Scanning Progress", "Progreso del escaneo", "Progresso da varredura"],
+    ScanResults => ["Scan Results", "Resultados del escaneo", "Resultados da varredura"],
+    TotalSponsored => ["Total Sponsored", "Total patrocinadas", "Total patrocinadas"],
+    CachedExisting => ["Cached (existing)", "En caché (existentes)", "Em cache (existentes)"],
+    NewAccounts => ["New accounts", "Cuentas nuevas", "Contas novas"],
+    EligibleCli => ["Eligible for Reclaim", "Elegibles para recuperar", "Elegíveis para recuperação"],
+    TotalReclaimable => ["Total Reclaimable", "Total recuperable", "Total recuperável"],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translations_keep_placeholders() {
+        for &key in Text::ALL {
+            let expected = Language::En.text(key).matches("{}").count();
+            for language in Language::ALL {
+                let text = language.text(key);
+                assert!(!text.is_empty(), "{:?} missing in {}", key, language);
+                assert_eq!(text.matches("{}").count(), expected, "{:?} placeholders in {}", key, language);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fill_and_language_codes() {
+        assert_eq!(fill(Language::Es.text(Text::AccountCount), &[&3]), "3 cuentas");
+        assert_eq!(fill("{} of {}", &[&1]), "1 of {}");
+        assert_eq!("PT".parse::<Language>(), Ok(Language::Pt));
+        assert!("fr".parse::<Language>().is_err());
+    }
+}
@@ -0,0 +1,119 @@
+// src/kora/classify.rs - Re-runs strategy classification over tracked accounts
+
+use futures::{stream, StreamExt};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use crate::{
+    error::Result,
+    reclaim::EligibilityChecker,
+    solana::client::SolanaRpcClient,
+    storage::{models::{ReclaimStrategy, SponsoredAccount}, Database},
+};
+use tracing::{info, warn};
+
+/// getMultipleAccounts accepts at most 100 keys per request
+const MAX_BATCH_SIZE: usize = 100;
+
+/// Result of a classification pass
+#[derive(Debug, Clone, Default)]
+pub struct ClassifySummary {
+    pub checked: usize,
+    /// Accounts whose strategy or close authority changed
+    pub changed: usize,
+    /// No longer found on-chain; left as they were for `refresh` to handle
+    pub missing: usize,
+    /// Classification failed, e.g. on an RPC error
+    pub failed: usize,
+    pub active: usize,
+    pub passive: usize,
+    pub unrecoverable: usize,
+    pub unknown: usize,
+}
+
+impl ClassifySummary {
+    fn count(&mut self, strategy: &ReclaimStrategy) {
+        match strategy {
+            ReclaimStrategy::ActiveReclaim => self.active += 1,
+            ReclaimStrategy::PassiveMonitoring => self.passive += 1,
+            ReclaimStrategy::Unrecoverable => self.unrecoverable += 1,
+            ReclaimStrategy::Unknown => self.unknown += 1,
+        }
+    }
+}
+
+/// Classifies stored accounts a batch at a time, `concurrency` of them at once
+pub struct StrategyClassifier {
+    rpc_client: SolanaRpcClient,
+    eligibility: EligibilityChecker,
+    db: Database,
+    concurrency: usize,
+}
+
+impl StrategyClassifier {
+    pub fn new(rpc_client: SolanaRpcClient, eligibility: EligibilityChecker, db: Database, concurrency: usize) -> Self {
+        Self { rpc_client, eligibility, db, concurrency: concurrency.max(1) }
+    }
+
+    /// Classify `accounts` again and store the new `reclaim_strategy` and
+    /// `close_authority`. `on_progress` gets the accounts done and the total
+    /// after each batch.
+    pub async fn classify(
+        &self,
+        accounts: &[SponsoredAccount],
+        mut on_progress: impl FnMut(usize, usize),
+    ) -> Result<ClassifySummary> {
+        let mut summary = ClassifySummary::default();
+        info!("Classifying {} accounts ({} at a time)", accounts.len(), self.concurrency);
+
+        for chunk in accounts.chunks(MAX_BATCH_SIZE) {
+            let pubkeys: Vec<Pubkey> = chunk
+                .iter()
+                .filter_map(|a| Pubkey::from_str(&a.pubkey).ok())
+                .collect();
+
+            // Closed accounts would come back Unknown and lose their authority
+            let fetched = self.rpc_client.get_multiple_accounts(&pubkeys).await?;
+            let existing: Vec<Pubkey> = pubkeys
+                .iter()
+                .zip(fetched.iter())
+                .filter_map(|(pubkey, account)| account.as_ref().map(|_| *pubkey))
+                .collect();
+            summary.missing += pubkeys.len() - existing.len();
+
+            let mut results = stream::iter(existing)
+                .map(|pubkey| async move { (pubkey, self.eligibility.determine_reclaim_strategy(&pubkey).await) })
+                .buffer_unordered(self.concurrency);
+
+            while let Some((pubkey, result)) = results.next().await {
+                summary.checked += 1;
+                let (strategy, close_authority) = match result {
+                    Ok(classified) => classified,
+                    Err(e) => {
+                        warn!("Failed to classify {}: {}", pubkey, e);
+                        summary.failed += 1;
+                        continue;
+                    }
+                };
+                summary.count(&strategy);
+
+                let pubkey = pubkey.to_string();
+                let Some(stored) = chunk.iter().find(|a| a.pubkey == pubkey) else {
+                    continue;
+                };
+                if stored.reclaim_strategy.as_ref() != Some(&strategy) || stored.close_authority != close_authority {
+                    self.db.update_account_authority(&pubkey, close_authority, &strategy.to_string())?;
+                    summary.changed += 1;
+                }
+            }
+
+            on_progress(summary.checked + summary.missing, accounts.len());
+        }
+
+        info!(
+            "Classification complete: {} checked, {} changed, {} missing, {} failed",
+            summary.checked, summary.changed, summary.missing, summary.failed
+        );
+
+        Ok(summary)
+    }
+}
@@ -0,0 +1,199 @@
+// src/kora/finality.rs - Confirms Provisional accounts once their creation is finalized
+
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::TransactionStatus;
+use std::str::FromStr;
+use crate::{
+    error::Result,
+    solana::client::SolanaRpcClient,
+    storage::Database,
+};
+use tracing::{debug, info, warn};
+
+/// Accounts checked per round of getSignatureStatuses and getMultipleAccounts
+const MAX_BATCH_SIZE: usize = 100;
+
+/// Slots past its creation slot that an unknown creation may still land in
+/// another fork; about two minutes, beyond the 150-slot blockhash lifetime
+const FORK_GRACE_SLOTS: u64 = 300;
+
+/// Result of a confirmation pass over Provisional accounts
+#[derive(Debug, Clone, Default)]
+pub struct FinalitySummary {
+    pub checked: usize,
+    pub confirmed: usize,
+    /// Not yet finalized; checked again next pass
+    pub pending: usize,
+    /// Creations the cluster no longer knows about, removed as phantom rows
+    pub dropped: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Verdict {
+    Confirmed,
+    Pending,
+    Dropped,
+}
+
+/// Decide one account from its creation status and whether it exists now
+fn verdict(status: Option<&TransactionStatus>, creation_slot: Option<u64>, finalized_slot: u64, exists: bool) -> Verdict {
+    match status {
+        Some(status) if status.err.is_none() => {
+            if status.satisfies_commitment(CommitmentConfig::finalized()) {
+                Verdict::Confirmed
+            } else {
+                Verdict::Pending
+            }
+        }
+        // Unknown or failed: wait out the fork window, then trust the chain.
+        // Nodes without full history forget old signatures, so an account
+        // that still exists is kept.
+        _ if creation_slot.is_some_and(|slot| slot + FORK_GRACE_SLOTS >= finalized_slot) => Verdict::Pending,
+        _ if exists => Verdict::Confirmed,
+        _ => Verdict::Dropped,
+    }
+}
+
+/// Re-verifies creation signatures of Provisional accounts at `finalized`
+pub struct FinalityChecker {
+    rpc_client: SolanaRpcClient,
+    db: Database,
+}
+
+impl FinalityChecker {
+    pub fn new(rpc_client: SolanaRpcClient, db: Database) -> Self {
+        Self { rpc_client, db }
+    }
+
+    /// Promote finalized accounts to Active and delete those a fork dropped
+    pub async fn confirm_provisional_accounts(&self) -> Result<FinalitySummary> {
+        let accounts = self.db.get_provisional_accounts()?;
+        let mut summary = FinalitySummary::default();
+
+        if accounts.is_empty() {
+            debug!("No provisional accounts to confirm");
+            return Ok(summary);
+        }
+
+        let finalized_slot = self.rpc_client.get_finalized_slot().await?;
+        info!("Confirming {} provisional accounts at finalized slot {}", accounts.len(), finalized_slot);
+
+        for chunk in accounts.chunks(MAX_BATCH_SIZE) {
+            let chunk: Vec<_> = chunk
+                .iter()
+                .filter_map(|a| Pubkey::from_str(&a.pubkey).ok().map(|pubkey| (a, pubkey)))
+                .collect();
+            let pubkeys: Vec<Pubkey> = chunk.iter().map(|(_, pubkey)| *pubkey).collect();
+
+            // Accounts without a creation signature are judged by existence alone
+            let signatures: Vec<(usize, Signature)> = chunk
+                .iter()
+                .enumerate()
+                .filter_map(|(i, (a, _))| {
+                    a.creation_signature.as_deref().and_then(|s| Signature::from_str(s).ok()).map(|s| (i, s))
+                })
+                .collect();
+            let keys: Vec<Signature> = signatures.iter().map(|(_, s)| *s).collect();
+
+            let statuses = if keys.is_empty() {
+                Vec::new()
+            } else {
+                match self.rpc_client.get_signature_statuses_with_history(&keys).await {
+                    Ok(statuses) => statuses,
+                    Err(e) => {
+                        warn!("Failed to fetch creation statuses of {} accounts: {}", keys.len(), e);
+                        continue;
+                    }
+                }
+            };
+            let on_chain = match self.rpc_client.get_multiple_accounts(&pubkeys).await {
+                Ok(on_chain) => on_chain,
+                Err(e) => {
+                    warn!("Failed to fetch batch of {} accounts: {}", pubkeys.len(), e);
+                    continue;
+                }
+            };
+
+            let mut status_of = vec![None; chunk.len()];
+            for ((i, _), status) in signatures.iter().zip(statuses) {
+                status_of[*i] = status;
+            }
+
+            let mut confirmed = Vec::new();
+            let mut dropped = Vec::new();
+            for (((account, _), status), exists) in chunk.iter().zip(&status_of).zip(&on_chain) {
+                summary.checked += 1;
+                match verdict(status.as_ref(), account.creation_slot, finalized_slot, exists.is_some()) {
+                    Verdict::Confirmed => confirmed.push(account.pubkey.clone()),
+                    Verdict::Pending => summary.pending += 1,
+                    Verdict::Dropped => {
+                        warn!(
+                            "Creation of {} ({}) was never finalized; removing the phantom account",
+                            account.pubkey,
+                            account.creation_signature.as_deref().unwrap_or("no signature")
+                        );
+                        dropped.push(account.pubkey.clone());
+                    }
+                }
+            }
+
+            summary.confirmed += self.db.confirm_provisional_accounts(&confirmed)?;
+            self.db.delete_provisional_accounts(&dropped)?;
+            summary.dropped.extend(dropped);
+        }
+
+        info!(
+            "Finality check complete: {} confirmed, {} pending, {} dropped",
+            summary.confirmed,
+            summary.pending,
+            summary.dropped.len()
+        );
+
+        Ok(summary)
+    }
+
+    /// Pass run right after a path saves newly discovered accounts. A failure
+    /// is only logged: the accounts stay Provisional until the next pass.
+    pub async fn confirm_saved(&self) -> FinalitySummary {
+        match self.confirm_provisional_accounts().await {
+            Ok(summary) => summary,
+            Err(e) => {
+                warn!("Finality check failed: {}", e);
+                FinalitySummary::default()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_transaction_status::TransactionConfirmationStatus;
+
+    fn status(confirmations: Option<usize>) -> TransactionStatus {
+        TransactionStatus {
+            slot: 1_000,
+            confirmations,
+            status: Ok(()),
+            err: None,
+            confirmation_status: Some(match confirmations {
+                None => TransactionConfirmationStatus::Finalized,
+                Some(_) => TransactionConfirmationStatus::Confirmed,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(verdict(Some(&status(None)), Some(1_000), 1_010, true), Verdict::Confirmed);
+        assert_eq!(verdict(Some(&status(Some(5))), Some(1_000), 1_010, true), Verdict::Pending);
+
+        // Unknown creation inside the fork window, even if the account shows up
+        assert_eq!(verdict(None, Some(1_000), 1_200, true), Verdict::Pending);
+        // Past the window: a missing account was a phantom, an existing one
+        // outlived the node's signature history
+        assert_eq!(verdict(None, Some(1_000), 1_400, false), Verdict::Dropped);
+        assert_eq!(verdict(None, Some(1_000), 1_400, true), Verdict::Confirmed);
+        assert_eq!(verdict(None, None, 1_400, true), Verdict::Confirmed);
+    }
+}
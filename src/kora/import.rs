@@ -0,0 +1,220 @@
+// src/kora/import.rs - Track accounts handed to us outside of discovery
+
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::collections::HashSet;
+use std::str::FromStr;
+use crate::{
+    config::Config,
+    error::Result,
+    kora::types::AccountType,
+    reclaim::EligibilityChecker,
+    solana::{accounts::{token_mint, AccountDiscovery}, client::SolanaRpcClient, ParserRegistry},
+    storage::{Database, models::{ReclaimStrategy, SponsoredAccount}},
+};
+use chrono::{DateTime, Utc};
+use tracing::{debug, warn};
+
+const BATCH_SIZE: usize = 1000;
+/// Accounts with more history than this get no creation record
+const MAX_HISTORY_PAGES: usize = 10;
+
+/// A pubkey read from an import file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportRow {
+    pub line: usize,
+    pub pubkey: Pubkey,
+}
+
+/// Rows from a CSV whose first column is a pubkey. Blank lines, `#` comments,
+/// a `pubkey` header and duplicates are skipped; invalid pubkeys are returned
+/// as `line N: ...` errors.
+pub fn parse_import_csv(text: &str) -> (Vec<ImportRow>, Vec<String>) {
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    let mut seen = HashSet::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let field = line
+            .split(',')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .trim_matches('"');
+        if field.eq_ignore_ascii_case("pubkey") || field.eq_ignore_ascii_case("address") {
+            continue;
+        }
+
+        match Pubkey::from_str(field) {
+            Ok(pubkey) => {
+                if seen.insert(pubkey) {
+                    rows.push(ImportRow { line: line_number, pubkey });
+                }
+            }
+            Err(_) => errors.push(format!("line {}: '{}' is not a valid pubkey", line_number, field)),
+        }
+    }
+
+    (rows, errors)
+}
+
+/// What happened to one imported pubkey
+#[derive(Debug, Clone)]
+pub enum ImportOutcome {
+    /// Stored as an Active account with the given strategy
+    Imported { account: Box<SponsoredAccount>, strategy: ReclaimStrategy },
+    /// Already in the database; left untouched
+    AlreadyTracked,
+    /// No account at this address on-chain (closed, or never created)
+    NotFound,
+}
+
+/// Records externally known accounts as if a scan had discovered them:
+/// creation details come from the account's first transaction when it
+/// parses as a sponsored creation, otherwise from the account itself.
+pub struct AccountImporter {
+    rpc_client: SolanaRpcClient,
+    discovery: AccountDiscovery,
+    eligibility: EligibilityChecker,
+    db: Database,
+    operator: Pubkey,
+}
+
+impl AccountImporter {
+    pub fn new(config: &Config, rpc_client: SolanaRpcClient, db: Database) -> Result<Self> {
+        let operator = config.operator_pubkey()?;
+        let discovery = AccountDiscovery::new(rpc_client.clone(), operator)
+            .with_parsers(std::sync::Arc::new(ParserRegistry::from_config(config)))
+            .with_cache(db.clone());
+
+        Ok(Self {
+            eligibility: EligibilityChecker::new(rpc_client.clone(), config.clone()),
+            rpc_client,
+            discovery,
+            db,
+            operator,
+        })
+    }
+
+    /// Import one account; with `dry_run` nothing is written
+    pub async fn import(&self, pubkey: &Pubkey, dry_run: bool) -> Result<ImportOutcome> {
+        if self.db.account_exists(&pubkey.to_string())? {
+            return Ok(ImportOutcome::AlreadyTracked);
+        }
+
+        let Some(on_chain) = self.rpc_client.get_account(pubkey).await? else {
+            return Ok(ImportOutcome::NotFound);
+        };
+
+        let mut account = match self.first_signature(pubkey).await? {
+            Some((signature, slot, time)) => match self.discovery.creation_of(pubkey, signature).await? {
+                Some(creation) => {
+                    debug!("{} matched sponsored creation {}", pubkey, signature);
+                    SponsoredAccount::from(&crate::kora::types::SponsoredAccountInfo {
+                        pubkey: creation.pubkey,
+                        created_at: creation.creation_time,
+                        rent_lamports: creation.initial_balance,
+                        data_size: creation.data_size,
+                        account_type: creation.account_type.into(),
+                        last_activity: None,
+                        creation_signature: creation.creation_signature,
+                        creation_slot: creation.creation_slot,
+                        mint: creation.mint,
+                        operator: self.operator,
+                    })
+                }
+                None => {
+                    let mut account = SponsoredAccount::new(*pubkey, on_chain.lamports, on_chain.data.len());
+                    account.created_at = time.unwrap_or(account.created_at);
+                    account.creation_signature = Some(signature.to_string());
+                    account.creation_slot = Some(slot);
+                    account
+                }
+            },
+            None => SponsoredAccount::new(*pubkey, on_chain.lamports, on_chain.data.len()),
+        };
+
+        if account.account_type.is_none() {
+            account.account_type = Some(AccountType::from_account(&on_chain));
+        }
+        if account.mint.is_none() {
+            account.mint = token_mint(&on_chain).map(|mint| mint.to_string());
+        }
+        account.current_lamports = Some(on_chain.lamports);
+        account.balance_checked_at = Some(Utc::now());
+
+        let (strategy, close_authority) = self.eligibility.determine_reclaim_strategy(pubkey).await?;
+        account.reclaim_strategy = Some(strategy.clone());
+        account.close_authority = close_authority;
+
+        if !dry_run {
+            self.db.save_account(&account)?;
+        }
+
+        Ok(ImportOutcome::Imported { account: Box::new(account), strategy })
+    }
+
+    /// Oldest transaction touching `pubkey`: signature, slot and block time
+    async fn first_signature(
+        &self,
+        pubkey: &Pubkey,
+    ) -> Result<Option<(Signature, u64, Option<DateTime<Utc>>)>> {
+        let mut before = None;
+        let mut oldest = None;
+
+        for _ in 0..MAX_HISTORY_PAGES {
+            let signatures = self
+                .rpc_client
+                .get_signatures_for_address(pubkey, before, None, BATCH_SIZE)
+                .await?;
+
+            let Some(last) = signatures.last() else {
+                return Ok(oldest);
+            };
+            let signature = Signature::from_str(&last.signature)?;
+            let time = last.block_time.and_then(|t| DateTime::from_timestamp(t, 0));
+            oldest = Some((signature, last.slot, time));
+            before = Some(signature);
+
+            if signatures.len() < BATCH_SIZE {
+                return Ok(oldest);
+            }
+        }
+
+        warn!(
+            "{} has more than {} transactions; importing without its creation record",
+            pubkey,
+            MAX_HISTORY_PAGES * BATCH_SIZE
+        );
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_import_csv() {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let text = format!(
+            "pubkey,partner,created\n# exported 2024-05-01\n{first},acme,2024-01-03\n\n\"{second}\"\nnot-a-key,acme\n{first}\n"
+        );
+
+        let (rows, errors) = parse_import_csv(&text);
+        assert_eq!(
+            rows,
+            vec![
+                ImportRow { line: 3, pubkey: first },
+                ImportRow { line: 5, pubkey: second },
+            ]
+        );
+        assert_eq!(errors, vec!["line 6: 'not-a-key' is not a valid pubkey".to_string()]);
+    }
+}
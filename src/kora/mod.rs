@@ -1,5 +1,13 @@
 pub mod monitor;
 pub mod types;
+pub mod refresh;
+pub mod import;
+pub mod finality;
+pub mod classify;
 
 pub use monitor::KoraMonitor;
-pub use types::AccountType;
\ No newline at end of file
+pub use types::AccountType;
+pub use refresh::BalanceRefresher;
+pub use import::AccountImporter;
+pub use finality::FinalityChecker;
+pub use classify::StrategyClassifier;
\ No newline at end of file
@@ -1,65 +1,114 @@
-// src/kora/monitor.rs - Enhanced with RateLimiter
+// src/kora/monitor.rs - Discovers and tracks accounts sponsored by the operator
 
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use std::str::FromStr;
+use std::sync::Arc;
 use crate::{
-    error::Result,
-    solana::{client::SolanaRpcClient, accounts::AccountDiscovery},
+    error::{ReclaimError, Result},
+    solana::{client::SolanaRpcClient, accounts::{AccountDiscovery, ScanProgress}, parser::ParserRegistry},
     kora::types::SponsoredAccountInfo,
-    utils::RateLimiter, // ✅ USE: Import RateLimiter
+    storage::{Database, models::{ScanSession, SponsoredAccount as StoredAccount}},
 };
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 use tracing::{info, debug, warn};
 
+#[derive(Clone)]
 pub struct KoraMonitor {
     rpc_client: SolanaRpcClient,
     operator_pubkey: Pubkey,
-    rate_limiter: RateLimiter, // ✅ USE: Add RateLimiter field
+    parsers: Arc<ParserRegistry>,
+    cache: Option<Database>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    floor_slot: Option<u64>,
+    progress: Option<Arc<ScanProgress>>,
+    cancel: CancellationToken,
 }
 
 impl KoraMonitor {
     pub fn new(rpc_client: SolanaRpcClient, operator_pubkey: Pubkey) -> Self {
-        // Use the RPC client's rate limit delay for the monitor's rate limiter
-        let rate_limit_ms = rpc_client.rate_limit_delay.as_millis() as u64;
-        
+        // Every call through the RPC client takes a token from its shared rate limiter
         Self {
             rpc_client,
             operator_pubkey,
-            rate_limiter: RateLimiter::new(rate_limit_ms), // ✅ USE: new()
+            parsers: Arc::new(ParserRegistry::default()),
+            cache: None,
+            since: None,
+            floor_slot: None,
+            progress: None,
+            cancel: CancellationToken::new(),
+        }
+    }
+    
+    /// Use the given instruction parsers for discovery (e.g. with custom programs)
+    pub fn with_parsers(mut self, parsers: ParserRegistry) -> Self {
+        self.parsers = Arc::new(parsers);
+        self
+    }
+    
+    /// Reuse transactions parsed by earlier scans (see `AccountDiscovery::with_cache`)
+    pub fn with_cache(mut self, db: Database) -> Self {
+        self.cache = Some(db);
+        self
+    }
+    
+    /// Bound scans to transactions from `since` onwards (see `AccountDiscovery::with_since`)
+    pub fn with_since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+    
+    /// Oldest slot scans may reach (`reclaim.scan_floor_slot`)
+    pub fn with_floor_slot(mut self, slot: Option<u64>) -> Self {
+        self.floor_slot = slot;
+        self
+    }
+    
+    /// Report scan progress through shared counters (see `AccountDiscovery::with_progress`)
+    pub fn with_progress(mut self, progress: Arc<ScanProgress>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+    
+    /// Abort scans once `cancel` is cancelled (see `AccountDiscovery::with_cancel`)
+    pub fn with_cancel(mut self, cancel: CancellationToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+    
+    /// The same monitor scanning another operator key, e.g. a retired one
+    pub fn for_operator(&self, operator_pubkey: Pubkey) -> Self {
+        Self { operator_pubkey, ..self.clone() }
+    }
+    
+    /// Keep the floor shown by `checkpoints` in step with the configuration
+    fn record_floor(&self) {
+        if let Some(db) = &self.cache {
+            if let Err(e) = db.save_scan_floor(self.floor_slot) {
+                warn!("Failed to record the scan floor: {}", e);
+            }
+        }
+    }
+    
+    fn discovery(&self) -> AccountDiscovery {
+        let mut discovery = AccountDiscovery::new(self.rpc_client.clone(), self.operator_pubkey)
+            .with_parsers(self.parsers.clone())
+            .with_floor_slot(self.floor_slot)
+            .with_progress(self.progress.clone())
+            .with_cancel(self.cancel.clone());
+        if let Some(db) = &self.cache {
+            discovery = discovery.with_cache(db.clone());
         }
+        if let Some(since) = self.since {
+            discovery = discovery.with_since(since);
+        }
+        discovery
     }
     
     /// Get all sponsored accounts by scanning transaction history
     pub async fn get_sponsored_accounts(&self, max_transactions: usize) -> Result<Vec<SponsoredAccountInfo>> {
         info!("Scanning for Kora-sponsored accounts...");
-        
-        let discovery = AccountDiscovery::new(
-            self.rpc_client.clone(),
-            self.operator_pubkey,
-        );
-        
-        let discovered = discovery.discover_from_signatures(max_transactions).await?;
-        
-        let mut sponsored_accounts = Vec::new();
-        for account_info in discovered {
-            // ✅ USE: wait() - Rate limit when fetching last transaction times
-            self.rate_limiter.wait().await;
-            
-            let last_activity = discovery.get_last_transaction_time(&account_info.pubkey).await?;
-            
-            sponsored_accounts.push(SponsoredAccountInfo {
-                pubkey: account_info.pubkey,
-                created_at: account_info.creation_time,
-                rent_lamports: account_info.initial_balance,
-                data_size: account_info.data_size,
-                account_type: account_info.account_type.into(),
-                last_activity,
-                creation_signature: account_info.creation_signature,
-                creation_slot: account_info.creation_slot,
-            });
-        }
-        
-        debug!("Found {} sponsored accounts", sponsored_accounts.len());
-        Ok(sponsored_accounts)
+        self.scan(None, max_transactions).await
     }
     
     pub async fn is_kora_sponsored(&self, pubkey: &Pubkey) -> Result<bool> {
@@ -77,9 +126,6 @@ impl KoraMonitor {
         let mut history_exhausted = false;
         
         for iteration in 0..MAX_ITERATIONS {
-            // ✅ USE: wait() - Rate limit signature fetches
-            self.rate_limiter.wait().await;
-            
             let signatures = self.rpc_client.get_signatures_for_address(
                 pubkey,
                 before,
@@ -117,9 +163,6 @@ impl KoraMonitor {
         
         // Now check the oldest (creation) transaction
         if let Some(creation_sig) = oldest_signature {
-            // ✅ USE: wait() - Rate limit before fetching transaction
-            self.rate_limiter.wait().await;
-            
             match self.rpc_client.get_transaction(&creation_sig).await? {
                 Some(tx) => {
                     // Check if transaction succeeded
@@ -187,11 +230,42 @@ impl KoraMonitor {
         max_transactions: usize,
     ) -> Result<Vec<SponsoredAccountInfo>> {
         info!("Scanning for new sponsored accounts...");
+        self.scan(since_signature, max_transactions).await
+    }
+    
+    /// Discover accounts and record the run in the scan history
+    async fn scan(
+        &self,
+        since_signature: Option<solana_sdk::signature::Signature>,
+        max_transactions: usize,
+    ) -> Result<Vec<SponsoredAccountInfo>> {
+        let started_at = chrono::Utc::now();
+        let result = self.discover(since_signature, max_transactions).await;
         
-        let discovery = AccountDiscovery::new(
-            self.rpc_client.clone(),
-            self.operator_pubkey,
-        );
+        if let Some(db) = &self.cache {
+            let session = ScanSession {
+                id: 0,
+                started_at,
+                finished_at: chrono::Utc::now(),
+                incremental: since_signature.is_some(),
+                accounts_found: result.as_ref().map_or(0, |accounts| accounts.len()),
+                error: result.as_ref().err().map(|e| e.to_string()),
+            };
+            if let Err(e) = db.save_scan_session(&session) {
+                warn!("Failed to record the scan session: {}", e);
+            }
+        }
+        
+        result
+    }
+    
+    async fn discover(
+        &self,
+        since_signature: Option<solana_sdk::signature::Signature>,
+        max_transactions: usize,
+    ) -> Result<Vec<SponsoredAccountInfo>> {
+        self.record_floor();
+        let discovery = self.discovery();
         
         let discovered = if let Some(since_sig) = since_signature {
             info!("Incremental scan since: {}", since_sig);
@@ -203,9 +277,9 @@ impl KoraMonitor {
         
         let mut sponsored_accounts = Vec::new();
         for account_info in discovered {
-            // ✅ USE: wait() - Rate limit when fetching last transaction times
-            self.rate_limiter.wait().await;
-            
+            if self.cancel.is_cancelled() {
+                return Err(ReclaimError::Cancelled);
+            }
             let last_activity = discovery.get_last_transaction_time(&account_info.pubkey).await?;
             
             sponsored_accounts.push(SponsoredAccountInfo {
@@ -217,6 +291,8 @@ impl KoraMonitor {
                 last_activity,
                 creation_signature: account_info.creation_signature,
                 creation_slot: account_info.creation_slot,
+                mint: account_info.mint,
+                operator: self.operator_pubkey,
             });
         }
         
@@ -224,6 +300,58 @@ impl KoraMonitor {
         Ok(sponsored_accounts)
     }
     
+    /// Record accounts created by operator transactions as their signatures
+    /// arrive from a live feed ([`crate::solana::SignatureStream`] or
+    /// [`crate::solana::GeyserStream`]).
+    /// Runs until the feed stops.
+    ///
+    /// Only discovery is real-time: checkpoints, eligibility and reclaims stay
+    /// with the polling cycle, which finds these transactions in the cache.
+    pub async fn watch(&self, mut signatures: mpsc::UnboundedReceiver<Signature>, db: Database) {
+        let discovery = self.discovery().with_cache(db.clone());
+        let finality = super::FinalityChecker::new(self.rpc_client.clone(), db.clone());
+        
+        while let Some(signature) = signatures.recv().await {
+            let created = match discovery.discover_signature(signature).await {
+                Ok(created) => created,
+                Err(e) => {
+                    warn!("Live discovery failed for {}: {}", signature, e);
+                    continue;
+                }
+            };
+            
+            if created.is_empty() {
+                continue;
+            }
+            
+            let accounts: Vec<StoredAccount> = created
+                .into_iter()
+                .map(|account_info| StoredAccount::from(&SponsoredAccountInfo {
+                    pubkey: account_info.pubkey,
+                    created_at: account_info.creation_time,
+                    rent_lamports: account_info.initial_balance,
+                    data_size: account_info.data_size,
+                    account_type: account_info.account_type.into(),
+                    last_activity: None,
+                    creation_signature: account_info.creation_signature,
+                    creation_slot: account_info.creation_slot,
+                    mint: account_info.mint,
+                    operator: self.operator_pubkey,
+                }))
+                .collect();
+            
+            match db.save_accounts_batch(&accounts) {
+                Ok(saved) => info!("Live discovery recorded {} new accounts from {}", saved, signature),
+                Err(e) => {
+                    warn!("Failed to save live-discovered accounts: {}", e);
+                    continue;
+                }
+            }
+            // These are too fresh to be finalized; earlier ones usually are by now
+            finality.confirm_saved().await;
+        }
+    }
+    
     /// Get total rent locked across all accounts (optimized with batching)
     pub async fn get_total_locked_rent(&self, accounts: &[SponsoredAccountInfo]) -> Result<u64> {
         if accounts.is_empty() {
@@ -239,9 +367,6 @@ impl KoraMonitor {
         for chunk in pubkeys.chunks(MAX_BATCH_SIZE) {
             debug!("Fetching batch of {} accounts", chunk.len());
             
-            // ✅ USE: wait() - Rate limit batch fetches
-            self.rate_limiter.wait().await;
-            
             match self.rpc_client.get_multiple_accounts(chunk).await {
                 Ok(account_data) => {
                     for account_opt in account_data {
@@ -255,9 +380,6 @@ impl KoraMonitor {
                     warn!("Batch fetch failed ({}), falling back to individual calls", e);
                     
                     for pubkey in chunk {
-                        // ✅ USE: wait() - Rate limit individual fallback calls
-                        self.rate_limiter.wait().await;
-                        
                         if let Ok(Some(account)) = self.rpc_client.get_account(pubkey).await {
                             total = total.saturating_add(account.lamports);
                         }
@@ -274,4 +396,56 @@ impl KoraMonitor {
         
         Ok(total)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solana::{
+        accounts::{AccountType, SponsoredAccountInfo as DiscoveredAccount},
+        test_rpc::{multiple_accounts, with_context, TestRpc},
+    };
+    use crate::storage::models::AccountStatus;
+    use serde_json::json;
+    use solana_sdk::account::Account;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_live_discovery_confirms_finalized_creations() {
+        let (operator, pubkey, signature) = (Pubkey::new_unique(), Pubkey::new_unique(), Signature::new_unique());
+        let rpc = TestRpc::start(move |method, params| match method {
+            "getSlot" => Ok(json!(1_400)),
+            "getSignatureStatuses" => Ok(with_context(json!([{
+                "slot": 1_000,
+                "confirmations": null,
+                "err": null,
+                "status": { "Ok": null },
+                "confirmationStatus": "finalized",
+            }]))),
+            "getMultipleAccounts" => Ok(multiple_accounts(params, &[(pubkey, Account::new(2_039_280, 165, &spl_token::id()))])),
+            _ => Err(format!("unexpected {}", method)),
+        });
+
+        // The creation is already in the transaction cache, so only the
+        // confirmation pass reaches the node
+        let db = Database::in_memory().unwrap();
+        let created = DiscoveredAccount {
+            pubkey,
+            creation_signature: signature,
+            creation_slot: 1_000,
+            creation_time: chrono::Utc::now(),
+            initial_balance: 2_039_280,
+            data_size: 165,
+            account_type: AccountType::SplToken,
+            mint: None,
+            instructions: Vec::new(),
+        };
+        db.cache_creations(&signature.to_string(), &ParserRegistry::default().fingerprint(), &[created]).unwrap();
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        sender.send(signature).unwrap();
+        drop(sender);
+        KoraMonitor::new(rpc.client(), operator).watch(receiver, db.clone()).await;
+
+        let account = db.get_account_by_pubkey(&pubkey.to_string()).unwrap().unwrap();
+        assert_eq!(account.status, AccountStatus::Active);
+    }
+}
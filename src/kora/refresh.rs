@@ -0,0 +1,207 @@
+// src/kora/refresh.rs - Keeps tracked account balances in sync with chain state
+
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use crate::{
+    error::Result,
+    kora::types::AccountType,
+    reclaim::EligibilityChecker,
+    solana::{accounts::token_mint, client::SolanaRpcClient},
+    storage::{models::{ReclaimStrategy, SponsoredAccount}, Database},
+};
+use tracing::{info, debug, warn};
+
+/// getMultipleAccounts accepts at most 100 keys per request
+const MAX_BATCH_SIZE: usize = 100;
+
+/// A tracked account whose on-chain balance differs from what we recorded
+#[derive(Debug, Clone)]
+pub struct BalanceDiscrepancy {
+    pub pubkey: String,
+    /// Balance recorded at creation time (estimate)
+    pub expected_lamports: u64,
+    /// Balance currently on-chain (0 if the account no longer exists)
+    pub actual_lamports: u64,
+    /// Account could not be found on-chain
+    pub missing: bool,
+}
+
+/// Result of a refresh pass over all Active accounts
+#[derive(Debug, Clone, Default)]
+pub struct RefreshSummary {
+    pub checked: usize,
+    pub updated: usize,
+    pub discrepancies: Vec<BalanceDiscrepancy>,
+}
+
+impl RefreshSummary {
+    pub fn missing_count(&self) -> usize {
+        self.discrepancies.iter().filter(|d| d.missing).count()
+    }
+}
+
+/// Result of re-checking the accounts classified Unrecoverable
+#[derive(Debug, Clone, Default)]
+pub struct UnrecoverableAudit {
+    pub checked: usize,
+    /// Still plain system accounts whose keys the user holds
+    pub unchanged: usize,
+    /// No longer found on-chain
+    pub missing: Vec<String>,
+    /// Accounts that changed shape or owner, with the strategy they now get
+    pub reclassified: Vec<(String, ReclaimStrategy)>,
+}
+
+/// Chunk-fetches Active accounts and stores their current balances
+pub struct BalanceRefresher {
+    rpc_client: SolanaRpcClient,
+    db: Database,
+}
+
+impl BalanceRefresher {
+    pub fn new(rpc_client: SolanaRpcClient, db: Database) -> Self {
+        Self { rpc_client, db }
+    }
+
+    /// Refresh `current_lamports` for every Active account
+    pub async fn refresh_active_accounts(&self) -> Result<RefreshSummary> {
+        self.refresh(self.db.get_active_accounts()?).await
+    }
+
+    /// Refresh the Active accounts routine cycles check, skipping Unrecoverable
+    /// ones; [`audit_unrecoverable`](Self::audit_unrecoverable) covers those
+    pub async fn refresh_routine_accounts(&self) -> Result<RefreshSummary> {
+        self.refresh(self.db.get_routine_active_accounts()?).await
+    }
+
+    /// Confirm that accounts classified Unrecoverable still are. Their balances
+    /// are refreshed, and any that are no longer plain system accounts are
+    /// classified again so routine cycles pick them up.
+    pub async fn audit_unrecoverable(&self, eligibility: &EligibilityChecker) -> Result<UnrecoverableAudit> {
+        let accounts = self.db.get_unrecoverable_accounts()?;
+        let mut audit = UnrecoverableAudit::default();
+
+        if accounts.is_empty() {
+            debug!("No unrecoverable accounts to audit");
+            return Ok(audit);
+        }
+
+        info!("Auditing {} unrecoverable accounts", accounts.len());
+
+        for chunk in accounts.chunks(MAX_BATCH_SIZE) {
+            let pubkeys: Vec<Pubkey> = chunk
+                .iter()
+                .filter_map(|a| Pubkey::from_str(&a.pubkey).ok())
+                .collect();
+
+            let fetched = match self.rpc_client.get_multiple_accounts(&pubkeys).await {
+                Ok(fetched) => fetched,
+                Err(e) => {
+                    warn!("Failed to fetch batch of {} accounts: {}", pubkeys.len(), e);
+                    continue;
+                }
+            };
+
+            let mut balances = Vec::with_capacity(pubkeys.len());
+            for (pubkey, account_opt) in pubkeys.iter().zip(fetched.iter()) {
+                audit.checked += 1;
+                balances.push((pubkey.to_string(), account_opt.as_ref().map(|a| a.lamports).unwrap_or(0)));
+
+                match account_opt.as_ref().map(AccountType::from_account) {
+                    None => audit.missing.push(pubkey.to_string()),
+                    Some(AccountType::System | AccountType::SeededSystem) => audit.unchanged += 1,
+                    Some(account_type) => {
+                        let (strategy, authority) = eligibility.determine_reclaim_strategy(pubkey).await?;
+                        info!("Unrecoverable account {} is now {}, reclassified as {}", pubkey, account_type, strategy);
+                        self.db.update_account_authority(&pubkey.to_string(), authority, &strategy.to_string())?;
+                        audit.reclassified.push((pubkey.to_string(), strategy));
+                    }
+                }
+            }
+
+            self.db.update_current_balances(&balances)?;
+        }
+
+        info!(
+            "Unrecoverable audit complete: {} checked, {} reclassified, {} missing on-chain",
+            audit.checked,
+            audit.reclassified.len(),
+            audit.missing.len()
+        );
+
+        Ok(audit)
+    }
+
+    async fn refresh(&self, accounts: Vec<SponsoredAccount>) -> Result<RefreshSummary> {
+        let mut summary = RefreshSummary::default();
+
+        if accounts.is_empty() {
+            debug!("No active accounts to refresh");
+            return Ok(summary);
+        }
+
+        info!("Refreshing balances for {} active accounts", accounts.len());
+
+        for chunk in accounts.chunks(MAX_BATCH_SIZE) {
+            let pubkeys: Vec<Pubkey> = chunk
+                .iter()
+                .filter_map(|a| Pubkey::from_str(&a.pubkey).ok())
+                .collect();
+
+            if pubkeys.len() != chunk.len() {
+                warn!("Skipping {} accounts with invalid pubkeys", chunk.len() - pubkeys.len());
+            }
+
+            let fetched = match self.rpc_client.get_multiple_accounts(&pubkeys).await {
+                Ok(fetched) => fetched,
+                Err(e) => {
+                    warn!("Failed to fetch batch of {} accounts: {}", pubkeys.len(), e);
+                    continue;
+                }
+            };
+
+            let mut balances = Vec::with_capacity(pubkeys.len());
+            let mut mints = Vec::new();
+            for (pubkey, account_opt) in pubkeys.iter().zip(fetched.iter()) {
+                let Some(record) = chunk.iter().find(|a| a.pubkey == pubkey.to_string()) else {
+                    continue;
+                };
+
+                let actual = account_opt.as_ref().map(|a| a.lamports).unwrap_or(0);
+                summary.checked += 1;
+                balances.push((record.pubkey.clone(), actual));
+                // Accounts found before mints were tracked learn theirs here
+                if record.mint.is_none() {
+                    if let Some(mint) = account_opt.as_ref().and_then(token_mint) {
+                        mints.push((record.pubkey.clone(), mint.to_string()));
+                    }
+                }
+
+                if account_opt.is_none() || actual != record.rent_lamports {
+                    debug!(
+                        "Balance discrepancy for {}: expected {}, actual {}",
+                        record.pubkey, record.rent_lamports, actual
+                    );
+                    summary.discrepancies.push(BalanceDiscrepancy {
+                        pubkey: record.pubkey.clone(),
+                        expected_lamports: record.rent_lamports,
+                        actual_lamports: actual,
+                        missing: account_opt.is_none(),
+                    });
+                }
+            }
+
+            summary.updated += self.db.update_current_balances(&balances)?;
+            self.db.update_mints(&mints)?;
+        }
+
+        info!(
+            "Balance refresh complete: {} checked, {} discrepancies ({} missing on-chain)",
+            summary.checked,
+            summary.discrepancies.len(),
+            summary.missing_count()
+        );
+
+        Ok(summary)
+    }
+}
@@ -13,6 +13,10 @@ pub struct SponsoredAccountInfo {
     pub last_activity: Option<DateTime<Utc>>,
     pub creation_signature: solana_sdk::signature::Signature,
     pub creation_slot: u64,
+    /// Token mint, for token accounts
+    pub mint: Option<Pubkey>,
+    /// Operator key whose transaction created the account
+    pub operator: Pubkey,
 }
 
 /// Type of account (determines how to close it)
@@ -22,6 +26,10 @@ pub enum AccountType {
     System,
     /// SPL Token account (close with spl_token::close_account)
     SplToken,
+    /// Durable nonce account (close with withdraw_nonce_account as nonce authority)
+    NonceAccount,
+    /// System account created with a seed (user controls the base key)
+    SeededSystem,
     /// Other program account (store program ID for reference)
     Other(Pubkey),
 }
@@ -30,11 +38,37 @@ impl AccountType {
     /// Get the program ID for this account type
     pub fn program_id(&self) -> Pubkey {
         match self {
-            AccountType::System => solana_sdk::system_program::id(),
+            AccountType::System
+            | AccountType::NonceAccount
+            | AccountType::SeededSystem => solana_sdk::system_program::id(),
             AccountType::SplToken => spl_token::id(),
             AccountType::Other(program_id) => *program_id,
         }
     }
+    
+    /// SPL Token or Token-2022 account; both share the base token account layout
+    pub fn is_token_account(&self) -> bool {
+        match self {
+            AccountType::SplToken => true,
+            AccountType::Other(program_id) => *program_id == crate::solana::parser::TOKEN_2022_PROGRAM_ID,
+            _ => false,
+        }
+    }
+
+    /// Classify an on-chain account by its owner program
+    pub fn from_account(account: &solana_sdk::account::Account) -> Self {
+        if account.owner == spl_token::id() && account.data.len() >= 165 {
+            AccountType::SplToken
+        } else if account.owner == solana_sdk::system_program::id()
+            && account.data.len() == crate::solana::accounts::NONCE_ACCOUNT_SIZE
+        {
+            AccountType::NonceAccount
+        } else if account.owner == solana_sdk::system_program::id() {
+            AccountType::System
+        } else {
+            AccountType::Other(account.owner)
+        }
+    }
 }
 
 impl From<crate::solana::accounts::AccountType> for AccountType {
@@ -42,7 +76,40 @@ impl From<crate::solana::accounts::AccountType> for AccountType {
         match value {
             crate::solana::accounts::AccountType::System => AccountType::System,
             crate::solana::accounts::AccountType::SplToken => AccountType::SplToken,
+            crate::solana::accounts::AccountType::NonceAccount => AccountType::NonceAccount,
+            crate::solana::accounts::AccountType::SeededSystem => AccountType::SeededSystem,
             crate::solana::accounts::AccountType::Other(program_id) => AccountType::Other(program_id),
         }
     }
 }
+
+
+impl std::fmt::Display for AccountType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccountType::System => write!(f, "System"),
+            AccountType::SplToken => write!(f, "SplToken"),
+            AccountType::NonceAccount => write!(f, "NonceAccount"),
+            AccountType::SeededSystem => write!(f, "SeededSystem"),
+            AccountType::Other(program_id) => write!(f, "Other:{}", program_id),
+        }
+    }
+}
+
+impl std::str::FromStr for AccountType {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "System" => Ok(AccountType::System),
+            "SplToken" => Ok(AccountType::SplToken),
+            "NonceAccount" => Ok(AccountType::NonceAccount),
+            "SeededSystem" => Ok(AccountType::SeededSystem),
+            other => other
+                .strip_prefix("Other:")
+                .and_then(|id| Pubkey::from_str(id).ok())
+                .map(AccountType::Other)
+                .ok_or(()),
+        }
+    }
+}
@@ -1,3 +1,29 @@
+//! Kora rent reclaim library.
+//!
+//! Everything the `kora-reclaim` binary does is available here, so other Rust
+//! services can embed discovery, eligibility and reclaim logic directly. The
+//! [`KoraReclaim`] façade is the stable entry point:
+//!
+//! ```no_run
+//! use kora_rent_reclaim_bot::{Config, KoraReclaim};
+//!
+//! # async fn run() -> kora_rent_reclaim_bot::Result<()> {
+//! let config = Config::load("config.toml")?;
+//! let kora = KoraReclaim::new(config)?;
+//!
+//! let report = kora.scan(1000).await?;
+//! for account in &report.accounts {
+//!     let eligibility = kora.check_eligibility(&account.pubkey).await?;
+//!     if eligibility.eligible {
+//!         kora.reclaim(&account.pubkey).await?;
+//!     }
+//! }
+//!
+//! println!("{:?}", kora.stats()?);
+//! # Ok(())
+//! # }
+//! ```
+
 pub mod solana;
 pub mod kora;
 pub mod reclaim;
@@ -6,7 +32,18 @@ pub mod config;
 pub mod error;
 pub mod utils;
 pub mod treasury; 
+pub mod cli;
+pub mod telegram;
+pub mod tui;
+pub mod api;
+pub mod events;
+pub mod i18n;
+pub mod daemon;
+pub mod price;
 
 pub use error::{Result, ReclaimError};
 pub use config::Config;
-
+pub use api::{KoraReclaim, ScanReport, EligibilityReport};
+pub use kora::types::{AccountType, SponsoredAccountInfo};
+pub use reclaim::engine::ReclaimResult;
+pub use storage::db::DatabaseStats;
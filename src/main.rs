@@ -1,103 +1,250 @@
-mod cli;
-mod config;
-mod error;
-mod kora;
-mod reclaim;
-mod solana;
-mod storage;
-mod telegram;
-mod treasury;
-mod tui;
-mod utils;
-
 use clap::Parser;
-use cli::{Cli, Commands};
 use colored::*;
-use config::Config;
+use kora_rent_reclaim_bot::cli::{self, Cli, Commands};
+use kora_rent_reclaim_bot::config::{Config, DiscoverySource, StagingConfig};
+use kora_rent_reclaim_bot::i18n::{t, tf, Text};
+use kora_rent_reclaim_bot::{
+    daemon, error, events, kora, price, reclaim, solana, storage, telegram, treasury, tui, utils,
+};
 use tracing::{debug, error, info, warn};
 
+/// `println!` for progress and tables; goes to stderr while the command
+/// prints JSON, so stdout stays parseable
+macro_rules! say {
+    ($($arg:tt)*) => {
+        if utils::is_json_output() {
+            eprintln!($($arg)*)
+        } else {
+            println!($($arg)*)
+        }
+    };
+}
+
 #[tokio::main]
 async fn main() {
-    tracing_subscriber::fmt()
-        .with_env_filter("kora_reclaim=debug,info")
-        .init();
-
     let cli = Cli::parse();
+    let plain = utils::configure_color(cli.no_color);
+    let json = cli.command.json_output();
+    utils::set_json_output(json);
+
+    // The TUI owns the terminal, so its logs go to the activity log pane
+    let tui_logs = tui::LogBuffer::new();
+    if matches!(cli.command, Commands::Tui) {
+        use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer};
+
+        tracing_subscriber::registry()
+            .with(tui_logs.layer().with_filter(tracing_subscriber::EnvFilter::new(
+                "kora_rent_reclaim_bot=debug,kora_reclaim=debug,warn",
+            )))
+            .init();
+    } else {
+        let logs = tracing_subscriber::fmt()
+            .with_env_filter("kora_reclaim=debug,info")
+            .with_ansi(!plain);
+        if json {
+            logs.with_writer(std::io::stderr).init();
+        } else {
+            logs.init();
+        }
+    }
+
+    // Setup runs before any configuration exists
+    if let Commands::Setup { force } = cli.command {
+        if let Err(e) = cli::setup::run_setup(&cli.config, force).await {
+            error!("{}", format!("Error: {}", e).red());
+            std::process::exit(1);
+        }
+        return;
+    }
 
-    let config = match Config::load() {
+    let file_config = match Config::load(&cli.config) {
         Ok(cfg) => cfg,
         Err(e) => {
-            error!("Failed to load configuration: {}", e);
+            error!("Failed to load configuration from {}: {}", cli.config, e);
+            error!("Run `kora-reclaim setup` to create a configuration file");
             std::process::exit(1);
         }
     };
+    // `tenants` reports on every tenant, so it keeps the top-level configuration
+    let root_config = file_config.clone();
+    let mut file_config = match &cli.tenant {
+        Some(tenant) => match select_tenant(&file_config, tenant) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                error!("{}", format!("Error: {}", e).red());
+                std::process::exit(1);
+            }
+        },
+        None => file_config,
+    };
+    if let Some(db) = &cli.db {
+        file_config.database.path = db.clone();
+    }
+    if cli.ephemeral {
+        info!("Using an in-memory database; nothing is saved on exit");
+        file_config.database.path = storage::db::MEMORY_PATH.to_string();
+    }
+    let config = storage::settings::effective_config(&file_config);
+    config.language.set_current();
+    storage::profile::configure(config.database.slow_query_ms);
 
     let result = match cli.command {
-        Commands::Tui => run_tui(config).await,
+        Commands::Tui => run_tui(config, tui_logs).await,
 
         Commands::Scan {
             verbose,
             dry_run,
             limit,
+            since,
+            last,
+            fail_if_behind,
+            format,
         } => {
             info!("Scanning for eligible accounts...");
-            scan_accounts(&config, verbose, dry_run, limit).await
+            let since = since.or_else(|| last.map(|window| chrono::Utc::now() - window));
+            async {
+                scan_accounts(&config, verbose, dry_run, limit, since, &format).await?;
+                match fail_if_behind {
+                    Some(max_slots) => check_scan_lag(&config, max_slots),
+                    None => Ok(()),
+                }
+            }
+            .await
         }
 
-        Commands::Stats { format, total } => {
+        Commands::Plan { format, verbose } => cli::plan::show(&config, &format, verbose).await,
+
+        Commands::Prove { pubkey, format, output } => {
+            cli::prove::show(&config, &pubkey, &format, output.as_deref()).await
+        }
+
+        Commands::Stats { format, total, compare, fail_if_eligible_over } => {
             info!("Generating statistics...");
-            show_stats(&config, &format, total).await
+            // Snapshots are daily
+            let compare_days = compare.map(|period| period.num_days().max(1));
+            async {
+                show_stats(&config, &format, total, compare_days).await?;
+                match fail_if_eligible_over {
+                    Some(max_lamports) => check_eligible_total(&config, max_lamports).await,
+                    None => Ok(()),
+                }
+            }
+            .await
         }
 
-        Commands::PassiveCheck => {
+        Commands::PassiveCheck { format } => {
             info!("Checking for passive reclaims...");
-            check_passive_reclaims(&config).await
+            check_passive_reclaims(&config, &format).await
         }
 
+        Commands::PassiveReview { action } => review_passive_reclaims(&config, action).await,
+
+        Commands::DeadLetter { action } => manage_dead_letters(&config, action).await,
+        Commands::Hold { action } => manage_holds(&config, action).await,
+
+        Commands::Staged { action } => manage_staged(&config, action).await,
+
+        Commands::Outreach { action } => manage_outreach(&config, action).await,
+
+        Commands::Evidence { action } => manage_evidence(&config, action).await,
+
+        Commands::Devnet {
+            action: cli::commands::DevnetAction::Bootstrap { accounts, airdrop_sol },
+        } => cli::devnet::bootstrap(&config, accounts, airdrop_sol).await,
+
         Commands::DailySummary => {
             info!("Sending daily summary...");
             send_daily_summary(&config).await
         }
 
         // ✅ NEW: List command using get_all_accounts
+        Commands::List { soon: Some(days), format, .. } => list_upcoming(&config, days, &format).await,
+
         Commands::List {
             status,
             format,
             detailed,
+            soon: None,
         } => {
             info!("Listing accounts with filter: {}", status);
             list_accounts(&config, &status, &format, detailed).await
         }
 
         // ✅ NEW: Reset command using clear_checkpoints
-        Commands::Reset { yes } => {
+        Commands::Reset { yes, clear_cache, format } => {
             info!("Resetting checkpoints...");
-            reset_checkpoints(&config, yes).await
+            reset_checkpoints(&config, yes, clear_cache, &format).await
         }
 
         // ✅ NEW: Checkpoints command using get_checkpoint_info
-        Commands::Checkpoints => {
+        Commands::Checkpoints { format } => {
             info!("Showing checkpoint information...");
-            show_checkpoints(&config).await
+            show_checkpoints(&config, &format).await
+        }
+
+        Commands::Report {
+            lifetime,
+            outreach,
+            format,
+        } => show_report(&config, lifetime, outreach, &format).await,
+
+        Commands::Integrity { repair } => check_integrity(&config, repair).await,
+
+        Commands::VerifyLedger => verify_ledger(&config).await,
+        Commands::Snapshot { history } => match history {
+            Some(days) => show_snapshot_history(&config, days).await,
+            None => take_snapshot(&config).await,
+        },
+
+        Commands::Refresh { verbose } => {
+            info!("Refreshing account balances...");
+            refresh_balances(&config, verbose).await
+        }
+
+        Commands::Classify { only, concurrency } => {
+            info!("Classifying account strategies...");
+            classify_accounts(&config, only.as_deref(), concurrency).await
+        }
+
+        Commands::Import { file, dry_run } => {
+            info!("Importing accounts from {}", file);
+            import_accounts(&config, &file, dry_run).await
         }
 
+        Commands::RequestClose { pubkey } => request_close(&config, &pubkey).await,
+
         Commands::Reclaim {
             pubkey,
             yes,
             dry_run,
+            destination,
+            format,
         } => {
             info!("Reclaiming account: {}", pubkey);
-            reclaim_account(&config, &pubkey, yes, dry_run).await
+            reclaim_account(&with_destination(&config, destination), &pubkey, yes, dry_run, &format).await
         }
 
-        Commands::Auto { interval, dry_run } => {
+        Commands::Auto { interval, dry_run, destination, budget } => {
             info!(
                 "Starting automated reclaim service (interval: {}s)",
                 interval
             );
+            // Re-reads runtime settings every cycle
+            let config = with_rpc_budget(&with_destination(&file_config, destination), budget);
             run_auto_service(&config, interval, dry_run).await
         }
 
+        Commands::Daemon { interval, dry_run, budget } => {
+            info!("Starting daemon");
+            // Like `auto`, the reclaim loop re-reads runtime settings every cycle
+            run_daemon(&with_rpc_budget(&file_config, budget), interval, dry_run).await
+        }
+
+        // Handled above, before the configuration is loaded
+        Commands::Setup { .. } => Ok(()),
+
+        Commands::Tenants => list_tenants(&root_config).await,
+
         Commands::Init => {
             info!("Initializing...");
             initialize(&config).await
@@ -105,19 +252,72 @@ async fn main() {
 
         Commands::Telegram => {
             info!("Starting Telegram bot interface...");
-            telegram::run_telegram_bot(config).await
+            // Applies runtime settings per request so /settings edits take effect
+            telegram::run_telegram_bot(file_config).await
         }
     };
 
     if let Err(e) = result {
+        let cancelled = matches!(e, error::ReclaimError::Cancelled);
+        if let error::ReclaimError::ThresholdBreached(_) = e {
+            // The command's own output, JSON included, has already been printed
+            error!("{}", e);
+            std::process::exit(cli::EXIT_THRESHOLD);
+        }
+        if json {
+            println!("{}", serde_json::json!({ "error": e.to_string(), "cancelled": cancelled }));
+        }
+        if cancelled {
+            say!("Cancelled");
+            std::process::exit(cli::EXIT_CANCELLED);
+        }
         error!("{}", format!("Error: {}", e).red());
-        std::process::exit(1);
+        std::process::exit(cli::EXIT_FAILURE);
+    }
+}
+
+/// `scan --fail-if-behind`: compare the slot checkpoint with the network
+fn check_scan_lag(config: &Config, max_slots: u64) -> error::Result<()> {
+    let db = storage::Database::new(&config.database.path)?;
+    let Some(last_slot) = db.get_last_processed_slot()? else {
+        return Err(error::ReclaimError::ThresholdBreached(
+            "no slot checkpoint recorded after the scan".to_string(),
+        ));
+    };
+    let current_slot = solana::SolanaRpcClient::from_config(config).client.get_slot()?;
+    let behind = current_slot.saturating_sub(last_slot);
+    if behind > max_slots {
+        return Err(error::ReclaimError::ThresholdBreached(format!(
+            "scan checkpoint is {} slots behind the network (limit {})",
+            behind, max_slots
+        )));
+    }
+    say!("Scan checkpoint is {} slots behind the network (limit {})", behind, max_slots);
+    Ok(())
+}
+
+/// `stats --fail-if-eligible-over`: total the balances a reclaim run would recover now
+async fn check_eligible_total(config: &Config, max_lamports: u64) -> error::Result<()> {
+    let plan = cli::plan::build(config, false).await?;
+    let eligible = plan.cost.expected_recovery_lamports;
+    if eligible > max_lamports {
+        return Err(error::ReclaimError::ThresholdBreached(format!(
+            "{} is eligible for reclaim (limit {})",
+            utils::format_sol(eligible),
+            utils::format_sol(max_lamports)
+        )));
     }
+    say!(
+        "Eligible for reclaim: {} (limit {})",
+        utils::format_sol(eligible),
+        utils::format_sol(max_lamports)
+    );
+    Ok(())
 }
 
-async fn run_tui(config: Config) -> error::Result<()> {
+async fn run_tui(config: Config, logs: tui::LogBuffer) -> error::Result<()> {
     info!("Launching TUI...");
-    tui::run_tui(config).await
+    tui::run_tui(config, logs).await
 }
 
 async fn scan_accounts(
@@ -125,28 +325,40 @@ async fn scan_accounts(
     verbose: bool,
     dry_run: bool,
     limit: Option<usize>,
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    format: &str,
 ) -> error::Result<()> {
     use solana_sdk::pubkey::Pubkey;
 
-    println!("{}", "Scanning for eligible accounts...".cyan());
-
-    let rpc_client = solana::SolanaRpcClient::new(
-        &config.solana.rpc_url,
-        config.commitment_config(),
-        config.solana.rate_limit_delay_ms,
-    );
-
-    let operator_pubkey = config.operator_pubkey()?;
-    let monitor = kora::KoraMonitor::new(rpc_client.clone(), operator_pubkey);
+    say!("{}", "Scanning for eligible accounts...".cyan());
 
-    let max_txns = limit.unwrap_or(5000);
-    info!(
-        "Discovering sponsored accounts from up to {} transactions",
-        max_txns
-    );
+    let rpc_client = solana::SolanaRpcClient::from_config(config);
 
     let db = storage::Database::new(&config.database.path)?;
 
+    let operator_pubkey = config.operator_pubkey()?;
+    let mut monitor = kora::KoraMonitor::new(rpc_client.clone(), operator_pubkey)
+        .with_parsers(solana::ParserRegistry::from_config(config))
+        .with_floor_slot(config.reclaim.scan_floor_slot)
+        .with_cache(db.clone());
+
+    // A time window bounds the scan by itself; --limit still applies on top
+    let max_txns = match since {
+        Some(since) => {
+            monitor = monitor.with_since(since);
+            say!("Scanning transactions since {}", utils::format_timestamp(&since).cyan());
+            limit.unwrap_or(usize::MAX)
+        }
+        None => {
+            let max_txns = limit.unwrap_or(5000);
+            info!(
+                "Discovering sponsored accounts from up to {} transactions",
+                max_txns
+            );
+            max_txns
+        }
+    };
+
     // ✅ USE: get_all_accounts to cache existing accounts and avoid re-processing
     let existing_accounts = db.get_all_accounts()?;
     info!(
@@ -156,16 +368,32 @@ async fn scan_accounts(
 
     let existing_pubkeys: std::collections::HashSet<String> =
         existing_accounts.iter().map(|a| a.pubkey.clone()).collect();
+    // Plain system accounts stay locked; the auto service audits them periodically
+    let unrecoverable: std::collections::HashSet<String> = existing_accounts
+        .iter()
+        .filter(|a| {
+            a.status == storage::models::AccountStatus::Active
+                && a.reclaim_strategy == Some(storage::models::ReclaimStrategy::Unrecoverable)
+        })
+        .map(|a| a.pubkey.clone())
+        .collect();
 
     // ✅ USE: get_last_processed_slot to show scanning progress
     if let Ok(Some(last_slot)) = db.get_last_processed_slot() {
-        println!(
+        say!(
             "Resuming from last checkpoint at slot: {}",
             last_slot.to_string().cyan()
         );
     }
 
-    let sponsored_accounts = monitor.get_sponsored_accounts(max_txns).await?;
+    let mut sponsored_accounts = monitor.get_sponsored_accounts(max_txns).await?;
+    for operator in config.retired_operator_pubkeys()? {
+        let accounts = monitor.for_operator(operator).get_sponsored_accounts(max_txns).await?;
+        if !accounts.is_empty() {
+            say!("Found {} accounts sponsored by retired operator key {}", accounts.len(), operator);
+        }
+        sponsored_accounts.extend(accounts);
+    }
 
     // Calculate and log total locked rent
     if !sponsored_accounts.is_empty() {
@@ -177,25 +405,14 @@ async fn scan_accounts(
         }
     }
 
-    println!("Found {} sponsored accounts", sponsored_accounts.len());
+    say!("Found {} sponsored accounts", sponsored_accounts.len());
 
     // Separate new accounts from existing ones
     let mut new_accounts = Vec::new();
     let mut updated_accounts = 0;
 
     for account_info in &sponsored_accounts {
-        let db_account = storage::models::SponsoredAccount {
-            pubkey: account_info.pubkey.to_string(),
-            created_at: account_info.created_at,
-            closed_at: None,
-            rent_lamports: account_info.rent_lamports,
-            data_size: account_info.data_size,
-            status: storage::models::AccountStatus::Active,
-            creation_signature: Some(account_info.creation_signature.to_string()),
-            creation_slot: Some(account_info.creation_slot),
-            close_authority: None,
-            reclaim_strategy: None,
-        };
+        let db_account = storage::models::SponsoredAccount::from(account_info);
 
         if existing_pubkeys.contains(&account_info.pubkey.to_string()) {
             updated_accounts += 1;
@@ -214,33 +431,92 @@ async fn scan_accounts(
         updated_accounts
     );
 
+    let finality = kora::FinalityChecker::new(rpc_client.clone(), db.clone());
+    let mut provisional = (0, 0);
+    match finality.confirm_provisional_accounts().await {
+        Ok(summary) => {
+            provisional = (summary.pending, summary.dropped.len());
+            if summary.pending > 0 {
+                say!(
+                    "{} accounts stay provisional until their creation is finalized",
+                    summary.pending.to_string().yellow()
+                );
+            }
+            if !summary.dropped.is_empty() {
+                say!(
+                    "{} Removed {} phantom accounts whose creation was never finalized",
+                    "!".yellow(),
+                    summary.dropped.len()
+                );
+            }
+        }
+        Err(e) => warn!("Finality check failed: {}", e),
+    }
+
     if !new_accounts.is_empty() {
-        println!(
+        say!(
             "{} {} new accounts discovered",
             "✓".green(),
             new_accounts.len().to_string().cyan()
         );
     }
 
-    let eligibility_checker = reclaim::EligibilityChecker::new(rpc_client.clone(), config.clone());
+    let eligibility_checker = reclaim::EligibilityChecker::new(rpc_client.clone(), config.clone())
+        .with_holds(db.clone());
 
-    let mut eligible_accounts = Vec::new();
+    let dead_lettered = db.get_dead_lettered_pubkeys()?;
+    let mut skipped_dead_letters = 0;
+    let mut skipped_unrecoverable = 0;
 
+    // Settled from the database alone, before any RPC call
+    let mut candidates = Vec::new();
     for account_info in &sponsored_accounts {
-        // ✅ USE: is_account_active to check if account still exists before processing
-        let is_active = match rpc_client.is_account_active(&account_info.pubkey).await {
-            Ok(active) => active,
-            Err(e) => {
-                warn!(
-                    "Failed to check if account {} is active: {}",
-                    account_info.pubkey, e
-                );
-                // Assume inactive if check fails
-                false
-            }
-        };
+        let pubkey = account_info.pubkey.to_string();
+        if unrecoverable.contains(&pubkey) {
+            skipped_unrecoverable += 1;
+            continue;
+        }
+
+        // Skip already reclaimed accounts
+        if existing_accounts
+            .iter()
+            .any(|a| a.pubkey == pubkey && a.status == storage::models::AccountStatus::Reclaimed)
+        {
+            continue;
+        }
+
+        if dead_lettered.contains(&pubkey) {
+            skipped_dead_letters += 1;
+            continue;
+        }
 
-        if !is_active {
+        candidates.push(account_info);
+    }
+
+    // Phase one: a batched fetch drops closed and empty accounts
+    let pubkeys: Vec<Pubkey> = candidates.iter().map(|a| a.pubkey).collect();
+    let prescreen = match eligibility_checker.prescreen(&pubkeys).await {
+        Ok(prescreen) => prescreen,
+        Err(e) => {
+            warn!("Batched prescreen failed, fetching accounts one at a time: {}", e);
+            eligibility_checker.prescreen_each(&pubkeys).await
+        }
+    };
+    info!(
+        "Prescreen kept {} of {} candidates for eligibility checks",
+        prescreen.live_count(),
+        candidates.len()
+    );
+
+    // Phase two: the per-account checks, on survivors only
+    let mut eligible = Vec::new();
+    let mut total_reclaimable = 0u64;
+    for (index, account_info) in candidates.iter().enumerate() {
+        let Some(account) = prescreen.get(index) else {
+            if !prescreen.is_gone(index) {
+                warn!("Could not fetch {}, skipping eligibility check", account_info.pubkey);
+                continue;
+            }
             debug!(
                 "Account {} is no longer active, skipping eligibility check",
                 account_info.pubkey
@@ -251,65 +527,35 @@ async fn scan_accounts(
                 storage::models::AccountStatus::Closed,
             );
             continue;
-        }
-
-        // Skip already reclaimed accounts
-        if let Some(existing) = existing_accounts
-            .iter()
-            .find(|a| a.pubkey == account_info.pubkey.to_string())
-        {
-            if existing.status == storage::models::AccountStatus::Reclaimed {
-                continue;
-            }
-        }
+        };
 
         let is_eligible = eligibility_checker
-            .is_eligible(&account_info.pubkey, account_info.created_at)
+            .is_eligible_account(&account_info.pubkey, account, account_info.created_at)
             .await?;
 
         if is_eligible {
-            eligible_accounts.push(account_info.clone());
+            total_reclaimable += account.lamports;
+            eligible.push(((*account_info).clone(), account.lamports));
         }
     }
 
-    let mut eligible = Vec::new();
-    let mut total_reclaimable = 0u64;
-
-    if !eligible_accounts.is_empty() {
-        let pubkeys: Vec<Pubkey> = eligible_accounts.iter().map(|a| a.pubkey).collect();
-
-        info!(
-            "Fetching balances for {} eligible accounts in batch",
-            pubkeys.len()
+    if skipped_dead_letters > 0 {
+        say!(
+            "Skipped {} dead-lettered accounts (see {})",
+            skipped_dead_letters.to_string().yellow(),
+            "kora-reclaim dead-letter list".cyan()
+        );
+    }
+    if skipped_unrecoverable > 0 {
+        say!(
+            "Skipped {} accounts already classified unrecoverable",
+            skipped_unrecoverable.to_string().yellow()
         );
-        match rpc_client.get_multiple_accounts(&pubkeys).await {
-            Ok(accounts) => {
-                for (account_info, account_opt) in eligible_accounts.iter().zip(accounts.iter()) {
-                    if let Some(account) = account_opt {
-                        let balance = account.lamports;
-                        total_reclaimable += balance;
-                        eligible.push((account_info.clone(), balance));
-                    }
-                }
-            }
-            Err(e) => {
-                warn!(
-                    "Failed to batch fetch accounts, falling back to individual calls: {}",
-                    e
-                );
-                for account_info in &eligible_accounts {
-                    if let Ok(balance) = rpc_client.get_balance(&account_info.pubkey).await {
-                        total_reclaimable += balance;
-                        eligible.push((account_info.clone(), balance));
-                    }
-                }
-            }
-        }
     }
 
     // In scan_accounts(), after discovering accounts, add classification:
 
-    println!("\n{}", "Analyzing reclaim strategies...".cyan());
+    say!("\n{}", "Analyzing reclaim strategies...".cyan());
 
     let eligibility_checker = reclaim::EligibilityChecker::new(rpc_client.clone(), config.clone());
 
@@ -318,6 +564,11 @@ async fn scan_accounts(
     let mut unrecoverable_count = 0;
 
     for account_info in &sponsored_accounts {
+        if unrecoverable.contains(&account_info.pubkey.to_string()) {
+            unrecoverable_count += 1;
+            continue;
+        }
+
         // Determine strategy
         if let Ok((strategy, close_authority)) = eligibility_checker
             .determine_reclaim_strategy(&account_info.pubkey)
@@ -339,42 +590,85 @@ async fn scan_accounts(
         }
     }
 
-    println!("\n{}", "=== Reclaim Strategy Analysis ===".cyan().bold());
-    println!(
-        "Active Reclaim Possible:  {} accounts ✓",
-        active_count.to_string().green()
+    say!("\n{}", format!("=== {} ===", t(Text::StrategyAnalysis)).cyan().bold());
+    say!(
+        "{} {} ✓",
+        label(Text::ActiveReclaimPossible, 25),
+        tf(Text::AccountCount, &[&active_count]).green()
     );
-    println!(
-        "Passive Monitoring:       {} accounts ⏱",
-        passive_count.to_string().yellow()
+    say!(
+        "{} {} ⏱",
+        label(Text::PassiveMonitoring, 25),
+        tf(Text::AccountCount, &[&passive_count]).yellow()
     );
-    println!(
-        "Unrecoverable:            {} accounts ✗",
-        unrecoverable_count.to_string().red()
+    say!(
+        "{} {} ✗",
+        label(Text::Unrecoverable, 25),
+        tf(Text::AccountCount, &[&unrecoverable_count]).red()
     );
 
     // Display results
-    println!("\n{}", "=== Scan Results ===".cyan().bold());
-    println!("Total Sponsored:      {}", sponsored_accounts.len());
-    println!(
-        "Cached (existing):    {}",
+    say!("\n{}", format!("=== {} ===", t(Text::ScanResults)).cyan().bold());
+    say!("{} {}", label(Text::TotalSponsored, 21), sponsored_accounts.len());
+    say!(
+        "{} {}",
+        label(Text::CachedExisting, 21),
         existing_accounts.len().to_string().yellow()
     );
-    println!(
-        "New accounts:         {}",
+    say!(
+        "{} {}",
+        label(Text::NewAccounts, 21),
         new_accounts.len().to_string().green()
     );
-    println!(
-        "Eligible for Reclaim: {} ✓",
+    say!(
+        "{} {} ✓",
+        label(Text::EligibleCli, 21),
         eligible.len().to_string().green()
     );
-    println!(
-        "Total Reclaimable:    {}",
+    say!(
+        "{} {}",
+        label(Text::TotalReclaimable, 21),
         utils::format_sol(total_reclaimable).cyan()
     );
 
+    if format == "json" {
+        let eligible: Vec<_> = eligible
+            .iter()
+            .map(|(account, balance)| {
+                serde_json::json!({
+                    "pubkey": account.pubkey.to_string(),
+                    "balance_lamports": balance,
+                    "created_at": account.created_at,
+                    "creation_slot": account.creation_slot,
+                })
+            })
+            .collect();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "sponsored": sponsored_accounts.len(),
+                "cached": existing_accounts.len(),
+                "new": new_accounts.len(),
+                "updated": updated_accounts,
+                "provisional": provisional.0,
+                "phantoms_dropped": provisional.1,
+                "skipped_dead_lettered": skipped_dead_letters,
+                "skipped_unrecoverable": skipped_unrecoverable,
+                "strategies": {
+                    "active_reclaim": active_count,
+                    "passive_monitoring": passive_count,
+                    "unrecoverable": unrecoverable_count,
+                },
+                "eligible": eligible,
+                "total_reclaimable_lamports": total_reclaimable,
+                "dry_run": dry_run,
+            }))?
+        );
+        return Ok(());
+    }
+
     if verbose && !eligible.is_empty() {
-        println!("\n{}", "Eligible Accounts:".yellow());
+        say!("\n{}", "Eligible Accounts:".yellow());
         utils::print_table_border(120);
         utils::print_table_row(
             &["Pubkey", "Balance", "Created", "Status", "Slot"],
@@ -407,9 +701,61 @@ async fn scan_accounts(
     }
 
     if dry_run && !eligible.is_empty() {
-        println!("\n{}", "DRY RUN: No transactions will be sent".yellow());
+        say!("\n{}", "DRY RUN: No transactions will be sent".yellow());
+    }
+
+    Ok(())
+}
+
+async fn request_close(config: &Config, pubkey: &str) -> error::Result<()> {
+    use solana_sdk::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    let account = Pubkey::from_str(pubkey)?;
+    let public_url = config.api.public_url.as_deref().ok_or_else(|| {
+        error::ReclaimError::ConfigError(
+            "api.public_url is not set; close requests are served by the daemon's HTTP API".to_string(),
+        )
+    })?;
+
+    let db = storage::Database::new(&config.database.path)?;
+    match db.get_account_by_pubkey(pubkey)? {
+        Some(stored)
+            if stored.reclaim_strategy != Some(storage::models::ReclaimStrategy::PassiveMonitoring) =>
+        {
+            println!(
+                "{}",
+                format!(
+                    "⚠️  Account strategy is {}, not PassiveMonitoring",
+                    stored
+                        .reclaim_strategy
+                        .map(|s| s.to_string())
+                        .unwrap_or_else(|| "unknown".to_string())
+                )
+                .yellow()
+            );
+        }
+        Some(_) => {}
+        None => println!("{}", "⚠️  Account not tracked in database".yellow()),
     }
 
+    let rpc_client = solana::SolanaRpcClient::from_config(config);
+    let request = reclaim::CloseRequestBuilder::new(rpc_client, config.treasury_wallet()?)
+        .link(public_url, &account)
+        .await?;
+
+    println!("{}", "=== Close Request ===".cyan().bold());
+    println!("Account:   {}", account);
+    println!("Signer:    {}", request.authority);
+    println!("Returns:   {}", utils::format_sol(request.lamports));
+    println!("\n{}", request.link.cyan());
+    println!("\n{}", reclaim::close_request::qr_code(&request.link)?);
+    println!(
+        "Share the link or QR code with {}. Their wallet fetches the close transaction from {}.",
+        request.authority,
+        public_url
+    );
+
     Ok(())
 }
 
@@ -418,21 +764,25 @@ async fn reclaim_account(
     pubkey: &str,
     yes: bool,
     dry_run: bool,
+    format: &str,
 ) -> error::Result<()> {
     use solana_sdk::pubkey::Pubkey;
     use std::str::FromStr;
 
-    println!("{}", format!("Reclaiming account: {}", pubkey).cyan());
+    let json = format == "json";
+    if json && !yes && !dry_run {
+        return Err(error::ReclaimError::ConfigError(
+            "--format json cannot prompt for confirmation; pass --yes".to_string(),
+        ));
+    }
+
+    say!("{}", format!("Reclaiming account: {}", pubkey).cyan());
 
     let account_pubkey = Pubkey::from_str(pubkey)
         .map_err(|e| error::ReclaimError::Other(anyhow::anyhow!("Invalid pubkey: {}", e)))?;
 
     // Initialize clients
-    let rpc_client = solana::SolanaRpcClient::new(
-        &config.solana.rpc_url,
-        config.commitment_config(),
-        config.solana.rate_limit_delay_ms,
-    );
+    let rpc_client = solana::SolanaRpcClient::from_config(config);
 
     let db = storage::Database::new(&config.database.path)?;
 
@@ -441,11 +791,11 @@ async fn reclaim_account(
             "Account found in database with status: {:?}",
             db_account.status
         );
-        println!("Account status in database: {:?}", db_account.status);
+        say!("Account status in database: {:?}", db_account.status);
 
         // ✅ USE: get_account_creation_details to show when account was created
         if let Ok(Some((creation_sig, creation_slot))) = db.get_account_creation_details(pubkey) {
-            println!(
+            say!(
                 "Created at slot: {} (signature: {})",
                 creation_slot.to_string().cyan(),
                 utils::format_pubkey(&creation_sig)
@@ -453,44 +803,47 @@ async fn reclaim_account(
 
             // Calculate account age
             let account_age = chrono::Utc::now() - db_account.created_at;
-            println!(
+            say!(
                 "Account age: {} days",
                 account_age.num_days().to_string().yellow()
             );
         }
     } else {
         info!("Account not found in database, proceeding with reclaim");
-        println!("{}", "⚠️  Account not tracked in database".yellow());
+        say!("{}", "⚠️  Account not tracked in database".yellow());
     }
 
     // Verify sponsorship
     let operator_pubkey = config.operator_pubkey()?;
-    let monitor = kora::KoraMonitor::new(rpc_client.clone(), operator_pubkey);
+    let monitor = kora::KoraMonitor::new(rpc_client.clone(), operator_pubkey)
+        .with_parsers(solana::ParserRegistry::from_config(config));
 
     info!(
         "Verifying if account {} is sponsored by Kora...",
         account_pubkey
     );
-    if let Ok(is_sponsored) = monitor.is_kora_sponsored(&account_pubkey).await {
+    let sponsored = monitor.is_kora_sponsored(&account_pubkey).await.ok();
+    if let Some(is_sponsored) = sponsored {
         if is_sponsored {
             info!("✓ Verified: Account is sponsored by Kora");
-            println!("{}", "✓ Verified: Account is sponsored by Kora".green());
+            say!("{}", "✓ Verified: Account is sponsored by Kora".green());
         } else {
             warn!("⚠️ Warning: Account does not appear to be sponsored by Kora operator");
-            println!(
+            say!(
                 "{}",
                 "⚠️  Warning: Account not sponsored by Kora operator".yellow()
             );
             if !yes && !dry_run {
                 if !utils::confirm_action("Account not sponsored by Kora. Continue anyway?") {
-                    return Ok(());
+                    return Err(error::ReclaimError::Cancelled);
                 }
             }
         }
     }
 
     // Check eligibility
-    let eligibility_checker = reclaim::EligibilityChecker::new(rpc_client.clone(), config.clone());
+    let eligibility_checker = reclaim::EligibilityChecker::new(rpc_client.clone(), config.clone())
+        .with_holds(db.clone());
 
     // Get account info to determine creation time (use current time as fallback)
     let created_at = chrono::Utc::now() - chrono::Duration::days(365); // Assume old enough
@@ -498,18 +851,22 @@ async fn reclaim_account(
     let reason = eligibility_checker
         .get_eligibility_reason(&account_pubkey, created_at)
         .await?;
-    println!("Eligibility: {}", reason);
+    say!("Eligibility: {}", reason);
 
     let is_eligible = eligibility_checker
         .is_eligible(&account_pubkey, created_at)
         .await?;
     if !is_eligible {
-        return Err(error::ReclaimError::NotEligible(reason));
+        return Err(error::ReclaimError::Ineligible(reason));
     }
 
     // Get account balance
     let balance = rpc_client.get_balance(&account_pubkey).await?;
-    println!("Account balance: {}", utils::format_sol(balance));
+    say!("Account balance: {}", utils::format_sol(balance));
+    let destination = config.reclaim_destination()?;
+    if config.reclaim.destination.is_some() {
+        say!("Destination: {} (instead of the treasury)", destination);
+    }
 
     // Confirm action
     if !yes && !dry_run {
@@ -517,25 +874,31 @@ async fn reclaim_account(
             "Reclaim {} from this account?",
             utils::format_sol(balance)
         )) {
-            println!("Cancelled");
-            return Ok(());
+            return Err(error::ReclaimError::Cancelled);
         }
     }
 
-    // Load treasury keypair
-    let treasury_keypair = config.load_treasury_keypair()?;
-    let treasury_wallet = config.treasury_wallet()?;
+    // Load the close authority and fee payer signers
+    let signer = reclaim::signer::from_config(config)?;
+    let fee_payer = reclaim::signer::fee_payer_from_config(config)?;
 
     // Initialize reclaim engine
     let engine = reclaim::ReclaimEngine::new(
         rpc_client.clone(),
-        treasury_wallet,
-        treasury_keypair,
+        destination,
+        signer,
         dry_run || config.reclaim.dry_run,
-    );
-
-    // Determine account type - Default to SplToken since System accounts can't be reclaimed
-    let account_type = kora::AccountType::SplToken;
+    )
+    .with_fee_payer(fee_payer)
+    .with_close_adapters(reclaim::CloseAdapters::from_config(config))
+    .with_fees(config.reclaim.fees.clone())
+    .with_memo(config.reclaim.memo.clone(), storage::models::ReclaimReason::ManualCli);
+
+    // Determine account type from the owner program - Default to SplToken since System accounts can't be reclaimed
+    let account_type = match rpc_client.get_account(&account_pubkey).await? {
+        Some(account) => kora::AccountType::from_account(&account),
+        None => kora::AccountType::SplToken,
+    };
 
     // Reclaim
     let result = engine
@@ -543,13 +906,16 @@ async fn reclaim_account(
         .await?;
 
     if let Some(sig) = result.signature {
-        println!("✓ Reclaim successful!");
-        println!("Account: {}", result.account);
-        println!("Signature: {}", sig);
-        println!("Reclaimed: {}", utils::format_sol(result.amount_reclaimed));
-
-        // Save to database
-        db.update_account_status(&pubkey, storage::models::AccountStatus::Reclaimed)?;
+        say!("✓ Reclaim successful!");
+        say!("Account: {}", result.account);
+        say!("Signature: {}", sig);
+        say!("Reclaimed: {}", utils::format_sol(result.amount_reclaimed));
+
+        // Save to database; the operation is recorded even if another
+        // process already marked the account
+        if let Err(e) = db.update_account_status(&pubkey, storage::models::AccountStatus::Reclaimed) {
+            warn!("{}", e);
+        }
 
         db.save_reclaim_operation(&storage::models::ReclaimOperation {
             id: 0,
@@ -557,24 +923,50 @@ async fn reclaim_account(
             reclaimed_amount: result.amount_reclaimed,
             tx_signature: sig.to_string(),
             timestamp: chrono::Utc::now(),
-            reason: "Manual CLI reclaim".to_string(),
+            reason: storage::models::ReclaimReason::ManualCli,
+            credited_amount: result.credited_amount(),
+            destination: Some(result.destination.to_string()),
+            fee_lamports: result.fee_lamports(),
         })?;
 
         info!("Reclaim operation saved to database");
 
-        // Send notification if enabled
-        if let Some(notifier) = telegram::AutoNotifier::new(config) {
-            notifier
-                .notify_reclaim_success(&pubkey, result.amount_reclaimed)
-                .await;
+        let bus = events::EventBus::new();
+        let subscribers = bus.spawn_subscribers(config, Default::default());
+        bus.publish(events::ReclaimEvent::ReclaimSucceeded {
+            pubkey: pubkey.to_string(),
+            amount: result.amount_reclaimed,
+            signature: sig.to_string(),
+            batched: false,
+        });
+        if let Some(mismatch) = result.credit_mismatch_event() {
+            say!("⚠ {}", mismatch);
+            bus.publish(mismatch);
         }
+        bus.finish(subscribers).await;
     } else if result.dry_run {
-        println!(
+        say!(
             "DRY RUN: Would reclaim {}",
             utils::format_sol(result.amount_reclaimed)
         );
     }
 
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "account": result.account.to_string(),
+                "sponsored": sponsored,
+                "signature": result.signature.map(|sig| sig.to_string()),
+                "amount_reclaimed_lamports": result.amount_reclaimed,
+                "destination": result.destination.to_string(),
+                "credited_lamports": result.credited_amount(),
+                "fee_lamports": result.fee_lamports(),
+                "dry_run": result.dry_run,
+            }))?
+        );
+    }
+
     Ok(())
 }
 
@@ -582,39 +974,39 @@ async fn reclaim_account(
 
 // Add this function to main.rs
 
-async fn check_passive_reclaims(config: &Config) -> error::Result<()> {
-    println!("{}", "Checking treasury for passive reclaims...".cyan());
+async fn check_passive_reclaims(config: &Config, format: &str) -> error::Result<()> {
+    say!("{}", "Checking treasury for passive reclaims...".cyan());
 
-    let rpc_client = solana::SolanaRpcClient::new(
-        &config.solana.rpc_url,
-        config.commitment_config(),
-        config.solana.rate_limit_delay_ms,
-    );
+    let rpc_client = solana::SolanaRpcClient::from_config(config);
 
     let treasury_wallet = config.treasury_wallet()?;
     let db = storage::Database::new(&config.database.path)?;
 
-    let monitor = treasury::TreasuryMonitor::new(treasury_wallet, rpc_client.clone(), db.clone());
+    let monitor = treasury::TreasuryMonitor::new(treasury_wallet, rpc_client.clone(), db.clone())
+        .with_operator_keys(operator_keys(config)?)
+        .with_matching(config.reclaim.passive_matching.clone());
 
     let passive_reclaims = monitor.check_for_passive_reclaims().await?;
 
-    if passive_reclaims.is_empty() {
-        println!("{}", "No passive reclaims detected".yellow());
+    if passive_reclaims.is_empty() && format != "json" {
+        say!("{}", "No passive reclaims detected".yellow());
         return Ok(());
     }
+    let mut detected = Vec::new();
 
-    println!("\n{} passive reclaim(s) detected:", passive_reclaims.len());
+    say!("\n{} passive reclaim(s) detected:", passive_reclaims.len());
 
     for reclaim in &passive_reclaims {
-        println!("\n{}", "═".repeat(80));
-        println!("Amount: {}", utils::format_sol(reclaim.amount).green());
-        println!("Confidence: {:?}", reclaim.confidence);
-        println!("Timestamp: {}", utils::format_timestamp(&reclaim.timestamp));
+        say!();
+        utils::print_separator(80);
+        say!("Amount: {}", utils::format_sol(reclaim.amount).green());
+        say!("Confidence: {:?}", reclaim.confidence);
+        say!("Timestamp: {}", utils::format_timestamp(&reclaim.timestamp));
 
         if !reclaim.attributed_accounts.is_empty() {
-            println!("Likely from accounts:");
+            say!("Likely from accounts:");
             for acc in &reclaim.attributed_accounts {
-                println!("  • {}", acc);
+                say!("  • {}", acc);
             }
         }
 
@@ -626,77 +1018,1129 @@ async fn check_passive_reclaims(config: &Config) -> error::Result<()> {
             .collect();
 
         let confidence_str = format!("{:?}", reclaim.confidence);
-        db.save_passive_reclaim(reclaim.amount, &account_strs, &confidence_str)?;
+        let id = db.save_passive_reclaim(reclaim.amount, &account_strs, &confidence_str)?;
+        let review = storage::models::ReviewStatus::for_confidence(&confidence_str);
+        detected.push(serde_json::json!({
+            "id": id,
+            "amount_lamports": reclaim.amount,
+            "confidence": confidence_str,
+            "timestamp": reclaim.timestamp,
+            "attributed_accounts": account_strs,
+            "review": review.to_string(),
+        }));
+
+        if review == storage::models::ReviewStatus::Pending
+        {
+            say!(
+                "{}",
+                format!(
+                    "Pending review (#{}): confirm with `kora-reclaim passive-review confirm {}`",
+                    id, id
+                )
+                .yellow()
+            );
+        }
     }
 
-    println!("\n{}", "═".repeat(80));
+    say!();
+    utils::print_separator(80);
 
     let total_passive = monitor.get_total_passive_reclaimed()?;
-    println!(
+    if format == "json" {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "detected": detected,
+                "total_passive_reclaimed_lamports": total_passive,
+            }))?
+        );
+        return Ok(());
+    }
+    say!(
         "\nTotal passive reclaims recorded: {}",
         utils::format_sol(total_passive).green()
     );
 
+    let pending_total = db.get_total_pending_passive_reclaimed()?;
+    if pending_total > 0 {
+        say!(
+            "Awaiting review (excluded from totals): {}",
+            utils::format_sol(pending_total).yellow()
+        );
+    }
+
     Ok(())
 }
 
-async fn run_auto_service(config: &Config, interval: u64, dry_run: bool) -> error::Result<()> {
-    println!("{}", "Starting automated reclaim service...".green());
-
-    let actual_interval = if interval > 0 {
-        interval
-    } else {
-        config.reclaim.scan_interval_seconds
-    };
-
-    println!("Scan interval: {} seconds", actual_interval);
-    println!("Dry run: {}", dry_run);
-
-    let actual_dry_run = dry_run || config.reclaim.dry_run;
-    let notifier = telegram::AutoNotifier::new(config);
-
-    if notifier.is_some() {
-        println!("{}", "✓ Telegram notifications enabled".green());
-    }
+async fn review_passive_reclaims(
+    config: &Config,
+    action: cli::commands::ReviewAction,
+) -> error::Result<()> {
+    use cli::commands::ReviewAction;
+    use storage::models::ReviewStatus;
 
-    loop {
-        info!("Running reclaim cycle...");
+    let db = storage::Database::new(&config.database.path)?;
 
-        // Initialize clients
-        let rpc_client = solana::SolanaRpcClient::new(
-            &config.solana.rpc_url,
-            config.commitment_config(),
-            config.solana.rate_limit_delay_ms,
-        );
+    let (id, status) = match action {
+        ReviewAction::List => {
+            let pending = db.get_pending_passive_reclaims()?;
+            if pending.is_empty() {
+                println!("{}", "No passive reclaims awaiting review".green());
+                return Ok(());
+            }
 
-        let operator_pubkey = match config.operator_pubkey() {
-            Ok(pk) => pk,
-            Err(e) => {
-                error!("Failed to get operator pubkey: {}", e);
-                if let Some(ref n) = notifier {
-                    n.notify_error(&format!("Failed to get operator pubkey: {}", e))
-                        .await;
+            println!(
+                "{}",
+                format!("=== Passive Reclaims Awaiting Review ({}) ===", pending.len())
+                    .cyan()
+                    .bold()
+            );
+            for record in &pending {
+                println!();
+                utils::print_separator(80);
+                println!("ID:         #{}", record.id);
+                println!("Amount:     {}", utils::format_sol(record.amount));
+                println!("Confidence: {}", record.confidence);
+                println!("Detected:   {}", utils::format_timestamp(&record.timestamp));
+                if record.attributed_accounts.is_empty() {
+                    println!("Likely from: (no candidate accounts)");
+                } else {
+                    println!("Likely from:");
+                    for acc in &record.attributed_accounts {
+                        println!("  • {}", acc);
+                    }
+                }
+            }
+            println!();
+            utils::print_separator(80);
+            println!(
+                "\nUse {} or {} to resolve",
+                "kora-reclaim passive-review confirm <ID>".cyan(),
+                "kora-reclaim passive-review reject <ID>".cyan()
+            );
+            return Ok(());
+        }
+        ReviewAction::Confirm { id } => (id, ReviewStatus::Confirmed),
+        ReviewAction::Reject { id } => (id, ReviewStatus::Rejected),
+    };
+
+    let record = db.get_passive_reclaim(id)?.ok_or_else(|| {
+        error::ReclaimError::NotFound(format!("No passive reclaim with id #{}", id))
+    })?;
+
+    if !db.set_passive_reclaim_review(id, status.clone())? {
+        println!("{}", format!("Passive reclaim #{} is already {}", id, status).yellow());
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!(
+            "✓ Passive reclaim #{} ({}, {} confidence) marked as {}",
+            id,
+            utils::format_sol(record.amount),
+            record.confidence,
+            status
+        )
+        .green()
+    );
+    println!(
+        "Total passive reclaims recorded: {}",
+        utils::format_sol(db.get_total_passive_reclaimed()?).green()
+    );
+
+    Ok(())
+}
+
+async fn manage_dead_letters(
+    config: &Config,
+    action: cli::commands::DeadLetterAction,
+) -> error::Result<()> {
+    use cli::commands::DeadLetterAction;
+
+    let db = storage::Database::new(&config.database.path)?;
+
+    match action {
+        DeadLetterAction::List => {
+            let dead_letters = db.get_dead_letters()?;
+            if dead_letters.is_empty() {
+                println!("{}", "No dead-lettered accounts".green());
+                return Ok(());
+            }
+
+            println!(
+                "{}",
+                format!("=== Dead-Lettered Accounts ({}) ===", dead_letters.len())
+                    .cyan()
+                    .bold()
+            );
+            for entry in &dead_letters {
+                println!();
+                utils::print_separator(80);
+                println!("Account:     {}", entry.pubkey);
+                println!("Failures:    {}", entry.failures);
+                println!("Last error:  {}", entry.last_error.red());
+                println!("Last failed: {}", utils::format_timestamp(&entry.last_failed_at));
+                if let Some(at) = entry.dead_lettered_at {
+                    println!("Since:       {}", utils::format_timestamp(&at));
+                }
+            }
+            println!();
+            utils::print_separator(80);
+            println!(
+                "\nUse {} once the account can be reclaimed again",
+                "kora-reclaim dead-letter restore <PUBKEY>".cyan()
+            );
+        }
+        DeadLetterAction::Restore { pubkey } => {
+            if !db.clear_reclaim_failures(&pubkey)? {
+                return Err(error::ReclaimError::Ineligible(format!(
+                    "No reclaim failures recorded for {}",
+                    pubkey
+                )));
+            }
+            println!(
+                "{}",
+                format!("✓ {} restored; it will be retried on the next cycle", pubkey).green()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn manage_holds(config: &Config, action: cli::commands::HoldAction) -> error::Result<()> {
+    use cli::commands::HoldAction;
+
+    let db = storage::Database::new(&config.database.path)?;
+    let min_inactive_days = config.reclaim.min_inactive_days;
+
+    match action {
+        HoldAction::List => {
+            let held = db.get_held_accounts()?;
+            if held.is_empty() {
+                println!("No held accounts; all wait {} days (reclaim.min_inactive_days)", min_inactive_days);
+                return Ok(());
+            }
+
+            println!("{}", format!("=== Held Accounts ({}) ===", held.len()).cyan().bold());
+            utils::print_table_border(90);
+            utils::print_table_row(&["Pubkey", "Hold", "Until", "Balance"], &[44, 10, 20, 14]);
+            utils::print_table_border(90);
+            for account in &held {
+                utils::print_table_row(
+                    &[
+                        &account.pubkey,
+                        &format!("{}d", account.min_inactive_days(min_inactive_days)),
+                        &utils::format_timestamp(&account.eligible_at(min_inactive_days)),
+                        &utils::format_sol(account.balance()),
+                    ],
+                    &[44, 10, 20, 14],
+                );
+            }
+            utils::print_table_border(90);
+        }
+        HoldAction::Set { pubkey, days } => {
+            let account = if db.set_min_inactive_days(&pubkey, Some(days))? {
+                db.get_account_by_pubkey(&pubkey)?
+            } else {
+                None
+            };
+            let Some(account) = account else {
+                return Err(error::ReclaimError::Ineligible(format!(
+                    "{} is not tracked; scan it before setting a hold",
+                    pubkey
+                )));
+            };
+            println!(
+                "{}",
+                format!(
+                    "✓ {} held for {} days, until {}",
+                    pubkey,
+                    days,
+                    utils::format_timestamp(&account.eligible_at(min_inactive_days))
+                )
+                .green()
+            );
+        }
+        HoldAction::Clear { pubkey } => {
+            if db.get_min_inactive_days(&pubkey)?.is_none() {
+                return Err(error::ReclaimError::Ineligible(format!("{} is not held", pubkey)));
+            }
+            db.set_min_inactive_days(&pubkey, None)?;
+            println!(
+                "{}",
+                format!("✓ Hold on {} released; it waits {} days again", pubkey, min_inactive_days).green()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn manage_staged(config: &Config, action: cli::commands::StagedAction) -> error::Result<()> {
+    use cli::commands::StagedAction;
+
+    let db = storage::Database::new(&config.database.path)?;
+
+    match action {
+        StagedAction::List => {
+            let staged = db.get_staged_reclaims()?;
+            if staged.is_empty() {
+                println!("No staged reclaims");
+                return Ok(());
+            }
+
+            let require_approval = config.reclaim.staging.require_approval;
+            println!("{}", format!("=== Staged Reclaims ({}) ===", staged.len()).cyan().bold());
+            utils::print_table_border(100);
+            utils::print_table_row(&["Pubkey", "Amount", "Runs", "Simulation"], &[44, 14, 24, 18]);
+            utils::print_table_border(100);
+            for reclaim in &staged {
+                let runs = match &reclaim.approved_by {
+                    Some(by) => format!("approved ({})", by),
+                    None if require_approval => "awaiting approval".to_string(),
+                    None => utils::format_timestamp(&reclaim.execute_after),
+                };
+                let simulation = match (&reclaim.simulation_error, reclaim.units_consumed) {
+                    (Some(_), _) => "failed".to_string(),
+                    (None, Some(units)) => format!("ok, {} CU", units),
+                    (None, None) => "ok".to_string(),
+                };
+                utils::print_table_row(
+                    &[&reclaim.pubkey, &utils::format_sol(reclaim.amount), &runs, &simulation],
+                    &[44, 14, 24, 18],
+                );
+                if let Some(error) = &reclaim.simulation_error {
+                    println!("  {}", error.red());
+                }
+            }
+            utils::print_table_border(100);
+        }
+        StagedAction::Approve { pubkey } => {
+            if !db.approve_staged_reclaim(&pubkey, "cli")? {
+                return Err(error::ReclaimError::Ineligible(format!("{} has no staged reclaim", pubkey)));
+            }
+            println!("{}", format!("✓ {} is released for the next auto cycle", pubkey).green());
+        }
+        StagedAction::Deny { pubkey } => {
+            if !db.remove_staged_reclaim(&pubkey)? {
+                return Err(error::ReclaimError::Ineligible(format!("{} has no staged reclaim", pubkey)));
+            }
+            println!("{}", format!("✓ Staged reclaim of {} cancelled", pubkey).green());
+        }
+    }
+
+    Ok(())
+}
+
+async fn manage_evidence(
+    config: &Config,
+    action: cli::commands::EvidenceAction,
+) -> error::Result<()> {
+    use cli::commands::EvidenceAction;
+
+    let db = storage::Database::new(&config.database.path)?;
+
+    match action {
+        EvidenceAction::Show { pubkey } => {
+            let Some(evidence) = db.get_discovery_evidence(Some(&pubkey))?.into_iter().next() else {
+                return Err(error::ReclaimError::Ineligible(format!(
+                    "No discovery evidence recorded for {}",
+                    pubkey
+                )));
+            };
+
+            println!("{}", format!("=== Discovery Evidence: {} ===", pubkey).cyan().bold());
+            println!("Signature:   {}", evidence.signature);
+            println!("Slot:        {}", evidence.slot);
+            println!("Created:     {}", utils::format_timestamp(&evidence.creation_time));
+            println!("Recorded:    {}", utils::format_timestamp(&evidence.recorded_at));
+            for instruction in &evidence.instructions {
+                println!("\n{}", serde_json::to_string_pretty(instruction)?);
+            }
+        }
+        EvidenceAction::Replay { apply } => {
+            let parsers = solana::parser::ParserRegistry::from_config(config);
+            let evidence = db.get_discovery_evidence(None)?;
+            if evidence.is_empty() {
+                println!("{}", "No discovery evidence recorded yet".yellow());
+                return Ok(());
+            }
+
+            let mut changed = 0;
+            let mut lost = 0;
+            for entry in &evidence {
+                let Some(mut stored) = db.get_account_by_pubkey(&entry.account_pubkey)? else {
+                    continue;
+                };
+                let Some(replayed) = solana::accounts::replay_evidence(&parsers, entry)? else {
+                    lost += 1;
+                    println!("{} {} is no longer detected", "✗".red(), entry.account_pubkey);
+                    continue;
+                };
+
+                let account_type: kora::AccountType = replayed.account_type.into();
+                let mint = replayed.mint.map(|mint| mint.to_string());
+                let mut differences = Vec::new();
+                if stored.account_type.as_ref() != Some(&account_type) {
+                    differences.push(format!(
+                        "type {} → {}",
+                        stored.account_type.as_ref().map_or("-".to_string(), |t| t.to_string()),
+                        account_type
+                    ));
+                }
+                if stored.rent_lamports != replayed.initial_balance {
+                    differences.push(format!(
+                        "rent {} → {}",
+                        stored.rent_lamports, replayed.initial_balance
+                    ));
+                }
+                if stored.data_size != replayed.data_size {
+                    differences.push(format!("size {} → {}", stored.data_size, replayed.data_size));
+                }
+                if mint.is_some() && stored.mint != mint {
+                    differences.push(format!(
+                        "mint {} → {}",
+                        stored.mint.as_deref().unwrap_or("-"),
+                        mint.as_deref().unwrap_or("-")
+                    ));
+                }
+                if differences.is_empty() {
+                    continue;
+                }
+
+                changed += 1;
+                println!("{} {}: {}", "~".yellow(), entry.account_pubkey, differences.join(", "));
+                if apply {
+                    stored.account_type = Some(account_type);
+                    stored.rent_lamports = replayed.initial_balance;
+                    stored.data_size = replayed.data_size;
+                    stored.mint = mint.or(stored.mint);
+                    db.save_account(&stored)?;
+                }
+            }
+
+            println!(
+                "\nReplayed {} attributions: {} changed, {} no longer detected",
+                evidence.len(),
+                changed,
+                lost
+            );
+            if changed > 0 && !apply {
+                println!("Run with {} to update the stored accounts", "--apply".cyan());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn manage_outreach(
+    config: &Config,
+    action: cli::commands::OutreachAction,
+) -> error::Result<()> {
+    use cli::commands::OutreachAction;
+    use std::str::FromStr;
+    use storage::models::{OutreachRecord, OutreachResponse};
+
+    let db = storage::Database::new(&config.database.path)?;
+    let parse_response = |value: &str| {
+        value
+            .parse::<OutreachResponse>()
+            .map_err(|e| error::ReclaimError::Other(anyhow::anyhow!(e)))
+    };
+
+    match action {
+        OutreachAction::Record {
+            pubkey,
+            channel,
+            response,
+            notes,
+            at,
+        } => {
+            let pubkey = solana_sdk::pubkey::Pubkey::from_str(&pubkey)?.to_string();
+            if db.get_account_by_pubkey(&pubkey)?.is_none() {
+                println!("{}", format!("⚠ {} is not a tracked account", pubkey).yellow());
+            }
+
+            let id = db.save_outreach(&OutreachRecord {
+                id: 0,
+                account_pubkey: pubkey.clone(),
+                contacted_at: at.unwrap_or_else(chrono::Utc::now),
+                channel,
+                response: parse_response(&response)?,
+                notes,
+            })?;
+            println!("{}", format!("✓ Recorded outreach #{} for {}", id, pubkey).green());
+        }
+        OutreachAction::Respond { id, response } => {
+            let response = parse_response(&response)?;
+            if !db.set_outreach_response(id, response)? {
+                return Err(anyhow::anyhow!("No outreach record #{}", id).into());
+            }
+            println!("{}", format!("✓ Outreach #{} marked {}", id, response).green());
+        }
+        OutreachAction::List { pubkey } => {
+            let records = db.get_outreach(pubkey.as_deref())?;
+            if records.is_empty() {
+                println!("No outreach recorded");
+                return Ok(());
+            }
+
+            let widths = [6, 44, 17, 12, 12];
+            utils::print_table_row(&["ID", "Account", "Contacted", "Channel", "Response"], &widths);
+            utils::print_table_border(95);
+            for record in &records {
+                utils::print_table_row(
+                    &[
+                        &record.id.to_string(),
+                        &record.account_pubkey,
+                        &record.contacted_at.format("%Y-%m-%d %H:%M").to_string(),
+                        &record.channel,
+                        &record.response.to_string(),
+                    ],
+                    &widths,
+                );
+                if let Some(notes) = &record.notes {
+                    println!("       {}", notes.dimmed());
+                }
+            }
+        }
+        OutreachAction::Candidates { limit } => {
+            let mut accounts: Vec<_> = db
+                .get_accounts_by_strategy(&storage::models::ReclaimStrategy::PassiveMonitoring.to_string())?
+                .into_iter()
+                .filter(|account| account.status == storage::models::AccountStatus::Active)
+                .collect();
+            if accounts.is_empty() {
+                println!("No passively monitored accounts");
+                return Ok(());
+            }
+            // Most rent first, so the limit keeps the accounts worth asking about
+            accounts.sort_by_key(|account| std::cmp::Reverse(account.rent_lamports));
+            accounts.truncate(limit);
+
+            let rpc_client = solana::SolanaRpcClient::from_config(config);
+            let checker = reclaim::EligibilityChecker::new(rpc_client, config.clone());
+            let now = chrono::Utc::now();
+            let mut candidates = Vec::with_capacity(accounts.len());
+            for account in &accounts {
+                let pubkey = solana_sdk::pubkey::Pubkey::from_str(&account.pubkey)?;
+                match checker.owner_activity(&pubkey).await {
+                    Ok(Some(owner)) => candidates.push((account, owner)),
+                    Ok(None) => {}
+                    Err(e) => println!("{}", format!("⚠ Owner of {} not checked: {}", account.pubkey, e).yellow()),
+                }
+            }
+            // Users still around are the ones likely to respond
+            candidates.sort_by_key(|(_, owner)| {
+                (owner.is_churned(config.reclaim.churn_after_days, now), owner.idle_days(now))
+            });
+
+            let widths = [44, 44, 12, 10, 14];
+            utils::print_table_row(&["Account", "Owner", "Idle days", "Status", "Rent"], &widths);
+            utils::print_table_border(128);
+            let mut churned = 0;
+            for (account, owner) in &candidates {
+                let idle = owner.idle_days(now).map_or("never".to_string(), |days| days.to_string());
+                let state = if owner.is_churned(config.reclaim.churn_after_days, now) {
+                    churned += 1;
+                    "churned"
+                } else {
+                    "active"
+                };
+                utils::print_table_row(
+                    &[
+                        &account.pubkey,
+                        &owner.owner,
+                        &idle,
+                        state,
+                        &utils::format_sol(account.rent_lamports),
+                    ],
+                    &widths,
+                );
+            }
+            println!(
+                "\n{} active, {} churned (idle {}+ days; their rent is unlikely to return passively)",
+                candidates.len() - churned,
+                churned,
+                config.reclaim.churn_after_days
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn run_auto_service(config: &Config, interval: u64, dry_run: bool) -> error::Result<()> {
+    println!("{}", "Starting automated reclaim service...".green());
+
+    let actual_interval = if interval > 0 {
+        interval
+    } else {
+        config.reclaim.scan_interval_seconds
+    };
+
+    println!("Scan interval: {} seconds", actual_interval);
+    println!("Dry run: {}", dry_run);
+    if let Some(destination) = &config.reclaim.destination {
+        println!("Destination: {}", destination);
+    }
+
+    if telegram::AutoNotifier::new(config).is_some() {
+        println!("{}", "✓ Telegram notifications enabled".green());
+    }
+    if !config.webhooks.is_empty() {
+        println!("{} ({} endpoints)", "✓ Webhook notifications enabled".green(), config.webhooks.len());
+    }
+
+    // Refuse to run next to another service on the same database
+    let database = storage::Database::new(&config.database.path)?;
+    let lease = daemon::lease::InstanceLease::acquire(&database)?;
+
+    let (trigger, shutdown) = daemon::shutdown_channel();
+    let _signals = daemon::trigger_on_signal(trigger.clone());
+    let _heartbeat = lease.spawn_heartbeat(trigger.clone());
+
+    // Notification channels and metrics observe the cycle through the event bus
+    let bus = events::EventBus::new();
+    let metrics = std::sync::Arc::new(events::EventMetrics::default());
+    let subscribers = bus.spawn_subscribers(config, metrics.clone());
+    let _live_discovery = spawn_live_discovery(config, &database)?;
+
+    // The treasury is watched on its own schedule, whether or not cycles reclaim
+    let policy = daemon::supervisor::RestartPolicy::from_config(&config.daemon);
+    let passive_interval = config.reclaim.passive_check_interval_seconds.unwrap_or(actual_interval);
+    let passive_monitor = supervise_passive_monitor(config, passive_interval, &database, &bus, &shutdown, policy);
+    println!("Passive check interval: {} seconds", passive_interval);
+
+    let result = run_reclaim_cycles(
+        config,
+        actual_interval,
+        dry_run,
+        &database,
+        &bus,
+        &metrics,
+        &shutdown,
+    )
+    .await;
+
+    trigger.trigger();
+    if let Err(e) = passive_monitor.await {
+        error!("Passive monitor supervisor failed: {}", e);
+    }
+    bus.finish(subscribers).await;
+    lease.release();
+    result
+}
+
+/// Auto loop, Telegram bot, passive monitor and optional HTTP API in one
+/// process, sharing a database handle and event bus
+async fn run_daemon(config: &Config, interval: u64, dry_run: bool) -> error::Result<()> {
+    println!("{}", "Starting Kora reclaim daemon...".green());
+
+    let actual_interval = if interval > 0 {
+        interval
+    } else {
+        config.reclaim.scan_interval_seconds
+    };
+
+    println!("Scan interval: {} seconds", actual_interval);
+    println!("Dry run: {}", dry_run);
+
+    let database = storage::Database::new(&config.database.path)?;
+    let lease = daemon::lease::InstanceLease::acquire(&database)?;
+
+    let (trigger, shutdown) = daemon::shutdown_channel();
+    let _signals = daemon::trigger_on_signal(trigger.clone());
+    let _heartbeat = lease.spawn_heartbeat(trigger.clone());
+
+    let bus = events::EventBus::new();
+    let metrics = std::sync::Arc::new(events::EventMetrics::default());
+    let subscribers = bus.spawn_subscribers(config, metrics.clone());
+    let _live_discovery = spawn_live_discovery(config, &database)?;
+
+    let policy = daemon::supervisor::RestartPolicy::from_config(&config.daemon);
+    let supervise = |name, start| supervise_service(name, policy, &shutdown, &bus, start);
+
+    let passive_interval = config.reclaim.passive_check_interval_seconds.unwrap_or(actual_interval);
+    let mut services = vec![supervise_passive_monitor(config, passive_interval, &database, &bus, &shutdown, policy)];
+    println!("{} (every {}s)", "✓ Passive reclaim monitor".green(), passive_interval);
+
+    if config.telegram.is_some() {
+        let (bot_config, bot_db, stop) = (config.clone(), database.clone(), shutdown.clone());
+        services.push(supervise(
+            "Telegram bot",
+            Box::new(move || {
+                let stop = stop.clone();
+                Box::pin(telegram::bot::run_until(bot_config.clone(), bot_db.clone(), async move {
+                    stop.triggered().await
+                }))
+            }),
+        ));
+        println!("{}", "✓ Telegram bot and notifications".green());
+    }
+    if !config.webhooks.is_empty() {
+        println!("{} ({} endpoints)", "✓ Webhook notifications enabled".green(), config.webhooks.len());
+    }
+
+    if config.api.enabled {
+        let close_requests = match config.api.public_url {
+            Some(_) => Some(daemon::http::CloseRequests {
+                builder: reclaim::CloseRequestBuilder::new(
+                    solana::SolanaRpcClient::from_config(config),
+                    config.treasury_wallet()?,
+                ),
+                label: config.api.close_request_label.clone(),
+                icon: config.api.close_request_icon.clone(),
+            }),
+            None => None,
+        };
+        let state = daemon::http::ApiState {
+            database: database.clone(),
+            metrics: metrics.clone(),
+            started_at: std::time::Instant::now(),
+            close_requests,
+            health: daemon::health::HealthChecks {
+                rpc_client: solana::SolanaRpcClient::from_config(config),
+                max_scan_age: std::time::Duration::from_secs(
+                    config.api.max_scan_age_seconds.unwrap_or(3 * actual_interval),
+                ),
+                channels: [
+                    ("telegram", config.telegram.is_some()),
+                    ("webhook", !config.webhooks.is_empty()),
+                ]
+                .into_iter()
+                .filter_map(|(channel, configured)| configured.then_some(channel))
+                .collect(),
+            },
+        };
+        let bind = config.api.bind.clone();
+        let stop = shutdown.clone();
+        services.push(supervise(
+            "HTTP API",
+            Box::new(move || {
+                let (bind, state, stop) = (bind.clone(), state.clone(), stop.clone());
+                Box::pin(async move { daemon::http::serve(&bind, state, stop).await })
+            }),
+        ));
+        println!("{} (http://{})", "✓ HTTP API".green(), config.api.bind);
+    }
+
+    // Runs until shutdown; failures are restarted like the other services
+    let (cycle_config, cycle_db, cycle_bus, cycle_metrics, cycle_stop) =
+        (config.clone(), database.clone(), bus.clone(), metrics.clone(), shutdown.clone());
+    let reclaim_loop = supervise(
+        "Reclaim loop",
+        Box::new(move || {
+            let (config, database, bus, metrics, shutdown) = (
+                cycle_config.clone(),
+                cycle_db.clone(),
+                cycle_bus.clone(),
+                cycle_metrics.clone(),
+                cycle_stop.clone(),
+            );
+            Box::pin(async move {
+                run_reclaim_cycles(&config, actual_interval, dry_run, &database, &bus, &metrics, &shutdown).await
+            })
+        }),
+    );
+    let result = reclaim_loop.await.map_err(|e| error::ReclaimError::Other(e.into()));
+
+    trigger.trigger();
+    for service in services {
+        if let Err(e) = service.await {
+            error!("Daemon supervisor failed: {}", e);
+        }
+    }
+    bus.finish(subscribers).await;
+    lease.release();
+
+    println!("{}", "Daemon stopped".green());
+    result
+}
+
+/// Starts one run of a daemon service
+type ServiceStart =
+    Box<dyn FnMut() -> std::pin::Pin<Box<dyn std::future::Future<Output = error::Result<()>> + Send>> + Send>;
+
+/// Run a daemon service in the background, restarting it after failures and
+/// publishing each restart
+fn supervise_service(
+    name: &'static str,
+    policy: daemon::supervisor::RestartPolicy,
+    shutdown: &daemon::Shutdown,
+    bus: &events::EventBus,
+    start: ServiceStart,
+) -> tokio::task::JoinHandle<()> {
+    let bus = bus.clone();
+    daemon::supervisor::supervise(name, policy, shutdown.clone(), start, move |restart| {
+        bus.publish(events::ReclaimEvent::ServiceRestarted {
+            service: restart.service.to_string(),
+            error: restart.error.clone(),
+            consecutive: restart.consecutive,
+            backoff_seconds: restart.backoff.as_secs(),
+        });
+    })
+}
+
+/// Live discovery records new accounts between polling cycles
+fn spawn_live_discovery(
+    config: &Config,
+    database: &storage::Database,
+) -> error::Result<Option<tokio::task::JoinHandle<()>>> {
+    let operator = config.operator_pubkey()?;
+    let signatures = match config.discovery.source {
+        DiscoverySource::Polling => return Ok(None),
+        DiscoverySource::Websocket => {
+            let ws_url = config.ws_url();
+            println!("{} ({})", "✓ Live discovery via websocket".green(), ws_url);
+            solana::SignatureStream::new(&ws_url, operator, config.commitment_config()).spawn()
+        }
+        DiscoverySource::Grpc => {
+            let grpc_url = config.discovery.grpc_url.clone().ok_or_else(|| {
+                error::ReclaimError::ConfigError("discovery.source = \"grpc\" needs discovery.grpc_url".to_string())
+            })?;
+            println!("{} ({})", "✓ Live discovery via Yellowstone gRPC".green(), grpc_url);
+            let token = config.discovery.grpc_x_token.clone();
+            solana::GeyserStream::new(&grpc_url, token, operator, config.commitment_config()).spawn()
+        }
+    };
+
+    let rpc_client = solana::SolanaRpcClient::from_config(config);
+    let monitor = kora::KoraMonitor::new(rpc_client, operator)
+        .with_parsers(solana::ParserRegistry::from_config(config));
+    let db = database.clone();
+    Ok(Some(tokio::spawn(async move { monitor.watch(signatures, db).await })))
+}
+
+/// Run [`run_passive_monitor`] as a supervised service
+fn supervise_passive_monitor(
+    config: &Config,
+    interval: u64,
+    database: &storage::Database,
+    bus: &events::EventBus,
+    shutdown: &daemon::Shutdown,
+    policy: daemon::supervisor::RestartPolicy,
+) -> tokio::task::JoinHandle<()> {
+    let (config, db, service_bus, stop) = (config.clone(), database.clone(), bus.clone(), shutdown.clone());
+    supervise_service(
+        "Passive monitor",
+        policy,
+        shutdown,
+        bus,
+        Box::new(move || {
+            Box::pin(run_passive_monitor(config.clone(), interval, db.clone(), service_bus.clone(), stop.clone()))
+        }),
+    )
+}
+
+/// Check the treasury for passive reclaims every `interval` seconds, and the
+/// PassiveMonitoring accounts for closes every
+/// `reclaim.closure_watch_interval_seconds`. A close moves the treasury
+/// check up so the returned rent is attributed right away.
+async fn run_passive_monitor(
+    config: Config,
+    interval: u64,
+    database: storage::Database,
+    bus: events::EventBus,
+    shutdown: daemon::Shutdown,
+) -> error::Result<()> {
+    use std::time::Instant;
+
+    let rpc_client = solana::SolanaRpcClient::from_config(&config);
+    let monitor = treasury::TreasuryMonitor::new(config.treasury_wallet()?, rpc_client.clone(), database.clone())
+        .with_operator_keys(operator_keys(&config)?)
+        .with_matching(config.reclaim.passive_matching.clone());
+    let watcher = treasury::ClosureWatcher::new(rpc_client, database.clone())
+        .with_external_reclaims(monitor.external_reclaims());
+    let mut schedule =
+        treasury::PassiveSchedule::new(interval, config.reclaim.closure_watch_interval_seconds, Instant::now());
+
+    loop {
+        if schedule.watches_closures() && watch_closures(&watcher, &bus).await > 0 {
+            schedule.closes_found(Instant::now());
+        }
+        if schedule.treasury_due(Instant::now()) {
+            detect_passive_reclaims(&monitor, &database, &bus).await;
+        }
+
+        if shutdown.sleep(schedule.wait(Instant::now())).await {
+            return Ok(());
+        }
+    }
+}
+
+/// Announce PassiveMonitoring accounts their users closed; returns how many
+async fn watch_closures(watcher: &treasury::ClosureWatcher, bus: &events::EventBus) -> usize {
+    match watcher.check().await {
+        Ok(closed) => {
+            for account in &closed {
+                bus.publish(events::ReclaimEvent::PassiveAccountClosed {
+                    pubkey: account.pubkey.clone(),
+                    amount: account.lamports,
+                });
+            }
+            closed.len()
+        }
+        Err(e) => {
+            warn!("Failed to check PassiveMonitoring accounts for closes: {}", e);
+            0
+        }
+    }
+}
+
+/// The operator key and its retired predecessors
+fn operator_keys(config: &Config) -> error::Result<Vec<solana_sdk::pubkey::Pubkey>> {
+    let mut keys = vec![config.operator_pubkey()?];
+    keys.extend(config.retired_operator_pubkeys()?);
+    Ok(keys)
+}
+
+/// Record newly detected passive reclaims and announce them on the bus
+async fn detect_passive_reclaims(
+    monitor: &treasury::TreasuryMonitor,
+    db: &storage::Database,
+    bus: &events::EventBus,
+) {
+    match monitor.check_for_passive_reclaims().await {
+        Ok(passive_reclaims) => {
+            if !passive_reclaims.is_empty() {
+                info!("Detected {} passive reclaim(s)", passive_reclaims.len());
+
+                for reclaim in &passive_reclaims {
+                    let account_strs: Vec<String> = reclaim
+                        .attributed_accounts
+                        .iter()
+                        .map(|pk| pk.to_string())
+                        .collect();
+
+                    let confidence_str = format!("{:?}", reclaim.confidence);
+                    let saved_id = db
+                        .save_passive_reclaim(reclaim.amount, &account_strs, &confidence_str)
+                        .ok();
+
+                    bus.publish(events::ReclaimEvent::PassiveReclaimDetected {
+                        id: saved_id,
+                        amount: reclaim.amount,
+                        accounts: account_strs,
+                        confidence: confidence_str,
+                    });
                 }
-                tokio::time::sleep(tokio::time::Duration::from_secs(actual_interval)).await;
+            }
+        }
+        Err(e) => {
+            warn!("Failed to check for passive reclaims: {}", e);
+        }
+    }
+}
+
+/// Count a failure that retrying cannot fix, dead-lettering the account at the limit
+fn record_deterministic_failure(
+    db: &storage::Database,
+    pubkey: &solana_sdk::pubkey::Pubkey,
+    error: &error::ReclaimError,
+    dead_letter_after: u32,
+) {
+    match db.record_reclaim_failure(&pubkey.to_string(), &error.to_string(), dead_letter_after) {
+        Ok(true) => warn!(
+            "Dead-lettered {} after {} deterministic failures: {}",
+            pubkey, dead_letter_after, error
+        ),
+        Ok(false) => {}
+        Err(e) => warn!("Failed to record reclaim failure for {}: {}", pubkey, e),
+    }
+}
+
+/// Daily self-heal: repair what can be repaired and alert admins about any findings
+fn run_integrity_check(db: &storage::Database, bus: &events::EventBus) {
+    match db.check_integrity(true) {
+        Ok(report) if report.is_healthy() => info!("Database integrity check passed"),
+        Ok(report) => {
+            warn!("Database integrity check: {}", report.summary());
+            bus.publish(events::ReclaimEvent::Error {
+                message: format!("Database integrity check: {}", report.summary()),
+            });
+        }
+        Err(e) => {
+            error!("Database integrity check failed: {}", e);
+            bus.publish(events::ReclaimEvent::Error {
+                message: format!("Database integrity check failed: {}", e),
+            });
+        }
+    }
+    if let Err(e) = db.mark_integrity_checked() {
+        warn!("Failed to record the integrity check: {}", e);
+    }
+}
+
+/// Whether this cycle's RPC budget is spent, logging and reporting it if so;
+/// the cycle then skips its remaining steps until the next interval
+fn rpc_budget_spent(
+    budget: &solana::budget::RpcBudget,
+    bus: &events::EventBus,
+    metrics: &events::EventMetrics,
+    stage: &str,
+) -> bool {
+    if !budget.is_exhausted() {
+        return false;
+    }
+    warn!("RPC budget spent {}: {}; pausing until the next cycle", stage, budget.summary());
+    bus.publish(events::ReclaimEvent::Error {
+        message: format!("RPC budget spent {}: {}", stage, budget.summary()),
+    });
+    metrics.record_cycle_rpc_calls(budget.total());
+    true
+}
+
+/// Discover and save accounts sponsored by `kora.retired_operator_pubkeys`,
+/// each scanned incrementally from its own checkpoint
+async fn scan_retired_operators(
+    config: &Config,
+    monitor: &kora::KoraMonitor,
+    db: &storage::Database,
+    max_transactions: usize,
+) -> Vec<kora::types::SponsoredAccountInfo> {
+    let retired = match config.retired_operator_pubkeys() {
+        Ok(retired) => retired,
+        Err(e) => {
+            warn!("Skipping retired operator keys: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut discovered = Vec::new();
+    for operator in retired {
+        let key = operator.to_string();
+        let since_signature = db.get_operator_checkpoint(&key).unwrap_or_default();
+        let accounts = match monitor.for_operator(operator).scan_new_accounts(since_signature, max_transactions).await {
+            Ok(accounts) => accounts,
+            Err(e) => {
+                warn!("Failed to scan retired operator key {}: {}", key, e);
                 continue;
             }
         };
 
-        let monitor = kora::KoraMonitor::new(rpc_client.clone(), operator_pubkey);
+        let db_accounts: Vec<storage::models::SponsoredAccount> =
+            accounts.iter().map(storage::models::SponsoredAccount::from).collect();
+        if let Err(e) = db.save_accounts_batch(&db_accounts) {
+            warn!("Failed to save accounts of retired operator key {}: {}", key, e);
+            continue;
+        }
+        if let Some(latest) = accounts.first() {
+            let _ = db.save_operator_checkpoint(&key, &latest.creation_signature.to_string());
+        }
+        if !accounts.is_empty() {
+            info!("Found {} accounts sponsored by retired operator key {}", accounts.len(), key);
+        }
+        discovered.extend(accounts);
+    }
+    discovered
+}
 
-        // ✅ FIX: Use incremental scanning with checkpoints
-        let db = match storage::Database::new(&config.database.path) {
-            Ok(database) => database,
+/// Re-check the accounts routine cycles skip as Unrecoverable
+async fn run_unrecoverable_audit(
+    refresher: &kora::BalanceRefresher,
+    eligibility_checker: &reclaim::EligibilityChecker,
+    db: &storage::Database,
+) {
+    match refresher.audit_unrecoverable(eligibility_checker).await {
+        Ok(audit) if !audit.reclassified.is_empty() => info!(
+            "{} unrecoverable accounts were reclassified and rejoin routine cycles",
+            audit.reclassified.len()
+        ),
+        Ok(_) => {}
+        Err(e) => warn!("Unrecoverable account audit failed: {}", e),
+    }
+    if let Err(e) = db.mark_unrecoverable_audited() {
+        warn!("Failed to record the unrecoverable audit: {}", e);
+    }
+}
+
+/// Scan, refresh and reclaim every `interval` seconds until shutdown.
+/// The treasury is watched separately by [`run_passive_monitor`].
+async fn run_reclaim_cycles(
+    file_config: &Config,
+    interval: u64,
+    dry_run: bool,
+    database: &storage::Database,
+    bus: &events::EventBus,
+    metrics: &events::EventMetrics,
+    shutdown: &daemon::Shutdown,
+) -> error::Result<()> {
+    let actual_interval = std::time::Duration::from_secs(interval);
+    let schedule = reclaim::schedule::ReclaimSchedule::from_config(&file_config.reclaim.schedule)?;
+    let mut rpc_health = solana::health::RpcHealth::default();
+
+    while !shutdown.is_triggered() {
+        info!("Running reclaim cycle...");
+
+        // Pick up /settings changes made since the last cycle
+        let runtime = database.get_runtime_settings().unwrap_or_default();
+        let mut cycle_config = file_config.clone();
+        runtime.apply(&mut cycle_config);
+        let config = &cycle_config;
+        let actual_dry_run = dry_run || config.reclaim.dry_run;
+
+        // Initialize clients; every clone shares this cycle's RPC budget
+        let rpc_budget = std::sync::Arc::new(solana::budget::RpcBudget::new(config.solana.cycle_rpc_budget));
+        let rpc_client = solana::SolanaRpcClient::from_config(config).with_budget(rpc_budget.clone());
+
+        // A down endpoint gets one alert instead of an error every cycle,
+        // and is checked again sooner than the next interval
+        let now = chrono::Utc::now();
+        match rpc_client.ping().await {
+            Ok(_) => {
+                if let Some(downtime) = rpc_health.record_success(now) {
+                    info!("RPC endpoint recovered after {} minutes", downtime.num_minutes());
+                    bus.publish(events::ReclaimEvent::RpcRecovered { down_minutes: downtime.num_minutes() });
+                }
+            }
             Err(e) => {
-                error!("Failed to open database: {}", e);
-                if let Some(ref n) = notifier {
-                    n.notify_error(&format!("Database error: {}", e)).await;
+                let degraded_after = chrono::Duration::minutes(config.solana.degraded_after_minutes as i64);
+                if rpc_health.record_failure(now, degraded_after) {
+                    let down_minutes = rpc_health.down_since().map_or(0, |since| (now - since).num_minutes());
+                    error!("RPC endpoint down for {} minutes, pausing cycles: {}", down_minutes, e);
+                    bus.publish(events::ReclaimEvent::RpcDegraded { error: e.to_string(), down_minutes });
+                } else {
+                    warn!("RPC endpoint unreachable, skipping this cycle: {}", e);
                 }
-                tokio::time::sleep(tokio::time::Duration::from_secs(actual_interval)).await;
+                shutdown.sleep(actual_interval.min(solana::health::RETRY_INTERVAL)).await;
+                continue;
+            }
+        }
+
+        let operator_pubkey = match config.operator_pubkey() {
+            Ok(pk) => pk,
+            Err(e) => {
+                error!("Failed to get operator pubkey: {}", e);
+                bus.publish(events::ReclaimEvent::Error {
+                    message: format!("Failed to get operator pubkey: {}", e),
+                });
+                shutdown.sleep(actual_interval).await;
                 continue;
             }
         };
 
+        // ✅ FIX: Use incremental scanning with checkpoints
+        let db = database.clone();
+
+        let monitor = kora::KoraMonitor::new(rpc_client.clone(), operator_pubkey)
+            .with_parsers(solana::ParserRegistry::from_config(config))
+            .with_floor_slot(config.reclaim.scan_floor_slot)
+            .with_cache(db.clone());
+
+        match db.bind_checkpoint_operator(&operator_pubkey.to_string()) {
+            Ok(Some(previous)) => info!(
+                "Operator key changed from {} to {}; scanning the new key from scratch \
+                 (list the old one in kora.retired_operator_pubkeys to keep scanning it)",
+                previous, operator_pubkey
+            ),
+            Ok(None) => {}
+            Err(e) => warn!("Failed to check the checkpoint's operator key: {}", e),
+        }
+
         // ✅ Get last checkpoint signature for incremental scanning
         let since_signature = match db.get_last_processed_signature() {
             Ok(sig) => sig,
@@ -707,15 +2151,25 @@ async fn run_auto_service(config: &Config, interval: u64, dry_run: bool) -> erro
         };
 
         // Discover new accounts (scan incrementally if checkpoint exists)
-        let sponsored_accounts = match monitor.scan_new_accounts(since_signature, 5000).await {
+        let mut sponsored_accounts = match monitor.scan_new_accounts(since_signature, 5000).await {
             Ok(accounts) => accounts,
             Err(e) => {
-                warn!("Failed to discover accounts: {}", e);
-                if let Some(ref n) = notifier {
-                    n.notify_error(&format!("Account discovery failed: {}", e))
-                        .await;
+                if rpc_budget_spent(&rpc_budget, bus, metrics, "during discovery") {
+                    shutdown.sleep(actual_interval).await;
+                    continue;
                 }
-                tokio::time::sleep(tokio::time::Duration::from_secs(actual_interval)).await;
+                // The monitor already recorded the failed session
+                let streak = db.get_scan_failure_streak().unwrap_or_default();
+                warn!(
+                    "Failed to discover accounts ({} in a row): {}",
+                    streak.consecutive_failures, e
+                );
+                bus.publish(events::ReclaimEvent::ScanFailed {
+                    error: e.to_string(),
+                    consecutive_failures: streak.consecutive_failures,
+                    escalated: streak.is_escalated(config.reclaim.scan_failure_alert_after),
+                });
+                shutdown.sleep(actual_interval).await;
                 continue;
             }
         };
@@ -726,18 +2180,7 @@ async fn run_auto_service(config: &Config, interval: u64, dry_run: bool) -> erro
         if !sponsored_accounts.is_empty() {
             let db_accounts: Vec<storage::models::SponsoredAccount> = sponsored_accounts
                 .iter()
-                .map(|account_info| storage::models::SponsoredAccount {
-                    pubkey: account_info.pubkey.to_string(),
-                    created_at: account_info.created_at,
-                    closed_at: None,
-                    rent_lamports: account_info.rent_lamports,
-                    data_size: account_info.data_size,
-                    status: storage::models::AccountStatus::Active,
-                    creation_signature: Some(account_info.creation_signature.to_string()),
-                    creation_slot: Some(account_info.creation_slot),
-                    close_authority: None,
-                    reclaim_strategy: None,
-                })
+                .map(storage::models::SponsoredAccount::from)
                 .collect();
 
             match db.save_accounts_batch(&db_accounts) {
@@ -753,12 +2196,85 @@ async fn run_auto_service(config: &Config, interval: u64, dry_run: bool) -> erro
             }
         }
 
+        // Accounts of retired operator keys keep their attribution
+        sponsored_accounts.extend(scan_retired_operators(config, &monitor, &db, 5000).await);
+
+        if rpc_budget_spent(&rpc_budget, bus, metrics, "after discovery") {
+            shutdown.sleep(actual_interval).await;
+            continue;
+        }
+
+        // New accounts stay Provisional until their creation is finalized
+        let finality = kora::FinalityChecker::new(rpc_client.clone(), db.clone());
+        match finality.confirm_provisional_accounts().await {
+            Ok(summary) if !summary.dropped.is_empty() => warn!(
+                "Removed {} phantom accounts whose creation was never finalized",
+                summary.dropped.len()
+            ),
+            Ok(_) => {}
+            Err(e) => warn!("Finality check failed: {}", e),
+        }
+
+        // Keep stored balances current for every tracked active account;
+        // Unrecoverable ones wait for the periodic audit below
+        let refresher = kora::BalanceRefresher::new(rpc_client.clone(), db.clone());
+        match refresher.refresh_routine_accounts().await {
+            Ok(summary) if !summary.discrepancies.is_empty() => info!(
+                "Balance refresh flagged {} discrepancies ({} missing on-chain)",
+                summary.discrepancies.len(),
+                summary.missing_count()
+            ),
+            Ok(_) => {}
+            Err(e) => warn!("Balance refresh failed: {}", e),
+        }
+
+        // First cycle of each (UTC) day records the trend snapshot
+        let today = chrono::Utc::now().date_naive();
+        match db.get_snapshot_on_or_before(today) {
+            Ok(Some(latest)) if latest.date == today => {}
+            Ok(_) => match db.take_snapshot() {
+                Ok(snapshot) => info!(
+                    "Recorded daily snapshot: {} active accounts, {} locked",
+                    snapshot.active_accounts,
+                    utils::format_sol(snapshot.locked_lamports)
+                ),
+                Err(e) => warn!("Failed to record daily snapshot: {}", e),
+            },
+            Err(e) => warn!("Failed to read snapshots: {}", e),
+        }
+
+        if config.database.integrity_check && db.last_integrity_check().ok().flatten() != Some(today) {
+            run_integrity_check(&db, bus);
+        }
+
         // Check eligibility
         let eligibility_checker =
-            reclaim::EligibilityChecker::new(rpc_client.clone(), config.clone());
-        let mut eligible = Vec::new();
+            reclaim::EligibilityChecker::new(rpc_client.clone(), config.clone()).with_holds(db.clone());
+
+        let audit_days = config.reclaim.unrecoverable_audit_days;
+        if audit_days > 0
+            && db.last_unrecoverable_audit().ok().flatten().is_none_or(|at| {
+                chrono::Utc::now() - at >= chrono::Duration::days(audit_days as i64)
+            })
+        {
+            run_unrecoverable_audit(&refresher, &eligibility_checker, &db).await;
+        }
+
+        if rpc_budget_spent(&rpc_budget, bus, metrics, "before eligibility checks") {
+            shutdown.sleep(actual_interval).await;
+            continue;
+        }
+
+        let dead_lettered = db.get_dead_lettered_pubkeys().unwrap_or_default();
 
+        let mut candidates = Vec::new();
         for account_info in &sponsored_accounts {
+            // Repeated deterministic failures; restored with `dead-letter restore`
+            if dead_lettered.contains(&account_info.pubkey.to_string()) {
+                debug!("Skipping dead-lettered account {}", account_info.pubkey);
+                continue;
+            }
+
             // ✅ Check if account already exists to avoid re-processing
             if let Ok(true) = db.account_exists(&account_info.pubkey.to_string()) {
                 if let Ok(Some(db_account)) =
@@ -768,115 +2284,177 @@ async fn run_auto_service(config: &Config, interval: u64, dry_run: bool) -> erro
                     if db_account.status == storage::models::AccountStatus::Reclaimed {
                         continue;
                     }
+                    // Left to the periodic audit
+                    if db_account.reclaim_strategy == Some(storage::models::ReclaimStrategy::Unrecoverable) {
+                        continue;
+                    }
+                    // Creation not finalized yet; a fork may still drop it
+                    if db_account.status == storage::models::AccountStatus::Provisional {
+                        continue;
+                    }
                 }
             }
 
+            candidates.push(account_info);
+        }
+
+        // Closed and empty accounts are dropped in one batched pass; the
+        // refresher marks them closed
+        let pubkeys: Vec<_> = candidates.iter().map(|account| account.pubkey).collect();
+        let prescreen = match eligibility_checker.prescreen(&pubkeys).await {
+            Ok(prescreen) => prescreen,
+            Err(e) => {
+                warn!("Batched prescreen failed, fetching accounts one at a time: {}", e);
+                eligibility_checker.prescreen_each(&pubkeys).await
+            }
+        };
+
+        let mut eligible = Vec::new();
+        for (index, account_info) in candidates.iter().enumerate() {
+            let Some(account) = prescreen.get(index) else {
+                if !prescreen.is_gone(index) {
+                    debug!("Could not fetch {}, leaving it for the next cycle", account_info.pubkey);
+                }
+                continue;
+            };
             if let Ok(true) = eligibility_checker
-                .is_eligible(&account_info.pubkey, account_info.created_at)
+                .is_eligible_account(&account_info.pubkey, account, account_info.created_at)
                 .await
             {
+                bus.publish(events::ReclaimEvent::AccountEligible {
+                    pubkey: account_info.pubkey.to_string(),
+                });
                 eligible.push((account_info.pubkey, account_info.account_type.clone()));
             }
         }
 
-        // Notify scan complete
-        if let Some(ref n) = notifier {
-            n.notify_scan_complete(sponsored_accounts.len(), eligible.len())
-                .await;
+        bus.publish(events::ReclaimEvent::ScanCompleted {
+            total: sponsored_accounts.len(),
+            eligible: eligible.len(),
+        });
+
+        if rpc_budget_spent(&rpc_budget, bus, metrics, "before reclaiming") {
+            shutdown.sleep(actual_interval).await;
+            continue;
         }
 
-        if !eligible.is_empty() {
-            info!("Found {} eligible accounts", eligible.len());
+        // High-value accounts are simulated and announced first, and join a
+        // later batch once their delay passes or they are approved
+        let staging = &config.reclaim.staging;
+        let staged = db.get_staged_reclaims().unwrap_or_default();
+        let rent: std::collections::HashMap<_, _> =
+            sponsored_accounts.iter().map(|account| (account.pubkey, account.rent_lamports)).collect();
+        let reclaim::staging::StagingSplit { ready: mut eligible, to_stage } = reclaim::staging::split(
+            eligible,
+            |pubkey| rent.get(pubkey).copied().unwrap_or_default(),
+            staging.threshold_lamports(),
+            &staged.iter().map(|reclaim| reclaim.pubkey.clone()).collect(),
+        );
+        let released = release_staged_reclaims(&staged, staging, &eligibility_checker, &db).await;
+        eligible.extend(released.iter().map(|(pubkey, account_type, _)| (*pubkey, account_type.clone())));
+        let pending = eligible.len() + to_stage.len();
 
-            // Load treasury and reclaim
-            let treasury_keypair = match config.load_treasury_keypair() {
-                Ok(kp) => kp,
+        // The service reclaims by default; an explicit runtime "off" pauses it
+        if pending > 0 && runtime.auto_reclaim_enabled == Some(false) {
+            info!(
+                "Auto reclaim disabled via runtime settings, skipping {} eligible accounts",
+                pending
+            );
+        } else if pending > 0 && !schedule.is_open_now() {
+            // Eligible accounts stay queued in the database for the next cycle inside a window
+            info!(
+                "Outside the reclaim schedule, holding {} eligible accounts until a window opens",
+                pending
+            );
+        } else if pending > 0 {
+            info!("Found {} eligible accounts", pending);
+
+            // Load signers and reclaim
+            let signers = reclaim::signer::from_config(config)
+                .and_then(|signer| Ok((signer, reclaim::signer::fee_payer_from_config(config)?)));
+            let (signer, fee_payer) = match signers {
+                Ok(signers) => signers,
                 Err(e) => {
-                    error!("Failed to load treasury keypair: {}", e);
-                    if let Some(ref n) = notifier {
-                        n.notify_error(&format!("Failed to load treasury keypair: {}", e))
-                            .await;
-                    }
-                    tokio::time::sleep(tokio::time::Duration::from_secs(actual_interval)).await;
+                    error!("Failed to load signer: {}", e);
+                    bus.publish(events::ReclaimEvent::Error {
+                        message: format!("Failed to load signer: {}", e),
+                    });
+                    shutdown.sleep(actual_interval).await;
                     continue;
                 }
             };
 
-            let treasury_wallet = config.treasury_wallet()?;
             let engine = reclaim::ReclaimEngine::new(
                 rpc_client.clone(),
-                treasury_wallet,
-                treasury_keypair,
+                config.reclaim_destination()?,
+                signer,
                 actual_dry_run,
-            );
-
-            // In run_auto_service(), add after the main reclaim logic:
-
-            // Check for passive reclaims
-            let treasury_wallet = config.treasury_wallet()?;
-            let treasury_monitor =
-                treasury::TreasuryMonitor::new(treasury_wallet, rpc_client.clone(), db.clone());
-
-            match treasury_monitor.check_for_passive_reclaims().await {
-                Ok(passive_reclaims) => {
-                    if !passive_reclaims.is_empty() {
-                        info!("Detected {} passive reclaim(s)", passive_reclaims.len());
-
-                        for reclaim in &passive_reclaims {
-                            let account_strs: Vec<String> = reclaim
-                                .attributed_accounts
-                                .iter()
-                                .map(|pk| pk.to_string())
-                                .collect();
-
-                            let confidence_str = format!("{:?}", reclaim.confidence);
-                            let _ = db.save_passive_reclaim(
-                                reclaim.amount,
-                                &account_strs,
-                                &confidence_str,
-                            );
-
-                            // Notify
-                            if let Some(ref n) = notifier {
-                                n.notify_passive_reclaim(
-                                    reclaim.amount,
-                                    &account_strs,
-                                    &confidence_str,
-                                )
-                                .await;
-                            }
-                        }
-                    }
-                }
-                Err(e) => {
-                    warn!("Failed to check for passive reclaims: {}", e);
+            )
+            .with_fee_payer(fee_payer)
+            .with_close_adapters(reclaim::CloseAdapters::from_config(config))
+            .with_fees(config.reclaim.fees.clone())
+            .with_memo(config.reclaim.memo.clone(), storage::models::ReclaimReason::AutoBatch);
+
+            if actual_dry_run && !to_stage.is_empty() {
+                info!("Dry run: would stage {} high-value reclaims", to_stage.len());
+            } else {
+                for (pubkey, account_type) in &to_stage {
+                    stage_reclaim(&engine, &db, bus, staging, pubkey, account_type).await;
                 }
             }
 
-            let batch_processor = reclaim::BatchProcessor::new(
-                engine,
-                config.reclaim.batch_size,
-                config.reclaim.batch_delay_ms,
-            );
+            if !eligible.is_empty() {
+                let batch_processor = reclaim::BatchProcessor::new(
+                    engine,
+                    config.reclaim.batch_size,
+                    config.reclaim.batch_delay_ms,
+                )
+                .with_adaptive(&config.reclaim.adaptive);
+
+                // The plan is logged by execute(); an over-limit plan aborts with an error
+                let outcome = match batch_processor.plan(&eligible).await {
+                    Ok(plan) => {
+                        if config.reclaim.fees.notify_plan {
+                            bus.publish(events::ReclaimEvent::BatchPlanned {
+                                transactions: plan.transactions,
+                                estimated_cost: plan.estimated_cost(),
+                                expected_recovery: plan.expected_recovery_lamports,
+                                aborted: plan.exceeds(config.reclaim.fees.max_cost_percent),
+                            });
+                        }
+                        batch_processor.execute(&plan, eligible).await
+                    }
+                    Err(e) => Err(e),
+                };
 
-            match batch_processor.reclaim_all_eligible(eligible).await {
-                Ok(summary) => {
-                    info!(
-                        "Batch complete: {} successful, {} failed, {} SOL reclaimed",
-                        summary.successful,
-                        summary.failed,
-                        solana::rent::RentCalculator::lamports_to_sol(summary.total_reclaimed)
-                    );
+                match outcome {
+                    Ok(summary) => {
+                        info!(
+                            "Batch complete: {} successful, {} failed, {} SOL reclaimed",
+                            summary.successful,
+                            summary.failed,
+                            solana::rent::RentCalculator::lamports_to_sol(summary.total_reclaimed)
+                        );
 
-                    if summary.successful > 0 {
                         for (pubkey, result) in &summary.results {
+                            // A released staged reclaim is attempted once, like any batch entry
+                            let staged = released.iter().find(|(staged, ..)| staged == pubkey);
+                            if staged.is_some() && !actual_dry_run {
+                                let _ = db.remove_staged_reclaim(&pubkey.to_string());
+                            }
+                            let reason = match staged {
+                                Some((_, _, true)) => storage::models::ReclaimReason::Approved,
+                                _ => storage::models::ReclaimReason::AutoBatch,
+                            };
                             if let Ok(reclaim_result) = result {
                                 if let Some(sig) = reclaim_result.signature {
                                     // Update account status
-                                    let _ = db.update_account_status(
+                                    if let Err(e) = db.update_account_status(
                                         &pubkey.to_string(),
                                         storage::models::AccountStatus::Reclaimed,
-                                    );
+                                    ) {
+                                        warn!("{}", e);
+                                    }
 
                                     // Save reclaim operation
                                     let _ = db.save_reclaim_operation(
@@ -886,52 +2464,56 @@ async fn run_auto_service(config: &Config, interval: u64, dry_run: bool) -> erro
                                             reclaimed_amount: reclaim_result.amount_reclaimed,
                                             tx_signature: sig.to_string(),
                                             timestamp: chrono::Utc::now(),
-                                            reason: "Automated batch reclaim".to_string(),
+                                            reason,
+                                            credited_amount: reclaim_result.credited_amount(),
+                                            destination: Some(reclaim_result.destination.to_string()),
+                                            fee_lamports: reclaim_result.fee_lamports(),
                                         },
                                     );
 
-                                    // Send individual success notification for high-value reclaims
-                                    if let Some(ref n) = notifier {
-                                        if let Some(tg_config) = &config.telegram {
-                                            n.notify_high_value_reclaim(
-                                                &pubkey.to_string(),
-                                                reclaim_result.amount_reclaimed,
-                                                tg_config.alert_threshold_sol,
-                                            )
-                                            .await;
-                                        }
+                                    bus.publish(events::ReclaimEvent::ReclaimSucceeded {
+                                        pubkey: pubkey.to_string(),
+                                        amount: reclaim_result.amount_reclaimed,
+                                        signature: sig.to_string(),
+                                        batched: true,
+                                    });
+                                    if let Some(mismatch) = reclaim_result.credit_mismatch_event() {
+                                        bus.publish(mismatch);
                                     }
                                 }
                             } else if let Err(e) = result {
-                                // Notify failure
-                                if let Some(ref n) = notifier {
-                                    n.notify_reclaim_failed(&pubkey.to_string(), &e.to_string())
-                                        .await;
+                                if e.is_deterministic() {
+                                    record_deterministic_failure(&db, pubkey, e, config.reclaim.dead_letter_after);
                                 }
+                                bus.publish(events::ReclaimEvent::ReclaimFailed {
+                                    pubkey: pubkey.to_string(),
+                                    error: e.to_string(),
+                                    retryable: e.is_retryable(),
+                                });
                             }
                         }
-                        info!(
-                            "Saved {} reclaim operations to database",
-                            summary.successful
-                        );
-                    }
+                        if summary.successful > 0 {
+                            info!(
+                                "Saved {} reclaim operations to database",
+                                summary.successful
+                            );
+                        }
 
-                    // Send batch summary notification
-                    if let Some(ref n) = notifier {
-                        let total_sol =
-                            solana::rent::RentCalculator::lamports_to_sol(summary.total_reclaimed);
-                        n.notify_batch_complete(summary.successful, summary.failed, total_sol)
-                            .await;
-                    }
+                        // Send batch summary notification
+                        bus.publish(events::ReclaimEvent::BatchCompleted {
+                            successful: summary.successful,
+                            failed: summary.failed,
+                            total_reclaimed: summary.total_reclaimed,
+                        });
 
-                    // Print summary
-                    summary.print_summary();
-                }
-                Err(e) => {
-                    warn!("Batch processing failed: {}", e);
-                    if let Some(ref n) = notifier {
-                        n.notify_error(&format!("Batch processing failed: {}", e))
-                            .await;
+                        // Print summary
+                        summary.print_summary();
+                    }
+                    Err(e) => {
+                        warn!("Batch processing failed: {}", e);
+                        bus.publish(events::ReclaimEvent::Error {
+                            message: format!("Batch processing failed: {}", e),
+                        });
                     }
                 }
             }
@@ -939,11 +2521,111 @@ async fn run_auto_service(config: &Config, interval: u64, dry_run: bool) -> erro
             info!("No eligible accounts found");
         }
 
-        tokio::time::sleep(tokio::time::Duration::from_secs(actual_interval)).await;
+        metrics.record_cycle_rpc_calls(rpc_budget.total());
+        info!("Cycle used {}", rpc_budget.summary());
+        info!("Service metrics: {}", metrics.snapshot());
+
+        shutdown.sleep(actual_interval).await;
+    }
+
+    info!("Reclaim service stopped");
+    Ok(())
+}
+/// Simulate a high-value reclaim and stage it for `reclaim.staging`
+async fn stage_reclaim(
+    engine: &reclaim::ReclaimEngine,
+    db: &storage::Database,
+    bus: &events::EventBus,
+    staging: &StagingConfig,
+    pubkey: &solana_sdk::pubkey::Pubkey,
+    account_type: &kora::AccountType,
+) {
+    let simulation = match engine.simulate_reclaim(pubkey, account_type).await {
+        Ok(simulation) => simulation,
+        Err(e) => {
+            warn!("Failed to simulate the reclaim of {}, not staging it: {}", pubkey, e);
+            return;
+        }
+    };
+
+    let now = chrono::Utc::now();
+    let staged = storage::models::StagedReclaim {
+        pubkey: pubkey.to_string(),
+        amount: simulation.amount,
+        staged_at: now,
+        execute_after: now + chrono::Duration::minutes(staging.delay_minutes as i64),
+        units_consumed: simulation.units_consumed,
+        simulation_error: simulation.error,
+        approved_at: None,
+        approved_by: None,
+    };
+    match db.stage_reclaim(&staged) {
+        Ok(true) => {
+            info!("Staged the reclaim of {} ({})", pubkey, utils::format_sol(staged.amount));
+            bus.publish(events::ReclaimEvent::ReclaimStaged {
+                pubkey: staged.pubkey,
+                amount: staged.amount,
+                execute_after: staged.execute_after,
+                requires_approval: staging.require_approval,
+                units_consumed: staged.units_consumed,
+                simulation_error: staged.simulation_error,
+            });
+        }
+        Ok(false) => {}
+        Err(e) => warn!("Failed to stage the reclaim of {}: {}", pubkey, e),
+    }
+}
+
+/// Staged reclaims released by their delay or an approval that are still
+/// eligible, flagged when approved. Those no longer eligible are dropped.
+async fn release_staged_reclaims(
+    staged: &[storage::models::StagedReclaim],
+    staging: &StagingConfig,
+    checker: &reclaim::EligibilityChecker,
+    db: &storage::Database,
+) -> Vec<(solana_sdk::pubkey::Pubkey, kora::AccountType, bool)> {
+    let now = chrono::Utc::now();
+    let mut released = Vec::new();
+
+    // Turning staging off releases whatever was still waiting
+    let staging_off = staging.threshold_sol.is_none();
+    for reclaim in staged {
+        if !staging_off && !reclaim.is_released(now, staging.require_approval) {
+            continue;
+        }
+        let account = db.get_account_by_pubkey(&reclaim.pubkey).ok().flatten();
+        let pubkey = reclaim.pubkey.parse::<solana_sdk::pubkey::Pubkey>().ok();
+        let (Some(account), Some(pubkey)) = (account, pubkey) else {
+            let _ = db.remove_staged_reclaim(&reclaim.pubkey);
+            continue;
+        };
+
+        match checker.is_eligible(&pubkey, account.created_at).await {
+            Ok(true) => released.push((
+                pubkey,
+                account.account_type.unwrap_or(kora::AccountType::SplToken),
+                reclaim.approved_at.is_some(),
+            )),
+            Ok(false) => {
+                info!("Staged account {} is no longer eligible, dropping its reclaim", reclaim.pubkey);
+                let _ = db.remove_staged_reclaim(&reclaim.pubkey);
+            }
+            Err(e) => warn!("Failed to re-check staged account {}: {}", reclaim.pubkey, e),
+        }
     }
+
+    released
 }
-async fn show_stats(config: &Config, format: &str, total_only: bool) -> error::Result<()> {
+
+async fn show_stats(
+    config: &Config,
+    format: &str,
+    total_only: bool,
+    compare_days: Option<i64>,
+) -> error::Result<()> {
     let db = storage::Database::new(&config.database.path)?;
+    let sol_usd = current_sol_usd(config).await;
+    let usd = |lamports: u64| sol_usd.map(|sol_usd| price::lamports_to_usd(lamports, sol_usd));
 
     // ✅ USE: get_total_reclaimed for lightweight query
     if total_only {
@@ -953,19 +2635,22 @@ async fn show_stats(config: &Config, format: &str, total_only: bool) -> error::R
                 "{}",
                 serde_json::json!({
                     "total_reclaimed": total,
-                    "total_reclaimed_sol": utils::format_sol(total)
+                    "total_reclaimed_sol": utils::format_sol(total),
+                    "total_reclaimed_usd": usd(total),
                 })
             );
         } else {
             println!(
-                "Total Reclaimed: {}",
-                utils::format_sol(total).green().bold()
+                "Total Reclaimed: {}{}",
+                utils::format_sol(total).green().bold(),
+                usd_suffix(total, sol_usd)
             );
         }
         return Ok(());
     }
 
     let stats = db.get_stats()?;
+    let scan_failures = db.get_scan_failure_streak().unwrap_or_default();
 
     if format == "json" {
         // JSON output with passive reclaims
@@ -976,6 +2661,7 @@ async fn show_stats(config: &Config, format: &str, total_only: bool) -> error::R
             .collect();
 
         let passive_total = db.get_total_passive_reclaimed().unwrap_or(0);
+        let passive_pending = db.get_total_pending_passive_reclaimed().unwrap_or(0);
 
         let active_accounts = db
             .get_accounts_by_strategy("ActiveReclaim")
@@ -991,12 +2677,60 @@ async fn show_stats(config: &Config, format: &str, total_only: bool) -> error::R
         let passive_rent: u64 = passive_accounts.iter().map(|a| a.rent_lamports).sum();
         let unrecoverable_rent: u64 = unrecoverable.iter().map(|a| a.rent_lamports).sum();
 
+        let trend: Vec<serde_json::Value> = db
+            .current_totals()
+            .map(|current| trend_deltas(&db, &current))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(label, delta)| {
+                serde_json::json!({
+                    "period": label,
+                    "since": delta.since.to_string(),
+                    "active_accounts": delta.active_accounts,
+                    "locked_lamports": delta.locked_lamports,
+                    "reclaimed_lamports": delta.reclaimed_lamports,
+                })
+            })
+            .collect();
+
         let json_output = serde_json::json!({
             "stats": stats,
+            "usd": sol_usd.map(|sol_usd| serde_json::json!({
+                "sol_usd": sol_usd,
+                "source": config.price.source.to_string(),
+                "total_reclaimed": usd(stats.total_reclaimed),
+                "passive_reclaimed": usd(passive_total),
+                "locked": usd(active_rent + passive_rent + unrecoverable_rent),
+            })),
+            "reclaims_by_reason": db
+                .get_reclaim_totals_by_reason()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(reason, count, amount)| {
+                    serde_json::json!({ "reason": reason, "operations": count, "total_reclaimed": amount })
+                })
+                .collect::<Vec<_>>(),
+            "credit_mismatches": db.get_credit_mismatches().unwrap_or_default(),
+            "ledger": db.verify_ledger().ok(),
+            "locked_by_mint": db.get_locked_rent_by_mint().unwrap_or_default(),
+            "by_operator": db.get_operator_totals().unwrap_or_default(),
+            "trend": trend,
+            "comparison": compare_days.map(|days| match snapshot_comparison(&db, days) {
+                Some((earlier, changes)) => serde_json::json!({
+                    "days": days,
+                    "since": earlier.date.to_string(),
+                    "metrics": changes,
+                }),
+                None => serde_json::json!({ "days": days, "since": null, "metrics": [] }),
+            }),
+            "lifetime": db.get_lifetime_report().ok(),
             "checkpoints": checkpoint_map,
+            "scan_failures": &scan_failures,
             "passive_reclaims": {
                 "total_amount": passive_total,
                 "total_amount_sol": crate::solana::rent::RentCalculator::lamports_to_sol(passive_total),
+                "pending_review_amount": passive_pending,
+                "pending_review_amount_sol": crate::solana::rent::RentCalculator::lamports_to_sol(passive_pending),
             },
             "reclaim_strategies": {
                 "active_reclaim": {
@@ -1022,25 +2756,57 @@ async fn show_stats(config: &Config, format: &str, total_only: bool) -> error::R
     }
 
     // Enhanced table format
-    println!("{}", "=== Kora Rent Reclaim Statistics ===".cyan().bold());
+    println!("{}", format!("=== {} ===", t(Text::StatsTitle)).cyan().bold());
+
+    if scan_failures.is_failing() {
+        let warning = format!(
+            "⚠ {}: {} ({}: {})",
+            t(Text::ScanFailures),
+            tf(Text::FailuresInARow, &[&scan_failures.consecutive_failures]),
+            t(Text::LastScanError),
+            scan_failures.last_error.as_deref().unwrap_or_default()
+        );
+        if scan_failures.is_escalated(config.reclaim.scan_failure_alert_after) {
+            println!("\n{}", warning.red().bold());
+        } else {
+            println!("\n{}", warning.yellow());
+        }
+    }
 
-    println!("\n{}", "Accounts:".cyan());
-    println!("  Total:      {}", stats.total_accounts);
+    if let Ok(ledger) = db.verify_ledger() {
+        if !ledger.is_intact() {
+            println!(
+                "\n{}",
+                format!(
+                    "⚠ {}",
+                    tf(Text::LedgerTampered, &[&ledger.tampered.len(), &"kora-reclaim verify-ledger"])
+                )
+                .red()
+                .bold()
+            );
+        }
+    }
+
+    println!("\n{}", format!("{}:", t(Text::Accounts)).cyan());
+    println!("  {} {}", label(Text::Total, 13), stats.total_accounts);
     println!(
-        "  Active:     {}",
+        "  {} {}",
+        label(Text::Active, 13),
         stats.active_accounts.to_string().green()
     );
     println!(
-        "  Closed:     {}",
+        "  {} {}",
+        label(Text::Closed, 13),
         stats.closed_accounts.to_string().yellow()
     );
     println!(
-        "  Reclaimed:  {}",
+        "  {} {}",
+        label(Text::Reclaimed, 13),
         stats.reclaimed_accounts.to_string().cyan()
     );
 
     // NEW: Reclaim strategy breakdown
-    println!("\n{}", "Reclaim Strategy Analysis:".cyan().bold());
+    println!("\n{}", format!("{}:", t(Text::StrategyAnalysis)).cyan().bold());
 
     let active_accounts = db
         .get_accounts_by_strategy("ActiveReclaim")
@@ -1068,64 +2834,164 @@ async fn show_stats(config: &Config, format: &str, total_only: bool) -> error::R
         .map(|a| a.rent_lamports)
         .sum();
 
-    println!("  {} Active Reclaim Possible:", "✓".green());
+    println!("  {} {}:", "✓".green(), t(Text::ActiveReclaimPossible));
     println!(
-        "    {} accounts | {} locked",
-        active_accounts.len().to_string().green(),
-        utils::format_sol(active_rent).green()
+        "    {}{}",
+        tf(Text::AccountsLocked, &[&active_accounts.len().to_string().green(), &utils::format_sol(active_rent).green()]),
+        usd_suffix(active_rent, sol_usd)
     );
-    println!("    → Operator has close authority, can reclaim anytime");
+    println!("    → {}", t(Text::ActiveReclaimHint));
 
-    println!("\n  {} Passive Monitoring:", "⏱".yellow());
+    println!("\n  {} {}:", "⏱".yellow(), t(Text::PassiveMonitoring));
     println!(
-        "    {} accounts | {} locked",
-        passive_accounts.len().to_string().yellow(),
-        utils::format_sol(passive_rent).yellow()
+        "    {}{}",
+        tf(Text::AccountsLocked, &[&passive_accounts.len().to_string().yellow(), &utils::format_sol(passive_rent).yellow()]),
+        usd_suffix(passive_rent, sol_usd)
     );
-    println!("    → User controls account, monitor for when they close it");
+    println!("    → {}", t(Text::PassiveMonitoringHint));
 
-    println!("\n  {} Unrecoverable:", "✗".red());
+    println!("\n  {} {}:", "✗".red(), t(Text::Unrecoverable));
     println!(
-        "    {} accounts | {} locked",
-        unrecoverable.len().to_string().red(),
-        utils::format_sol(unrecoverable_rent).red()
+        "    {}{}",
+        tf(Text::AccountsLocked, &[&unrecoverable.len().to_string().red(), &utils::format_sol(unrecoverable_rent).red()]),
+        usd_suffix(unrecoverable_rent, sol_usd)
     );
-    println!("    → System accounts or permanently locked");
+    println!("    → {}", t(Text::UnrecoverableHint));
+
+    let by_mint = db.get_locked_rent_by_mint().unwrap_or_default();
+    if !by_mint.is_empty() {
+        println!("\n{}", format!("{}:", t(Text::LockedByMint)).cyan().bold());
+        for mint in by_mint.iter().take(MINTS_SHOWN) {
+            let passive = match mint.passive_lamports {
+                0 => String::new(),
+                lamports => format!(" | {}", tf(Text::MintPassiveShare, &[&utils::format_sol(lamports)])),
+            };
+            println!(
+                "  {:<15} {}{}",
+                utils::format_mint(&mint.mint),
+                tf(Text::AccountsLocked, &[&mint.accounts, &utils::format_sol(mint.locked_lamports)]),
+                passive
+            );
+        }
+        if by_mint.len() > MINTS_SHOWN {
+            let rest = &by_mint[MINTS_SHOWN..];
+            let lamports: u64 = rest.iter().map(|mint| mint.locked_lamports).sum();
+            println!("  {}", tf(Text::OtherMints, &[&rest.len(), &utils::format_sol(lamports)]).dimmed());
+        }
+    }
+
+    // Only worth splitting once the operator key has been rotated
+    let by_operator = db.get_operator_totals().unwrap_or_default();
+    if by_operator.len() > 1 || !config.kora.retired_operator_pubkeys.is_empty() {
+        println!("\n{}", format!("{}:", t(Text::ByOperatorKey)).cyan().bold());
+        for totals in &by_operator {
+            let role = match totals.operator.as_deref() {
+                Some(key) if key == config.kora.operator_pubkey => t(Text::OperatorCurrent),
+                Some(key) if config.kora.retired_operator_pubkeys.iter().any(|retired| retired == key) => {
+                    t(Text::OperatorRetired)
+                }
+                Some(_) => "",
+                None => t(Text::OperatorUnrecorded),
+            };
+            let key = totals.operator.as_deref().map(utils::format_pubkey).unwrap_or_default();
+            println!(
+                "  {:<15} {:<14} {}",
+                key,
+                role.dimmed(),
+                tf(
+                    Text::OperatorTotalsLine,
+                    &[
+                        &totals.accounts,
+                        &totals.active_accounts,
+                        &utils::format_sol(totals.locked_lamports),
+                        &utils::format_sol(totals.reclaimed_lamports),
+                    ]
+                )
+            );
+        }
+    }
 
     // Reclaim operations
-    println!("\n{}", "Reclaim Operations:".cyan());
-    println!("  Active Reclaims:   {}", stats.total_operations);
+    println!("\n{}", format!("{}:", t(Text::ReclaimOperations)).cyan());
+    println!("  {} {}", label(Text::ActiveReclaims, 23), stats.total_operations);
     println!(
-        "  Total SOL:         {}",
-        utils::format_sol(stats.total_reclaimed)
+        "  {} {}{}",
+        label(Text::TotalSol, 23),
+        utils::format_sol(stats.total_reclaimed),
+        usd_suffix(stats.total_reclaimed, sol_usd)
     );
     println!(
-        "  Average:           {}",
+        "  {} {}",
+        label(Text::Average, 23),
         utils::format_sol(stats.avg_reclaim_amount)
     );
 
+    let by_reason = db.get_reclaim_totals_by_reason().unwrap_or_default();
+    if !by_reason.is_empty() {
+        println!("  {}:", t(Text::BySource));
+        for (reason, count, amount) in &by_reason {
+            println!("    {:<12} {:>5} ops | {}", reason.to_string(), count, utils::format_sol(*amount));
+        }
+    }
+
     // NEW: Passive reclaims
     let passive_total = db.get_total_passive_reclaimed().unwrap_or(0);
     if passive_total > 0 {
         println!(
-            "\n  Passive Reclaims:  {}",
+            "\n  {} {}",
+            label(Text::PassiveReclaims, 23),
             utils::format_sol(passive_total).green()
         );
-        println!("  (Rent that returned to treasury when users closed accounts)");
+        println!("  {}", t(Text::PassiveReclaimsNote));
+    }
+
+    let passive_pending = db.get_total_pending_passive_reclaimed().unwrap_or(0);
+    if passive_pending > 0 {
+        println!(
+            "  {} {} {}",
+            label(Text::AwaitingReview, 23),
+            utils::format_sol(passive_pending).yellow(),
+            t(Text::NotCountedUntilConfirmed)
+        );
     }
 
     // Total recovery
     let total_recovered = stats.total_reclaimed + passive_total;
     if total_recovered > 0 {
         println!(
-            "\n  {} Total Recovered:  {}",
+            "\n  {} {} {}{}",
             "💰".green(),
-            utils::format_sol(total_recovered).green().bold()
+            label(Text::TotalRecovered, 21),
+            utils::format_sol(total_recovered).green().bold(),
+            usd_suffix(total_recovered, sol_usd)
         );
     }
 
+    // How long accounts live before they close, against the inactivity threshold
+    if let Ok(report) = db.get_lifetime_report() {
+        if report.overall.is_some() {
+            println!("\n{}", format!("{}:", t(Text::AccountLifetime)).cyan().bold());
+            print_lifetime_report(&report, config.reclaim.min_inactive_days);
+        }
+    }
+
+    // Period-over-period change from the daily snapshots
+    if let Ok(current) = db.current_totals() {
+        let deltas = trend_deltas(&db, &current);
+        if !deltas.is_empty() {
+            println!("\n{}", format!("{}:", t(Text::Trend)).cyan());
+            for (label, delta) in deltas {
+                println!("  {:<8} {}", label, delta);
+            }
+        }
+    }
+
+    if let Some(days) = compare_days {
+        print_comparison(&db, days);
+    }
+
     // Scanning Progress
-    println!("\n{}", "Scanning Progress:".cyan());
+    println!("\n{}", format!("{}:", t(Text::ScanningProgress)).cyan());
     match db.get_checkpoint_info() {
         Ok(checkpoints) => {
             if checkpoints.is_empty() {
@@ -1176,8 +3042,8 @@ async fn show_stats(config: &Config, format: &str, total_only: bool) -> error::R
         println!("\n{}", "Recent Passive Reclaims:".yellow());
         utils::print_table_border(100);
         utils::print_table_row(
-            &["Timestamp", "Amount", "Confidence", "Accounts"],
-            &[22, 18, 15, 45],
+            &["ID", "Timestamp", "Amount", "Confidence", "Review", "Accounts"],
+            &[6, 22, 18, 11, 10, 33],
         );
         utils::print_table_border(100);
 
@@ -1195,12 +3061,14 @@ async fn show_stats(config: &Config, format: &str, total_only: bool) -> error::R
 
             utils::print_table_row(
                 &[
+                    &record.id.to_string(),
                     &utils::format_timestamp(&record.timestamp),
                     &utils::format_sol(record.amount),
                     &record.confidence,
+                    &record.review_status.to_string(),
                     &accounts_str,
                 ],
-                &[22, 18, 15, 45],
+                &[6, 22, 18, 11, 10, 33],
             );
         }
         utils::print_table_border(100);
@@ -1231,6 +3099,32 @@ async fn show_stats(config: &Config, format: &str, total_only: bool) -> error::R
         utils::print_table_border(100);
     }
 
+    // Confirmed reclaims that did not credit the treasury as expected
+    let mismatches = db.get_credit_mismatches()?;
+    if !mismatches.is_empty() {
+        println!("\n{}", "⚠ Treasury Credit Mismatches:".red().bold());
+        utils::print_table_border(100);
+        utils::print_table_row(
+            &["Timestamp", "Account", "Expected", "Credited", "Signature"],
+            &[22, 22, 17, 17, 20],
+        );
+        utils::print_table_border(100);
+
+        for op in mismatches {
+            utils::print_table_row(
+                &[
+                    &utils::format_timestamp(&op.timestamp),
+                    &utils::format_pubkey(&op.account_pubkey),
+                    &op.reclaimed_amount.to_string(),
+                    &op.credited_amount.unwrap_or_default().to_string(),
+                    &utils::format_pubkey(&op.tx_signature),
+                ],
+                &[22, 22, 17, 17, 20],
+            );
+        }
+        utils::print_table_border(100);
+    }
+
     // Recommendations
     println!("\n{}", "💡 Recommendations:".yellow().bold());
     if passive_accounts.len() > 0 {
@@ -1307,7 +3201,10 @@ async fn list_accounts(
                     "pubkey": acc.pubkey,
                     "created_at": acc.created_at.to_rfc3339(),
                     "rent_lamports": acc.rent_lamports,
+                    "current_lamports": acc.current_lamports,
+                    "balance_checked_at": acc.balance_checked_at.map(|t| t.to_rfc3339()),
                     "data_size": acc.data_size,
+                    "account_type": acc.account_type.as_ref().map(|t| t.to_string()),
                     "status": format!("{:?}", acc.status),
                 });
 
@@ -1375,7 +3272,7 @@ async fn list_accounts(
                     &utils::format_pubkey(&acc.pubkey),
                     &format!("{:?}", acc.status),
                     &utils::format_timestamp(&acc.created_at),
-                    &utils::format_sol(acc.rent_lamports),
+                    &utils::format_sol(acc.balance()),
                     &slot_str,
                     &sig_str,
                 ],
@@ -1397,7 +3294,7 @@ async fn list_accounts(
                     &utils::format_pubkey(&acc.pubkey),
                     &format!("{:?}", acc.status),
                     &utils::format_timestamp(&acc.created_at),
-                    &utils::format_sol(acc.rent_lamports),
+                    &utils::format_sol(acc.balance()),
                 ],
                 &[44, 12, 20, 14],
             );
@@ -1425,54 +3322,171 @@ async fn list_accounts(
     Ok(())
 }
 
-async fn reset_checkpoints(config: &Config, yes: bool) -> error::Result<()> {
-    println!("{}", "Resetting scanning checkpoints...".yellow());
+/// `list --soon`: Active accounts that become old enough for reclaim within `days` days
+async fn list_upcoming(config: &Config, days: u64, format: &str) -> error::Result<()> {
+    let db = storage::Database::new(&config.database.path)?;
+    let min_inactive_days = config.reclaim.min_inactive_days;
+    let accounts = db.get_accounts_eligible_within(min_inactive_days, days)?;
+    let total: u64 = accounts.iter().map(|a| a.balance()).sum();
+    let now = chrono::Utc::now();
+
+    if format == "json" {
+        let json_data = serde_json::json!({
+            "within_days": days,
+            "min_inactive_days": min_inactive_days,
+            "total_lamports": total,
+            "accounts": accounts
+                .iter()
+                .map(|acc| serde_json::json!({
+                    "pubkey": acc.pubkey,
+                    "created_at": acc.created_at.to_rfc3339(),
+                    "eligible_at": acc.eligible_at(min_inactive_days).to_rfc3339(),
+                    "days_until_eligible": acc.days_until_eligible(min_inactive_days, now),
+                    "balance_lamports": acc.balance(),
+                }))
+                .collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&json_data)?);
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("=== Eligible Within {} Days ({}) ===", days, accounts.len())
+            .cyan()
+            .bold()
+    );
+
+    if accounts.is_empty() {
+        println!("No active accounts reach {} days of age in the next {} days", min_inactive_days, days);
+        return Ok(());
+    }
+
+    utils::print_table_border(90);
+    utils::print_table_row(&["Pubkey", "Eligible at", "In", "Balance"], &[44, 20, 8, 14]);
+    utils::print_table_border(90);
+
+    for acc in &accounts {
+        utils::print_table_row(
+            &[
+                &utils::format_pubkey(&acc.pubkey),
+                &utils::format_timestamp(&acc.eligible_at(min_inactive_days)),
+                &format!("{}d", acc.days_until_eligible(min_inactive_days, now)),
+                &utils::format_sol(acc.balance()),
+            ],
+            &[44, 20, 8, 14],
+        );
+    }
+    utils::print_table_border(90);
+
+    println!(
+        "\nCombined reclaimable: {} across {} accounts, if they are still inactive on-chain",
+        utils::format_sol(total),
+        accounts.len()
+    );
+
+    Ok(())
+}
+
+async fn reset_checkpoints(config: &Config, yes: bool, clear_cache: bool, format: &str) -> error::Result<()> {
+    if format == "json" && !yes {
+        return Err(error::ReclaimError::ConfigError(
+            "--format json cannot prompt for confirmation; pass --yes".to_string(),
+        ));
+    }
+    say!("{}", "Resetting scanning checkpoints...".yellow());
 
     let db = storage::Database::new(&config.database.path)?;
 
+    let mut cleared_cache = 0;
+    if clear_cache {
+        cleared_cache = db.clear_transaction_cache()?;
+        say!("{}", format!("✓ Cleared {} cached transactions", cleared_cache).green());
+    }
+
     // ✅ USE: get_checkpoint_info to show what will be cleared
-    match db.get_checkpoint_info() {
-        Ok(checkpoints) => {
-            if checkpoints.is_empty() {
-                println!("No checkpoints to clear.");
-                return Ok(());
-            }
+    let checkpoints = db.get_checkpoint_info()?;
+    if checkpoints.is_empty() {
+        say!("No checkpoints to clear.");
+    } else {
+        say!("\nCurrent checkpoints:");
+        for (key, value, updated_at) in &checkpoints {
+            say!("  {} = {} (updated: {})", key, value, updated_at);
+        }
 
-            println!("\nCurrent checkpoints:");
-            for (key, value, updated_at) in &checkpoints {
-                println!("  {} = {} (updated: {})", key, value, updated_at);
+        if !yes {
+            say!(
+                "\n{}",
+                "⚠️  WARNING: This will force a full rescan on the next run!"
+                    .yellow()
+                    .bold()
+            );
+            if !utils::confirm_action("Are you sure you want to reset all checkpoints?") {
+                return Err(error::ReclaimError::Cancelled);
             }
+        }
 
-            if !yes {
-                println!(
-                    "\n{}",
-                    "⚠️  WARNING: This will force a full rescan on the next run!"
-                        .yellow()
-                        .bold()
-                );
-                if !utils::confirm_action("Are you sure you want to reset all checkpoints?") {
-                    println!("Cancelled");
-                    return Ok(());
-                }
-            }
+        // ✅ USE: clear_checkpoints
+        db.clear_checkpoints()?;
+        say!("{}", "✓ All checkpoints cleared successfully".green());
+        say!("The next scan will be a full scan from the beginning.");
+    }
 
-            // ✅ USE: clear_checkpoints
-            db.clear_checkpoints()?;
-            println!("{}", "✓ All checkpoints cleared successfully".green());
-            println!("The next scan will be a full scan from the beginning.");
-        }
-        Err(e) => {
-            println!("Error reading checkpoints: {}", e);
-        }
+    if format == "json" {
+        let cleared: Vec<_> = checkpoints
+            .iter()
+            .map(|(key, value, updated_at)| serde_json::json!({"key": key, "value": value, "updated_at": updated_at}))
+            .collect();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "cleared_cached_transactions": cleared_cache,
+                "cleared_checkpoints": cleared,
+            }))?
+        );
     }
 
     Ok(())
 }
 
-async fn show_checkpoints(config: &Config) -> error::Result<()> {
+async fn show_checkpoints(config: &Config, format: &str) -> error::Result<()> {
     let db = storage::Database::new(&config.database.path)?;
 
+    if format == "json" {
+        let checkpoints: Vec<_> = db
+            .get_checkpoint_info()?
+            .into_iter()
+            .map(|(key, value, updated_at)| serde_json::json!({"key": key, "value": value, "updated_at": updated_at}))
+            .collect();
+        let last_slot = db.get_last_processed_slot()?;
+        let current_slot = match solana::SolanaRpcClient::from_config(config).client.get_slot() {
+            Ok(slot) => Some(slot),
+            Err(e) => {
+                warn!("Could not fetch current slot: {}", e);
+                None
+            }
+        };
+        let slots_behind = last_slot.zip(current_slot).map(|(last, current)| current.saturating_sub(last));
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "scan_floor_slot": config.reclaim.scan_floor_slot,
+                "checkpoints": checkpoints,
+                "last_processed_slot": last_slot,
+                "current_slot": current_slot,
+                "slots_behind": slots_behind,
+                "cached_transactions": db.transaction_cache_size()?,
+                "recent_scans": db.get_scan_sessions(10)?,
+            }))?
+        );
+        return Ok(());
+    }
+
     println!("{}", "=== Scanning Checkpoints ===".cyan().bold());
+    match config.reclaim.scan_floor_slot {
+        Some(floor) => println!("\nScan floor: slot {}", floor.to_string().cyan()),
+        None => println!("\nScan floor: {}", "none (full history)".dimmed()),
+    }
 
     match db.get_checkpoint_info() {
         Ok(checkpoints) => {
@@ -1525,11 +3539,7 @@ async fn show_checkpoints(config: &Config) -> error::Result<()> {
         println!("  Last Processed Slot: {}", last_slot.to_string().cyan());
 
         // ✅ FIX: Actually use the rpc_client
-        let rpc_client = solana::SolanaRpcClient::new(
-            &config.solana.rpc_url,
-            config.commitment_config(),
-            config.solana.rate_limit_delay_ms,
-        );
+        let rpc_client = solana::SolanaRpcClient::from_config(config);
 
         // Get current slot to compare
         match rpc_client.client.get_slot() {
@@ -1542,8 +3552,8 @@ async fn show_checkpoints(config: &Config) -> error::Result<()> {
 
                 if slots_behind > 0 {
                     println!("  Slots Behind: {}", slots_behind.to_string().yellow());
-                    // Roughly 400ms per slot on Solana mainnet
-                    let minutes_behind = (slots_behind as f64 * 0.4) / 60.0;
+                    let minutes_behind =
+                        solana::slot_time::nominal_duration(slots_behind).num_seconds() as f64 / 60.0;
                     if minutes_behind >= 1.0 {
                         println!("  Est. Time Behind: ~{:.1} minutes", minutes_behind);
                     }
@@ -1562,6 +3572,38 @@ async fn show_checkpoints(config: &Config) -> error::Result<()> {
         println!("  Status: Full scan mode");
     }
 
+    if let Ok(cached) = db.transaction_cache_size() {
+        println!("  Cached Transactions: {}", cached.to_string().cyan());
+    }
+
+    println!("\n{}", "Recent Scans:".cyan());
+    match db.get_scan_sessions(10) {
+        Ok(sessions) if sessions.is_empty() => println!("  No scans recorded yet"),
+        Ok(sessions) => {
+            let widths = [23, 12, 9, 6, 30];
+            utils::print_table_row(&["Started", "Mode", "Duration", "Found", "Result"], &widths);
+            utils::print_table_border(90);
+            for session in sessions {
+                let mode = if session.incremental { "incremental" } else { "full" };
+                let result = match &session.error {
+                    Some(error) => error.red().to_string(),
+                    None => "ok".green().to_string(),
+                };
+                utils::print_table_row(
+                    &[
+                        &utils::format_timestamp(&session.started_at),
+                        mode,
+                        &format!("{}s", session.duration().num_seconds()),
+                        &session.accounts_found.to_string(),
+                        &result,
+                    ],
+                    &widths,
+                );
+            }
+        }
+        Err(e) => println!("  Error reading scan history: {}", e),
+    }
+
     println!(
         "\nTip: Use {} to reset checkpoints and force a full rescan",
         "kora-reclaim reset".yellow()
@@ -1570,7 +3612,596 @@ async fn show_checkpoints(config: &Config) -> error::Result<()> {
     Ok(())
 }
 
+async fn show_report(config: &Config, lifetime: bool, outreach: bool, format: &str) -> error::Result<()> {
+    if !lifetime && !outreach {
+        println!("Choose a report: {} or {}", "--lifetime".yellow(), "--outreach".yellow());
+        return Ok(());
+    }
+
+    let db = storage::Database::new(&config.database.path)?;
+
+    if outreach {
+        let report = db.get_outreach_report()?;
+        if format == "json" {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+            return Ok(());
+        }
+
+        println!("{}", "=== Outreach Conversion ===".cyan().bold());
+        print_outreach_report(&report, current_sol_usd(config).await);
+        return Ok(());
+    }
+
+    let report = db.get_lifetime_report()?;
+
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!("{}", "=== Account Lifetime ===".cyan().bold());
+    print_lifetime_report(&report, config.reclaim.min_inactive_days);
+    Ok(())
+}
+
+/// Conversion tables by channel and by the user's response
+fn print_outreach_report(report: &storage::analytics::OutreachReport, sol_usd: Option<f64>) {
+    if report.overall.contacted == 0 {
+        println!("  No outreach recorded yet");
+        return;
+    }
+
+    let widths = [20, 10, 10, 8, 16, 10];
+    let print_groups = |title: &str, groups: &[storage::analytics::OutreachGroup]| {
+        println!("\n{}", title.cyan());
+        utils::print_table_row(
+            &["Group", "Contacted", "Closed", "Rate", "Recovered SOL", "Median (d)"],
+            &widths,
+        );
+        utils::print_table_border(84);
+        for group in groups {
+            utils::print_table_row(
+                &[
+                    &group.label,
+                    &group.contacted.to_string(),
+                    &group.converted.to_string(),
+                    &format!("{:.0}%", group.conversion_rate * 100.0),
+                    &format!("{:.4}", solana::rent::RentCalculator::lamports_to_sol(group.recovered_lamports)),
+                    &group
+                        .median_days_to_close
+                        .map_or_else(|| "-".to_string(), |days| format!("{:.1}", days)),
+                ],
+                &widths,
+            );
+        }
+    };
+
+    print_groups("By channel:", &report.by_channel);
+    print_groups("By response:", &report.by_response);
+
+    let overall = &report.overall;
+    println!(
+        "\n  {} of {} contacted accounts closed by their users ({:.0}%), {}{} recovered",
+        overall.converted,
+        overall.contacted,
+        overall.conversion_rate * 100.0,
+        utils::format_sol(overall.recovered_lamports).green(),
+        usd_suffix(overall.recovered_lamports, sol_usd)
+    );
+}
+
+/// Lifetime tables plus how the current `min_inactive_days` compares
+fn print_lifetime_report(report: &storage::analytics::LifetimeReport, min_inactive_days: u64) {
+    let Some(overall) = &report.overall else {
+        println!("  No closed or reclaimed accounts yet");
+        return;
+    };
+
+    let widths = [20, 8, 10, 10, 10, 10];
+    let print_groups = |title: &str, groups: &[storage::analytics::LifetimeGroup]| {
+        println!("\n{}", title.cyan());
+        utils::print_table_row(&["Group", "Count", "Avg (d)", "Median", "Min", "Max"], &widths);
+        utils::print_table_border(72);
+        for group in groups {
+            utils::print_table_row(
+                &[
+                    &group.label,
+                    &group.count.to_string(),
+                    &format!("{:.1}", group.avg_days),
+                    &format!("{:.1}", group.median_days),
+                    &format!("{:.1}", group.min_days),
+                    &format!("{:.1}", group.max_days),
+                ],
+                &widths,
+            );
+        }
+    };
+
+    print_groups("By account type:", &report.by_type);
+    print_groups("By reclaim strategy:", &report.by_strategy);
+
+    println!(
+        "\n  {} accounts, average {:.1} days, median {:.1} days",
+        overall.count, overall.avg_days, overall.median_days
+    );
+    if let Some(share) = report.share_closed_within(min_inactive_days) {
+        println!(
+            "  {:.0}% closed within min_inactive_days ({} days)",
+            share * 100.0,
+            min_inactive_days
+        );
+    }
+}
+
+/// Mints listed one by one in `stats`; the rest are summed
+const MINTS_SHOWN: usize = 10;
+
+/// Periods compared in `stats` and `snapshot` reports
+const TREND_PERIODS: [(i64, &str); 3] = [(1, "1 day"), (7, "7 days"), (30, "30 days")];
+
+/// SOL/USD from the configured price source, if any
+async fn current_sol_usd(config: &Config) -> Option<f64> {
+    price::PriceOracle::from_config(&config.price)?.sol_usd().await
+}
+
+/// ` (≈ $1.23)` after a SOL amount, or nothing without a price
+fn usd_suffix(lamports: u64, sol_usd: Option<f64>) -> String {
+    sol_usd
+        .map(|sol_usd| format!(" (≈ {})", price::format_usd(price::lamports_to_usd(lamports, sol_usd))))
+        .unwrap_or_default()
+}
+
+/// `key` followed by a colon, padded to `width` for aligned summaries
+fn label(key: Text, width: usize) -> String {
+    format!("{:<width$}", format!("{}:", t(key)), width = width)
+}
+
+/// Deltas of `current` against the snapshots 1, 7 and 30 days earlier
+fn trend_deltas(
+    db: &storage::Database,
+    current: &storage::models::Snapshot,
+) -> Vec<(&'static str, storage::models::SnapshotDelta)> {
+    TREND_PERIODS
+        .iter()
+        .filter_map(|(days, label)| {
+            let earlier = db
+                .get_snapshot_on_or_before(current.date - chrono::Duration::days(*days))
+                .ok()??;
+            Some((*label, current.delta_since(&earlier)))
+        })
+        .collect()
+}
+
+/// Current totals against the latest snapshot at least `days` old
+fn snapshot_comparison(
+    db: &storage::Database,
+    days: i64,
+) -> Option<(storage::models::Snapshot, Vec<storage::models::MetricChange>)> {
+    let current = db.current_totals().ok()?;
+    let earlier = db
+        .get_snapshot_on_or_before(current.date - chrono::Duration::days(days))
+        .ok()??;
+    let changes = current.compare_with(&earlier);
+    Some((earlier, changes))
+}
+
+fn print_comparison(db: &storage::Database, days: i64) {
+    let period = tf(Text::Days, &[&days]);
+    let Some((earlier, changes)) = snapshot_comparison(db, days) else {
+        let date = chrono::Utc::now().date_naive() - chrono::Duration::days(days);
+        println!("\n{}", tf(Text::NoSnapshotToCompare, &[&date]).yellow());
+        return;
+    };
+
+    println!("\n{}", format!("{}:", tf(Text::ComparedWith, &[&period, &earlier.date])).cyan());
+    let widths = [20, 18, 18, 18, 9];
+    utils::print_table_row(&["", t(Text::Then), t(Text::Now), t(Text::Change), "%"], &widths);
+    utils::print_table_border(87);
+
+    for change in &changes {
+        let metric = match change.metric {
+            "active_accounts" => Text::ActiveAccounts,
+            "locked_lamports" => Text::StillLocked,
+            "reclaimed_lamports" => Text::ActiveReclaims,
+            "passive_reclaimed_lamports" => Text::PassiveReclaims,
+            _ => Text::TotalRecovered,
+        };
+        let (previous, current, delta) = if change.is_lamports() {
+            (
+                format!("{:.4} SOL", solana::rent::RentCalculator::lamports_to_sol(change.previous)),
+                format!("{:.4} SOL", solana::rent::RentCalculator::lamports_to_sol(change.current)),
+                format!("{:+.4} SOL", change.delta as f64 / 1_000_000_000.0),
+            )
+        } else {
+            (change.previous.to_string(), change.current.to_string(), format!("{:+}", change.delta))
+        };
+        let percent = change.percent.map_or("-".to_string(), |p| format!("{:+.1}%", p));
+        utils::print_table_row(&[t(metric), &previous, &current, &delta, &percent], &widths);
+    }
+}
+
+async fn take_snapshot(config: &Config) -> error::Result<()> {
+    let db = storage::Database::new(&config.database.path)?;
+    let snapshot = db.take_snapshot()?;
+
+    println!("{}", format!("=== Snapshot {} ===", snapshot.date).cyan().bold());
+    println!("  Active accounts:  {}", snapshot.active_accounts);
+    println!("  Locked:           {}", utils::format_sol(snapshot.locked_lamports).yellow());
+    for (strategy, lamports) in &snapshot.locked_by_strategy {
+        println!("    {:<18} {}", strategy, utils::format_sol(*lamports));
+    }
+    println!("  Reclaimed:        {}", utils::format_sol(snapshot.reclaimed_lamports).green());
+    println!("  Passive:          {}", utils::format_sol(snapshot.passive_reclaimed_lamports).green());
+
+    let deltas = trend_deltas(&db, &snapshot);
+    if !deltas.is_empty() {
+        println!("\n{}", "Change:".cyan());
+        for (label, delta) in deltas {
+            println!("  {:<8} {}", label, delta);
+        }
+    }
+
+    Ok(())
+}
+
+async fn check_integrity(config: &Config, repair: bool) -> error::Result<()> {
+    let db = storage::Database::new(&config.database.path)?;
+    let report = db.check_integrity(repair)?;
+
+    println!("{}", "=== Database Integrity ===".cyan().bold());
+    if report.is_healthy() {
+        println!("\n{}", "No problems found.".green());
+        return Ok(());
+    }
+
+    let sections = [
+        ("SQLite errors", &report.sqlite_errors),
+        ("Foreign key violations", &report.foreign_key_violations),
+        ("Operations without an account", &report.orphaned_operations),
+        ("Reclaimed accounts marked Active", &report.unreclaimed_accounts),
+        ("Duplicated reclaim operations", &report.duplicate_operations),
+    ];
+    for (title, items) in sections {
+        if items.is_empty() {
+            continue;
+        }
+        println!("\n{} ({})", title.yellow(), items.len());
+        for item in items {
+            println!("  {}", item);
+        }
+    }
+
+    println!();
+    if repair {
+        println!("Repaired {} of {} problem(s).", report.repaired, report.problem_count());
+        if report.unresolved() > 0 {
+            println!("{}", "Restore the database from a backup to fix the rest.".yellow());
+        }
+    } else {
+        println!("Run {} to fix what can be fixed.", "kora-reclaim integrity --repair".yellow());
+    }
+
+    Ok(())
+}
+
+async fn verify_ledger(config: &Config) -> error::Result<()> {
+    let db = storage::Database::new(&config.database.path)?;
+    let report = db.verify_ledger()?;
+
+    println!("{}", "=== Reclaim Ledger ===".cyan().bold());
+    println!("\nEntries: {}", report.entries);
+    println!("Head:    {}", report.head);
+
+    if report.is_intact() {
+        println!("\n{}", "Hash chain intact.".green());
+        return Ok(());
+    }
+
+    println!("\n{} ({})", "Entries failing verification".red().bold(), report.tampered.len());
+    for id in &report.tampered {
+        println!("  operation #{}", id);
+    }
+    println!("\nThese rows were edited, written outside the bot, or follow a deleted row.");
+    Err(anyhow::anyhow!("reclaim ledger failed verification at {} entries", report.tampered.len()).into())
+}
+
+async fn show_snapshot_history(config: &Config, days: usize) -> error::Result<()> {
+    let db = storage::Database::new(&config.database.path)?;
+    let snapshots = db.get_snapshots(days)?;
+
+    if snapshots.is_empty() {
+        println!(
+            "No snapshots yet. Run {} or the auto service to record one.",
+            "kora-reclaim snapshot".yellow()
+        );
+        return Ok(());
+    }
+
+    let sol = solana::rent::RentCalculator::lamports_to_sol;
+    let widths = [12, 10, 22, 22];
+    utils::print_table_border(70);
+    utils::print_table_row(&["Date", "Active", "Locked", "Reclaimed to date"], &widths);
+    utils::print_table_border(70);
+    for snapshot in &snapshots {
+        utils::print_table_row(
+            &[
+                &snapshot.date.to_string(),
+                &snapshot.active_accounts.to_string(),
+                &format!("{:.6} SOL", sol(snapshot.locked_lamports)),
+                &format!("{:.6} SOL", sol(snapshot.reclaimed_total())),
+            ],
+            &widths,
+        );
+    }
+    utils::print_table_border(70);
+
+    Ok(())
+}
+
+async fn refresh_balances(config: &Config, verbose: bool) -> error::Result<()> {
+    println!("{}", "Refreshing on-chain balances of active accounts...".cyan());
+
+    let rpc_client = solana::SolanaRpcClient::from_config(config);
+    let db = storage::Database::new(&config.database.path)?;
+
+    let refresher = kora::BalanceRefresher::new(rpc_client, db);
+    let summary = refresher.refresh_active_accounts().await?;
+
+    println!("\n{}", "=== Balance Refresh ===".cyan().bold());
+    println!("Checked:        {}", summary.checked);
+    println!("Updated:        {}", summary.updated.to_string().green());
+    println!(
+        "Discrepancies:  {}",
+        summary.discrepancies.len().to_string().yellow()
+    );
+    println!(
+        "Missing:        {}",
+        summary.missing_count().to_string().red()
+    );
+
+    if verbose && !summary.discrepancies.is_empty() {
+        println!("\n{}", "Accounts with changed balances:".yellow());
+        utils::print_table_border(100);
+        utils::print_table_row(&["Pubkey", "Expected", "Actual", "Note"], &[44, 20, 20, 10]);
+        utils::print_table_border(100);
+
+        for d in &summary.discrepancies {
+            utils::print_table_row(
+                &[
+                    &d.pubkey,
+                    &utils::format_sol(d.expected_lamports),
+                    &utils::format_sol(d.actual_lamports),
+                    if d.missing { "missing" } else { "" },
+                ],
+                &[44, 20, 20, 10],
+            );
+        }
+        utils::print_table_border(100);
+    }
+
+    Ok(())
+}
+
+async fn classify_accounts(config: &Config, only: Option<&str>, concurrency: usize) -> error::Result<()> {
+    use storage::models::ReclaimStrategy;
+
+    let rpc_client = solana::SolanaRpcClient::from_config(config);
+    let db = storage::Database::new(&config.database.path)?;
+
+    let only = only.map(|only| match only {
+        "active" => ReclaimStrategy::ActiveReclaim,
+        "passive" => ReclaimStrategy::PassiveMonitoring,
+        "unrecoverable" => ReclaimStrategy::Unrecoverable,
+        _ => ReclaimStrategy::Unknown,
+    });
+    let accounts: Vec<_> = db
+        .get_active_accounts()?
+        .into_iter()
+        .filter(|account| match &only {
+            Some(ReclaimStrategy::Unknown) => {
+                matches!(account.reclaim_strategy, None | Some(ReclaimStrategy::Unknown))
+            }
+            Some(strategy) => account.reclaim_strategy.as_ref() == Some(strategy),
+            None => true,
+        })
+        .collect();
+
+    println!(
+        "{}",
+        format!("Classifying {} active accounts ({} at a time)...", accounts.len(), concurrency.max(1)).cyan()
+    );
+
+    let eligibility = reclaim::EligibilityChecker::new(rpc_client.clone(), config.clone());
+    let classifier = kora::StrategyClassifier::new(rpc_client, eligibility, db, concurrency);
+    let summary = classifier
+        .classify(&accounts, |done, total| println!("  {}/{}", done, total))
+        .await?;
+
+    println!("\n{}", format!("=== {} ===", t(Text::StrategyAnalysis)).cyan().bold());
+    println!("Checked:        {}", summary.checked);
+    println!("Changed:        {}", summary.changed.to_string().green());
+    println!("Missing:        {}", summary.missing.to_string().yellow());
+    println!("Failed:         {}", summary.failed.to_string().red());
+    println!(
+        "{} {} ✓",
+        label(Text::ActiveReclaimPossible, 25),
+        tf(Text::AccountCount, &[&summary.active]).green()
+    );
+    println!(
+        "{} {} ⏱",
+        label(Text::PassiveMonitoring, 25),
+        tf(Text::AccountCount, &[&summary.passive]).yellow()
+    );
+    println!(
+        "{} {} ✗",
+        label(Text::Unrecoverable, 25),
+        tf(Text::AccountCount, &[&summary.unrecoverable]).red()
+    );
+    println!("{:<25} {}", "Unknown:", tf(Text::AccountCount, &[&summary.unknown]));
+
+    if summary.missing > 0 {
+        println!("\nRun `refresh` to record the balances of accounts missing on-chain.");
+    }
+
+    Ok(())
+}
+
+async fn import_accounts(config: &Config, file: &str, dry_run: bool) -> error::Result<()> {
+    use kora::import::ImportOutcome;
+
+    let text = std::fs::read_to_string(file)?;
+    let (rows, invalid) = kora::import::parse_import_csv(&text);
+
+    for message in &invalid {
+        println!("{} {}", "✗".red(), message);
+    }
+    println!(
+        "{}",
+        format!("Importing {} accounts from {}{}...", rows.len(), file, if dry_run { " (dry run)" } else { "" })
+            .cyan()
+    );
+
+    let rpc_client = solana::SolanaRpcClient::from_config(config);
+    let db = storage::Database::new(&config.database.path)?;
+    let importer = kora::AccountImporter::new(config, rpc_client.clone(), db.clone())?;
+
+    let mut imported = 0;
+    let mut by_strategy = std::collections::BTreeMap::new();
+    let mut already_tracked = 0;
+    let mut not_found = 0;
+    let mut failed = 0;
+
+    for row in &rows {
+        match importer.import(&row.pubkey, dry_run).await {
+            Ok(ImportOutcome::Imported { account, strategy }) => {
+                imported += 1;
+                *by_strategy.entry(strategy.to_string()).or_insert(0) += 1;
+                println!(
+                    "{} {}  {}  {}  {}",
+                    "✓".green(),
+                    account.pubkey,
+                    utils::format_sol(account.balance()),
+                    account.account_type.map(|t| t.to_string()).unwrap_or_default(),
+                    strategy
+                );
+            }
+            Ok(ImportOutcome::AlreadyTracked) => {
+                already_tracked += 1;
+                println!("{} {}  already tracked", "•".yellow(), row.pubkey);
+            }
+            Ok(ImportOutcome::NotFound) => {
+                not_found += 1;
+                println!("{} {}  not found on-chain", "✗".red(), row.pubkey);
+            }
+            Err(e) => {
+                failed += 1;
+                println!("{} {}  line {}: {}", "✗".red(), row.pubkey, row.line, e);
+            }
+        }
+    }
+
+    // Imported accounts are Provisional like scanned ones until the pass confirms them
+    let provisional = if imported > 0 && !dry_run {
+        kora::FinalityChecker::new(rpc_client, db).confirm_saved().await.pending
+    } else {
+        0
+    };
+
+    println!("\n{}", "=== Import Summary ===".cyan().bold());
+    println!("Imported:         {}", imported.to_string().green());
+    for (strategy, count) in &by_strategy {
+        println!("  {:<16}{}", strategy, count);
+    }
+    println!("Already tracked:  {}", already_tracked.to_string().yellow());
+    println!("Not on-chain:     {}", not_found.to_string().red());
+    println!("Invalid rows:     {}", invalid.len().to_string().red());
+    if failed > 0 {
+        println!("RPC failures:     {}", failed.to_string().red());
+    }
+    if provisional > 0 {
+        println!("Provisional:      {} (creation not finalized yet)", provisional.to_string().yellow());
+    }
+    if dry_run {
+        println!("{}", "Dry run: nothing was written".yellow());
+    }
+
+    Ok(())
+}
+
+/// `config` with reclaimed rent sent to `destination`, if one was given
+fn with_destination(config: &Config, destination: Option<solana_sdk::pubkey::Pubkey>) -> Config {
+    let mut config = config.clone();
+    if let Some(destination) = destination {
+        config.reclaim.destination = Some(destination.to_string());
+    }
+    config
+}
+
+fn with_rpc_budget(config: &Config, budget: Option<u64>) -> Config {
+    let mut config = config.clone();
+    if budget.is_some() {
+        config.solana.cycle_rpc_budget = budget;
+    }
+    config
+}
+
 // Update the initialize function to use checkpoint info
+/// Configuration for tenant `id`, with its database claimed for that tenant
+fn select_tenant(config: &Config, id: &str) -> error::Result<Config> {
+    let tenant_config = config.for_tenant(id)?;
+    let tenant = config.tenants.iter().find(|t| t.id == id).expect("for_tenant found it");
+
+    let db = storage::Database::new(&tenant_config.database.path)?;
+    db.claim_tenant(&storage::models::Tenant {
+        id: tenant.id.clone(),
+        name: tenant.name.clone(),
+        operator_pubkey: tenant.operator_pubkey.clone(),
+        treasury_wallet: tenant.treasury_wallet.clone(),
+        registered_at: chrono::Utc::now(),
+    })?;
+
+    info!("Tenant: {} ({})", id, tenant_config.database.path);
+    Ok(tenant_config)
+}
+
+async fn list_tenants(config: &Config) -> error::Result<()> {
+    config.validate_tenants()?;
+    if config.tenants.is_empty() {
+        println!("No tenants configured; add [[tenants]] entries to config.toml");
+        return Ok(());
+    }
+
+    println!("{}", format!("=== Tenants ({}) ===", config.tenants.len()).cyan().bold());
+    let widths = [16, 24, 10, 10, 18];
+    utils::print_table_row(&["ID", "Name", "Active", "Reclaimed", "Total SOL"], &widths);
+    utils::print_table_border(82);
+
+    for tenant in &config.tenants {
+        let path = tenant.database_path(&config.database.path);
+        let name = tenant.name.as_deref().unwrap_or("-");
+
+        // Opening would create the file; a tenant that never ran has no stats
+        if !std::path::Path::new(&path).exists() {
+            utils::print_table_row(&[&tenant.id, name, "-", "-", "not initialized"], &widths);
+            continue;
+        }
+        let stats = storage::Database::new(&path)?.get_stats()?;
+        utils::print_table_row(
+            &[
+                &tenant.id,
+                name,
+                &stats.active_accounts.to_string(),
+                &stats.reclaimed_accounts.to_string(),
+                &format!("{:.4}", solana::rent::RentCalculator::lamports_to_sol(stats.total_reclaimed)),
+            ],
+            &widths,
+        );
+    }
+
+    println!("\nRun any command with {} to operate on one tenant", "--tenant <ID>".cyan());
+    Ok(())
+}
+
 async fn initialize(config: &Config) -> error::Result<()> {
     println!("{}", "Initializing Kora Rent Reclaim Bot...".green());
     let db = storage::Database::new(&config.database.path)?;
@@ -1580,6 +4211,9 @@ async fn initialize(config: &Config) -> error::Result<()> {
     println!("\n{}", "Configuration:".cyan());
     println!("  RPC URL:        {}", config.solana.rpc_url);
     println!("  Network:        {:?}", config.solana.network);
+    if let Some(tenant) = db.get_tenant()? {
+        println!("  Tenant:         {}", tenant.name.unwrap_or(tenant.id));
+    }
     println!("  Operator:       {}", config.kora.operator_pubkey);
     println!("  Treasury:       {}", config.kora.treasury_wallet);
     println!("  Dry Run:        {}", config.reclaim.dry_run);
@@ -1634,32 +4268,56 @@ async fn send_daily_summary(config: &Config) -> error::Result<()> {
 
     let db = storage::Database::new(&config.database.path)?;
 
-    // Get operations from last 24 hours
-    let all_ops = db.get_reclaim_history(None)?;
+    // Last 24 hours, and the 24 before them for day-over-day changes
     let now = chrono::Utc::now();
     let yesterday = now - chrono::Duration::hours(24);
+    let activity = db.activity_between(yesterday, now)?;
+    let previous = db.activity_between(yesterday - chrono::Duration::hours(24), yesterday)?;
+
+    // The summary doubles as the daily snapshot when the auto service isn't running
+    let snapshot = db.take_snapshot()?;
+    let locked_change = db
+        .get_snapshot_on_or_before(snapshot.date - chrono::Duration::days(1))?
+        .map(|previous| snapshot.delta_since(&previous).locked_lamports);
+
+    let sol_usd = current_sol_usd(config).await;
+    let net = activity.net_lamports();
+    println!("Operations in last 24h: {} ({:+})", activity.operations, activity.operations as i64 - previous.operations as i64);
+    println!("Total reclaimed: {}{}", utils::format_sol(activity.reclaimed_lamports), usd_suffix(activity.reclaimed_lamports, sol_usd));
+    println!(
+        "Passive reclaims: {} ({})",
+        activity.passive_reclaims,
+        utils::format_sol(activity.passive_reclaimed_lamports)
+    );
+    println!("Fees paid: {}", utils::format_sol(activity.fee_lamports));
+    println!(
+        "Net recovery: {:.9} SOL ({:+.9} SOL vs yesterday)",
+        net as f64 / 1_000_000_000.0,
+        (net - previous.net_lamports()) as f64 / 1_000_000_000.0
+    );
+    println!("Failures: {} ({:+})", activity.failures, activity.failures as i64 - previous.failures as i64);
+    println!(
+        "Still locked: {}{}",
+        utils::format_sol(snapshot.locked_lamports),
+        usd_suffix(snapshot.locked_lamports, sol_usd)
+    );
 
-    let daily_ops: Vec<_> = all_ops
-        .into_iter()
-        .filter(|op| op.timestamp > yesterday)
-        .collect();
-
-    let total_reclaimed: u64 = daily_ops.iter().map(|op| op.reclaimed_amount).sum();
-
-    let operations_count = daily_ops.len();
-
-    println!("Operations in last 24h: {}", operations_count);
-    println!("Total reclaimed: {}", utils::format_sol(total_reclaimed));
-
-    // ✅ USE: notify_daily_summary
-    if let Some(notifier) = telegram::AutoNotifier::new(config) {
-        notifier
-            .notify_daily_summary(total_reclaimed, operations_count)
-            .await;
-        println!("{}", "✓ Daily summary sent via Telegram".green());
-    } else {
-        println!("{}", "⚠️  Telegram not configured".yellow());
+    if telegram::AutoNotifier::new(config).is_none() && config.webhooks.is_empty() {
+        println!("{}", "⚠️  No notification channels configured".yellow());
+        return Ok(());
     }
 
+    let bus = events::EventBus::new();
+    let subscribers = bus.spawn_subscribers(config, Default::default());
+    bus.publish(events::ReclaimEvent::DailySummary {
+        activity,
+        previous,
+        locked_lamports: snapshot.locked_lamports,
+        locked_change,
+        sol_usd,
+    });
+    bus.finish(subscribers).await;
+    println!("{}", "✓ Daily summary sent".green());
+
     Ok(())
 }
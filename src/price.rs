@@ -0,0 +1,190 @@
+// src/price.rs - SOL/USD price for valuing rent in summaries
+
+use crate::config::PriceConfig;
+use serde::Deserialize;
+use serde_json::Value;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+const COINGECKO_URL: &str = "https://api.coingecko.com/api/v3/simple/price?ids=solana&vs_currencies=usd";
+const PYTH_HERMES_URL: &str = "https://hermes.pyth.network";
+/// Pyth SOL/USD price feed
+pub const PYTH_SOL_USD_FEED: &str = "ef0d8b6fda2ceba41da15d4095d1da392a0d2f8ed0c6c7bc0f4cfac8c280b56d";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Where the SOL/USD price comes from
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PriceSource {
+    /// No USD values
+    #[default]
+    None,
+    CoinGecko,
+    /// Pyth Hermes price service
+    Pyth,
+}
+
+impl fmt::Display for PriceSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PriceSource::None => write!(f, "none"),
+            PriceSource::CoinGecko => write!(f, "CoinGecko"),
+            PriceSource::Pyth => write!(f, "Pyth"),
+        }
+    }
+}
+
+/// Cached SOL/USD price, refetched once older than `price.refresh_seconds`.
+///
+/// Clones share the cache.
+#[derive(Clone)]
+pub struct PriceOracle {
+    source: PriceSource,
+    url: String,
+    api_key: Option<String>,
+    refresh: Duration,
+    client: reqwest::Client,
+    cache: Arc<Mutex<Option<(f64, Instant)>>>,
+}
+
+impl PriceOracle {
+    /// `None` when no price source is configured
+    pub fn from_config(config: &PriceConfig) -> Option<Self> {
+        let url = match config.source {
+            PriceSource::None => return None,
+            PriceSource::CoinGecko => config.url.clone().unwrap_or_else(|| COINGECKO_URL.to_string()),
+            PriceSource::Pyth => format!(
+                "{}/v2/updates/price/latest?ids[]={}",
+                config.url.as_deref().unwrap_or(PYTH_HERMES_URL).trim_end_matches('/'),
+                config.pyth_feed_id.trim_start_matches("0x")
+            ),
+        };
+
+        Some(Self {
+            source: config.source,
+            url,
+            api_key: config.api_key.clone(),
+            refresh: Duration::from_secs(config.refresh_seconds.max(1)),
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+            cache: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Last fetched price, however old
+    pub fn latest(&self) -> Option<f64> {
+        self.cache.lock().unwrap().map(|(price, _)| price)
+    }
+
+    /// Current price, fetched if the cached one is due for a refresh. A failed
+    /// fetch falls back to the last known price.
+    pub async fn sol_usd(&self) -> Option<f64> {
+        let cached = *self.cache.lock().unwrap();
+        if let Some((price, fetched_at)) = cached {
+            if fetched_at.elapsed() < self.refresh {
+                return Some(price);
+            }
+        }
+
+        match self.fetch().await {
+            Ok(price) => {
+                debug!("SOL/USD from {}: {}", self.source, price);
+                *self.cache.lock().unwrap() = Some((price, Instant::now()));
+                Some(price)
+            }
+            Err(e) => {
+                warn!("Failed to fetch the SOL/USD price from {}: {}", self.source, e);
+                cached.map(|(price, _)| price)
+            }
+        }
+    }
+
+    /// Keep the cache fresh for readers of [`latest`](Self::latest)
+    pub async fn run(self) {
+        loop {
+            self.sol_usd().await;
+            tokio::time::sleep(self.refresh).await;
+        }
+    }
+
+    async fn fetch(&self) -> anyhow::Result<f64> {
+        let mut request = self.client.get(&self.url);
+        if let (PriceSource::CoinGecko, Some(api_key)) = (self.source, &self.api_key) {
+            request = request.header("x-cg-demo-api-key", api_key);
+        }
+        let body: Value = request.send().await?.error_for_status()?.json().await?;
+
+        let price = match self.source {
+            PriceSource::CoinGecko => parse_coingecko(&body),
+            PriceSource::Pyth => parse_pyth(&body),
+            PriceSource::None => None,
+        };
+        price
+            .filter(|price| price.is_finite() && *price > 0.0)
+            .ok_or_else(|| anyhow::anyhow!("unexpected response: {}", body))
+    }
+}
+
+/// `{"solana":{"usd":142.5}}`
+fn parse_coingecko(body: &Value) -> Option<f64> {
+    body.get("solana")?.get("usd")?.as_f64()
+}
+
+/// Hermes `/v2/updates/price/latest`: the price is an integer string scaled by `10^expo`
+fn parse_pyth(body: &Value) -> Option<f64> {
+    let price = body.get("parsed")?.get(0)?.get("price")?;
+    let mantissa: i64 = price.get("price")?.as_str()?.parse().ok()?;
+    let expo = price.get("expo")?.as_i64()?;
+    Some(mantissa as f64 * 10f64.powi(expo as i32))
+}
+
+/// USD value of `lamports` at `sol_usd` per SOL
+pub fn lamports_to_usd(lamports: u64, sol_usd: f64) -> f64 {
+    crate::solana::rent::RentCalculator::lamports_to_sol(lamports) * sol_usd
+}
+
+/// `$1,234.56`
+pub fn format_usd(amount: f64) -> String {
+    let cents = (amount.abs() * 100.0).round() as u64;
+    let dollars = (cents / 100).to_string();
+
+    let mut grouped = String::with_capacity(dollars.len() + dollars.len() / 3);
+    for (i, digit) in dollars.chars().enumerate() {
+        if i > 0 && (dollars.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    let sign = if amount < 0.0 && cents > 0 { "-" } else { "" };
+    format!("{}${}.{:02}", sign, grouped, cents % 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_responses_and_formatting() {
+        let coingecko = serde_json::json!({ "solana": { "usd": 142.57 } });
+        assert_eq!(parse_coingecko(&coingecko), Some(142.57));
+
+        let pyth = serde_json::json!({
+            "parsed": [{
+                "id": PYTH_SOL_USD_FEED,
+                "price": { "price": "14257000000", "conf": "8000000", "expo": -8, "publish_time": 1760000000 }
+            }]
+        });
+        assert!((parse_pyth(&pyth).unwrap() - 142.57).abs() < 1e-9);
+        assert_eq!(parse_pyth(&serde_json::json!({ "parsed": [] })), None);
+
+        assert!((lamports_to_usd(2_039_280, 150.0) - 0.305892).abs() < 1e-9);
+        assert_eq!(format_usd(1_234_567.891), "$1,234,567.89");
+        assert_eq!(format_usd(0.305892), "$0.31");
+        assert_eq!(format_usd(-42.0), "-$42.00");
+    }
+}
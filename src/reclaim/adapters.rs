@@ -0,0 +1,188 @@
+// src/reclaim/adapters.rs - Config-driven close instructions for custom program accounts
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use std::collections::HashMap;
+use std::str::FromStr;
+use crate::{
+    config::{CloseAdapterConfig, Config},
+    error::{ReclaimError, Result},
+};
+use tracing::warn;
+
+/// One position in a close instruction's account list
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccountSeat {
+    /// The account being closed (writable)
+    Account,
+    /// Where the lamports go: the treasury wallet (writable)
+    Destination,
+    /// The operator signing the reclaim (signer)
+    Authority,
+    /// A fixed read-only account such as the system program
+    Fixed(Pubkey),
+}
+
+impl FromStr for AccountSeat {
+    type Err = ReclaimError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "account" => Ok(AccountSeat::Account),
+            "destination" => Ok(AccountSeat::Destination),
+            "authority" => Ok(AccountSeat::Authority),
+            other => Pubkey::from_str(other)
+                .map(AccountSeat::Fixed)
+                .map_err(|_| ReclaimError::ConfigError(format!(
+                    "Unknown account seat '{}' (expected account, destination, authority or a pubkey)",
+                    other
+                ))),
+        }
+    }
+}
+
+/// Close instruction template for one program
+#[derive(Debug, Clone)]
+pub struct CloseAdapter {
+    pub program_id: Pubkey,
+    pub name: String,
+    data: Vec<u8>,
+    seats: Vec<AccountSeat>,
+}
+
+impl CloseAdapter {
+    pub fn from_config(config: &CloseAdapterConfig) -> Result<Self> {
+        let program_id = Pubkey::from_str(&config.program_id)?;
+
+        let seats = config.accounts.iter()
+            .map(|s| s.parse())
+            .collect::<Result<Vec<AccountSeat>>>()?;
+
+        if !seats.contains(&AccountSeat::Account) {
+            return Err(ReclaimError::ConfigError(format!(
+                "Close adapter for {} must include the 'account' seat",
+                program_id
+            )));
+        }
+
+        Ok(Self {
+            program_id,
+            name: config.name.clone().unwrap_or_else(|| program_id.to_string()),
+            data: config.discriminator.clone(),
+            seats,
+        })
+    }
+
+    /// Build the close instruction for `account`
+    pub fn build_instruction(
+        &self,
+        account: &Pubkey,
+        destination: &Pubkey,
+        authority: &Pubkey,
+    ) -> Instruction {
+        let accounts = self.seats.iter()
+            .map(|seat| match seat {
+                AccountSeat::Account => AccountMeta::new(*account, false),
+                AccountSeat::Destination => AccountMeta::new(*destination, false),
+                AccountSeat::Authority => AccountMeta::new_readonly(*authority, true),
+                AccountSeat::Fixed(pubkey) => AccountMeta::new_readonly(*pubkey, false),
+            })
+            .collect();
+
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.data.clone(),
+        }
+    }
+}
+
+/// Close adapters keyed by program ID
+#[derive(Debug, Clone, Default)]
+pub struct CloseAdapters {
+    adapters: HashMap<Pubkey, CloseAdapter>,
+}
+
+impl CloseAdapters {
+    /// Adapters from `[[reclaim.close_adapters]]`. `Config::load` rejects
+    /// invalid entries; one in a hand-built config is skipped.
+    pub fn from_config(config: &Config) -> Self {
+        let mut adapters = HashMap::new();
+
+        for entry in &config.reclaim.close_adapters {
+            match CloseAdapter::from_config(entry) {
+                Ok(adapter) => {
+                    adapters.insert(adapter.program_id, adapter);
+                }
+                Err(e) => warn!("Skipping close adapter for {}: {}", entry.program_id, e),
+            }
+        }
+
+        Self { adapters }
+    }
+
+    pub fn get(&self, program_id: &Pubkey) -> Option<&CloseAdapter> {
+        self.adapters.get(program_id)
+    }
+
+    pub fn contains(&self, program_id: &Pubkey) -> bool {
+        self.adapters.contains_key(program_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adapter_config(program_id: &Pubkey, accounts: &[&str]) -> CloseAdapterConfig {
+        CloseAdapterConfig {
+            program_id: program_id.to_string(),
+            name: None,
+            discriminator: vec![9],
+            accounts: accounts.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_account_seat_parsing() {
+        let fixed = Pubkey::new_unique();
+        assert_eq!("account".parse::<AccountSeat>().unwrap(), AccountSeat::Account);
+        assert_eq!("destination".parse::<AccountSeat>().unwrap(), AccountSeat::Destination);
+        assert_eq!("authority".parse::<AccountSeat>().unwrap(), AccountSeat::Authority);
+        assert_eq!(fixed.to_string().parse::<AccountSeat>().unwrap(), AccountSeat::Fixed(fixed));
+        assert!(matches!("owner".parse::<AccountSeat>(), Err(ReclaimError::ConfigError(_))));
+    }
+
+    #[test]
+    fn test_from_config_and_build_instruction() {
+        let program_id = Pubkey::new_unique();
+        let system = solana_sdk::system_program::id();
+        let seats = ["destination", "account", &system.to_string(), "authority"];
+        let adapter = CloseAdapter::from_config(&adapter_config(&program_id, &seats)).unwrap();
+        assert_eq!(adapter.name, program_id.to_string());
+
+        let (account, destination, authority) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let ix = adapter.build_instruction(&account, &destination, &authority);
+        assert_eq!(ix.program_id, program_id);
+        assert_eq!(ix.data, vec![9]);
+        // Seats keep the configured order
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(destination, false),
+                AccountMeta::new(account, false),
+                AccountMeta::new_readonly(system, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
+
+        // The closed account must have a seat, and seats and program must parse
+        assert!(CloseAdapter::from_config(&adapter_config(&program_id, &["destination", "authority"])).is_err());
+        assert!(CloseAdapter::from_config(&adapter_config(&program_id, &["account", "owner"])).is_err());
+        let mut bad_program = adapter_config(&program_id, &["account"]);
+        bad_program.program_id = "not-a-program".to_string();
+        assert!(CloseAdapter::from_config(&bad_program).is_err());
+    }
+}
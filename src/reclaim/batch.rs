@@ -1,21 +1,28 @@
-// src/reclaim/batch.rs - Enhanced with RateLimiter
+// src/reclaim/batch.rs - Batch reclaims paced by a token-bucket RateLimiter
 
 use solana_sdk::pubkey::Pubkey;
 use crate::{
+    config::AdaptiveBatchConfig,
     error::Result,
     reclaim::engine::{ReclaimEngine, ReclaimResult},
+    reclaim::plan::CostPlan,
+    reclaim::tuning::{BatchOutcome, BatchTuner},
     kora::types::AccountType,
-    utils::RateLimiter, // ✅ USE: Import RateLimiter
+    utils::RateLimiter,
 };
 use tracing::{info, warn};
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
 
 /// Batch processor for reclaiming multiple accounts with rate limiting
 pub struct BatchProcessor {
     engine: ReclaimEngine,
     batch_size: usize,
     batch_delay: Duration,
-    rate_limiter: RateLimiter, // ✅ USE: Add RateLimiter field
+    rate_limiter: RateLimiter,
+    cancel: CancellationToken,
+    /// Tuning bounds when batch size and delay adapt to each batch's outcome
+    adaptive: Option<AdaptiveBatchConfig>,
 }
 
 impl BatchProcessor {
@@ -24,10 +31,30 @@ impl BatchProcessor {
             engine,
             batch_size,
             batch_delay: Duration::from_millis(batch_delay_ms),
-            rate_limiter: RateLimiter::new(batch_delay_ms), // ✅ USE: new()
+            rate_limiter: RateLimiter::from_delay_ms(batch_delay_ms), // one token per batch_delay_ms
+            cancel: CancellationToken::new(),
+            adaptive: None,
         }
     }
     
+    /// Adapt batch size and delay between batches when `adaptive.enabled`,
+    /// starting from the configured values
+    pub fn with_adaptive(mut self, adaptive: &AdaptiveBatchConfig) -> Self {
+        if adaptive.enabled {
+            // The tuner paces batches; the limiter only enforces its floor
+            self.rate_limiter = RateLimiter::from_delay_ms(adaptive.min_delay_ms);
+            self.adaptive = Some(adaptive.clone());
+        }
+        self
+    }
+    
+    /// Stop before the next chunk once `cancel` is cancelled. Transactions
+    /// already sent are awaited, so every attempt still ends up in the summary.
+    pub fn with_cancel(mut self, cancel: CancellationToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+    
     /// Process multiple accounts in batches with rate limiting
     pub async fn process_batch(
         &self,
@@ -41,58 +68,89 @@ impl BatchProcessor {
         
         let mut summary = BatchSummary::default();
         summary.total_accounts = accounts.len();
+        let mut tuner = self
+            .adaptive
+            .clone()
+            .map(|config| BatchTuner::new(config, self.batch_size, self.batch_delay));
         
-        // Process in batches
-        for (batch_num, chunk) in accounts.chunks(self.batch_size).enumerate() {
-            info!("Processing batch {}/{}", batch_num + 1, (accounts.len() + self.batch_size - 1) / self.batch_size);
+        // Process in batches, sized by the tuner when adapting
+        let mut remaining = accounts.as_slice();
+        let mut batch_num = 0;
+        while !remaining.is_empty() {
+            if self.cancel.is_cancelled() {
+                warn!(
+                    "Batch cancelled with {} of {} accounts not attempted",
+                    accounts.len() - summary.results.len(),
+                    accounts.len()
+                );
+                summary.cancelled = true;
+                break;
+            }
+            
+            let batch_size = tuner.as_ref().map_or(self.batch_size, BatchTuner::batch_size).max(1);
+            let (chunk, rest) = remaining.split_at(batch_size.min(remaining.len()));
+            remaining = rest;
+            batch_num += 1;
+            info!(
+                "Processing batch {} ({} accounts, {} left after it)",
+                batch_num,
+                chunk.len(),
+                remaining.len()
+            );
             
-            // ✅ USE: wait() - Rate limit before processing each batch
+            // Take a token before each batch
             self.rate_limiter.wait().await;
             
+            let started = Instant::now();
+            let rate_limited_before = crate::solana::client::rate_limited_count();
+            let mut transient_failures = 0;
             let results = self.engine.batch_reclaim(chunk).await;
             
-            // Handle batch results with retry for failed chunks
+            // Handle batch results, retrying only failures that may clear up
             match results {
                 Ok(res) => {
-                    // Process successful batch results
-                    for (pubkey, result) in res {
-                        match result {
-                            Ok(reclaim_res) => {
-                                summary.successful += 1;
-                                summary.total_reclaimed += reclaim_res.amount_reclaimed;
-                                summary.results.push((pubkey, Ok(reclaim_res)));
+                    // Results come back in the chunk's order
+                    for ((pubkey, result), (_, account_type)) in res.into_iter().zip(chunk) {
+                        let result = match result {
+                            Err(e) if e.is_retryable() => {
+                                transient_failures += 1;
+                                warn!("Transient failure reclaiming {}: {}. Retrying once...", pubkey, e);
+                                self.engine.reclaim_account(&pubkey, account_type).await
                             }
-                            Err(e) => {
-                                summary.failed += 1;
-                                warn!("Failed to reclaim {}: {}", pubkey, e);
-                                summary.results.push((pubkey, Err(e)));
-                            }
-                        }
+                            result => result,
+                        };
+                        summary.record(pubkey, result);
                     }
                 }
-                Err(e) => {
-                    // If entire batch failed, retry individual accounts
+                Err(e) if e.is_retryable() => {
+                    // If entire batch failed transiently, retry individual accounts
                     warn!("Batch reclaim failed for chunk: {}. Retrying individual accounts...", e);
+                    transient_failures = chunk.len();
                     for (account, account_type) in chunk {
-                        match self.engine.reclaim_account(account, account_type).await {
-                            Ok(res) => {
-                                summary.successful += 1;
-                                summary.total_reclaimed += res.amount_reclaimed;
-                                summary.results.push((*account, Ok(res)));
-                            }
-                            Err(err) => {
-                                summary.failed += 1;
-                                warn!("Failed to reclaim {} on retry: {}", account, err);
-                                summary.results.push((*account, Err(err)));
-                            }
-                        }
+                        let result = self.engine.reclaim_account(account, account_type).await;
+                        summary.record(*account, result);
                     }
                 }
+                // Every account in the chunk would hit the same permanent error
+                Err(e) => return Err(e),
+            }
+            
+            if let Some(tuner) = tuner.as_mut() {
+                tuner.observe(&BatchOutcome {
+                    accounts: chunk.len(),
+                    transient_failures,
+                    rate_limited: crate::solana::client::rate_limited_count() - rate_limited_before,
+                    elapsed: started.elapsed(),
+                });
             }
             
             // Delay between batches (except after last batch)
-            if batch_num < (accounts.len() + self.batch_size - 1) / self.batch_size - 1 {
-                tokio::time::sleep(self.batch_delay).await;
+            if !remaining.is_empty() {
+                let delay = tuner.as_ref().map_or(self.batch_delay, BatchTuner::delay);
+                tokio::select! {
+                    _ = self.cancel.cancelled() => {}
+                    _ = tokio::time::sleep(delay) => {}
+                }
             }
         }
         
@@ -117,7 +175,50 @@ impl BatchProcessor {
         }
         
         info!("Found {} eligible accounts for reclaim", eligible_accounts.len());
-        self.process_batch(eligible_accounts).await
+        let plan = self.plan(&eligible_accounts).await?;
+        self.execute(&plan, eligible_accounts).await
+    }
+    
+    /// Estimate fees and recovery from the accounts' current balances
+    pub async fn plan(&self, accounts: &[(Pubkey, AccountType)]) -> Result<CostPlan> {
+        let pubkeys: Vec<Pubkey> = accounts.iter().map(|(pubkey, _)| *pubkey).collect();
+        let mut balances = Vec::with_capacity(pubkeys.len());
+        
+        // getMultipleAccounts accepts at most 100 keys per request
+        for chunk in pubkeys.chunks(100) {
+            let fetched = self.engine.rpc_client.get_multiple_accounts(chunk).await?;
+            balances.extend(fetched.iter().map(|account| account.as_ref().map_or(0, |a| a.lamports)));
+        }
+        
+        let mut plan = CostPlan::new(&balances, &self.engine.fees, self.engine.signatures_per_transaction());
+        // getRecentPrioritizationFees accepts at most 128 addresses
+        match self.engine.rpc_client.get_recent_priority_fee(&pubkeys[..pubkeys.len().min(128)]).await {
+            Ok(fee) => plan.recent_priority_fee_micro_lamports = Some(fee),
+            Err(e) => warn!("Failed to fetch recent priority fees: {}", e),
+        }
+        
+        Ok(plan)
+    }
+    
+    /// Run a planned batch, unless its fees break `fees.max_cost_percent`
+    pub async fn execute(
+        &self,
+        plan: &CostPlan,
+        accounts: Vec<(Pubkey, AccountType)>,
+    ) -> Result<BatchSummary> {
+        info!("Batch cost plan:\n{}", plan);
+        
+        let max_cost_percent = self.engine.fees.max_cost_percent;
+        if plan.exceeds(max_cost_percent) {
+            return Err(crate::error::ReclaimError::Ineligible(format!(
+                "Batch aborted: estimated fees of {:.9} SOL are {:.2}% of the expected recovery (limit {}%)",
+                crate::solana::rent::RentCalculator::lamports_to_sol(plan.estimated_cost()),
+                plan.cost_percent(),
+                max_cost_percent.unwrap_or_default()
+            )));
+        }
+        
+        self.process_batch(accounts).await
     }
 }
 
@@ -129,9 +230,25 @@ pub struct BatchSummary {
     pub failed: usize,
     pub total_reclaimed: u64,
     pub results: Vec<(Pubkey, Result<ReclaimResult>)>,
+    /// Stopped early; accounts without a result were never attempted
+    pub cancelled: bool,
 }
 
 impl BatchSummary {
+    fn record(&mut self, pubkey: Pubkey, result: Result<ReclaimResult>) {
+        match &result {
+            Ok(res) => {
+                self.successful += 1;
+                self.total_reclaimed += res.amount_reclaimed;
+            }
+            Err(e) => {
+                self.failed += 1;
+                warn!("Failed to reclaim {}: {}", pubkey, e);
+            }
+        }
+        self.results.push((pubkey, result));
+    }
+    
     /// Print a formatted summary to console
     pub fn print_summary(&self) {
         println!("\n{}", "=== Reclaim Batch Summary ===".to_string());
@@ -145,6 +262,9 @@ impl BatchSummary {
         );
             
         println!("Success Rate:    {:.1}%", self.success_rate());
+        if self.cancelled {
+            println!("Cancelled:       {} not attempted", self.total_accounts - self.results.len());
+        }
         println!("{}", "============================".to_string());
     }
     
@@ -0,0 +1,216 @@
+// src/reclaim/close_request.rs - Solana Pay links asking users to close their own accounts
+
+use crate::error::{ReclaimError, Result};
+use crate::solana::SolanaRpcClient;
+use base64::Engine;
+use solana_sdk::{
+    account::Account, hash::Hash, message::Message, program_pack::Pack, pubkey::Pubkey,
+    transaction::Transaction,
+};
+use spl_token::state::{Account as TokenAccount, AccountState};
+
+/// API path serving transaction requests, followed by the account pubkey
+pub const CLOSE_REQUEST_PATH: &str = "/close-request/";
+
+/// Solana Pay transaction request link for closing `account`.
+///
+/// Wallets fetch a transaction from the API at `public_url`, so the link
+/// contains no query string and needs no URL encoding.
+pub fn transaction_request_link(public_url: &str, account: &Pubkey) -> String {
+    format!("solana:{}{}{}", public_url.trim_end_matches('/'), CLOSE_REQUEST_PATH, account)
+}
+
+/// Terminal rendering of `link` as a QR code
+pub fn qr_code(link: &str) -> Result<String> {
+    let code = qrcode::QrCode::new(link.as_bytes())
+        .map_err(|e| anyhow::anyhow!("Failed to encode QR code: {}", e))?;
+    Ok(code
+        .render::<qrcode::render::unicode::Dense1x2>()
+        .quiet_zone(true)
+        .build())
+}
+
+/// Who can close the SPL token account `account` right now: its close
+/// authority if set, otherwise the token owner
+pub fn user_close_authority(account: &Account) -> Result<Pubkey> {
+    let unsupported = |reason: String| Err(ReclaimError::Ineligible(reason));
+
+    if account.owner != spl_token::id() {
+        return unsupported(format!(
+            "Only SPL Token accounts can be closed by request (owner is {})",
+            account.owner
+        ));
+    }
+    let token = TokenAccount::unpack(&account.data)
+        .map_err(|_| ReclaimError::Ineligible("Invalid SPL Token account data".to_string()))?;
+
+    if token.state == AccountState::Frozen {
+        return unsupported("Token account is frozen".to_string());
+    }
+    if token.amount > 0 {
+        return unsupported(format!(
+            "Token account still holds {} tokens; they must be moved or burned first",
+            token.amount
+        ));
+    }
+
+    Ok(Option::<Pubkey>::from(token.close_authority).unwrap_or(token.owner))
+}
+
+/// Check that `signer` can close the SPL token account `account` right now
+pub fn verify_user_closable(account: &Account, signer: &Pubkey) -> Result<()> {
+    let authority = user_close_authority(account)?;
+    if authority != *signer {
+        return Err(ReclaimError::Ineligible(format!(
+            "Only {} can close this token account",
+            authority
+        )));
+    }
+    Ok(())
+}
+
+/// Unsigned transaction closing `account` into `destination`, paid and signed by `signer`
+pub fn build_close_transaction(
+    account_pubkey: &Pubkey,
+    account: &Account,
+    signer: &Pubkey,
+    destination: &Pubkey,
+    recent_blockhash: Hash,
+) -> Result<Transaction> {
+    verify_user_closable(account, signer)?;
+
+    let instruction = spl_token::instruction::close_account(
+        &spl_token::id(),
+        account_pubkey,
+        destination,
+        signer,
+        &[],
+    )?;
+    let message = Message::new_with_blockhash(&[instruction], Some(signer), &recent_blockhash);
+    Ok(Transaction::new_unsigned(message))
+}
+
+/// Transaction and wallet message answering a Solana Pay POST
+#[derive(Debug, Clone)]
+pub struct CloseTransaction {
+    /// Base64 of the bincode-serialized, unsigned transaction
+    pub transaction: String,
+    pub message: String,
+}
+
+/// Link to share with the user who can close an account
+#[derive(Debug, Clone)]
+pub struct CloseRequestLink {
+    pub link: String,
+    /// Wallet that has to sign the close
+    pub authority: Pubkey,
+    /// Rent returned to the treasury if the user closes now
+    pub lamports: u64,
+}
+
+/// Builds close transactions that send the rent back to the treasury
+#[derive(Clone)]
+pub struct CloseRequestBuilder {
+    rpc_client: SolanaRpcClient,
+    treasury_wallet: Pubkey,
+}
+
+impl CloseRequestBuilder {
+    pub fn new(rpc_client: SolanaRpcClient, treasury_wallet: Pubkey) -> Self {
+        Self {
+            rpc_client,
+            treasury_wallet,
+        }
+    }
+
+    /// Link for `account_pubkey`, if its current state lets the user close it
+    pub async fn link(&self, public_url: &str, account_pubkey: &Pubkey) -> Result<CloseRequestLink> {
+        let account = self
+            .rpc_client
+            .get_account(account_pubkey)
+            .await?
+            .ok_or_else(|| ReclaimError::AccountNotFound(account_pubkey.to_string()))?;
+
+        Ok(CloseRequestLink {
+            link: transaction_request_link(public_url, account_pubkey),
+            authority: user_close_authority(&account)?,
+            lamports: account.lamports,
+        })
+    }
+
+    /// Transaction for `signer` to close `account_pubkey` with its current state
+    pub async fn transaction(&self, account_pubkey: &Pubkey, signer: &Pubkey) -> Result<CloseTransaction> {
+        let account = self
+            .rpc_client
+            .get_account(account_pubkey)
+            .await?
+            .ok_or_else(|| ReclaimError::AccountNotFound(account_pubkey.to_string()))?;
+
+        let transaction = build_close_transaction(
+            account_pubkey,
+            &account,
+            signer,
+            &self.treasury_wallet,
+            self.rpc_client.get_latest_blockhash()?,
+        )?;
+        let serialized = bincode::serialize(&transaction)
+            .map_err(|e| anyhow::anyhow!("Failed to serialize transaction: {}", e))?;
+
+        Ok(CloseTransaction {
+            transaction: base64::engine::general_purpose::STANDARD.encode(serialized),
+            message: format!(
+                "Close your empty token account and return {:.9} SOL of rent to the sponsor",
+                crate::solana::rent::RentCalculator::lamports_to_sol(account.lamports)
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::program_option::COption;
+
+    fn token_account(owner: Pubkey, amount: u64, close_authority: Option<Pubkey>) -> Account {
+        let token = TokenAccount {
+            mint: Pubkey::new_unique(),
+            owner,
+            amount,
+            state: AccountState::Initialized,
+            close_authority: close_authority.map_or(COption::None, COption::Some),
+            ..Default::default()
+        };
+        let mut data = vec![0; TokenAccount::LEN];
+        token.pack_into_slice(&mut data);
+
+        Account {
+            lamports: 2_039_280,
+            data,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn test_close_transaction_is_paid_by_token_owner() {
+        let user = Pubkey::new_unique();
+        let treasury = Pubkey::new_unique();
+        let ata = Pubkey::new_unique();
+
+        let transaction =
+            build_close_transaction(&ata, &token_account(user, 0, None), &user, &treasury, Hash::default()).unwrap();
+        assert_eq!(transaction.message.account_keys[0], user);
+        assert!(transaction.message.account_keys.contains(&treasury));
+        assert_eq!(transaction.signatures.len(), 1);
+
+        assert!(verify_user_closable(&token_account(user, 5, None), &user).is_err());
+        assert!(verify_user_closable(&token_account(user, 0, Some(treasury)), &user).is_err());
+        assert!(verify_user_closable(&token_account(user, 0, None), &treasury).is_err());
+
+        assert_eq!(
+            transaction_request_link("https://reclaim.example.com/", &ata),
+            format!("solana:https://reclaim.example.com/close-request/{}", ata)
+        );
+    }
+}
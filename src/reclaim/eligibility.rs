@@ -5,44 +5,168 @@ use chrono::{DateTime, Utc, Duration};
 use crate::{
     error::Result,
     solana::{client::SolanaRpcClient, accounts::AccountDiscovery},
-    config::Config,
+    config::{Config, InactivityCheck, ReclaimableType},
     kora::types::AccountType,
+    storage::{models::OwnerActivity, Database},
 };
 use tracing::{debug};
 
+/// getMultipleAccounts accepts at most 100 keys per request
+const MAX_BATCH_SIZE: usize = 100;
+
+/// How long an account's place in the inactivity sample lasts; each period
+/// draws a different share of accounts
+const SAMPLE_PERIOD_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Whether `pubkey` is in the `rate` share of accounts whose last activity is
+/// looked up during `period`. The draw hashes both, so it is the same in every
+/// process and on every call within the period.
+fn in_inactivity_sample(pubkey: &Pubkey, period: u64, rate: f64) -> bool {
+    let hash = solana_sdk::hash::hashv(&[pubkey.as_ref(), &period.to_le_bytes()]);
+    let draw = u64::from_le_bytes(hash.to_bytes()[..8].try_into().unwrap());
+    (draw as f64 / u64::MAX as f64) < rate
+}
+
+/// Candidates still worth a detailed check, one entry per pubkey passed to
+/// `EligibilityChecker::prescreen` and in the same order
+#[derive(Debug, Clone, Default)]
+pub struct Prescreen {
+    /// `None` for accounts that are closed or hold no lamports
+    accounts: Vec<Option<solana_sdk::account::Account>>,
+    /// Indexes whose fetch failed, so nothing is known about them
+    failed: Vec<usize>,
+}
+
+impl Prescreen {
+    /// The fetched account at `index`, if it is still live
+    pub fn get(&self, index: usize) -> Option<&solana_sdk::account::Account> {
+        self.accounts.get(index).and_then(Option::as_ref)
+    }
+
+    pub fn is_live(&self, index: usize) -> bool {
+        self.get(index).is_some()
+    }
+
+    /// Whether the account at `index` was fetched and found closed or empty
+    pub fn is_gone(&self, index: usize) -> bool {
+        index < self.accounts.len() && !self.is_live(index) && !self.failed.contains(&index)
+    }
+
+    pub fn live_count(&self) -> usize {
+        self.accounts.iter().filter(|account| account.is_some()).count()
+    }
+}
+
 pub struct EligibilityChecker {
     rpc_client: SolanaRpcClient,
     config: Config,
+    /// Where per-account holds are read from; without it every account
+    /// uses `reclaim.min_inactive_days`
+    holds: Option<Database>,
 }
 
 impl EligibilityChecker {
     pub fn new(rpc_client: SolanaRpcClient, config: Config) -> Self {
-        Self { rpc_client, config }
+        Self { rpc_client, config, holds: None }
+    }
+
+    /// Honor holds set on tracked accounts in `db`
+    pub fn with_holds(mut self, db: Database) -> Self {
+        self.holds = Some(db);
+        self
+    }
+
+    /// Days `pubkey` must stay inactive: its hold, else `reclaim.min_inactive_days`
+    fn min_inactive_days(&self, pubkey: &Pubkey) -> Result<u64> {
+        let hold = match &self.holds {
+            Some(db) => db.get_min_inactive_days(&pubkey.to_string())?,
+            None => None,
+        };
+        Ok(hold.unwrap_or(self.config.reclaim.min_inactive_days))
     }
     
+    /// Phase one of a scan: fetch `pubkeys` with getMultipleAccounts and
+    /// flag those that are closed or hold no lamports, so only the rest go
+    /// through the per-account checks
+    pub async fn prescreen(&self, pubkeys: &[Pubkey]) -> Result<Prescreen> {
+        let mut accounts = Vec::with_capacity(pubkeys.len());
+        for chunk in pubkeys.chunks(MAX_BATCH_SIZE) {
+            let fetched = self.rpc_client.get_multiple_accounts(chunk).await?;
+            accounts.extend(fetched.into_iter().map(|account| account.filter(|a| a.lamports > 0)));
+        }
+
+        let prescreen = Prescreen { accounts, failed: Vec::new() };
+        debug!("Prescreen: {} of {} candidates still live", prescreen.live_count(), pubkeys.len());
+        Ok(prescreen)
+    }
+
+    /// `prescreen` one account at a time, for when the batched fetch fails.
+    /// An account whose own fetch fails is neither live nor gone.
+    pub async fn prescreen_each(&self, pubkeys: &[Pubkey]) -> Prescreen {
+        let mut prescreen = Prescreen::default();
+        for (index, pubkey) in pubkeys.iter().enumerate() {
+            match self.rpc_client.get_account(pubkey).await {
+                Ok(account) => prescreen.accounts.push(account.filter(|a| a.lamports > 0)),
+                Err(e) => {
+                    debug!("Failed to fetch {}: {}", pubkey, e);
+                    prescreen.accounts.push(None);
+                    prescreen.failed.push(index);
+                }
+            }
+        }
+        debug!(
+            "Prescreen: {} of {} candidates still live, {} not fetched",
+            prescreen.live_count(),
+            pubkeys.len(),
+            prescreen.failed.len()
+        );
+        prescreen
+    }
+
     pub async fn is_eligible(&self, pubkey: &Pubkey, created_at: DateTime<Utc>) -> Result<bool> {
-        // Check whitelist first (never reclaim)
-       if self.is_blacklisted(pubkey) {
-        debug!("Account {} is blacklisted", pubkey);
-        return Ok(false);
+        // Listed accounts are settled without a fetch
+        if !self.passes_lists(pubkey) {
+            return Ok(false);
+        }
+
+        let account = self.rpc_client.get_account(pubkey).await?;
+        let Some(account) = account else {
+            return Err(crate::error::ReclaimError::AccountNotFound(
+                format!("Account {} does not exist", pubkey)
+            ));
+        };
+
+        self.is_eligible_account(pubkey, &account, created_at).await
     }
-    
-    // Whitelist check - if whitelist exists and is not empty, ONLY reclaim whitelisted accounts
-    if !self.config.reclaim.whitelist.is_empty() {
-        if !self.is_whitelisted(pubkey) {
+
+    /// Whether the blacklist and, when set, the whitelist allow reclaiming `pubkey`
+    fn passes_lists(&self, pubkey: &Pubkey) -> bool {
+        // Check blacklist first (never reclaim)
+        if self.is_blacklisted(pubkey) {
+            debug!("Account {} is blacklisted", pubkey);
+            return false;
+        }
+
+        // Whitelist check - if whitelist exists and is not empty, ONLY reclaim whitelisted accounts
+        if !self.config.reclaim.whitelist.is_empty() && !self.is_whitelisted(pubkey) {
             debug!("Account {} not on whitelist", pubkey);
-            return Ok(false);
+            return false;
         }
+
+        true
     }
-        
-        let account = self.rpc_client.get_account(pubkey).await?;
-if account.is_none() {
-    return Err(crate::error::ReclaimError::AccountNotFound(
-        format!("Account {} does not exist", pubkey)
-    ));
-}
-        
-        let account = account.unwrap();
+
+    /// Phase two of a scan: the eligibility checks for an account already
+    /// fetched, e.g. by `prescreen`
+    pub async fn is_eligible_account(
+        &self,
+        pubkey: &Pubkey,
+        account: &solana_sdk::account::Account,
+        created_at: DateTime<Utc>,
+    ) -> Result<bool> {
+        if !self.passes_lists(pubkey) {
+            return Ok(false);
+        }
         
         // Account must have balance to reclaim
         if account.lamports == 0 {
@@ -51,20 +175,20 @@ if account.is_none() {
         }
         
         // Check if account type is reclaimable
-        let account_type = self.determine_account_type(&account);
+        let account_type = self.determine_account_type(account);
         if !self.is_reclaimable_type(&account_type) {
             debug!("Account {} is not reclaimable (type: {:?})", pubkey, account_type);
             return Ok(false);
         }
         
-        // For SPL Token accounts, verify token balance and close authority
-        if matches!(account_type, AccountType::SplToken) {
+        // For SPL Token and Token-2022 accounts, verify token balance and close authority
+        if account_type.is_token_account() {
             // CRITICAL: Check if token account has zero token balance
             // SPL Token amount is stored at bytes 64-71 as u64 little-endian
             if account.data.len() >= 72 {
                 let amount_bytes: [u8; 8] = account.data[64..72]
                     .try_into()
-                    .map_err(|_| crate::error::ReclaimError::NotEligible(
+                    .map_err(|_| crate::error::ReclaimError::Ineligible(
                         "Failed to parse token amount".to_string()
                     ))?;
                 let token_amount = u64::from_le_bytes(amount_bytes);
@@ -76,14 +200,20 @@ if account.is_none() {
             }
             
             // Verify operator has close authority
-            if !self.has_close_authority(&account).await? {
+            if !self.has_close_authority(account).await? {
                 debug!("Account {} - operator doesn't have close authority", pubkey);
                 return Ok(false);
             }
         }
         
+        // Only the nonce authority can withdraw from a nonce account
+        if matches!(account_type, AccountType::NonceAccount) && !self.is_nonce_authority(account)? {
+            debug!("Account {} - operator is not the nonce authority", pubkey);
+            return Ok(false);
+        }
+        
         let now = Utc::now();
-        let min_inactive = Duration::days(self.config.reclaim.min_inactive_days as i64);
+        let min_inactive = Duration::days(self.min_inactive_days(pubkey)? as i64);
         
         if now - created_at < min_inactive {
             debug!("Account {} hasn't been inactive long enough (created: {})", pubkey, created_at);
@@ -91,22 +221,26 @@ if account.is_none() {
         }
         
         // Check last activity time with improved error handling
-        let is_inactive = match self.check_inactivity(pubkey).await {
-            Ok(inactive) => inactive,
-            Err(e) => {
-                tracing::warn!("Failed to check inactivity for {}: {}. Assuming active to be conservative.", pubkey, e);
-                // Conservative: assume active on error to avoid premature reclaim
-                false
+        if let Some(skipped) = self.skipped_inactivity_check(pubkey) {
+            debug!("Account {} relies on creation age: {}", pubkey, skipped);
+        } else {
+            let is_inactive = match self.check_inactivity(pubkey).await {
+                Ok(inactive) => inactive,
+                Err(e) => {
+                    tracing::warn!("Failed to check inactivity for {}: {}. Assuming active to be conservative.", pubkey, e);
+                    // Conservative: assume active on error to avoid premature reclaim
+                    false
+                }
+            };
+            
+            if !is_inactive {
+                debug!("Account {} has recent activity", pubkey);
+                return Ok(false);
             }
-        };
-        
-        if !is_inactive {
-            debug!("Account {} has recent activity", pubkey);
-            return Ok(false);
         }
         
         let min_balance = self.rpc_client.get_minimum_balance_for_rent_exemption(account.data.len())?;
-        let is_empty = crate::solana::rent::RentCalculator::is_empty_account(&account, min_balance);
+        let is_empty = crate::solana::rent::RentCalculator::is_empty_account(account, min_balance);
         
         if is_empty {
             debug!("Account {} is eligible: empty and inactive", pubkey);
@@ -139,22 +273,35 @@ if account.is_none() {
 
 
     fn determine_account_type(&self, account: &solana_sdk::account::Account) -> AccountType {
-        if account.owner == spl_token::id() && account.data.len() >= 165 {
-            AccountType::SplToken
-        } else if account.owner == solana_sdk::system_program::id() {
-            AccountType::System
-        } else {
-            AccountType::Other(account.owner)
-        }
+        AccountType::from_account(account)
     }
     
+    /// Whether `reclaim.account_types` lets the bot close this kind of account
     fn is_reclaimable_type(&self, account_type: &AccountType) -> bool {
+        let enabled = |kind: ReclaimableType| self.config.reclaim.account_types.contains(&kind);
         match account_type {
-            AccountType::System => false,
-            AccountType::SplToken => true,
-            AccountType::Other(_) => false,
+            AccountType::System | AccountType::SeededSystem => false,
+            AccountType::SplToken => enabled(ReclaimableType::SplToken),
+            AccountType::NonceAccount => enabled(ReclaimableType::Nonce),
+            AccountType::Other(_) if account_type.is_token_account() && enabled(ReclaimableType::Token2022) => true,
+            AccountType::Other(program_id) => {
+                self.has_close_adapter(program_id)
+                    && (enabled(ReclaimableType::CloseAdapters) || enabled(ReclaimableType::Program(*program_id)))
+            }
         }
     }
+    
+    /// Whether the operator is the authority of a nonce account
+    fn is_nonce_authority(&self, account: &solana_sdk::account::Account) -> Result<bool> {
+        let operator = self.config.operator_pubkey()?;
+        Ok(crate::solana::accounts::nonce_authority(&account.data) == Some(operator))
+    }
+    
+    /// Whether `[[reclaim.close_adapters]]` covers this program
+    fn has_close_adapter(&self, program_id: &Pubkey) -> bool {
+        let program_id = program_id.to_string();
+        self.config.reclaim.close_adapters.iter().any(|a| a.program_id == program_id)
+    }
 
 
     // Add to impl EligibilityChecker in src/reclaim/eligibility.rs
@@ -173,7 +320,7 @@ pub async fn determine_reclaim_strategy(
     let account_type = self.determine_account_type(&account);
     
     match account_type {
-        AccountType::System => {
+        AccountType::System | AccountType::SeededSystem => {
             // System accounts: user controls the keys
             Ok((
                 crate::storage::models::ReclaimStrategy::Unrecoverable,
@@ -181,24 +328,38 @@ pub async fn determine_reclaim_strategy(
             ))
         }
         
-        AccountType::SplToken => {
-            // Check if operator has close authority
-            if self.has_close_authority(&account).await? {
-                let operator = self.config.operator_pubkey()?;
+        AccountType::SplToken => self.token_account_strategy(&account).await,
+        
+        AccountType::Other(_) if account_type.is_token_account() && self.is_reclaimable_type(&account_type) => {
+            // Token-2022, once enabled, is classified like SPL Token
+            self.token_account_strategy(&account).await
+        }
+        
+        AccountType::NonceAccount => {
+            let authority = crate::solana::accounts::nonce_authority(&account.data);
+            if self.is_nonce_authority(&account)? {
                 Ok((
                     crate::storage::models::ReclaimStrategy::ActiveReclaim,
-                    Some(operator.to_string())
+                    authority.map(|a| a.to_string())
                 ))
             } else {
-                // Try to get the actual close authority
-                let close_authority = self.get_token_close_authority(&account)?;
+                // The nonce authority can withdraw and return the rent
                 Ok((
                     crate::storage::models::ReclaimStrategy::PassiveMonitoring,
-                    close_authority
+                    authority.map(|a| a.to_string())
                 ))
             }
         }
         
+        AccountType::Other(_) if self.is_reclaimable_type(&account_type) => {
+            // Operator configured a close instruction for this program
+            let operator = self.config.operator_pubkey()?;
+            Ok((
+                crate::storage::models::ReclaimStrategy::ActiveReclaim,
+                Some(operator.to_string())
+            ))
+        }
+        
         AccountType::Other(_) => {
             // Custom programs: depends on program logic
             Ok((
@@ -209,6 +370,29 @@ pub async fn determine_reclaim_strategy(
     }
 }
 
+/// ActiveReclaim when the operator can close this token account, else
+/// PassiveMonitoring with whoever can
+async fn token_account_strategy(
+    &self,
+    account: &solana_sdk::account::Account,
+) -> Result<(crate::storage::models::ReclaimStrategy, Option<String>)> {
+    // Check if operator has close authority
+    if self.has_close_authority(account).await? {
+        let operator = self.config.operator_pubkey()?;
+        Ok((
+            crate::storage::models::ReclaimStrategy::ActiveReclaim,
+            Some(operator.to_string())
+        ))
+    } else {
+        // Try to get the actual close authority
+        let close_authority = self.get_token_close_authority(account)?;
+        Ok((
+            crate::storage::models::ReclaimStrategy::PassiveMonitoring,
+            close_authority
+        ))
+    }
+}
+
 /// Get the close authority from a token account
 fn get_token_close_authority(&self, account: &solana_sdk::account::Account) -> Result<Option<String>> {
     if account.data.len() < 165 {
@@ -220,7 +404,7 @@ fn get_token_close_authority(&self, account: &solana_sdk::account::Account) -> R
     if has_close_authority {
         let close_authority_bytes: [u8; 32] = account.data[130..162]
             .try_into()
-            .map_err(|_| crate::error::ReclaimError::NotEligible(
+            .map_err(|_| crate::error::ReclaimError::Ineligible(
                 "Failed to parse close authority".to_string()
             ))?;
         let close_authority = Pubkey::new_from_array(close_authority_bytes);
@@ -229,7 +413,7 @@ fn get_token_close_authority(&self, account: &solana_sdk::account::Account) -> R
         // No close authority set - owner is the authority
         let owner_bytes: [u8; 32] = account.data[32..64]
             .try_into()
-            .map_err(|_| crate::error::ReclaimError::NotEligible(
+            .map_err(|_| crate::error::ReclaimError::Ineligible(
                 "Failed to parse owner".to_string()
             ))?;
         let owner = Pubkey::new_from_array(owner_bytes);
@@ -256,7 +440,7 @@ fn get_token_close_authority(&self, account: &solana_sdk::account::Account) -> R
         if has_close_authority {
             let close_authority_bytes: [u8; 32] = account.data[130..162]
                 .try_into()
-                .map_err(|_| crate::error::ReclaimError::NotEligible(
+                .map_err(|_| crate::error::ReclaimError::Ineligible(
                     "Failed to parse close authority".to_string()
                 ))?;
             let close_authority = Pubkey::new_from_array(close_authority_bytes);
@@ -269,7 +453,7 @@ fn get_token_close_authority(&self, account: &solana_sdk::account::Account) -> R
             // No close authority set - check if operator is owner
             let owner_bytes: [u8; 32] = account.data[32..64]
                 .try_into()
-                .map_err(|_| crate::error::ReclaimError::NotEligible(
+                .map_err(|_| crate::error::ReclaimError::Ineligible(
                     "Failed to parse owner".to_string()
                 ))?;
             let owner = Pubkey::new_from_array(owner_bytes);
@@ -279,6 +463,21 @@ fn get_token_close_authority(&self, account: &solana_sdk::account::Account) -> R
         }
     }
     
+    /// Why `reclaim.inactivity_check` skips the last-activity lookup for
+    /// `pubkey`, or `None` to run it. A sampled account stays sampled for the
+    /// whole period, so rechecking it cannot draw it out of the sample.
+    fn skipped_inactivity_check(&self, pubkey: &Pubkey) -> Option<&'static str> {
+        match self.config.reclaim.inactivity_check {
+            InactivityCheck::Always => None,
+            InactivityCheck::Never => Some("inactivity check disabled"),
+            InactivityCheck::Sample => {
+                let period = Utc::now().timestamp().div_euclid(SAMPLE_PERIOD_SECS) as u64;
+                let sampled = in_inactivity_sample(pubkey, period, self.config.reclaim.inactivity_sample_rate);
+                (!sampled).then_some("not sampled for the inactivity check")
+            }
+        }
+    }
+
     pub async fn check_inactivity(&self, pubkey: &Pubkey) -> Result<bool> {
         let discovery = AccountDiscovery::new(
             self.rpc_client.clone(),
@@ -288,7 +487,7 @@ fn get_token_close_authority(&self, account: &solana_sdk::account::Account) -> R
         match discovery.get_last_transaction_time(pubkey).await? {
             Some(last_activity) => {
                 let now = Utc::now();
-                let min_inactive = Duration::days(self.config.reclaim.min_inactive_days as i64);
+                let min_inactive = Duration::days(self.min_inactive_days(pubkey)? as i64);
                 let inactive = now - last_activity > min_inactive;
                 
                 debug!(
@@ -307,6 +506,24 @@ fn get_token_close_authority(&self, account: &solana_sdk::account::Account) -> R
         }
     }
     
+    /// When the wallet owning an SPL token account last transacted; `None`
+    /// for closed accounts and other account types
+    pub async fn owner_activity(&self, pubkey: &Pubkey) -> Result<Option<OwnerActivity>> {
+        let Some(account) = self.rpc_client.get_account(pubkey).await? else {
+            return Ok(None);
+        };
+        if !matches!(self.determine_account_type(&account), AccountType::SplToken) || account.data.len() < 64 {
+            return Ok(None);
+        }
+        
+        let owner = Pubkey::new_from_array(account.data[32..64].try_into().unwrap_or_default());
+        let discovery = AccountDiscovery::new(self.rpc_client.clone(), Pubkey::default());
+        let last_active_at = discovery.get_last_transaction_time(&owner).await?;
+        debug!("Owner {} of {} last active: {:?}", owner, pubkey, last_active_at);
+        
+        Ok(Some(OwnerActivity { owner: owner.to_string(), last_active_at }))
+    }
+    
     fn is_whitelisted(&self, pubkey: &Pubkey) -> bool {
         self.config.reclaim.whitelist
             .iter()
@@ -319,28 +536,51 @@ fn get_token_close_authority(&self, account: &solana_sdk::account::Account) -> R
             .any(|addr| addr == &pubkey.to_string())
     }
     
-    pub async fn get_eligibility_reason(&self, pubkey: &Pubkey, created_at: DateTime<Utc>) -> Result<String> {
+    /// Why the white- or blacklist settles `pubkey`, if it does
+    fn list_reason(&self, pubkey: &Pubkey) -> Option<String> {
         if self.is_whitelisted(pubkey) {
-            return Ok("Account is whitelisted (protected)".to_string());
+            return Some("Account is whitelisted (protected)".to_string());
         }
         
         if self.is_blacklisted(pubkey) {
-            return Ok("Account is blacklisted (excluded)".to_string());
+            return Some("Account is blacklisted (excluded)".to_string());
+        }
+        
+        None
+    }
+    
+    pub async fn get_eligibility_reason(&self, pubkey: &Pubkey, created_at: DateTime<Utc>) -> Result<String> {
+        // Listed accounts are settled without a fetch
+        if let Some(reason) = self.list_reason(pubkey) {
+            return Ok(reason);
         }
         
         let account = self.rpc_client.get_account(pubkey).await?;
-        if account.is_none() {
-            return Ok("Account is closed (nothing to reclaim)".to_string());
+        self.eligibility_reason_for(pubkey, account.as_ref(), created_at).await
+    }
+    
+    /// The reason `get_eligibility_reason` gives for an account already
+    /// fetched; `None` means it no longer exists
+    pub async fn eligibility_reason_for(
+        &self,
+        pubkey: &Pubkey,
+        account: Option<&solana_sdk::account::Account>,
+        created_at: DateTime<Utc>,
+    ) -> Result<String> {
+        if let Some(reason) = self.list_reason(pubkey) {
+            return Ok(reason);
         }
         
-        let account = account.unwrap();
+        let Some(account) = account else {
+            return Ok("Account is closed (nothing to reclaim)".to_string());
+        };
         
         if account.lamports == 0 {
             return Ok("Account has zero balance (nothing to reclaim)".to_string());
         }
         
         // Check account type
-        let account_type = self.determine_account_type(&account);
+        let account_type = self.determine_account_type(account);
         if !self.is_reclaimable_type(&account_type) {
             return Ok(format!(
                 "Account type {:?} cannot be reclaimed (operator doesn't control it)",
@@ -349,40 +589,69 @@ fn get_token_close_authority(&self, account: &solana_sdk::account::Account) -> R
         }
         
         // For SPL Token, check close authority - ✅ FIX: Pass only account
-        if matches!(account_type, AccountType::SplToken) {
-            if !self.has_close_authority(&account).await? {
-                return Ok("Operator is not the close authority for this SPL Token account".to_string());
+        if account_type.is_token_account() {
+            if !self.has_close_authority(account).await? {
+                let reason = "Operator is not the close authority for this SPL Token account";
+                // Whether the user who can close it is still around
+                let owner = match self.owner_activity(pubkey).await {
+                    Ok(Some(owner)) => owner,
+                    _ => return Ok(reason.to_string()),
+                };
+                let now = Utc::now();
+                let state = if owner.is_churned(self.config.reclaim.churn_after_days, now) { "churned" } else { "active" };
+                return Ok(match owner.idle_days(now) {
+                    Some(days) => format!("{} (owner {} last active {} days ago)", reason, state, days),
+                    None => format!("{} (owner {}: no transactions)", reason, state),
+                });
             }
         }
         
+        if matches!(account_type, AccountType::NonceAccount) && !self.is_nonce_authority(account)? {
+            return Ok("Operator is not the nonce authority for this nonce account".to_string());
+        }
+        
         let now = Utc::now();
-        let min_inactive = Duration::days(self.config.reclaim.min_inactive_days as i64);
+        let min_inactive_days = self.min_inactive_days(pubkey)?;
+        let min_inactive = Duration::days(min_inactive_days as i64);
         let age = now - created_at;
         
         if age < min_inactive {
             let days_remaining = (min_inactive - age).num_days();
+            if min_inactive_days != self.config.reclaim.min_inactive_days {
+                return Ok(format!(
+                    "Account is held for {} days; needs {} more days of inactivity",
+                    min_inactive_days, days_remaining
+                ));
+            }
             return Ok(format!("Account needs {} more days of inactivity", days_remaining));
         }
         
-        let is_inactive = self.check_inactivity(pubkey).await.unwrap_or(false);
-        if !is_inactive {
-            return Ok("Account has recent activity".to_string());
-        }
+        // Eligible reasons say when inactivity was assumed from creation age
+        let basis = match self.skipped_inactivity_check(pubkey) {
+            Some(skipped) => format!(" (creation age only: {})", skipped),
+            None => {
+                let is_inactive = self.check_inactivity(pubkey).await.unwrap_or(false);
+                if !is_inactive {
+                    return Ok("Account has recent activity".to_string());
+                }
+                String::new()
+            }
+        };
         
         let min_balance = self.rpc_client.get_minimum_balance_for_rent_exemption(account.data.len())?;
-        let is_empty = crate::solana::rent::RentCalculator::is_empty_account(&account, min_balance);
+        let is_empty = crate::solana::rent::RentCalculator::is_empty_account(account, min_balance);
         
         if is_empty {
             return Ok(format!(
-                "Eligible for reclaim: empty account with {} lamports",
-                account.lamports
+                "Eligible for reclaim: empty account with {} lamports{}",
+                account.lamports, basis
             ));
         }
         
         if account.lamports <= min_balance * 2 {
             return Ok(format!(
-                "Eligible for reclaim: minimal balance ({} lamports)",
-                account.lamports
+                "Eligible for reclaim: minimal balance ({} lamports){}",
+                account.lamports, basis
             ));
         }
         
@@ -392,4 +661,116 @@ fn get_token_close_authority(&self, account: &solana_sdk::account::Account) -> R
             account.data.len()
         ))
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solana::test_rpc::{account_json, multiple_accounts, with_context, TestRpc};
+    use serde_json::Value;
+    use solana_sdk::account::Account;
+
+    fn checker(rpc: &TestRpc) -> EligibilityChecker {
+        let config: Config = toml::from_str(include_str!("../../config.toml")).unwrap();
+        EligibilityChecker::new(rpc.client(), config)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_prescreen_keeps_live_accounts() {
+        let (live, empty, missing) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let on_chain = vec![
+            (live, Account::new(2_039_280, 165, &spl_token::id())),
+            (empty, Account::new(0, 165, &spl_token::id())),
+        ];
+        let rpc = TestRpc::start(move |method, params| match method {
+            "getMultipleAccounts" => Ok(multiple_accounts(params, &on_chain)),
+            _ => Err(format!("unexpected {}", method)),
+        });
+
+        // More than one batch, in the order asked for
+        let mut pubkeys = vec![missing; MAX_BATCH_SIZE];
+        pubkeys.extend([live, empty, missing]);
+        let prescreen = checker(&rpc).prescreen(&pubkeys).await.unwrap();
+
+        assert_eq!(prescreen.live_count(), 1);
+        assert_eq!(prescreen.get(MAX_BATCH_SIZE).map(|a| a.lamports), Some(2_039_280));
+        assert!(prescreen.is_gone(0) && prescreen.is_gone(MAX_BATCH_SIZE + 1) && prescreen.is_gone(MAX_BATCH_SIZE + 2));
+        assert!(!prescreen.is_gone(MAX_BATCH_SIZE + 3));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_prescreen_each_when_batch_fails() {
+        let (live, missing, failing) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let account = Account::new(2_039_280, 165, &spl_token::id());
+        let rpc = TestRpc::start(move |method, params| match method {
+            "getAccountInfo" if params[0] == Value::String(live.to_string()) => {
+                Ok(with_context(account_json(Some(&account))))
+            }
+            "getAccountInfo" if params[0] == Value::String(missing.to_string()) => Ok(with_context(Value::Null)),
+            _ => Err("node overloaded".to_string()),
+        });
+        let checker = checker(&rpc);
+        let pubkeys = [live, missing, failing];
+
+        assert!(checker.prescreen(&pubkeys).await.is_err());
+        let prescreen = checker.prescreen_each(&pubkeys).await;
+        assert!(prescreen.is_live(0));
+        assert!(prescreen.is_gone(1));
+        // A failed fetch says nothing about the account
+        assert!(!prescreen.is_live(2) && !prescreen.is_gone(2));
+    }
+
+    #[test]
+    fn test_inactivity_sample_is_stable() {
+        let pubkeys: Vec<Pubkey> = (0..2_000).map(|_| Pubkey::new_unique()).collect();
+        let sampled = |period: u64, rate: f64| -> Vec<bool> {
+            pubkeys.iter().map(|pubkey| in_inactivity_sample(pubkey, period, rate)).collect()
+        };
+
+        // The same account and period always draw the same way
+        let this_period = sampled(7, 0.25);
+        assert_eq!(sampled(7, 0.25), this_period);
+        let share = this_period.iter().filter(|&&s| s).count();
+        assert!((400..600).contains(&share), "{} of 2000 sampled", share);
+        // Another period samples other accounts
+        assert_ne!(sampled(8, 0.25), this_period);
+
+        assert!(sampled(7, 0.0).iter().all(|&s| !s));
+        assert!(sampled(7, 1.0).iter().all(|&s| s));
+    }
+
+    #[test]
+    fn test_inactivity_check_modes() {
+        let rpc = TestRpc::start(|method, _| Err(format!("unexpected {}", method)));
+        let pubkey = Pubkey::new_unique();
+        let mut checker = checker(&rpc);
+        let mut with = |mode: InactivityCheck, rate: f64| {
+            checker.config.reclaim.inactivity_check = mode;
+            checker.config.reclaim.inactivity_sample_rate = rate;
+            checker.skipped_inactivity_check(&pubkey)
+        };
+
+        assert_eq!(with(InactivityCheck::Always, 0.0), None);
+        assert_eq!(with(InactivityCheck::Never, 1.0), Some("inactivity check disabled"));
+        assert_eq!(with(InactivityCheck::Sample, 1.0), None);
+        assert_eq!(with(InactivityCheck::Sample, 0.0), Some("not sampled for the inactivity check"));
+
+        // Asking again, as the eligibility reason does, gives the same answer
+        let first = with(InactivityCheck::Sample, 0.5);
+        assert!((0..50).all(|_| with(InactivityCheck::Sample, 0.5) == first));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_reason_for_fetched_account() {
+        let rpc = TestRpc::start(|method, _| Err(format!("unexpected {}", method)));
+        let checker = checker(&rpc);
+        let now = Utc::now();
+
+        let closed = checker.eligibility_reason_for(&Pubkey::new_unique(), None, now).await.unwrap();
+        assert_eq!(closed, "Account is closed (nothing to reclaim)");
+        let drained = Account::new(0, 165, &spl_token::id());
+        let empty = checker.eligibility_reason_for(&Pubkey::new_unique(), Some(&drained), now).await.unwrap();
+        assert_eq!(empty, "Account has zero balance (nothing to reclaim)");
+        // Neither needed the account fetched again
+        assert_eq!(rpc.calls("getAccountInfo"), 0);
+    }
+}
@@ -1,17 +1,47 @@
 use solana_sdk::{
+    account::Account,
+    compute_budget::ComputeBudgetInstruction,
+    hash::Hash,
     pubkey::Pubkey,
-    signature::{Keypair, Signer, Signature},
+    message::Message,
+    signature::Signature,
     transaction::Transaction,
     instruction::Instruction,
+    pubkey,
 };
 use spl_token::state::AccountState;
+use std::sync::Arc;
 use crate::{
+    config::FeeConfig,
     error::Result,
     solana::client::SolanaRpcClient,
     kora::types::AccountType,
+    reclaim::adapters::CloseAdapters,
+    reclaim::signer::TxSigner,
+    reclaim::verify::{treasury_credit, TreasuryCredit},
+    storage::models::ReclaimReason,
 };
 use tracing::{info, warn};
 
+/// Smallest priority fee increase per rebuild, in micro-lamports per compute
+/// unit, so a rebuild of a transaction sent without a priority fee pays one
+const MIN_PRIORITY_FEE_BUMP: u64 = 1_000;
+
+/// SPL Memo v2; a memo without signer accounts needs no extra signature
+const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// Longest memo attached, in bytes, to leave room in the transaction
+const MAX_MEMO_LEN: usize = 256;
+
+/// One transaction sent for a reclaim
+#[derive(Debug, Clone, PartialEq)]
+pub struct SendAttempt {
+    pub signature: Signature,
+    pub priority_fee_micro_lamports: u64,
+    /// Why the transaction did not land; `None` for the one that did
+    pub error: Option<String>,
+}
+
 /// Result of a reclaim operation
 #[derive(Debug, Clone)]
 pub struct ReclaimResult {
@@ -19,35 +49,122 @@ pub struct ReclaimResult {
     pub amount_reclaimed: u64,
     pub account: Pubkey,
     pub dry_run: bool,
+    /// Wallet the rent was sent to
+    pub destination: Pubkey,
+    /// Treasury credit read back from the confirmed transaction, if it could be
+    pub credit: Option<TreasuryCredit>,
+    /// Transactions sent, oldest first; more than one when an expired
+    /// blockhash forced a rebuild
+    pub attempts: Vec<SendAttempt>,
 }
 
+impl ReclaimResult {
+    /// Lamports the treasury was verified to have received
+    pub fn credited_amount(&self) -> Option<i64> {
+        self.credit.map(|credit| credit.credited)
+    }
+    
+    /// Fee paid by the landed transaction, when it was read back
+    pub fn fee_lamports(&self) -> Option<u64> {
+        self.credit.map(|credit| credit.fee)
+    }
+    
+    /// Alert for a confirmed reclaim whose treasury credit differs from the amount reclaimed
+    pub fn credit_mismatch_event(&self) -> Option<crate::events::ReclaimEvent> {
+        let credit = self.credit.filter(|credit| !credit.is_match())?;
+        Some(crate::events::ReclaimEvent::CreditMismatch {
+            pubkey: self.account.to_string(),
+            signature: self.signature?.to_string(),
+            expected: credit.expected,
+            credited: credit.credited,
+        })
+    }
+}
+
+/// Outcome of simulating a reclaim transaction without sending it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReclaimSimulation {
+    /// Balance the reclaim would recover
+    pub amount: u64,
+    pub units_consumed: Option<u64>,
+    /// Why the transaction would fail; `None` if it would succeed
+    pub error: Option<String>,
+}
+
+#[derive(Clone)]
 pub struct ReclaimEngine {
     pub(crate) rpc_client: SolanaRpcClient,
     pub(crate) treasury_wallet: Pubkey,
-    pub(crate) signer: Keypair,
+    /// Close authority
+    pub(crate) signer: Arc<dyn TxSigner>,
+    /// Pays transaction fees instead of `signer`, which then only authorizes the close
+    pub(crate) fee_payer: Option<Arc<dyn TxSigner>>,
     pub(crate) dry_run: bool,
+    pub(crate) close_adapters: Arc<CloseAdapters>,
+    pub(crate) fees: FeeConfig,
+    /// `reclaim.memo` template, and the reason filled into it
+    pub(crate) memo: Option<(String, ReclaimReason)>,
 }
 
 impl ReclaimEngine {
     pub fn new(
         rpc_client: SolanaRpcClient,
         treasury_wallet: Pubkey,
-        signer: Keypair,
+        signer: Arc<dyn TxSigner>,
         dry_run: bool,
     ) -> Self {
         Self {
             rpc_client,
             treasury_wallet,
             signer,
+            fee_payer: None,
             dry_run,
+            close_adapters: Arc::new(CloseAdapters::default()),
+            fees: FeeConfig::default(),
+            memo: None,
         }
     }
     
+    /// Enable reclaiming `AccountType::Other` accounts through configured adapters
+    pub fn with_close_adapters(mut self, adapters: CloseAdapters) -> Self {
+        self.close_adapters = Arc::new(adapters);
+        self
+    }
+    
+    /// Attach ComputeBudget instructions paying the configured priority fee
+    pub fn with_fees(mut self, fees: FeeConfig) -> Self {
+        self.fees = fees;
+        self
+    }
+    
+    /// Append a memo rendered from `template` to every reclaim transaction
+    pub fn with_memo(mut self, template: Option<String>, reason: ReclaimReason) -> Self {
+        self.memo = template.map(|template| (template, reason));
+        self
+    }
+    
+    /// Pay transaction fees from `fee_payer` rather than the close authority
+    pub fn with_fee_payer(mut self, fee_payer: Option<Arc<dyn TxSigner>>) -> Self {
+        self.fee_payer = fee_payer.filter(|payer| payer.pubkey() != self.signer.pubkey());
+        self
+    }
+    
+    /// Signatures on each reclaim transaction: the close authority's, plus the fee payer's
+    pub fn signatures_per_transaction(&self) -> u64 {
+        1 + self.fee_payer.is_some() as u64
+    }
+    
     /// Reclaim rent from an account
     /// 
     /// Handles different account types:
     /// - System accounts: Transfer balance to treasury
     /// - SPL Token accounts: Close account instruction
+    /// - Nonce accounts: Withdraw full balance as nonce authority
+    /// - Other program accounts: Configured close adapter, if any
+    ///
+    /// Scan-time state is never trusted: the account is fetched and checked
+    /// here, then fetched and checked again right before sending, and the
+    /// reclaim is refused if anything changed in between.
 pub async fn reclaim_account(
     &self,
     account_pubkey: &Pubkey,
@@ -55,24 +172,16 @@ pub async fn reclaim_account(
 ) -> Result<ReclaimResult> {
     info!("Attempting to reclaim rent from account: {}", account_pubkey);
     
-    let account = self.rpc_client.get_account(account_pubkey).await?;
-    
-    let (balance, account_data) = if let Some(acc) = account {
-        (acc.lamports, acc)
-    } else {
+    let Some(account_data) = self.rpc_client.get_account(account_pubkey).await? else {
         // Account already closed
         warn!("Account {} is already closed, nothing to reclaim", account_pubkey);
-        return Ok(ReclaimResult {
-            signature: None,
-            amount_reclaimed: 0,
-            account: *account_pubkey,
-            dry_run: self.dry_run,
-        });
+        return Ok(self.nothing_reclaimed(account_pubkey));
     };
+    let balance = account_data.lamports;
     
     if balance == 0 {
         warn!("No rent to reclaim from account: {}", account_pubkey);
-        return Err(crate::error::ReclaimError::NotEligible(
+        return Err(crate::error::ReclaimError::Ineligible(
             "Account has no balance".to_string()
         ));
     }
@@ -85,118 +194,21 @@ pub async fn reclaim_account(
         account_type
     );
     
-    // For SPL Token accounts, verify token balance is zero before closing
-    if let AccountType::SplToken = account_type {
-        // SPL Token account data structure:
-        // - Mint: 32 bytes (offset 0)
-        // - Owner: 32 bytes (offset 32)
-        // - Amount: 8 bytes (offset 64)
-        // - Delegate: 36 bytes (offset 72)
-        // - State: 1 byte (offset 108)
-        // - IsNative: 12 bytes (offset 109)
-        // - DelegatedAmount: 8 bytes (offset 121)
-        // - CloseAuthority: 36 bytes (offset 129)
-        
-        if account_data.data.len() < 165 {
-            return Err(crate::error::ReclaimError::NotEligible(
-                "Invalid SPL Token account data size".to_string()
-            ));
-        }
-        
-        // Check token amount (offset 64, 8 bytes as u64 little-endian)
-        let amount_bytes: [u8; 8] = account_data.data[64..72]
-            .try_into()
-            .map_err(|_| crate::error::ReclaimError::NotEligible(
-                "Failed to parse token amount from account data".to_string()
-            ))?;
-        let token_amount = u64::from_le_bytes(amount_bytes);
-        
-        if token_amount > 0 {
-            return Err(crate::error::ReclaimError::NotEligible(
-                format!(
-                    "Cannot close token account: still has {} tokens. Account must be emptied first.",
-                    token_amount
-                )
-            ));
-        }
-        
-        // Check account state (offset 108, 1 byte)
-        // AccountState: Uninitialized = 0, Initialized = 1, Frozen = 2
-        let state = account_data.data[108];
-        if state == AccountState::Frozen as u8 {
-            return Err(crate::error::ReclaimError::NotEligible(
-                "Cannot close frozen token account".to_string()
-            ));
-        }
-        
-        // Verify close authority
-        // CloseAuthority is at offset 129 (4 bytes for option discriminant + 32 bytes for pubkey)
-        // First byte indicates if close authority is set (0 = None, 1 = Some)
-        let has_close_authority = account_data.data[129] == 1;
-        
-        if has_close_authority {
-            let close_authority_bytes: [u8; 32] = account_data.data[130..162]
-                .try_into()
-                .map_err(|_| crate::error::ReclaimError::NotEligible(
-                    "Failed to parse close authority from account data".to_string()
-                ))?;
-            let close_authority = Pubkey::new_from_array(close_authority_bytes);
-            
-            if close_authority != self.signer.pubkey() {
-                return Err(crate::error::ReclaimError::NotEligible(
-                    format!(
-                        "Cannot close token account: operator ({}) is not the close authority ({})",
-                        self.signer.pubkey(),
-                        close_authority
-                    )
-                ));
-            }
-            
-            info!(
-                "Verified: Operator {} has close authority for token account {}",
-                self.signer.pubkey(),
-                account_pubkey
-            );
-        } else {
-            // Check if operator is the account owner as fallback
-            let owner_bytes: [u8; 32] = account_data.data[32..64]
-                .try_into()
-                .map_err(|_| crate::error::ReclaimError::NotEligible(
-                    "Failed to parse owner from account data".to_string()
-                ))?;
-            let owner = Pubkey::new_from_array(owner_bytes);
-            
-            if owner != self.signer.pubkey() {
-                return Err(crate::error::ReclaimError::NotEligible(
-                    format!(
-                        "Cannot close token account: no close authority set and operator ({}) is not the owner ({})",
-                        self.signer.pubkey(),
-                        owner
-                    )
-                ));
-            }
-            
-            info!(
-                "Verified: Operator {} is the owner of token account {}",
-                self.signer.pubkey(),
-                account_pubkey
-            );
-        }
-    }
+    verify_closable(account_type, &account_data, &self.signer.pubkey())?;
+    let instruction = self.build_close_instruction(account_pubkey, account_type, balance)?;
     
-    // Re-verify balance before building transaction (prevent race conditions)
-    let current_balance = self.rpc_client.get_balance(account_pubkey).await?;
-    if current_balance == 0 {
-        warn!("Account {} balance changed to zero before transaction", account_pubkey);
-        return Ok(ReclaimResult {
-            signature: None,
-            amount_reclaimed: 0,
-            account: *account_pubkey,
-            dry_run: self.dry_run,
-        });
+    // Pre-flight: refetch and re-verify immediately before sending
+    let Some(current) = self.rpc_client.get_account(account_pubkey).await? else {
+        warn!("Account {} was closed before the transaction was sent", account_pubkey);
+        return Ok(self.nothing_reclaimed(account_pubkey));
+    };
+    if let Some(change) = describe_change(&account_data, &current) {
+        return Err(crate::error::ReclaimError::Ineligible(format!(
+            "Account changed during pre-flight ({}); refusing to reclaim",
+            change
+        )));
     }
-    
-    let instruction = self.build_close_instruction(account_pubkey, account_type, current_balance)?;
+    verify_closable(account_type, &current, &self.signer.pubkey())?;
     
     if self.dry_run {
         info!("DRY RUN: Would reclaim {} lamports from {}", balance, account_pubkey);
@@ -205,21 +217,16 @@ pub async fn reclaim_account(
             amount_reclaimed: balance,
             account: *account_pubkey,
             dry_run: true,
+            destination: self.treasury_wallet,
+            credit: None,
+            attempts: Vec::new(),
         });
     }
     
-    let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
-    
-    let transaction = Transaction::new_signed_with_payer(
-        &[instruction],
-        Some(&self.signer.pubkey()),
-        &[&self.signer],
-        recent_blockhash,
-    );
-    
-    // Send transaction with retry logic
     info!("Sending reclaim transaction for account {}", account_pubkey);
-    let signature = self.rpc_client.send_and_confirm_transaction(&transaction).await?;
+    let instructions = self.with_memo_instruction(account_pubkey, instruction);
+    let attempts = self.send_with_rebuilds(account_pubkey, &instructions).await?;
+    let signature = attempts.last().expect("a landed attempt").signature;
     
     info!(
         "✓ Successfully reclaimed {} lamports from {} | Signature: {}",
@@ -233,23 +240,163 @@ pub async fn reclaim_account(
         amount_reclaimed: balance,
         account: *account_pubkey,
         dry_run: false,
+        destination: self.treasury_wallet,
+        credit: self.verify_credit(&signature, balance).await,
+        attempts,
+    })
+}
+
+/// Build the reclaim of `account_pubkey` as it would be sent and simulate
+/// it, without sending anything
+pub async fn simulate_reclaim(&self, account_pubkey: &Pubkey, account_type: &AccountType) -> Result<ReclaimSimulation> {
+    let Some(account) = self.rpc_client.get_account(account_pubkey).await? else {
+        return Err(crate::error::ReclaimError::Ineligible("Account is already closed".to_string()));
+    };
+    verify_closable(account_type, &account, &self.signer.pubkey())?;
+    
+    let instruction = self.build_close_instruction(account_pubkey, account_type, account.lamports)?;
+    let instructions = self.with_memo_instruction(account_pubkey, instruction);
+    let transaction = self.build_transaction(&instructions, self.fees.priority_fee_micro_lamports).await?;
+    let (error, units_consumed) = self.rpc_client.simulate_transaction(&transaction).await?;
+    
+    Ok(ReclaimSimulation {
+        amount: account.lamports,
+        units_consumed,
+        error: error.map(|e| e.to_string()),
     })
 }
+
+/// `instruction`, followed by the `reclaim.memo` instruction if one is configured
+fn with_memo_instruction(&self, account_pubkey: &Pubkey, instruction: Instruction) -> Vec<Instruction> {
+    let mut instructions = vec![instruction];
+    if let Some((template, reason)) = &self.memo {
+        instructions.push(memo_instruction(&render_memo(template, account_pubkey, *reason)));
+    }
+    instructions
+}
+
+/// Send `instructions` until they land. A transaction whose blockhash expired
+/// is rebuilt with a fresh blockhash and a higher priority fee, up to
+/// `fees.max_rebuilds` times; every send is returned, the landed one last.
+async fn send_with_rebuilds(&self, account_pubkey: &Pubkey, instructions: &[Instruction]) -> Result<Vec<SendAttempt>> {
+    let mut attempts: Vec<SendAttempt> = Vec::new();
+    let mut priority_fee = self.fees.priority_fee_micro_lamports;
+    
+    loop {
+        let transaction = self.build_transaction(instructions, priority_fee).await?;
+        let signature = transaction.signatures[0];
+        
+        // Send transaction with retry logic
+        let error = match self.rpc_client.send_and_confirm_transaction(&transaction).await {
+            Ok(signature) => {
+                attempts.push(SendAttempt { signature, priority_fee_micro_lamports: priority_fee, error: None });
+                return Ok(attempts);
+            }
+            Err(e) if e.is_blockhash_expired() && (attempts.len() as u32) < self.fees.max_rebuilds => e,
+            Err(e) => return Err(e),
+        };
+        
+        // Confirmation can time out on a transaction that did land; resending would fail
+        if let Ok(Some(Ok(()))) = self.rpc_client.get_signature_status(&signature).await {
+            info!("Expired-looking transaction {} for {} had landed", signature, account_pubkey);
+            attempts.push(SendAttempt { signature, priority_fee_micro_lamports: priority_fee, error: None });
+            return Ok(attempts);
+        }
+        
+        attempts.push(SendAttempt {
+            signature,
+            priority_fee_micro_lamports: priority_fee,
+            error: Some(error.to_string()),
+        });
+        let bumped = bump_priority_fee(priority_fee, self.fees.fee_bump_percent);
+        warn!(
+            "Reclaim transaction for {} expired ({}); rebuilding with a fresh blockhash, priority fee {} -> {} micro-lamports (rebuild {}/{})",
+            account_pubkey,
+            error,
+            priority_fee,
+            bumped,
+            attempts.len(),
+            self.fees.max_rebuilds
+        );
+        priority_fee = bumped;
+    }
+}
+
+/// Signed transaction for `close` (the close instruction and any memo) on a
+/// fresh blockhash, with ComputeBudget instructions when a priority fee is
+/// paid. A separate fee payer signs first; the close authority co-signs.
+async fn build_transaction(&self, close: &[Instruction], priority_fee: u64) -> Result<Transaction> {
+    let recent_blockhash = self.rpc_client.get_latest_blockhash()?;
+    let message = self.transaction_message(close, priority_fee, &recent_blockhash);
+    sign_transaction(message, &self.transaction_signers()).await
+}
+
+/// Unsigned message for `close`, paid for by the first of [`Self::transaction_signers`]
+fn transaction_message(&self, close: &[Instruction], priority_fee: u64, recent_blockhash: &Hash) -> Message {
+    let mut instructions = Vec::with_capacity(close.len() + 2);
+    if priority_fee > 0 {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(self.fees.compute_unit_limit));
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(priority_fee));
+    }
+    instructions.extend_from_slice(close);
+    
+    let payer = self.transaction_signers()[0].pubkey();
+    Message::new_with_blockhash(&instructions, Some(&payer), recent_blockhash)
+}
+
+/// The fee payer, if separate, then the close authority
+fn transaction_signers(&self) -> Vec<&Arc<dyn TxSigner>> {
+    self.fee_payer.iter().chain([&self.signer]).collect()
+}
+
+/// Read the treasury's balance change back from a confirmed reclaim
+async fn verify_credit(&self, signature: &Signature, expected: u64) -> Option<TreasuryCredit> {
+    let credit = match self.rpc_client.get_transaction(signature).await {
+        Ok(Some(tx)) => treasury_credit(&tx, &self.treasury_wallet, expected),
+        Ok(None) => None,
+        Err(e) => {
+            warn!("Failed to fetch reclaim transaction {} for verification: {}", signature, e);
+            None
+        }
+    };
+    
+    match credit {
+        Some(credit) if !credit.is_match() => warn!(
+            "Treasury {} was credited {} lamports by {}, expected {}",
+            self.treasury_wallet, credit.credited, signature, credit.expected
+        ),
+        Some(_) => info!("Verified treasury credit of {} lamports", expected),
+        None => warn!("Could not verify the treasury credit of {}", signature),
+    }
+    credit
+}
+
+fn nothing_reclaimed(&self, account_pubkey: &Pubkey) -> ReclaimResult {
+    ReclaimResult {
+        signature: None,
+        amount_reclaimed: 0,
+        account: *account_pubkey,
+        dry_run: self.dry_run,
+        destination: self.treasury_wallet,
+        credit: None,
+        attempts: Vec::new(),
+    }
+}
     
 fn build_close_instruction(
     &self,
     account_pubkey: &Pubkey,
     account_type: &AccountType,
-    _balance: u64,
+    balance: u64,
 ) -> Result<Instruction> {
     match account_type {
-        AccountType::System => {
+        AccountType::System | AccountType::SeededSystem => {
             // CRITICAL: We cannot close system accounts we don't own!
             // For Kora-sponsored accounts, the user owns the account after creation.
             // The only way to reclaim is if the user voluntarily transfers back
             // or if we implement a program-based solution.
             warn!("Cannot automatically reclaim from System account: user owns the keys");
-            Err(crate::error::ReclaimError::NotEligible(
+            Err(crate::error::ReclaimError::Ineligible(
                 "Cannot reclaim from System accounts - user controls the private key. \
                  Reclaim only possible if user voluntarily closes account.".to_string()
             ))
@@ -277,7 +424,50 @@ fn build_close_instruction(
             Ok(close_instruction)
         }
         
+        AccountType::NonceAccount => {
+            // Withdrawing the full balance closes the nonce account
+            info!(
+                "Building withdraw instruction for nonce account {} ({} lamports)",
+                account_pubkey,
+                balance
+            );
+            Ok(solana_sdk::system_instruction::withdraw_nonce_account(
+                account_pubkey,
+                &self.signer.pubkey(), // Authority (must be nonce authority)
+                &self.treasury_wallet,
+                balance,
+            ))
+        }
+        
         AccountType::Other(program_id) => {
+            if let Some(adapter) = self.close_adapters.get(program_id) {
+                info!(
+                    "Building close instruction for {} account {} via adapter '{}'",
+                    program_id,
+                    account_pubkey,
+                    adapter.name
+                );
+                return Ok(adapter.build_instruction(
+                    account_pubkey,
+                    &self.treasury_wallet,
+                    &self.signer.pubkey(),
+                ));
+            }
+            
+            if account_type.is_token_account() {
+                // Token-2022 keeps SPL Token's CloseAccount instruction layout
+                info!("Building close instruction for Token-2022 account {}", account_pubkey);
+                let mut close_instruction = spl_token::instruction::close_account(
+                    &spl_token::id(),
+                    account_pubkey,
+                    &self.treasury_wallet,
+                    &self.signer.pubkey(),
+                    &[],
+                )?;
+                close_instruction.program_id = *program_id;
+                return Ok(close_instruction);
+            }
+            
             // For other program accounts, we need program-specific logic
             //warn!("Cannot automatically close account owned by program: {}", program_id);
             warn!(
@@ -285,7 +475,7 @@ fn build_close_instruction(
                 program_id,
                 account_type.program_id()
             );
-            Err(crate::error::ReclaimError::NotEligible(
+            Err(crate::error::ReclaimError::Ineligible(
                 format!("Custom program accounts require program-specific close logic for: {}", program_id)
             ))
         }
@@ -311,22 +501,352 @@ fn build_close_instruction(
 }
 
 
-// Clone implementation for ReclaimEngine (needed for batch processing in TUI)
-impl Clone for ReclaimEngine {
-    fn clone(&self) -> Self {
-        use solana_sdk::signature::Keypair;
+/// Check that `authority` can close `account` as `account_type` in its current state
+fn verify_closable(account_type: &AccountType, account: &Account, authority: &Pubkey) -> Result<()> {
+    let not_eligible = |reason: String| Err(crate::error::ReclaimError::Ineligible(reason));
+    // Failures that will not go away on their own; repeated ones dead-letter the account
+    let unreclaimable = |reason: String| Err(crate::error::ReclaimError::Unreclaimable(reason));
+    let authority_mismatch = |reason: String| Err(crate::error::ReclaimError::AuthorityMismatch(reason));
+    
+    // The owner program decides which close instruction applies
+    let expected_owner = account_type.program_id();
+    if account.owner != expected_owner {
+        return unreclaimable(format!(
+            "Account is owned by {}, expected {} for a {} account",
+            account.owner, expected_owner, account_type
+        ));
+    }
+    
+    match account_type {
+        // Token-2022 accounts start with the same layout
+        _ if account_type.is_token_account() => {
+            // SPL Token account data structure:
+            // - Mint: 32 bytes (offset 0)
+            // - Owner: 32 bytes (offset 32)
+            // - Amount: 8 bytes (offset 64)
+            // - Delegate: 36 bytes (offset 72)
+            // - State: 1 byte (offset 108)
+            // - IsNative: 12 bytes (offset 109)
+            // - DelegatedAmount: 8 bytes (offset 121)
+            // - CloseAuthority: 36 bytes (offset 129)
+            let data = &account.data;
+            if data.len() < 165 {
+                return not_eligible("Invalid SPL Token account data size".to_string());
+            }
+            
+            let token_amount = u64::from_le_bytes(data[64..72].try_into().unwrap_or_default());
+            if token_amount > 0 {
+                return not_eligible(format!(
+                    "Cannot close token account: still has {} tokens. Account must be emptied first.",
+                    token_amount
+                ));
+            }
+            
+            // AccountState: Uninitialized = 0, Initialized = 1, Frozen = 2
+            if data[108] == AccountState::Frozen as u8 {
+                return unreclaimable("Cannot close frozen token account".to_string());
+            }
+            
+            // The close authority (COption: 4-byte tag, then the key) takes
+            // precedence; without one the token owner may close
+            let pubkey_at = |offset: usize| {
+                Pubkey::new_from_array(data[offset..offset + 32].try_into().unwrap_or_default())
+            };
+            if data[129] == 1 {
+                let close_authority = pubkey_at(133);
+                if close_authority != *authority {
+                    return authority_mismatch(format!(
+                        "Cannot close token account: operator ({}) is not the close authority ({})",
+                        authority, close_authority
+                    ));
+                }
+                info!("Verified: Operator {} has close authority", authority);
+            } else {
+                let owner = pubkey_at(32);
+                if owner != *authority {
+                    return authority_mismatch(format!(
+                        "Cannot close token account: no close authority set and operator ({}) is not the owner ({})",
+                        authority, owner
+                    ));
+                }
+                info!("Verified: Operator {} is the token account owner", authority);
+            }
+        }
         
-        // Clone the keypair by reconstructing from bytes
-        let signer_bytes = self.signer.to_bytes();
-        let signer = Keypair::from_bytes(&signer_bytes)
-            .expect("Failed to clone keypair");
+        // Nonce accounts can only be withdrawn by their nonce authority
+        AccountType::NonceAccount => match crate::solana::accounts::nonce_authority(&account.data) {
+            Some(nonce_authority) if nonce_authority == *authority => {
+                info!("Verified: Operator {} is the nonce authority", authority);
+            }
+            Some(nonce_authority) => {
+                return authority_mismatch(format!(
+                    "Cannot withdraw nonce account: operator ({}) is not the nonce authority ({})",
+                    authority, nonce_authority
+                ));
+            }
+            None => return not_eligible("Nonce account is not initialized".to_string()),
+        },
         
-        Self {
-            rpc_client: self.rpc_client.clone(),
-            treasury_wallet: self.treasury_wallet,
-            signer,
-            dry_run: self.dry_run,
-        }
+        _ => {}
+    }
+    
+    Ok(())
+}
+
+/// Priority fee for a rebuilt transaction: `percent` more than `fee`, and at
+/// least `MIN_PRIORITY_FEE_BUMP` more
+fn bump_priority_fee(fee: u64, percent: u64) -> u64 {
+    let scaled = fee.saturating_mul(100u64.saturating_add(percent)) / 100;
+    scaled.max(fee.saturating_add(MIN_PRIORITY_FEE_BUMP))
+}
+
+/// `reclaim.memo` with `{account}` and `{reason}` filled in, cut to
+/// `MAX_MEMO_LEN` bytes
+fn render_memo(template: &str, account: &Pubkey, reason: ReclaimReason) -> String {
+    let mut memo = template
+        .replace("{account}", &account.to_string())
+        .replace("{reason}", &reason.to_string());
+    if memo.len() > MAX_MEMO_LEN {
+        let end = (0..=MAX_MEMO_LEN).rev().find(|&i| memo.is_char_boundary(i)).unwrap_or(0);
+        memo.truncate(end);
     }
+    memo
 }
 
+fn memo_instruction(memo: &str) -> Instruction {
+    Instruction::new_with_bytes(MEMO_PROGRAM_ID, memo.as_bytes(), Vec::new())
+}
+
+/// Collect a signature from each signer, in the message's signer order
+async fn sign_transaction(message: Message, signers: &[&Arc<dyn TxSigner>]) -> Result<Transaction> {
+    let mut transaction = Transaction::new_unsigned(message);
+    let message_data = transaction.message_data();
+    
+    for signer in signers {
+        let position = transaction.message.account_keys
+            [..transaction.message.header.num_required_signatures as usize]
+            .iter()
+            .position(|key| *key == signer.pubkey())
+            .ok_or_else(|| crate::error::ReclaimError::TransactionFailed(format!(
+                "{} is not a signer of the transaction",
+                signer.pubkey()
+            )))?;
+        transaction.signatures[position] = signer.sign_message(&message_data).await?;
+    }
+    Ok(transaction)
+}
+
+/// What differs between two fetches of the same account, if anything
+fn describe_change(before: &Account, after: &Account) -> Option<String> {
+    if before.owner != after.owner {
+        Some(format!("owner changed from {} to {}", before.owner, after.owner))
+    } else if before.lamports != after.lamports {
+        Some(format!("balance changed from {} to {} lamports", before.lamports, after.lamports))
+    } else if before.data != after.data {
+        Some("account data changed".to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::program_option::COption;
+    use solana_sdk::program_pack::Pack;
+
+    fn token_account(amount: u64, state: AccountState, owner: Pubkey, close_authority: Option<Pubkey>) -> Account {
+        let token = spl_token::state::Account {
+            mint: Pubkey::new_unique(),
+            owner,
+            amount,
+            state,
+            close_authority: close_authority.map_or(COption::None, COption::Some),
+            ..Default::default()
+        };
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        token.pack_into_slice(&mut data);
+
+        Account {
+            lamports: 2_039_280,
+            data,
+            owner: spl_token::id(),
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn test_token_account_checks() {
+        let operator = Pubkey::new_unique();
+        let user = Pubkey::new_unique();
+        let check = |account: &Account| verify_closable(&AccountType::SplToken, account, &operator);
+
+        assert!(check(&token_account(0, AccountState::Initialized, user, Some(operator))).is_ok());
+        assert!(check(&token_account(0, AccountState::Initialized, operator, None)).is_ok());
+
+        // A token balance can still be emptied; the rest will fail on every retry
+        assert!(!check(&token_account(5, AccountState::Initialized, user, Some(operator))).unwrap_err().is_deterministic());
+        assert!(check(&token_account(0, AccountState::Frozen, user, Some(operator))).unwrap_err().is_deterministic());
+        assert!(check(&token_account(0, AccountState::Initialized, operator, Some(user))).unwrap_err().is_deterministic());
+        assert!(check(&token_account(0, AccountState::Initialized, user, None)).unwrap_err().is_deterministic());
+
+        // Reassigned to another program since the scan
+        let mut reassigned = token_account(0, AccountState::Initialized, user, Some(operator));
+        reassigned.owner = Pubkey::new_unique();
+        assert!(check(&reassigned).is_err());
+
+        // Token-2022 accounts get the same checks, with extensions past the base layout
+        let token_2022 = AccountType::Other(crate::solana::parser::TOKEN_2022_PROGRAM_ID);
+        let mut extended = token_account(0, AccountState::Initialized, user, Some(operator));
+        extended.owner = crate::solana::parser::TOKEN_2022_PROGRAM_ID;
+        extended.data.resize(182, 0);
+        assert!(verify_closable(&token_2022, &extended, &operator).is_ok());
+        extended.data[64] = 1;
+        assert!(verify_closable(&token_2022, &extended, &operator).is_err());
+    }
+
+    fn nonce_account(authority: Pubkey) -> Account {
+        use solana_sdk::nonce;
+
+        let durable_nonce = nonce::state::DurableNonce::from_blockhash(&solana_sdk::hash::Hash::new_unique());
+        let state = nonce::state::Versions::new(nonce::State::Initialized(nonce::state::Data::new(
+            authority,
+            durable_nonce,
+            5_000,
+        )));
+        Account {
+            lamports: 1_447_680,
+            data: bincode::serialize(&state).unwrap(),
+            owner: solana_sdk::system_program::id(),
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn test_nonce_account_withdrawal() {
+        use crate::reclaim::signer::KeypairSigner;
+        use solana_sdk::{signature::{Keypair, Signer}, system_instruction::SystemInstruction};
+
+        let operator = Keypair::new();
+        let operator_pubkey = operator.pubkey();
+        let check = |account: &Account| verify_closable(&AccountType::NonceAccount, account, &operator_pubkey);
+        assert!(check(&nonce_account(operator_pubkey)).is_ok());
+        assert!(check(&nonce_account(Pubkey::new_unique())).unwrap_err().is_deterministic());
+        let mut uninitialized = nonce_account(operator_pubkey);
+        uninitialized.data = vec![0; crate::solana::accounts::NONCE_ACCOUNT_SIZE];
+        assert!(check(&uninitialized).is_err());
+
+        // The whole balance goes to the treasury, authorized by the operator
+        let treasury = Pubkey::new_unique();
+        let commitment = solana_sdk::commitment_config::CommitmentConfig::confirmed();
+        let rpc = SolanaRpcClient::new("http://127.0.0.1:8899", commitment, 0);
+        let engine = ReclaimEngine::new(rpc, treasury, Arc::new(KeypairSigner::new(operator)), false);
+        let nonce = Pubkey::new_unique();
+        let ix = engine.build_close_instruction(&nonce, &AccountType::NonceAccount, 1_447_680).unwrap();
+
+        assert_eq!(ix.program_id, solana_sdk::system_program::id());
+        assert_eq!(
+            bincode::deserialize::<SystemInstruction>(&ix.data).unwrap(),
+            SystemInstruction::WithdrawNonceAccount(1_447_680)
+        );
+        assert_eq!((ix.accounts[0].pubkey, ix.accounts[0].is_writable), (nonce, true));
+        assert_eq!((ix.accounts[1].pubkey, ix.accounts[1].is_writable), (treasury, true));
+        let signer = ix.accounts.iter().find(|meta| meta.is_signer).unwrap();
+        assert_eq!(signer.pubkey, operator_pubkey);
+    }
+
+    #[tokio::test]
+    async fn test_separate_fee_payer() {
+        use crate::reclaim::signer::KeypairSigner;
+        use solana_sdk::signature::{Keypair, Signer};
+
+        let (authority, fee_payer) = (Keypair::new(), Keypair::new());
+        let (authority_pubkey, fee_payer_pubkey) = (authority.pubkey(), fee_payer.pubkey());
+        let treasury = Pubkey::new_unique();
+        let commitment = solana_sdk::commitment_config::CommitmentConfig::confirmed();
+        let rpc = SolanaRpcClient::new("http://127.0.0.1:8899", commitment, 0);
+        let engine = ReclaimEngine::new(rpc, treasury, Arc::new(KeypairSigner::new(authority)), false)
+            .with_fee_payer(Some(Arc::new(KeypairSigner::new(fee_payer))));
+
+        let account = Pubkey::new_unique();
+        let close = engine.build_close_instruction(&account, &AccountType::SplToken, 2_039_280).unwrap();
+        // Rent goes to the treasury; the fee payer only pays fees
+        assert_eq!(close.accounts[1].pubkey, treasury);
+        assert!(close.accounts.iter().all(|meta| meta.pubkey != fee_payer_pubkey));
+
+        let message = engine.transaction_message(&[close], 0, &solana_sdk::hash::Hash::new_unique());
+        let transaction = sign_transaction(message, &engine.transaction_signers()).await.unwrap();
+        // The fee payer signs first, the close authority second
+        assert_eq!(transaction.message.header.num_required_signatures, 2);
+        assert_eq!(transaction.message.account_keys[..2], [fee_payer_pubkey, authority_pubkey]);
+        assert!(transaction.verify().is_ok());
+    }
+
+    #[test]
+    fn test_describe_change() {
+        let operator = Pubkey::new_unique();
+        let before = token_account(0, AccountState::Initialized, operator, None);
+        assert_eq!(describe_change(&before, &before.clone()), None);
+
+        let mut topped_up = before.clone();
+        topped_up.lamports += 1;
+        assert_eq!(
+            describe_change(&before, &topped_up).as_deref(),
+            Some("balance changed from 2039280 to 2039281 lamports")
+        );
+
+        let frozen = token_account(0, AccountState::Frozen, operator, None);
+        assert!(describe_change(&before, &frozen).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_sign_transaction_places_each_signature() {
+        use crate::reclaim::signer::KeypairSigner;
+        use solana_sdk::signature::{Keypair, Signer};
+
+        let fee_payer = Keypair::new();
+        let authority = Keypair::new();
+        let instruction = spl_token::instruction::close_account(
+            &spl_token::id(),
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &authority.pubkey(),
+            &[],
+        )
+        .unwrap();
+        let message = Message::new(&[instruction], Some(&fee_payer.pubkey()));
+        let signers: Vec<Arc<dyn TxSigner>> = vec![
+            Arc::new(KeypairSigner::new(authority)),
+            Arc::new(KeypairSigner::new(fee_payer)),
+        ];
+
+        let transaction = sign_transaction(message.clone(), &signers.iter().collect::<Vec<_>>()).await.unwrap();
+        assert!(transaction.verify().is_ok());
+
+        let outsider: Arc<dyn TxSigner> = Arc::new(KeypairSigner::new(Keypair::new()));
+        assert!(sign_transaction(message, &[&outsider]).await.is_err());
+    }
+
+    #[test]
+    fn test_render_memo() {
+        let account = Pubkey::new_unique();
+        assert_eq!(
+            render_memo("kora-reclaim:{account}:{reason}", &account, ReclaimReason::AutoBatch),
+            format!("kora-reclaim:{}:AutoBatch", account)
+        );
+
+        let long = render_memo(&"é".repeat(MAX_MEMO_LEN), &account, ReclaimReason::ManualCli);
+        assert_eq!(long.len(), MAX_MEMO_LEN);
+    }
+
+    #[test]
+    fn test_priority_fee_bump() {
+        assert_eq!(bump_priority_fee(10_000, 100), 20_000);
+        assert_eq!(bump_priority_fee(10_000, 50), 15_000);
+        assert_eq!(bump_priority_fee(0, 100), MIN_PRIORITY_FEE_BUMP);
+        assert_eq!(bump_priority_fee(100, 0), 100 + MIN_PRIORITY_FEE_BUMP);
+        assert_eq!(bump_priority_fee(u64::MAX, 100), u64::MAX);
+    }
+}
@@ -1,7 +1,19 @@
+pub mod adapters;
 pub mod eligibility;
 pub mod engine;
 pub mod batch;
+pub mod plan;
+pub mod schedule;
+pub mod staging;
+pub mod close_request;
+pub mod signer;
+pub mod tuning;
+pub mod verify;
 
-pub use eligibility::EligibilityChecker;
+pub use eligibility::{EligibilityChecker, Prescreen};
 pub use engine::ReclaimEngine;
 pub use batch::BatchProcessor;
+pub use adapters::{CloseAdapter, CloseAdapters};
+pub use plan::CostPlan;
+pub use close_request::CloseRequestBuilder;
+pub use signer::TxSigner;
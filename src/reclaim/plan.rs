@@ -0,0 +1,128 @@
+// src/reclaim/plan.rs - Fee estimate for a batch before anything is sent
+
+use crate::config::FeeConfig;
+use crate::solana::rent::RentCalculator;
+use serde::Serialize;
+use std::fmt;
+
+/// Base fee charged per transaction signature
+pub const BASE_FEE_LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// Expected cost and recovery of reclaiming a set of accounts.
+///
+/// Every account is closed in its own transaction, with `signatures` base fees
+/// each; accounts that are already empty are skipped by the engine and cost
+/// nothing.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CostPlan {
+    pub transactions: usize,
+    pub base_fee_lamports: u64,
+    pub priority_fee_lamports: u64,
+    pub expected_recovery_lamports: u64,
+    /// Configured priority fee, in micro-lamports per compute unit
+    pub priority_fee_micro_lamports: u64,
+    /// Median priority fee paid recently on these accounts, for comparison
+    pub recent_priority_fee_micro_lamports: Option<u64>,
+}
+
+impl CostPlan {
+    /// Plan for accounts with the given current balances, closed by
+    /// transactions carrying `signatures` signatures
+    pub fn new(balances: &[u64], fees: &FeeConfig, signatures: u64) -> Self {
+        let transactions = balances.iter().filter(|&&balance| balance > 0).count();
+        let per_transaction_priority =
+            priority_fee_lamports(fees.compute_unit_limit, fees.priority_fee_micro_lamports);
+
+        Self {
+            transactions,
+            base_fee_lamports: transactions as u64 * signatures * BASE_FEE_LAMPORTS_PER_SIGNATURE,
+            priority_fee_lamports: transactions as u64 * per_transaction_priority,
+            expected_recovery_lamports: balances.iter().sum(),
+            priority_fee_micro_lamports: fees.priority_fee_micro_lamports,
+            recent_priority_fee_micro_lamports: None,
+        }
+    }
+
+    pub fn estimated_cost(&self) -> u64 {
+        self.base_fee_lamports + self.priority_fee_lamports
+    }
+
+    pub fn net_recovery(&self) -> i64 {
+        self.expected_recovery_lamports as i64 - self.estimated_cost() as i64
+    }
+
+    /// Estimated cost as a percentage of expected recovery
+    pub fn cost_percent(&self) -> f64 {
+        match (self.estimated_cost(), self.expected_recovery_lamports) {
+            (0, _) => 0.0,
+            (_, 0) => f64::INFINITY,
+            (cost, recovery) => cost as f64 / recovery as f64 * 100.0,
+        }
+    }
+
+    /// Whether the plan breaks a `max_cost_percent` limit
+    pub fn exceeds(&self, max_cost_percent: Option<f64>) -> bool {
+        max_cost_percent.is_some_and(|max| self.cost_percent() > max)
+    }
+}
+
+/// Priority fee for one transaction, rounded up to whole lamports
+pub fn priority_fee_lamports(compute_unit_limit: u32, micro_lamports_per_unit: u64) -> u64 {
+    (compute_unit_limit as u64 * micro_lamports_per_unit).div_ceil(1_000_000)
+}
+
+impl fmt::Display for CostPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sol = RentCalculator::lamports_to_sol;
+        writeln!(f, "Transactions:        {}", self.transactions)?;
+        writeln!(f, "Base fees:           {:.9} SOL", sol(self.base_fee_lamports))?;
+        write!(
+            f,
+            "Priority fees:       {:.9} SOL ({} µlamports/CU",
+            sol(self.priority_fee_lamports),
+            self.priority_fee_micro_lamports
+        )?;
+        match self.recent_priority_fee_micro_lamports {
+            Some(recent) => writeln!(f, ", recent median {})", recent)?,
+            None => writeln!(f, ")")?,
+        }
+        writeln!(f, "Expected recovery:   {:.9} SOL", sol(self.expected_recovery_lamports))?;
+        write!(
+            f,
+            "Net recovery:        {:.9} SOL (fees {:.2}%)",
+            self.net_recovery() as f64 / 1_000_000_000.0,
+            self.cost_percent()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cost_plan_and_limit() {
+        let fees = FeeConfig {
+            compute_unit_limit: 50_000,
+            priority_fee_micro_lamports: 10_000,
+            ..FeeConfig::default()
+        };
+        // The empty account is skipped, so only two transactions are planned
+        let plan = CostPlan::new(&[2_039_280, 890_880, 0], &fees, 1);
+
+        assert_eq!(plan.transactions, 2);
+        assert_eq!(plan.base_fee_lamports, 10_000);
+        assert_eq!(plan.priority_fee_lamports, 1_000);
+        assert_eq!(plan.net_recovery(), 2_930_160 - 11_000);
+        assert!(!plan.exceeds(None));
+        assert!(!plan.exceeds(Some(1.0)));
+        assert!(plan.exceeds(Some(0.3)));
+
+        assert_eq!(priority_fee_lamports(200_000, 1), 1);
+        assert_eq!(CostPlan::new(&[], &fees, 1).cost_percent(), 0.0);
+
+        // A separate fee payer adds a second signature to every transaction
+        let co_signed = CostPlan::new(&[2_039_280, 890_880, 0], &fees, 2);
+        assert_eq!(co_signed.base_fee_lamports, 20_000);
+    }
+}
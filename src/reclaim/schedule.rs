@@ -0,0 +1,134 @@
+// src/reclaim/schedule.rs - Hours when the auto service may send reclaims
+
+use crate::config::ReclaimScheduleConfig;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveTime, Utc, Weekday};
+
+/// Weekly windows during which reclaim transactions may be broadcast
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReclaimSchedule {
+    windows: Vec<Window>,
+    /// `None` follows the system's local time zone
+    offset: Option<FixedOffset>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Window {
+    /// Days the window starts on; empty for every day
+    days: Vec<Weekday>,
+    start: NaiveTime,
+    end: NaiveTime,
+}
+
+impl Window {
+    fn starts_on(&self, day: Weekday) -> bool {
+        self.days.is_empty() || self.days.contains(&day)
+    }
+
+    fn contains(&self, day: Weekday, time: NaiveTime) -> bool {
+        if self.start == self.end {
+            self.starts_on(day)
+        } else if self.start < self.end {
+            self.starts_on(day) && time >= self.start && time < self.end
+        } else {
+            // Past midnight the window belongs to the day it started on
+            (self.starts_on(day) && time >= self.start) || (self.starts_on(day.pred()) && time < self.end)
+        }
+    }
+}
+
+impl ReclaimSchedule {
+    pub fn from_config(config: &ReclaimScheduleConfig) -> anyhow::Result<Self> {
+        let time = |value: &str| {
+            NaiveTime::parse_from_str(value, "%H:%M")
+                .map_err(|e| anyhow::anyhow!("Invalid reclaim window time '{}' (expected HH:MM): {}", value, e))
+        };
+
+        let windows = config
+            .windows
+            .iter()
+            .map(|window| {
+                let days = window
+                    .days
+                    .iter()
+                    .map(|day| {
+                        day.parse::<Weekday>()
+                            .map_err(|_| anyhow::anyhow!("Invalid reclaim window day '{}' (expected mon..sun)", day))
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                Ok(Window {
+                    days,
+                    start: time(&window.start)?,
+                    end: time(&window.end)?,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let offset = crate::utils::parse_timezone(config.timezone.as_deref())
+            .map_err(|e| anyhow::anyhow!("Reclaim schedule: {}", e))?;
+
+        Ok(Self { windows, offset })
+    }
+
+    /// No windows configured: reclaims may run at any time
+    pub fn is_unrestricted(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    pub fn contains(&self, now: DateTime<Utc>) -> bool {
+        let local = crate::utils::wall_clock(now, self.offset);
+        self.is_unrestricted()
+            || self.windows.iter().any(|window| window.contains(local.weekday(), local.time()))
+    }
+
+    pub fn is_open_now(&self) -> bool {
+        self.contains(Utc::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ReclaimWindowConfig;
+
+    fn at(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339).unwrap().with_timezone(&Utc)
+    }
+
+    fn window(days: &[&str], start: &str, end: &str) -> ReclaimWindowConfig {
+        ReclaimWindowConfig {
+            days: days.iter().map(|day| day.to_string()).collect(),
+            start: start.to_string(),
+            end: end.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_weekday_and_overnight_windows() {
+        // 2026-03-06 is a Friday
+        let schedule = ReclaimSchedule::from_config(&ReclaimScheduleConfig {
+            timezone: Some("UTC".to_string()),
+            windows: vec![window(&["fri"], "22:00", "04:00"), window(&["sunday"], "00:00", "00:00")],
+        })
+        .unwrap();
+
+        assert!(schedule.contains(at("2026-03-06T23:00:00Z")));
+        // Friday's window runs into Saturday morning, but not Saturday night
+        assert!(schedule.contains(at("2026-03-07T03:59:00Z")));
+        assert!(!schedule.contains(at("2026-03-07T22:30:00Z")));
+        assert!(!schedule.contains(at("2026-03-06T03:00:00Z")));
+        // Equal start and end cover the whole day
+        assert!(schedule.contains(at("2026-03-08T12:00:00Z")));
+    }
+
+    #[test]
+    fn test_empty_schedule_and_invalid_input() {
+        assert!(ReclaimSchedule::from_config(&ReclaimScheduleConfig::default()).unwrap().is_open_now());
+
+        let config = |days: &[&str], start: &str| ReclaimScheduleConfig {
+            timezone: None,
+            windows: vec![window(days, start, "05:00")],
+        };
+        assert!(ReclaimSchedule::from_config(&config(&["someday"], "01:00")).is_err());
+        assert!(ReclaimSchedule::from_config(&config(&["mon"], "1am")).is_err());
+    }
+}
@@ -0,0 +1,139 @@
+// src/reclaim/signer.rs - Transaction signers: local keypairs or a remote signing service
+
+use async_trait::async_trait;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use crate::{
+    config::{Config, RemoteSignerConfig},
+    error::{ReclaimError, Result},
+};
+
+/// Signs transaction messages for one public key.
+///
+/// The engine only assembles transactions; whatever holds the key signs the
+/// serialized message, so keys can live outside this process.
+#[async_trait]
+pub trait TxSigner: Send + Sync {
+    fn pubkey(&self) -> Pubkey;
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature>;
+}
+
+/// Keypair loaded into this process
+pub struct KeypairSigner(Keypair);
+
+impl KeypairSigner {
+    pub fn new(keypair: Keypair) -> Self {
+        Self(keypair)
+    }
+}
+
+#[async_trait]
+impl TxSigner for KeypairSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.0.pubkey()
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature> {
+        Ok(self.0.sign_message(message))
+    }
+}
+
+#[derive(Serialize)]
+struct SignRequest<'a> {
+    pubkey: String,
+    /// Serialized transaction message, base64
+    message: &'a str,
+}
+
+#[derive(Deserialize)]
+struct SignResponse {
+    /// Base58 signature
+    signature: String,
+}
+
+/// HTTP signing service, e.g. one backed by an HSM. Sends
+/// `POST {"pubkey", "message"}` and expects `{"signature"}` back.
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    url: String,
+    pubkey: Pubkey,
+    api_token: Option<String>,
+}
+
+impl RemoteSigner {
+    pub fn new(config: &RemoteSignerConfig) -> anyhow::Result<Self> {
+        let pubkey = Pubkey::from_str(&config.pubkey)
+            .map_err(|e| anyhow::anyhow!("Invalid remote signer pubkey: {}", e))?;
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_seconds))
+            .build()?;
+
+        Ok(Self {
+            client,
+            url: config.url.clone(),
+            pubkey,
+            api_token: config.api_token.clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl TxSigner for RemoteSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature> {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(message);
+        let mut request = self.client.post(&self.url).json(&SignRequest {
+            pubkey: self.pubkey.to_string(),
+            message: &encoded,
+        });
+        if let Some(token) = &self.api_token {
+            request = request.bearer_auth(token);
+        }
+
+        let response: SignResponse = request
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| ReclaimError::TransactionFailed(format!("Remote signer: {}", e.without_url())))?
+            .json()
+            .await
+            .map_err(|e| ReclaimError::TransactionFailed(format!("Remote signer response: {}", e.without_url())))?;
+
+        let signature = Signature::from_str(&response.signature)?;
+        // A wrong key or message would only surface as a rejected transaction
+        if !signature.verify(self.pubkey.as_ref(), message) {
+            return Err(ReclaimError::TransactionFailed(format!(
+                "Remote signer returned a signature that does not verify for {}",
+                self.pubkey
+            )));
+        }
+        Ok(signature)
+    }
+}
+
+/// Close authority signer: `kora.remote_signer` when configured, otherwise
+/// the treasury keypair
+pub fn from_config(config: &Config) -> anyhow::Result<Arc<dyn TxSigner>> {
+    match &config.kora.remote_signer {
+        Some(remote) => Ok(Arc::new(RemoteSigner::new(remote)?)),
+        None => Ok(Arc::new(KeypairSigner::new(config.load_treasury_keypair()?))),
+    }
+}
+
+/// Separate fee payer signer, if one is configured
+pub fn fee_payer_from_config(config: &Config) -> anyhow::Result<Option<Arc<dyn TxSigner>>> {
+    Ok(config
+        .load_fee_payer_keypair()?
+        .map(|keypair| Arc::new(KeypairSigner::new(keypair)) as Arc<dyn TxSigner>))
+}
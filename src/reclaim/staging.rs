@@ -0,0 +1,64 @@
+// src/reclaim/staging.rs - Simulate-and-wait policy for high-value auto reclaims
+
+use crate::kora::AccountType;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
+
+/// This cycle's eligible accounts, split by `reclaim.staging`
+#[derive(Debug, Default, PartialEq)]
+pub struct StagingSplit {
+    /// Reclaimed in this cycle's batch
+    pub ready: Vec<(Pubkey, AccountType)>,
+    /// Simulated and announced now, reclaimed once released
+    pub to_stage: Vec<(Pubkey, AccountType)>,
+}
+
+/// Split eligible accounts at `threshold` lamports. Accounts already staged
+/// are left out: their staged row decides when they go.
+pub fn split(
+    eligible: Vec<(Pubkey, AccountType)>,
+    balance: impl Fn(&Pubkey) -> u64,
+    threshold: Option<u64>,
+    staged: &HashSet<String>,
+) -> StagingSplit {
+    let mut split = StagingSplit::default();
+    for (pubkey, account_type) in eligible {
+        if staged.contains(&pubkey.to_string()) {
+            continue;
+        }
+        if threshold.is_some_and(|threshold| balance(&pubkey) >= threshold) {
+            split.to_stage.push((pubkey, account_type));
+        } else {
+            split.ready.push((pubkey, account_type));
+        }
+    }
+    split
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_at_threshold() {
+        let small = Pubkey::new_unique();
+        let large = Pubkey::new_unique();
+        let already = Pubkey::new_unique();
+        let eligible = vec![
+            (small, AccountType::SplToken),
+            (large, AccountType::SplToken),
+            (already, AccountType::SplToken),
+        ];
+        let balance = |pubkey: &Pubkey| if *pubkey == small { 2_039_280 } else { 5_000_000_000 };
+        let staged = HashSet::from([already.to_string()]);
+
+        let split = split(eligible.clone(), balance, Some(1_000_000_000), &staged);
+        assert_eq!(split.ready, vec![(small, AccountType::SplToken)]);
+        assert_eq!(split.to_stage, vec![(large, AccountType::SplToken)]);
+
+        // Without a threshold everything goes, except what is already staged
+        let split = super::split(eligible, balance, None, &staged);
+        assert_eq!(split.ready.len(), 2);
+        assert!(split.to_stage.is_empty());
+    }
+}
@@ -0,0 +1,163 @@
+// src/reclaim/tuning.rs - Adaptive batch size and delay for batch reclaims
+
+use crate::config::AdaptiveBatchConfig;
+use std::time::Duration;
+use tracing::info;
+
+/// What one batch saw
+#[derive(Debug, Clone, Copy)]
+pub struct BatchOutcome {
+    pub accounts: usize,
+    /// Failures that may clear up on their own: RPC errors, timeouts, locks
+    pub transient_failures: usize,
+    /// Rate-limit responses from the RPC provider while the batch ran
+    pub rate_limited: u64,
+    pub elapsed: Duration,
+}
+
+impl BatchOutcome {
+    fn error_rate(&self) -> f64 {
+        self.transient_failures as f64 / self.accounts.max(1) as f64
+    }
+
+    fn latency_per_account(&self) -> Duration {
+        self.elapsed / self.accounts.max(1) as u32
+    }
+}
+
+/// Additive-increase, multiplicative-decrease controller: batches grow and
+/// the delay shrinks while batches run clean and fast; rate limiting,
+/// transient failures or slow confirmations halve the batch and double the delay.
+#[derive(Debug, Clone)]
+pub struct BatchTuner {
+    config: AdaptiveBatchConfig,
+    batch_size: usize,
+    delay: Duration,
+}
+
+impl BatchTuner {
+    /// Start from the configured size and delay, clamped to the tuning bounds
+    pub fn new(config: AdaptiveBatchConfig, batch_size: usize, delay: Duration) -> Self {
+        let min_size = config.min_batch_size.max(1);
+        let batch_size = batch_size.clamp(min_size, config.max_batch_size.max(min_size));
+        let (min_delay, max_delay) = delay_bounds(&config);
+        let delay = delay.clamp(min_delay, max_delay);
+        Self { config, batch_size, delay }
+    }
+
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Adjust after a batch, logging any change and why
+    pub fn observe(&mut self, outcome: &BatchOutcome) {
+        let latency = outcome.latency_per_account();
+        let target = Duration::from_millis(self.config.target_latency_ms);
+
+        let (batch_size, delay, reason) = if outcome.rate_limited > 0 {
+            (self.shrunk_size(), self.longer_delay(), format!("{} rate-limited responses", outcome.rate_limited))
+        } else if outcome.error_rate() > self.config.max_error_rate {
+            (
+                self.shrunk_size(),
+                self.longer_delay(),
+                format!("{:.0}% transient failures", outcome.error_rate() * 100.0),
+            )
+        } else if latency > target {
+            (self.shrunk_size(), self.longer_delay(), format!("{:?} per account, target {:?}", latency, target))
+        } else if outcome.transient_failures == 0 && latency < target / 2 {
+            (self.grown_size(), self.shorter_delay(), format!("clean batch at {:?} per account", latency))
+        } else {
+            return;
+        };
+
+        if (batch_size, delay) != (self.batch_size, self.delay) {
+            info!(
+                "Batch tuning ({}): size {} -> {}, delay {:?} -> {:?}",
+                reason, self.batch_size, batch_size, self.delay, delay
+            );
+            self.batch_size = batch_size;
+            self.delay = delay;
+        }
+    }
+
+    fn shrunk_size(&self) -> usize {
+        (self.batch_size / 2).max(self.config.min_batch_size.max(1))
+    }
+
+    fn grown_size(&self) -> usize {
+        (self.batch_size + (self.batch_size / 4).max(1)).min(self.config.max_batch_size.max(self.batch_size))
+    }
+
+    fn longer_delay(&self) -> Duration {
+        let (min_delay, max_delay) = delay_bounds(&self.config);
+        (self.delay * 2).clamp(min_delay, max_delay)
+    }
+
+    fn shorter_delay(&self) -> Duration {
+        let (min_delay, max_delay) = delay_bounds(&self.config);
+        (self.delay * 3 / 4).clamp(min_delay, max_delay)
+    }
+}
+
+fn delay_bounds(config: &AdaptiveBatchConfig) -> (Duration, Duration) {
+    (
+        Duration::from_millis(config.min_delay_ms),
+        Duration::from_millis(config.max_delay_ms.max(config.min_delay_ms)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(transient_failures: usize, rate_limited: u64, elapsed_ms: u64) -> BatchOutcome {
+        BatchOutcome {
+            accounts: 10,
+            transient_failures,
+            rate_limited,
+            elapsed: Duration::from_millis(elapsed_ms),
+        }
+    }
+
+    #[test]
+    fn test_tuner_grows_when_clean_and_backs_off_on_trouble() {
+        let config = AdaptiveBatchConfig {
+            min_batch_size: 2,
+            max_batch_size: 20,
+            min_delay_ms: 100,
+            max_delay_ms: 5_000,
+            target_latency_ms: 1_000,
+            ..Default::default()
+        };
+        let mut tuner = BatchTuner::new(config, 10, Duration::from_millis(1_000));
+
+        // 100ms per account is well under target
+        tuner.observe(&outcome(0, 0, 1_000));
+        assert_eq!((tuner.batch_size(), tuner.delay()), (12, Duration::from_millis(750)));
+
+        tuner.observe(&outcome(0, 1, 1_000));
+        assert_eq!((tuner.batch_size(), tuner.delay()), (6, Duration::from_millis(1_500)));
+
+        // 2 of 10 transient failures beats the default 10% limit
+        tuner.observe(&outcome(2, 0, 1_000));
+        assert_eq!((tuner.batch_size(), tuner.delay()), (3, Duration::from_millis(3_000)));
+
+        // Slow confirmations back off down to the bounds
+        tuner.observe(&outcome(0, 0, 20_000));
+        assert_eq!((tuner.batch_size(), tuner.delay()), (2, Duration::from_millis(5_000)));
+
+        // Between half the target and the target nothing changes
+        tuner.observe(&outcome(0, 0, 7_000));
+        assert_eq!((tuner.batch_size(), tuner.delay()), (2, Duration::from_millis(5_000)));
+    }
+
+    #[test]
+    fn test_tuner_starts_within_bounds() {
+        let tuner = BatchTuner::new(AdaptiveBatchConfig::default(), 500, Duration::ZERO);
+        assert_eq!((tuner.batch_size(), tuner.delay()), (50, Duration::from_millis(250)));
+    }
+}
@@ -0,0 +1,197 @@
+// src/reclaim/verify.rs - Check that confirmed reclaims credited the treasury
+
+use solana_sdk::pubkey::Pubkey;
+use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction, UiMessage};
+
+/// Treasury balance change recorded in a confirmed reclaim transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TreasuryCredit {
+    /// Lamports the reclaim was expected to return
+    pub expected: u64,
+    /// Lamports the treasury actually gained, ignoring the fee if it paid one
+    pub credited: i64,
+    /// Fee the transaction paid
+    pub fee: u64,
+}
+
+impl TreasuryCredit {
+    pub fn is_match(&self) -> bool {
+        self.credited == self.expected as i64
+    }
+}
+
+/// Read the treasury's lamport delta from `tx`'s metadata.
+///
+/// `None` when the transaction carries no metadata or failed; a treasury
+/// missing from the account keys is reported as a credit of zero.
+pub fn treasury_credit(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    treasury: &Pubkey,
+    expected: u64,
+) -> Option<TreasuryCredit> {
+    let meta = tx.transaction.meta.as_ref()?;
+    if meta.err.is_some() {
+        return None;
+    }
+
+    let EncodedTransaction::Json(ui_tx) = &tx.transaction.transaction else {
+        return None;
+    };
+    let keys = account_keys(&ui_tx.message);
+
+    Some(TreasuryCredit {
+        expected,
+        credited: balance_delta(&keys, &meta.pre_balances, &meta.post_balances, meta.fee, &treasury.to_string()),
+        fee: meta.fee,
+    })
+}
+
+/// A close of `account` in `tx` that moved its rent to the treasury and was
+/// signed by one of `signers`: a reclaim made without the bot.
+///
+/// `expected` is the closed account's balance, `credited` what the treasury
+/// gained. `None` if `tx` failed, left the account open, paid the treasury
+/// nothing or was signed by someone else.
+pub fn external_reclaim(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    account: &Pubkey,
+    treasury: &Pubkey,
+    signers: &[Pubkey],
+) -> Option<TreasuryCredit> {
+    let meta = tx.transaction.meta.as_ref()?;
+    if meta.err.is_some() {
+        return None;
+    }
+
+    let EncodedTransaction::Json(ui_tx) = &tx.transaction.transaction else {
+        return None;
+    };
+    let (keys, signed): (Vec<&str>, Vec<&str>) = match &ui_tx.message {
+        UiMessage::Parsed(parsed) => (
+            parsed.account_keys.iter().map(|k| k.pubkey.as_str()).collect(),
+            parsed.account_keys.iter().filter(|k| k.signer).map(|k| k.pubkey.as_str()).collect(),
+        ),
+        UiMessage::Raw(raw) => {
+            let keys: Vec<&str> = raw.account_keys.iter().map(String::as_str).collect();
+            let signed = keys.iter().take(raw.header.num_required_signatures as usize).copied().collect();
+            (keys, signed)
+        }
+    };
+    if !signers.iter().any(|signer| signed.contains(&signer.to_string().as_str())) {
+        return None;
+    }
+
+    closed_into(&keys, &meta.pre_balances, &meta.post_balances, meta.fee, &account.to_string(), &treasury.to_string())
+        .map(|(expected, credited)| TreasuryCredit { expected, credited, fee: meta.fee })
+}
+
+/// Whether `tx` succeeded and left `account` with no lamports, whoever closed it
+pub fn emptied(tx: &EncodedConfirmedTransactionWithStatusMeta, account: &Pubkey) -> bool {
+    let Some(meta) = tx.transaction.meta.as_ref().filter(|meta| meta.err.is_none()) else {
+        return false;
+    };
+    let EncodedTransaction::Json(ui_tx) = &tx.transaction.transaction else {
+        return false;
+    };
+    let account = account.to_string();
+    account_keys(&ui_tx.message).iter().position(|key| *key == account).is_some_and(|index| {
+        meta.pre_balances.get(index).is_some_and(|&before| before > 0) && meta.post_balances.get(index) == Some(&0)
+    })
+}
+
+fn account_keys(message: &UiMessage) -> Vec<&str> {
+    match message {
+        UiMessage::Parsed(parsed) => parsed.account_keys.iter().map(|k| k.pubkey.as_str()).collect(),
+        UiMessage::Raw(raw) => raw.account_keys.iter().map(String::as_str).collect(),
+    }
+}
+
+/// Balance `account` had before it was emptied, and what `treasury` gained
+fn closed_into(keys: &[&str], pre: &[u64], post: &[u64], fee: u64, account: &str, treasury: &str) -> Option<(u64, i64)> {
+    let index = keys.iter().position(|key| *key == account)?;
+    let (&before, &after) = (pre.get(index)?, post.get(index)?);
+    let credited = balance_delta(keys, pre, post, fee, treasury);
+    (before > 0 && after == 0 && credited > 0).then_some((before, credited))
+}
+
+/// Lamports gained by `account`; the first key pays `fee`, which is added back
+fn balance_delta(keys: &[&str], pre: &[u64], post: &[u64], fee: u64, account: &str) -> i64 {
+    let Some(index) = keys.iter().position(|key| *key == account) else {
+        return 0;
+    };
+    let (Some(&pre), Some(&post)) = (pre.get(index), post.get(index)) else {
+        return 0;
+    };
+
+    let fee = if index == 0 { fee as i64 } else { 0 };
+    post as i64 - pre as i64 + fee
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_treasury_delta_accounts_for_fee_payer() {
+        let keys = ["operator", "token_account", "treasury"];
+        let pre = [1_000_000, 2_039_280, 50_000_000];
+        let post = [995_000, 0, 52_039_280];
+
+        assert_eq!(balance_delta(&keys, &pre, &post, 5_000, "treasury"), 2_039_280);
+        // Treasury that also pays the fee still nets the full amount
+        assert_eq!(balance_delta(&keys, &pre, &post, 5_000, "operator"), 0);
+        // Rent sent somewhere else
+        assert_eq!(balance_delta(&keys, &pre, &post, 5_000, "other"), 0);
+
+        assert_eq!(closed_into(&keys, &pre, &post, 5_000, "token_account", "treasury"), Some((2_039_280, 2_039_280)));
+        // Closed, but the rent went elsewhere
+        assert_eq!(closed_into(&keys, &pre, &post, 5_000, "token_account", "other"), None);
+        // Still open
+        let open = [995_000, 2_039_280, 50_000_000];
+        assert_eq!(closed_into(&keys, &pre, &open, 5_000, "token_account", "treasury"), None);
+
+        let credit = TreasuryCredit { expected: 2_039_280, credited: 2_039_280, fee: 5_000 };
+        assert!(credit.is_match());
+        assert!(!TreasuryCredit { credited: 0, ..credit }.is_match());
+    }
+
+    #[test]
+    fn test_external_reclaim_requires_known_signer() {
+        use crate::solana::test_rpc::parsed_transaction;
+
+        let (operator, retired, treasury, user, account) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let close_by = |signer: Pubkey| -> EncodedConfirmedTransactionWithStatusMeta {
+            serde_json::from_value(parsed_transaction(
+                "sig",
+                &[(signer, true, 1_000_000, 995_000), (account, false, 2_039_280, 0), (treasury, false, 0, 2_039_280)],
+                5_000,
+            ))
+            .unwrap()
+        };
+        let signers = [operator, retired, treasury];
+
+        let expected = TreasuryCredit { expected: 2_039_280, credited: 2_039_280, fee: 5_000 };
+        assert_eq!(external_reclaim(&close_by(operator), &account, &treasury, &signers), Some(expected));
+        assert_eq!(external_reclaim(&close_by(retired), &account, &treasury, &signers), Some(expected));
+        // A treasury that paid the fee itself still nets the full rent
+        let by_treasury = serde_json::from_value(parsed_transaction(
+            "sig",
+            &[(treasury, true, 0, 2_034_280), (account, false, 2_039_280, 0)],
+            5_000,
+        ))
+        .unwrap();
+        assert_eq!(external_reclaim(&by_treasury, &account, &treasury, &signers), Some(expected));
+
+        // The same close signed by anyone else is not the operator's
+        let by_user = close_by(user);
+        assert_eq!(external_reclaim(&by_user, &account, &treasury, &signers), None);
+        assert!(emptied(&by_user, &account));
+        assert!(!emptied(&by_user, &treasury));
+    }
+}
@@ -1,4 +1,4 @@
-// src/solana/accounts.rs - Enhanced with RateLimiter
+// src/solana/accounts.rs - Account discovery from the fee payer's transaction history
 
 use solana_sdk::{
     pubkey::Pubkey,
@@ -6,31 +6,70 @@ use solana_sdk::{
 };
 use solana_transaction_status::{
     EncodedConfirmedTransactionWithStatusMeta,
+    UiInstruction,
     UiMessage,
 };
 use crate::{
-    error::Result,
-    solana::client::SolanaRpcClient,
-    utils::RateLimiter, 
+    error::{ReclaimError, Result},
+    solana::{
+        client::SolanaRpcClient,
+        parser::{CreationContext, ParserRegistry},
+        slot_time::SlotTimeEstimator,
+    },
+    storage::{models::DiscoveryEvidence, Database},
 };
+use serde::{Deserialize, Serialize};
 use tracing::{info, debug, warn};
 use std::str::FromStr;
 use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use chrono::{DateTime, Utc};
-
-// Constants for hardcoded values
-const ATA_RENT_EXEMPTION: u64 = 2_039_280; // ~0.00203928 SOL
-const ATA_SIZE: usize = 165;
+use tokio_util::sync::CancellationToken;
 
 /// Discovers accounts created/sponsored by a specific fee payer
 pub struct AccountDiscovery {
     rpc_client: SolanaRpcClient,
     fee_payer: Pubkey,
-    rate_limiter: RateLimiter, 
+    slot_clock: SlotTimeEstimator,
+    parsers: Arc<ParserRegistry>,
+    /// Parsed creations keyed by signature, consulted before `getTransaction`
+    cache: Option<Database>,
+    /// Stop paging once transactions are older than this
+    since: Option<DateTime<Utc>>,
+    /// Stop paging at transactions from before this slot
+    floor_slot: Option<u64>,
+    progress: Option<Arc<ScanProgress>>,
+    /// Checked before every signature; cancelled scans fail with `ReclaimError::Cancelled`
+    cancel: CancellationToken,
+}
+
+/// Counters a running discovery updates, so callers can report progress
+#[derive(Debug, Default)]
+pub struct ScanProgress {
+    signatures: AtomicUsize,
+    accounts: AtomicUsize,
+}
+
+impl ScanProgress {
+    /// Signatures looked at so far
+    pub fn signatures(&self) -> usize {
+        self.signatures.load(Ordering::Relaxed)
+    }
+
+    /// Sponsored accounts found so far
+    pub fn accounts(&self) -> usize {
+        self.accounts.load(Ordering::Relaxed)
+    }
+
+    fn record(&self, accounts: usize) {
+        self.signatures.fetch_add(1, Ordering::Relaxed);
+        self.accounts.store(accounts, Ordering::Relaxed);
+    }
 }
 
 /// Information about a discovered sponsored account
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SponsoredAccountInfo {
     pub pubkey: Pubkey,
     pub creation_signature: Signature,
@@ -39,27 +78,204 @@ pub struct SponsoredAccountInfo {
     pub initial_balance: u64,
     pub data_size: usize,
     pub account_type: AccountType,
+    /// Token mint, for token accounts whose creation names it
+    #[serde(default)]
+    pub mint: Option<Pubkey>,
+    /// Instructions that reported the account, kept as evidence; not cached
+    #[serde(skip)]
+    pub instructions: Vec<serde_json::Value>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum AccountType {
     System,
     SplToken,
+    /// Durable nonce account (system-owned, 80 bytes)
+    NonceAccount,
+    /// System account derived with createAccountWithSeed
+    SeededSystem,
     Other(Pubkey),
 }
 
+/// Size of a durable nonce account's state
+pub const NONCE_ACCOUNT_SIZE: usize = 80;
+
+/// Nonce authority of an initialized nonce account.
+///
+/// Layout (bincode): version u32, state u32 (1 = initialized),
+/// authority (32), durable nonce (32), lamports per signature (8)
+pub fn nonce_authority(data: &[u8]) -> Option<Pubkey> {
+    if data.len() < NONCE_ACCOUNT_SIZE {
+        return None;
+    }
+    
+    let state = u32::from_le_bytes(data[4..8].try_into().ok()?);
+    if state != 1 {
+        return None;
+    }
+    
+    let authority_bytes: [u8; 32] = data[8..40].try_into().ok()?;
+    Some(Pubkey::new_from_array(authority_bytes))
+}
+
+/// Size of an SPL token account; Token-2022 accounts with extensions are larger
+const TOKEN_ACCOUNT_SIZE: usize = 165;
+
+/// Mint of an SPL Token or Token-2022 account, the first field of its state
+pub fn token_mint(account: &solana_sdk::account::Account) -> Option<Pubkey> {
+    let is_token_program = account.owner == spl_token::id()
+        || account.owner == crate::solana::parser::TOKEN_2022_PROGRAM_ID;
+    if !is_token_program || account.data.len() < TOKEN_ACCOUNT_SIZE {
+        return None;
+    }
+    Some(Pubkey::new_from_array(account.data[..32].try_into().ok()?))
+}
+
+impl SponsoredAccountInfo {
+    /// Fold a later instruction on the same account into this record,
+    /// keeping the more specific type and the known rent and size
+    fn refine(&mut self, later: SponsoredAccountInfo) {
+        if self.account_type == AccountType::System || later.account_type == AccountType::NonceAccount {
+            self.account_type = later.account_type;
+        }
+        self.initial_balance = self.initial_balance.max(later.initial_balance);
+        self.data_size = self.data_size.max(later.data_size);
+        self.mint = self.mint.or(later.mint);
+        self.instructions.extend(later.instructions);
+    }
+    
+    /// Evidence of the attribution, when the creating instructions are known
+    pub fn evidence(&self) -> Option<DiscoveryEvidence> {
+        if self.instructions.is_empty() {
+            return None;
+        }
+        Some(DiscoveryEvidence {
+            account_pubkey: self.pubkey.to_string(),
+            signature: self.creation_signature.to_string(),
+            slot: self.creation_slot,
+            creation_time: self.creation_time,
+            instructions: self.instructions.clone(),
+            recorded_at: Utc::now(),
+        })
+    }
+}
+
+/// Re-run `parsers` over stored evidence, without any RPC call. `None` when
+/// the instructions no longer yield the account.
+pub fn replay_evidence(parsers: &ParserRegistry, evidence: &DiscoveryEvidence) -> Result<Option<SponsoredAccountInfo>> {
+    let ctx = CreationContext {
+        signature: Signature::from_str(&evidence.signature)?,
+        slot: evidence.slot,
+        creation_time: evidence.creation_time,
+    };
+    let instructions = evidence
+        .instructions
+        .iter()
+        .map(|instruction| serde_json::from_value(instruction.clone()))
+        .collect::<std::result::Result<Vec<UiInstruction>, _>>()?;
+    
+    let pubkey = Pubkey::from_str(&evidence.account_pubkey)?;
+    Ok(parse_instructions(parsers, &instructions, &ctx)?
+        .into_iter()
+        .find(|creation| creation.pubkey == pubkey))
+}
+
+/// Creations reported by `instructions`, one per account
+fn parse_instructions(
+    parsers: &ParserRegistry,
+    instructions: &[UiInstruction],
+    ctx: &CreationContext,
+) -> Result<Vec<SponsoredAccountInfo>> {
+    let mut creations: Vec<SponsoredAccountInfo> = Vec::new();
+    for instruction in instructions {
+        if let Some(mut creation) = parsers.parse(instruction, ctx)? {
+            creation.instructions = serde_json::to_value(instruction).into_iter().collect();
+            // createAccount + initializeNonce report the same account twice
+            match creations.iter_mut().find(|c| c.pubkey == creation.pubkey) {
+                Some(existing) => existing.refine(creation),
+                None => creations.push(creation),
+            }
+        }
+    }
+    Ok(creations)
+}
+
 impl AccountDiscovery {
     pub fn new(rpc_client: SolanaRpcClient, fee_payer: Pubkey) -> Self {
-        // Use the RPC client's rate limit delay
-        let rate_limit_ms = rpc_client.rate_limit_delay.as_millis() as u64;
-        
+        // Every call through the RPC client takes a token from its shared rate limiter
         Self { 
+            slot_clock: SlotTimeEstimator::new(rpc_client.clone()),
             rpc_client, 
             fee_payer,
-            rate_limiter: RateLimiter::new(rate_limit_ms), 
+            parsers: Arc::new(ParserRegistry::default()),
+            cache: None,
+            since: None,
+            floor_slot: None,
+            progress: None,
+            cancel: CancellationToken::new(),
         }
     }
     
+    /// Use a custom parser registry instead of the built-in parsers
+    pub fn with_parsers(mut self, parsers: Arc<ParserRegistry>) -> Self {
+        self.parsers = parsers;
+        self
+    }
+    
+    /// Cache parsed transactions in the database, so rescans only hit the
+    /// RPC for signatures that haven't been seen before
+    pub fn with_cache(mut self, db: Database) -> Self {
+        self.cache = Some(db);
+        self
+    }
+    
+    /// Only look at transactions from `since` onwards. Signatures arrive
+    /// newest first, so paging stops at the first older one.
+    pub fn with_since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+    
+    /// Never page past `slot`, however deep the scan. Nothing the operator
+    /// sponsored can be older than its first transaction.
+    pub fn with_floor_slot(mut self, slot: Option<u64>) -> Self {
+        self.floor_slot = slot;
+        self
+    }
+    
+    /// Count processed signatures and found accounts in `progress`
+    pub fn with_progress(mut self, progress: Option<Arc<ScanProgress>>) -> Self {
+        self.progress = progress;
+        self
+    }
+    
+    /// Stop at the next signature once `cancel` is cancelled. Nothing found
+    /// so far is returned, so callers never checkpoint a partial scan.
+    pub fn with_cancel(mut self, cancel: CancellationToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+    
+    /// Whether a signature predates the floor slot or the `since` bound. Uses
+    /// the block time, or the calibrated slot estimate when the RPC omits it.
+    async fn before_window(
+        &self,
+        sig_info: &solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature,
+    ) -> bool {
+        if self.floor_slot.is_some_and(|floor| sig_info.slot < floor) {
+            debug!("Reached the scan floor at slot {}", sig_info.slot);
+            return true;
+        }
+        let Some(since) = self.since else {
+            return false;
+        };
+        let time = match sig_info.block_time.and_then(|t| DateTime::from_timestamp(t, 0)) {
+            Some(time) => time,
+            None => self.slot_clock.estimate(sig_info.slot).await,
+        };
+        time < since
+    }
+    
     /// Discover accounts sponsored by the fee payer from transaction history
     pub async fn discover_from_signatures(
         &self,
@@ -73,13 +289,12 @@ impl AccountDiscovery {
         const BATCH_SIZE: usize = 1000;
         
         let mut total_fetched = 0;
+        let mut window_reached = false;
         
-        while total_fetched < max_signatures {
+        while total_fetched < max_signatures && !window_reached {
             let limit = std::cmp::min(BATCH_SIZE, max_signatures - total_fetched);
             
             
-            self.rate_limiter.wait().await;
-            
             // Fetch batch of signatures
             let signatures = self.rpc_client.get_signatures_for_address(
                 &self.fee_payer,
@@ -95,25 +310,29 @@ impl AccountDiscovery {
             debug!("Processing batch of {} signatures", signatures.len());
             
             for sig_info in &signatures {
+                if self.cancel.is_cancelled() {
+                    info!("Discovery cancelled after {} signatures", total_fetched);
+                    return Err(ReclaimError::Cancelled);
+                }
+                if self.before_window(sig_info).await {
+                    window_reached = true;
+                    break;
+                }
                 if sig_info.err.is_some() {
                     continue;
                 }
                 
                 let signature = Signature::from_str(&sig_info.signature)?;
                 
-                // ✅ USE: wait() - Rate limit transaction fetches
-                self.rate_limiter.wait().await;
-                
-                // Get full transaction details
-                if let Some(tx) = self.rpc_client.get_transaction(&signature).await? {
-                    let sponsored = self.parse_transaction_for_creations(&tx, signature).await?;
-                    // Only add accounts we haven't seen before
-                    for account_info in sponsored {
-                        if seen_accounts.insert(account_info.pubkey) {
-                            all_sponsored.push(account_info);
-                        }
+                // Only add accounts we haven't seen before
+                for account_info in self.creations_for(signature).await? {
+                    if seen_accounts.insert(account_info.pubkey) {
+                        all_sponsored.push(account_info);
                     }
                 }
+                if let Some(progress) = &self.progress {
+                    progress.record(all_sponsored.len());
+                }
             }
             
             total_fetched += signatures.len();
@@ -147,13 +366,11 @@ impl AccountDiscovery {
         const BATCH_SIZE: usize = 1000;
         
         let mut total_fetched = 0;
+        let mut window_reached = false;
         
-        while total_fetched < max_signatures {
+        while total_fetched < max_signatures && !window_reached {
             let limit = std::cmp::min(BATCH_SIZE, max_signatures - total_fetched);
             
-            // ✅ USE: wait() - Rate limit signature fetches
-            self.rate_limiter.wait().await;
-            
             // Fetch signatures UNTIL we reach since_signature
             let signatures = self.rpc_client.get_signatures_for_address(
                 &self.fee_payer,
@@ -170,25 +387,29 @@ impl AccountDiscovery {
             debug!("Processing batch of {} new signatures", signatures.len());
             
             for sig_info in &signatures {
+                if self.cancel.is_cancelled() {
+                    info!("Discovery cancelled after {} signatures", total_fetched);
+                    return Err(ReclaimError::Cancelled);
+                }
+                if self.before_window(sig_info).await {
+                    window_reached = true;
+                    break;
+                }
                 if sig_info.err.is_some() {
                     continue;
                 }
                 
                 let signature = Signature::from_str(&sig_info.signature)?;
                 
-                // ✅ USE: wait() - Rate limit transaction fetches
-                self.rate_limiter.wait().await;
-                
-                // Get full transaction details
-                if let Some(tx) = self.rpc_client.get_transaction(&signature).await? {
-                    let sponsored = self.parse_transaction_for_creations(&tx, signature).await?;
-                    // Only add accounts we haven't seen before
-                    for account_info in sponsored {
-                        if seen_accounts.insert(account_info.pubkey) {
-                            all_sponsored.push(account_info);
-                        }
+                // Only add accounts we haven't seen before
+                for account_info in self.creations_for(signature).await? {
+                    if seen_accounts.insert(account_info.pubkey) {
+                        all_sponsored.push(account_info);
                     }
                 }
+                if let Some(progress) = &self.progress {
+                    progress.record(all_sponsored.len());
+                }
             }
             
             total_fetched += signatures.len();
@@ -208,266 +429,126 @@ impl AccountDiscovery {
         Ok(all_sponsored)
     }
     
+    /// Accounts created by a single transaction seen on a live feed.
+    ///
+    /// Subscriptions can report a transaction slightly before the RPC serves
+    /// it, so a missing transaction is retried a few times before giving up.
+    pub async fn discover_signature(&self, signature: Signature) -> Result<Vec<SponsoredAccountInfo>> {
+        const ATTEMPTS: u32 = 5;
+        const RETRY_DELAY: std::time::Duration = std::time::Duration::from_secs(2);
+        
+        for attempt in 1..=ATTEMPTS {
+            if let Some(creations) = self.fetch_creations(signature).await? {
+                return Ok(creations);
+            }
+            if attempt < ATTEMPTS {
+                debug!("Transaction {} not available yet (attempt {})", signature, attempt);
+                tokio::time::sleep(RETRY_DELAY).await;
+            }
+        }
+        
+        warn!("Transaction {} still unavailable; the next polling scan will pick it up", signature);
+        Ok(Vec::new())
+    }
+    
+    /// Creation record for `pubkey` if `signature` is a sponsored creation of it
+    pub async fn creation_of(
+        &self,
+        pubkey: &Pubkey,
+        signature: Signature,
+    ) -> Result<Option<SponsoredAccountInfo>> {
+        Ok(self
+            .creations_for(signature)
+            .await?
+            .into_iter()
+            .find(|info| info.pubkey == *pubkey))
+    }
+    
+    /// Accounts created by a transaction, from the cache when possible
+    async fn creations_for(&self, signature: Signature) -> Result<Vec<SponsoredAccountInfo>> {
+        Ok(self.fetch_creations(signature).await?.unwrap_or_default())
+    }
+    
+    /// Like `creations_for`, but `None` when the RPC doesn't have the transaction
+    async fn fetch_creations(&self, signature: Signature) -> Result<Option<Vec<SponsoredAccountInfo>>> {
+        let fingerprint = self.parsers.fingerprint();
+        let key = signature.to_string();
+        
+        if let Some(db) = &self.cache {
+            match db.get_cached_creations(&key, &fingerprint) {
+                Ok(Some(creations)) => {
+                    debug!("Transaction cache hit for {}", signature);
+                    return Ok(Some(creations));
+                }
+                Ok(None) => {}
+                Err(e) => warn!("Failed to read transaction cache for {}: {}", signature, e),
+            }
+        }
+        
+        // Get full transaction details; not-yet-available transactions aren't cached
+        let creations = match self.rpc_client.get_transaction(&signature).await? {
+            Some(tx) => self.parse_transaction_for_creations(&tx, signature).await?,
+            None => return Ok(None),
+        };
+        
+        if let Some(db) = &self.cache {
+            if let Err(e) = db.cache_creations(&key, &fingerprint, &creations) {
+                warn!("Failed to cache transaction {}: {}", signature, e);
+            }
+            for evidence in creations.iter().filter_map(SponsoredAccountInfo::evidence) {
+                if let Err(e) = db.save_discovery_evidence(&evidence) {
+                    warn!("Failed to store discovery evidence for {}: {}", evidence.account_pubkey, e);
+                }
+            }
+        }
+        
+        Ok(Some(creations))
+    }
+    
     /// Parse a transaction to find account creation instructions
     async fn parse_transaction_for_creations(
         &self,
         tx: &EncodedConfirmedTransactionWithStatusMeta,
         signature: Signature,
     ) -> Result<Vec<SponsoredAccountInfo>> {
-        let mut creations = Vec::new();
-        
-        let slot = tx.slot;
-        let block_time = tx.block_time.unwrap_or(0);
-        
-        // CRITICAL: Do NOT use Utc::now() as fallback - it breaks inactivity calculations!
-        // If block_time is missing, estimate from slot (each slot is ~400ms)
-        let creation_time = if block_time > 0 {
-            DateTime::from_timestamp(block_time, 0)
-                .unwrap_or_else(|| {
-                    warn!("Invalid block_time {} for slot {}, using slot-based estimation", block_time, slot);
-                    // Estimate: slot 0 was around Sept 2020, each slot ~400ms
-                    let estimated_seconds = (slot as i64 * 400) / 1000;
-                    DateTime::from_timestamp(1600000000 + estimated_seconds, 0)
-                        .unwrap_or_else(|| DateTime::from_timestamp(0, 0).unwrap())
-                })
-        } else {
-            warn!("Missing block_time for slot {}, using slot-based estimation", slot);
-            // Estimate from slot number
-            let estimated_seconds = (slot as i64 * 400) / 1000;
-            DateTime::from_timestamp(1600000000 + estimated_seconds, 0)
-                .unwrap_or_else(|| DateTime::from_timestamp(0, 0).unwrap())
-        };
+        let creations = Vec::new();
         
         let transaction = match &tx.transaction.transaction {
             solana_transaction_status::EncodedTransaction::Json(ui_tx) => ui_tx,
             _ => return Ok(creations),
         };
+        let UiMessage::Parsed(parsed_msg) = &transaction.message else {
+            return Ok(creations);
+        };
+        if self.parsers.is_noise(parsed_msg) {
+            debug!("Skipping {}: only skipped programs invoked", signature);
+            return Ok(creations);
+        }
         
-        let message = &transaction.message;
-        let account_keys = self.extract_account_keys(message)?;
+        let slot = tx.slot;
+        let block_time = tx.block_time.unwrap_or(0);
         
-        if let UiMessage::Parsed(parsed_msg) = message {
-            for instruction in &parsed_msg.instructions {
-                if let Some(creation) = self.parse_instruction_for_creation(
-                    instruction,
-                    &account_keys,
-                    signature,
-                    slot,
-                    creation_time,
-                ).await? {
-                    creations.push(creation);
-                }
+        // CRITICAL: Do NOT use Utc::now() as fallback - it breaks inactivity calculations!
+        // If block_time is missing, estimate from slot using calibrated reference block times
+        let creation_time = match DateTime::from_timestamp(block_time, 0) {
+            Some(time) if block_time > 0 => time,
+            _ => {
+                warn!("Missing block_time for slot {}, using calibrated slot-time estimate", slot);
+                self.slot_clock.estimate(slot).await
             }
-        }
+        };
         
-        Ok(creations)
-    }
-    
-    fn extract_account_keys(&self, message: &UiMessage) -> Result<Vec<Pubkey>> {
-        match message {
-            UiMessage::Parsed(parsed) => {
-                parsed.account_keys.iter()
-                    .map(|key| Pubkey::from_str(&key.pubkey))
-                    .collect::<std::result::Result<Vec<_>, _>>()
-                    .map_err(|e| crate::error::ReclaimError::ParsePubkey(e))
-            }
-            UiMessage::Raw(raw) => {
-                raw.account_keys.iter()
-                    .map(|key| Pubkey::from_str(key))
-                    .collect::<std::result::Result<Vec<_>, _>>()
-                    .map_err(|e| crate::error::ReclaimError::ParsePubkey(e))
-            }
-        }
-    }
-    
-    async fn parse_instruction_for_creation(
-    &self,
-    instruction: &solana_transaction_status::UiInstruction,
-    _account_keys: &[Pubkey],
-    signature: Signature,
-    slot: u64,
-    creation_time: DateTime<Utc>,
-) -> Result<Option<SponsoredAccountInfo>> {
-    use solana_transaction_status::{UiInstruction, UiParsedInstruction};
-    use serde_json::Value;
-    
-    match instruction {
-        UiInstruction::Parsed(parsed_instr_enum) => {
-            match parsed_instr_enum {
-                UiParsedInstruction::Parsed(parsed_instr) => {
-                    let program = &parsed_instr.program;
-                    let parsed_value = &parsed_instr.parsed;
-                    
-                    // ✅ PRIORITY 1: Check for spl-associated-token-account (this is what Kora uses!)
-                    if program == "spl-associated-token-account" {
-                        if let Some(parsed_info) = parsed_value.as_object() {
-                            let type_option: Option<&str> = parsed_info.get("type").and_then(|v| v.as_str());
-                            if let Some(info_type) = type_option {
-                                // Both "create" and "createIdempotent" create ATAs
-                                if info_type == "create" || info_type == "createIdempotent" {
-                                    let info_option: Option<&serde_json::Map<String, Value>> = 
-                                        parsed_info.get("info").and_then(|v| v.as_object());
-                                    if let Some(info) = info_option {
-                                        // The ATA address is in the "account" field
-                                        let account_option: Option<&str> = 
-                                            info.get("account").and_then(|v| v.as_str());
-                                        if let Some(account_str) = account_option {
-                                            let ata_address = Pubkey::from_str(account_str)?;
-                                            
-                                            debug!("✓ Found ATA creation: {}", ata_address);
-                                            
-                                            // ATAs are 165 bytes and typically have ~0.00203928 SOL rent
-                                            return Ok(Some(SponsoredAccountInfo {
-                                                pubkey: ata_address,
-                                                creation_signature: signature,
-                                                creation_slot: slot,
-                                                creation_time,
-                                                initial_balance: ATA_RENT_EXEMPTION,
-                                                data_size: ATA_SIZE,
-                                                account_type: AccountType::SplToken,
-                                            }));
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                        
-                        debug!("Found spl-associated-token-account instruction but couldn't parse account address");
-                        return Ok(None);
-                    }
-                    
-                    // Check for System program CreateAccount
-                    if program == "system" {
-                        if let Some(parsed_info) = parsed_value.as_object() {
-                            let type_option: Option<&str> = parsed_info.get("type").and_then(|v| v.as_str());
-                            if let Some(info_type) = type_option {
-                                if info_type == "createAccount" || info_type == "createAccountWithSeed" {
-                                    let info_option: Option<&serde_json::Map<String, Value>> = 
-                                        parsed_info.get("info").and_then(|v| v.as_object());
-                                    if let Some(info) = info_option {
-                                        let new_account_option: Option<&str> = 
-                                            info.get("newAccount").and_then(|v| v.as_str());
-                                        if let Some(new_account_str) = new_account_option {
-                                            let new_account = Pubkey::from_str(new_account_str)?;
-                                            let lamports = info.get("lamports").and_then(|v| v.as_u64()).unwrap_or(0);
-                                            let space = info.get("space").and_then(|v| v.as_u64()).unwrap_or(0) as usize;
-                                            
-                                            debug!("✓ Found system account creation: {}", new_account);
-                                            
-                                            return Ok(Some(SponsoredAccountInfo {
-                                                pubkey: new_account,
-                                                creation_signature: signature,
-                                                creation_slot: slot,
-                                                creation_time,
-                                                initial_balance: lamports,
-                                                data_size: space,
-                                                account_type: AccountType::System,
-                                            }));
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    
-                    // Check for SPL Token InitializeAccount (less common, but still valid)
-                    if program == "spl-token" {
-                        if let Some(parsed_info) = parsed_value.as_object() {
-                            let type_option: Option<&str> = parsed_info.get("type").and_then(|v| v.as_str());
-                            if let Some(info_type) = type_option {
-                                if info_type == "initializeAccount" {
-                                    let info_option: Option<&serde_json::Map<String, Value>> = 
-                                        parsed_info.get("info").and_then(|v| v.as_object());
-                                    if let Some(info) = info_option {
-                                        let account_option: Option<&str> = 
-                                            info.get("account").and_then(|v| v.as_str());
-                                        if let Some(account_str) = account_option {
-                                            let account = Pubkey::from_str(account_str)?;
-                                            
-                                            debug!("✓ Found token account initialization: {}", account);
-                                            
-                                            return Ok(Some(SponsoredAccountInfo {
-                                                pubkey: account,
-                                                creation_signature: signature,
-                                                creation_slot: slot,
-                                                creation_time,
-                                                initial_balance: 0, // We can't determine balance from initializeAccount alone
-                                                data_size: ATA_SIZE,
-                                                account_type: AccountType::SplToken,
-                                            }));
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    
-                    // ✅ IMPROVED: More selective "Other" program detection
-                    // Only capture if it's clearly an account CREATION instruction
-                    if program != "system" 
-                        && program != "spl-token" 
-                        && program != "spl-associated-token-account" 
-                    {
-                        if let Some(parsed_info) = parsed_value.as_object() {
-                            let type_option: Option<&str> = parsed_info.get("type").and_then(|v| v.as_str());
-                            
-                            // Only process if the instruction type indicates creation
-                            if let Some(info_type) = type_option {
-                                let is_creation = info_type.contains("create") 
-                                    || info_type.contains("initialize")
-                                    || info_type.contains("init");
-                                
-                                if is_creation {
-                                    if let Some(info) = parsed_info.get("info").and_then(|v| v.as_object()) {
-                                        // Look for common account creation patterns
-                                        let account_key = info.get("account")
-                                            .or_else(|| info.get("newAccount"))
-                                            .or_else(|| info.get("address"))
-                                            .and_then(|v| v.as_str());
-                                        
-                                        if let Some(account_str) = account_key {
-                                            if let Ok(account_pubkey) = Pubkey::from_str(account_str) {
-                                                // Try to parse the program ID
-                                                if let Ok(program_id) = Pubkey::from_str(program) {
-                                                    debug!("✓ Detected account creation from program: {} (type: {})", program, info_type);
-                                                    
-                                                    return Ok(Some(SponsoredAccountInfo {
-                                                        pubkey: account_pubkey,
-                                                        creation_signature: signature,
-                                                        creation_slot: slot,
-                                                        creation_time,
-                                                        initial_balance: info.get("lamports").and_then(|v| v.as_u64()).unwrap_or(0),
-                                                        data_size: info.get("space").and_then(|v| v.as_u64()).unwrap_or(0) as usize,
-                                                        account_type: AccountType::Other(program_id),
-                                                    }));
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                            
-                            // Only log if we're actually looking at parsed info
-                            if parsed_info.get("info").is_some() {
-                                debug!("Found instruction from program: {} (no account creation detected)", program);
-                            }
-                        }
-                    }
-                }
-                UiParsedInstruction::PartiallyDecoded(_) => {}
-            }
-        }
-        UiInstruction::Compiled(_) => {}
+        let ctx = CreationContext {
+            signature,
+            slot,
+            creation_time,
+        };
+        
+        parse_instructions(&self.parsers, &parsed_msg.instructions, &ctx)
     }
     
-    Ok(None)
-}
-    
     /// Get the last transaction time for an account (for inactivity detection)
     pub async fn get_last_transaction_time(&self, address: &Pubkey) -> Result<Option<DateTime<Utc>>> {
-        // ✅ USE: wait() - Rate limit before fetching signatures
-        self.rate_limiter.wait().await;
-        
         let signatures = self.rpc_client.get_signatures_for_address(
             address,
             None,
@@ -483,4 +564,27 @@ impl AccountDiscovery {
         
         Ok(None)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{hash::Hash, nonce};
+
+    #[test]
+    fn test_nonce_authority() {
+        let authority = Pubkey::new_unique();
+        let durable_nonce = nonce::state::DurableNonce::from_blockhash(&Hash::new_unique());
+        let initialized = nonce::state::Versions::new(nonce::State::Initialized(nonce::state::Data::new(
+            authority,
+            durable_nonce,
+            5_000,
+        )));
+        let data = bincode::serialize(&initialized).unwrap();
+        assert_eq!(data.len(), NONCE_ACCOUNT_SIZE);
+        assert_eq!(nonce_authority(&data), Some(authority));
+
+        let uninitialized = bincode::serialize(&nonce::state::Versions::new(nonce::State::Uninitialized)).unwrap();
+        assert_eq!(nonce_authority(&uninitialized), None);
+        assert_eq!(nonce_authority(&data[..NONCE_ACCOUNT_SIZE - 1]), None);
+    }
+}
@@ -0,0 +1,93 @@
+// src/solana/budget.rs - Counts RPC calls by method and caps them per service cycle
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// RPC requests sent by any client in this process, by method
+static CALLS: Mutex<BTreeMap<&'static str, u64>> = Mutex::new(BTreeMap::new());
+
+/// Count one request to `method` towards the process totals
+pub(crate) fn record_call(method: &'static str) {
+    *CALLS.lock().unwrap().entry(method).or_default() += 1;
+}
+
+/// RPC requests sent so far by every client in the process, by method
+pub fn call_counts() -> BTreeMap<String, u64> {
+    CALLS.lock().unwrap().iter().map(|(method, calls)| (method.to_string(), *calls)).collect()
+}
+
+/// RPC requests made during one auto-service cycle, refused once `limit` is spent
+#[derive(Debug, Default)]
+pub struct RpcBudget {
+    limit: Option<u64>,
+    calls: Mutex<BTreeMap<&'static str, u64>>,
+}
+
+impl RpcBudget {
+    /// `None` only counts
+    pub fn new(limit: Option<u64>) -> Self {
+        Self { limit, calls: Mutex::default() }
+    }
+
+    pub fn limit(&self) -> Option<u64> {
+        self.limit
+    }
+
+    /// Count a request to `method`; `false` when the budget is already spent,
+    /// in which case the request must not be sent
+    pub(crate) fn charge(&self, method: &'static str) -> bool {
+        let mut calls = self.calls.lock().unwrap();
+        if self.limit.is_some_and(|limit| calls.values().sum::<u64>() >= limit) {
+            return false;
+        }
+        *calls.entry(method).or_default() += 1;
+        true
+    }
+
+    pub fn total(&self) -> u64 {
+        self.calls.lock().unwrap().values().sum()
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.limit.is_some_and(|limit| self.total() >= limit)
+    }
+
+    /// e.g. `1240 RPC calls (getTransaction 1000, getSignaturesForAddress 200, getMultipleAccounts 40)`
+    pub fn summary(&self) -> String {
+        let calls = self.calls.lock().unwrap();
+        let mut by_method: Vec<_> = calls.iter().collect();
+        by_method.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        let methods: Vec<String> = by_method.iter().map(|(method, n)| format!("{} {}", method, n)).collect();
+        let total: u64 = calls.values().sum();
+        let counted = match self.limit {
+            Some(limit) => format!("{} of {} RPC calls", total, limit),
+            None => format!("{} RPC calls", total),
+        };
+        if methods.is_empty() {
+            counted
+        } else {
+            format!("{} ({})", counted, methods.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget_refuses_calls_past_the_limit() {
+        let budget = RpcBudget::new(Some(3));
+        assert!(budget.charge("getTransaction"));
+        assert!(budget.charge("getTransaction"));
+        assert!(budget.charge("getAccountInfo"));
+        assert!(budget.is_exhausted());
+        assert!(!budget.charge("getTransaction"));
+        assert_eq!(budget.total(), 3);
+        assert_eq!(budget.summary(), "3 of 3 RPC calls (getTransaction 2, getAccountInfo 1)");
+
+        let unlimited = RpcBudget::new(None);
+        assert!((0..1_000).all(|_| unlimited.charge("getSlot")));
+        assert!(!unlimited.is_exhausted());
+    }
+}
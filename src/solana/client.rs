@@ -9,14 +9,75 @@ use solana_sdk::{
 use solana_transaction_status::{
     UiTransactionEncoding, EncodedConfirmedTransactionWithStatusMeta,
 };
+use solana_client::client_error::{ClientError, ClientErrorKind, Result as ClientResult};
 use solana_client::rpc_config::RpcTransactionConfig;
+use solana_client::rpc_request::RpcError;
+use crate::config::{Config, SolanaConfig};
 use crate::error::Result;
-use tracing::{debug, warn};
+use crate::solana::budget::{self, RpcBudget};
+use crate::utils::RateLimiter;
+use tracing::{debug, info, warn};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+/// Retries after a 429 before the error is returned to the caller
+const MAX_RATE_LIMIT_RETRIES: u32 = 6;
+const BACKOFF_BASE: Duration = Duration::from_millis(500);
+const BACKOFF_MAX: Duration = Duration::from_secs(30);
+
+/// JSON-RPC error code some providers use for rate limiting
+const RPC_RATE_LIMIT_CODE: i64 = -32429;
+
+/// Rate-limit responses seen by any client in this process
+static RATE_LIMITED_RESPONSES: AtomicU64 = AtomicU64::new(0);
+
+/// Number of rate-limit (HTTP 429 / -32429) responses received so far
+pub fn rate_limited_count() -> u64 {
+    RATE_LIMITED_RESPONSES.load(Ordering::Relaxed)
+}
+
+/// One limiter per RPC endpoint, so every client in the process shares the provider's budget
+static RATE_LIMITERS: Mutex<Option<HashMap<String, Arc<RateLimiter>>>> = Mutex::new(None);
+
+/// Process-wide limiter for `config.rpc_url`, replaced when its settings change
+fn shared_rate_limiter(config: &SolanaConfig) -> Arc<RateLimiter> {
+    let limiter = config.rate_limiter();
+    let mut limiters = RATE_LIMITERS.lock().unwrap();
+    let limiters = limiters.get_or_insert_with(HashMap::new);
+
+    match limiters.get(&config.rpc_url) {
+        Some(shared)
+            if shared.requests_per_second() == limiter.requests_per_second()
+                && shared.burst() == limiter.burst()
+                && shared.is_adaptive() == limiter.is_adaptive() =>
+        {
+            shared.clone()
+        }
+        _ => {
+            match limiter.requests_per_second() {
+                Some(rps) => info!(
+                    "RPC rate limit ({} provider): {:.2} req/s, burst {}{}",
+                    config.provider.resolve(&config.rpc_url),
+                    rps,
+                    limiter.burst(),
+                    if limiter.is_adaptive() { ", adaptive" } else { "" }
+                ),
+                None => info!("RPC rate limit disabled"),
+            }
+            let limiter = Arc::new(limiter);
+            limiters.insert(config.rpc_url.clone(), limiter.clone());
+            limiter
+        }
+    }
+}
+
 pub struct SolanaRpcClient {
     pub client: RpcClient,
-    pub(crate) rate_limit_delay: Duration,
+    rate_limiter: Arc<RateLimiter>,
+    /// Cap shared with clones, set for one auto-service cycle
+    budget: Option<Arc<RpcBudget>>,
 }
 
 impl Clone for SolanaRpcClient {
@@ -26,40 +87,114 @@ impl Clone for SolanaRpcClient {
                 self.client.url(),
                 self.client.commitment(),
             ),
-            rate_limit_delay: self.rate_limit_delay,
+            rate_limiter: self.rate_limiter.clone(),
+            budget: self.budget.clone(),
         }
     }
 }
 
 impl SolanaRpcClient {
+    /// Client with its own limiter spacing calls `rate_limit_ms` apart
     pub fn new(rpc_url: &str, commitment: CommitmentConfig, rate_limit_ms: u64) -> Self {
         let client = RpcClient::new_with_commitment(rpc_url.to_string(), commitment);
-        let rate_limit_delay = Duration::from_millis(rate_limit_ms);
-        Self { client, rate_limit_delay }
+        let rate_limiter = Arc::new(RateLimiter::from_delay_ms(rate_limit_ms));
+        Self { client, rate_limiter, budget: None }
+    }
+
+    /// Client for the configured endpoint, sharing that endpoint's rate
+    /// limiter with every other client in the process
+    pub fn from_config(config: &Config) -> Self {
+        let client = RpcClient::new_with_commitment(config.solana.rpc_url.clone(), config.commitment_config());
+        Self {
+            client,
+            rate_limiter: shared_rate_limiter(&config.solana),
+            budget: None,
+        }
+    }
+
+    /// Count every call through this client and its clones against `budget`,
+    /// refusing calls once it is spent
+    pub fn with_budget(mut self, budget: Arc<RpcBudget>) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// Limiter applied to every call through this client and its clones
+    pub fn rate_limiter(&self) -> Arc<RateLimiter> {
+        self.rate_limiter.clone()
     }
     
-    /// Apply rate limiting delay to avoid RPC throttling
+    /// Take a token from the rate limiter to avoid RPC throttling
     async fn rate_limit(&self) {
-        tokio::time::sleep(self.rate_limit_delay).await;
+        self.rate_limiter.wait().await;
     }
-    
-    /// Get account information
-    pub async fn get_account(&self, pubkey: &Pubkey) -> Result<Option<Account>> {
-        self.rate_limit().await;
-    
-        match self.client.get_account(pubkey) {
-            Ok(account) => Ok(Some(account)),
-            Err(e) => {
-                // Return None for AccountNotFound to allow callers to handle gracefully
-                if e.to_string().contains("AccountNotFound") {
-                    Ok(None)
-                } else {
-                    Err(e.into())
+
+    /// Count a request about to be sent, failing instead once the budget is spent
+    fn charge(&self, method: &'static str) -> ClientResult<()> {
+        if let Some(budget) = &self.budget {
+            if !budget.charge(method) {
+                return Err(ClientErrorKind::Custom(format!(
+                    "RPC budget of {} calls exhausted, {} not sent",
+                    budget.limit().unwrap_or_default(),
+                    method
+                ))
+                .into());
+            }
+        }
+        budget::record_call(method);
+        Ok(())
+    }
+
+    /// Run an RPC call, backing off exponentially (with jitter) while the
+    /// provider answers with rate-limit errors.
+    ///
+    /// The HTTP sender already honors `Retry-After` headers for a handful of
+    /// immediate retries; this covers sustained bursts beyond that, using a
+    /// retry hint from the error message when the provider sends one.
+    async fn call<T>(&self, method: &'static str, rpc: impl Fn() -> ClientResult<T>) -> ClientResult<T> {
+        let mut attempt = 0;
+        loop {
+            self.charge(method)?;
+            self.rate_limit().await;
+
+            match rpc() {
+                Err(e) if is_rate_limited(&e) && attempt < MAX_RATE_LIMIT_RETRIES => {
+                    RATE_LIMITED_RESPONSES.fetch_add(1, Ordering::Relaxed);
+                    self.rate_limiter.throttled();
+                    let delay = parse_retry_after(&e.to_string())
+                        .unwrap_or_else(|| backoff_delay(attempt, jitter_fraction()));
+                    warn!(
+                        "{} rate limited (attempt {}/{}), retrying in {:?}",
+                        method,
+                        attempt + 1,
+                        MAX_RATE_LIMIT_RETRIES,
+                        delay
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) if is_rate_limited(&e) => {
+                    RATE_LIMITED_RESPONSES.fetch_add(1, Ordering::Relaxed);
+                    self.rate_limiter.throttled();
+                    return Err(e);
                 }
+                result => return result,
             }
         }
     }
     
+    /// Get account information; `None` if the account does not exist.
+    ///
+    /// The underlying client words every failed request as "AccountNotFound",
+    /// so the typed response is used rather than the error text.
+    pub async fn get_account(&self, pubkey: &Pubkey) -> Result<Option<Account>> {
+        let commitment = self.client.commitment();
+        Ok(self
+            .call("getAccount", || self.client.get_account_with_commitment(pubkey, commitment))
+            .await?
+            .value)
+    }
+    
     /// Check if account exists and is active
     pub async fn is_account_active(&self, pubkey: &Pubkey) -> Result<bool> {
         Ok(self.get_account(pubkey).await?.is_some())
@@ -67,19 +202,31 @@ impl SolanaRpcClient {
     
     /// Get minimum balance for rent exemption
     pub fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> Result<u64> {
+        self.charge("getMinimumBalanceForRentExemption")?;
         Ok(self.client.get_minimum_balance_for_rent_exemption(data_len)?)
     }
     
     /// Get account balance (lamports)
     pub async fn get_balance(&self, pubkey: &Pubkey) -> Result<u64> {
-        self.rate_limit().await;
-        Ok(self.client.get_balance(pubkey)?)
+        Ok(self.call("getBalance", || self.client.get_balance(pubkey)).await?)
+    }
+    
+    /// Median priority fee (micro-lamports per compute unit) paid in recent
+    /// slots by transactions writing to `addresses`
+    pub async fn get_recent_priority_fee(&self, addresses: &[Pubkey]) -> Result<u64> {
+        let mut fees: Vec<u64> = self
+            .call("getRecentPrioritizationFees", || self.client.get_recent_prioritization_fees(addresses))
+            .await?
+            .into_iter()
+            .map(|fee| fee.prioritization_fee)
+            .collect();
+        fees.sort_unstable();
+        Ok(fees.get(fees.len() / 2).copied().unwrap_or(0))
     }
     
     /// Get multiple accounts efficiently
     pub async fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> Result<Vec<Option<Account>>> {
-        self.rate_limit().await;
-        Ok(self.client.get_multiple_accounts(pubkeys)?)
+        Ok(self.call("getMultipleAccounts", || self.client.get_multiple_accounts(pubkeys)).await?)
     }
     
     /// Get transaction signatures for an address with pagination
@@ -91,17 +238,18 @@ impl SolanaRpcClient {
         until: Option<Signature>,
         limit: usize,
     ) -> Result<Vec<solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature>> {
-        self.rate_limit().await;
-        
-        let config = solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config {
-            before,
-            until,
-            limit: Some(limit),
-            commitment: Some(self.client.commitment()),
-        };
-        
         debug!("Fetching signatures for address: {}", address);
-        let signatures = self.client.get_signatures_for_address_with_config(address, config)?;
+        let signatures = self
+            .call("getSignaturesForAddress", || {
+                let config = solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    until,
+                    limit: Some(limit),
+                    commitment: Some(self.client.commitment()),
+                };
+                self.client.get_signatures_for_address_with_config(address, config)
+            })
+            .await?;
         debug!("Found {} signatures", signatures.len());
         
         Ok(signatures)
@@ -112,15 +260,13 @@ impl SolanaRpcClient {
         &self,
         signature: &Signature,
     ) -> Result<Option<EncodedConfirmedTransactionWithStatusMeta>> {
-        self.rate_limit().await;
-        
         let config = RpcTransactionConfig {
     encoding: Some(UiTransactionEncoding::JsonParsed),
     commitment: Some(self.client.commitment()),
     max_supported_transaction_version: Some(0),
 };
         
-        match self.client.get_transaction_with_config(signature, config) {
+        match self.call("getTransaction", || self.client.get_transaction_with_config(signature, config)).await {
             Ok(tx) => Ok(Some(tx)),
             Err(e) => {
                 if e.to_string().contains("not found") {
@@ -133,20 +279,96 @@ impl SolanaRpcClient {
         }
     }
     
+    /// Get the current slot at the client's commitment
+    pub async fn get_slot(&self) -> Result<u64> {
+        Ok(self.call("getSlot", || self.client.get_slot()).await?)
+    }
+    
+    /// Current slot and how long the node took to return it, not counting
+    /// the wait for the rate limiter
+    pub async fn ping(&self) -> Result<(u64, Duration)> {
+        self.charge("getSlot")?;
+        self.rate_limit().await;
+        let started = std::time::Instant::now();
+        let slot = self.client.get_slot()?;
+        Ok((slot, started.elapsed()))
+    }
+    
+    /// Get the production time of a slot, or None if the slot was skipped
+    /// or its block is no longer available on this node
+    pub async fn get_block_time(&self, slot: u64) -> Result<Option<i64>> {
+        match self.call("getBlockTime", || self.client.get_block_time(slot)).await {
+            Ok(timestamp) => Ok(Some(timestamp)),
+            Err(e) => {
+                let msg = e.to_string();
+                if msg.contains("skipped") || msg.contains("not available") || msg.contains("cleaned up") {
+                    debug!("No block time for slot {}: {}", slot, msg);
+                    Ok(None)
+                } else {
+                    Err(e.into())
+                }
+            }
+        }
+    }
+    
+    /// Whether a sent transaction was processed, and if so whether it succeeded
+    pub async fn get_signature_status(
+        &self,
+        signature: &Signature,
+    ) -> Result<Option<std::result::Result<(), solana_sdk::transaction::TransactionError>>> {
+        Ok(self.call("getSignatureStatuses", || self.client.get_signature_status(signature)).await?)
+    }
+    
+    /// Statuses of up to 256 signatures, searching the ledger history for old
+    /// ones; `None` where the cluster has no record of the signature
+    pub async fn get_signature_statuses_with_history(
+        &self,
+        signatures: &[Signature],
+    ) -> Result<Vec<Option<solana_transaction_status::TransactionStatus>>> {
+        Ok(self
+            .call("getSignatureStatuses", || self.client.get_signature_statuses_with_history(signatures))
+            .await?
+            .value)
+    }
+
+    /// Latest slot the cluster has finalized, whatever the client's commitment
+    pub async fn get_finalized_slot(&self) -> Result<u64> {
+        Ok(self
+            .call("getSlot", || self.client.get_slot_with_commitment(CommitmentConfig::finalized()))
+            .await?)
+    }
+
+    /// Ask the cluster faucet for lamports (devnet and test validators only)
+    pub async fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> Result<Signature> {
+        Ok(self.call("requestAirdrop", || self.client.request_airdrop(pubkey, lamports)).await?)
+    }
+
     /// Get latest blockhash
     pub fn get_latest_blockhash(&self) -> Result<solana_sdk::hash::Hash> {
+        self.charge("getLatestBlockhash")?;
         Ok(self.client.get_latest_blockhash()?)
     }
     
+    /// Simulate a transaction without sending it: its error, if it would
+    /// fail, and the compute units it used
+    pub async fn simulate_transaction(
+        &self,
+        transaction: &Transaction,
+    ) -> Result<(Option<solana_sdk::transaction::TransactionError>, Option<u64>)> {
+        let result = self.call("simulateTransaction", || self.client.simulate_transaction(transaction)).await?;
+        Ok((result.value.err, result.value.units_consumed))
+    }
+    
     /// Send and confirm transaction with retry logic
     pub async fn send_and_confirm_transaction(
         &self,
         transaction: &Transaction,
     ) -> Result<Signature> {
         const MAX_RETRIES: u32 = 3;
-        let mut last_error = None;
+        let mut attempt = 1;
         
-        for attempt in 1..=MAX_RETRIES {
+        loop {
+            self.charge("sendTransaction")?;
             self.rate_limit().await;
             
             match self.client.send_and_confirm_transaction(transaction) {
@@ -155,21 +377,79 @@ impl SolanaRpcClient {
                     return Ok(signature);
                 }
                 Err(e) => {
-                    warn!("Transaction attempt {} failed: {}", attempt, e);
-                    last_error = Some(e);
-                    
-                    if attempt < MAX_RETRIES {
-                        let delay = Duration::from_secs(2u64.pow(attempt));
-                        tokio::time::sleep(delay).await;
+                    let error = crate::error::ReclaimError::from(e);
+                    // A rejected transaction fails the same way on every attempt
+                    if !error.is_retryable() || attempt == MAX_RETRIES {
+                        warn!("Transaction failed after {} attempt(s): {}", attempt, error);
+                        return Err(error);
                     }
+                    
+                    warn!("Transaction attempt {} failed: {}", attempt, error);
+                    tokio::time::sleep(Duration::from_secs(2u64.pow(attempt))).await;
+                    attempt += 1;
                 }
             }
         }
-        
-        Err(crate::error::ReclaimError::TransactionFailed(
-            format!("Transaction failed after {} retries: {:?}", 
-                MAX_RETRIES, 
-                last_error.unwrap())
-        ))
     }
-}
\ No newline at end of file
+}
+
+fn is_rate_limited(error: &ClientError) -> bool {
+    match error.kind() {
+        ClientErrorKind::Reqwest(e) => e.status().map(|status| status.as_u16()) == Some(429),
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. }) => *code == RPC_RATE_LIMIT_CODE,
+        _ => {
+            let msg = error.to_string().to_lowercase();
+            msg.contains("429") || msg.contains("too many requests") || msg.contains("rate limit")
+        }
+    }
+}
+
+/// Retry hint such as "retry after 3" or "Retry-After: 3" (seconds)
+fn parse_retry_after(message: &str) -> Option<Duration> {
+    let lower = message.to_lowercase();
+    let start = lower.find("retry after").or_else(|| lower.find("retry-after"))? + "retry after".len();
+    let seconds: String = lower[start..]
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+
+    seconds.parse::<u64>().ok()
+        .map(|secs| Duration::from_secs(secs).min(BACKOFF_MAX))
+}
+
+/// Exponential delay for `attempt` (0-based), stretched by up to half again
+/// by `jitter` in [0, 1) so parallel clients don't retry in lockstep
+fn backoff_delay(attempt: u32, jitter: f64) -> Duration {
+    let exponential = BACKOFF_BASE.saturating_mul(2u32.saturating_pow(attempt));
+    exponential.mul_f64(1.0 + jitter / 2.0).min(BACKOFF_MAX)
+}
+
+fn jitter_fraction() -> f64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    nanos as f64 / 1_000_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_caps() {
+        assert_eq!(backoff_delay(0, 0.0), Duration::from_millis(500));
+        assert_eq!(backoff_delay(3, 0.0), Duration::from_secs(4));
+        assert_eq!(backoff_delay(2, 0.5), Duration::from_millis(2500));
+        assert_eq!(backoff_delay(20, 0.9), BACKOFF_MAX);
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("Too many requests, retry after 3s"), Some(Duration::from_secs(3)));
+        assert_eq!(parse_retry_after("HTTP 429 Retry-After: 12"), Some(Duration::from_secs(12)));
+        assert_eq!(parse_retry_after("retry after 900"), Some(BACKOFF_MAX));
+        assert_eq!(parse_retry_after("429 Too Many Requests"), None);
+    }
+}
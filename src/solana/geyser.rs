@@ -0,0 +1,299 @@
+// src/solana/geyser.rs - Live operator transactions from a Yellowstone gRPC (Geyser) endpoint
+
+use futures::channel::mpsc::{unbounded, UnboundedSender};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tonic::{
+    codec::ProstCodec,
+    codegen::http::uri::PathAndQuery,
+    transport::{ClientTlsConfig, Endpoint},
+    Request,
+};
+use tracing::{debug, info, warn};
+
+use super::stream::reconnect_delay;
+use proto::subscribe_update::UpdateOneof;
+
+const SUBSCRIBE_PATH: &str = "/geyser.Geyser/Subscribe";
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Name of the transaction filter in the subscription; updates echo it back
+const FILTER_NAME: &str = "kora-operator";
+
+/// The messages of Yellowstone's `geyser.proto` this client reads and writes.
+/// Only the fields used are declared; the rest are skipped when decoding.
+pub(crate) mod proto {
+    use std::collections::HashMap;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SubscribeRequest {
+        #[prost(map = "string, message", tag = "3")]
+        pub transactions: HashMap<String, SubscribeRequestFilterTransactions>,
+        #[prost(enumeration = "CommitmentLevel", optional, tag = "6")]
+        pub commitment: Option<i32>,
+        #[prost(message, optional, tag = "9")]
+        pub ping: Option<SubscribeRequestPing>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SubscribeRequestFilterTransactions {
+        #[prost(bool, optional, tag = "1")]
+        pub vote: Option<bool>,
+        #[prost(bool, optional, tag = "2")]
+        pub failed: Option<bool>,
+        #[prost(string, repeated, tag = "3")]
+        pub account_include: Vec<String>,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+    #[repr(i32)]
+    pub enum CommitmentLevel {
+        Processed = 0,
+        Confirmed = 1,
+        Finalized = 2,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SubscribeRequestPing {
+        #[prost(int32, tag = "1")]
+        pub id: i32,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SubscribeUpdate {
+        #[prost(string, repeated, tag = "1")]
+        pub filters: Vec<String>,
+        #[prost(oneof = "subscribe_update::UpdateOneof", tags = "4, 6")]
+        pub update_oneof: Option<subscribe_update::UpdateOneof>,
+    }
+
+    pub mod subscribe_update {
+        #[derive(Clone, PartialEq, prost::Oneof)]
+        pub enum UpdateOneof {
+            #[prost(message, tag = "4")]
+            Transaction(super::SubscribeUpdateTransaction),
+            #[prost(message, tag = "6")]
+            Ping(super::SubscribeUpdatePing),
+        }
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SubscribeUpdateTransaction {
+        #[prost(message, optional, tag = "1")]
+        pub transaction: Option<SubscribeUpdateTransactionInfo>,
+        #[prost(uint64, tag = "2")]
+        pub slot: u64,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SubscribeUpdateTransactionInfo {
+        #[prost(bytes = "vec", tag = "1")]
+        pub signature: Vec<u8>,
+        #[prost(bool, tag = "2")]
+        pub is_vote: bool,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct SubscribeUpdatePing {}
+}
+
+/// Yellowstone `Subscribe` feed of successful transactions including a pubkey.
+///
+/// Like [`super::SignatureStream`], the subscription is re-established with
+/// backoff whenever the stream drops, so consumers only see signatures.
+pub struct GeyserStream {
+    endpoint: String,
+    x_token: Option<String>,
+    address: Pubkey,
+    commitment: CommitmentConfig,
+}
+
+impl GeyserStream {
+    pub fn new(endpoint: &str, x_token: Option<String>, address: Pubkey, commitment: CommitmentConfig) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            x_token,
+            address,
+            commitment,
+        }
+    }
+
+    /// Start the subscription in the background; it stops once the receiver is dropped
+    pub fn spawn(self) -> mpsc::UnboundedReceiver<Signature> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(async move { self.run(sender).await });
+        receiver
+    }
+
+    async fn run(self, sender: mpsc::UnboundedSender<Signature>) {
+        let mut failures = 0u32;
+
+        while !sender.is_closed() {
+            match self.subscribe_once(&sender).await {
+                Ok(()) => {
+                    warn!("Geyser subscription for {} ended, reconnecting", self.address);
+                    failures = 0;
+                }
+                Err(e) => {
+                    warn!("Geyser subscription to {} failed: {}", self.endpoint, e);
+                    failures = failures.saturating_add(1);
+                }
+            }
+
+            tokio::time::sleep(reconnect_delay(failures)).await;
+        }
+
+        debug!("Geyser stream for {} stopped", self.address);
+    }
+
+    /// Forward signatures until the server ends the stream
+    async fn subscribe_once(
+        &self,
+        sender: &mpsc::UnboundedSender<Signature>,
+    ) -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut endpoint = Endpoint::from_shared(self.endpoint.clone())?
+            .connect_timeout(CONNECT_TIMEOUT)
+            .http2_keep_alive_interval(KEEPALIVE_INTERVAL)
+            .keep_alive_while_idle(true);
+        if self.endpoint.starts_with("https://") {
+            endpoint = endpoint.tls_config(ClientTlsConfig::new())?;
+        }
+        let mut grpc = tonic::client::Grpc::new(endpoint.connect().await?);
+        grpc.ready().await?;
+
+        // The request stream stays open so pings can be answered
+        let (requests, outbound) = unbounded();
+        requests.unbounded_send(subscribe_request(&self.address, self.commitment))?;
+        let mut request = Request::new(outbound);
+        if let Some(token) = &self.x_token {
+            request.metadata_mut().insert("x-token", token.parse()?);
+        }
+
+        let codec = ProstCodec::<proto::SubscribeRequest, proto::SubscribeUpdate>::default();
+        let mut updates = grpc
+            .streaming(request, PathAndQuery::from_static(SUBSCRIBE_PATH), codec)
+            .await?
+            .into_inner();
+
+        info!("Subscribed to transactions including {} via {}", self.address, self.endpoint);
+
+        while let Some(update) = updates.message().await? {
+            match update.update_oneof {
+                Some(UpdateOneof::Ping(_)) => answer_ping(&requests),
+                Some(UpdateOneof::Transaction(transaction)) => {
+                    let Some(signature) = transaction_signature(&transaction) else {
+                        continue;
+                    };
+                    if sender.send(signature).is_err() {
+                        break;
+                    }
+                }
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Successful non-vote transactions that include `address`
+fn subscribe_request(address: &Pubkey, commitment: CommitmentConfig) -> proto::SubscribeRequest {
+    let filter = proto::SubscribeRequestFilterTransactions {
+        vote: Some(false),
+        failed: Some(false),
+        account_include: vec![address.to_string()],
+    };
+    let level = if commitment.is_finalized() {
+        proto::CommitmentLevel::Finalized
+    } else if commitment.is_confirmed() {
+        proto::CommitmentLevel::Confirmed
+    } else {
+        proto::CommitmentLevel::Processed
+    };
+
+    proto::SubscribeRequest {
+        transactions: HashMap::from([(FILTER_NAME.to_string(), filter)]),
+        commitment: Some(level as i32),
+        ping: None,
+    }
+}
+
+/// Servers and proxies drop subscriptions whose client never answers a ping
+fn answer_ping(requests: &UnboundedSender<proto::SubscribeRequest>) {
+    let pong = proto::SubscribeRequest {
+        ping: Some(proto::SubscribeRequestPing { id: 1 }),
+        ..Default::default()
+    };
+    if let Err(e) = requests.unbounded_send(pong) {
+        debug!("Could not answer Geyser ping: {}", e);
+    }
+}
+
+fn transaction_signature(update: &proto::SubscribeUpdateTransaction) -> Option<Signature> {
+    let info = update.transaction.as_ref().filter(|info| !info.is_vote)?;
+    match Signature::try_from(info.signature.as_slice()) {
+        Ok(signature) => Some(signature),
+        Err(e) => {
+            debug!("Ignoring malformed signature in slot {}: {}", update.slot, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+
+    #[test]
+    fn test_subscribe_request_filters_operator_transactions() {
+        let operator = Pubkey::new_unique();
+        let request = subscribe_request(&operator, CommitmentConfig::finalized());
+
+        let decoded = proto::SubscribeRequest::decode(request.encode_to_vec().as_slice()).unwrap();
+        let filter = &decoded.transactions[FILTER_NAME];
+        assert_eq!(filter.account_include, vec![operator.to_string()]);
+        assert_eq!((filter.vote, filter.failed), (Some(false), Some(false)));
+        assert_eq!(decoded.commitment, Some(proto::CommitmentLevel::Finalized as i32));
+        assert_eq!(
+            subscribe_request(&operator, CommitmentConfig::confirmed()).commitment,
+            Some(proto::CommitmentLevel::Confirmed as i32)
+        );
+    }
+
+    #[test]
+    fn test_transaction_update_signature() {
+        let signature = Signature::new_unique();
+        let update = proto::SubscribeUpdate {
+            filters: vec![FILTER_NAME.to_string()],
+            update_oneof: Some(UpdateOneof::Transaction(proto::SubscribeUpdateTransaction {
+                transaction: Some(proto::SubscribeUpdateTransactionInfo {
+                    signature: signature.as_ref().to_vec(),
+                    is_vote: false,
+                }),
+                slot: 42,
+            })),
+        };
+
+        // Fields this client does not declare, such as `created_at`, are skipped
+        let mut bytes = update.encode_to_vec();
+        bytes.extend_from_slice(&[0x5a, 0x02, 0x08, 0x01]);
+        let Some(UpdateOneof::Transaction(transaction)) = proto::SubscribeUpdate::decode(bytes.as_slice())
+            .unwrap()
+            .update_oneof
+        else {
+            panic!("expected a transaction update");
+        };
+        assert_eq!(transaction_signature(&transaction), Some(signature));
+
+        let mut vote = transaction.clone();
+        vote.transaction.as_mut().unwrap().is_vote = true;
+        assert_eq!(transaction_signature(&vote), None);
+        let mut malformed = transaction;
+        malformed.transaction.as_mut().unwrap().signature.truncate(10);
+        assert_eq!(transaction_signature(&malformed), None);
+    }
+}
@@ -0,0 +1,66 @@
+// src/solana/health.rs - RPC outage tracking behind the degraded-mode alert
+
+use chrono::{DateTime, Duration, Utc};
+
+/// How often the auto service checks a down endpoint again, instead of
+/// waiting out the whole cycle interval
+pub const RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Whether the endpoint has been failing health checks, and for how long
+#[derive(Debug, Clone, Default)]
+pub struct RpcHealth {
+    /// First failed check of the current outage
+    down_since: Option<DateTime<Utc>>,
+    /// The degraded-mode alert went out for the current outage
+    degraded: bool,
+}
+
+impl RpcHealth {
+    /// Record a failed check. True exactly once per outage: when it has
+    /// lasted `degraded_after`, and the alert should go out.
+    pub fn record_failure(&mut self, now: DateTime<Utc>, degraded_after: Duration) -> bool {
+        let down_since = *self.down_since.get_or_insert(now);
+        if self.degraded || now - down_since < degraded_after {
+            return false;
+        }
+        self.degraded = true;
+        true
+    }
+
+    /// Record a passed check. The outage's length if it had been reported as
+    /// degraded, so recovery is announced only after an alert.
+    pub fn record_success(&mut self, now: DateTime<Utc>) -> Option<Duration> {
+        let down_since = self.down_since.take()?;
+        std::mem::take(&mut self.degraded).then(|| now - down_since)
+    }
+
+    /// When the current outage started, if the endpoint is failing
+    pub fn down_since(&self) -> Option<DateTime<Utc>> {
+        self.down_since
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_alert_and_one_recovery_per_outage() {
+        let start = Utc::now();
+        let after = Duration::minutes(10);
+        let mut health = RpcHealth::default();
+
+        // A blip shorter than the threshold is never reported
+        assert!(!health.record_failure(start, after));
+        assert_eq!(health.record_success(start + Duration::minutes(1)), None);
+
+        assert!(!health.record_failure(start, after));
+        assert!(!health.record_failure(start + Duration::minutes(5), after));
+        assert!(health.record_failure(start + Duration::minutes(10), after));
+        assert!(!health.record_failure(start + Duration::minutes(15), after));
+        assert_eq!(health.down_since(), Some(start));
+
+        assert_eq!(health.record_success(start + Duration::minutes(20)), Some(Duration::minutes(20)));
+        assert_eq!(health.down_since(), None);
+    }
+}
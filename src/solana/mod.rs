@@ -1,5 +1,19 @@
+pub mod budget;
 pub mod client;
+pub mod geyser;
+pub mod health;
 pub mod accounts;
+pub mod parser;
+pub mod provider;
 pub mod rent;
+pub mod slot_time;
+pub mod stream;
+#[cfg(test)]
+pub(crate) mod test_rpc;
 
 pub use client::SolanaRpcClient;
+pub use geyser::GeyserStream;
+pub use parser::ParserRegistry;
+pub use provider::RpcProvider;
+pub use slot_time::SlotTimeEstimator;
+pub use stream::SignatureStream;
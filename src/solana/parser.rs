@@ -0,0 +1,697 @@
+// src/solana/parser.rs - Per-program instruction parsers for sponsored account discovery
+
+use solana_sdk::{pubkey, pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::{UiInstruction, UiParsedInstruction, UiParsedMessage};
+use std::collections::HashSet;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::str::FromStr;
+use chrono::{DateTime, Utc};
+use crate::{
+    config::{Config, CustomProgramConfig},
+    error::Result,
+    solana::accounts::{AccountType, SponsoredAccountInfo, NONCE_ACCOUNT_SIZE},
+};
+use tracing::{debug, warn};
+
+// Constants for hardcoded values
+const ATA_RENT_EXEMPTION: u64 = 2_039_280; // ~0.00203928 SOL
+const ATA_SIZE: usize = 165;
+
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bMg8TnGP");
+
+/// Programs that never create accounts; operators pay for plenty of
+/// transactions made only of these
+const NOISE_PROGRAMS: [Pubkey; 3] = [
+    solana_sdk::compute_budget::ID,
+    pubkey!("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo"),
+    pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
+];
+
+// Token Metadata instruction discriminators and account sizes
+const CREATE_MASTER_EDITION_V3: u8 = 17;
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+const MAX_MASTER_EDITION_LEN: usize = 282;
+const MAX_METADATA_LEN: usize = 679;
+
+/// Transaction-level facts shared by every account created in it
+#[derive(Debug, Clone, Copy)]
+pub struct CreationContext {
+    pub signature: Signature,
+    pub slot: u64,
+    pub creation_time: DateTime<Utc>,
+}
+
+impl CreationContext {
+    pub fn account(
+        &self,
+        pubkey: Pubkey,
+        initial_balance: u64,
+        data_size: usize,
+        account_type: AccountType,
+    ) -> SponsoredAccountInfo {
+        SponsoredAccountInfo {
+            pubkey,
+            creation_signature: self.signature,
+            creation_slot: self.slot,
+            creation_time: self.creation_time,
+            initial_balance,
+            data_size,
+            account_type,
+            mint: None,
+            instructions: Vec::new(),
+        }
+    }
+}
+
+impl SponsoredAccountInfo {
+    /// Record the token mint named by the creating instruction
+    fn with_mint(mut self, mint: Option<Pubkey>) -> Self {
+        self.mint = mint;
+        self
+    }
+}
+
+/// An instruction as seen by a program parser
+#[derive(Debug)]
+pub enum InstructionView<'a> {
+    /// jsonParsed output from the RPC node
+    Parsed {
+        instruction_type: &'a str,
+        info: &'a Map<String, Value>,
+    },
+    /// Programs the node cannot parse: account list and decoded data
+    Raw {
+        accounts: &'a [String],
+        data: &'a [u8],
+    },
+}
+
+/// Detects account creations for a single program.
+///
+/// Implement this and register it with [`ParserRegistry::register`] to
+/// teach discovery about a new sponsored program.
+pub trait ProgramParser: Send + Sync {
+    fn program_id(&self) -> Pubkey;
+
+    fn name(&self) -> &str;
+
+    fn parse(
+        &self,
+        instruction: &InstructionView<'_>,
+        ctx: &CreationContext,
+    ) -> Result<Option<SponsoredAccountInfo>>;
+}
+
+/// Program ID → parser lookup used by `AccountDiscovery`
+pub struct ParserRegistry {
+    parsers: HashMap<Pubkey, Box<dyn ProgramParser>>,
+    /// Transactions invoking only these programs are not parsed
+    skipped: HashSet<Pubkey>,
+}
+
+impl Default for ParserRegistry {
+    /// Registry with the built-in parsers
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        registry.register(Box::new(SystemParser));
+        registry.register(Box::new(SplTokenParser));
+        registry.register(Box::new(Token2022Parser));
+        registry.register(Box::new(AssociatedTokenParser));
+        registry.register(Box::new(TokenMetadataParser));
+        registry
+    }
+}
+
+impl ParserRegistry {
+    /// Registry without any parsers, skipping only the built-in noise programs
+    pub fn empty() -> Self {
+        Self {
+            parsers: HashMap::new(),
+            skipped: NOISE_PROGRAMS.into_iter().collect(),
+        }
+    }
+
+    /// Built-in parsers plus the custom programs from `[[kora.custom_programs]]`,
+    /// skipping the programs in `discovery.skip_programs`
+    pub fn from_config(config: &Config) -> Self {
+        let mut registry = Self::default();
+
+        for program in &config.kora.custom_programs {
+            match CustomProgramParser::from_config(program) {
+                Ok(parser) => registry.register(Box::new(parser)),
+                Err(e) => warn!("Skipping custom program {}: {}", program.program_id, e),
+            }
+        }
+
+        for program_id in &config.discovery.skip_programs {
+            match Pubkey::from_str(program_id) {
+                Ok(program_id) => registry.skip(program_id),
+                Err(e) => warn!("Ignoring skip program {}: {}", program_id, e),
+            }
+        }
+
+        registry
+    }
+
+    /// Don't parse transactions whose instructions all belong to skipped programs
+    pub fn skip(&mut self, program_id: Pubkey) {
+        self.skipped.insert(program_id);
+    }
+
+    /// Whether every top-level instruction of `message` belongs to a skipped
+    /// program, so it cannot create an account. Only looks up program IDs in
+    /// the account keys, which is far cheaper than a full parse.
+    pub fn is_noise(&self, message: &UiParsedMessage) -> bool {
+        let program_id = |instruction: &UiInstruction| -> Option<Pubkey> {
+            let id = match instruction {
+                UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed)) => &parsed.program_id,
+                UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(partial)) => &partial.program_id,
+                UiInstruction::Compiled(compiled) => {
+                    &message.account_keys.get(compiled.program_id_index as usize)?.pubkey
+                }
+            };
+            Pubkey::from_str(id).ok()
+        };
+
+        !message.instructions.is_empty()
+            && message
+                .instructions
+                .iter()
+                .all(|instruction| program_id(instruction).is_some_and(|id| self.skipped.contains(&id)))
+    }
+
+    /// Add a parser, replacing any existing one for the same program
+    pub fn register(&mut self, parser: Box<dyn ProgramParser>) {
+        debug!("Registering instruction parser: {} ({})", parser.name(), parser.program_id());
+        self.parsers.insert(parser.program_id(), parser);
+    }
+
+    pub fn get(&self, program_id: &Pubkey) -> Option<&dyn ProgramParser> {
+        self.parsers.get(program_id).map(|p| p.as_ref())
+    }
+
+    /// Stable identifier for the registered program set, used to invalidate
+    /// cached parse results when parsers are added or removed. Skipping the
+    /// built-in noise programs never changes a result, so only other skipped
+    /// programs count.
+    pub fn fingerprint(&self) -> String {
+        let mut ids: Vec<String> = self.parsers.keys().map(|id| id.to_string()).collect();
+        ids.sort();
+        let mut skipped: Vec<String> = self
+            .skipped
+            .iter()
+            .filter(|id| !NOISE_PROGRAMS.contains(id))
+            .map(|id| id.to_string())
+            .collect();
+        if skipped.is_empty() {
+            return ids.join(",");
+        }
+        skipped.sort();
+        format!("{};skip:{}", ids.join(","), skipped.join(","))
+    }
+
+    pub fn len(&self) -> usize {
+        self.parsers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parsers.is_empty()
+    }
+
+    /// Dispatch an instruction to its program's parser.
+    ///
+    /// Parsed instructions from unregistered programs fall back to a generic
+    /// create/initialize heuristic; raw ones are ignored.
+    pub fn parse(
+        &self,
+        instruction: &UiInstruction,
+        ctx: &CreationContext,
+    ) -> Result<Option<SponsoredAccountInfo>> {
+        let parsed_instr_enum = match instruction {
+            UiInstruction::Parsed(parsed) => parsed,
+            UiInstruction::Compiled(_) => return Ok(None),
+        };
+
+        match parsed_instr_enum {
+            UiParsedInstruction::Parsed(parsed_instr) => {
+                let Ok(program_id) = Pubkey::from_str(&parsed_instr.program_id) else {
+                    return Ok(None);
+                };
+
+                let Some(parsed_info) = parsed_instr.parsed.as_object() else {
+                    return Ok(None);
+                };
+                let instruction_type = parsed_info.get("type").and_then(|v| v.as_str());
+                let info = parsed_info.get("info").and_then(|v| v.as_object());
+
+                let (Some(instruction_type), Some(info)) = (instruction_type, info) else {
+                    return Ok(None);
+                };
+
+                let view = InstructionView::Parsed { instruction_type, info };
+
+                match self.get(&program_id) {
+                    Some(parser) => parser.parse(&view, ctx),
+                    None => generic_creation(&program_id, &parsed_instr.program, instruction_type, info, ctx),
+                }
+            }
+            UiParsedInstruction::PartiallyDecoded(partial) => {
+                let Ok(program_id) = Pubkey::from_str(&partial.program_id) else {
+                    return Ok(None);
+                };
+                let Some(parser) = self.get(&program_id) else {
+                    return Ok(None);
+                };
+
+                let data = match solana_sdk::bs58::decode(&partial.data).into_vec() {
+                    Ok(data) => data,
+                    Err(e) => {
+                        debug!("Undecodable instruction data for {}: {}", program_id, e);
+                        return Ok(None);
+                    }
+                };
+
+                let view = InstructionView::Raw {
+                    accounts: &partial.accounts,
+                    data: &data,
+                };
+
+                parser.parse(&view, ctx)
+            }
+        }
+    }
+}
+
+fn info_pubkey(info: &Map<String, Value>, field: &str) -> Result<Option<Pubkey>> {
+    match info.get(field).and_then(|v| v.as_str()) {
+        Some(s) => Ok(Some(Pubkey::from_str(s)?)),
+        None => Ok(None),
+    }
+}
+
+fn info_u64(info: &Map<String, Value>, field: &str) -> u64 {
+    info.get(field).and_then(|v| v.as_u64()).unwrap_or(0)
+}
+
+/// Heuristic for unregistered programs: only clear create/initialize instructions
+fn generic_creation(
+    program_id: &Pubkey,
+    program: &str,
+    instruction_type: &str,
+    info: &Map<String, Value>,
+    ctx: &CreationContext,
+) -> Result<Option<SponsoredAccountInfo>> {
+    let is_creation = instruction_type.contains("create")
+        || instruction_type.contains("initialize")
+        || instruction_type.contains("init");
+
+    if !is_creation {
+        debug!("Found instruction from program: {} (no account creation detected)", program);
+        return Ok(None);
+    }
+
+    // Look for common account creation patterns
+    let account_key = info.get("account")
+        .or_else(|| info.get("newAccount"))
+        .or_else(|| info.get("address"))
+        .and_then(|v| v.as_str())
+        .and_then(|s| Pubkey::from_str(s).ok());
+
+    Ok(account_key.map(|account| {
+        debug!("✓ Detected account creation from program: {} (type: {})", program, instruction_type);
+        ctx.account(
+            account,
+            info_u64(info, "lamports"),
+            info_u64(info, "space") as usize,
+            AccountType::Other(*program_id),
+        )
+    }))
+}
+
+/// System program `createAccount` / `createAccountWithSeed` / `initializeNonce`
+pub struct SystemParser;
+
+impl ProgramParser for SystemParser {
+    fn program_id(&self) -> Pubkey {
+        solana_sdk::system_program::id()
+    }
+
+    fn name(&self) -> &str {
+        "system"
+    }
+
+    fn parse(&self, instruction: &InstructionView<'_>, ctx: &CreationContext) -> Result<Option<SponsoredAccountInfo>> {
+        let InstructionView::Parsed { instruction_type, info } = instruction else {
+            return Ok(None);
+        };
+
+        let account_type = match *instruction_type {
+            "createAccount" => AccountType::System,
+            "createAccountWithSeed" => match info_pubkey(info, "owner")? {
+                Some(owner) if owner != solana_sdk::system_program::id() => AccountType::Other(owner),
+                _ => AccountType::SeededSystem,
+            },
+            "initializeNonce" => {
+                // Rent was funded by the preceding createAccount; this only classifies it
+                return Ok(info_pubkey(info, "nonceAccount")?.map(|nonce_account| {
+                    debug!("✓ Found nonce account initialization: {}", nonce_account);
+                    ctx.account(nonce_account, 0, NONCE_ACCOUNT_SIZE, AccountType::NonceAccount)
+                }));
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(info_pubkey(info, "newAccount")?.map(|new_account| {
+            debug!("✓ Found system account creation: {} ({:?})", new_account, account_type);
+            ctx.account(
+                new_account,
+                info_u64(info, "lamports"),
+                info_u64(info, "space") as usize,
+                account_type,
+            )
+        }))
+    }
+}
+
+/// SPL Token `initializeAccount{,2,3}` (less common than ATAs, but still valid)
+pub struct SplTokenParser;
+
+impl ProgramParser for SplTokenParser {
+    fn program_id(&self) -> Pubkey {
+        spl_token::id()
+    }
+
+    fn name(&self) -> &str {
+        "spl-token"
+    }
+
+    fn parse(&self, instruction: &InstructionView<'_>, ctx: &CreationContext) -> Result<Option<SponsoredAccountInfo>> {
+        parse_token_initialize(instruction, ctx, AccountType::SplToken)
+    }
+}
+
+/// Token-2022 `initializeAccount{,2,3}`
+pub struct Token2022Parser;
+
+impl ProgramParser for Token2022Parser {
+    fn program_id(&self) -> Pubkey {
+        TOKEN_2022_PROGRAM_ID
+    }
+
+    fn name(&self) -> &str {
+        "spl-token-2022"
+    }
+
+    fn parse(&self, instruction: &InstructionView<'_>, ctx: &CreationContext) -> Result<Option<SponsoredAccountInfo>> {
+        parse_token_initialize(instruction, ctx, AccountType::Other(TOKEN_2022_PROGRAM_ID))
+    }
+}
+
+fn parse_token_initialize(
+    instruction: &InstructionView<'_>,
+    ctx: &CreationContext,
+    account_type: AccountType,
+) -> Result<Option<SponsoredAccountInfo>> {
+    let InstructionView::Parsed { instruction_type, info } = instruction else {
+        return Ok(None);
+    };
+
+    if !matches!(*instruction_type, "initializeAccount" | "initializeAccount2" | "initializeAccount3") {
+        return Ok(None);
+    }
+
+    let mint = info_pubkey(info, "mint")?;
+    Ok(info_pubkey(info, "account")?.map(|account| {
+        debug!("✓ Found token account initialization: {}", account);
+        // We can't determine balance from initializeAccount alone
+        ctx.account(account, 0, ATA_SIZE, account_type).with_mint(mint)
+    }))
+}
+
+/// Associated Token Account `create` / `createIdempotent` (this is what Kora uses!)
+pub struct AssociatedTokenParser;
+
+impl ProgramParser for AssociatedTokenParser {
+    fn program_id(&self) -> Pubkey {
+        spl_associated_token_account::id()
+    }
+
+    fn name(&self) -> &str {
+        "spl-associated-token-account"
+    }
+
+    fn parse(&self, instruction: &InstructionView<'_>, ctx: &CreationContext) -> Result<Option<SponsoredAccountInfo>> {
+        let InstructionView::Parsed { instruction_type, info } = instruction else {
+            return Ok(None);
+        };
+
+        if !matches!(*instruction_type, "create" | "createIdempotent") {
+            return Ok(None);
+        }
+
+        let Some(ata_address) = info_pubkey(info, "account")? else {
+            debug!("Found spl-associated-token-account instruction but couldn't parse account address");
+            return Ok(None);
+        };
+
+        // ATAs for Token-2022 mints are owned by the Token-2022 program
+        let account_type = match info_pubkey(info, "tokenProgram")? {
+            Some(program) if program == TOKEN_2022_PROGRAM_ID => AccountType::Other(program),
+            _ => AccountType::SplToken,
+        };
+
+        debug!("✓ Found ATA creation: {}", ata_address);
+
+        // ATAs are 165 bytes and typically have ~0.00203928 SOL rent
+        Ok(Some(
+            ctx.account(ata_address, ATA_RENT_EXEMPTION, ATA_SIZE, account_type)
+                .with_mint(info_pubkey(info, "mint")?),
+        ))
+    }
+}
+
+/// Metaplex Token Metadata metadata and master edition accounts.
+///
+/// The RPC node does not parse this program, so detection works on the raw
+/// discriminator; the created PDA is always the first account.
+pub struct TokenMetadataParser;
+
+impl ProgramParser for TokenMetadataParser {
+    fn program_id(&self) -> Pubkey {
+        TOKEN_METADATA_PROGRAM_ID
+    }
+
+    fn name(&self) -> &str {
+        "metaplex-token-metadata"
+    }
+
+    fn parse(&self, instruction: &InstructionView<'_>, ctx: &CreationContext) -> Result<Option<SponsoredAccountInfo>> {
+        let InstructionView::Raw { accounts, data } = instruction else {
+            return Ok(None);
+        };
+
+        let data_size = match data.first() {
+            Some(&CREATE_METADATA_ACCOUNT_V3) => MAX_METADATA_LEN,
+            Some(&CREATE_MASTER_EDITION_V3) => MAX_MASTER_EDITION_LEN,
+            _ => return Ok(None),
+        };
+
+        let Some(account) = accounts.first() else {
+            return Ok(None);
+        };
+        let account = Pubkey::from_str(account)?;
+
+        debug!("✓ Found token metadata account creation: {}", account);
+
+        // Rent is paid via CPI; the balance refresher fills in the real amount
+        Ok(Some(ctx.account(account, 0, data_size, AccountType::Other(TOKEN_METADATA_PROGRAM_ID))))
+    }
+}
+
+/// Operator-defined program from `[[kora.custom_programs]]`
+pub struct CustomProgramParser {
+    program_id: Pubkey,
+    name: String,
+    instruction_types: Vec<String>,
+    discriminator: Vec<u8>,
+    account_index: usize,
+    data_size: usize,
+}
+
+impl CustomProgramParser {
+    pub fn from_config(config: &CustomProgramConfig) -> Result<Self> {
+        let program_id = Pubkey::from_str(&config.program_id)?;
+
+        Ok(Self {
+            program_id,
+            name: config.name.clone().unwrap_or_else(|| program_id.to_string()),
+            instruction_types: config.instruction_types.clone(),
+            discriminator: config.discriminator.clone(),
+            account_index: config.account_index,
+            data_size: config.data_size,
+        })
+    }
+}
+
+impl ProgramParser for CustomProgramParser {
+    fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn parse(&self, instruction: &InstructionView<'_>, ctx: &CreationContext) -> Result<Option<SponsoredAccountInfo>> {
+        let account_type = AccountType::Other(self.program_id);
+
+        match instruction {
+            InstructionView::Parsed { instruction_type, info } => {
+                if !self.instruction_types.iter().any(|t| t == instruction_type) {
+                    return Ok(None);
+                }
+
+                let account = info_pubkey(info, "account")?
+                    .or(info_pubkey(info, "newAccount")?);
+
+                Ok(account.map(|account| {
+                    debug!("✓ Found {} account creation: {}", self.name, account);
+                    let space = info_u64(info, "space") as usize;
+                    ctx.account(
+                        account,
+                        info_u64(info, "lamports"),
+                        if space > 0 { space } else { self.data_size },
+                        account_type,
+                    )
+                }))
+            }
+            InstructionView::Raw { accounts, data } => {
+                // Without a discriminator every instruction would match
+                if self.discriminator.is_empty() || !data.starts_with(&self.discriminator) {
+                    return Ok(None);
+                }
+
+                let Some(account) = accounts.get(self.account_index) else {
+                    return Ok(None);
+                };
+                let account = Pubkey::from_str(account)?;
+
+                debug!("✓ Found {} account creation: {}", self.name, account);
+                Ok(Some(ctx.account(account, 0, self.data_size, account_type)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn ctx() -> CreationContext {
+        CreationContext {
+            signature: Signature::default(),
+            slot: 1,
+            creation_time: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_ata_parser_detects_token_2022_accounts() {
+        let account = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let info = json!({
+            "account": account.to_string(),
+            "mint": mint.to_string(),
+            "tokenProgram": TOKEN_2022_PROGRAM_ID.to_string(),
+        });
+        let view = InstructionView::Parsed {
+            instruction_type: "createIdempotent",
+            info: info.as_object().unwrap(),
+        };
+
+        let found = AssociatedTokenParser.parse(&view, &ctx()).unwrap().unwrap();
+        assert_eq!(found.pubkey, account);
+        assert_eq!(found.account_type, AccountType::Other(TOKEN_2022_PROGRAM_ID));
+        assert_eq!(found.mint, Some(mint));
+    }
+
+    #[test]
+    fn test_custom_parser_requires_discriminator_for_raw() {
+        let account = Pubkey::new_unique();
+        let accounts = vec![Pubkey::new_unique().to_string(), account.to_string()];
+        let mut config = CustomProgramConfig {
+            program_id: Pubkey::new_unique().to_string(),
+            name: None,
+            instruction_types: vec![],
+            discriminator: vec![],
+            account_index: 1,
+            data_size: 64,
+        };
+
+        let view = InstructionView::Raw { accounts: &accounts, data: &[7, 1, 2] };
+        let parser = CustomProgramParser::from_config(&config).unwrap();
+        assert!(parser.parse(&view, &ctx()).unwrap().is_none());
+
+        config.discriminator = vec![7];
+        let parser = CustomProgramParser::from_config(&config).unwrap();
+        let found = parser.parse(&view, &ctx()).unwrap().unwrap();
+        assert_eq!(found.pubkey, account);
+        assert_eq!(found.data_size, 64);
+    }
+
+    #[test]
+    fn test_fingerprint_tracks_registered_programs() {
+        let builtin = ParserRegistry::default();
+        assert_eq!(builtin.fingerprint(), ParserRegistry::default().fingerprint());
+
+        let mut custom = ParserRegistry::default();
+        custom.register(Box::new(CustomProgramParser::from_config(&CustomProgramConfig {
+            program_id: Pubkey::new_unique().to_string(),
+            name: None,
+            instruction_types: vec!["create".to_string()],
+            discriminator: vec![],
+            account_index: 0,
+            data_size: 0,
+        }).unwrap()));
+        assert_ne!(builtin.fingerprint(), custom.fingerprint());
+
+        // Skipping noise programs never changes results; other programs might
+        let mut noise = ParserRegistry::default();
+        noise.skip(NOISE_PROGRAMS[0]);
+        assert_eq!(builtin.fingerprint(), noise.fingerprint());
+        noise.skip(Pubkey::new_unique());
+        assert_ne!(builtin.fingerprint(), noise.fingerprint());
+    }
+
+    #[test]
+    fn test_noise_transactions_are_not_parsed() {
+        let message = |program_ids: &[Pubkey]| -> UiParsedMessage {
+            let instructions: Vec<_> = program_ids
+                .iter()
+                .map(|id| json!({ "programId": id.to_string(), "accounts": [], "data": "" }))
+                .collect();
+            serde_json::from_value(json!({
+                "accountKeys": [],
+                "recentBlockhash": "",
+                "instructions": instructions,
+            }))
+            .unwrap()
+        };
+        let memo = NOISE_PROGRAMS[2];
+        let compute_budget = NOISE_PROGRAMS[0];
+        let registry = ParserRegistry::default();
+
+        assert!(registry.is_noise(&message(&[compute_budget, memo])));
+        assert!(!registry.is_noise(&message(&[compute_budget, spl_associated_token_account::id()])));
+        assert!(!registry.is_noise(&message(&[])));
+
+        let mut custom = ParserRegistry::default();
+        let program = Pubkey::new_unique();
+        assert!(!custom.is_noise(&message(&[program])));
+        custom.skip(program);
+        assert!(custom.is_noise(&message(&[program, memo])));
+    }
+}
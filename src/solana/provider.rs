@@ -0,0 +1,105 @@
+// src/solana/provider.rs - RPC provider presets for rate limiting
+
+use serde::Deserialize;
+use std::fmt;
+
+/// RPC provider behind `solana.rpc_url`, which sets the default rate limit
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RpcProvider {
+    /// Detect from the RPC URL's host
+    #[default]
+    Auto,
+    Helius,
+    Triton,
+    QuickNode,
+    /// Solana Foundation public endpoints (`api.*.solana.com`)
+    Public,
+    /// No preset; `rate_limit_delay_ms` applies
+    Custom,
+}
+
+impl RpcProvider {
+    /// Resolve `Auto` from the host of `rpc_url`
+    pub fn resolve(self, rpc_url: &str) -> Self {
+        if self != RpcProvider::Auto {
+            return self;
+        }
+
+        let host = host(rpc_url);
+        let on = |domain: &str| host == domain || host.ends_with(&format!(".{}", domain));
+
+        if on("helius-rpc.com") || on("helius.xyz") {
+            RpcProvider::Helius
+        } else if on("rpcpool.com") || on("triton.one") {
+            RpcProvider::Triton
+        } else if on("quiknode.pro") || on("quicknode.com") {
+            RpcProvider::QuickNode
+        } else if on("solana.com") {
+            RpcProvider::Public
+        } else {
+            RpcProvider::Custom
+        }
+    }
+
+    /// Requests per second and burst for the provider's entry-level plan
+    pub fn preset(self) -> Option<(f64, u32)> {
+        match self {
+            RpcProvider::Helius => Some((10.0, 10)),
+            RpcProvider::Triton => Some((50.0, 25)),
+            RpcProvider::QuickNode => Some((15.0, 5)),
+            // 100 requests per 10 seconds, 40 of them for any single method
+            RpcProvider::Public => Some((4.0, 8)),
+            RpcProvider::Auto | RpcProvider::Custom => None,
+        }
+    }
+}
+
+/// Host of an RPC URL, lowercased; drops the path and query, where
+/// providers put API keys
+pub fn host(rpc_url: &str) -> String {
+    rpc_url
+        .split("://")
+        .last()
+        .unwrap_or(rpc_url)
+        .split(['/', '?', ':'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+impl fmt::Display for RpcProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RpcProvider::Auto => write!(f, "auto"),
+            RpcProvider::Helius => write!(f, "Helius"),
+            RpcProvider::Triton => write!(f, "Triton"),
+            RpcProvider::QuickNode => write!(f, "QuickNode"),
+            RpcProvider::Public => write!(f, "public"),
+            RpcProvider::Custom => write!(f, "custom"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_detected_from_host() {
+        let detect = |url: &str| RpcProvider::Auto.resolve(url);
+
+        assert_eq!(detect("https://mainnet.helius-rpc.com/?api-key=abc"), RpcProvider::Helius);
+        assert_eq!(detect("https://example.rpcpool.com/token"), RpcProvider::Triton);
+        assert_eq!(detect("https://a-b-c.solana-mainnet.quiknode.pro/xyz/"), RpcProvider::QuickNode);
+        assert_eq!(detect("https://api.devnet.solana.com"), RpcProvider::Public);
+        assert_eq!(detect("http://127.0.0.1:8899"), RpcProvider::Custom);
+        // Only the host counts, not lookalike paths or suffixes
+        assert_eq!(detect("https://proxy.example.com/helius-rpc.com"), RpcProvider::Custom);
+        assert_eq!(detect("https://notsolana.com"), RpcProvider::Custom);
+        assert_eq!(host("https://Mainnet.helius-rpc.com/?api-key=abc"), "mainnet.helius-rpc.com");
+
+        assert_eq!(RpcProvider::Triton.resolve("https://api.devnet.solana.com"), RpcProvider::Triton);
+        assert_eq!(RpcProvider::Public.preset(), Some((4.0, 8)));
+    }
+}
@@ -0,0 +1,170 @@
+// src/solana/slot_time.rs - Slot to wall-clock estimation calibrated with getBlockTime
+
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use crate::solana::client::SolanaRpcClient;
+use tracing::{debug, warn};
+
+/// Nominal slot duration used when only one reference point is known
+const DEFAULT_SLOT_MS: i64 = 400;
+/// Reference slots are taken on this grid so nearby lookups share cache entries
+const REFERENCE_SPACING: u64 = 50_000;
+/// Skipped slots have no block time; probe this many following slots
+const MAX_PROBES: u64 = 8;
+
+/// Estimates block times for slots whose transactions lack `block_time`.
+///
+/// Queries `getBlockTime` for reference slots around the target (cached) and
+/// linearly interpolates between them, instead of assuming a fixed genesis
+/// time and slot duration.
+#[derive(Clone)]
+pub struct SlotTimeEstimator {
+    rpc_client: SolanaRpcClient,
+    points: Arc<Mutex<BTreeMap<u64, i64>>>,
+}
+
+impl SlotTimeEstimator {
+    pub fn new(rpc_client: SolanaRpcClient) -> Self {
+        Self {
+            rpc_client,
+            points: Arc::new(Mutex::new(BTreeMap::new())),
+        }
+    }
+
+    /// Estimate the wall-clock time a slot was produced
+    pub async fn estimate(&self, slot: u64) -> DateTime<Utc> {
+        self.calibrate_around(slot).await;
+
+        let points = self.points.lock().unwrap().clone();
+        let timestamp = match interpolate(&points, slot) {
+            Some(ts) => ts,
+            None => {
+                warn!("No reference block times available, using legacy estimate for slot {}", slot);
+                legacy_estimate(slot)
+            }
+        };
+
+        DateTime::from_timestamp(timestamp, 0)
+            .unwrap_or_else(|| DateTime::from_timestamp(0, 0).unwrap())
+    }
+
+    /// Make sure reference points bracket `slot` (anchor at the tip plus
+    /// grid points below and above the target)
+    async fn calibrate_around(&self, slot: u64) {
+        let has_tip = self.points.lock().unwrap().keys().next_back().is_some_and(|&s| s >= slot);
+        if !has_tip {
+            match self.rpc_client.get_slot().await {
+                Ok(tip) => {
+                    self.record_near(tip.saturating_sub(MAX_PROBES), tip).await;
+                }
+                Err(e) => warn!("Failed to fetch current slot for calibration: {}", e),
+            }
+        }
+
+        let lower = slot / REFERENCE_SPACING * REFERENCE_SPACING;
+        let upper = lower + REFERENCE_SPACING;
+        let tip = self.points.lock().unwrap().keys().next_back().copied().unwrap_or(0);
+
+        self.record_near(lower, tip).await;
+        if upper < tip {
+            self.record_near(upper, tip).await;
+        }
+    }
+
+    /// Record the block time of the first non-skipped slot at or after `slot`
+    async fn record_near(&self, slot: u64, limit: u64) {
+        let already_known = {
+            let points = self.points.lock().unwrap();
+            points.range(slot..=slot + MAX_PROBES).next().is_some()
+        };
+        if already_known {
+            return;
+        }
+
+        for candidate in slot..slot + MAX_PROBES {
+            if limit > 0 && candidate > limit {
+                break;
+            }
+            match self.rpc_client.get_block_time(candidate).await {
+                Ok(Some(timestamp)) => {
+                    debug!("Calibrated slot {} -> {}", candidate, timestamp);
+                    self.points.lock().unwrap().insert(candidate, timestamp);
+                    return;
+                }
+                Ok(None) => continue,
+                Err(e) => {
+                    debug!("getBlockTime failed for slot {}: {}", candidate, e);
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Linear interpolation between the reference points surrounding `slot`,
+/// extrapolating from the nearest pair (or the nominal slot time) outside them
+pub fn interpolate(points: &BTreeMap<u64, i64>, slot: u64) -> Option<i64> {
+    if let Some(&ts) = points.get(&slot) {
+        return Some(ts);
+    }
+
+    let below = points.range(..slot).next_back();
+    let above = points.range(slot..).next();
+
+    let ((s0, t0), (s1, t1)) = match (below, above) {
+        (Some(b), Some(a)) => (b, a),
+        (Some(b), None) => match points.range(..*b.0).next_back() {
+            Some(prev) => (prev, b),
+            None => return Some(b.1 + (slot - b.0) as i64 * DEFAULT_SLOT_MS / 1000),
+        },
+        (None, Some(a)) => match points.range(*a.0 + 1..).next() {
+            Some(next) => (a, next),
+            None => return Some(a.1 - (a.0 - slot) as i64 * DEFAULT_SLOT_MS / 1000),
+        },
+        (None, None) => return None,
+    };
+
+    let slot_span = (*s1 as i64 - *s0 as i64) as f64;
+    let time_span = (t1 - t0) as f64;
+    let offset = slot as i64 - *s0 as i64;
+
+    Some(*t0 + (offset as f64 * time_span / slot_span).round() as i64)
+}
+
+/// Previous fixed estimate: slot 0 around Sept 2020 and 400ms per slot
+pub fn legacy_estimate(slot: u64) -> i64 {
+    1_600_000_000 + (slot as i64 * DEFAULT_SLOT_MS) / 1000
+}
+
+/// Rough wall-clock span of `slots` at the nominal slot time, e.g. how far
+/// a checkpoint trails the network
+pub fn nominal_duration(slots: u64) -> chrono::Duration {
+    chrono::Duration::milliseconds(slots as i64 * DEFAULT_SLOT_MS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interpolate_between_points() {
+        let points = BTreeMap::from([(1_000, 10_000), (2_000, 10_500)]);
+        assert_eq!(interpolate(&points, 1_500), Some(10_250));
+        assert_eq!(interpolate(&points, 1_000), Some(10_000));
+    }
+
+    #[test]
+    fn test_interpolate_extrapolates_from_nearest_pair() {
+        let points = BTreeMap::from([(1_000, 10_000), (2_000, 10_500)]);
+        assert_eq!(interpolate(&points, 3_000), Some(11_000));
+        assert_eq!(interpolate(&points, 0), Some(9_500));
+    }
+
+    #[test]
+    fn test_interpolate_single_point_uses_nominal_slot_time() {
+        let points = BTreeMap::from([(1_000, 10_000)]);
+        assert_eq!(interpolate(&points, 1_010), Some(10_004));
+        assert_eq!(interpolate(&BTreeMap::new(), 1_000), None);
+    }
+}
@@ -0,0 +1,115 @@
+// src/solana/stream.rs - Live operator transactions over the RPC websocket
+
+use futures::StreamExt;
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::rpc_config::{RpcTransactionLogsConfig, RpcTransactionLogsFilter};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature};
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{debug, info, warn};
+
+const RECONNECT_BASE: Duration = Duration::from_secs(1);
+const RECONNECT_MAX: Duration = Duration::from_secs(60);
+
+/// `logsSubscribe` feed of successful transactions mentioning a pubkey.
+///
+/// The subscription is re-established with exponential backoff whenever the
+/// websocket drops, so consumers only ever see a stream of signatures.
+pub struct SignatureStream {
+    ws_url: String,
+    address: Pubkey,
+    commitment: CommitmentConfig,
+}
+
+impl SignatureStream {
+    pub fn new(ws_url: &str, address: Pubkey, commitment: CommitmentConfig) -> Self {
+        Self {
+            ws_url: ws_url.to_string(),
+            address,
+            commitment,
+        }
+    }
+
+    /// Start the subscription in the background; it stops once the receiver is dropped
+    pub fn spawn(self) -> mpsc::UnboundedReceiver<Signature> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(async move { self.run(sender).await });
+        receiver
+    }
+
+    async fn run(self, sender: mpsc::UnboundedSender<Signature>) {
+        let mut failures = 0u32;
+
+        while !sender.is_closed() {
+            match self.subscribe_once(&sender).await {
+                Ok(()) => {
+                    warn!("Websocket subscription for {} ended, reconnecting", self.address);
+                    failures = 0;
+                }
+                Err(e) => {
+                    warn!("Websocket subscription to {} failed: {}", self.ws_url, e);
+                    failures = failures.saturating_add(1);
+                }
+            }
+
+            tokio::time::sleep(reconnect_delay(failures)).await;
+        }
+
+        debug!("Signature stream for {} stopped", self.address);
+    }
+
+    /// Forward signatures until the websocket closes
+    async fn subscribe_once(
+        &self,
+        sender: &mpsc::UnboundedSender<Signature>,
+    ) -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let client = PubsubClient::new(&self.ws_url).await?;
+        let (mut notifications, unsubscribe) = client
+            .logs_subscribe(
+                RpcTransactionLogsFilter::Mentions(vec![self.address.to_string()]),
+                RpcTransactionLogsConfig { commitment: Some(self.commitment) },
+            )
+            .await?;
+
+        info!("Subscribed to transactions mentioning {} via {}", self.address, self.ws_url);
+
+        while let Some(notification) = notifications.next().await {
+            let logs = notification.value;
+            if logs.err.is_some() {
+                continue;
+            }
+
+            match Signature::from_str(&logs.signature) {
+                Ok(signature) => {
+                    if sender.send(signature).is_err() {
+                        break;
+                    }
+                }
+                Err(e) => debug!("Ignoring malformed signature {}: {}", logs.signature, e),
+            }
+        }
+
+        unsubscribe().await;
+        Ok(())
+    }
+}
+
+/// Wait before reconnecting after `failures` consecutive failed attempts
+pub(crate) fn reconnect_delay(failures: u32) -> Duration {
+    RECONNECT_BASE
+        .saturating_mul(2u32.saturating_pow(failures.min(16)))
+        .min(RECONNECT_MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reconnect_delay_grows_to_cap() {
+        assert_eq!(reconnect_delay(0), RECONNECT_BASE);
+        assert_eq!(reconnect_delay(3), Duration::from_secs(8));
+        assert_eq!(reconnect_delay(30), RECONNECT_MAX);
+    }
+}
@@ -0,0 +1,189 @@
+// src/solana/test_rpc.rs - Canned JSON-RPC node for tests
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+use solana_sdk::{account::Account, commitment_config::CommitmentConfig, pubkey::Pubkey};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+
+use super::SolanaRpcClient;
+
+type Handler = dyn Fn(&str, &Value) -> Result<Value, String> + Send + Sync;
+
+/// Local HTTP endpoint answering each JSON-RPC request with `handler(method, params)`;
+/// an `Err` is returned to the client as a JSON-RPC error.
+///
+/// The blocking client underneath [`SolanaRpcClient`] needs a multi-threaded runtime.
+pub struct TestRpc {
+    url: String,
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl TestRpc {
+    pub fn start(handler: impl Fn(&str, &Value) -> Result<Value, String> + Send + Sync + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handler: Arc<Handler> = Arc::new(handler);
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        let served = calls.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let (handler, calls) = (handler.clone(), served.clone());
+                std::thread::spawn(move || serve(stream, &*handler, &calls));
+            }
+        });
+
+        Self { url, calls }
+    }
+
+    pub fn client(&self) -> SolanaRpcClient {
+        SolanaRpcClient::new(&self.url, CommitmentConfig::confirmed(), 0)
+    }
+
+    /// How many times `method` was requested
+    pub fn calls(&self, method: &str) -> usize {
+        self.calls.lock().unwrap().iter().filter(|called| *called == method).count()
+    }
+}
+
+/// Answer requests on one keep-alive connection until the client hangs up
+fn serve(stream: TcpStream, handler: &Handler, calls: &Mutex<Vec<String>>) {
+    let mut writer = stream.try_clone().unwrap();
+    let mut reader = BufReader::new(stream);
+
+    loop {
+        let mut line = String::new();
+        let mut content_length = 0;
+        // Request line, then headers up to the blank line
+        loop {
+            line.clear();
+            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                return;
+            }
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        if reader.read_exact(&mut body).is_err() {
+            return;
+        }
+        let request: Value = serde_json::from_slice(&body).unwrap_or(Value::Null);
+        let method = request["method"].as_str().unwrap_or_default();
+        calls.lock().unwrap().push(method.to_string());
+
+        // The client asks for the node version before version-dependent calls
+        let result = match method {
+            "getVersion" => Ok(json!({ "solana-core": "1.18.26", "feature-set": 0 })),
+            _ => handler(method, &request["params"]),
+        };
+        let response = match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+            Err(message) => json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "error": { "code": -32602, "message": message },
+            }),
+        };
+        let body = response.to_string();
+        let written = write!(
+            writer,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        if written.is_err() {
+            return;
+        }
+    }
+}
+
+/// `value` wrapped in the response context account methods return
+pub fn with_context(value: Value) -> Value {
+    json!({ "context": { "slot": 1 }, "value": value })
+}
+
+/// An account as `getAccountInfo` and `getMultipleAccounts` encode it, `null` if missing
+pub fn account_json(account: Option<&Account>) -> Value {
+    let Some(account) = account else {
+        return Value::Null;
+    };
+    json!({
+        "lamports": account.lamports,
+        "data": [STANDARD.encode(&account.data), "base64"],
+        "owner": account.owner.to_string(),
+        "executable": account.executable,
+        "rentEpoch": account.rent_epoch,
+        "space": account.data.len(),
+    })
+}
+
+/// `getMultipleAccounts` result for the requested keys, looked up in `accounts`
+pub fn multiple_accounts(params: &Value, accounts: &[(Pubkey, Account)]) -> Value {
+    let requested = params[0].as_array().cloned().unwrap_or_default();
+    let values = requested
+        .iter()
+        .map(|key| {
+            let found = accounts.iter().find(|(pubkey, _)| Some(pubkey.to_string().as_str()) == key.as_str());
+            account_json(found.map(|(_, account)| account))
+        })
+        .collect();
+    with_context(Value::Array(values))
+}
+
+/// `getSignaturesForAddress` entry for `signature`
+pub fn signature_status(signature: &str, block_time: i64) -> Value {
+    json!({
+        "signature": signature,
+        "slot": 1,
+        "err": null,
+        "memo": null,
+        "blockTime": block_time,
+        "confirmationStatus": "finalized",
+    })
+}
+
+/// Successful jsonParsed `getTransaction` result; `keys` are `(pubkey, signer, pre, post)`
+/// and the first key pays `fee`
+pub fn parsed_transaction(signature: &str, keys: &[(Pubkey, bool, u64, u64)], fee: u64) -> Value {
+    let account_keys: Vec<Value> = keys
+        .iter()
+        .map(|(pubkey, signer, _, _)| {
+            json!({ "pubkey": pubkey.to_string(), "signer": signer, "writable": true, "source": "transaction" })
+        })
+        .collect();
+    json!({
+        "slot": 1,
+        "blockTime": 1_700_000_000,
+        "transaction": {
+            "signatures": [signature],
+            "message": {
+                "accountKeys": account_keys,
+                "instructions": [],
+                "recentBlockhash": Pubkey::default().to_string(),
+            },
+        },
+        "meta": {
+            "err": null,
+            "status": { "Ok": null },
+            "fee": fee,
+            "preBalances": keys.iter().map(|key| key.2).collect::<Vec<_>>(),
+            "postBalances": keys.iter().map(|key| key.3).collect::<Vec<_>>(),
+            "innerInstructions": [],
+            "logMessages": [],
+            "preTokenBalances": [],
+            "postTokenBalances": [],
+            "rewards": [],
+        },
+        "version": "legacy",
+    })
+}
@@ -0,0 +1,301 @@
+// src/storage/analytics.rs - Aggregate queries over stored accounts
+
+use crate::storage::models::{AccountStatus, OutreachRecord, SponsoredAccount};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// Lifetime statistics for one group of closed or reclaimed accounts
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct LifetimeGroup {
+    pub label: String,
+    pub count: usize,
+    pub avg_days: f64,
+    pub median_days: f64,
+    pub min_days: f64,
+    pub max_days: f64,
+}
+
+impl LifetimeGroup {
+    /// `None` for an empty group
+    fn from_days(label: &str, mut days: Vec<f64>) -> Option<Self> {
+        if days.is_empty() {
+            return None;
+        }
+        days.sort_by(|a, b| a.total_cmp(b));
+
+        let count = days.len();
+        let median_days = if count.is_multiple_of(2) {
+            (days[count / 2 - 1] + days[count / 2]) / 2.0
+        } else {
+            days[count / 2]
+        };
+
+        Some(Self {
+            label: label.to_string(),
+            count,
+            avg_days: days.iter().sum::<f64>() / count as f64,
+            median_days,
+            min_days: days[0],
+            max_days: days[count - 1],
+        })
+    }
+}
+
+/// How long sponsored accounts lived, from creation to close or reclaim
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LifetimeReport {
+    pub overall: Option<LifetimeGroup>,
+    pub by_type: Vec<LifetimeGroup>,
+    pub by_strategy: Vec<LifetimeGroup>,
+    /// Every measured lifetime in days, for threshold comparisons
+    #[serde(skip)]
+    lifetimes: Vec<f64>,
+}
+
+impl LifetimeReport {
+    /// Build from accounts; those without a close time are ignored
+    pub fn from_accounts(accounts: &[SponsoredAccount]) -> Self {
+        let mut by_type: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+        let mut by_strategy: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+        let mut lifetimes = Vec::new();
+
+        for account in accounts {
+            let Some(closed_at) = account.closed_at else {
+                continue;
+            };
+            let days = (closed_at - account.created_at).num_seconds().max(0) as f64 / 86_400.0;
+
+            let account_type = account.account_type
+                .as_ref()
+                .map(|t| t.to_string())
+                .unwrap_or_else(|| "Unknown".to_string());
+            let strategy = account.reclaim_strategy
+                .as_ref()
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "Unknown".to_string());
+
+            by_type.entry(account_type).or_default().push(days);
+            by_strategy.entry(strategy).or_default().push(days);
+            lifetimes.push(days);
+        }
+
+        let groups = |map: BTreeMap<String, Vec<f64>>| -> Vec<LifetimeGroup> {
+            map.into_iter()
+                .filter_map(|(label, days)| LifetimeGroup::from_days(&label, days))
+                .collect()
+        };
+
+        Self {
+            overall: LifetimeGroup::from_days("All", lifetimes.clone()),
+            by_type: groups(by_type),
+            by_strategy: groups(by_strategy),
+            lifetimes,
+        }
+    }
+
+    /// Fraction of measured accounts that closed within `days` of creation
+    pub fn share_closed_within(&self, days: u64) -> Option<f64> {
+        if self.lifetimes.is_empty() {
+            return None;
+        }
+        let within = self.lifetimes.iter().filter(|&&d| d <= days as f64).count();
+        Some(within as f64 / self.lifetimes.len() as f64)
+    }
+}
+
+/// Outreach results for one group of contacted accounts
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct OutreachGroup {
+    pub label: String,
+    pub contacted: usize,
+    /// Accounts their users closed after being contacted
+    pub converted: usize,
+    pub conversion_rate: f64,
+    pub recovered_lamports: u64,
+    /// From the credited contact to the close
+    pub median_days_to_close: Option<f64>,
+}
+
+impl OutreachGroup {
+    fn from_outcomes(label: &str, outcomes: &[&Outcome]) -> Self {
+        let mut days: Vec<f64> = outcomes.iter().filter_map(|o| o.days_to_close).collect();
+        days.sort_by(|a, b| a.total_cmp(b));
+        let converted = days.len();
+
+        let median_days_to_close = match converted {
+            0 => None,
+            n if n.is_multiple_of(2) => Some((days[n / 2 - 1] + days[n / 2]) / 2.0),
+            n => Some(days[n / 2]),
+        };
+
+        Self {
+            label: label.to_string(),
+            contacted: outcomes.len(),
+            converted,
+            conversion_rate: if outcomes.is_empty() { 0.0 } else { converted as f64 / outcomes.len() as f64 },
+            recovered_lamports: outcomes.iter().filter(|o| o.days_to_close.is_some()).map(|o| o.lamports).sum(),
+            median_days_to_close,
+        }
+    }
+}
+
+/// One contacted account, credited to a single contact
+struct Outcome {
+    channel: String,
+    response: String,
+    lamports: u64,
+    days_to_close: Option<f64>,
+}
+
+/// Conversion from outreach to passive reclaim (the user closing the account)
+#[derive(Debug, Clone, Serialize)]
+pub struct OutreachReport {
+    pub overall: OutreachGroup,
+    pub by_channel: Vec<OutreachGroup>,
+    pub by_response: Vec<OutreachGroup>,
+}
+
+impl OutreachReport {
+    /// Each contacted account is credited to its last contact before it was
+    /// closed, or its last contact overall while it is still open. Closes
+    /// that happened before the first contact do not count as conversions.
+    pub fn from_records(records: &[OutreachRecord], accounts: &[SponsoredAccount]) -> Self {
+        let accounts: HashMap<&str, &SponsoredAccount> =
+            accounts.iter().map(|a| (a.pubkey.as_str(), a)).collect();
+
+        let mut contacts: BTreeMap<&str, Vec<&OutreachRecord>> = BTreeMap::new();
+        for record in records {
+            contacts.entry(record.account_pubkey.as_str()).or_default().push(record);
+        }
+
+        let outcomes: Vec<Outcome> = contacts
+            .into_iter()
+            .map(|(pubkey, mut contacts)| {
+                contacts.sort_by_key(|r| r.contacted_at);
+                let account = accounts.get(pubkey);
+                let closed_at = account
+                    .filter(|a| a.status == AccountStatus::Closed)
+                    .and_then(|a| a.closed_at);
+
+                let credited = match closed_at {
+                    Some(closed_at) => contacts.iter().rev().find(|r| r.contacted_at <= closed_at),
+                    None => contacts.last(),
+                };
+                let (credited, days_to_close) = match (credited, closed_at) {
+                    (Some(contact), Some(closed_at)) => {
+                        let days = (closed_at - contact.contacted_at).num_seconds() as f64 / 86_400.0;
+                        (*contact, Some(days))
+                    }
+                    // Closed before anyone reached out, or still open
+                    (_, _) => (*contacts.last().unwrap(), None),
+                };
+
+                Outcome {
+                    channel: credited.channel.clone(),
+                    response: credited.response.to_string(),
+                    lamports: account.map_or(0, |a| a.rent_lamports),
+                    days_to_close,
+                }
+            })
+            .collect();
+
+        let groups = |key: fn(&Outcome) -> &str| -> Vec<OutreachGroup> {
+            let mut grouped: BTreeMap<&str, Vec<&Outcome>> = BTreeMap::new();
+            for outcome in &outcomes {
+                grouped.entry(key(outcome)).or_default().push(outcome);
+            }
+            grouped
+                .into_iter()
+                .map(|(label, outcomes)| OutreachGroup::from_outcomes(label, &outcomes))
+                .collect()
+        };
+
+        Self {
+            overall: OutreachGroup::from_outcomes("All", &outcomes.iter().collect::<Vec<_>>()),
+            by_channel: groups(|o| &o.channel),
+            by_response: groups(|o| &o.response),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::models::ReclaimStrategy;
+    use chrono::{Duration, Utc};
+    use solana_sdk::pubkey::Pubkey;
+
+    fn closed_after(days: i64, strategy: ReclaimStrategy) -> SponsoredAccount {
+        let mut account = SponsoredAccount::new(Pubkey::new_unique(), 2_039_280, 165);
+        account.status = AccountStatus::Closed;
+        account.closed_at = Some(account.created_at + Duration::days(days));
+        account.reclaim_strategy = Some(strategy);
+        account
+    }
+
+    #[test]
+    fn test_lifetime_groups_and_threshold_share() {
+        let mut still_open = SponsoredAccount::new(Pubkey::new_unique(), 890_880, 0);
+        still_open.created_at = Utc::now() - Duration::days(400);
+
+        let report = LifetimeReport::from_accounts(&[
+            closed_after(10, ReclaimStrategy::ActiveReclaim),
+            closed_after(30, ReclaimStrategy::ActiveReclaim),
+            closed_after(90, ReclaimStrategy::PassiveMonitoring),
+            still_open,
+        ]);
+
+        let overall = report.overall.as_ref().unwrap();
+        assert_eq!(overall.count, 3);
+        assert!((overall.avg_days - 130.0 / 3.0).abs() < 1e-9);
+        assert_eq!(overall.median_days, 30.0);
+
+        let active = report.by_strategy.iter().find(|g| g.label == "ActiveReclaim").unwrap();
+        assert_eq!((active.count, active.median_days), (2, 20.0));
+        assert_eq!(report.by_type[0].label, "Unknown");
+
+        assert_eq!(report.share_closed_within(30), Some(2.0 / 3.0));
+    }
+
+    #[test]
+    fn test_outreach_conversion_by_channel() {
+        use crate::storage::models::OutreachResponse;
+
+        let start = Utc::now() - Duration::days(60);
+        let contact = |account: &SponsoredAccount, days: i64, channel: &str, response: OutreachResponse| OutreachRecord {
+            id: 0,
+            account_pubkey: account.pubkey.clone(),
+            contacted_at: start + Duration::days(days),
+            channel: channel.to_string(),
+            response,
+            notes: None,
+        };
+
+        // Emailed, then messaged on Telegram, then closed: credited to Telegram
+        let mut converted = SponsoredAccount::new(Pubkey::new_unique(), 2_039_280, 165);
+        converted.status = AccountStatus::Closed;
+        converted.closed_at = Some(start + Duration::days(14));
+        // Closed before anyone reached out
+        let mut closed_early = SponsoredAccount::new(Pubkey::new_unique(), 2_039_280, 165);
+        closed_early.status = AccountStatus::Closed;
+        closed_early.closed_at = Some(start);
+        let open = SponsoredAccount::new(Pubkey::new_unique(), 2_039_280, 165);
+
+        let records = [
+            contact(&converted, 1, "email", OutreachResponse::NoResponse),
+            contact(&converted, 10, "telegram", OutreachResponse::Agreed),
+            contact(&closed_early, 5, "email", OutreachResponse::NoResponse),
+            contact(&open, 2, "email", OutreachResponse::Declined),
+        ];
+        let report = OutreachReport::from_records(&records, &[converted, closed_early, open]);
+
+        assert_eq!((report.overall.contacted, report.overall.converted), (3, 1));
+        assert_eq!(report.overall.recovered_lamports, 2_039_280);
+
+        let telegram = report.by_channel.iter().find(|g| g.label == "telegram").unwrap();
+        assert_eq!((telegram.converted, telegram.median_days_to_close), (1, Some(4.0)));
+        let email = report.by_channel.iter().find(|g| g.label == "email").unwrap();
+        assert_eq!((email.contacted, email.converted), (2, 0));
+        assert_eq!(report.by_response.iter().map(|g| g.label.as_str()).collect::<Vec<_>>(), ["Agreed", "Declined", "NoResponse"]);
+    }
+}
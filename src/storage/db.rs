@@ -1,19 +1,72 @@
-use rusqlite::{Connection, params};
+use rusqlite::{Connection, OptionalExtension, TransactionBehavior, params};
 use std::sync::{Arc, Mutex};
 use crate::{
     error::Result,
-    storage::models::{SponsoredAccount, ReclaimOperation, AccountStatus, PassiveReclaimRecord, ReclaimStrategy},
+    storage::models::{SponsoredAccount, ReclaimOperation, AccountStatus, PassiveReclaimRecord, ReclaimStrategy, ReviewStatus, Snapshot, DailyActivity, ReclaimReason, NotificationLevel, NotificationCategory, LeaseHolder, ReclaimFailure, OutreachRecord, OutreachResponse, Tenant, ScanSession, ScanFailureStreak, MintRent, DiscoveryEvidence, OperatorTotals, AccountEpoch, StagedReclaim},
+    storage::settings::RuntimeSettings,
+    storage::analytics::{LifetimeReport, OutreachReport},
+    storage::integrity::IntegrityReport,
+    storage::ledger::{self, LedgerReport},
+    solana::accounts::SponsoredAccountInfo as DiscoveredAccount,
 };
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
 use std::str::FromStr;
 
+/// Checkpoint key holding the UTC date of the last scheduled integrity check
+const INTEGRITY_CHECK_KEY: &str = "integrity_checked";
+
+/// Checkpoint key holding when Unrecoverable accounts were last audited
+const UNRECOVERABLE_AUDIT_KEY: &str = "unrecoverable_audited";
+
+/// Discovery runs kept in `scan_sessions`
+const SCAN_SESSIONS_KEPT: i64 = 500;
+
+/// `database.path` that keeps the database in memory until the process exits.
+/// Tenants of an in-memory configuration get `:memory:<id>`.
+pub const MEMORY_PATH: &str = ":memory:";
+
+/// Open in-memory databases by path, so that every `Database::new` of the
+/// same path shares one database within the process
+static MEMORY_DATABASES: Mutex<std::collections::BTreeMap<String, Database>> =
+    Mutex::new(std::collections::BTreeMap::new());
+
+/// Whether `path` names an in-memory database rather than a file
+pub fn is_memory_path(path: &str) -> bool {
+    path.starts_with(MEMORY_PATH)
+}
+
+/// Columns selected for every `SponsoredAccount` query, in `row_to_account` order
+const ACCOUNT_COLUMNS: &str = "pubkey, created_at, closed_at, rent_lamports, data_size, status, \
+    creation_signature, creation_slot, close_authority, reclaim_strategy, \
+    current_lamports, balance_checked_at, account_type, mint, operator, min_inactive_days";
+
 pub struct Database {
     conn: Arc<Mutex<Connection>>,
 }
 
 impl Database {
     pub fn new(path: &str) -> Result<Self> {
-        let conn = Connection::open(path)?;
+        if !is_memory_path(path) {
+            return Self::open(path);
+        }
+
+        let mut open = MEMORY_DATABASES.lock().unwrap();
+        if let Some(db) = open.get(path) {
+            return Ok(db.clone());
+        }
+        let db = Self::in_memory()?;
+        open.insert(path.to_string(), db.clone());
+        Ok(db)
+    }
+
+    /// A new private in-memory database, not shared with other handles
+    pub fn in_memory() -> Result<Self> {
+        Self::open(MEMORY_PATH)
+    }
+
+    fn open(path: &str) -> Result<Self> {
+        let mut conn = Connection::open(path)?;
+        super::profile::attach(&mut conn);
         let db = Self { 
             conn: Arc::new(Mutex::new(conn)) 
         };
@@ -34,7 +87,36 @@ impl Database {
                 creation_signature TEXT,
                 creation_slot INTEGER,
                 close_authority TEXT,
-                reclaim_strategy TEXT
+                reclaim_strategy TEXT,
+                current_lamports INTEGER,
+                balance_checked_at TEXT,
+                account_type TEXT
+            )",
+            [],
+        )?;
+
+        Self::add_column_if_missing(&conn, "sponsored_accounts", "current_lamports", "INTEGER")?;
+        Self::add_column_if_missing(&conn, "sponsored_accounts", "balance_checked_at", "TEXT")?;
+        Self::add_column_if_missing(&conn, "sponsored_accounts", "account_type", "TEXT")?;
+        Self::add_column_if_missing(&conn, "sponsored_accounts", "tenant_id", "TEXT")?;
+        Self::add_column_if_missing(&conn, "sponsored_accounts", "mint", "TEXT")?;
+        // Operator key that sponsored the account; NULL for rows from before key rotation
+        Self::add_column_if_missing(&conn, "sponsored_accounts", "operator", "TEXT")?;
+        // Per-account hold replacing reclaim.min_inactive_days
+        Self::add_column_if_missing(&conn, "sponsored_accounts", "min_inactive_days", "INTEGER")?;
+
+        // Earlier creations of addresses that were closed and created again
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS account_epochs (
+                pubkey TEXT NOT NULL,
+                creation_signature TEXT NOT NULL,
+                creation_slot INTEGER,
+                created_at TEXT NOT NULL,
+                closed_at TEXT,
+                rent_lamports INTEGER NOT NULL,
+                status TEXT NOT NULL,
+                archived_at TEXT NOT NULL,
+                PRIMARY KEY (pubkey, creation_signature)
             )",
             [],
         )?;
@@ -52,6 +134,133 @@ impl Database {
             [],
         )?;
         
+        // Structured reclaim source; older rows are mapped from their free-form reason
+        if Self::add_column_if_missing(&conn, "reclaim_operations", "reason_code", "TEXT")? {
+            let mut stmt = conn.prepare("SELECT DISTINCT reason FROM reclaim_operations")?;
+            let legacy = stmt.query_map([], |row| row.get::<_, String>(0))?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            for reason in legacy {
+                conn.execute(
+                    "UPDATE reclaim_operations SET reason_code = ?1 WHERE reason = ?2",
+                    params![ReclaimReason::from_legacy(&reason).to_string(), reason],
+                )?;
+            }
+        }
+        Self::add_column_if_missing(&conn, "reclaim_operations", "tenant_id", "TEXT")?;
+        // Treasury credit read back from the confirmed transaction; NULL if unverified
+        Self::add_column_if_missing(&conn, "reclaim_operations", "credited_amount", "INTEGER")?;
+        Self::add_column_if_missing(&conn, "reclaim_operations", "destination", "TEXT")?;
+        // Transaction fee read back with the credit; NULL if unverified
+        Self::add_column_if_missing(&conn, "reclaim_operations", "fee_lamports", "INTEGER")?;
+        // Hash chain for tamper evidence; rows from before it existed are chained as found
+        if Self::add_column_if_missing(&conn, "reclaim_operations", "entry_hash", "TEXT")? {
+            Self::rechain_ledger(&conn)?;
+        }
+
+        // Tenant that owns this database in multi-tenant mode; at most one row
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS tenants (
+                id TEXT PRIMARY KEY,
+                name TEXT,
+                operator_pubkey TEXT NOT NULL,
+                treasury_wallet TEXT NOT NULL,
+                registered_at TEXT NOT NULL
+            )",
+            [],
+        )?;
+        
+        // Operator overrides edited at runtime (Telegram /settings)
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS runtime_settings (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )?;
+        
+        // TUI alerts dismissed by the operator, and the severity they had
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS alert_acknowledgements (
+                kind TEXT PRIMARY KEY,
+                severity TEXT NOT NULL,
+                acknowledged_at TEXT NOT NULL
+            )",
+            [],
+        )?;
+        
+        // Per-user Telegram notification choices (/subscribe, /unsubscribe)
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS notification_preferences (
+                user_id INTEGER PRIMARY KEY,
+                level TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )",
+            [],
+        )?;
+        
+        // Notifications held back during quiet hours, delivered as a digest
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS notification_queue (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                category TEXT NOT NULL,
+                message TEXT NOT NULL,
+                queued_at TEXT NOT NULL
+            )",
+            [],
+        )?;
+        
+        // Deterministic reclaim failures; dead-lettered accounts are no longer retried
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS reclaim_failures (
+                pubkey TEXT PRIMARY KEY,
+                failures INTEGER NOT NULL,
+                last_error TEXT NOT NULL,
+                last_failed_at TEXT NOT NULL,
+                dead_lettered_at TEXT
+            )",
+            [],
+        )?;
+        
+        // High-value reclaims waiting for their delay or an approval
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS staged_reclaims (
+                pubkey TEXT PRIMARY KEY,
+                amount INTEGER NOT NULL,
+                staged_at TEXT NOT NULL,
+                execute_after TEXT NOT NULL,
+                units_consumed INTEGER,
+                simulation_error TEXT,
+                approved_at TEXT,
+                approved_by TEXT
+            )",
+            [],
+        )?;
+        
+        // Contacts with users about closing their accounts (`outreach` command)
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS outreach (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                account_pubkey TEXT NOT NULL,
+                contacted_at TEXT NOT NULL,
+                channel TEXT NOT NULL,
+                response TEXT NOT NULL,
+                notes TEXT
+            )",
+            [],
+        )?;
+        
+        // Single-writer leases so two services never reclaim from one database
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS service_leases (
+                name TEXT PRIMARY KEY,
+                holder TEXT NOT NULL,
+                acquired_at TEXT NOT NULL,
+                heartbeat_at TEXT NOT NULL
+            )",
+            [],
+        )?;
+        
         // Checkpoints table for tracking scan progress
         conn.execute(
             "CREATE TABLE IF NOT EXISTS checkpoints (
@@ -62,16 +271,98 @@ impl Database {
             [],
         )?;
 
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS scan_sessions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                started_at TEXT NOT NULL,
+                finished_at TEXT NOT NULL,
+                incremental INTEGER NOT NULL,
+                accounts_found INTEGER NOT NULL,
+                error TEXT
+            )",
+            [],
+        )?;
+
         conn.execute(
             "CREATE TABLE IF NOT EXISTS passive_reclaims (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
                 amount INTEGER NOT NULL,
                 attributed_accounts TEXT NOT NULL,
                 confidence TEXT NOT NULL,
-                timestamp TEXT NOT NULL
+                timestamp TEXT NOT NULL,
+                review_status TEXT NOT NULL DEFAULT 'Confirmed'
+            )",
+            [],
+        )?;
+
+        // Parsed creations per transaction, so rescans after a checkpoint
+        // reset don't refetch every getTransaction
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS transaction_cache (
+                signature TEXT PRIMARY KEY,
+                parsers TEXT NOT NULL,
+                creations TEXT NOT NULL,
+                cached_at TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        // The instructions behind each attribution, kept when the cache is
+        // cleared so a classification can be re-examined or replayed
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS discovery_evidence (
+                account_pubkey TEXT PRIMARY KEY,
+                signature TEXT NOT NULL,
+                slot INTEGER NOT NULL,
+                creation_time TEXT NOT NULL,
+                instructions TEXT NOT NULL,
+                recorded_at TEXT NOT NULL
+            )",
+            [],
+        )?;
+
+        // Closes already found not to be the operator's, so treasury checks
+        // don't fetch their closing transaction again. Keyed by creation, as
+        // a re-created address closes anew.
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS close_checks (
+                pubkey TEXT NOT NULL,
+                creation_signature TEXT NOT NULL,
+                close_signature TEXT NOT NULL,
+                checked_at TEXT NOT NULL,
+                PRIMARY KEY (pubkey, creation_signature)
+            )",
+            [],
+        )?;
+
+        // One row of totals per day for trend charts and report deltas
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS snapshots (
+                date TEXT PRIMARY KEY,
+                taken_at TEXT NOT NULL,
+                active_accounts INTEGER NOT NULL,
+                locked_lamports INTEGER NOT NULL,
+                locked_by_strategy TEXT NOT NULL,
+                reclaimed_lamports INTEGER NOT NULL,
+                passive_reclaimed_lamports INTEGER NOT NULL
             )",
             [],
         )?;
+
+        // Older databases predate the review workflow: add the column and
+        // send existing low-confidence attributions back for review
+        if Self::add_column_if_missing(
+            &conn,
+            "passive_reclaims",
+            "review_status",
+            "TEXT NOT NULL DEFAULT 'Confirmed'",
+        )? {
+            conn.execute(
+                "UPDATE passive_reclaims SET review_status = 'Pending'
+                 WHERE confidence IN ('Low', 'Unknown')",
+                [],
+            )?;
+        }
         
         conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_status ON sponsored_accounts(status)",
@@ -83,6 +374,12 @@ impl Database {
              ON sponsored_accounts(reclaim_strategy)",
             [],
         )?;
+
+        // Passive attribution looks up Active accounts by their rent
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_status_rent ON sponsored_accounts(status, rent_lamports)",
+            [],
+        )?;
         
         // Index on creation_signature for faster lookups
         conn.execute(
@@ -90,68 +387,304 @@ impl Database {
             [],
         )?;
         
+        // One operation per reclaim transaction; existing duplicates keep the
+        // index off until `integrity --repair` removes them
+        if let Err(e) = Self::create_operation_signature_index(&conn) {
+            tracing::warn!(
+                "Duplicate reclaim operations found ({}); run `kora-reclaim integrity --repair` to remove them",
+                e
+            );
+        }
+        
+        Ok(())
+    }
+
+    fn create_operation_signature_index(conn: &Connection) -> rusqlite::Result<usize> {
+        conn.execute(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_operation_signature ON reclaim_operations(tx_signature)",
+            [],
+        )
+    }
+
+    /// Recompute every operation's chain hash in insertion order
+    fn rechain_ledger(conn: &Connection) -> Result<()> {
+        let mut previous = ledger::genesis_hash();
+        for (operation, _) in Self::ledger_entries(conn)? {
+            previous = ledger::entry_hash(&previous, &operation);
+            conn.execute(
+                "UPDATE reclaim_operations SET entry_hash = ?1 WHERE id = ?2",
+                params![previous, operation.id],
+            )?;
+        }
         Ok(())
     }
+
+    /// Add a column to an existing table if it is not there yet.
+    /// Returns true when the column was added.
+    fn add_column_if_missing(
+        conn: &Connection,
+        table: &str,
+        column: &str,
+        definition: &str,
+    ) -> Result<bool> {
+        let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+        let exists = stmt
+            .query_map([], |row| row.get::<_, String>(1))?
+            .filter_map(|name| name.ok())
+            .any(|name| name == column);
+
+        if exists {
+            return Ok(false);
+        }
+
+        conn.execute(
+            &format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition),
+            [],
+        )?;
+        Ok(true)
+    }
     
+    /// Cheap query proving the database is reachable and not locked up
+    pub fn ping(&self) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.query_row("SELECT 1", [], |row| row.get::<_, i64>(0))?;
+        Ok(())
+    }
+
+    /// Register `tenant` as the owner of this database, refreshing its
+    /// name and wallets. Fails if another tenant already owns it. Rows
+    /// written before the database had an owner are assigned to `tenant`.
+    pub fn claim_tenant(&self, tenant: &Tenant) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        let owner: Option<String> = conn
+            .query_row("SELECT id FROM tenants", [], |row| row.get(0))
+            .optional()?;
+
+        if let Some(owner) = owner.filter(|owner| *owner != tenant.id) {
+            return Err(crate::error::ReclaimError::ConfigError(format!(
+                "Database belongs to tenant '{}', not '{}'",
+                owner, tenant.id
+            )));
+        }
+
+        conn.execute(
+            "INSERT INTO tenants (id, name, operator_pubkey, treasury_wallet, registered_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(id) DO UPDATE SET
+                name = excluded.name,
+                operator_pubkey = excluded.operator_pubkey,
+                treasury_wallet = excluded.treasury_wallet",
+            params![
+                tenant.id,
+                tenant.name,
+                tenant.operator_pubkey,
+                tenant.treasury_wallet,
+                tenant.registered_at.to_rfc3339(),
+            ],
+        )?;
+        for table in ["sponsored_accounts", "reclaim_operations"] {
+            conn.execute(
+                &format!("UPDATE {} SET tenant_id = ?1 WHERE tenant_id IS NULL", table),
+                params![tenant.id],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Tenant that owns this database, if any
+    pub fn get_tenant(&self) -> Result<Option<Tenant>> {
+        let conn = self.conn.lock().unwrap();
+        let tenant = conn
+            .query_row(
+                "SELECT id, name, operator_pubkey, treasury_wallet, registered_at FROM tenants",
+                [],
+                |row| {
+                    Ok(Tenant {
+                        id: row.get(0)?,
+                        name: row.get(1)?,
+                        operator_pubkey: row.get(2)?,
+                        treasury_wallet: row.get(3)?,
+                        registered_at: row.get::<_, String>(4)?.parse().unwrap_or_else(|_| Utc::now()),
+                    })
+                },
+            )
+            .optional()?;
+        Ok(tenant)
+    }
+
     pub fn save_account(&self, account: &SponsoredAccount) -> Result<()> {
         let conn = self.conn.lock().unwrap();
+        Self::upsert_account(&conn, account)?;
+        Ok(())
+    }
+
+    /// Insert or update a tracked account. A missing `current_lamports`
+    /// keeps the last refreshed balance instead of clearing it.
+    ///
+    /// A creation signature other than the stored one means the address was
+    /// closed and created again. A later creation moves the stored one to
+    /// `account_epochs` and starts the row afresh; an earlier one found by a
+    /// rescan goes to `account_epochs` directly.
+    fn upsert_account(conn: &Connection, account: &SponsoredAccount) -> Result<()> {
+        let stored: Option<(Option<String>, Option<i64>, String)> = conn
+            .query_row(
+                "SELECT creation_signature, creation_slot, status FROM sponsored_accounts WHERE pubkey = ?1",
+                params![account.pubkey],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()?;
+
+        let mut new_life = false;
+        if let (Some((Some(stored_signature), stored_slot, stored_status)), Some(signature)) =
+            (&stored, &account.creation_signature)
+        {
+            if stored_signature != signature {
+                new_life = true;
+                let now = Utc::now().to_rfc3339();
+                let earlier = matches!(
+                    (account.creation_slot, stored_slot),
+                    (Some(slot), Some(stored)) if (slot as i64) < *stored
+                );
+                if earlier {
+                    conn.execute(
+                        "INSERT OR IGNORE INTO account_epochs
+                         (pubkey, creation_signature, creation_slot, created_at, closed_at, rent_lamports, status, archived_at)
+                         VALUES (?1, ?2, ?3, ?4, NULL, ?5, 'Closed', ?6)",
+                        params![
+                            account.pubkey,
+                            signature,
+                            account.creation_slot.map(|s| s as i64),
+                            account.created_at.to_rfc3339(),
+                            account.rent_lamports,
+                            now,
+                        ],
+                    )?;
+                    return Ok(());
+                }
+
+                // An unconfirmed creation was never a life of its own
+                if stored_status != "Provisional" {
+                    conn.execute(
+                        "INSERT OR IGNORE INTO account_epochs
+                         (pubkey, creation_signature, creation_slot, created_at, closed_at, rent_lamports, status, archived_at)
+                         SELECT pubkey, creation_signature, creation_slot, created_at, closed_at, rent_lamports,
+                                CASE WHEN status = 'Reclaimed' THEN 'Reclaimed' ELSE 'Closed' END, ?2
+                         FROM sponsored_accounts WHERE pubkey = ?1",
+                        params![account.pubkey, now],
+                    )?;
+                }
+                // Nothing known about the earlier creation applies to the new one
+                conn.execute(
+                    "UPDATE sponsored_accounts SET
+                        closed_at = NULL, close_authority = NULL, reclaim_strategy = NULL,
+                        current_lamports = NULL, balance_checked_at = NULL, account_type = NULL,
+                        mint = NULL, operator = NULL, min_inactive_days = NULL
+                     WHERE pubkey = ?1",
+                    params![account.pubkey],
+                )?;
+                conn.execute("DELETE FROM reclaim_failures WHERE pubkey = ?1", params![account.pubkey])?;
+            }
+        }
+
+        // Within one life the status only moves forward; a rescan that sees
+        // the creation again must not revive a closed or reclaimed account
+        let status = match stored.as_ref().and_then(|(_, _, status)| AccountStatus::from_db(status)) {
+            Some(stored_status)
+                if !new_life
+                    && stored_status != account.status
+                    && !stored_status.can_transition_to(&account.status) =>
+            {
+                // Routine for full rescans, which see every closed account again
+                tracing::debug!(
+                    "Rejected status change of {} from {:?} to {:?}",
+                    account.pubkey, stored_status, account.status
+                );
+                stored_status
+            }
+            _ => account.status.clone(),
+        };
+
         conn.execute(
             "INSERT INTO sponsored_accounts 
-             (pubkey, created_at, closed_at, rent_lamports, data_size, status, creation_signature, creation_slot, close_authority, reclaim_strategy) 
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+             (pubkey, created_at, closed_at, rent_lamports, data_size, status, creation_signature, creation_slot, close_authority, reclaim_strategy, current_lamports, balance_checked_at, account_type, mint, operator, tenant_id) 
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, (SELECT id FROM tenants))
              ON CONFLICT(pubkey) DO UPDATE SET
                 created_at = excluded.created_at,
-                closed_at = excluded.closed_at,
+                closed_at = COALESCE(excluded.closed_at, closed_at),
                 rent_lamports = excluded.rent_lamports,
                 data_size = excluded.data_size,
                 status = excluded.status,
                 creation_signature = excluded.creation_signature,
                 creation_slot = excluded.creation_slot,
-                close_authority = excluded.close_authority,
-                reclaim_strategy = excluded.reclaim_strategy",
+                close_authority = COALESCE(excluded.close_authority, close_authority),
+                reclaim_strategy = COALESCE(excluded.reclaim_strategy, reclaim_strategy),
+                current_lamports = COALESCE(excluded.current_lamports, current_lamports),
+                balance_checked_at = COALESCE(excluded.balance_checked_at, balance_checked_at),
+                account_type = COALESCE(excluded.account_type, account_type),
+                mint = COALESCE(excluded.mint, mint),
+                operator = COALESCE(operator, excluded.operator)",
             params![
                 account.pubkey,
                 account.created_at.to_rfc3339(),
                 account.closed_at.map(|dt| dt.to_rfc3339()),
                 account.rent_lamports,
                 account.data_size,
-                format!("{:?}", account.status),
+                format!("{:?}", status),
                 account.creation_signature,
                 account.creation_slot.map(|s| s as i64),
                 account.close_authority,
                 account.reclaim_strategy.as_ref().map(|s| s.to_string()),
+                account.current_lamports,
+                account.balance_checked_at.map(|dt| dt.to_rfc3339()),
+                account.account_type.as_ref().map(|t| t.to_string()),
+                account.mint,
+                account.operator,
             ],
         )?;
         Ok(())
     }
+
+    fn row_to_account(row: &rusqlite::Row) -> rusqlite::Result<SponsoredAccount> {
+        let status_str: String = row.get(5)?;
+        let status = AccountStatus::from_db(&status_str).unwrap_or(AccountStatus::Active);
+
+        Ok(SponsoredAccount {
+            pubkey: row.get(0)?,
+            created_at: row.get::<_, String>(1)?.parse().unwrap(),
+            closed_at: row.get::<_, Option<String>>(2)?
+                .map(|s| s.parse().unwrap()),
+            rent_lamports: row.get(3)?,
+            data_size: row.get(4)?,
+            status,
+            creation_signature: row.get(6).ok(),
+            creation_slot: row.get::<_, Option<i64>>(7).ok()
+                .flatten()
+                .map(|s| s as u64),
+            close_authority: row.get(8).ok(),
+            reclaim_strategy: row.get::<_, Option<String>>(9).ok()
+                .flatten()
+                .and_then(|s| ReclaimStrategy::from_str(&s).ok()),
+            current_lamports: row.get::<_, Option<u64>>(10).ok().flatten(),
+            balance_checked_at: row.get::<_, Option<String>>(11).ok()
+                .flatten()
+                .and_then(|s| s.parse().ok()),
+            account_type: row.get::<_, Option<String>>(12).ok()
+                .flatten()
+                .and_then(|s| s.parse().ok()),
+            mint: row.get::<_, Option<String>>(13).ok().flatten(),
+            operator: row.get::<_, Option<String>>(14).ok().flatten(),
+            min_inactive_days: row.get::<_, Option<u64>>(15).ok().flatten(),
+        })
+    }
     
     pub fn get_active_accounts(&self) -> Result<Vec<SponsoredAccount>> {
         let conn = self.conn.lock().unwrap();
-        let mut stmt = conn.prepare(
-            "SELECT pubkey, created_at, closed_at, rent_lamports, data_size, status, creation_signature, creation_slot, close_authority, reclaim_strategy
-             FROM sponsored_accounts 
-             WHERE status = 'Active'"
-        )?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts WHERE status = 'Active'",
+            ACCOUNT_COLUMNS
+        ))?;
         
-        let accounts = stmt.query_map([], |row| {
-            Ok(SponsoredAccount {
-                pubkey: row.get(0)?,
-                created_at: row.get::<_, String>(1)?.parse().unwrap(),
-                closed_at: row.get::<_, Option<String>>(2)?
-                    .map(|s| s.parse().unwrap()),
-                rent_lamports: row.get(3)?,
-                data_size: row.get(4)?,
-                status: AccountStatus::Active,
-                creation_signature: row.get(6).ok(),
-                creation_slot: row.get::<_, Option<i64>>(7).ok()
-                    .flatten()
-                    .map(|s| s as u64),
-                close_authority: row.get(8).ok(),
-                reclaim_strategy: row.get::<_, Option<String>>(9).ok()
-                    .flatten()
-                    .and_then(|s| ReclaimStrategy::from_str(&s).ok()),
-            })
-        })?
+        let accounts = stmt.query_map([], Self::row_to_account)?
         .collect::<std::result::Result<Vec<_>, _>>()?;
         
         Ok(accounts)
@@ -159,31 +692,12 @@ impl Database {
     
     pub fn get_closed_accounts(&self) -> Result<Vec<SponsoredAccount>> {
         let conn = self.conn.lock().unwrap();
-        let mut stmt = conn.prepare(
-            "SELECT pubkey, created_at, closed_at, rent_lamports, data_size, status, creation_signature, creation_slot, close_authority, reclaim_strategy
-             FROM sponsored_accounts 
-             WHERE status = 'Closed'"
-        )?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts WHERE status = 'Closed'",
+            ACCOUNT_COLUMNS
+        ))?;
         
-        let accounts = stmt.query_map([], |row| {
-            Ok(SponsoredAccount {
-                pubkey: row.get(0)?,
-                created_at: row.get::<_, String>(1)?.parse().unwrap(),
-                closed_at: row.get::<_, Option<String>>(2)?
-                    .map(|s| s.parse().unwrap()),
-                rent_lamports: row.get(3)?,
-                data_size: row.get(4)?,
-                status: AccountStatus::Closed,
-                creation_signature: row.get(6).ok(),
-                creation_slot: row.get::<_, Option<i64>>(7).ok()
-                    .flatten()
-                    .map(|s| s as u64),
-                close_authority: row.get(8).ok(),
-                reclaim_strategy: row.get::<_, Option<String>>(9).ok()
-                    .flatten()
-                    .and_then(|s| ReclaimStrategy::from_str(&s).ok()),
-            })
-        })?
+        let accounts = stmt.query_map([], Self::row_to_account)?
         .collect::<std::result::Result<Vec<_>, _>>()?;
         
         Ok(accounts)
@@ -191,31 +705,12 @@ impl Database {
     
     pub fn get_reclaimed_accounts(&self) -> Result<Vec<SponsoredAccount>> {
         let conn = self.conn.lock().unwrap();
-        let mut stmt = conn.prepare(
-            "SELECT pubkey, created_at, closed_at, rent_lamports, data_size, status, creation_signature, creation_slot, close_authority, reclaim_strategy
-             FROM sponsored_accounts 
-             WHERE status = 'Reclaimed'"
-        )?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts WHERE status = 'Reclaimed'",
+            ACCOUNT_COLUMNS
+        ))?;
         
-        let accounts = stmt.query_map([], |row| {
-            Ok(SponsoredAccount {
-                pubkey: row.get(0)?,
-                created_at: row.get::<_, String>(1)?.parse().unwrap(),
-                closed_at: row.get::<_, Option<String>>(2)?
-                    .map(|s| s.parse().unwrap()),
-                rent_lamports: row.get(3)?,
-                data_size: row.get(4)?,
-                status: AccountStatus::Reclaimed,
-                creation_signature: row.get(6).ok(),
-                creation_slot: row.get::<_, Option<i64>>(7).ok()
-                    .flatten()
-                    .map(|s| s as u64),
-                close_authority: row.get(8).ok(),
-                reclaim_strategy: row.get::<_, Option<String>>(9).ok()
-                    .flatten()
-                    .and_then(|s| ReclaimStrategy::from_str(&s).ok()),
-            })
-        })?
+        let accounts = stmt.query_map([], Self::row_to_account)?
         .collect::<std::result::Result<Vec<_>, _>>()?;
         
         Ok(accounts)
@@ -223,43 +718,58 @@ impl Database {
     
     pub fn get_account_by_pubkey(&self, pubkey: &str) -> Result<Option<SponsoredAccount>> {
         let conn = self.conn.lock().unwrap();
-        let mut stmt = conn.prepare(
-            "SELECT pubkey, created_at, closed_at, rent_lamports, data_size, status, creation_signature, creation_slot, close_authority, reclaim_strategy
-             FROM sponsored_accounts 
-             WHERE pubkey = ?1"
-        )?;
-        
-        let mut accounts = stmt.query_map([pubkey], |row| {
-            let status_str: String = row.get(5)?;
-            let status = match status_str.as_str() {
-                "Active" => AccountStatus::Active,
-                "Closed" => AccountStatus::Closed,
-                "Reclaimed" => AccountStatus::Reclaimed,
-                _ => AccountStatus::Active,
-            };
-            
-            Ok(SponsoredAccount {
-                pubkey: row.get(0)?,
-                created_at: row.get::<_, String>(1)?.parse().unwrap(),
-                closed_at: row.get::<_, Option<String>>(2)?
-                    .map(|s| s.parse().unwrap()),
-                rent_lamports: row.get(3)?,
-                data_size: row.get(4)?,
-                status,
-                creation_signature: row.get(6).ok(),
-                creation_slot: row.get::<_, Option<i64>>(7).ok()
-                    .flatten()
-                    .map(|s| s as u64),
-                close_authority: row.get(8).ok(),
-                reclaim_strategy: row.get::<_, Option<String>>(9).ok()
-                    .flatten()
-                    .and_then(|s| ReclaimStrategy::from_str(&s).ok()),
-            })
-        })?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts WHERE pubkey = ?1",
+            ACCOUNT_COLUMNS
+        ))?;
+        
+        let mut accounts = stmt.query_map([pubkey], Self::row_to_account)?;
         
         Ok(accounts.next().transpose()?)
     }
     
+    /// Earlier creations of `pubkey`, first to last
+    pub fn get_account_epochs(&self, pubkey: &str) -> Result<Vec<AccountEpoch>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT pubkey, creation_signature, creation_slot, created_at, closed_at, rent_lamports, status
+             FROM account_epochs WHERE pubkey = ?1
+             ORDER BY creation_slot, julianday(created_at)",
+        )?;
+
+        let epochs = stmt.query_map([pubkey], |row| {
+            let created_at: String = row.get(3)?;
+            let closed_at: Option<String> = row.get(4)?;
+            let status: String = row.get(6)?;
+            Ok(AccountEpoch {
+                pubkey: row.get(0)?,
+                epoch: 0,
+                creation_signature: row.get(1)?,
+                creation_slot: row.get::<_, Option<i64>>(2)?.map(|slot| slot as u64),
+                created_at: chrono::DateTime::parse_from_rfc3339(&created_at)
+                    .map(|dt| dt.with_timezone(&Utc))
+                    .unwrap_or_else(|_| Utc::now()),
+                closed_at: closed_at
+                    .and_then(|at| chrono::DateTime::parse_from_rfc3339(&at).ok())
+                    .map(|dt| dt.with_timezone(&Utc)),
+                rent_lamports: row.get(5)?,
+                status: if status == "Reclaimed" { AccountStatus::Reclaimed } else { AccountStatus::Closed },
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(epochs
+            .into_iter()
+            .enumerate()
+            .map(|(i, epoch)| AccountEpoch { epoch: i + 1, ..epoch })
+            .collect())
+    }
+
+    /// Move an account to `status`, only from a state that allows it (see
+    /// [`AccountStatus::predecessors`]). The check and the write are one
+    /// statement, so when two processes race, e.g. both marking an account
+    /// Reclaimed, the second gets `StatusConflict`. Untracked accounts are
+    /// left alone.
     pub fn update_account_status(&self, pubkey: &str, status: AccountStatus) -> Result<()> {
         let conn = self.conn.lock().unwrap();
         let now = if status != AccountStatus::Active {
@@ -267,76 +777,219 @@ impl Database {
         } else {
             None
         };
-        
-        conn.execute(
-            "UPDATE sponsored_accounts 
-             SET status = ?1, closed_at = COALESCE(?2, closed_at)
-             WHERE pubkey = ?3",
-            params![format!("{:?}", status), now, pubkey],
+        let expected: Vec<String> = status.predecessors().iter().map(|s| format!("{:?}", s)).collect();
+        let placeholders = vec!["?"; expected.len()].join(", ");
+
+        let status_str = format!("{:?}", status);
+        let mut values: Vec<&dyn rusqlite::ToSql> = vec![&status_str, &now, &pubkey];
+        values.extend(expected.iter().map(|s| s as &dyn rusqlite::ToSql));
+        let changed = conn.execute(
+            &format!(
+                "UPDATE sponsored_accounts
+                 SET status = ?, closed_at = COALESCE(?, closed_at)
+                 WHERE pubkey = ? AND status IN ({})",
+                placeholders
+            ),
+            values.as_slice(),
         )?;
-        
-        Ok(())
+        if changed > 0 {
+            return Ok(());
+        }
+
+        let found: Option<String> = conn
+            .query_row(
+                "SELECT status FROM sponsored_accounts WHERE pubkey = ?1",
+                params![pubkey],
+                |row| row.get(0),
+            )
+            .optional()?;
+        match found {
+            None => Ok(()),
+            Some(found) => Err(crate::error::ReclaimError::StatusConflict(format!(
+                "{} is {}, cannot become {:?}",
+                pubkey, found, status
+            ))),
+        }
     }
     
-    pub fn save_reclaim_operation(&self, operation: &ReclaimOperation) -> Result<()> {
-        let conn = self.conn.lock().unwrap();
-        conn.execute(
+    /// Record an operation, chaining its hash to the latest entry. An
+    /// operation whose transaction signature is already recorded is skipped,
+    /// so retried or recovered reclaims are counted once; returns whether it
+    /// was inserted.
+    pub fn save_reclaim_operation(&self, operation: &ReclaimOperation) -> Result<bool> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let recorded = tx
+            .query_row(
+                "SELECT 1 FROM reclaim_operations WHERE tx_signature = ?1",
+                params![operation.tx_signature],
+                |_| Ok(()),
+            )
+            .optional()?
+            .is_some();
+        if recorded {
+            tracing::debug!("Reclaim operation {} already recorded", operation.tx_signature);
+            return Ok(false);
+        }
+        let previous = tx
+            .query_row(
+                "SELECT entry_hash FROM reclaim_operations ORDER BY id DESC LIMIT 1",
+                [],
+                |row| row.get::<_, Option<String>>(0),
+            )
+            .optional()?
+            .flatten()
+            .unwrap_or_else(ledger::genesis_hash);
+        tx.execute(
             "INSERT INTO reclaim_operations 
-             (account_pubkey, reclaimed_amount, tx_signature, timestamp, reason) 
-             VALUES (?1, ?2, ?3, ?4, ?5)",
+             (account_pubkey, reclaimed_amount, tx_signature, timestamp, reason, reason_code, credited_amount, destination, fee_lamports, tenant_id, entry_hash) 
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, (SELECT id FROM tenants), ?10)",
             params![
                 operation.account_pubkey,
                 operation.reclaimed_amount,
                 operation.tx_signature,
                 operation.timestamp.to_rfc3339(),
-                operation.reason,
+                operation.reason.label(),
+                operation.reason.to_string(),
+                operation.credited_amount,
+                operation.destination,
+                operation.fee_lamports,
+                ledger::entry_hash(&previous, operation),
             ],
         )?;
-        Ok(())
+        tx.commit()?;
+        Ok(true)
+    }
+
+    /// Every operation with its stored chain hash, in insertion order
+    fn ledger_entries(conn: &Connection) -> Result<Vec<(ReclaimOperation, Option<String>)>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, account_pubkey, reclaimed_amount, tx_signature, timestamp, reason_code, credited_amount, destination, fee_lamports, entry_hash 
+             FROM reclaim_operations 
+             ORDER BY id",
+        )?;
+        let entries = stmt
+            .query_map([], |row| Ok((Self::row_to_operation(row)?, row.get(9)?)))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(entries)
+    }
+
+    /// Walk the operation hash chain
+    pub fn verify_ledger(&self) -> Result<LedgerReport> {
+        let conn = self.conn.lock().unwrap();
+        Ok(ledger::verify(&Self::ledger_entries(&conn)?))
+    }
+    
+    fn row_to_operation(row: &rusqlite::Row) -> rusqlite::Result<ReclaimOperation> {
+        Ok(ReclaimOperation {
+            id: row.get(0)?,
+            account_pubkey: row.get(1)?,
+            reclaimed_amount: row.get(2)?,
+            tx_signature: row.get(3)?,
+            timestamp: row.get::<_, String>(4)?.parse().unwrap(),
+            reason: row.get::<_, Option<String>>(5)?
+                .and_then(|code| code.parse().ok())
+                .unwrap_or(ReclaimReason::Unknown),
+            credited_amount: row.get(6)?,
+            destination: row.get(7)?,
+            fee_lamports: row.get(8)?,
+        })
     }
     
     pub fn get_reclaim_history(&self, limit: Option<usize>) -> Result<Vec<ReclaimOperation>> {
         let conn = self.conn.lock().unwrap();
         let query = if let Some(lim) = limit {
             format!(
-                "SELECT id, account_pubkey, reclaimed_amount, tx_signature, timestamp, reason 
+                "SELECT id, account_pubkey, reclaimed_amount, tx_signature, timestamp, reason_code, credited_amount, destination, fee_lamports 
                  FROM reclaim_operations 
                  ORDER BY timestamp DESC 
                  LIMIT {}",
                 lim
             )
         } else {
-            "SELECT id, account_pubkey, reclaimed_amount, tx_signature, timestamp, reason 
+            "SELECT id, account_pubkey, reclaimed_amount, tx_signature, timestamp, reason_code, credited_amount, destination, fee_lamports 
              FROM reclaim_operations 
              ORDER BY timestamp DESC".to_string()
         };
         
         let mut stmt = conn.prepare(&query)?;
         
-        let operations = stmt.query_map([], |row| {
-            Ok(ReclaimOperation {
-                id: row.get(0)?,
-                account_pubkey: row.get(1)?,
-                reclaimed_amount: row.get(2)?,
-                tx_signature: row.get(3)?,
-                timestamp: row.get::<_, String>(4)?.parse().unwrap(),
-                reason: row.get(5)?,
-            })
-        })?
+        let operations = stmt.query_map([], Self::row_to_operation)?
         .collect::<std::result::Result<Vec<_>, _>>()?;
         
         Ok(operations)
     }
     
-    pub fn get_total_reclaimed(&self) -> Result<u64> {
+    /// Reclaim operations recorded for one account, newest first
+    pub fn get_reclaim_operations_for(&self, pubkey: &str) -> Result<Vec<ReclaimOperation>> {
         let conn = self.conn.lock().unwrap();
-        let total: Option<u64> = conn.query_row(
-            "SELECT SUM(reclaimed_amount) FROM reclaim_operations",
-            [],
-            |row| row.get(0),
+        let mut stmt = conn.prepare(
+            "SELECT id, account_pubkey, reclaimed_amount, tx_signature, timestamp, reason_code, credited_amount, destination, fee_lamports 
+             FROM reclaim_operations 
+             WHERE account_pubkey = ?1 
+             ORDER BY timestamp DESC",
         )?;
         
-        Ok(total.unwrap_or(0))
+        let operations = stmt.query_map(params![pubkey], Self::row_to_operation)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+        
+        Ok(operations)
+    }
+    
+    /// Verified reclaims whose treasury credit differs from the amount reclaimed, newest first
+    pub fn get_credit_mismatches(&self) -> Result<Vec<ReclaimOperation>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, account_pubkey, reclaimed_amount, tx_signature, timestamp, reason_code, credited_amount, destination, fee_lamports 
+             FROM reclaim_operations 
+             WHERE credited_amount IS NOT NULL AND credited_amount != reclaimed_amount 
+             ORDER BY timestamp DESC",
+        )?;
+        
+        let operations = stmt.query_map([], Self::row_to_operation)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        
+        Ok(operations)
+    }
+    
+    /// Operation count and lamports reclaimed per reclaim reason
+    pub fn get_reclaim_totals_by_reason(&self) -> Result<Vec<(ReclaimReason, usize, u64)>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT reason_code, COUNT(*), SUM(reclaimed_amount) 
+             FROM reclaim_operations GROUP BY reason_code"
+        )?;
+        
+        let mut totals: Vec<(ReclaimReason, usize, u64)> = Vec::new();
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, Option<String>>(0)?, row.get::<_, i64>(1)?, row.get::<_, Option<u64>>(2)?))
+        })?;
+        for row in rows {
+            let (code, count, amount) = row?;
+            let reason = code.and_then(|c| c.parse().ok()).unwrap_or(ReclaimReason::Unknown);
+            // NULL and unparseable codes both fold into Unknown
+            match totals.iter_mut().find(|(r, _, _)| *r == reason) {
+                Some(entry) => {
+                    entry.1 += count as usize;
+                    entry.2 += amount.unwrap_or(0);
+                }
+                None => totals.push((reason, count as usize, amount.unwrap_or(0))),
+            }
+        }
+        
+        totals.sort_by_key(|(reason, _, _)| *reason);
+        Ok(totals)
+    }
+    
+    pub fn get_total_reclaimed(&self) -> Result<u64> {
+        let conn = self.conn.lock().unwrap();
+        let total: Option<u64> = conn.query_row(
+            "SELECT SUM(reclaimed_amount) FROM reclaim_operations",
+            [],
+            |row| row.get(0),
+        )?;
+        
+        Ok(total.unwrap_or(0))
     }
     
     pub fn get_stats(&self) -> Result<DatabaseStats> {
@@ -417,6 +1070,373 @@ impl Database {
         }
     }
     
+    // Runtime settings overriding the configuration file
+    
+    /// Acknowledged TUI alerts: kind -> severity at acknowledgement
+    pub fn get_alert_acknowledgements(&self) -> Result<std::collections::HashMap<String, String>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT kind, severity FROM alert_acknowledgements")?;
+        
+        let acknowledged = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<std::result::Result<std::collections::HashMap<String, String>, _>>()?;
+        
+        Ok(acknowledged)
+    }
+    
+    pub fn acknowledge_alert(&self, kind: &str, severity: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT OR REPLACE INTO alert_acknowledgements (kind, severity, acknowledged_at) 
+             VALUES (?1, ?2, ?3)",
+            params![kind, severity, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+    
+    /// Forget an acknowledgement once its alert cleared, so it shows if raised again
+    pub fn clear_alert_acknowledgement(&self, kind: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute("DELETE FROM alert_acknowledgements WHERE kind = ?1", params![kind])?;
+        Ok(())
+    }
+    
+    /// Load all runtime overrides
+    pub fn get_runtime_settings(&self) -> Result<RuntimeSettings> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT key, value FROM runtime_settings")?;
+        
+        let pairs = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<std::result::Result<Vec<(String, String)>, _>>()?;
+        
+        Ok(RuntimeSettings::from_pairs(&pairs))
+    }
+    
+    /// Store a runtime override (see `storage::settings` for keys)
+    pub fn set_runtime_setting(&self, key: &str, value: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT OR REPLACE INTO runtime_settings (key, value, updated_at) 
+             VALUES (?1, ?2, ?3)",
+            params![key, value, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+    
+    /// Drop every runtime override, reverting to the configuration file
+    pub fn clear_runtime_settings(&self) -> Result<usize> {
+        let conn = self.conn.lock().unwrap();
+        let removed = conn.execute("DELETE FROM runtime_settings", [])?;
+        Ok(removed)
+    }
+    
+    // Telegram notification preferences
+    
+    /// Store which notifications a Telegram user receives
+    pub fn set_notification_level(&self, user_id: i64, level: NotificationLevel) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT OR REPLACE INTO notification_preferences (user_id, level, updated_at) 
+             VALUES (?1, ?2, ?3)",
+            params![user_id, level.to_string(), Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+    
+    /// Stored choices by user id; users without a row receive everything
+    pub fn get_notification_levels(&self) -> Result<std::collections::HashMap<i64, NotificationLevel>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT user_id, level FROM notification_preferences")?;
+        
+        let rows = stmt.query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        
+        Ok(rows
+            .into_iter()
+            .map(|(user_id, level)| (user_id, level.parse().unwrap_or_default()))
+            .collect())
+    }
+    
+    /// Hold a rendered notification for the next digest
+    pub fn queue_notification(&self, category: NotificationCategory, message: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO notification_queue (category, message, queued_at) VALUES (?1, ?2, ?3)",
+            params![category.to_string(), message, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+    
+    /// Remove and return every queued notification, oldest first
+    pub fn take_queued_notifications(&self) -> Result<Vec<(NotificationCategory, String)>> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        
+        let queued = {
+            let mut stmt = tx.prepare("SELECT category, message FROM notification_queue ORDER BY id")?;
+            let rows = stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            rows
+        };
+        tx.execute("DELETE FROM notification_queue", [])?;
+        tx.commit()?;
+        
+        Ok(queued
+            .into_iter()
+            .map(|(category, message)| {
+                (category.parse().unwrap_or(NotificationCategory::General), message)
+            })
+            .collect())
+    }
+    
+    /// Take the named lease for `holder` unless another holder heartbeat within
+    /// `ttl`. Returns that holder when the lease is taken.
+    pub fn try_acquire_lease(
+        &self,
+        name: &str,
+        holder: &str,
+        ttl: chrono::Duration,
+    ) -> Result<Option<LeaseHolder>> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let now = Utc::now();
+        
+        let current = tx
+            .query_row(
+                "SELECT holder, heartbeat_at FROM service_leases WHERE name = ?1",
+                params![name],
+                |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+            )
+            .optional()?;
+        
+        if let Some((current_holder, heartbeat_at)) = current {
+            let heartbeat_at = chrono::DateTime::parse_from_rfc3339(&heartbeat_at)
+                .map(|t| t.with_timezone(&Utc))
+                .unwrap_or_default();
+            if current_holder != holder && now - heartbeat_at < ttl {
+                return Ok(Some(LeaseHolder { holder: current_holder, heartbeat_at }));
+            }
+        }
+        
+        tx.execute(
+            "INSERT OR REPLACE INTO service_leases (name, holder, acquired_at, heartbeat_at)
+             VALUES (?1, ?2, ?3, ?3)",
+            params![name, holder, now.to_rfc3339()],
+        )?;
+        tx.commit()?;
+        Ok(None)
+    }
+    
+    /// Refresh the heartbeat; false if `holder` no longer owns the lease
+    pub fn renew_lease(&self, name: &str, holder: &str) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let updated = conn.execute(
+            "UPDATE service_leases SET heartbeat_at = ?3 WHERE name = ?1 AND holder = ?2",
+            params![name, holder, Utc::now().to_rfc3339()],
+        )?;
+        Ok(updated == 1)
+    }
+    
+    pub fn release_lease(&self, name: &str, holder: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "DELETE FROM service_leases WHERE name = ?1 AND holder = ?2",
+            params![name, holder],
+        )?;
+        Ok(())
+    }
+    
+    /// Count a deterministic reclaim failure. Once `dead_letter_after`
+    /// failures are recorded (0 never) the account is dead-lettered;
+    /// returns true when this call did so.
+    pub fn record_reclaim_failure(&self, pubkey: &str, error: &str, dead_letter_after: u32) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let now = Utc::now().to_rfc3339();
+        
+        conn.execute(
+            "INSERT INTO reclaim_failures (pubkey, failures, last_error, last_failed_at)
+             VALUES (?1, 1, ?2, ?3)
+             ON CONFLICT(pubkey) DO UPDATE SET
+                failures = failures + 1, last_error = ?2, last_failed_at = ?3",
+            params![pubkey, error, now],
+        )?;
+        
+        let dead_lettered = conn.execute(
+            "UPDATE reclaim_failures SET dead_lettered_at = ?3
+             WHERE pubkey = ?1 AND dead_lettered_at IS NULL AND ?2 > 0 AND failures >= ?2",
+            params![pubkey, dead_letter_after, now],
+        )?;
+        Ok(dead_lettered == 1)
+    }
+    
+    /// Forget an account's failures, returning it to normal retries.
+    /// Returns false if none were recorded.
+    pub fn clear_reclaim_failures(&self, pubkey: &str) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let removed = conn.execute("DELETE FROM reclaim_failures WHERE pubkey = ?1", params![pubkey])?;
+        Ok(removed == 1)
+    }
+    
+    /// Set or clear the hold on a tracked account; false if it isn't tracked
+    pub fn set_min_inactive_days(&self, pubkey: &str, days: Option<u64>) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let updated = conn.execute(
+            "UPDATE sponsored_accounts SET min_inactive_days = ?1 WHERE pubkey = ?2",
+            params![days.map(|days| days as i64), pubkey],
+        )?;
+        Ok(updated == 1)
+    }
+
+    /// Hold on one account, if it is tracked and has one
+    pub fn get_min_inactive_days(&self, pubkey: &str) -> Result<Option<u64>> {
+        let conn = self.conn.lock().unwrap();
+        let days: Option<Option<i64>> = conn
+            .query_row(
+                "SELECT min_inactive_days FROM sponsored_accounts WHERE pubkey = ?1",
+                params![pubkey],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(days.flatten().map(|days| days as u64))
+    }
+
+    /// Active accounts with a hold, longest first
+    pub fn get_held_accounts(&self) -> Result<Vec<SponsoredAccount>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts
+             WHERE status = 'Active' AND min_inactive_days IS NOT NULL
+             ORDER BY julianday(created_at) + min_inactive_days DESC",
+            ACCOUNT_COLUMNS
+        ))?;
+
+        let accounts = stmt.query_map([], Self::row_to_account)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(accounts)
+    }
+
+    /// Dead-lettered accounts, most recently marked first
+    pub fn get_dead_letters(&self) -> Result<Vec<ReclaimFailure>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT pubkey, failures, last_error, last_failed_at, dead_lettered_at
+             FROM reclaim_failures WHERE dead_lettered_at IS NOT NULL
+             ORDER BY dead_lettered_at DESC",
+        )?;
+        
+        let failures = stmt.query_map([], Self::row_to_failure)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        
+        Ok(failures)
+    }
+    
+    /// Recorded failures for one account, dead-lettered or not
+    pub fn get_reclaim_failure(&self, pubkey: &str) -> Result<Option<ReclaimFailure>> {
+        let conn = self.conn.lock().unwrap();
+        let failure = conn
+            .query_row(
+                "SELECT pubkey, failures, last_error, last_failed_at, dead_lettered_at
+                 FROM reclaim_failures WHERE pubkey = ?1",
+                params![pubkey],
+                Self::row_to_failure,
+            )
+            .optional()?;
+        Ok(failure)
+    }
+    
+    fn row_to_failure(row: &rusqlite::Row) -> rusqlite::Result<ReclaimFailure> {
+        let timestamp = |value: String| {
+            chrono::DateTime::parse_from_rfc3339(&value)
+                .map(|t| t.with_timezone(&Utc))
+                .unwrap_or_default()
+        };
+        Ok(ReclaimFailure {
+            pubkey: row.get(0)?,
+            failures: row.get(1)?,
+            last_error: row.get(2)?,
+            last_failed_at: timestamp(row.get(3)?),
+            dead_lettered_at: row.get::<_, Option<String>>(4)?.map(timestamp),
+        })
+    }
+    
+    /// Stage a high-value reclaim; false if the account is already staged
+    pub fn stage_reclaim(&self, staged: &StagedReclaim) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO staged_reclaims
+             (pubkey, amount, staged_at, execute_after, units_consumed, simulation_error, approved_at, approved_by)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                staged.pubkey,
+                staged.amount as i64,
+                staged.staged_at.to_rfc3339(),
+                staged.execute_after.to_rfc3339(),
+                staged.units_consumed.map(|units| units as i64),
+                staged.simulation_error,
+                staged.approved_at.map(|at| at.to_rfc3339()),
+                staged.approved_by,
+            ],
+        )?;
+        Ok(inserted == 1)
+    }
+    
+    /// Staged reclaims, soonest due first
+    pub fn get_staged_reclaims(&self) -> Result<Vec<StagedReclaim>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT pubkey, amount, staged_at, execute_after, units_consumed, simulation_error, approved_at, approved_by
+             FROM staged_reclaims ORDER BY julianday(execute_after)",
+        )?;
+        
+        let timestamp = |value: String| {
+            chrono::DateTime::parse_from_rfc3339(&value)
+                .map(|t| t.with_timezone(&Utc))
+                .unwrap_or_default()
+        };
+        let staged = stmt.query_map([], |row| {
+            Ok(StagedReclaim {
+                pubkey: row.get(0)?,
+                amount: row.get::<_, i64>(1)? as u64,
+                staged_at: timestamp(row.get(2)?),
+                execute_after: timestamp(row.get(3)?),
+                units_consumed: row.get::<_, Option<i64>>(4)?.map(|units| units as u64),
+                simulation_error: row.get(5)?,
+                approved_at: row.get::<_, Option<String>>(6)?.map(timestamp),
+                approved_by: row.get(7)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+        
+        Ok(staged)
+    }
+    
+    /// Release a staged reclaim for the next cycle; false if it isn't staged
+    pub fn approve_staged_reclaim(&self, pubkey: &str, approved_by: &str) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let updated = conn.execute(
+            "UPDATE staged_reclaims SET approved_at = COALESCE(approved_at, ?1), approved_by = COALESCE(approved_by, ?2)
+             WHERE pubkey = ?3",
+            params![Utc::now().to_rfc3339(), approved_by, pubkey],
+        )?;
+        Ok(updated == 1)
+    }
+    
+    /// Drop a staged reclaim once it ran, was denied, or no longer applies
+    pub fn remove_staged_reclaim(&self, pubkey: &str) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let removed = conn.execute("DELETE FROM staged_reclaims WHERE pubkey = ?1", params![pubkey])?;
+        Ok(removed == 1)
+    }
+    
+    /// Pubkeys of dead-lettered accounts, for skipping them during scans
+    pub fn get_dead_lettered_pubkeys(&self) -> Result<std::collections::HashSet<String>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT pubkey FROM reclaim_failures WHERE dead_lettered_at IS NOT NULL")?;
+        let pubkeys = stmt.query_map([], |row| row.get(0))?
+            .collect::<std::result::Result<_, _>>()?;
+        Ok(pubkeys)
+    }
+    
     // Checkpoint management for incremental scanning
     
     /// Save the last processed signature to avoid re-scanning old transactions
@@ -454,6 +1474,62 @@ impl Database {
         }
     }
     
+    /// Keep the incremental-scan checkpoint with the operator key it was
+    /// taken for. After a rotation the previous key's checkpoint moves to
+    /// its retired slot and `operator` starts from a full scan; returns the
+    /// previous key when that happened.
+    pub fn bind_checkpoint_operator(&self, operator: &str) -> Result<Option<String>> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let previous: Option<String> = tx
+            .query_row("SELECT value FROM checkpoints WHERE key = 'last_signature_operator'", [], |row| row.get(0))
+            .optional()?;
+        let now = Utc::now().to_rfc3339();
+        
+        // Checkpoints from before rotation support belong to the current key
+        let rotated_from = previous.filter(|previous| previous != operator);
+        if let Some(previous) = &rotated_from {
+            tx.execute(
+                "INSERT OR REPLACE INTO checkpoints (key, value, updated_at)
+                 SELECT ?1, value, ?2 FROM checkpoints WHERE key = 'last_signature'",
+                params![Self::operator_checkpoint_key(previous), now],
+            )?;
+            tx.execute("DELETE FROM checkpoints WHERE key IN ('last_signature', 'last_slot')", [])?;
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO checkpoints (key, value, updated_at) VALUES ('last_signature_operator', ?1, ?2)",
+            params![operator, now],
+        )?;
+        tx.commit()?;
+        Ok(rotated_from)
+    }
+    
+    fn operator_checkpoint_key(operator: &str) -> String {
+        format!("last_signature:{}", operator)
+    }
+    
+    /// Last processed signature of a retired operator key
+    pub fn get_operator_checkpoint(&self, operator: &str) -> Result<Option<solana_sdk::signature::Signature>> {
+        let conn = self.conn.lock().unwrap();
+        let value: Option<String> = conn
+            .query_row(
+                "SELECT value FROM checkpoints WHERE key = ?1",
+                [Self::operator_checkpoint_key(operator)],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(value.and_then(|value| solana_sdk::signature::Signature::from_str(&value).ok()))
+    }
+    
+    pub fn save_operator_checkpoint(&self, operator: &str, signature: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT OR REPLACE INTO checkpoints (key, value, updated_at) VALUES (?1, ?2, ?3)",
+            params![Self::operator_checkpoint_key(operator), signature, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+    
     /// Save the last processed slot for tracking
     pub fn save_last_processed_slot(&self, slot: u64) -> Result<()> {
         let conn = self.conn.lock().unwrap();
@@ -495,104 +1571,708 @@ impl Database {
     /// Get all accounts (regardless of status) for caching
     pub fn get_all_accounts(&self) -> Result<Vec<SponsoredAccount>> {
         let conn = self.conn.lock().unwrap();
-        let mut stmt = conn.prepare(
-            "SELECT pubkey, created_at, closed_at, rent_lamports, data_size, status, creation_signature, creation_slot, close_authority, reclaim_strategy
-             FROM sponsored_accounts 
-             ORDER BY created_at DESC"
-        )?;
-        
-        let accounts = stmt.query_map([], |row| {
-            let status_str: String = row.get(5)?;
-            let status = match status_str.as_str() {
-                "Active" => AccountStatus::Active,
-                "Closed" => AccountStatus::Closed,
-                "Reclaimed" => AccountStatus::Reclaimed,
-                _ => AccountStatus::Active,
-            };
-            
-            Ok(SponsoredAccount {
-                pubkey: row.get(0)?,
-                created_at: row.get::<_, String>(1)?.parse().unwrap(),
-                closed_at: row.get::<_, Option<String>>(2)?
-                    .map(|s| s.parse().unwrap()),
-                rent_lamports: row.get(3)?,
-                data_size: row.get(4)?,
-                status,
-                creation_signature: row.get(6).ok(),
-                creation_slot: row.get::<_, Option<i64>>(7).ok()
-                    .flatten()
-                    .map(|s| s as u64),
-                close_authority: row.get(8).ok(),
-                reclaim_strategy: row.get::<_, Option<String>>(9).ok()
-                    .flatten()
-                    .and_then(|s| ReclaimStrategy::from_str(&s).ok()),
-            })
-        })?
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts ORDER BY created_at DESC",
+            ACCOUNT_COLUMNS
+        ))?;
+        
+        let accounts = stmt.query_map([], Self::row_to_account)?
         .collect::<std::result::Result<Vec<_>, _>>()?;
         
         Ok(accounts)
     }
     
-    /// Find active accounts with rent lamports in a specific range
+    /// Active accounts whose rent is between `min` and `max` lamports, inclusive
     pub fn get_active_accounts_by_rent_range(&self, min: u64, max: u64) -> Result<Vec<SponsoredAccount>> {
         let conn = self.conn.lock().unwrap();
-        let mut stmt = conn.prepare(
-            "SELECT pubkey, created_at, closed_at, rent_lamports, data_size, status, 
-                    creation_signature, creation_slot, close_authority, reclaim_strategy
-             FROM sponsored_accounts 
-             WHERE status = 'Active' AND rent_lamports BETWEEN ?1 AND ?2"
-        )?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts WHERE status = 'Active' AND rent_lamports BETWEEN ?1 AND ?2",
+            ACCOUNT_COLUMNS
+        ))?;
         
-        let accounts = stmt.query_map(params![min, max], |row| {
-             Ok(SponsoredAccount {
-                pubkey: row.get(0)?,
-                created_at: row.get::<_, String>(1)?.parse().unwrap(),
-                closed_at: row.get::<_, Option<String>>(2)?
-                    .map(|s| s.parse().unwrap()),
-                rent_lamports: row.get(3)?,
-                data_size: row.get(4)?,
-                status: AccountStatus::Active,
-                creation_signature: row.get(6).ok(),
-                creation_slot: row.get::<_, Option<i64>>(7).ok()
-                    .flatten()
-                    .map(|s| s as u64),
-                close_authority: row.get(8).ok(),
-                reclaim_strategy: row.get::<_, Option<String>>(9).ok()
-                    .flatten()
-                    .and_then(|s| ReclaimStrategy::from_str(&s).ok()),
-            })
-        })?
+        let accounts = stmt.query_map(params![min, max], Self::row_to_account)?
         .collect::<std::result::Result<Vec<_>, _>>()?;
         
         Ok(accounts)
     }
 
-    /// Get checkpoint metadata (useful for debugging)
-    pub fn get_checkpoint_info(&self) -> Result<Vec<(String, String, String)>> {
+    /// Record freshly fetched on-chain balances for tracked accounts
+    pub fn update_current_balances(&self, balances: &[(String, u64)]) -> Result<usize> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let checked_at = Utc::now().to_rfc3339();
+        let mut updated = 0;
+
+        for (pubkey, lamports) in balances {
+            updated += tx.execute(
+                "UPDATE sponsored_accounts 
+                 SET current_lamports = ?1, balance_checked_at = ?2
+                 WHERE pubkey = ?3",
+                params![lamports, checked_at, pubkey],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(updated)
+    }
+
+    /// Fill in token mints read from chain for accounts recorded without one
+    pub fn update_mints(&self, mints: &[(String, String)]) -> Result<usize> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let mut updated = 0;
+
+        for (pubkey, mint) in mints {
+            updated += tx.execute(
+                "UPDATE sponsored_accounts SET mint = ?1 WHERE pubkey = ?2 AND mint IS NULL",
+                params![mint, pubkey],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(updated)
+    }
+
+    /// Rent locked in active token accounts per mint, largest first
+    pub fn get_locked_rent_by_mint(&self) -> Result<Vec<MintRent>> {
         let conn = self.conn.lock().unwrap();
         let mut stmt = conn.prepare(
-            "SELECT key, value, updated_at FROM checkpoints ORDER BY updated_at DESC"
+            "SELECT mint, COUNT(*),
+                    SUM(COALESCE(current_lamports, rent_lamports)),
+                    SUM(CASE WHEN reclaim_strategy = 'PassiveMonitoring'
+                        THEN COALESCE(current_lamports, rent_lamports) ELSE 0 END)
+             FROM sponsored_accounts
+             WHERE status = 'Active' AND mint IS NOT NULL
+             GROUP BY mint
+             ORDER BY 3 DESC"
         )?;
-        
-        let checkpoints = stmt.query_map([], |row| {
-            Ok((
-                row.get::<_, String>(0)?,
-                row.get::<_, String>(1)?,
-                row.get::<_, String>(2)?,
-            ))
+
+        let mints = stmt.query_map([], |row| {
+            Ok(MintRent {
+                mint: row.get(0)?,
+                accounts: row.get::<_, i64>(1)? as usize,
+                locked_lamports: row.get::<_, Option<u64>>(2)?.unwrap_or(0),
+                passive_lamports: row.get::<_, Option<u64>>(3)?.unwrap_or(0),
+            })
         })?
         .collect::<std::result::Result<Vec<_>, _>>()?;
-        
-        Ok(checkpoints)
+
+        Ok(mints)
     }
-    
-    /// Clear all checkpoints (useful for reset/debugging)
-    pub fn clear_checkpoints(&self) -> Result<()> {
+
+    /// Accounts, locked rent and reclaims per sponsoring operator key, most accounts first
+    pub fn get_operator_totals(&self) -> Result<Vec<OperatorTotals>> {
         let conn = self.conn.lock().unwrap();
-        conn.execute("DELETE FROM checkpoints", [])?;
+        let mut stmt = conn.prepare(
+            "SELECT a.operator, COUNT(*),
+                    SUM(a.status = 'Active'),
+                    SUM(CASE WHEN a.status = 'Active' THEN COALESCE(a.current_lamports, a.rent_lamports) ELSE 0 END),
+                    COALESCE(SUM(o.reclaimed), 0)
+             FROM sponsored_accounts a
+             LEFT JOIN (
+                 SELECT account_pubkey, SUM(reclaimed_amount) AS reclaimed
+                 FROM reclaim_operations GROUP BY account_pubkey
+             ) o ON o.account_pubkey = a.pubkey
+             GROUP BY a.operator
+             ORDER BY 2 DESC"
+        )?;
+
+        let totals = stmt.query_map([], |row| {
+            Ok(OperatorTotals {
+                operator: row.get(0)?,
+                accounts: row.get::<_, i64>(1)? as usize,
+                active_accounts: row.get::<_, Option<i64>>(2)?.unwrap_or(0) as usize,
+                locked_lamports: row.get::<_, Option<u64>>(3)?.unwrap_or(0),
+                reclaimed_lamports: row.get(4)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(totals)
+    }
+
+    /// Record the configured scan floor, or clear it when none is set
+    pub fn save_scan_floor(&self, slot: Option<u64>) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        match slot {
+            Some(slot) => conn.execute(
+                "INSERT INTO checkpoints (key, value, updated_at) VALUES ('scan_floor_slot', ?1, ?2)
+                 ON CONFLICT(key) DO UPDATE SET value = excluded.value,
+                     updated_at = CASE WHEN value = excluded.value THEN updated_at ELSE excluded.updated_at END",
+                params![slot.to_string(), Utc::now().to_rfc3339()],
+            )?,
+            None => conn.execute("DELETE FROM checkpoints WHERE key = 'scan_floor_slot'", [])?,
+        };
+        Ok(())
+    }
+
+    /// Get checkpoint metadata (useful for debugging)
+    pub fn get_checkpoint_info(&self) -> Result<Vec<(String, String, String)>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT key, value, updated_at FROM checkpoints ORDER BY updated_at DESC"
+        )?;
+        
+        let checkpoints = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+        
+        Ok(checkpoints)
+    }
+    
+    /// Clear all checkpoints (useful for reset/debugging)
+    pub fn clear_checkpoints(&self) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute("DELETE FROM checkpoints", [])?;
+        Ok(())
+    }
+    
+    /// Record a finished discovery run, keeping the latest `SCAN_SESSIONS_KEPT`
+    pub fn save_scan_session(&self, session: &ScanSession) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO scan_sessions (started_at, finished_at, incremental, accounts_found, error)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                session.started_at.to_rfc3339(),
+                session.finished_at.to_rfc3339(),
+                session.incremental,
+                session.accounts_found,
+                session.error,
+            ],
+        )?;
+        conn.execute(
+            "DELETE FROM scan_sessions WHERE id <= (SELECT MAX(id) FROM scan_sessions) - ?1",
+            params![SCAN_SESSIONS_KEPT],
+        )?;
+        Ok(())
+    }
+
+    /// Most recent discovery runs, newest first
+    pub fn get_scan_sessions(&self, limit: usize) -> Result<Vec<ScanSession>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, started_at, finished_at, incremental, accounts_found, error
+             FROM scan_sessions ORDER BY id DESC LIMIT ?1",
+        )?;
+
+        let timestamp = |value: String| {
+            chrono::DateTime::parse_from_rfc3339(&value)
+                .map(|t| t.with_timezone(&Utc))
+                .unwrap_or_default()
+        };
+        let sessions = stmt.query_map(params![limit], |row| {
+            Ok(ScanSession {
+                id: row.get(0)?,
+                started_at: timestamp(row.get(1)?),
+                finished_at: timestamp(row.get(2)?),
+                incremental: row.get(3)?,
+                accounts_found: row.get(4)?,
+                error: row.get(5)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(sessions)
+    }
+
+    /// Failures since the last successful scan and the latest scan error
+    pub fn get_scan_failure_streak(&self) -> Result<ScanFailureStreak> {
+        let conn = self.conn.lock().unwrap();
+        let consecutive_failures: u32 = conn.query_row(
+            "SELECT COUNT(*) FROM scan_sessions WHERE error IS NOT NULL
+             AND id > COALESCE((SELECT MAX(id) FROM scan_sessions WHERE error IS NULL), 0)",
+            [],
+            |row| row.get(0),
+        )?;
+        let last_failure: Option<(String, String)> = conn
+            .query_row(
+                "SELECT error, finished_at FROM scan_sessions WHERE error IS NOT NULL
+                 ORDER BY id DESC LIMIT 1",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+
+        let (last_error, last_failed_at) = match last_failure {
+            Some((error, finished_at)) => (
+                Some(error),
+                chrono::DateTime::parse_from_rfc3339(&finished_at)
+                    .map(|t| t.with_timezone(&Utc))
+                    .ok(),
+            ),
+            None => (None, None),
+        };
+        Ok(ScanFailureStreak { consecutive_failures, last_error, last_failed_at })
+    }
+
+    // Integrity checks
+
+    /// Check the database for corruption and for operations that disagree
+    /// with the accounts table. With `repair`, orphaned operations get a
+    /// placeholder Reclaimed account, reclaimed accounts still marked Active
+    /// are moved to Reclaimed, and indexes are rebuilt after SQLite errors.
+    pub fn check_integrity(&self, repair: bool) -> Result<IntegrityReport> {
+        let conn = self.conn.lock().unwrap();
+        let mut report = IntegrityReport {
+            sqlite_errors: Self::sqlite_errors(&conn)?,
+            ..Default::default()
+        };
+
+        let mut stmt = conn.prepare("PRAGMA foreign_key_check")?;
+        report.foreign_key_violations = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, Option<i64>>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .into_iter()
+            // Orphaned reclaim operations are reported per account below
+            .filter(|(table, _, _)| table != "reclaim_operations")
+            .map(|(table, rowid, parent)| {
+                format!("{} {} -> {}", table, rowid.map_or("?".to_string(), |id| id.to_string()), parent)
+            })
+            .collect();
+
+        let mut stmt = conn.prepare(
+            "SELECT o.account_pubkey, SUM(o.reclaimed_amount), MIN(o.timestamp), MAX(o.timestamp)
+             FROM reclaim_operations o
+             LEFT JOIN sponsored_accounts a ON a.pubkey = o.account_pubkey
+             WHERE a.pubkey IS NULL
+             GROUP BY o.account_pubkey",
+        )?;
+        let orphans = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                ))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut stmt = conn.prepare(
+            "SELECT a.pubkey, MAX(o.timestamp)
+             FROM sponsored_accounts a
+             JOIN reclaim_operations o ON o.account_pubkey = a.pubkey
+             -- Reclaims of an earlier creation of the address don't count
+             WHERE a.status = 'Active' AND julianday(o.timestamp) >= julianday(a.created_at)
+             GROUP BY a.pubkey",
+        )?;
+        let unreclaimed = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut stmt = conn.prepare(
+            "SELECT tx_signature FROM reclaim_operations
+             GROUP BY tx_signature
+             HAVING COUNT(*) > 1",
+        )?;
+        report.duplicate_operations = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        report.orphaned_operations = orphans.iter().map(|(pubkey, ..)| pubkey.clone()).collect();
+        report.unreclaimed_accounts = unreclaimed.iter().map(|(pubkey, _)| pubkey.clone()).collect();
+
+        if !repair {
+            return Ok(report);
+        }
+
+        if !report.sqlite_errors.is_empty() {
+            conn.execute_batch("REINDEX")?;
+            let remaining = Self::sqlite_errors(&conn)?;
+            report.repaired += report.sqlite_errors.len().saturating_sub(remaining.len());
+        }
+
+        if !report.duplicate_operations.is_empty() {
+            // Re-chain only a ledger that was intact, so earlier tampering stays visible
+            let intact = ledger::verify(&Self::ledger_entries(&conn)?).is_intact();
+            for signature in &report.duplicate_operations {
+                conn.execute(
+                    "DELETE FROM reclaim_operations
+                     WHERE tx_signature = ?1
+                       AND id > (SELECT MIN(id) FROM reclaim_operations WHERE tx_signature = ?1)",
+                    params![signature],
+                )?;
+                report.repaired += 1;
+            }
+            if intact {
+                Self::rechain_ledger(&conn)?;
+            }
+            Self::create_operation_signature_index(&conn)?;
+        }
+
+        for (pubkey, amount, first_at, last_at) in &orphans {
+            // The data size and creation are unknown; the operation is all that's left
+            report.repaired += conn.execute(
+                "INSERT OR IGNORE INTO sponsored_accounts
+                 (pubkey, created_at, closed_at, rent_lamports, data_size, status)
+                 VALUES (?1, ?2, ?3, ?4, 0, 'Reclaimed')",
+                params![pubkey, first_at, last_at, amount],
+            )?;
+        }
+
+        // Guarded like `update_account_status`, so an account that moved on
+        // since it was read is left alone
+        let from: Vec<String> = AccountStatus::Reclaimed.predecessors().iter().map(|s| format!("'{:?}'", s)).collect();
+        for (pubkey, reclaimed_at) in &unreclaimed {
+            report.repaired += conn.execute(
+                &format!(
+                    "UPDATE sponsored_accounts SET status = 'Reclaimed', closed_at = COALESCE(closed_at, ?1)
+                     WHERE pubkey = ?2 AND status IN ({})",
+                    from.join(", ")
+                ),
+                params![reclaimed_at, pubkey],
+            )?;
+        }
+
+        Ok(report)
+    }
+
+    /// UTC date of the last check recorded with [`mark_integrity_checked`](Self::mark_integrity_checked)
+    pub fn last_integrity_check(&self) -> Result<Option<NaiveDate>> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT value FROM checkpoints WHERE key = ?1",
+            params![INTEGRITY_CHECK_KEY],
+            |row| row.get::<_, String>(0),
+        );
+
+        match result {
+            Ok(date) => Ok(date.parse().ok()),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn mark_integrity_checked(&self) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        let now = Utc::now();
+        conn.execute(
+            "INSERT OR REPLACE INTO checkpoints (key, value, updated_at) VALUES (?1, ?2, ?3)",
+            params![INTEGRITY_CHECK_KEY, now.date_naive().to_string(), now.to_rfc3339()],
+        )?;
         Ok(())
     }
 
+    /// When Unrecoverable accounts were last audited, recorded with
+    /// [`mark_unrecoverable_audited`](Self::mark_unrecoverable_audited)
+    pub fn last_unrecoverable_audit(&self) -> Result<Option<chrono::DateTime<Utc>>> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT value FROM checkpoints WHERE key = ?1",
+            params![UNRECOVERABLE_AUDIT_KEY],
+            |row| row.get::<_, String>(0),
+        );
+
+        match result {
+            Ok(at) => Ok(chrono::DateTime::parse_from_rfc3339(&at).ok().map(|at| at.with_timezone(&Utc))),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn mark_unrecoverable_audited(&self) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        let now = Utc::now().to_rfc3339();
+        conn.execute(
+            "INSERT OR REPLACE INTO checkpoints (key, value, updated_at) VALUES (?1, ?2, ?2)",
+            params![UNRECOVERABLE_AUDIT_KEY, now],
+        )?;
+        Ok(())
+    }
+
+    /// Messages from `PRAGMA integrity_check` other than `ok`
+    fn sqlite_errors(conn: &Connection) -> Result<Vec<String>> {
+        let mut stmt = conn.prepare("PRAGMA integrity_check")?;
+        let messages = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(messages.into_iter().filter(|m| m != "ok").collect())
+    }
+    
+    /// Reclaims, fees, passive reclaims and failures recorded in `[from, to)`
+    pub fn activity_between(&self, from: chrono::DateTime<Utc>, to: chrono::DateTime<Utc>) -> Result<DailyActivity> {
+        let conn = self.conn.lock().unwrap();
+        let (from, to) = (from.to_rfc3339(), to.to_rfc3339());
+        let window = params![from, to];
+        
+        let (operations, reclaimed_lamports, fee_lamports) = conn.query_row(
+            "SELECT COUNT(*), COALESCE(SUM(reclaimed_amount), 0), COALESCE(SUM(fee_lamports), 0)
+             FROM reclaim_operations
+             WHERE julianday(timestamp) >= julianday(?1) AND julianday(timestamp) < julianday(?2)",
+            window,
+            |row| Ok((row.get::<_, i64>(0)?, row.get(1)?, row.get(2)?)),
+        )?;
+        let (passive_reclaims, passive_reclaimed_lamports) = conn.query_row(
+            "SELECT COUNT(*), COALESCE(SUM(amount), 0)
+             FROM passive_reclaims
+             WHERE review_status = 'Confirmed'
+               AND julianday(timestamp) >= julianday(?1) AND julianday(timestamp) < julianday(?2)",
+            window,
+            |row| Ok((row.get::<_, i64>(0)?, row.get(1)?)),
+        )?;
+        let failures: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM reclaim_failures
+             WHERE julianday(last_failed_at) >= julianday(?1) AND julianday(last_failed_at) < julianday(?2)",
+            window,
+            |row| row.get(0),
+        )?;
+        
+        Ok(DailyActivity {
+            operations: operations as usize,
+            reclaimed_lamports,
+            fee_lamports,
+            passive_reclaims: passive_reclaims as usize,
+            passive_reclaimed_lamports,
+            failures: failures as usize,
+        })
+    }
+    
+    // Daily snapshots
+    
+    /// Record today's totals, replacing any snapshot already taken today
+    pub fn take_snapshot(&self) -> Result<Snapshot> {
+        let snapshot = self.current_totals()?;
+        self.save_snapshot(&snapshot)?;
+        Ok(snapshot)
+    }
+    
+    /// Today's totals as a snapshot, without storing it
+    pub fn current_totals(&self) -> Result<Snapshot> {
+        let conn = self.conn.lock().unwrap();
+        
+        let mut stmt = conn.prepare(
+            "SELECT COALESCE(reclaim_strategy, 'Unknown'), COUNT(*),
+                    SUM(COALESCE(current_lamports, rent_lamports))
+             FROM sponsored_accounts WHERE status = 'Active'
+             GROUP BY 1"
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?, row.get::<_, Option<u64>>(2)?))
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+        
+        let mut locked_by_strategy = std::collections::BTreeMap::new();
+        let mut active_accounts = 0;
+        for (strategy, count, lamports) in rows {
+            active_accounts += count as usize;
+            locked_by_strategy.insert(strategy, lamports.unwrap_or(0));
+        }
+        
+        let reclaimed_lamports: Option<u64> = conn.query_row(
+            "SELECT SUM(reclaimed_amount) FROM reclaim_operations",
+            [],
+            |row| row.get(0),
+        )?;
+        let passive_reclaimed_lamports: Option<u64> = conn.query_row(
+            "SELECT SUM(amount) FROM passive_reclaims WHERE review_status = 'Confirmed'",
+            [],
+            |row| row.get(0),
+        )?;
+        
+        let taken_at = Utc::now();
+        let snapshot = Snapshot {
+            date: taken_at.date_naive(),
+            taken_at,
+            active_accounts,
+            locked_lamports: locked_by_strategy.values().sum(),
+            locked_by_strategy,
+            reclaimed_lamports: reclaimed_lamports.unwrap_or(0),
+            passive_reclaimed_lamports: passive_reclaimed_lamports.unwrap_or(0),
+        };
+        
+        Ok(snapshot)
+    }
+    
+    pub fn save_snapshot(&self, snapshot: &Snapshot) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT OR REPLACE INTO snapshots 
+             (date, taken_at, active_accounts, locked_lamports, locked_by_strategy, 
+              reclaimed_lamports, passive_reclaimed_lamports) 
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                snapshot.date.to_string(),
+                snapshot.taken_at.to_rfc3339(),
+                snapshot.active_accounts as i64,
+                snapshot.locked_lamports,
+                serde_json::to_string(&snapshot.locked_by_strategy)?,
+                snapshot.reclaimed_lamports,
+                snapshot.passive_reclaimed_lamports,
+            ],
+        )?;
+        Ok(())
+    }
+    
+    /// The most recent `limit` snapshots, oldest first
+    pub fn get_snapshots(&self, limit: usize) -> Result<Vec<Snapshot>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT date, taken_at, active_accounts, locked_lamports, locked_by_strategy, 
+                    reclaimed_lamports, passive_reclaimed_lamports 
+             FROM snapshots ORDER BY date DESC LIMIT ?1"
+        )?;
+        
+        let mut snapshots = stmt.query_map(params![limit as i64], Self::row_to_snapshot)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        snapshots.reverse();
+        Ok(snapshots)
+    }
+    
+    /// Latest snapshot taken on or before `date`
+    pub fn get_snapshot_on_or_before(&self, date: chrono::NaiveDate) -> Result<Option<Snapshot>> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT date, taken_at, active_accounts, locked_lamports, locked_by_strategy, 
+                    reclaimed_lamports, passive_reclaimed_lamports 
+             FROM snapshots WHERE date <= ?1 ORDER BY date DESC LIMIT 1",
+            params![date.to_string()],
+            Self::row_to_snapshot,
+        );
+        
+        match result {
+            Ok(snapshot) => Ok(Some(snapshot)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    fn row_to_snapshot(row: &rusqlite::Row) -> rusqlite::Result<Snapshot> {
+        let date: String = row.get(0)?;
+        let taken_at: String = row.get(1)?;
+        let by_strategy: String = row.get(4)?;
+        
+        Ok(Snapshot {
+            date: chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d").unwrap_or_default(),
+            taken_at: chrono::DateTime::parse_from_rfc3339(&taken_at)
+                .map(|dt| dt.with_timezone(&Utc))
+                .unwrap_or_else(|_| Utc::now()),
+            active_accounts: row.get::<_, i64>(2)? as usize,
+            locked_lamports: row.get(3)?,
+            locked_by_strategy: serde_json::from_str(&by_strategy).unwrap_or_default(),
+            reclaimed_lamports: row.get(5)?,
+            passive_reclaimed_lamports: row.get(6)?,
+        })
+    }
+    
+    // Transaction cache used by `AccountDiscovery`
+    
+    /// Creations parsed from a transaction, if cached under the same parser set.
+    ///
+    /// Entries written with a different `parsers` fingerprint are treated as
+    /// misses, since adding a custom program can change what a transaction yields.
+    pub fn get_cached_creations(
+        &self,
+        signature: &str,
+        parsers: &str,
+    ) -> Result<Option<Vec<DiscoveredAccount>>> {
+        let conn = self.conn.lock().unwrap();
+        let result: std::result::Result<String, rusqlite::Error> = conn.query_row(
+            "SELECT creations FROM transaction_cache WHERE signature = ?1 AND parsers = ?2",
+            params![signature, parsers],
+            |row| row.get(0),
+        );
+        
+        match result {
+            Ok(json) => Ok(Some(serde_json::from_str(&json)?)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Store the creations parsed from a transaction (empty results included)
+    pub fn cache_creations(
+        &self,
+        signature: &str,
+        parsers: &str,
+        creations: &[DiscoveredAccount],
+    ) -> Result<()> {
+        let json = serde_json::to_string(creations)?;
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT OR REPLACE INTO transaction_cache (signature, parsers, creations, cached_at) 
+             VALUES (?1, ?2, ?3, ?4)",
+            params![signature, parsers, json, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+    
+    /// Store the evidence behind an attribution; the first one recorded is kept
+    pub fn save_discovery_evidence(&self, evidence: &DiscoveryEvidence) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT OR IGNORE INTO discovery_evidence
+             (account_pubkey, signature, slot, creation_time, instructions, recorded_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                evidence.account_pubkey,
+                evidence.signature,
+                evidence.slot as i64,
+                evidence.creation_time.to_rfc3339(),
+                serde_json::to_string(&evidence.instructions)?,
+                evidence.recorded_at.to_rfc3339(),
+            ],
+        )?;
+        Ok(())
+    }
+    
+    /// Evidence for one account, or for every account when `pubkey` is `None`
+    pub fn get_discovery_evidence(&self, pubkey: Option<&str>) -> Result<Vec<DiscoveryEvidence>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT account_pubkey, signature, slot, creation_time, instructions, recorded_at
+             FROM discovery_evidence
+             WHERE ?1 IS NULL OR account_pubkey = ?1
+             ORDER BY slot"
+        )?;
+        
+        let parse_time = |value: String| {
+            chrono::DateTime::parse_from_rfc3339(&value)
+                .map(|dt| dt.with_timezone(&Utc))
+                .unwrap_or_default()
+        };
+        let evidence = stmt.query_map(params![pubkey], |row| {
+            Ok(DiscoveryEvidence {
+                account_pubkey: row.get(0)?,
+                signature: row.get(1)?,
+                slot: row.get::<_, i64>(2)? as u64,
+                creation_time: parse_time(row.get(3)?),
+                instructions: serde_json::from_str(&row.get::<_, String>(4)?).unwrap_or_default(),
+                recorded_at: parse_time(row.get(5)?),
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+        
+        Ok(evidence)
+    }
+    
+    /// Number of cached transactions
+    pub fn transaction_cache_size(&self) -> Result<usize> {
+        let conn = self.conn.lock().unwrap();
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM transaction_cache", [], |row| row.get(0))?;
+        Ok(count as usize)
+    }
+    
+    /// Drop every cached transaction, returning how many were removed
+    pub fn clear_transaction_cache(&self) -> Result<usize> {
+        let conn = self.conn.lock().unwrap();
+        let removed = conn.execute("DELETE FROM transaction_cache", [])?;
+        Ok(removed)
+    }
+
     /// Save treasury balance checkpoint
     pub fn save_treasury_balance(&self, balance: u64) -> Result<()> {
         let conn = self.conn.lock().unwrap();
@@ -625,65 +2305,174 @@ impl Database {
         let conn = self.conn.lock().unwrap();
         let cutoff = Utc::now() - chrono::Duration::hours(hours);
         
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts WHERE status = 'Closed' AND closed_at > ?1 ORDER BY closed_at DESC",
+            ACCOUNT_COLUMNS
+        ))?;
+        
+        let accounts = stmt.query_map([cutoff.to_rfc3339()], Self::row_to_account)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+        
+        Ok(accounts)
+    }
+
+    /// Whether the close of this creation of `account` was already found not
+    /// to be an external reclaim
+    pub fn is_close_checked(&self, account: &SponsoredAccount) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let found = conn
+            .query_row(
+                "SELECT 1 FROM close_checks WHERE pubkey = ?1 AND creation_signature = ?2",
+                params![account.pubkey, account.creation_signature.as_deref().unwrap_or_default()],
+                |_| Ok(()),
+            )
+            .optional()?;
+        Ok(found.is_some())
+    }
+
+    /// Remember that `close_signature`, which closed `account`, was not the operator's
+    pub fn mark_close_checked(&self, account: &SponsoredAccount, close_signature: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT OR REPLACE INTO close_checks (pubkey, creation_signature, close_signature, checked_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                account.pubkey,
+                account.creation_signature.as_deref().unwrap_or_default(),
+                close_signature,
+                Utc::now().to_rfc3339()
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Closed or reclaimed accounts with a recorded close time
+    pub fn get_ended_accounts(&self) -> Result<Vec<SponsoredAccount>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts WHERE status != 'Active' AND closed_at IS NOT NULL",
+            ACCOUNT_COLUMNS
+        ))?;
+        
+        let accounts = stmt.query_map([], Self::row_to_account)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+        
+        Ok(accounts)
+    }
+    
+    /// Lifetime of closed and reclaimed accounts, grouped by type and strategy
+    pub fn get_lifetime_report(&self) -> Result<LifetimeReport> {
+        Ok(LifetimeReport::from_accounts(&self.get_ended_accounts()?))
+    }
+
+    /// Record a contact with an account's user, returning its id
+    pub fn save_outreach(&self, record: &OutreachRecord) -> Result<i64> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO outreach (account_pubkey, contacted_at, channel, response, notes)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                record.account_pubkey,
+                record.contacted_at.to_rfc3339(),
+                record.channel,
+                record.response.to_string(),
+                record.notes,
+            ],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+    
+    /// Update the user's response to a contact; false if no such id
+    pub fn set_outreach_response(&self, id: i64, response: OutreachResponse) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let updated = conn.execute(
+            "UPDATE outreach SET response = ?2 WHERE id = ?1",
+            params![id, response.to_string()],
+        )?;
+        Ok(updated == 1)
+    }
+    
+    /// Contacts, newest first, optionally for one account only
+    pub fn get_outreach(&self, pubkey: Option<&str>) -> Result<Vec<OutreachRecord>> {
+        let conn = self.conn.lock().unwrap();
         let mut stmt = conn.prepare(
-            "SELECT pubkey, created_at, closed_at, rent_lamports, data_size, status, 
-                    creation_signature, creation_slot, close_authority, reclaim_strategy
-             FROM sponsored_accounts 
-             WHERE status = 'Closed' AND closed_at > ?1
-             ORDER BY closed_at DESC"
+            "SELECT id, account_pubkey, contacted_at, channel, response, notes
+             FROM outreach
+             WHERE ?1 IS NULL OR account_pubkey = ?1
+             ORDER BY contacted_at DESC, id DESC",
         )?;
         
-        let accounts = stmt.query_map([cutoff.to_rfc3339()], |row| {
-            Ok(SponsoredAccount {
-                pubkey: row.get(0)?,
-                created_at: row.get::<_, String>(1)?.parse().unwrap(),
-                closed_at: row.get::<_, Option<String>>(2)?
-                    .map(|s| s.parse().unwrap()),
-                rent_lamports: row.get(3)?,
-                data_size: row.get(4)?,
-                status: AccountStatus::Closed,
-                creation_signature: row.get(6).ok(),
-                creation_slot: row.get::<_, Option<i64>>(7).ok()
-                    .flatten()
-                    .map(|s| s as u64),
-                close_authority: row.get(8).ok(),
-                reclaim_strategy: row.get::<_, Option<String>>(9).ok()
-                    .flatten()
-                    .and_then(|s| ReclaimStrategy::from_str(&s).ok()),
+        let records = stmt.query_map(params![pubkey], |row| {
+            Ok(OutreachRecord {
+                id: row.get(0)?,
+                account_pubkey: row.get(1)?,
+                contacted_at: chrono::DateTime::parse_from_rfc3339(&row.get::<_, String>(2)?)
+                    .map(|t| t.with_timezone(&Utc))
+                    .unwrap_or_default(),
+                channel: row.get(3)?,
+                response: row.get::<_, String>(4)?.parse().unwrap_or_default(),
+                notes: row.get(5)?,
             })
         })?
         .collect::<std::result::Result<Vec<_>, _>>()?;
         
-        Ok(accounts)
+        Ok(records)
     }
-
-    /// Save a passive reclaim event
+    
+    /// How often contacted accounts were closed by their users afterwards
+    pub fn get_outreach_report(&self) -> Result<OutreachReport> {
+        let records = self.get_outreach(None)?;
+        let mut accounts = Vec::new();
+        for pubkey in records.iter().map(|r| r.account_pubkey.as_str()).collect::<std::collections::BTreeSet<_>>() {
+            if let Some(account) = self.get_account_by_pubkey(pubkey)? {
+                accounts.push(account);
+            }
+        }
+        Ok(OutreachReport::from_records(&records, &accounts))
+    }
+    
+    /// Save a passive reclaim event, returning its id.
+    ///
+    /// Low/Unknown confidence attributions are stored as `Pending` review.
     pub fn save_passive_reclaim(
         &self,
         amount: u64,
         attributed_accounts: &[String],
         confidence: &str,
-    ) -> Result<()> {
+    ) -> Result<i64> {
         let conn = self.conn.lock().unwrap();
         conn.execute(
             "INSERT INTO passive_reclaims 
-             (amount, attributed_accounts, confidence, timestamp) 
-             VALUES (?1, ?2, ?3, ?4)",
+             (amount, attributed_accounts, confidence, timestamp, review_status) 
+             VALUES (?1, ?2, ?3, ?4, ?5)",
             params![
                 amount,
                 serde_json::to_string(attributed_accounts)?,
                 confidence,
                 Utc::now().to_rfc3339(),
+                ReviewStatus::for_confidence(confidence).to_string(),
             ],
         )?;
-        Ok(())
+        Ok(conn.last_insert_rowid())
     }
 
-    /// Get total amount passively reclaimed
+    /// Get total amount passively reclaimed (confirmed attributions only)
     pub fn get_total_passive_reclaimed(&self) -> Result<u64> {
         let conn = self.conn.lock().unwrap();
         let total: Option<u64> = conn.query_row(
-            "SELECT SUM(amount) FROM passive_reclaims",
+            "SELECT SUM(amount) FROM passive_reclaims WHERE review_status = 'Confirmed'",
+            [],
+            |row| row.get(0),
+        )?;
+        
+        Ok(total.unwrap_or(0))
+    }
+
+    /// Get total amount of passive reclaims still awaiting review
+    pub fn get_total_pending_passive_reclaimed(&self) -> Result<u64> {
+        let conn = self.conn.lock().unwrap();
+        let total: Option<u64> = conn.query_row(
+            "SELECT SUM(amount) FROM passive_reclaims WHERE review_status = 'Pending'",
             [],
             |row| row.get(0),
         )?;
@@ -696,34 +2485,103 @@ impl Database {
         let conn = self.conn.lock().unwrap();
         let query = if let Some(lim) = limit {
             format!(
-                "SELECT id, amount, attributed_accounts, confidence, timestamp 
+                "SELECT id, amount, attributed_accounts, confidence, timestamp, review_status 
                  FROM passive_reclaims 
                  ORDER BY timestamp DESC 
                  LIMIT {}",
                 lim
             )
         } else {
-            "SELECT id, amount, attributed_accounts, confidence, timestamp 
+            "SELECT id, amount, attributed_accounts, confidence, timestamp, review_status 
              FROM passive_reclaims 
              ORDER BY timestamp DESC".to_string()
         };
         
         let mut stmt = conn.prepare(&query)?;
         
-        let records = stmt.query_map([], |row| {
-            Ok(PassiveReclaimRecord {
-                id: row.get(0)?,
-                amount: row.get(1)?,
-                attributed_accounts: serde_json::from_str(&row.get::<_, String>(2)?).unwrap_or_default(),
-                confidence: row.get(3)?,
-                timestamp: row.get::<_, String>(4)?.parse().unwrap(),
-            })
-        })?
-        .collect::<std::result::Result<Vec<_>, _>>()?;
+        let records = stmt.query_map([], Self::row_to_passive_reclaim)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        
+        Ok(records)
+    }
+
+    /// Get passive reclaims awaiting operator review (oldest first)
+    pub fn get_pending_passive_reclaims(&self) -> Result<Vec<PassiveReclaimRecord>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, amount, attributed_accounts, confidence, timestamp, review_status 
+             FROM passive_reclaims 
+             WHERE review_status = 'Pending'
+             ORDER BY timestamp ASC"
+        )?;
+        
+        let records = stmt.query_map([], Self::row_to_passive_reclaim)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
         
         Ok(records)
     }
 
+    /// Get a single passive reclaim record by id
+    pub fn get_passive_reclaim(&self, id: i64) -> Result<Option<PassiveReclaimRecord>> {
+        let conn = self.conn.lock().unwrap();
+        let result = conn.query_row(
+            "SELECT id, amount, attributed_accounts, confidence, timestamp, review_status 
+             FROM passive_reclaims 
+             WHERE id = ?1",
+            [id],
+            Self::row_to_passive_reclaim,
+        );
+        
+        match result {
+            Ok(record) => Ok(Some(record)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Confirm or reject a passive reclaim attribution awaiting review.
+    /// Returns false when the record already has `status`; a record resolved
+    /// the other way gives `StatusConflict` and an unknown id `NotFound`.
+    pub fn set_passive_reclaim_review(&self, id: i64, status: ReviewStatus) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let updated = conn.execute(
+            "UPDATE passive_reclaims SET review_status = ?1 WHERE id = ?2 AND review_status = 'Pending'",
+            params![status.to_string(), id],
+        )?;
+        if updated > 0 {
+            return Ok(true);
+        }
+
+        let found: Option<String> = conn
+            .query_row(
+                "SELECT review_status FROM passive_reclaims WHERE id = ?1",
+                [id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        match found {
+            None => Err(crate::error::ReclaimError::NotFound(format!("No passive reclaim with id #{}", id))),
+            Some(found) if found == status.to_string() => Ok(false),
+            Some(found) => Err(crate::error::ReclaimError::StatusConflict(format!(
+                "Passive reclaim #{} is already {}",
+                id, found
+            ))),
+        }
+    }
+
+    fn row_to_passive_reclaim(row: &rusqlite::Row) -> rusqlite::Result<PassiveReclaimRecord> {
+        Ok(PassiveReclaimRecord {
+            id: row.get(0)?,
+            amount: row.get(1)?,
+            attributed_accounts: serde_json::from_str(&row.get::<_, String>(2)?).unwrap_or_default(),
+            confidence: row.get(3)?,
+            timestamp: row.get::<_, String>(4)?.parse().unwrap(),
+            review_status: row.get::<_, String>(5)?
+                .parse()
+                .unwrap_or(ReviewStatus::Pending),
+        })
+    }
+
     /// Update account authority information
     pub fn update_account_authority(
         &self,
@@ -744,45 +2602,141 @@ impl Database {
     /// Get accounts by reclaim strategy
     pub fn get_accounts_by_strategy(&self, strategy: &str) -> Result<Vec<SponsoredAccount>> {
         let conn = self.conn.lock().unwrap();
-        let mut stmt = conn.prepare(
-            "SELECT pubkey, created_at, closed_at, rent_lamports, data_size, status, 
-                    creation_signature, creation_slot, close_authority, reclaim_strategy
-             FROM sponsored_accounts 
-             WHERE reclaim_strategy = ?1"
-        )?;
-        
-        let accounts = stmt.query_map([strategy], |row| {
-            let status_str: String = row.get(5)?;
-            let status = match status_str.as_str() {
-                "Active" => AccountStatus::Active,
-                "Closed" => AccountStatus::Closed,
-                "Reclaimed" => AccountStatus::Reclaimed,
-                _ => AccountStatus::Active,
-            };
-            
-            Ok(SponsoredAccount {
-                pubkey: row.get(0)?,
-                created_at: row.get::<_, String>(1)?.parse().unwrap(),
-                closed_at: row.get::<_, Option<String>>(2)?
-                    .map(|s| s.parse().unwrap()),
-                rent_lamports: row.get(3)?,
-                data_size: row.get(4)?,
-                status,
-                creation_signature: row.get(6).ok(),
-                creation_slot: row.get::<_, Option<i64>>(7).ok()
-                    .flatten()
-                    .map(|s| s as u64),
-                close_authority: row.get(8).ok(),
-                reclaim_strategy: row.get::<_, Option<String>>(9).ok()
-                    .flatten()
-                    .and_then(|s| ReclaimStrategy::from_str(&s).ok()),
-            })
-        })?
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts WHERE reclaim_strategy = ?1",
+            ACCOUNT_COLUMNS
+        ))?;
+        
+        let accounts = stmt.query_map([strategy], Self::row_to_account)?
         .collect::<std::result::Result<Vec<_>, _>>()?;
         
         Ok(accounts)
     }
     
+    /// Accounts whose creation transaction has not been seen finalized yet
+    pub fn get_provisional_accounts(&self) -> Result<Vec<SponsoredAccount>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts WHERE status = 'Provisional'",
+            ACCOUNT_COLUMNS
+        ))?;
+
+        let accounts = stmt.query_map([], Self::row_to_account)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(accounts)
+    }
+
+    /// Promote Provisional accounts to Active; returns how many changed
+    pub fn confirm_provisional_accounts(&self, pubkeys: &[String]) -> Result<usize> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let mut confirmed = 0;
+        for pubkey in pubkeys {
+            confirmed += tx.execute(
+                "UPDATE sponsored_accounts SET status = 'Active' WHERE pubkey = ?1 AND status = 'Provisional'",
+                params![pubkey],
+            )?;
+        }
+        tx.commit()?;
+        Ok(confirmed)
+    }
+
+    /// Remove Provisional accounts whose creation never finalized, with the
+    /// discovery evidence recorded for them
+    pub fn delete_provisional_accounts(&self, pubkeys: &[String]) -> Result<usize> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let mut deleted = 0;
+        for pubkey in pubkeys {
+            let removed = tx.execute(
+                "DELETE FROM sponsored_accounts WHERE pubkey = ?1 AND status = 'Provisional'",
+                params![pubkey],
+            )?;
+            if removed > 0 {
+                tx.execute("DELETE FROM discovery_evidence WHERE account_pubkey = ?1", params![pubkey])?;
+            }
+            deleted += removed;
+        }
+        tx.commit()?;
+        Ok(deleted)
+    }
+
+    /// Active accounts that routine cycles check; Unrecoverable ones are left to
+    /// the periodic audit
+    pub fn get_routine_active_accounts(&self) -> Result<Vec<SponsoredAccount>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts
+             WHERE status = 'Active' AND COALESCE(reclaim_strategy, '') != 'Unrecoverable'",
+            ACCOUNT_COLUMNS
+        ))?;
+
+        let accounts = stmt.query_map([], Self::row_to_account)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(accounts)
+    }
+
+    /// Routine Active accounts that reach their hold or `min_inactive_days` of
+    /// age within the next `days` days, soonest first
+    pub fn get_accounts_eligible_within(&self, min_inactive_days: u64, days: u64) -> Result<Vec<SponsoredAccount>> {
+        let conn = self.conn.lock().unwrap();
+        let now = Utc::now();
+        let (from, until) = (now.to_rfc3339(), (now + chrono::Duration::days(days as i64)).to_rfc3339());
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts
+             WHERE status = 'Active' AND COALESCE(reclaim_strategy, '') != 'Unrecoverable'
+               AND julianday(created_at) + COALESCE(min_inactive_days, ?3) > julianday(?1)
+               AND julianday(created_at) + COALESCE(min_inactive_days, ?3) <= julianday(?2)
+             ORDER BY julianday(created_at) + COALESCE(min_inactive_days, ?3)",
+            ACCOUNT_COLUMNS
+        ))?;
+
+        let accounts = stmt.query_map(params![from, until, min_inactive_days as i64], Self::row_to_account)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(accounts)
+    }
+
+    /// Eligible ActiveReclaim accounts holding the most lamports, largest first.
+    /// Dead-lettered accounts are left out until they are requeued.
+    pub fn get_top_reclaimable(&self, min_inactive_days: u64, limit: usize) -> Result<Vec<SponsoredAccount>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts
+             WHERE status = 'Active' AND reclaim_strategy = 'ActiveReclaim'
+               AND julianday(created_at) + COALESCE(min_inactive_days, ?2) <= julianday(?1)
+               AND pubkey NOT IN (SELECT pubkey FROM reclaim_failures WHERE dead_lettered_at IS NOT NULL)
+             ORDER BY COALESCE(current_lamports, rent_lamports) DESC
+             LIMIT ?3",
+            ACCOUNT_COLUMNS
+        ))?;
+
+        let accounts = stmt.query_map(
+            params![Utc::now().to_rfc3339(), min_inactive_days as i64, limit as i64],
+            Self::row_to_account,
+        )?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(accounts)
+    }
+
+    /// Active accounts classified Unrecoverable
+    pub fn get_unrecoverable_accounts(&self) -> Result<Vec<SponsoredAccount>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM sponsored_accounts
+             WHERE status = 'Active' AND reclaim_strategy = 'Unrecoverable'",
+            ACCOUNT_COLUMNS
+        ))?;
+
+        let accounts = stmt.query_map([], Self::row_to_account)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(accounts)
+    }
+
     /// Batch save accounts (more efficient than individual saves)
     pub fn save_accounts_batch(&self, accounts: &[SponsoredAccount]) -> Result<usize> {
         let mut conn = self.conn.lock().unwrap();
@@ -790,33 +2744,7 @@ impl Database {
         let mut saved = 0;
         
         for account in accounts {
-            tx.execute(
-                "INSERT INTO sponsored_accounts 
-                 (pubkey, created_at, closed_at, rent_lamports, data_size, status, creation_signature, creation_slot, close_authority, reclaim_strategy) 
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
-                 ON CONFLICT(pubkey) DO UPDATE SET
-                    created_at = excluded.created_at,
-                    closed_at = excluded.closed_at,
-                    rent_lamports = excluded.rent_lamports,
-                    data_size = excluded.data_size,
-                    status = excluded.status,
-                    creation_signature = excluded.creation_signature,
-                    creation_slot = excluded.creation_slot,
-                    close_authority = excluded.close_authority,
-                    reclaim_strategy = excluded.reclaim_strategy",
-                params![
-                    account.pubkey,
-                    account.created_at.to_rfc3339(),
-                    account.closed_at.map(|dt| dt.to_rfc3339()),
-                    account.rent_lamports,
-                    account.data_size,
-                    format!("{:?}", account.status),
-                    account.creation_signature,
-                    account.creation_slot.map(|s| s as i64),
-                    account.close_authority,
-                    account.reclaim_strategy.as_ref().map(|s| s.to_string()),
-                ],
-            )?;
+            Self::upsert_account(&tx, account)?;
             saved += 1;
         }
         
@@ -843,4 +2771,227 @@ pub struct DatabaseStats {
     pub total_operations: usize,
     pub total_reclaimed: u64,
     pub avg_reclaim_amount: u64,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_active_accounts_by_rent_range() {
+        let db = Database::in_memory().unwrap();
+        let mut saved = Vec::new();
+        for rent in [890_880, 2_039_280, 2_044_280, 2_050_000] {
+            let account = SponsoredAccount::new(Pubkey::new_unique(), rent, 165);
+            db.save_account(&account).unwrap();
+            saved.push(account.pubkey);
+        }
+        db.update_account_status(&saved[2], AccountStatus::Closed).unwrap();
+
+        // Inclusive on both ends, and only Active accounts
+        let mut found: Vec<u64> = db
+            .get_active_accounts_by_rent_range(2_039_280, 2_050_000)
+            .unwrap()
+            .iter()
+            .map(|a| a.rent_lamports)
+            .collect();
+        found.sort();
+        assert_eq!(found, vec![2_039_280, 2_050_000]);
+        assert!(db.get_active_accounts_by_rent_range(3_000_000, 4_000_000).unwrap().is_empty());
+
+        let conn = db.conn.lock().unwrap();
+        let plan: String = conn
+            .query_row(
+                "EXPLAIN QUERY PLAN SELECT pubkey FROM sponsored_accounts
+                 WHERE status = 'Active' AND rent_lamports BETWEEN 1 AND 2",
+                [],
+                |row| row.get(3),
+            )
+            .unwrap();
+        assert!(plan.contains("idx_status_rent"), "{}", plan);
+    }
+
+    #[test]
+    fn test_alert_acknowledgements_round_trip() {
+        let db = Database::in_memory().unwrap();
+        db.acknowledge_alert("scan_failures", "warning").unwrap();
+        db.acknowledge_alert("scan_failures", "critical").unwrap();
+        db.acknowledge_alert("low_treasury", "warning").unwrap();
+        db.clear_alert_acknowledgement("low_treasury").unwrap();
+
+        let acknowledged = db.get_alert_acknowledgements().unwrap();
+        assert_eq!(acknowledged.len(), 1);
+        assert_eq!(acknowledged["scan_failures"], "critical");
+    }
+
+    #[test]
+    fn test_passive_reclaim_review() {
+        let db = Database::in_memory().unwrap();
+        let id = db.save_passive_reclaim(2_039_280, &["acct".to_string()], "Low").unwrap();
+        assert_eq!(db.get_pending_passive_reclaims().unwrap().len(), 1);
+
+        assert!(db.set_passive_reclaim_review(id, ReviewStatus::Confirmed).unwrap());
+        assert!(db.get_pending_passive_reclaims().unwrap().is_empty());
+        assert_eq!(db.get_total_passive_reclaimed().unwrap(), 2_039_280);
+
+        // Repeating the same review is a no-op, reversing it is refused
+        assert!(!db.set_passive_reclaim_review(id, ReviewStatus::Confirmed).unwrap());
+        assert!(matches!(
+            db.set_passive_reclaim_review(id, ReviewStatus::Rejected),
+            Err(crate::error::ReclaimError::StatusConflict(_))
+        ));
+        assert_eq!(
+            db.get_passive_reclaim(id).unwrap().unwrap().review_status,
+            ReviewStatus::Confirmed
+        );
+
+        assert!(matches!(
+            db.set_passive_reclaim_review(id + 1, ReviewStatus::Rejected),
+            Err(crate::error::ReclaimError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_dead_letter_queue() {
+        let db = Database::in_memory().unwrap();
+
+        // Failures below the limit are counted and retried
+        assert!(!db.record_reclaim_failure("acct", "frozen", 3).unwrap());
+        assert!(!db.record_reclaim_failure("acct", "still frozen", 3).unwrap());
+        let failure = db.get_reclaim_failure("acct").unwrap().unwrap();
+        assert_eq!((failure.failures, failure.last_error.as_str()), (2, "still frozen"));
+        assert!(failure.dead_lettered_at.is_none());
+        assert!(db.get_dead_letters().unwrap().is_empty());
+
+        // The third gives up on the account, once
+        assert!(db.record_reclaim_failure("acct", "frozen", 3).unwrap());
+        assert!(!db.record_reclaim_failure("acct", "frozen", 3).unwrap());
+        assert_eq!(db.get_dead_letters().unwrap()[0].failures, 4);
+        assert!(db.get_dead_lettered_pubkeys().unwrap().contains("acct"));
+
+        // A retry clears the record; 0 never dead-letters
+        assert!(db.clear_reclaim_failures("acct").unwrap());
+        assert!(!db.clear_reclaim_failures("acct").unwrap());
+        assert!(db.get_reclaim_failure("acct").unwrap().is_none());
+        for _ in 0..5 {
+            assert!(!db.record_reclaim_failure("other", "frozen", 0).unwrap());
+        }
+        assert!(db.get_dead_lettered_pubkeys().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_hold_survives_updates_but_not_a_new_life() {
+        let db = Database::in_memory().unwrap();
+        let mut account = SponsoredAccount::new(Pubkey::new_unique(), 2_039_280, 165);
+        account.creation_signature = Some("first".to_string());
+        account.creation_slot = Some(100);
+        db.save_account(&account).unwrap();
+        assert!(db.set_min_inactive_days(&account.pubkey, Some(90)).unwrap());
+
+        // A rescan of the same creation keeps the operator's hold
+        account.current_lamports = Some(2_039_280);
+        db.save_account(&account).unwrap();
+        assert_eq!(db.get_min_inactive_days(&account.pubkey).unwrap(), Some(90));
+
+        // A new creation of the address is a different account
+        account.creation_signature = Some("second".to_string());
+        account.creation_slot = Some(200);
+        db.save_account(&account).unwrap();
+        assert_eq!(db.get_min_inactive_days(&account.pubkey).unwrap(), None);
+        assert_eq!(db.get_account_epochs(&account.pubkey).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_holds_override_default_days() {
+        let db = Database::in_memory().unwrap();
+        let track = |age_days: i64, rent: u64, hold: Option<u64>| {
+            let mut account = SponsoredAccount::new(Pubkey::new_unique(), rent, 165);
+            account.created_at = Utc::now() - chrono::Duration::days(age_days);
+            db.save_account(&account).unwrap();
+            db.update_account_authority(&account.pubkey, None, "ActiveReclaim").unwrap();
+            assert!(db.set_min_inactive_days(&account.pubkey, hold).unwrap());
+            account.pubkey
+        };
+        let due_soon = track(25, 1_000_000, None);
+        let held = track(25, 2_000_000, Some(90));
+        let held_long = track(60, 3_000_000, Some(90));
+        let due = track(40, 4_000_000, None);
+        let short_hold = track(10, 5_000_000, Some(5));
+        assert!(!db.set_min_inactive_days("untracked", Some(90)).unwrap());
+
+        let pubkeys = |accounts: Vec<SponsoredAccount>| accounts.into_iter().map(|a| a.pubkey).collect::<Vec<_>>();
+        assert_eq!(pubkeys(db.get_accounts_eligible_within(30, 7).unwrap()), vec![due_soon.clone()]);
+        assert_eq!(
+            pubkeys(db.get_accounts_eligible_within(30, 31).unwrap()),
+            vec![due_soon.clone(), held_long.clone()]
+        );
+        // Largest balance first; a hold shorter than the default makes an account due early
+        assert_eq!(pubkeys(db.get_top_reclaimable(30, 10).unwrap()), vec![short_hold.clone(), due.clone()]);
+        assert_eq!(pubkeys(db.get_top_reclaimable(30, 1).unwrap()), vec![short_hold.clone()]);
+
+        // Cleared holds fall back to the default
+        db.set_min_inactive_days(&held, None).unwrap();
+        db.set_min_inactive_days(&held_long, None).unwrap();
+        assert_eq!(db.get_min_inactive_days(&held).unwrap(), None);
+        let mut soon = pubkeys(db.get_accounts_eligible_within(30, 7).unwrap());
+        soon.sort();
+        let mut expected = vec![due_soon, held];
+        expected.sort();
+        assert_eq!(soon, expected);
+        assert_eq!(pubkeys(db.get_top_reclaimable(30, 10).unwrap()), vec![short_hold, due, held_long]);
+    }
+
+    #[test]
+    fn test_update_account_status_is_guarded() {
+        let db = Database::in_memory().unwrap();
+        let account = SponsoredAccount::new(Pubkey::new_unique(), 2_039_280, 165);
+        db.save_account(&account).unwrap();
+
+        db.update_account_status(&account.pubkey, AccountStatus::Closed).unwrap();
+        db.update_account_status(&account.pubkey, AccountStatus::Reclaimed).unwrap();
+
+        // Nothing moves a Reclaimed account back, and the row is left as it was
+        for status in [AccountStatus::Active, AccountStatus::Closed, AccountStatus::Provisional] {
+            assert!(matches!(
+                db.update_account_status(&account.pubkey, status),
+                Err(crate::error::ReclaimError::StatusConflict(_))
+            ));
+        }
+        assert_eq!(db.get_account_by_pubkey(&account.pubkey).unwrap().unwrap().status, AccountStatus::Reclaimed);
+
+        // Untracked accounts have nothing to conflict with
+        db.update_account_status("untracked", AccountStatus::Reclaimed).unwrap();
+    }
+
+    #[test]
+    fn test_integrity_repair_marks_reclaimed_once() {
+        let db = Database::in_memory().unwrap();
+        let account = SponsoredAccount::new(Pubkey::new_unique(), 2_039_280, 165);
+        db.save_account(&account).unwrap();
+        db.save_reclaim_operation(&ReclaimOperation {
+            id: 0,
+            account_pubkey: account.pubkey.clone(),
+            reclaimed_amount: 2_039_280,
+            tx_signature: "sig".to_string(),
+            timestamp: Utc::now(),
+            reason: ReclaimReason::ManualCli,
+            credited_amount: None,
+            destination: None,
+            fee_lamports: None,
+        })
+        .unwrap();
+
+        let report = db.check_integrity(true).unwrap();
+        assert_eq!(report.unreclaimed_accounts, vec![account.pubkey.clone()]);
+        assert_eq!(report.repaired, 1);
+        assert_eq!(db.get_account_by_pubkey(&account.pubkey).unwrap().unwrap().status, AccountStatus::Reclaimed);
+        assert_eq!(db.check_integrity(true).unwrap().repaired, 0);
+
+        // The repair went through the same transition rules as everything else
+        assert!(matches!(
+            db.update_account_status(&account.pubkey, AccountStatus::Reclaimed),
+            Err(crate::error::ReclaimError::StatusConflict(_))
+        ));
+    }
+}
@@ -0,0 +1,133 @@
+// src/storage/export.rs - CSV exports of stored records
+
+use crate::storage::models::{ReclaimOperation, SponsoredAccount};
+use std::borrow::Cow;
+
+/// Tables that can be exported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportKind {
+    Accounts,
+    Operations,
+}
+
+impl std::fmt::Display for ExportKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportKind::Accounts => write!(f, "accounts"),
+            ExportKind::Operations => write!(f, "operations"),
+        }
+    }
+}
+
+impl std::str::FromStr for ExportKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "accounts" => Ok(ExportKind::Accounts),
+            "operations" => Ok(ExportKind::Operations),
+            _ => Err(()),
+        }
+    }
+}
+
+const ACCOUNT_HEADER: &str = "pubkey,status,created_at,closed_at,rent_lamports,current_lamports,data_size,account_type,reclaim_strategy,close_authority,mint,creation_signature,creation_slot";
+
+const OPERATION_HEADER: &str = "id,account_pubkey,reclaimed_amount,credited_amount,destination,reason,timestamp,tx_signature";
+
+/// Accounts as CSV, split into parts of at most `max_bytes` that each repeat the header
+pub fn accounts_csv(accounts: &[SponsoredAccount], max_bytes: usize) -> Vec<String> {
+    let rows = accounts.iter().map(|account| {
+        csv_row(&[
+            account.pubkey.clone(),
+            format!("{:?}", account.status),
+            account.created_at.to_rfc3339(),
+            optional(account.closed_at.map(|at| at.to_rfc3339())),
+            account.rent_lamports.to_string(),
+            optional(account.current_lamports),
+            account.data_size.to_string(),
+            optional(account.account_type.as_ref()),
+            optional(account.reclaim_strategy.as_ref()),
+            optional(account.close_authority.as_ref()),
+            optional(account.mint.as_ref()),
+            optional(account.creation_signature.as_ref()),
+            optional(account.creation_slot),
+        ])
+    });
+    split_parts(ACCOUNT_HEADER, rows, max_bytes)
+}
+
+/// Reclaim operations as CSV, split like [`accounts_csv`]
+pub fn operations_csv(operations: &[ReclaimOperation], max_bytes: usize) -> Vec<String> {
+    let rows = operations.iter().map(|op| {
+        csv_row(&[
+            op.id.to_string(),
+            op.account_pubkey.clone(),
+            op.reclaimed_amount.to_string(),
+            optional(op.credited_amount),
+            optional(op.destination.as_ref()),
+            op.reason.to_string(),
+            op.timestamp.to_rfc3339(),
+            op.tx_signature.clone(),
+        ])
+    });
+    split_parts(OPERATION_HEADER, rows, max_bytes)
+}
+
+fn optional<T: std::fmt::Display>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn csv_row(fields: &[String]) -> String {
+    fields.iter().map(|field| escape(field)).collect::<Vec<_>>().join(",")
+}
+
+/// Quote fields containing separators, quotes or line breaks
+fn escape(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Group rows into header-led parts; a single oversized row still gets a part of its own
+fn split_parts(header: &str, rows: impl Iterator<Item = String>, max_bytes: usize) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = format!("{}\n", header);
+    let mut has_rows = false;
+
+    for row in rows {
+        if has_rows && current.len() + row.len() + 1 > max_bytes {
+            parts.push(std::mem::replace(&mut current, format!("{}\n", header)));
+        }
+        current.push_str(&row);
+        current.push('\n');
+        has_rows = true;
+    }
+
+    parts.push(current);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_quotes_special_fields() {
+        assert_eq!(escape("plain"), "plain");
+        assert_eq!(escape("a,b"), "\"a,b\"");
+        assert_eq!(escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_split_parts_repeats_header() {
+        let rows = (0..5).map(|i| format!("row{}", i));
+        // header (2 + newline) plus two rows (5 bytes each) per part
+        let parts = split_parts("id", rows, 13);
+
+        assert_eq!(parts, vec!["id\nrow0\nrow1\n", "id\nrow2\nrow3\n", "id\nrow4\n"]);
+        assert_eq!(split_parts("id", std::iter::empty(), 13), vec!["id\n"]);
+    }
+}
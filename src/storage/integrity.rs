@@ -0,0 +1,144 @@
+// src/storage/integrity.rs - Database consistency checks and repairs
+
+use serde::Serialize;
+
+/// Findings of one integrity check, and what was repaired
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IntegrityReport {
+    /// Messages from `PRAGMA integrity_check` other than `ok`
+    pub sqlite_errors: Vec<String>,
+    /// Rows from `PRAGMA foreign_key_check` outside `reclaim_operations`,
+    /// as `table rowid -> parent`
+    pub foreign_key_violations: Vec<String>,
+    /// Accounts with reclaim operations but no `sponsored_accounts` row
+    pub orphaned_operations: Vec<String>,
+    /// Accounts still marked Active although a reclaim operation exists
+    pub unreclaimed_accounts: Vec<String>,
+    /// Transaction signatures recorded by more than one reclaim operation
+    pub duplicate_operations: Vec<String>,
+    /// Problems fixed during this check
+    pub repaired: usize,
+}
+
+impl IntegrityReport {
+    pub fn problem_count(&self) -> usize {
+        self.sqlite_errors.len()
+            + self.foreign_key_violations.len()
+            + self.orphaned_operations.len()
+            + self.unreclaimed_accounts.len()
+            + self.duplicate_operations.len()
+    }
+
+    pub fn is_healthy(&self) -> bool {
+        self.problem_count() == 0
+    }
+
+    /// Problems left after repairs
+    pub fn unresolved(&self) -> usize {
+        self.problem_count().saturating_sub(self.repaired)
+    }
+
+    /// One line for logs and alerts
+    pub fn summary(&self) -> String {
+        if self.is_healthy() {
+            return "database integrity ok".to_string();
+        }
+        let mut parts = Vec::new();
+        let mut push = |count: usize, label: &str| {
+            if count > 0 {
+                parts.push(format!("{} {}", count, label));
+            }
+        };
+        push(self.sqlite_errors.len(), "SQLite integrity error(s)");
+        push(self.foreign_key_violations.len(), "foreign key violation(s)");
+        push(self.orphaned_operations.len(), "account(s) with orphaned operations");
+        push(self.unreclaimed_accounts.len(), "reclaimed account(s) still marked Active");
+        push(self.duplicate_operations.len(), "duplicated reclaim operation(s)");
+        format!("{}; {} repaired", parts.join(", "), self.repaired)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::storage::Database;
+    use crate::storage::models::{AccountStatus, ReclaimOperation, ReclaimReason, SponsoredAccount};
+    use chrono::Utc;
+    use solana_sdk::pubkey::Pubkey;
+
+    fn operation(pubkey: &str) -> ReclaimOperation {
+        ReclaimOperation {
+            id: 0,
+            account_pubkey: pubkey.to_string(),
+            reclaimed_amount: 2_039_280,
+            tx_signature: "sig".to_string(),
+            timestamp: Utc::now(),
+            reason: ReclaimReason::AutoBatch,
+            credited_amount: None,
+            destination: None,
+            fee_lamports: None,
+        }
+    }
+
+    #[test]
+    fn test_orphaned_and_unreclaimed_accounts_are_repaired() {
+        let path = std::env::temp_dir().join(format!("kora-integrity-{}.db", std::process::id()));
+        let db = Database::new(path.to_str().unwrap()).unwrap();
+        let active = SponsoredAccount::new(Pubkey::new_unique(), 2_039_280, 165);
+        db.save_account(&active).unwrap();
+        db.save_reclaim_operation(&operation(&active.pubkey)).unwrap();
+        // Written by a client without foreign key enforcement, e.g. the sqlite3 shell
+        let orphan = Pubkey::new_unique().to_string();
+        let raw = rusqlite::Connection::open(&path).unwrap();
+        raw.execute_batch("PRAGMA foreign_keys = OFF").unwrap();
+        raw.execute(
+            "INSERT INTO reclaim_operations (account_pubkey, reclaimed_amount, tx_signature, timestamp, reason)
+             VALUES (?1, 2039280, 'orphan-sig', ?2, 'manual')",
+            rusqlite::params![orphan, Utc::now().to_rfc3339()],
+        )
+        .unwrap();
+
+        let report = db.check_integrity(false).unwrap();
+        assert_eq!(report.orphaned_operations, std::slice::from_ref(&orphan));
+        assert_eq!(report.unreclaimed_accounts, std::slice::from_ref(&active.pubkey));
+        assert_eq!((report.problem_count(), report.repaired), (2, 0));
+
+        let report = db.check_integrity(true).unwrap();
+        assert_eq!((report.repaired, report.unresolved()), (2, 0));
+        assert_eq!(db.get_account_by_pubkey(&orphan).unwrap().unwrap().status, AccountStatus::Reclaimed);
+        assert_eq!(db.get_account_by_pubkey(&active.pubkey).unwrap().unwrap().status, AccountStatus::Reclaimed);
+
+        assert!(db.check_integrity(false).unwrap().is_healthy());
+        drop((raw, db));
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_duplicate_operations_are_skipped_and_repaired() {
+        let path = std::env::temp_dir().join(format!("kora-duplicates-{}.db", std::process::id()));
+        let db = Database::new(path.to_str().unwrap()).unwrap();
+        let account = SponsoredAccount::new(Pubkey::new_unique(), 2_039_280, 165);
+        db.save_account(&account).unwrap();
+        db.update_account_status(&account.pubkey, AccountStatus::Reclaimed).unwrap();
+
+        assert!(db.save_reclaim_operation(&operation(&account.pubkey)).unwrap());
+        assert!(!db.save_reclaim_operation(&operation(&account.pubkey)).unwrap());
+        assert_eq!(db.get_total_reclaimed().unwrap(), 2_039_280);
+
+        // A database from before the unique index, holding a double-counted reclaim
+        let raw = rusqlite::Connection::open(&path).unwrap();
+        raw.execute_batch(
+            "DROP INDEX idx_operation_signature;
+             INSERT INTO reclaim_operations (account_pubkey, reclaimed_amount, tx_signature, timestamp, reason)
+             SELECT account_pubkey, reclaimed_amount, tx_signature, timestamp, reason FROM reclaim_operations",
+        )
+        .unwrap();
+        assert_eq!(db.check_integrity(false).unwrap().duplicate_operations, vec!["sig".to_string()]);
+
+        let report = db.check_integrity(true).unwrap();
+        assert_eq!((report.repaired, report.unresolved()), (1, 0));
+        assert_eq!(db.get_total_reclaimed().unwrap(), 2_039_280);
+        assert!(raw.execute_batch("INSERT INTO reclaim_operations SELECT * FROM reclaim_operations").is_err());
+        drop((raw, db));
+        std::fs::remove_file(path).ok();
+    }
+}
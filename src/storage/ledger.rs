@@ -0,0 +1,117 @@
+// src/storage/ledger.rs - Hash chain over reclaim operations for tamper evidence
+
+use crate::storage::models::ReclaimOperation;
+use serde::Serialize;
+use solana_sdk::hash::{hashv, Hash};
+
+/// Hash chained by the first operation
+pub fn genesis_hash() -> String {
+    Hash::default().to_string()
+}
+
+/// Hash of an operation chained to the previous entry's hash. Covers every
+/// stored field except the row id, which is only known after the insert.
+pub fn entry_hash(previous: &str, operation: &ReclaimOperation) -> String {
+    let fields = [
+        operation.account_pubkey.clone(),
+        operation.reclaimed_amount.to_string(),
+        operation.tx_signature.clone(),
+        operation.timestamp.to_rfc3339(),
+        operation.reason.to_string(),
+        operation.credited_amount.map(|amount| amount.to_string()).unwrap_or_default(),
+        operation.destination.clone().unwrap_or_default(),
+    ];
+    let mut parts: Vec<&[u8]> = vec![previous.as_bytes()];
+    for field in &fields {
+        // Terminated so no field boundary can be shifted into another
+        parts.push(field.as_bytes());
+        parts.push(b"\0");
+    }
+    hashv(&parts).to_string()
+}
+
+/// Result of walking the chain
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LedgerReport {
+    /// Operations checked
+    pub entries: usize,
+    /// Ids of operations whose stored hash does not match their contents
+    /// and predecessor: edited, inserted outside the bot, or following a
+    /// deleted row
+    pub tampered: Vec<i64>,
+    /// Hash of the last entry
+    pub head: String,
+}
+
+impl LedgerReport {
+    pub fn is_intact(&self) -> bool {
+        self.tampered.is_empty()
+    }
+}
+
+/// Verify operations in insertion order with their stored hashes. Each entry
+/// is checked against its predecessor's stored hash, so one edited row is
+/// reported alone rather than breaking everything after it.
+pub fn verify(entries: &[(ReclaimOperation, Option<String>)]) -> LedgerReport {
+    let mut report = LedgerReport {
+        entries: entries.len(),
+        head: genesis_hash(),
+        ..Default::default()
+    };
+
+    for (operation, stored) in entries {
+        let expected = entry_hash(&report.head, operation);
+        match stored {
+            Some(hash) if *hash == expected => {}
+            _ => report.tampered.push(operation.id),
+        }
+        report.head = stored.clone().unwrap_or(expected);
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::models::ReclaimReason;
+    use chrono::Utc;
+
+    fn chain(count: i64) -> Vec<(ReclaimOperation, Option<String>)> {
+        let mut previous = genesis_hash();
+        (1..=count)
+            .map(|id| {
+                let operation = ReclaimOperation {
+                    id,
+                    account_pubkey: format!("account{}", id),
+                    reclaimed_amount: 2_039_280,
+                    tx_signature: format!("sig{}", id),
+                    timestamp: Utc::now(),
+                    reason: ReclaimReason::AutoBatch,
+                    credited_amount: None,
+                    destination: None,
+                    fee_lamports: None,
+                };
+                previous = entry_hash(&previous, &operation);
+                (operation, Some(previous.clone()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_verify_flags_edited_and_deleted_entries() {
+        let mut entries = chain(4);
+        assert!(verify(&entries).is_intact());
+
+        entries[1].0.reclaimed_amount += 1;
+        assert_eq!(verify(&entries).tampered, vec![2]);
+
+        let mut entries = chain(4);
+        entries.remove(1);
+        assert_eq!(verify(&entries).tampered, vec![3]);
+
+        let mut entries = chain(2);
+        entries[0].1 = None;
+        assert_eq!(verify(&entries).tampered, vec![1]);
+    }
+}
@@ -1,4 +1,11 @@
+pub mod analytics;
 pub mod db;
+pub mod export;
+pub mod integrity;
+pub mod ledger;
 pub mod models;
+pub mod profile;
+pub mod settings;
 
 pub use db::Database;
+pub use settings::RuntimeSettings;
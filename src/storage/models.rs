@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SponsoredAccount {
@@ -14,15 +15,61 @@ pub struct SponsoredAccount {
     pub creation_slot: Option<u64>,
     pub close_authority: Option<String>,
     pub reclaim_strategy: Option<ReclaimStrategy>,
+    /// Last on-chain balance seen by the balance refresher
+    pub current_lamports: Option<u64>,
+    pub balance_checked_at: Option<DateTime<Utc>>,
+    /// How the account was created (None for rows predating type tracking)
+    pub account_type: Option<crate::kora::AccountType>,
+    /// Token mint of SPL Token and Token-2022 accounts
+    pub mint: Option<String>,
+    /// Operator key that sponsored the creation (None for rows predating rotation support)
+    pub operator: Option<String>,
+    /// Hold set with `/hold` or `kora-reclaim hold`: replaces
+    /// `reclaim.min_inactive_days` for this account
+    pub min_inactive_days: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum AccountStatus {
+    /// Discovered, but its creation transaction is not yet finalized; a fork
+    /// may still drop it. Not refreshed or reclaimed until confirmed Active.
+    Provisional,
     Active,
     Closed,
     Reclaimed,
 }
 
+impl AccountStatus {
+    /// Parse the value stored in `sponsored_accounts.status`
+    pub fn from_db(value: &str) -> Option<AccountStatus> {
+        match value {
+            "Provisional" => Some(AccountStatus::Provisional),
+            "Active" => Some(AccountStatus::Active),
+            "Closed" => Some(AccountStatus::Closed),
+            "Reclaimed" => Some(AccountStatus::Reclaimed),
+            _ => None,
+        }
+    }
+
+    /// States an account may move to this one from. A closed address that is
+    /// created again becomes Active through `save_account`, not a status change.
+    pub fn predecessors(&self) -> &'static [AccountStatus] {
+        match self {
+            AccountStatus::Provisional => &[],
+            AccountStatus::Active => &[AccountStatus::Provisional],
+            AccountStatus::Closed => &[AccountStatus::Provisional, AccountStatus::Active],
+            // A scan may see the account gone before the reclaim is recorded
+            AccountStatus::Reclaimed => &[AccountStatus::Active, AccountStatus::Closed],
+        }
+    }
+
+    /// Whether one life of an account may go from this state to `next`.
+    /// Staying in the same state is not a transition.
+    pub fn can_transition_to(&self, next: &AccountStatus) -> bool {
+        next.predecessors().contains(self)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReclaimOperation {
     pub id: i64,
@@ -30,7 +77,91 @@ pub struct ReclaimOperation {
     pub reclaimed_amount: u64,
     pub tx_signature: String,
     pub timestamp: DateTime<Utc>,
-    pub reason: String,
+    pub reason: ReclaimReason,
+    /// Lamports the treasury was verified to receive; `None` if not checked
+    pub credited_amount: Option<i64>,
+    /// Wallet the rent was sent to; `None` for operations recorded before destinations were tracked
+    pub destination: Option<String>,
+    /// Transaction fee paid, read back with the treasury credit; `None` if not checked
+    pub fee_lamports: Option<u64>,
+}
+
+/// What triggered a reclaim, stored in `reclaim_operations.reason_code`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ReclaimReason {
+    /// `kora-reclaim reclaim <pubkey>`
+    ManualCli,
+    /// Enter or batch reclaim in the TUI
+    ManualTui,
+    /// Reclaim requested through the Telegram bot
+    Telegram,
+    /// Auto service batch
+    AutoBatch,
+    /// Re-attempt of a previously failed reclaim
+    Retry,
+    /// Reclaim released after operator approval
+    Approved,
+    /// `KoraReclaim::reclaim` from the library API
+    Library,
+    /// Closed by the operator outside the bot, e.g. with the Solana CLI, and
+    /// found by the passive reclaim check
+    ExternalReclaim,
+    /// Rows written before reasons were structured, with an unrecognized note
+    Unknown,
+}
+
+impl ReclaimReason {
+    pub const ALL: [ReclaimReason; 9] = [
+        ReclaimReason::ManualCli,
+        ReclaimReason::ManualTui,
+        ReclaimReason::Telegram,
+        ReclaimReason::AutoBatch,
+        ReclaimReason::Retry,
+        ReclaimReason::Approved,
+        ReclaimReason::Library,
+        ReclaimReason::ExternalReclaim,
+        ReclaimReason::Unknown,
+    ];
+
+    /// Human-readable description, also written to the legacy `reason` column
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReclaimReason::ManualCli => "Manual CLI reclaim",
+            ReclaimReason::ManualTui => "TUI manual reclaim",
+            ReclaimReason::Telegram => "Telegram reclaim",
+            ReclaimReason::AutoBatch => "Automated batch reclaim",
+            ReclaimReason::Retry => "Retried reclaim",
+            ReclaimReason::Approved => "Approved reclaim",
+            ReclaimReason::Library => "Library reclaim",
+            ReclaimReason::ExternalReclaim => "Reclaimed outside the bot",
+            ReclaimReason::Unknown => "Unknown",
+        }
+    }
+
+    /// Map a free-form reason from before the `reason_code` column existed
+    pub fn from_legacy(reason: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|r| r.label() == reason)
+            .unwrap_or(ReclaimReason::Unknown)
+    }
+}
+
+impl std::fmt::Display for ReclaimReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::str::FromStr for ReclaimReason {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|r| r.to_string().eq_ignore_ascii_case(s))
+            .ok_or(())
+    }
 }
 
 
@@ -43,6 +174,476 @@ pub struct PassiveReclaimRecord {
     pub attributed_accounts: Vec<String>,
     pub confidence: String,
     pub timestamp: DateTime<Utc>,
+    pub review_status: ReviewStatus,
+}
+
+/// Review state of a passive reclaim attribution.
+///
+/// High/Medium confidence matches are confirmed automatically; Low/Unknown
+/// ones stay `Pending` until an operator confirms or rejects them, and are
+/// excluded from headline totals until then.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ReviewStatus {
+    Pending,
+    Confirmed,
+    Rejected,
+}
+
+impl ReviewStatus {
+    /// Initial review state for a freshly detected passive reclaim
+    pub fn for_confidence(confidence: &str) -> Self {
+        match confidence {
+            "High" | "Medium" => ReviewStatus::Confirmed,
+            _ => ReviewStatus::Pending,
+        }
+    }
+}
+
+impl std::fmt::Display for ReviewStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReviewStatus::Pending => write!(f, "Pending"),
+            ReviewStatus::Confirmed => write!(f, "Confirmed"),
+            ReviewStatus::Rejected => write!(f, "Rejected"),
+        }
+    }
+}
+
+impl std::str::FromStr for ReviewStatus {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "Pending" => Ok(ReviewStatus::Pending),
+            "Confirmed" => Ok(ReviewStatus::Confirmed),
+            "Rejected" => Ok(ReviewStatus::Rejected),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Which Telegram notifications a user receives, chosen with `/subscribe`
+/// and `/unsubscribe`. Users without a stored choice get everything.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum NotificationLevel {
+    #[default]
+    Everything,
+    /// High-value reclaim alerts only
+    HighValue,
+    /// Errors and failed reclaims only
+    Errors,
+    Off,
+}
+
+/// What a notification is about, matched against [`NotificationLevel`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationCategory {
+    General,
+    HighValue,
+    Error,
+}
+
+impl std::fmt::Display for NotificationCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationCategory::General => write!(f, "General"),
+            NotificationCategory::HighValue => write!(f, "HighValue"),
+            NotificationCategory::Error => write!(f, "Error"),
+        }
+    }
+}
+
+impl std::str::FromStr for NotificationCategory {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "General" => Ok(NotificationCategory::General),
+            "HighValue" => Ok(NotificationCategory::HighValue),
+            "Error" => Ok(NotificationCategory::Error),
+            _ => Err(()),
+        }
+    }
+}
+
+impl NotificationLevel {
+    pub fn receives(self, category: NotificationCategory) -> bool {
+        match self {
+            NotificationLevel::Everything => true,
+            NotificationLevel::HighValue => category == NotificationCategory::HighValue,
+            NotificationLevel::Errors => category == NotificationCategory::Error,
+            NotificationLevel::Off => false,
+        }
+    }
+}
+
+impl std::fmt::Display for NotificationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationLevel::Everything => write!(f, "Everything"),
+            NotificationLevel::HighValue => write!(f, "HighValue"),
+            NotificationLevel::Errors => write!(f, "Errors"),
+            NotificationLevel::Off => write!(f, "Off"),
+        }
+    }
+}
+
+impl std::str::FromStr for NotificationLevel {
+    type Err = ();
+
+    /// Accepts the variant names as well as `high_value`, `all` and `none`
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let normalized: String = s
+            .chars()
+            .filter(|c| !matches!(c, '_' | '-' | ' '))
+            .collect::<String>()
+            .to_lowercase();
+
+        match normalized.as_str() {
+            "everything" | "all" => Ok(NotificationLevel::Everything),
+            "highvalue" => Ok(NotificationLevel::HighValue),
+            "errors" | "error" => Ok(NotificationLevel::Errors),
+            "off" | "none" => Ok(NotificationLevel::Off),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How a contacted user answered a request to close their account
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum OutreachResponse {
+    #[default]
+    NoResponse,
+    Agreed,
+    Declined,
+    /// The message could not be delivered
+    Unreachable,
+}
+
+impl std::fmt::Display for OutreachResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutreachResponse::NoResponse => write!(f, "NoResponse"),
+            OutreachResponse::Agreed => write!(f, "Agreed"),
+            OutreachResponse::Declined => write!(f, "Declined"),
+            OutreachResponse::Unreachable => write!(f, "Unreachable"),
+        }
+    }
+}
+
+impl std::str::FromStr for OutreachResponse {
+    type Err = String;
+
+    /// Accepts the variant names in any case, plus `none` and `no_response`
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let normalized: String = s
+            .chars()
+            .filter(|c| !matches!(c, '_' | '-' | ' '))
+            .collect::<String>()
+            .to_lowercase();
+
+        match normalized.as_str() {
+            "noresponse" | "none" => Ok(OutreachResponse::NoResponse),
+            "agreed" | "yes" => Ok(OutreachResponse::Agreed),
+            "declined" | "no" => Ok(OutreachResponse::Declined),
+            "unreachable" => Ok(OutreachResponse::Unreachable),
+            _ => Err(format!(
+                "Unknown response '{}' (expected no_response, agreed, declined or unreachable)",
+                s
+            )),
+        }
+    }
+}
+
+/// One contact with a user about closing their sponsored account
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct OutreachRecord {
+    pub id: i64,
+    pub account_pubkey: String,
+    pub contacted_at: DateTime<Utc>,
+    /// Free-form, e.g. `email`, `telegram`, `solana-pay`
+    pub channel: String,
+    pub response: OutreachResponse,
+    pub notes: Option<String>,
+}
+
+/// What discovery saw when it attributed an account to the operator: the
+/// creating transaction and the instructions that reported the account
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DiscoveryEvidence {
+    pub account_pubkey: String,
+    pub signature: String,
+    pub slot: u64,
+    pub creation_time: DateTime<Utc>,
+    /// Instructions as returned by `getTransaction` with jsonParsed encoding
+    pub instructions: Vec<serde_json::Value>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Rent locked in active token accounts of one mint
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MintRent {
+    pub mint: String,
+    pub accounts: usize,
+    pub locked_lamports: u64,
+    /// Part of the locked rent in accounts the operator cannot close, which
+    /// a close-authority arrangement with the mint's issuer could unlock
+    pub passive_lamports: u64,
+}
+
+/// Accounts and reclaims attributed to one operator key
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct OperatorTotals {
+    /// `None` for accounts recorded before operators were
+    pub operator: Option<String>,
+    pub accounts: usize,
+    pub active_accounts: usize,
+    pub locked_lamports: u64,
+    pub reclaimed_lamports: u64,
+}
+
+/// Last use of the wallet owning a sponsored token account. An owner who is
+/// still around can be asked to close it; a churned one is unlikely to ever
+/// return the rent.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct OwnerActivity {
+    pub owner: String,
+    /// Time of the owner's latest transaction; `None` if it has none
+    pub last_active_at: Option<DateTime<Utc>>,
+}
+
+impl OwnerActivity {
+    /// Whole days since the owner's latest transaction
+    pub fn idle_days(&self, now: DateTime<Utc>) -> Option<i64> {
+        self.last_active_at.map(|at| (now - at).num_days())
+    }
+    
+    /// Idle for at least `churn_after_days`; an owner without history counts as churned
+    pub fn is_churned(&self, churn_after_days: u64, now: DateTime<Utc>) -> bool {
+        self.idle_days(now).is_none_or(|days| days >= churn_after_days as i64)
+    }
+}
+
+/// Kora operator client served by a multi-tenant deployment
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Tenant {
+    pub id: String,
+    pub name: Option<String>,
+    pub operator_pubkey: String,
+    pub treasury_wallet: String,
+    pub registered_at: DateTime<Utc>,
+}
+
+/// Instance currently owning a service lease
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaseHolder {
+    pub holder: String,
+    pub heartbeat_at: DateTime<Utc>,
+}
+
+/// Earlier life of an address that was closed and created again, as an ATA
+/// can be. The `sponsored_accounts` row always describes the latest creation.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AccountEpoch {
+    pub pubkey: String,
+    /// 1 for the first creation
+    pub epoch: usize,
+    pub creation_signature: String,
+    pub creation_slot: Option<u64>,
+    pub created_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub rent_lamports: u64,
+    /// Closed or Reclaimed: an address is only created again once closed
+    pub status: AccountStatus,
+}
+
+/// Deterministic reclaim failures recorded for one account
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ReclaimFailure {
+    pub pubkey: String,
+    pub failures: u32,
+    pub last_error: String,
+    pub last_failed_at: DateTime<Utc>,
+    /// Set once the account stopped being retried
+    pub dead_lettered_at: Option<DateTime<Utc>>,
+}
+
+/// High-value reclaim simulated and announced by the auto service, waiting
+/// for `reclaim.staging.delay_minutes` to pass or for an operator's approval
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct StagedReclaim {
+    pub pubkey: String,
+    /// Balance at the simulation
+    pub amount: u64,
+    pub staged_at: DateTime<Utc>,
+    pub execute_after: DateTime<Utc>,
+    pub units_consumed: Option<u64>,
+    /// Why the simulated transaction failed, if it did
+    pub simulation_error: Option<String>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub approved_by: Option<String>,
+}
+
+impl StagedReclaim {
+    /// Whether the auto service may reclaim it now. An approval releases it
+    /// at once; otherwise the delay must pass, and with `require_approval`
+    /// only an approval does.
+    pub fn is_released(&self, now: DateTime<Utc>, require_approval: bool) -> bool {
+        self.approved_at.is_some() || (!require_approval && now >= self.execute_after)
+    }
+}
+
+/// One discovery run, for the scan history in `checkpoints` and the TUI
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ScanSession {
+    pub id: i64,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    /// Resumed from a signature checkpoint rather than a full scan
+    pub incremental: bool,
+    pub accounts_found: usize,
+    /// Why the scan failed, if it did
+    pub error: Option<String>,
+}
+
+impl ScanSession {
+    pub fn duration(&self) -> chrono::Duration {
+        self.finished_at - self.started_at
+    }
+}
+
+/// Failed scans since the last successful one
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct ScanFailureStreak {
+    pub consecutive_failures: u32,
+    /// Most recent scan error, even if a later scan succeeded
+    pub last_error: Option<String>,
+    pub last_failed_at: Option<DateTime<Utc>>,
+}
+
+impl ScanFailureStreak {
+    pub fn is_failing(&self) -> bool {
+        self.consecutive_failures > 0
+    }
+
+    /// Whether the streak reached `reclaim.scan_failure_alert_after` (0 never)
+    pub fn is_escalated(&self, alert_after: u32) -> bool {
+        alert_after > 0 && self.consecutive_failures >= alert_after
+    }
+}
+
+/// What happened over one window of time, for the daily summary
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct DailyActivity {
+    pub operations: usize,
+    pub reclaimed_lamports: u64,
+    /// Fees read back from the reclaim transactions; unverified ones count as zero
+    pub fee_lamports: u64,
+    /// Confirmed passive reclaims
+    pub passive_reclaims: usize,
+    pub passive_reclaimed_lamports: u64,
+    /// Accounts whose latest reclaim failure falls in the window
+    pub failures: usize,
+}
+
+impl DailyActivity {
+    /// Active and passive recovery less the fees spent on it
+    pub fn net_lamports(&self) -> i64 {
+        (self.reclaimed_lamports + self.passive_reclaimed_lamports) as i64 - self.fee_lamports as i64
+    }
+}
+
+/// Daily totals recorded by the snapshot job, for trends and deltas
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Snapshot {
+    pub date: NaiveDate,
+    pub taken_at: DateTime<Utc>,
+    pub active_accounts: usize,
+    /// Lamports held by active accounts (last refreshed balance, else rent)
+    pub locked_lamports: u64,
+    /// `locked_lamports` split by reclaim strategy name ("Unknown" if unset)
+    pub locked_by_strategy: BTreeMap<String, u64>,
+    pub reclaimed_lamports: u64,
+    /// Confirmed passive reclaims
+    pub passive_reclaimed_lamports: u64,
+}
+
+impl Snapshot {
+    /// Active and confirmed passive reclaims to date
+    pub fn reclaimed_total(&self) -> u64 {
+        self.reclaimed_lamports + self.passive_reclaimed_lamports
+    }
+
+    /// Change since an earlier snapshot
+    pub fn delta_since(&self, earlier: &Snapshot) -> SnapshotDelta {
+        SnapshotDelta {
+            since: earlier.date,
+            active_accounts: self.active_accounts as i64 - earlier.active_accounts as i64,
+            locked_lamports: self.locked_lamports as i64 - earlier.locked_lamports as i64,
+            reclaimed_lamports: self.reclaimed_total() as i64 - earlier.reclaimed_total() as i64,
+        }
+    }
+
+    /// Each total next to its value in an earlier snapshot
+    pub fn compare_with(&self, earlier: &Snapshot) -> Vec<MetricChange> {
+        vec![
+            MetricChange::new("active_accounts", earlier.active_accounts as u64, self.active_accounts as u64),
+            MetricChange::new("locked_lamports", earlier.locked_lamports, self.locked_lamports),
+            MetricChange::new("reclaimed_lamports", earlier.reclaimed_lamports, self.reclaimed_lamports),
+            MetricChange::new("passive_reclaimed_lamports", earlier.passive_reclaimed_lamports, self.passive_reclaimed_lamports),
+            MetricChange::new("total_reclaimed_lamports", earlier.reclaimed_total(), self.reclaimed_total()),
+        ]
+    }
+}
+
+/// One total compared between two snapshots (`stats --compare`)
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MetricChange {
+    pub metric: &'static str,
+    pub previous: u64,
+    pub current: u64,
+    pub delta: i64,
+    /// `None` when the earlier value was zero
+    pub percent: Option<f64>,
+}
+
+impl MetricChange {
+    fn new(metric: &'static str, previous: u64, current: u64) -> Self {
+        Self {
+            metric,
+            previous,
+            current,
+            delta: current as i64 - previous as i64,
+            percent: (previous > 0).then(|| (current as f64 - previous as f64) / previous as f64 * 100.0),
+        }
+    }
+
+    /// Whether the metric counts lamports rather than accounts
+    pub fn is_lamports(&self) -> bool {
+        self.metric != "active_accounts"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotDelta {
+    pub since: NaiveDate,
+    pub active_accounts: i64,
+    pub locked_lamports: i64,
+    pub reclaimed_lamports: i64,
+}
+
+impl std::fmt::Display for SnapshotDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sol = |lamports: i64| lamports as f64 / 1_000_000_000.0;
+        write!(
+            f,
+            "since {}: accounts {:+}, locked {:+.4} SOL, reclaimed {:+.4} SOL",
+            self.since,
+            self.active_accounts,
+            sol(self.locked_lamports),
+            sol(self.reclaimed_lamports)
+        )
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -92,8 +693,36 @@ impl SponsoredAccount {
             creation_slot: None,
             close_authority: None,
             reclaim_strategy: None,
+            current_lamports: None,
+            balance_checked_at: None,
+            account_type: None,
+            mint: None,
+            operator: None,
+            min_inactive_days: None,
         }
     }
+
+    /// Best known balance: the refreshed on-chain value, else the creation estimate
+    pub fn balance(&self) -> u64 {
+        self.current_lamports.unwrap_or(self.rent_lamports)
+    }
+
+    /// Days the account must stay inactive: its hold if one is set, else `default`
+    pub fn min_inactive_days(&self, default: u64) -> u64 {
+        self.min_inactive_days.unwrap_or(default)
+    }
+
+    /// When the account is old enough for its hold or `min_inactive_days`; it
+    /// must still be inactive on-chain then to be eligible
+    pub fn eligible_at(&self, min_inactive_days: u64) -> DateTime<Utc> {
+        self.created_at + chrono::Duration::days(self.min_inactive_days(min_inactive_days) as i64)
+    }
+
+    /// Whole days until `eligible_at`, rounded up; 0 once it has passed
+    pub fn days_until_eligible(&self, min_inactive_days: u64, now: DateTime<Utc>) -> i64 {
+        let remaining = self.eligible_at(min_inactive_days) - now;
+        (remaining.num_seconds().max(0) + 86_399) / 86_400
+    }
     
     #[allow(dead_code)]
     pub fn mark_closed(&mut self) {
@@ -105,4 +734,164 @@ impl SponsoredAccount {
     pub fn mark_reclaimed(&mut self) {
         self.status = AccountStatus::Reclaimed;
     }
-}
\ No newline at end of file
+}
+
+impl From<&crate::kora::types::SponsoredAccountInfo> for SponsoredAccount {
+    fn from(info: &crate::kora::types::SponsoredAccountInfo) -> Self {
+        Self {
+            pubkey: info.pubkey.to_string(),
+            created_at: info.created_at,
+            closed_at: None,
+            rent_lamports: info.rent_lamports,
+            data_size: info.data_size,
+            status: AccountStatus::Provisional,
+            creation_signature: Some(info.creation_signature.to_string()),
+            creation_slot: Some(info.creation_slot),
+            close_authority: None,
+            reclaim_strategy: None,
+            current_lamports: None,
+            balance_checked_at: None,
+            account_type: Some(info.account_type.clone()),
+            mint: info.mint.map(|mint| mint.to_string()),
+            operator: Some(info.operator.to_string()),
+            min_inactive_days: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(date: &str, active: usize, locked: u64, reclaimed: u64, passive: u64) -> Snapshot {
+        Snapshot {
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            taken_at: Utc::now(),
+            active_accounts: active,
+            locked_lamports: locked,
+            locked_by_strategy: BTreeMap::new(),
+            reclaimed_lamports: reclaimed,
+            passive_reclaimed_lamports: passive,
+        }
+    }
+
+    #[test]
+    fn test_reclaim_reason_codes_and_legacy_labels() {
+        for reason in ReclaimReason::ALL {
+            assert_eq!(reason.to_string().parse::<ReclaimReason>(), Ok(reason));
+        }
+        assert_eq!(ReclaimReason::from_legacy("Manual CLI reclaim"), ReclaimReason::ManualCli);
+        assert_eq!(ReclaimReason::from_legacy("Automated batch reclaim"), ReclaimReason::AutoBatch);
+        assert_eq!(ReclaimReason::from_legacy("something else"), ReclaimReason::Unknown);
+    }
+
+    #[test]
+    fn test_notification_levels_filter_categories() {
+        use NotificationCategory::*;
+
+        assert!(NotificationLevel::Everything.receives(General));
+        assert!(NotificationLevel::HighValue.receives(HighValue));
+        assert!(!NotificationLevel::HighValue.receives(Error));
+        assert!(NotificationLevel::Errors.receives(Error));
+        assert!(!NotificationLevel::Errors.receives(General));
+        assert!(!NotificationLevel::Off.receives(Error));
+
+        assert_eq!("high_value".parse(), Ok(NotificationLevel::HighValue));
+        assert_eq!("HighValue".parse(), Ok(NotificationLevel::HighValue));
+        assert_eq!("ALL".parse(), Ok(NotificationLevel::Everything));
+        assert_eq!("sometimes".parse::<NotificationLevel>(), Err(()));
+    }
+
+    #[test]
+    fn test_snapshot_delta_counts_passive_reclaims() {
+        let earlier = snapshot("2026-01-01", 10, 5_000_000_000, 1_000_000_000, 0);
+        let later = snapshot("2026-01-08", 7, 3_000_000_000, 2_000_000_000, 500_000_000);
+
+        let delta = later.delta_since(&earlier);
+        assert_eq!(delta.active_accounts, -3);
+        assert_eq!(delta.locked_lamports, -2_000_000_000);
+        assert_eq!(delta.reclaimed_lamports, 1_500_000_000);
+        assert_eq!(
+            delta.to_string(),
+            "since 2026-01-01: accounts -3, locked -2.0000 SOL, reclaimed +1.5000 SOL"
+        );
+    }
+
+    #[test]
+    fn test_status_transitions() {
+        use AccountStatus::*;
+
+        assert!(Provisional.can_transition_to(&Active));
+        assert!(Active.can_transition_to(&Closed));
+        assert!(Active.can_transition_to(&Reclaimed));
+        assert!(Closed.can_transition_to(&Reclaimed));
+        // Two processes reclaiming the same account: the second one conflicts
+        assert!(!Reclaimed.can_transition_to(&Reclaimed));
+        // A rescan must not revive a closed or reclaimed account
+        assert!(!Reclaimed.can_transition_to(&Active));
+        assert!(!Reclaimed.can_transition_to(&Closed));
+        assert!(!Closed.can_transition_to(&Active));
+        assert!(!Active.can_transition_to(&Provisional));
+
+        assert_eq!(AccountStatus::from_db("Reclaimed"), Some(Reclaimed));
+        assert_eq!(AccountStatus::from_db("Gone"), None);
+    }
+
+    #[test]
+    fn test_scan_failure_escalation_threshold() {
+        let streak = |consecutive_failures| ScanFailureStreak { consecutive_failures, ..Default::default() };
+
+        assert!(!streak(0).is_failing());
+        assert!(!streak(2).is_escalated(3));
+        assert!(streak(3).is_escalated(3));
+        // 0 never escalates
+        assert!(!streak(10).is_escalated(0));
+    }
+
+    #[test]
+    fn test_snapshot_comparison_percentages() {
+        let earlier = snapshot("2026-01-01", 10, 5_000_000_000, 1_000_000_000, 0);
+        let later = snapshot("2026-01-08", 12, 4_000_000_000, 1_500_000_000, 500_000_000);
+
+        let changes = later.compare_with(&earlier);
+        let change = |metric: &str| changes.iter().find(|c| c.metric == metric).unwrap();
+
+        assert_eq!((change("active_accounts").delta, change("active_accounts").percent), (2, Some(20.0)));
+        assert!(!change("active_accounts").is_lamports());
+        assert_eq!(change("locked_lamports").percent, Some(-20.0));
+        // Nothing to grow from
+        assert_eq!(change("passive_reclaimed_lamports").percent, None);
+        assert_eq!(change("total_reclaimed_lamports").delta, 1_000_000_000);
+        assert_eq!(change("total_reclaimed_lamports").percent, Some(100.0));
+    }
+
+    #[test]
+    fn test_days_until_eligible_round_up() {
+        let now = Utc::now();
+        let mut account = SponsoredAccount::new(Pubkey::new_unique(), 2_039_280, 165);
+        account.created_at = now - chrono::Duration::days(25) - chrono::Duration::hours(1);
+
+        assert_eq!(account.days_until_eligible(30, now), 5);
+        assert_eq!(account.days_until_eligible(26, now), 1);
+        assert_eq!(account.days_until_eligible(7, now), 0);
+
+        // A hold replaces the configured days
+        account.min_inactive_days = Some(30);
+        assert_eq!(account.days_until_eligible(7, now), 5);
+    }
+
+    #[test]
+    fn test_owner_churn() {
+        let now = Utc::now();
+        let owner = |idle_days: Option<i64>| OwnerActivity {
+            owner: Pubkey::new_unique().to_string(),
+            last_active_at: idle_days.map(|days| now - chrono::Duration::days(days)),
+        };
+
+        assert!(!owner(Some(30)).is_churned(365, now));
+        assert!(owner(Some(400)).is_churned(365, now));
+        assert_eq!(owner(Some(400)).idle_days(now), Some(400));
+        // Never used at all
+        assert!(owner(None).is_churned(365, now));
+    }
+}
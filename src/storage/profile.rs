@@ -0,0 +1,133 @@
+// src/storage/profile.rs - Query latency histograms and the slow-query log
+
+use rusqlite::Connection;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::warn;
+
+/// Upper bounds of the histogram buckets; a last bucket counts slower queries
+pub const BUCKET_BOUNDS_MS: [u64; 7] = [1, 5, 10, 50, 100, 500, 1_000];
+
+/// Distinct statements tracked; the rest are counted together
+const MAX_STATEMENTS: usize = 200;
+const OTHER_STATEMENTS: &str = "(other statements)";
+
+/// Slow-query threshold plus one, in milliseconds; 0 while profiling is off
+static SLOW_QUERY_MS: AtomicU64 = AtomicU64::new(0);
+
+/// Latencies by statement text, for every connection in the process
+static LATENCIES: Mutex<BTreeMap<String, Latencies>> = Mutex::new(BTreeMap::new());
+
+#[derive(Debug, Default)]
+struct Latencies {
+    calls: u64,
+    total: Duration,
+    max: Duration,
+    buckets: [u64; BUCKET_BOUNDS_MS.len() + 1],
+}
+
+impl Latencies {
+    fn record(&mut self, elapsed: Duration) {
+        let ms = elapsed.as_millis() as u64;
+        let bucket = BUCKET_BOUNDS_MS.iter().position(|&bound| ms < bound).unwrap_or(BUCKET_BOUNDS_MS.len());
+        self.calls += 1;
+        self.total += elapsed;
+        self.max = self.max.max(elapsed);
+        self.buckets[bucket] += 1;
+    }
+}
+
+/// Latencies of one statement since profiling started
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct QueryProfile {
+    pub sql: String,
+    pub calls: u64,
+    pub total_ms: f64,
+    pub max_ms: f64,
+    /// Calls per bucket of `BUCKET_BOUNDS_MS`, then those slower than the last bound
+    pub histogram: Vec<u64>,
+}
+
+impl QueryProfile {
+    pub fn mean_ms(&self) -> f64 {
+        if self.calls == 0 { 0.0 } else { self.total_ms / self.calls as f64 }
+    }
+}
+
+/// Profile every query when `slow_query_ms` is set, logging those that take
+/// longer. Applies to connections opened afterwards.
+pub fn configure(slow_query_ms: Option<u64>) {
+    SLOW_QUERY_MS.store(slow_query_ms.map_or(0, |ms| ms + 1), Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    SLOW_QUERY_MS.load(Ordering::Relaxed) > 0
+}
+
+/// Hook the profiler into a newly opened connection, if profiling is on
+pub(crate) fn attach(conn: &mut Connection) {
+    if is_enabled() {
+        conn.profile(Some(record));
+    }
+}
+
+fn record(sql: &str, elapsed: Duration) {
+    let sql = sql.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let threshold = SLOW_QUERY_MS.load(Ordering::Relaxed);
+    if threshold > 0 && elapsed.as_millis() as u64 >= threshold {
+        warn!("Slow query ({} ms): {}", elapsed.as_millis(), sql);
+    }
+
+    let mut latencies = LATENCIES.lock().unwrap();
+    let key = if latencies.len() < MAX_STATEMENTS || latencies.contains_key(&sql) {
+        sql
+    } else {
+        OTHER_STATEMENTS.to_string()
+    };
+    latencies.entry(key).or_default().record(elapsed);
+}
+
+/// Profiled statements, slowest in total first; `None` while profiling is off
+pub fn snapshot() -> Option<Vec<QueryProfile>> {
+    if !is_enabled() {
+        return None;
+    }
+
+    let ms = |duration: Duration| duration.as_secs_f64() * 1_000.0;
+    let mut profiles: Vec<QueryProfile> = LATENCIES
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(sql, latencies)| QueryProfile {
+            sql: sql.clone(),
+            calls: latencies.calls,
+            total_ms: ms(latencies.total),
+            max_ms: ms(latencies.max),
+            histogram: latencies.buckets.to_vec(),
+        })
+        .collect();
+    profiles.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms));
+    Some(profiles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latencies_fill_buckets() {
+        let mut latencies = Latencies::default();
+        latencies.record(Duration::from_micros(300));
+        latencies.record(Duration::from_millis(7));
+        latencies.record(Duration::from_millis(7));
+        latencies.record(Duration::from_secs(2));
+
+        assert_eq!(latencies.calls, 4);
+        assert_eq!(latencies.max, Duration::from_secs(2));
+        assert_eq!(latencies.buckets, [1, 0, 2, 0, 0, 0, 0, 1]);
+    }
+}
@@ -0,0 +1,132 @@
+// src/storage/settings.rs - Runtime overrides edited from Telegram /settings
+
+use crate::config::Config;
+use tracing::{debug, warn};
+
+pub const DRY_RUN: &str = "dry_run";
+pub const AUTO_RECLAIM_ENABLED: &str = "auto_reclaim_enabled";
+pub const NOTIFICATIONS_ENABLED: &str = "notifications_enabled";
+pub const NOTIFY_SCANS: &str = "notify_scans";
+pub const NOTIFY_RECLAIMS: &str = "notify_reclaims";
+pub const NOTIFY_PASSIVE: &str = "notify_passive";
+pub const NOTIFY_SUMMARIES: &str = "notify_summaries";
+pub const ALERT_THRESHOLD_SOL: &str = "alert_threshold_sol";
+
+/// Boolean settings that can be flipped at runtime, with their labels
+pub const TOGGLES: [(&str, &str); 7] = [
+    (DRY_RUN, "Dry run"),
+    (AUTO_RECLAIM_ENABLED, "Auto reclaim"),
+    (NOTIFICATIONS_ENABLED, "Notifications"),
+    (NOTIFY_SCANS, "Scan reports"),
+    (NOTIFY_RECLAIMS, "Reclaim alerts"),
+    (NOTIFY_PASSIVE, "Passive reclaims"),
+    (NOTIFY_SUMMARIES, "Daily summaries"),
+];
+
+/// Alert threshold steps offered by the settings menu (SOL)
+pub const ALERT_THRESHOLD_STEPS: [f64; 7] = [0.001, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0];
+
+/// Settings stored in the `runtime_settings` table.
+///
+/// Each value is `None` unless an operator changed it at runtime; set
+/// values take precedence over the configuration file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RuntimeSettings {
+    pub dry_run: Option<bool>,
+    pub auto_reclaim_enabled: Option<bool>,
+    pub notifications_enabled: Option<bool>,
+    pub notify_scans: Option<bool>,
+    pub notify_reclaims: Option<bool>,
+    pub notify_passive: Option<bool>,
+    pub notify_summaries: Option<bool>,
+    pub alert_threshold_sol: Option<f64>,
+}
+
+impl RuntimeSettings {
+    /// Build from stored key/value rows, ignoring unknown or malformed entries
+    pub fn from_pairs(pairs: &[(String, String)]) -> Self {
+        let mut settings = Self::default();
+
+        for (key, value) in pairs {
+            let flag = || value.parse::<bool>().ok();
+            match key.as_str() {
+                DRY_RUN => settings.dry_run = flag(),
+                AUTO_RECLAIM_ENABLED => settings.auto_reclaim_enabled = flag(),
+                NOTIFICATIONS_ENABLED => settings.notifications_enabled = flag(),
+                NOTIFY_SCANS => settings.notify_scans = flag(),
+                NOTIFY_RECLAIMS => settings.notify_reclaims = flag(),
+                NOTIFY_PASSIVE => settings.notify_passive = flag(),
+                NOTIFY_SUMMARIES => settings.notify_summaries = flag(),
+                ALERT_THRESHOLD_SOL => settings.alert_threshold_sol = value.parse().ok(),
+                other => warn!("Ignoring unknown runtime setting: {}", other),
+            }
+        }
+
+        settings
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Overlay the overrides onto a configuration loaded from file
+    pub fn apply(&self, config: &mut Config) {
+        if let Some(dry_run) = self.dry_run {
+            config.reclaim.dry_run = dry_run;
+        }
+        if let Some(enabled) = self.auto_reclaim_enabled {
+            config.reclaim.auto_reclaim_enabled = enabled;
+        }
+
+        if let Some(telegram) = config.telegram.as_mut() {
+            if let Some(enabled) = self.notifications_enabled {
+                telegram.notifications_enabled = enabled;
+            }
+            if let Some(enabled) = self.notify_scans {
+                telegram.notify_scans = enabled;
+            }
+            if let Some(enabled) = self.notify_reclaims {
+                telegram.notify_reclaims = enabled;
+            }
+            if let Some(enabled) = self.notify_passive {
+                telegram.notify_passive = enabled;
+            }
+            if let Some(enabled) = self.notify_summaries {
+                telegram.notify_summaries = enabled;
+            }
+            if let Some(threshold) = self.alert_threshold_sol {
+                telegram.alert_threshold_sol = threshold;
+            }
+        }
+    }
+}
+
+/// The file configuration with the stored runtime settings applied
+pub fn effective_config(file_config: &Config) -> Config {
+    let mut config = file_config.clone();
+    match crate::storage::Database::new(&config.database.path).and_then(|db| db.get_runtime_settings()) {
+        Ok(settings) => {
+            if !settings.is_empty() {
+                debug!("Applying runtime settings: {:?}", settings);
+            }
+            settings.apply(&mut config);
+        }
+        Err(e) => warn!("Failed to load runtime settings: {}", e),
+    }
+    config
+}
+
+/// Current value of a toggle in an (already overridden) configuration
+pub fn toggle_value(config: &Config, key: &str) -> Option<bool> {
+    let telegram = config.telegram.as_ref();
+    match key {
+        DRY_RUN => Some(config.reclaim.dry_run),
+        AUTO_RECLAIM_ENABLED => Some(config.reclaim.auto_reclaim_enabled),
+        NOTIFICATIONS_ENABLED => telegram.map(|t| t.notifications_enabled),
+        NOTIFY_SCANS => telegram.map(|t| t.notify_scans),
+        NOTIFY_RECLAIMS => telegram.map(|t| t.notify_reclaims),
+        NOTIFY_PASSIVE => telegram.map(|t| t.notify_passive),
+        NOTIFY_SUMMARIES => telegram.map(|t| t.notify_summaries),
+        _ => None,
+    }
+}
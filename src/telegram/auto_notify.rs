@@ -2,13 +2,33 @@
 
 use teloxide::prelude::*;
 use teloxide::types::{ChatId, ParseMode};
-use tracing::{info, error};
+use tokio::sync::broadcast;
+use tracing::{info, error, warn};
 use crate::config::Config;
+use crate::events::{next_event, ReclaimEvent};
+use crate::storage::{Database, RuntimeSettings};
+use crate::storage::models::{DailyActivity, NotificationCategory, NotificationLevel};
+use crate::telegram::quiet_hours::QuietHours;
+use crate::telegram::templates;
+
+/// Telegram rejects messages longer than 4096 characters
+const MAX_MESSAGE_LEN: usize = 4000;
+
+/// How often the subscriber checks whether quiet hours ended
+const DIGEST_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
 
 pub struct AutoNotifier {
     bot: Bot,
     chat_ids: Vec<i64>,
     enabled: bool,
+    notify_scans: bool,
+    notify_reclaims: bool,
+    notify_passive: bool,
+    notify_summaries: bool,
+    /// Per-user `/subscribe` choices and the quiet-hours queue; without it
+    /// everyone gets everything immediately
+    database: Option<Database>,
+    quiet_hours: Option<QuietHours>,
 }
 
 impl AutoNotifier {
@@ -32,153 +52,213 @@ impl AutoNotifier {
 
             info!("Auto-notifier initialized for {} users", chat_ids.len());
 
+            let database = match Database::new(&config.database.path) {
+                Ok(db) => Some(db),
+                Err(e) => {
+                    warn!("Notification preferences unavailable, notifying all users: {}", e);
+                    None
+                }
+            };
+
+            let quiet_hours = telegram_config.quiet_hours.as_ref().and_then(|quiet| {
+                QuietHours::from_config(quiet)
+                    .map_err(|e| warn!("Ignoring quiet hours: {}", e))
+                    .ok()
+            });
+
             Some(Self {
                 bot,
                 chat_ids,
                 enabled: true,
+                notify_scans: telegram_config.notify_scans,
+                notify_reclaims: telegram_config.notify_reclaims,
+                notify_passive: telegram_config.notify_passive,
+                notify_summaries: telegram_config.notify_summaries,
+                database,
+                quiet_hours,
             })
         } else {
             None
         }
     }
 
-    /// Send message to all authorized users
-    async fn send_message(&self, message: &str) {
+    /// Send message to every authorized user whose preference covers `category`.
+    ///
+    /// During quiet hours only errors go out; everything else is queued for
+    /// the digest sent once the window ends.
+    async fn send_message(&self, category: NotificationCategory, message: &str) {
         if !self.enabled {
             return;
         }
 
-        for chat_id in &self.chat_ids {
-            match self.bot
-                .send_message(ChatId(*chat_id), message)
-                .parse_mode(ParseMode::MarkdownV2)
-                .await
-            {
-                Ok(_) => {
-                    info!("Notification sent to chat {}", chat_id);
-                }
-                Err(e) => {
-                    error!("Failed to send Telegram message to {}: {}", chat_id, e);
+        if category != NotificationCategory::Error && self.is_quiet() {
+            if let Some(db) = &self.database {
+                match db.queue_notification(category, message) {
+                    Ok(()) => return,
+                    Err(e) => error!("Failed to queue notification, sending now: {}", e),
                 }
             }
+        } else {
+            self.deliver_digest().await;
+        }
+
+        let levels = self.notification_levels();
+        for chat_id in &self.chat_ids {
+            let level = levels.get(chat_id).copied().unwrap_or_default();
+            if level.receives(category) {
+                self.deliver(*chat_id, message).await;
+            }
+        }
+    }
+
+    fn is_quiet(&self) -> bool {
+        self.quiet_hours.as_ref().is_some_and(|quiet| quiet.is_quiet_now())
+    }
+
+    fn notification_levels(&self) -> std::collections::HashMap<i64, NotificationLevel> {
+        match &self.database {
+            Some(db) => db.get_notification_levels().unwrap_or_else(|e| {
+                error!("Failed to load notification preferences: {}", e);
+                Default::default()
+            }),
+            None => Default::default(),
+        }
+    }
+
+    /// Send notifications queued during quiet hours, if the window has ended
+    pub async fn deliver_digest(&self) {
+        let Some(db) = &self.database else {
+            return;
+        };
+        if !self.enabled || self.is_quiet() {
+            return;
+        }
+
+        let queued = match db.take_queued_notifications() {
+            Ok(queued) if !queued.is_empty() => queued,
+            Ok(_) => return,
+            Err(e) => {
+                error!("Failed to load queued notifications: {}", e);
+                return;
+            }
+        };
+
+        info!("Quiet hours over, delivering {} queued notifications", queued.len());
+
+        let levels = self.notification_levels();
+        for chat_id in &self.chat_ids {
+            let level = levels.get(chat_id).copied().unwrap_or_default();
+            let messages: Vec<&str> = queued
+                .iter()
+                .filter(|(category, _)| level.receives(*category))
+                .map(|(_, message)| message.as_str())
+                .collect();
+
+            if messages.is_empty() {
+                continue;
+            }
+            for part in digest_parts(&templates::digest_header(messages.len()), &messages) {
+                self.deliver(*chat_id, &part).await;
+            }
+        }
+    }
+
+    async fn deliver(&self, chat_id: i64, message: &str) {
+        match self.bot
+            .send_message(ChatId(chat_id), message)
+            .parse_mode(ParseMode::MarkdownV2)
+            .await
+        {
+            Ok(_) => {
+                info!("Notification sent to chat {}", chat_id);
+                crate::events::delivery::record_delivery("telegram", Ok(()));
+            }
+            Err(e) => {
+                error!("Failed to send Telegram message to {}: {}", chat_id, e);
+                crate::events::delivery::record_delivery("telegram", Err(e.to_string()));
+            }
         }
     }
 
     /// Send passive reclaim notification
     pub async fn notify_passive_reclaim(
         &self,
+        id: Option<i64>,
         amount: u64,
         accounts: &[String],
         confidence: &str,
     ) {
-        if !self.enabled {
+        if !self.enabled || !self.notify_passive {
             return;
         }
-        
-        let sol_amount = crate::solana::rent::RentCalculator::lamports_to_sol(amount);
-        
-        let accounts_str = if accounts.len() <= 3 {
-            accounts.iter()
-                .map(|a| {
-                    // Format pubkey for display
-                    let short = if a.len() > 12 {
-                        format!("{}...{}", &a[..6], &a[a.len()-6..])
-                    } else {
-                        a.clone()
-                    };
-                    format!("• `{}`", short)
-                })
-                .collect::<Vec<_>>()
-                .join("\n")
-        } else {
-            format!("{} accounts", accounts.len())
-        };
-        
-        let message = format!(
-            "🔄 *Passive Reclaim Detected*\n\n\
-             Amount: *{:.9} SOL*\n\
-             Confidence: {}\n\
-             Likely from:\n{}\n\n\
-             This rent returned to treasury when the user closed their account.",
-            sol_amount,
-            confidence,
-            accounts_str
-        );
-        
-        self.send_message(&message).await;
+
+        let message = templates::passive_reclaim(id, amount, accounts, confidence);
+        self.send_message(NotificationCategory::General, &message).await;
+    }
+
+    /// Tell admins a PassiveMonitoring account was closed by its user
+    pub async fn notify_passive_account_closed(&self, pubkey: &str, amount: u64) {
+        if !self.enabled || !self.notify_passive {
+            return;
+        }
+
+        let message = templates::passive_account_closed(pubkey, amount);
+        self.send_message(NotificationCategory::General, &message).await;
     }
 
     /// Send scan complete notification
     pub async fn notify_scan_complete(&self, total: usize, eligible: usize) {
-        if !self.enabled {
+        if !self.enabled || !self.notify_scans {
             return;
         }
 
-        let message = format!(
-            "🔍 *Scan Complete*\n\n\
-            📊 Total sponsored accounts: {}\n\
-            ✅ Eligible for reclaim: {}\n\n\
-            _Automated scan completed successfully_",
-            total, eligible
-        );
-
-        self.send_message(&message).await;
+        let message = templates::scan_complete(total, eligible);
+        self.send_message(NotificationCategory::General, &message).await;
     }
 
     /// Send reclaim success notification
     pub async fn notify_reclaim_success(&self, pubkey: &str, amount: u64) {
-        if !self.enabled {
+        if !self.enabled || !self.notify_reclaims {
             return;
         }
 
-        let sol_amount = crate::solana::rent::RentCalculator::lamports_to_sol(amount);
-        let message = format!(
-            "✅ *Reclaim Successful*\n\n\
-            Account: `{}`\n\
-            Amount: *{:.9} SOL*\n\n\
-            _Rent successfully reclaimed to treasury_",
-            Self::format_pubkey(pubkey),
-            sol_amount
-        );
-
-        self.send_message(&message).await;
+        let message = templates::reclaim_success(pubkey, amount);
+        self.send_message(NotificationCategory::General, &message).await;
     }
 
     /// Send reclaim failure notification
-    pub async fn notify_reclaim_failed(&self, pubkey: &str, error: &str) {
-        if !self.enabled {
+    pub async fn notify_reclaim_failed(&self, pubkey: &str, error: &str, retryable: bool) {
+        if !self.enabled || !self.notify_reclaims {
             return;
         }
 
-        let message = format!(
-            "❌ *Reclaim Failed*\n\n\
-            Account: `{}`\n\
-            Error: {}\n\n\
-            _Check logs for more details_",
-            Self::format_pubkey(pubkey),
-            error
-        );
-
-        self.send_message(&message).await;
+        let message = templates::reclaim_failed(pubkey, error, retryable);
+        self.send_message(NotificationCategory::Error, &message).await;
     }
 
     /// Send batch complete notification
-    pub async fn notify_batch_complete(&self, successful: usize, failed: usize, total_sol: f64) {
-        if !self.enabled {
+    pub async fn notify_batch_planned(
+        &self,
+        transactions: usize,
+        estimated_cost: u64,
+        expected_recovery: u64,
+        aborted: bool,
+    ) {
+        if !self.enabled || !self.notify_reclaims {
             return;
         }
 
-        let emoji = if failed == 0 { "🎉" } else { "📦" };
-        let message = format!(
-            "{} *Batch Reclaim Complete*\n\n\
-            ✅ Successful: {}\n\
-            ❌ Failed: {}\n\
-            💰 Total reclaimed: *{:.9} SOL*\n\n\
-            _Automated batch processing completed_",
-            emoji, successful, failed, total_sol
-        );
+        let message = templates::batch_planned(transactions, estimated_cost, expected_recovery, aborted);
+        self.send_message(NotificationCategory::General, &message).await;
+    }
+
+    pub async fn notify_batch_complete(&self, successful: usize, failed: usize, total_reclaimed: u64) {
+        if !self.enabled || !self.notify_reclaims {
+            return;
+        }
 
-        self.send_message(&message).await;
+        let message = templates::batch_complete(successful, failed, total_reclaimed);
+        self.send_message(NotificationCategory::General, &message).await;
     }
 
     /// Send error notification
@@ -187,19 +267,59 @@ impl AutoNotifier {
             return;
         }
 
-        let message = format!(
-            "⚠️ *Error Occurred*\n\n\
-            {}\n\n\
-            _Please check the system logs_",
-            error_msg
-        );
+        let message = templates::error(error_msg);
+        self.send_message(NotificationCategory::Error, &message).await;
+    }
+
+    /// Scan failures are warnings until the streak escalates to an error,
+    /// which reaches error-only subscribers and skips quiet hours
+    pub async fn notify_scan_failed(&self, error: &str, consecutive_failures: u32, escalated: bool) {
+        if !self.enabled {
+            return;
+        }
+
+        let message = templates::scan_failed(error, consecutive_failures, escalated);
+        let category = if escalated { NotificationCategory::Error } else { NotificationCategory::General };
+        self.send_message(category, &message).await;
+    }
+
+    /// One alert per RPC outage, and one when it ends; both are errors so
+    /// error-only subscribers hear the outage is over
+    pub async fn notify_rpc_health(&self, error: Option<&str>, down_minutes: i64) {
+        if !self.enabled {
+            return;
+        }
 
-        self.send_message(&message).await;
+        let message = match error {
+            Some(error) => templates::rpc_degraded(error, down_minutes),
+            None => templates::rpc_recovered(down_minutes),
+        };
+        self.send_message(NotificationCategory::Error, &message).await;
+    }
+
+    /// A daemon service restart is an error: the service was down
+    pub async fn notify_service_restarted(&self, service: &str, error: &str, consecutive: u32, backoff_seconds: u64) {
+        if !self.enabled {
+            return;
+        }
+
+        let message = templates::service_restarted(service, error, consecutive, backoff_seconds);
+        self.send_message(NotificationCategory::Error, &message).await;
+    }
+
+    /// Alert that a reclaim did not credit the treasury as expected
+    pub async fn notify_credit_mismatch(&self, pubkey: &str, signature: &str, expected: u64, credited: i64) {
+        if !self.enabled {
+            return;
+        }
+
+        let message = templates::credit_mismatch(pubkey, signature, expected, credited);
+        self.send_message(NotificationCategory::Error, &message).await;
     }
 
     /// Send high-value alert (only if threshold exceeded)
     pub async fn notify_high_value_reclaim(&self, pubkey: &str, amount: u64, threshold_sol: f64) {
-        if !self.enabled {
+        if !self.enabled || !self.notify_reclaims {
             return;
         }
 
@@ -209,44 +329,200 @@ impl AutoNotifier {
             return; // Don't notify if below threshold
         }
 
-        let message = format!(
-            "💎 *High-Value Reclaim*\n\n\
-            Account: `{}`\n\
-            Amount: *{:.9} SOL*\n\n\
-            ⚠️ _This exceeds your alert threshold of {:.2} SOL_",
-            Self::format_pubkey(pubkey),
-            sol_amount,
-            threshold_sol
-        );
+        let message = templates::high_value_reclaim(pubkey, amount, threshold_sol);
+        self.send_message(NotificationCategory::HighValue, &message).await;
+    }
+
+    /// Announce a staged high-value reclaim, so it can be denied (or
+    /// approved) before it runs
+    pub async fn notify_reclaim_staged(
+        &self,
+        pubkey: &str,
+        amount: u64,
+        execute_after: &chrono::DateTime<chrono::Utc>,
+        requires_approval: bool,
+        units_consumed: Option<u64>,
+        simulation_error: Option<&str>,
+    ) {
+        if !self.enabled {
+            return;
+        }
 
-        self.send_message(&message).await;
+        let message =
+            templates::reclaim_staged(pubkey, amount, execute_after, requires_approval, units_consumed, simulation_error);
+        self.send_message(NotificationCategory::HighValue, &message).await;
     }
 
     /// Send daily summary
-    pub async fn notify_daily_summary(&self, total_reclaimed: u64, operations: usize) {
-        if !self.enabled {
+    pub async fn notify_daily_summary(
+        &self,
+        activity: &DailyActivity,
+        previous: &DailyActivity,
+        locked_lamports: u64,
+        locked_change: Option<i64>,
+        sol_usd: Option<f64>,
+    ) {
+        if !self.enabled || !self.notify_summaries {
             return;
         }
 
-        let sol_amount = crate::solana::rent::RentCalculator::lamports_to_sol(total_reclaimed);
-        let message = format!(
-            "📈 *Daily Summary*\n\n\
-            Operations: {}\n\
-            Total reclaimed: *{:.9} SOL*\n\n\
-            _Last 24 hours of activity_",
-            operations,
-            sol_amount
-        );
+        let message = templates::daily_summary(activity, previous, locked_lamports, locked_change, sol_usd);
+        self.send_message(NotificationCategory::General, &message).await;
+    }
+
+    /// Send the notification matching a pipeline event, if it has one
+    pub async fn handle(&self, event: &ReclaimEvent, alert_threshold_sol: f64) {
+        match event {
+            ReclaimEvent::ScanCompleted { total, eligible } => {
+                self.notify_scan_complete(*total, *eligible).await;
+            }
+            ReclaimEvent::ReclaimSucceeded { pubkey, amount, batched, .. } => {
+                if !batched {
+                    self.notify_reclaim_success(pubkey, *amount).await;
+                }
+                self.notify_high_value_reclaim(pubkey, *amount, alert_threshold_sol).await;
+            }
+            ReclaimEvent::ReclaimFailed { pubkey, error, retryable } => {
+                self.notify_reclaim_failed(pubkey, error, *retryable).await;
+            }
+            ReclaimEvent::CreditMismatch { pubkey, signature, expected, credited } => {
+                self.notify_credit_mismatch(pubkey, signature, *expected, *credited).await;
+            }
+            ReclaimEvent::BatchPlanned { transactions, estimated_cost, expected_recovery, aborted } => {
+                self.notify_batch_planned(*transactions, *estimated_cost, *expected_recovery, *aborted).await;
+            }
+            ReclaimEvent::BatchCompleted { successful, failed, total_reclaimed } => {
+                self.notify_batch_complete(*successful, *failed, *total_reclaimed).await;
+            }
+            ReclaimEvent::PassiveAccountClosed { pubkey, amount } => {
+                self.notify_passive_account_closed(pubkey, *amount).await;
+            }
+            ReclaimEvent::PassiveReclaimDetected { id, amount, accounts, confidence } => {
+                self.notify_passive_reclaim(*id, *amount, accounts, confidence).await;
+            }
+            ReclaimEvent::DailySummary { activity, previous, locked_lamports, locked_change, sol_usd } => {
+                self.notify_daily_summary(activity, previous, *locked_lamports, *locked_change, *sol_usd)
+                    .await;
+            }
+            ReclaimEvent::ScanFailed { error, consecutive_failures, escalated } => {
+                self.notify_scan_failed(error, *consecutive_failures, *escalated).await;
+            }
+            ReclaimEvent::ReclaimStaged {
+                pubkey,
+                amount,
+                execute_after,
+                requires_approval,
+                units_consumed,
+                simulation_error,
+            } => {
+                self.notify_reclaim_staged(
+                    pubkey,
+                    *amount,
+                    execute_after,
+                    *requires_approval,
+                    *units_consumed,
+                    simulation_error.as_deref(),
+                )
+                .await;
+            }
+            ReclaimEvent::RpcDegraded { error, down_minutes } => {
+                self.notify_rpc_health(Some(error), *down_minutes).await;
+            }
+            ReclaimEvent::RpcRecovered { down_minutes } => {
+                self.notify_rpc_health(None, *down_minutes).await;
+            }
+            ReclaimEvent::ServiceRestarted { service, error, consecutive, backoff_seconds } => {
+                self.notify_service_restarted(service, error, *consecutive, *backoff_seconds).await;
+            }
+            ReclaimEvent::Error { message } => self.notify_error(message).await,
+            ReclaimEvent::AccountEligible { .. } => {}
+        }
+    }
+
+}
 
-        self.send_message(&message).await;
+/// Event bus subscriber delivering notifications to Telegram.
+///
+/// Runtime settings are re-read for every event, so /settings changes apply
+/// to long-running services without a restart.
+pub async fn run_subscriber(file_config: Config, mut rx: broadcast::Receiver<ReclaimEvent>) {
+    if file_config.telegram.is_none() {
+        return;
     }
 
-    /// Format pubkey for display
-    fn format_pubkey(pubkey: &str) -> String {
-        if pubkey.len() <= 12 {
-            pubkey.to_string()
+    let mut cached: Option<(RuntimeSettings, Option<AutoNotifier>, f64)> = None;
+    let mut digest_check = tokio::time::interval(DIGEST_CHECK_INTERVAL);
+
+    loop {
+        tokio::select! {
+            event = next_event(&mut rx, "telegram") => {
+                let Some(event) = event else {
+                    break;
+                };
+                if let Some((notifier, threshold)) = current_notifier(&file_config, &mut cached) {
+                    notifier.handle(&event, threshold).await;
+                }
+            }
+            _ = digest_check.tick() => {
+                if let Some((notifier, _)) = current_notifier(&file_config, &mut cached) {
+                    notifier.deliver_digest().await;
+                }
+            }
+        }
+    }
+}
+
+/// Notifier for the current runtime settings, rebuilt when they change
+fn current_notifier<'a>(
+    file_config: &Config,
+    cached: &'a mut Option<(RuntimeSettings, Option<AutoNotifier>, f64)>,
+) -> Option<(&'a AutoNotifier, f64)> {
+    let runtime = Database::new(&file_config.database.path)
+        .and_then(|db| db.get_runtime_settings())
+        .unwrap_or_default();
+
+    if cached.as_ref().map(|(settings, ..)| settings) != Some(&runtime) {
+        let mut config = file_config.clone();
+        runtime.apply(&mut config);
+        let threshold = config.telegram.as_ref().map_or(0.0, |t| t.alert_threshold_sol);
+        *cached = Some((runtime, AutoNotifier::new(&config), threshold));
+    }
+
+    match cached {
+        Some((_, Some(notifier), threshold)) => Some((notifier, *threshold)),
+        _ => None,
+    }
+}
+
+/// Join queued messages under `header`, split to stay within Telegram's size limit
+fn digest_parts(header: &str, messages: &[&str]) -> Vec<String> {
+    let mut parts = vec![header.to_string()];
+
+    for message in messages {
+        let current = parts.last_mut().expect("parts starts non-empty");
+        if current.chars().count() + message.chars().count() + 2 > MAX_MESSAGE_LEN {
+            parts.push(message.to_string());
         } else {
-            format!("{}...{}", &pubkey[..8], &pubkey[pubkey.len()-8..])
+            current.push_str("\n\n");
+            current.push_str(message);
         }
     }
-}
\ No newline at end of file
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digest_parts_respect_message_limit() {
+        let long = "x".repeat(MAX_MESSAGE_LEN / 2);
+        let parts = digest_parts("header", &["short", &long, &long]);
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0], format!("header\n\nshort\n\n{}", long));
+        assert_eq!(parts[1], long);
+        assert!(parts.iter().all(|p| p.chars().count() <= MAX_MESSAGE_LEN));
+    }
+}
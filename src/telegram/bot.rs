@@ -1,11 +1,15 @@
 // src/telegram/bot.rs - Complete Rewrite
 
 use teloxide::{prelude::*, utils::command::BotCommands};
+use std::collections::HashMap;
 use std::sync::Arc;
+use teloxide::types::MessageId;
 use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
 use crate::config::Config;
 use crate::solana::SolanaRpcClient;
 use crate::storage::Database;
+use crate::telegram::throttle::CommandThrottle;
 use tracing::{info, error};
 
 /// State shared across all bot handlers
@@ -14,6 +18,22 @@ pub struct BotState {
     pub config: Config,
     pub rpc_client: SolanaRpcClient,
     pub database: Arc<Mutex<Database>>,
+    /// Running /scan tasks by their progress message, for the Cancel button
+    pub scans: Arc<std::sync::Mutex<HashMap<(ChatId, MessageId), CancellationToken>>>,
+    /// Cooldowns and the shared concurrency limit for /scan and /eligible
+    pub throttle: Arc<CommandThrottle>,
+}
+
+impl BotState {
+    /// Configuration with the runtime overrides from /settings applied
+    pub async fn effective_config(&self) -> Config {
+        let mut config = self.config.clone();
+        match self.database.lock().await.get_runtime_settings() {
+            Ok(settings) => settings.apply(&mut config),
+            Err(e) => error!("Failed to load runtime settings: {}", e),
+        }
+        config
+    }
 }
 
 #[derive(BotCommands, Clone)]
@@ -35,36 +55,78 @@ pub enum Command {
     Reclaimed,
     #[command(description = "Show accounts eligible for reclaim")]
     Eligible,
+    #[command(description = "Accounts becoming eligible soon: /upcoming [days]")]
+    Upcoming(String),
+    #[command(description = "Largest reclaimable accounts with Reclaim buttons: /top [n]")]
+    Top(String),
+    #[command(description = "Hold an account past min_inactive_days: /hold [<pubkey> <days>|off]")]
+    Hold(String),
+    #[command(description = "List high-value reclaims waiting to run")]
+    Staged,
+    #[command(description = "Release a staged reclaim: /approve <pubkey>")]
+    Approve(String),
+    #[command(description = "Cancel a staged reclaim: /deny <pubkey>")]
+    Deny(String),
+    #[command(description = "Show one account's details: /account <pubkey>")]
+    Account(String),
+    #[command(description = "Solana Pay link asking an account's owner to close it: /requestclose <pubkey>")]
+    RequestClose(String),
+    #[command(description = "Download records as CSV: /export accounts|operations")]
+    Export(String),
     #[command(description = "Show statistics")]
     Stats,
-    #[command(description = "View current settings")]
+    #[command(description = "View and change runtime settings")]
     Settings,
+    #[command(description = "List passive reclaims awaiting review")]
+    Pending,
+    #[command(description = "Confirm a passive reclaim attribution: /confirm <id>")]
+    Confirm(i64),
+    #[command(description = "Reject a passive reclaim attribution: /reject <id>")]
+    Reject(i64),
+    #[command(description = "Choose notifications: /subscribe [everything|high_value|errors]")]
+    Subscribe(String),
+    #[command(description = "Stop receiving notifications")]
+    Unsubscribe,
 }
 
 pub async fn run_telegram_bot(config: Config) -> crate::error::Result<()> {
+    let database = Database::new(&config.database.path)?;
+    run_until(config, database, async {
+        let _ = tokio::signal::ctrl_c().await;
+    })
+    .await
+}
+
+/// Run the bot on an existing database handle until `shutdown` resolves
+pub async fn run_until(
+    config: Config,
+    database: Database,
+    shutdown: impl std::future::Future<Output = ()> + Send + 'static,
+) -> crate::error::Result<()> {
     let telegram_config = if let Some(conf) = &config.telegram {
         conf
     } else {
         error!("Telegram configuration missing");
-        return Err(crate::error::ReclaimError::Config("Telegram configuration missing".to_string()));
+        return Err(crate::error::ReclaimError::ConfigError("Telegram configuration missing".to_string()));
     };
 
     info!("Starting Telegram bot...");
     
     let bot = Bot::new(telegram_config.bot_token.clone());
     
-    let rpc_client = SolanaRpcClient::new(
-        &config.solana.rpc_url,
-        config.commitment_config(),
-        config.solana.rate_limit_delay_ms,
-    );
+    let rpc_client = SolanaRpcClient::from_config(&config);
     
-    let database = Arc::new(Mutex::new(Database::new(&config.database.path)?));
+    let database = Arc::new(Mutex::new(database));
     
     let state = Arc::new(BotState {
         config: config.clone(),
         rpc_client,
         database,
+        scans: Arc::default(),
+        throttle: Arc::new(CommandThrottle::new(
+            std::time::Duration::from_secs(telegram_config.command_cooldown_seconds),
+            telegram_config.max_concurrent_scans,
+        )),
     });
 
     // Message handler for commands
@@ -100,11 +162,19 @@ pub async fn run_telegram_bot(config: Config) -> crate::error::Result<()> {
         .branch(command_handler)
         .branch(callback_handler);
 
-    Dispatcher::builder(bot, handler)
-        .enable_ctrlc_handler()
-        .build()
-        .dispatch()
-        .await;
+    let mut dispatcher = Dispatcher::builder(bot, handler).build();
+
+    // Stop taking updates once asked; in-flight handlers run to completion
+    let shutdown_token = dispatcher.shutdown_token();
+    tokio::spawn(async move {
+        shutdown.await;
+        info!("Stopping Telegram bot...");
+        if let Ok(stopped) = shutdown_token.shutdown() {
+            stopped.await;
+        }
+    });
+
+    dispatcher.dispatch().await;
 
     Ok(())
 }
\ No newline at end of file
@@ -1,6 +1,9 @@
 use teloxide::prelude::*;
 use std::sync::Arc;
 use crate::telegram::bot::BotState;
+use crate::telegram::commands::{cancel_scan, handle_reclaim_button, handle_review, handle_settings_action};
+use crate::storage::models::ReviewStatus;
+use crate::i18n::{t, tf, Text};
 
 /// Handle callback queries (inline buttons)
 pub async fn handle_callback(
@@ -11,13 +14,50 @@ pub async fn handle_callback(
     let user_id = q.from.id.0;
     if let Some(telegram_config) = &state.config.telegram {
         if !telegram_config.authorized_users.is_empty() && !telegram_config.authorized_users.contains(&user_id) {
-            bot.answer_callback_query(q.id).text("⛔ Not authorized").show_alert(true).await?;
+            bot.answer_callback_query(q.id).text(format!("⛔ {}", t(Text::Unauthorized))).show_alert(true).await?;
             return Ok(());
         }
     }
 
-    if let Some(data) = q.data {
-        bot.answer_callback_query(q.id).text(format!("Received: {}", data)).await?;
+    if let Some(data) = q.data.as_deref() {
+        if let (Some(action), Some(message)) = (data.strip_prefix("settings_"), q.message.as_ref()) {
+            let answer = handle_settings_action(bot.clone(), message, state, action).await?;
+            bot.answer_callback_query(q.id).text(answer).await?;
+            return Ok(());
+        }
+
+        if let ("scan_cancel", Some(message)) = (data, q.message.as_ref()) {
+            let answer = if cancel_scan(&state, message) { t(Text::CancellingScan) } else { t(Text::ScanAlreadyFinished) };
+            bot.answer_callback_query(q.id).text(answer).await?;
+            return Ok(());
+        }
+
+        if let (Some(pubkey), Some(message)) = (data.strip_prefix("reclaim:"), q.message.as_ref()) {
+            bot.answer_callback_query(q.id).await?;
+            handle_reclaim_button(bot, message.chat.id, state, pubkey).await?;
+            return Ok(());
+        }
+
+        let review = data
+            .strip_prefix("passive_confirm:")
+            .map(|id| (id, ReviewStatus::Confirmed))
+            .or_else(|| data.strip_prefix("passive_reject:").map(|id| (id, ReviewStatus::Rejected)));
+
+        match (review, q.message.as_ref().map(|m| m.chat.id)) {
+            (Some((id, status)), Some(chat_id)) => match id.parse::<i64>() {
+                Ok(id) => {
+                    bot.answer_callback_query(q.id).await?;
+                    handle_review(bot, chat_id, state, id, status).await?;
+                }
+                Err(_) => {
+                    bot.answer_callback_query(q.id).text(t(Text::InvalidReviewId)).await?;
+                }
+            },
+            _ => {
+                let answer = tf(Text::CallbackReceived, &[&data]);
+                bot.answer_callback_query(q.id).text(answer).await?;
+            }
+        }
     }
 
     Ok(())
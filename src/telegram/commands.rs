@@ -4,12 +4,25 @@ use teloxide::prelude::*;
 use teloxide::utils::command::BotCommands;
 use std::sync::Arc;
 use crate::telegram::bot::{BotState, Command};
-use crate::kora::KoraMonitor;
-use crate::reclaim::EligibilityChecker;
-use crate::utils;
+use crate::kora::{FinalityChecker, KoraMonitor};
+use crate::solana::ParserRegistry;
+use crate::solana::accounts::ScanProgress;
+use crate::error::ReclaimError;
+use tokio_util::sync::CancellationToken;
+use crate::reclaim::{CloseRequestBuilder, EligibilityChecker};
+use crate::kora::AccountType;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use crate::telegram::templates;
 use crate::telegram::formatters::format_sol_tg;
-use crate::storage::models::{SponsoredAccount, AccountStatus}; 
-use tracing::{info, error}; 
+use crate::api::KoraReclaim;
+use crate::telegram::throttle::Throttled;
+use tokio::sync::OwnedSemaphorePermit;
+use crate::i18n::{t, tf, Text};
+use crate::storage::models::{SponsoredAccount, ReviewStatus, NotificationLevel, ReclaimReason}; 
+use crate::storage::export::{self, ExportKind};
+use crate::storage::settings;
+use tracing::{debug, info, error}; 
 
 /// Main command handler
 pub async fn handle_command(
@@ -22,8 +35,7 @@ pub async fn handle_command(
     if let Some(telegram_config) = &state.config.telegram {
         if !telegram_config.authorized_users.is_empty() 
             && !telegram_config.authorized_users.contains(&user_id) {
-            bot.send_message(msg.chat.id, "⛔ Authorization failed. You are not authorized to use this bot.")
-                .await?;
+            reply(&bot, msg.chat.id, templates::notice("⛔", t(Text::Unauthorized))).await?;
             return Ok(());
         }
     }
@@ -32,23 +44,78 @@ pub async fn handle_command(
         Command::Start => handle_start(bot, msg).await,
         Command::Help => handle_help(bot, msg).await,
         Command::Status => handle_status(bot, msg, state).await,
-        Command::Scan => handle_scan(bot, msg, state).await,
+        Command::Scan => match start_throttled(&bot, msg.chat.id, &state, user_id, "scan").await? {
+            Some(permit) => handle_scan(bot, msg, state, permit).await,
+            None => Ok(()),
+        },
         Command::Accounts => handle_accounts(bot, msg, state).await,
         Command::Closed => handle_closed(bot, msg, state).await,
         Command::Reclaimed => handle_reclaimed(bot, msg, state).await,
-        Command::Eligible => handle_eligible(bot, msg, state).await,
+        Command::Eligible => match start_throttled(&bot, msg.chat.id, &state, user_id, "eligible").await? {
+            Some(permit) => handle_eligible(bot, msg, state, permit).await,
+            None => Ok(()),
+        },
+        Command::Upcoming(days) => handle_upcoming(bot, msg, state, days.trim()).await,
+        Command::Top(n) => handle_top(bot, msg, state, n.trim()).await,
+        Command::Hold(args) => handle_hold(bot, msg, state, args.trim()).await,
+        Command::Staged => handle_staged(bot, msg, state).await,
+        Command::Approve(pubkey) => handle_staged_decision(bot, msg, state, pubkey.trim(), true).await,
+        Command::Deny(pubkey) => handle_staged_decision(bot, msg, state, pubkey.trim(), false).await,
+        Command::Account(pubkey) => handle_account(bot, msg, state, pubkey.trim()).await,
+        Command::RequestClose(pubkey) => handle_request_close(bot, msg, state, pubkey.trim()).await,
+        Command::Export(kind) => handle_export(bot, msg, state, kind.trim()).await,
         Command::Stats => handle_stats(bot, msg, state).await,
         Command::Settings => handle_settings(bot, msg, state).await,
+        Command::Pending => handle_pending(bot, msg, state).await,
+        Command::Confirm(id) => {
+            handle_review(bot, msg.chat.id, state, id, ReviewStatus::Confirmed).await
+        }
+        Command::Reject(id) => {
+            handle_review(bot, msg.chat.id, state, id, ReviewStatus::Rejected).await
+        }
+        Command::Subscribe(level) => {
+            let level = if level.trim().is_empty() {
+                Ok(NotificationLevel::Everything)
+            } else {
+                level.trim().parse().map_err(|_| level)
+            };
+            handle_subscribe(bot, msg, state, level).await
+        }
+        Command::Unsubscribe => {
+            handle_subscribe(bot, msg, state, Ok(NotificationLevel::Off)).await
+        }
     }
 }
 
+/// Claim a slot for an expensive command, telling the user why when it is refused
+async fn start_throttled(
+    bot: &Bot,
+    chat_id: ChatId,
+    state: &BotState,
+    user_id: u64,
+    command: &'static str,
+) -> ResponseResult<Option<OwnedSemaphorePermit>> {
+    let text = match state.throttle.start(user_id, command) {
+        Ok(permit) => return Ok(Some(permit)),
+        Err(Throttled::Cooldown(wait)) => {
+            tf(Text::CommandCooldown, &[&command, &(wait.as_secs_f64().ceil() as u64)])
+        }
+        Err(Throttled::Busy) => t(Text::ScansBusy).to_string(),
+    };
+    debug!("Throttled /{} from user {}: {}", command, user_id, text);
+    reply(bot, chat_id, templates::notice("⏳", text)).await?;
+    Ok(None)
+}
+
+/// Send a MarkdownV2 reply built by [`templates`]
+async fn reply(bot: &Bot, chat_id: ChatId, text: String) -> ResponseResult<Message> {
+    bot.send_message(chat_id, text)
+        .parse_mode(teloxide::types::ParseMode::MarkdownV2)
+        .await
+}
+
 async fn handle_start(bot: Bot, msg: Message) -> ResponseResult<()> {
-    bot.send_message(
-        msg.chat.id, 
-        "👋 *Welcome to Kora Rent Reclaim Bot*\n\nI can help you monitor and reclaim rent from sponsored accounts\\.\n\nUse /help to see available commands\\.",
-    )
-    .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-    .await?;
+    reply(&bot, msg.chat.id, templates::welcome()).await?;
     Ok(())
 }
 
@@ -58,225 +125,239 @@ async fn handle_help(bot: Bot, msg: Message) -> ResponseResult<()> {
 }
 
 async fn handle_status(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
-    let config = &state.config;
-    let status_msg = format!(
-        "🟢 *Bot Status: Online*\n\nNetwork: {}\nMode: {}\nDry Run: {}\nOperator: `{}`",
-        match config.solana.network { 
-            crate::config::Network::Mainnet => "Mainnet",
-            crate::config::Network::Devnet => "Devnet",
-            crate::config::Network::Testnet => "Testnet",
-        },
-        if config.reclaim.auto_reclaim_enabled { "Auto" } else { "Manual" },
-        config.reclaim.dry_run,
-        utils::format_pubkey(&config.kora.operator_pubkey)
-    );
-    bot.send_message(msg.chat.id, status_msg)
-        .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-        .await?;
+    let config = state.effective_config().await;
+    let scan_failures = state.database.lock().await.get_scan_failure_streak().unwrap_or_default();
+    reply(&bot, msg.chat.id, templates::status(&config, &scan_failures)).await?;
     Ok(())
 }
 
-// ✅ CRITICAL FIX: Persist scan results to database
-async fn handle_scan(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
-    bot.send_message(msg.chat.id, "🔍 Scanning for sponsored accounts... This may take a moment.").await?;
-    
+/// Seconds between edits of the /scan progress message
+const SCAN_PROGRESS_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Scan in the background, editing a progress message until it finishes or is cancelled.
+/// The throttle `permit` is held until the scan ends.
+async fn handle_scan(bot: Bot, msg: Message, state: Arc<BotState>, permit: OwnedSemaphorePermit) -> ResponseResult<()> {
+    use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
     let operator_pubkey = match state.config.operator_pubkey() {
         Ok(pk) => pk,
         Err(e) => {
-            bot.send_message(msg.chat.id, format!("❌ Error: {}", e)).await?;
+            reply(&bot, msg.chat.id, templates::failure(Text::Error, e)).await?;
             return Ok(());
         }
     };
     
-    let monitor = KoraMonitor::new(state.rpc_client.clone(), operator_pubkey);
+    let progress = Arc::new(ScanProgress::default());
+    let cancel = CancellationToken::new();
+    let cache = state.database.lock().await.clone();
+    let monitor = KoraMonitor::new(state.rpc_client.clone(), operator_pubkey)
+        .with_parsers(ParserRegistry::from_config(&state.config))
+        .with_floor_slot(state.config.reclaim.scan_floor_slot)
+        .with_cache(cache)
+        .with_progress(Arc::clone(&progress))
+        .with_cancel(cancel.clone());
     
-    match monitor.get_sponsored_accounts(100).await {
-        Ok(accounts) => {
+    let keyboard = InlineKeyboardMarkup::new(vec![vec![
+        InlineKeyboardButton::callback("✖️ Cancel", "scan_cancel"),
+    ]]);
+    let status = bot.send_message(msg.chat.id, templates::scan_progress(0, 0, 0))
+        .parse_mode(teloxide::types::ParseMode::MarkdownV2)
+        .reply_markup(keyboard.clone())
+        .await?;
+    let key = (status.chat.id, status.id);
+    let task = tokio::spawn(async move { monitor.get_sponsored_accounts(100).await });
+    state.scans.lock().unwrap().insert(key, cancel);
+    
+    // Updates from one chat are handled in order, so waiting here would hold
+    // back the Cancel button press until the scan had finished
+    tokio::spawn(async move {
+        if let Err(e) = follow_scan(&bot, &state, key, task, progress, keyboard).await {
+            error!("Failed to report Telegram /scan result: {}", e);
+        }
+        drop(permit);
+    });
+    Ok(())
+}
+
+/// Edit the progress message while the scan runs, then replace it with the result
+async fn follow_scan(
+    bot: &Bot,
+    state: &BotState,
+    key: (ChatId, teloxide::types::MessageId),
+    mut task: tokio::task::JoinHandle<crate::error::Result<Vec<crate::kora::types::SponsoredAccountInfo>>>,
+    progress: Arc<ScanProgress>,
+    keyboard: teloxide::types::InlineKeyboardMarkup,
+) -> ResponseResult<()> {
+    let started_at = std::time::Instant::now();
+    let mut ticker = tokio::time::interval(SCAN_PROGRESS_INTERVAL);
+    ticker.tick().await;
+    let mut shown = (0, 0);
+    let outcome = loop {
+        tokio::select! {
+            outcome = &mut task => break outcome,
+            _ = ticker.tick() => {
+                let counts = (progress.signatures(), progress.accounts());
+                // Telegram rejects edits that leave the message unchanged
+                if counts == shown {
+                    continue;
+                }
+                shown = counts;
+                let text = templates::scan_progress(counts.0, counts.1, started_at.elapsed().as_secs());
+                if let Err(e) = bot.edit_message_text(key.0, key.1, text)
+                    .parse_mode(teloxide::types::ParseMode::MarkdownV2)
+                    .reply_markup(keyboard.clone())
+                    .await
+                {
+                    debug!("Failed to update scan progress: {}", e);
+                }
+            }
+        }
+    };
+    state.scans.lock().unwrap().remove(&key);
+    
+    let text = match outcome {
+        Ok(Err(ReclaimError::Cancelled)) => {
+            info!("Telegram /scan cancelled after {} signatures", progress.signatures());
+            templates::scan_cancelled(progress.signatures(), progress.accounts())
+        }
+        Err(e) => {
+            error!("Telegram /scan task failed: {}", e);
+            templates::failure(Text::ScanFailed, e)
+        }
+        Ok(Err(e)) => {
+            error!("Telegram /scan failed: {}", e);
+            templates::failure(Text::ScanFailed, e)
+        }
+        Ok(Ok(accounts)) => {
             let count = accounts.len();
-            
-            // ✅ FIX: Convert to database models and persist
             let db_accounts: Vec<SponsoredAccount> = accounts
                 .iter()
-                .map(|account_info| SponsoredAccount {
-                    pubkey: account_info.pubkey.to_string(),
-                    created_at: account_info.created_at,
-                    closed_at: None,
-                    rent_lamports: account_info.rent_lamports,
-                    data_size: account_info.data_size,
-                    status: AccountStatus::Active,
-                    creation_signature: Some(account_info.creation_signature.to_string()),
-                    creation_slot: Some(account_info.creation_slot),
-                    close_authority: None,
-                    reclaim_strategy: None,
-                })
+                .map(SponsoredAccount::from)
                 .collect();
             
-            // ✅ FIX: Save to database
-            let db = state.database.lock().await;
+            let db = state.database.lock().await.clone();
             match db.save_accounts_batch(&db_accounts) {
                 Ok(saved_count) => {
                     info!("Telegram /scan saved {} accounts to database", saved_count);
                     
-                    // ✅ FIX: Update checkpoint
                     if let Some(latest_account) = accounts.first() {
                         let _ = db.save_last_processed_signature(
                             &latest_account.creation_signature.to_string()
                         );
                         let _ = db.save_last_processed_slot(latest_account.creation_slot);
                     }
-                    
-                    bot.send_message(
-                        msg.chat.id,
-                        format!(
-                            "✅ Scan complete\\!\n\n\
-                             Found: {} accounts\n\
-                             Saved: {} to database\n\n\
-                             Use /accounts to view them\\.",
-                            count, saved_count
-                        )
-                    )
-                    .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-                    .await?;
+                    FinalityChecker::new(state.rpc_client.clone(), db).confirm_saved().await;
+                    templates::scan_saved(count, saved_count)
                 }
                 Err(e) => {
                     error!("Failed to save accounts from Telegram scan: {}", e);
-                    bot.send_message(
-                        msg.chat.id,
-                        format!(
-                            "⚠️ Found {} accounts but failed to save to database: {}\n\n\
-                             Accounts were not persisted\\.",
-                            count, e
-                        )
-                    )
-                    .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-                    .await?;
+                    templates::scan_not_saved(count, e)
                 }
             }
         }
-        Err(e) => {
-            error!("Telegram /scan failed: {}", e);
-            bot.send_message(msg.chat.id, format!("❌ Scan failed: {}", e)).await?;
+    };
+    
+    bot.edit_message_text(key.0, key.1, text)
+        .parse_mode(teloxide::types::ParseMode::MarkdownV2)
+        .await?;
+    Ok(())
+}
+
+/// Cancel the scan behind a progress message; `false` when it already finished
+pub fn cancel_scan(state: &BotState, message: &Message) -> bool {
+    match state.scans.lock().unwrap().remove(&(message.chat.id, message.id)) {
+        Some(cancel) => {
+            cancel.cancel();
+            true
         }
+        None => false,
     }
-    Ok(())
 }
 
 async fn handle_accounts(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
-    bot.send_message(msg.chat.id, "📋 Fetching account list...").await?;
+    reply(&bot, msg.chat.id, templates::notice("📋", t(Text::FetchingAccounts))).await?;
     
     let db = state.database.lock().await;
     match db.get_active_accounts() {
         Ok(accounts) => {
             if accounts.is_empty() {
-                bot.send_message(msg.chat.id, "No active accounts found in database. Run /scan first.").await?;
+                reply(&bot, msg.chat.id, templates::escape(t(Text::NoActiveAccounts))).await?;
             } else {
-                let count = accounts.len();
-                let display_limit = std::cmp::min(count, 5);
-                let mut response = format!("📋 *Active Accounts* ({})\\n\\n", count);
-                
-                for acc in &accounts[..display_limit] {
-                    response.push_str(&format!("• `{}`\\n  Rent: {} lamports\\n\\n", acc.pubkey, acc.rent_lamports));
-                }
-                
-                if count > display_limit {
-                    response.push_str(&format!("_\\.\\.\\.and {} more_", count - display_limit));
-                }
-                
-                bot.send_message(msg.chat.id, response)
-                    .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-                    .await?;
+                reply(&bot, msg.chat.id, templates::account_list("📋", Text::ActiveAccounts, &accounts)).await?;
             }
         }
         Err(e) => {
-            bot.send_message(msg.chat.id, format!("❌ Database error: {}", e)).await?;
+            reply(&bot, msg.chat.id, templates::failure(Text::DatabaseError, e)).await?;
         }
     }
     Ok(())
 }
 
 async fn handle_closed(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
-    bot.send_message(msg.chat.id, "📋 Fetching closed accounts...").await?;
+    reply(&bot, msg.chat.id, templates::notice("📋", t(Text::FetchingClosed))).await?;
     
     let db = state.database.lock().await;
     match db.get_closed_accounts() {
         Ok(accounts) => {
             if accounts.is_empty() {
-                bot.send_message(msg.chat.id, "No closed accounts found in database.").await?;
+                reply(&bot, msg.chat.id, templates::escape(t(Text::NoClosedAccounts))).await?;
             } else {
-                let count = accounts.len();
-                let display_limit = std::cmp::min(count, 5);
-                let mut response = format!("🔒 *Closed Accounts* ({})\\n\\n", count);
-                
-                for acc in &accounts[..display_limit] {
-                    response.push_str(&format!("• `{}`\\n  Rent: {} lamports\\n\\n", acc.pubkey, acc.rent_lamports));
-                }
-                
-                if count > display_limit {
-                    response.push_str(&format!("_\\.\\.\\.and {} more_", count - display_limit));
-                }
-                
-                bot.send_message(msg.chat.id, response)
-                    .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-                    .await?;
+                reply(&bot, msg.chat.id, templates::account_list("🔒", Text::ClosedAccounts, &accounts)).await?;
             }
         }
         Err(e) => {
-            bot.send_message(msg.chat.id, format!("❌ Database error: {}", e)).await?;
+            reply(&bot, msg.chat.id, templates::failure(Text::DatabaseError, e)).await?;
         }
     }
     Ok(())
 }
 
 async fn handle_reclaimed(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
-    bot.send_message(msg.chat.id, "📋 Fetching reclaimed accounts...").await?;
+    reply(&bot, msg.chat.id, templates::notice("📋", t(Text::FetchingReclaimed))).await?;
     
     let db = state.database.lock().await;
     match db.get_reclaimed_accounts() {
         Ok(accounts) => {
             if accounts.is_empty() {
-                bot.send_message(msg.chat.id, "No reclaimed accounts found in database.").await?;
+                reply(&bot, msg.chat.id, templates::escape(t(Text::NoReclaimedAccounts))).await?;
             } else {
-                let count = accounts.len();
-                let display_limit = std::cmp::min(count, 5);
-                let mut response = format!("✅ *Reclaimed Accounts* ({})\\n\\n", count);
-                
-                for acc in &accounts[..display_limit] {
-                    response.push_str(&format!("• `{}`\\n  Rent: {} lamports\\n\\n", acc.pubkey, acc.rent_lamports));
-                }
-                
-                if count > display_limit {
-                    response.push_str(&format!("_\\.\\.\\.and {} more_", count - display_limit));
-                }
-                
-                bot.send_message(msg.chat.id, response)
-                    .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-                    .await?;
+                reply(&bot, msg.chat.id, templates::account_list("✅", Text::ReclaimedAccounts, &accounts)).await?;
             }
         }
         Err(e) => {
-            bot.send_message(msg.chat.id, format!("❌ Database error: {}", e)).await?;
+            reply(&bot, msg.chat.id, templates::failure(Text::DatabaseError, e)).await?;
         }
     }
     Ok(())
 }
 
 // ✅ FIX: Also persist eligible accounts check results
-async fn handle_eligible(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
-    bot.send_message(msg.chat.id, "🔍 Checking eligibility...").await?;
+async fn handle_eligible(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    _permit: OwnedSemaphorePermit,
+) -> ResponseResult<()> {
+    reply(&bot, msg.chat.id, templates::notice("🔍", t(Text::CheckingEligibility))).await?;
     
     let operator_pubkey = match state.config.operator_pubkey() {
         Ok(pk) => pk,
         Err(e) => {
-            bot.send_message(msg.chat.id, format!("❌ Error: {}", e)).await?;
+            reply(&bot, msg.chat.id, templates::failure(Text::Error, e)).await?;
             return Ok(());
         }
     };
     
-    let monitor = KoraMonitor::new(state.rpc_client.clone(), operator_pubkey);
+    let cache = state.database.lock().await.clone();
+    let monitor = KoraMonitor::new(state.rpc_client.clone(), operator_pubkey)
+        .with_parsers(ParserRegistry::from_config(&state.config))
+        .with_floor_slot(state.config.reclaim.scan_floor_slot)
+        .with_cache(cache.clone());
     
     match monitor.get_sponsored_accounts(50).await {
         Ok(accounts) => {
-            let eligibility_checker = EligibilityChecker::new(state.rpc_client.clone(), state.config.clone());
+            let eligibility_checker = EligibilityChecker::new(state.rpc_client.clone(), state.config.clone())
+                .with_holds(cache);
             let mut eligible_count = 0;
             let mut total_reclaimable = 0u64;
             let mut eligible_accounts = Vec::new();
@@ -292,41 +373,438 @@ async fn handle_eligible(bot: Bot, msg: Message, state: Arc<BotState>) -> Respon
             // ✅ FIX: Save all scanned accounts to database (not just eligible ones)
             let db_accounts: Vec<SponsoredAccount> = accounts
                 .iter()
-                .map(|account_info| SponsoredAccount {
-                    pubkey: account_info.pubkey.to_string(),
-                    created_at: account_info.created_at,
-                    closed_at: None,
-                    rent_lamports: account_info.rent_lamports,
-                    data_size: account_info.data_size,
-                    status: AccountStatus::Active,
-                    creation_signature: Some(account_info.creation_signature.to_string()),
-                    creation_slot: Some(account_info.creation_slot),
-                    close_authority: None,
-                    reclaim_strategy: None,
-                })
+                .map(SponsoredAccount::from)
                 .collect();
             
-            let db = state.database.lock().await;
-            if let Err(e) = db.save_accounts_batch(&db_accounts) {
-                error!("Failed to save accounts from /eligible check: {}", e);
+            let db = state.database.lock().await.clone();
+            match db.save_accounts_batch(&db_accounts) {
+                Ok(_) => {
+                    FinalityChecker::new(state.rpc_client.clone(), db).confirm_saved().await;
+                }
+                Err(e) => error!("Failed to save accounts from /eligible check: {}", e),
             }
             
-            bot.send_message(
-                msg.chat.id,
-                format!(
-                    "💰 *Eligibility Check*\\n\\nFound {} eligible accounts\\.\\nEst\\. reclaimable: {}", 
-                    eligible_count,
-                    format_sol_tg(total_reclaimable)
-                )
-            )
-            .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-            .await?;
+            reply(&bot, msg.chat.id, templates::eligibility(eligible_count, total_reclaimable)).await?;
         }
         Err(e) => {
             error!("Telegram /eligible check failed: {}", e);
-            bot.send_message(msg.chat.id, format!("❌ Error checking eligibility: {}", e)).await?;
+            reply(&bot, msg.chat.id, templates::failure(Text::EligibilityFailed, e)).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Tracked accounts that become old enough for reclaim within `input` days (7 by default)
+async fn handle_upcoming(bot: Bot, msg: Message, state: Arc<BotState>, input: &str) -> ResponseResult<()> {
+    let days = if input.is_empty() { Ok(7) } else { input.parse::<u64>() };
+    let Ok(days) = days else {
+        reply(&bot, msg.chat.id, templates::notice("ℹ️", t(Text::UpcomingUsage))).await?;
+        return Ok(());
+    };
+
+    let min_inactive_days = state.config.reclaim.min_inactive_days;
+    let upcoming = state.database.lock().await.get_accounts_eligible_within(min_inactive_days, days);
+    match upcoming {
+        Ok(accounts) if accounts.is_empty() => {
+            let text = templates::escape(tf(Text::NoUpcomingAccounts, &[&days]));
+            reply(&bot, msg.chat.id, text).await?;
+        }
+        Ok(accounts) => {
+            reply(&bot, msg.chat.id, templates::upcoming(&accounts, days, min_inactive_days)).await?;
+        }
+        Err(e) => {
+            reply(&bot, msg.chat.id, templates::failure(Text::DatabaseError, e)).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Most accounts `/top` lists, keeping the message and keyboard readable
+const TOP_LIMIT: usize = 25;
+
+/// List the largest eligible ActiveReclaim accounts with a Reclaim button each
+async fn handle_top(bot: Bot, msg: Message, state: Arc<BotState>, input: &str) -> ResponseResult<()> {
+    use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+    let n = if input.is_empty() { Ok(10) } else { input.parse::<usize>() };
+    let n = match n {
+        Ok(n) if (1..=TOP_LIMIT).contains(&n) => n,
+        _ => {
+            let text = templates::notice("ℹ️", tf(Text::TopUsage, &[&TOP_LIMIT]));
+            reply(&bot, msg.chat.id, text).await?;
+            return Ok(());
+        }
+    };
+
+    let min_inactive_days = state.effective_config().await.reclaim.min_inactive_days;
+    let top = state.database.lock().await.get_top_reclaimable(min_inactive_days, n);
+    match top {
+        Ok(accounts) if accounts.is_empty() => {
+            reply(&bot, msg.chat.id, templates::notice("✅", t(Text::NoTopAccounts))).await?;
+        }
+        Ok(accounts) => {
+            let keyboard = InlineKeyboardMarkup::new(accounts.iter().enumerate().map(|(rank, account)| {
+                vec![InlineKeyboardButton::callback(
+                    format!("♻️ Reclaim {}. ({})", rank + 1, format_sol_tg(account.balance())),
+                    format!("reclaim:{}", account.pubkey),
+                )]
+            }));
+
+            bot.send_message(msg.chat.id, templates::top_accounts(&accounts))
+                .parse_mode(teloxide::types::ParseMode::MarkdownV2)
+                .reply_markup(keyboard)
+                .await?;
+        }
+        Err(e) => {
+            reply(&bot, msg.chat.id, templates::failure(Text::DatabaseError, e)).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Reclaim one account from a `/top` button, re-checking eligibility first
+/// since the list may be stale by the time the button is pressed
+pub async fn handle_reclaim_button(
+    bot: Bot,
+    chat_id: ChatId,
+    state: Arc<BotState>,
+    pubkey: &str,
+) -> ResponseResult<()> {
+    let Ok(account) = Pubkey::from_str(pubkey) else {
+        let text = templates::notice("❌", tf(Text::InvalidPubkey, &[&pubkey]));
+        reply(&bot, chat_id, text).await?;
+        return Ok(());
+    };
+    let text = templates::notice("♻️", tf(Text::ReclaimingAccount, &[&pubkey]));
+    reply(&bot, chat_id, text).await?;
+
+    let config = state.effective_config().await;
+    let kora = KoraReclaim::with_database(config, state.database.lock().await.clone());
+
+    match kora.check_eligibility(&account).await {
+        Ok(report) if !report.eligible => {
+            let text = tf(Text::NotEligible, &[&pubkey, &report.reason]);
+            reply(&bot, chat_id, templates::notice("ℹ️", text)).await?;
+            return Ok(());
+        }
+        Ok(_) => {}
+        Err(e) => {
+            reply(&bot, chat_id, templates::failure(Text::EligibilityFailed, e)).await?;
+            return Ok(());
+        }
+    }
+
+    let text = match kora.reclaim_as(&account, ReclaimReason::Telegram).await {
+        Ok(result) if result.signature.is_some() => {
+            info!("Reclaimed {} from Telegram: {} lamports", pubkey, result.amount_reclaimed);
+            templates::reclaim_success(pubkey, result.amount_reclaimed)
+        }
+        Ok(result) => {
+            let text = tf(Text::DryRunReclaim, &[&format_sol_tg(result.amount_reclaimed), &pubkey]);
+            templates::notice("🧪", text)
+        }
+        Err(e) => {
+            error!("Telegram reclaim of {} failed: {}", pubkey, e);
+            templates::reclaim_failed(pubkey, &e.to_string(), e.is_retryable())
+        }
+    };
+    reply(&bot, chat_id, text).await?;
+    Ok(())
+}
+
+/// List holds, or hold an account for `<days>` instead of min_inactive_days
+/// (`off` releases it)
+async fn handle_hold(bot: Bot, msg: Message, state: Arc<BotState>, input: &str) -> ResponseResult<()> {
+    let min_inactive_days = state.config.reclaim.min_inactive_days;
+    let db = state.database.lock().await.clone();
+
+    if input.is_empty() {
+        let text = match db.get_held_accounts() {
+            Ok(accounts) if accounts.is_empty() => templates::escape(tf(Text::NoHolds, &[&min_inactive_days])),
+            Ok(accounts) => templates::holds(&accounts, min_inactive_days),
+            Err(e) => templates::failure(Text::DatabaseError, e),
+        };
+        reply(&bot, msg.chat.id, text).await?;
+        return Ok(());
+    }
+
+    let mut args = input.split_whitespace();
+    let (Some(key), Some(days), None) = (args.next(), args.next(), args.next()) else {
+        reply(&bot, msg.chat.id, templates::notice("ℹ️", t(Text::HoldUsage))).await?;
+        return Ok(());
+    };
+    let days = match days {
+        "off" => None,
+        days => match days.parse::<u64>() {
+            Ok(days) => Some(days),
+            Err(_) => {
+                reply(&bot, msg.chat.id, templates::notice("ℹ️", t(Text::HoldUsage))).await?;
+                return Ok(());
+            }
+        },
+    };
+    let Ok(pubkey) = Pubkey::from_str(key) else {
+        let text = templates::notice("❌", tf(Text::InvalidPubkey, &[&key]));
+        reply(&bot, msg.chat.id, text).await?;
+        return Ok(());
+    };
+    let key = pubkey.to_string();
+
+    let text = match db.set_min_inactive_days(&key, days).and_then(|held| {
+        Ok(if held { db.get_account_by_pubkey(&key)? } else { None })
+    }) {
+        Ok(None) => templates::notice("❌", tf(Text::HoldNotTracked, &[&key])),
+        Ok(Some(account)) => match days {
+            Some(days) => {
+                info!("Account {} held for {} days", key, days);
+                let until = crate::utils::format_timestamp(&account.eligible_at(min_inactive_days));
+                templates::notice("⏸️", tf(Text::HoldSet, &[&key, &days, &until]))
+            }
+            None => {
+                info!("Hold on account {} released", key);
+                templates::notice("▶️", tf(Text::HoldCleared, &[&key, &min_inactive_days]))
+            }
+        },
+        Err(e) => templates::failure(Text::DatabaseError, e),
+    };
+    reply(&bot, msg.chat.id, text).await?;
+    Ok(())
+}
+
+/// List high-value reclaims waiting for their delay or an approval
+async fn handle_staged(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
+    let require_approval = state.config.reclaim.staging.require_approval;
+    let result = state.database.lock().await.get_staged_reclaims();
+    let text = match result {
+        Ok(staged) if staged.is_empty() => templates::escape(t(Text::NoStagedReclaims)),
+        Ok(staged) => templates::staged_reclaims(&staged, require_approval),
+        Err(e) => templates::failure(Text::DatabaseError, e),
+    };
+    reply(&bot, msg.chat.id, text).await?;
+    Ok(())
+}
+
+/// Release a staged reclaim for the next cycle, or cancel it
+async fn handle_staged_decision(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    input: &str,
+    approve: bool,
+) -> ResponseResult<()> {
+    let Ok(pubkey) = Pubkey::from_str(input) else {
+        let text = if input.is_empty() {
+            templates::notice("ℹ️", t(if approve { Text::ApproveUsage } else { Text::DenyUsage }))
+        } else {
+            templates::notice("❌", tf(Text::InvalidPubkey, &[&input]))
+        };
+        reply(&bot, msg.chat.id, text).await?;
+        return Ok(());
+    };
+    let key = pubkey.to_string();
+    let approver = msg.from().map_or_else(|| "telegram".to_string(), |user| format!("telegram:{}", user.id.0));
+
+    let result = {
+        let db = state.database.lock().await;
+        if approve { db.approve_staged_reclaim(&key, &approver) } else { db.remove_staged_reclaim(&key) }
+    };
+    let text = match result {
+        Ok(true) if approve => {
+            info!("Staged reclaim of {} approved by {}", key, approver);
+            templates::notice("✅", tf(Text::StagedApproved, &[&key]))
+        }
+        Ok(true) => {
+            info!("Staged reclaim of {} denied by {}", key, approver);
+            templates::notice("🛑", tf(Text::StagedDenied, &[&key]))
         }
+        Ok(false) => templates::notice("❌", tf(Text::NotStaged, &[&key])),
+        Err(e) => templates::failure(Text::DatabaseError, e),
+    };
+    reply(&bot, msg.chat.id, text).await?;
+    Ok(())
+}
+
+/// Database record, on-chain state, eligibility and history of one account
+async fn handle_account(bot: Bot, msg: Message, state: Arc<BotState>, input: &str) -> ResponseResult<()> {
+    if input.is_empty() {
+        reply(&bot, msg.chat.id, templates::notice("ℹ️", t(Text::AccountUsage))).await?;
+        return Ok(());
     }
+    let pubkey = match Pubkey::from_str(input) {
+        Ok(pubkey) => pubkey,
+        Err(_) => {
+            let text = templates::notice("❌", tf(Text::InvalidPubkey, &[&input]));
+            reply(&bot, msg.chat.id, text).await?;
+            return Ok(());
+        }
+    };
+    
+    reply(&bot, msg.chat.id, templates::notice("🔎", t(Text::LookingUpAccount))).await?;
+    
+    let key = pubkey.to_string();
+    let stored = {
+        let db = state.database.lock().await;
+        db.get_account_by_pubkey(&key).and_then(|stored| {
+            Ok((
+                stored,
+                db.get_reclaim_operations_for(&key)?,
+                db.get_reclaim_failure(&key)?,
+                db.get_account_epochs(&key)?,
+            ))
+        })
+    };
+    let (stored, operations, failure, epochs) = match stored {
+        Ok(stored) => stored,
+        Err(e) => {
+            reply(&bot, msg.chat.id, templates::failure(Text::DatabaseError, e)).await?;
+            return Ok(());
+        }
+    };
+    
+    let on_chain = match state.rpc_client.get_account(&pubkey).await {
+        Ok(account) => account.map(|account| templates::OnChainState {
+            lamports: account.lamports,
+            owner: account.owner.to_string(),
+            account_type: AccountType::from_account(&account).to_string(),
+        }),
+        Err(e) => {
+            reply(&bot, msg.chat.id, templates::failure(Text::Error, e)).await?;
+            return Ok(());
+        }
+    };
+    
+    // Untracked accounts are assumed old enough, as the CLI does
+    let config = state.effective_config().await;
+    let created_at = stored
+        .as_ref()
+        .map(|a| a.created_at)
+        .unwrap_or_else(|| chrono::Utc::now() - chrono::Duration::days(365));
+    let holds = state.database.lock().await.clone();
+    let eligibility = EligibilityChecker::new(state.rpc_client.clone(), config.clone())
+        .with_holds(holds)
+        .get_eligibility_reason(&pubkey, created_at)
+        .await
+        .unwrap_or_else(|e| e.to_string());
+    
+    let last_activity = match state.rpc_client.get_signatures_for_address(&pubkey, None, None, 1).await {
+        Ok(signatures) => signatures
+            .first()
+            .and_then(|s| s.block_time)
+            .and_then(|time| chrono::DateTime::from_timestamp(time, 0)),
+        Err(e) => {
+            error!("Failed to fetch last activity for {}: {}", pubkey, e);
+            None
+        }
+    };
+    
+    let details = templates::AccountDetails {
+        pubkey: key,
+        stored,
+        on_chain,
+        eligibility,
+        last_activity,
+        failure,
+        operations,
+        epochs,
+    };
+    reply(&bot, msg.chat.id, templates::account_details(&details, &config.solana.network)).await?;
+    Ok(())
+}
+
+/// Solana Pay link the account's owner can open to close it
+async fn handle_request_close(bot: Bot, msg: Message, state: Arc<BotState>, input: &str) -> ResponseResult<()> {
+    if input.is_empty() {
+        reply(&bot, msg.chat.id, templates::notice("ℹ️", t(Text::RequestCloseUsage))).await?;
+        return Ok(());
+    }
+    let Ok(pubkey) = Pubkey::from_str(input) else {
+        let text = templates::notice("❌", tf(Text::InvalidPubkey, &[&input]));
+        reply(&bot, msg.chat.id, text).await?;
+        return Ok(());
+    };
+    let Some(public_url) = state.config.api.public_url.as_deref() else {
+        reply(&bot, msg.chat.id, templates::notice("❌", t(Text::CloseRequestsDisabled))).await?;
+        return Ok(());
+    };
+    let treasury_wallet = match state.config.treasury_wallet() {
+        Ok(wallet) => wallet,
+        Err(e) => {
+            reply(&bot, msg.chat.id, templates::failure(Text::Error, e)).await?;
+            return Ok(());
+        }
+    };
+    
+    let text = match CloseRequestBuilder::new(state.rpc_client.clone(), treasury_wallet)
+        .link(public_url, &pubkey)
+        .await
+    {
+        Ok(request) => templates::close_request(
+            &pubkey.to_string(),
+            &request.authority.to_string(),
+            request.lamports,
+            &request.link,
+        ),
+        Err(e) => templates::failure(Text::CloseRequestFailed, e),
+    };
+    reply(&bot, msg.chat.id, text).await?;
+    Ok(())
+}
+
+/// Telegram bots may upload documents of up to 50 MB
+const EXPORT_PART_BYTES: usize = 45 * 1024 * 1024;
+
+/// Send a table as CSV documents, split into several files when large
+async fn handle_export(bot: Bot, msg: Message, state: Arc<BotState>, input: &str) -> ResponseResult<()> {
+    use teloxide::types::InputFile;
+
+    // Without an allow-list anyone could pull the full database
+    let restricted = state
+        .config
+        .telegram
+        .as_ref()
+        .is_some_and(|telegram| !telegram.authorized_users.is_empty());
+    if !restricted {
+        reply(&bot, msg.chat.id, templates::notice("⛔", t(Text::ExportRestricted))).await?;
+        return Ok(());
+    }
+
+    let Ok(kind) = input.parse::<ExportKind>() else {
+        reply(&bot, msg.chat.id, templates::notice("ℹ️", t(Text::ExportUsage))).await?;
+        return Ok(());
+    };
+
+    let parts = {
+        let db = state.database.lock().await;
+        match kind {
+            ExportKind::Accounts => db
+                .get_all_accounts()
+                .map(|accounts| export::accounts_csv(&accounts, EXPORT_PART_BYTES)),
+            ExportKind::Operations => db
+                .get_reclaim_history(None)
+                .map(|operations| export::operations_csv(&operations, EXPORT_PART_BYTES)),
+        }
+    };
+    let parts = match parts {
+        Ok(parts) => parts,
+        Err(e) => {
+            reply(&bot, msg.chat.id, templates::failure(Text::DatabaseError, e)).await?;
+            return Ok(());
+        }
+    };
+
+    let date = chrono::Utc::now().format("%Y%m%d");
+    let total = parts.len();
+    for (index, part) in parts.into_iter().enumerate() {
+        let file_name = if total == 1 {
+            format!("{}-{}.csv", kind, date)
+        } else {
+            format!("{}-{}-part{}.csv", kind, date, index + 1)
+        };
+        bot.send_document(msg.chat.id, InputFile::memory(part.into_bytes()).file_name(file_name))
+            .await?;
+    }
+
+    info!("Exported {} as {} CSV file(s) via Telegram", kind, total);
     Ok(())
 }
 
@@ -334,55 +812,230 @@ async fn handle_stats(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseR
     let db = state.database.lock().await;
     match db.get_stats() {
         Ok(stats) => {
-            let msg_text = format!(
-                "📊 *Kora Bot Statistics*\\n\\n\
-                *Accounts*\\n\
-                Total: {}\\n\
-                Active: {}\\n\
-                Closed: {}\\n\
-                Reclaimed: {}\\n\\n\
-                *Operations*\\n\
-                Total Ops: {}\\n\
-                Reclaimed: {}\\n\
-                Avg: {} lamports",
-                stats.total_accounts,
-                stats.active_accounts,
-                stats.closed_accounts,
-                stats.reclaimed_accounts,
-                stats.total_operations,
-                format_sol_tg(stats.total_reclaimed),
-                stats.avg_reclaim_amount
-            );
-            bot.send_message(msg.chat.id, msg_text)
-                .parse_mode(teloxide::types::ParseMode::MarkdownV2)
-                .await?;
+            reply(&bot, msg.chat.id, templates::stats(&stats)).await?;
         }
         Err(e) => {
-            bot.send_message(msg.chat.id, format!("❌ Error fetching stats: {}", e)).await?;
+            reply(&bot, msg.chat.id, templates::failure(Text::StatsFailed, e)).await?;
         }
     }
     Ok(())
 }
 
 async fn handle_settings(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
-    let config = &state.config;
-    let settings_msg = format!(
-        "⚙️ *Current Settings*\\n\\n\
-        *RPC*: `{}`\\n\
-        *Min Inactive*: {} days\\n\
-        *Auto Reclaim*: {}\\n\
-        *Batch Size*: {}\\n\
-        *Dry Run*: {}\\n\
-        *Database*: `{}`",
-        config.solana.rpc_url,
-        config.reclaim.min_inactive_days,
-        if config.reclaim.auto_reclaim_enabled { "On" } else { "Off" },
-        config.reclaim.batch_size,
-        if config.reclaim.dry_run { "Yes" } else { "No" },
-        config.database.path
-    );
-    bot.send_message(msg.chat.id, settings_msg)
+    let config = state.effective_config().await;
+    let (text, keyboard) = settings_view(&config);
+
+    bot.send_message(msg.chat.id, text)
         .parse_mode(teloxide::types::ParseMode::MarkdownV2)
+        .reply_markup(keyboard)
         .await?;
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Settings summary and the inline keyboard used to edit it
+fn settings_view(config: &crate::config::Config) -> (String, teloxide::types::InlineKeyboardMarkup) {
+    use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+    let threshold = config.telegram.as_ref().map(|t| t.alert_threshold_sol).unwrap_or_default();
+    let text = templates::settings(config, threshold);
+
+    let toggles: Vec<InlineKeyboardButton> = settings::TOGGLES
+        .iter()
+        .filter_map(|(key, label)| {
+            settings::toggle_value(config, key).map(|on| {
+                InlineKeyboardButton::callback(
+                    format!("{} {}", if on { "✅" } else { "❌" }, label),
+                    format!("settings_toggle:{}", key),
+                )
+            })
+        })
+        .collect();
+
+    let mut rows: Vec<Vec<InlineKeyboardButton>> = toggles.chunks(2).map(|c| c.to_vec()).collect();
+
+    if config.telegram.is_some() {
+        rows.push(vec![
+            InlineKeyboardButton::callback("➖", "settings_threshold:down"),
+            InlineKeyboardButton::callback(tf(Text::AlertAtLeast, &[&threshold]), "settings_threshold:show"),
+            InlineKeyboardButton::callback("➕", "settings_threshold:up"),
+        ]);
+    }
+    rows.push(vec![InlineKeyboardButton::callback("↩️ Reset to config file", "settings_reset")]);
+
+    (text, InlineKeyboardMarkup::new(rows))
+}
+
+/// Apply a settings button press and redraw the menu in place.
+///
+/// Returns the text for the callback answer.
+pub async fn handle_settings_action(
+    bot: Bot,
+    message: &Message,
+    state: Arc<BotState>,
+    action: &str,
+) -> ResponseResult<String> {
+    let config = state.effective_config().await;
+
+    let change = if let Some(key) = action.strip_prefix("toggle:") {
+        match settings::toggle_value(&config, key) {
+            Some(current) => Some((key.to_string(), (!current).to_string())),
+            None => return Ok("Unknown setting".to_string()),
+        }
+    } else if let Some(direction) = action.strip_prefix("threshold:") {
+        let current = config.telegram.as_ref().map(|t| t.alert_threshold_sol).unwrap_or_default();
+        let steps = settings::ALERT_THRESHOLD_STEPS;
+        let next = match direction {
+            "up" => steps.iter().find(|&&s| s > current + f64::EPSILON).copied(),
+            "down" => steps.iter().rev().find(|&&s| s < current - f64::EPSILON).copied(),
+            _ => return Ok(tf(Text::AlertThresholdValue, &[&current])),
+        };
+        match next {
+            Some(value) => Some((settings::ALERT_THRESHOLD_SOL.to_string(), value.to_string())),
+            None => return Ok("Threshold limit reached".to_string()),
+        }
+    } else if action == "reset" {
+        None
+    } else {
+        return Ok("Unknown action".to_string());
+    };
+
+    let result = {
+        let db = state.database.lock().await;
+        match &change {
+            Some((key, value)) => db.set_runtime_setting(key, value).map(|_| ()),
+            None => db.clear_runtime_settings().map(|_| ()),
+        }
+    };
+
+    if let Err(e) = result {
+        error!("Failed to save runtime setting: {}", e);
+        return Ok(format!("❌ Database error: {}", e));
+    }
+
+    let answer = match &change {
+        Some((key, value)) => {
+            info!("Runtime setting {} set to {} via Telegram", key, value);
+            format!("✓ {} = {}", key, value)
+        }
+        None => {
+            info!("Runtime settings reset via Telegram");
+            "✓ Reverted to config file".to_string()
+        }
+    };
+
+    let (text, keyboard) = settings_view(&state.effective_config().await);
+    bot.edit_message_text(message.chat.id, message.id, text)
+        .parse_mode(teloxide::types::ParseMode::MarkdownV2)
+        .reply_markup(keyboard)
+        .await?;
+
+    Ok(answer)
+}
+
+async fn handle_pending(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
+    use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+    let pending = {
+        let db = state.database.lock().await;
+        db.get_pending_passive_reclaims()
+    };
+
+    match pending {
+        Ok(records) if records.is_empty() => {
+            reply(&bot, msg.chat.id, templates::notice("✅", t(Text::NoPendingReviews))).await?;
+        }
+        Ok(records) => {
+            for record in records.iter().take(10) {
+                let text = templates::passive_review(record);
+
+                let keyboard = InlineKeyboardMarkup::new(vec![vec![
+                    InlineKeyboardButton::callback("✅ Confirm", format!("passive_confirm:{}", record.id)),
+                    InlineKeyboardButton::callback("❌ Reject", format!("passive_reject:{}", record.id)),
+                ]]);
+
+                bot.send_message(msg.chat.id, text)
+                    .parse_mode(teloxide::types::ParseMode::MarkdownV2)
+                    .reply_markup(keyboard)
+                    .await?;
+            }
+
+            if records.len() > 10 {
+                let more = tf(Text::MorePending, &[&(records.len() - 10)]);
+                reply(&bot, msg.chat.id, templates::escape(more)).await?;
+            }
+        }
+        Err(e) => {
+            reply(&bot, msg.chat.id, templates::failure(Text::DatabaseError, e)).await?;
+        }
+    }
+    Ok(())
+}
+
+/// Confirm or reject a passive reclaim attribution (shared with inline buttons)
+pub async fn handle_review(
+    bot: Bot,
+    chat_id: ChatId,
+    state: Arc<BotState>,
+    id: i64,
+    status: ReviewStatus,
+) -> ResponseResult<()> {
+    let result = {
+        let db = state.database.lock().await;
+        db.set_passive_reclaim_review(id, status.clone())
+    };
+
+    let text = match result {
+        Ok(true) => {
+            info!("Passive reclaim #{} marked as {} via Telegram", id, status);
+            templates::notice("✓", tf(Text::ReviewMarked, &[&id, &status]))
+        }
+        Ok(false) => templates::notice("ℹ️", tf(Text::ReviewAlreadyResolved, &[&id, &status])),
+        Err(ReclaimError::NotFound(_)) => templates::notice("❌", tf(Text::ReviewNotFound, &[&id])),
+        Err(ReclaimError::StatusConflict(_)) => {
+            templates::notice("⚠️", tf(Text::ReviewConflict, &[&id, &status]))
+        }
+        Err(e) => templates::failure(Text::DatabaseError, e),
+    };
+
+    reply(&bot, chat_id, text).await?;
+    Ok(())
+}
+
+/// Store which notifications the sender receives; `Err` carries unparsed input
+async fn handle_subscribe(
+    bot: Bot,
+    msg: Message,
+    state: Arc<BotState>,
+    level: Result<NotificationLevel, String>,
+) -> ResponseResult<()> {
+    let level = match level {
+        Ok(level) => level,
+        Err(input) => {
+            let text = templates::notice("❌", tf(Text::UnknownNotificationLevel, &[&input]));
+            reply(&bot, msg.chat.id, text).await?;
+            return Ok(());
+        }
+    };
+
+    let Some(user) = msg.from() else {
+        return Ok(());
+    };
+    let user_id = user.id.0 as i64;
+
+    let result = state.database.lock().await.set_notification_level(user_id, level);
+    let text = match result {
+        Ok(()) => {
+            info!("Telegram user {} set notifications to {}", user_id, level);
+            match level {
+                NotificationLevel::Off => templates::notice("🔕", t(Text::Unsubscribed)),
+                NotificationLevel::Everything => templates::notice("🔔", t(Text::SubscribedEverything)),
+                NotificationLevel::HighValue => templates::notice("🔔", t(Text::SubscribedHighValue)),
+                NotificationLevel::Errors => templates::notice("🔔", t(Text::SubscribedErrors)),
+            }
+        }
+        Err(e) => templates::failure(Text::DatabaseError, e),
+    };
+
+    reply(&bot, msg.chat.id, text).await?;
+    Ok(())
+}
@@ -3,7 +3,10 @@ pub mod commands;
 pub mod callbacks;
 pub mod notifications;
 pub mod formatters;
-pub mod auto_notify;  
+pub mod templates;
+pub mod quiet_hours;
+pub mod auto_notify;
+pub mod throttle;  
 
 pub use bot::run_telegram_bot;
 pub use auto_notify::AutoNotifier;  
\ No newline at end of file
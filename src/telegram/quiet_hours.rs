@@ -0,0 +1,94 @@
+// src/telegram/quiet_hours.rs - Do-not-disturb window for notifications
+
+use crate::config::QuietHoursConfig;
+use chrono::{DateTime, FixedOffset, NaiveTime, Utc};
+
+/// Daily window during which non-critical notifications are held back
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuietHours {
+    start: NaiveTime,
+    end: NaiveTime,
+    /// `None` follows the system's local time zone
+    offset: Option<FixedOffset>,
+}
+
+impl QuietHours {
+    pub fn from_config(config: &QuietHoursConfig) -> anyhow::Result<Self> {
+        let time = |value: &str| {
+            NaiveTime::parse_from_str(value, "%H:%M")
+                .map_err(|e| anyhow::anyhow!("Invalid quiet hours time '{}' (expected HH:MM): {}", value, e))
+        };
+
+        let offset = crate::utils::parse_timezone(config.timezone.as_deref())
+            .map_err(|e| anyhow::anyhow!("Quiet hours: {}", e))?;
+
+        Ok(Self {
+            start: time(&config.start)?,
+            end: time(&config.end)?,
+            offset,
+        })
+    }
+
+    /// Whether `now` falls inside the window; windows may wrap past midnight
+    pub fn contains(&self, now: DateTime<Utc>) -> bool {
+        let time = crate::utils::wall_clock(now, self.offset).time();
+
+        if self.start <= self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+
+    pub fn is_quiet_now(&self) -> bool {
+        self.contains(Utc::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(start: &str, end: &str, timezone: &str) -> QuietHours {
+        QuietHours::from_config(&QuietHoursConfig {
+            start: start.to_string(),
+            end: end.to_string(),
+            timezone: Some(timezone.to_string()),
+        })
+        .unwrap()
+    }
+
+    fn at(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_overnight_window_in_offset_timezone() {
+        // 22:00-07:00 in São Paulo (UTC-3) is 01:00-10:00 UTC
+        let quiet = window("22:00", "07:00", "-03:00");
+
+        assert!(quiet.contains(at("2026-03-01T01:00:00Z")));
+        assert!(quiet.contains(at("2026-03-01T09:59:00Z")));
+        assert!(!quiet.contains(at("2026-03-01T10:00:00Z")));
+        assert!(!quiet.contains(at("2026-03-01T00:59:00Z")));
+    }
+
+    #[test]
+    fn test_same_day_window_and_invalid_input() {
+        let quiet = window("12:00", "14:00", "UTC");
+        assert!(quiet.contains(at("2026-03-01T13:30:00Z")));
+        assert!(!quiet.contains(at("2026-03-01T14:00:00Z")));
+
+        let config = |start: &str, timezone: &str| QuietHoursConfig {
+            start: start.to_string(),
+            end: "07:00".to_string(),
+            timezone: Some(timezone.to_string()),
+        };
+        assert!(QuietHours::from_config(&config("25:00", "UTC")).is_err());
+        assert!(QuietHours::from_config(&config("22:00", "Europe/Lisbon")).is_err());
+        assert_eq!(
+            crate::utils::parse_timezone(Some("+0530")),
+            Ok(FixedOffset::east_opt(5 * 3600 + 30 * 60))
+        );
+    }
+}
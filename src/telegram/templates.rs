@@ -0,0 +1,871 @@
+// src/telegram/templates.rs - MarkdownV2 message templates
+//
+// Every bot message is built here. Interpolated values (errors, pubkeys,
+// amounts, URLs) always go through `escape` or `code`, so a stray `.` or `_`
+// can no longer make Telegram reject the whole message.
+
+use crate::config::{Config, Network};
+use crate::i18n::{t, tf, Text};
+use crate::storage::db::DatabaseStats;
+use crate::storage::models::{AccountEpoch, DailyActivity, PassiveReclaimRecord, ReclaimFailure, ReclaimOperation, ReviewStatus, ScanFailureStreak, SponsoredAccount, StagedReclaim};
+use crate::telegram::formatters::format_sol_tg;
+use crate::utils;
+use std::fmt::Display;
+use teloxide::utils::markdown;
+
+/// Accounts listed per message before the rest are summarized
+const LIST_LIMIT: usize = 5;
+
+/// Escape plain text for MarkdownV2
+pub fn escape(text: impl Display) -> String {
+    markdown::escape(&text.to_string())
+}
+
+pub fn bold(text: impl Display) -> String {
+    format!("*{}*", escape(text))
+}
+
+pub fn italic(text: impl Display) -> String {
+    format!("_{}_", escape(text))
+}
+
+/// Monospace span; only `` ` `` and `\` need escaping inside it
+pub fn code(text: impl Display) -> String {
+    markdown::code_inline(&text.to_string())
+}
+
+/// Escaped SOL amount, e.g. `0\.002039280 SOL`
+pub fn sol(lamports: u64) -> String {
+    escape(format_sol_tg(lamports))
+}
+
+/// Truncated pubkey in monospace
+pub fn pubkey(key: &str) -> String {
+    code(utils::format_pubkey(key))
+}
+
+/// Inline link with escaped text
+pub fn link(text: impl Display, url: &str) -> String {
+    markdown::link(url, &escape(text))
+}
+
+/// Line-by-line MarkdownV2 message under a bold title
+#[derive(Debug, Clone)]
+pub struct MessageBuilder {
+    lines: Vec<String>,
+}
+
+impl MessageBuilder {
+    pub fn new(emoji: &str, title: &str) -> Self {
+        Self {
+            lines: vec![format!("{} {}", emoji, bold(title)), String::new()],
+        }
+    }
+
+    /// Plain text line
+    pub fn text(self, text: impl Display) -> Self {
+        self.markdown(escape(text))
+    }
+
+    /// Line that is already valid MarkdownV2
+    pub fn markdown(mut self, markdown: impl Into<String>) -> Self {
+        self.lines.push(markdown.into());
+        self
+    }
+
+    /// `Label: value` with the value escaped
+    pub fn field(self, label: &str, value: impl Display) -> Self {
+        let value = escape(value);
+        self.field_markdown(label, value)
+    }
+
+    /// `Label: value` with the value already formatted
+    pub fn field_markdown(self, label: &str, value: impl Display) -> Self {
+        let line = format!("{}: {}", escape(label), value);
+        self.markdown(line)
+    }
+
+    pub fn blank(self) -> Self {
+        self.markdown(String::new())
+    }
+
+    /// Italic closing note after a blank line
+    pub fn footer(self, note: &str) -> Self {
+        self.blank().markdown(italic(note))
+    }
+
+    pub fn build(self) -> String {
+        self.lines.join("\n")
+    }
+}
+
+/// Single-line reply, e.g. `❌ Scan failed: <error>`
+pub fn notice(emoji: &str, text: impl Display) -> String {
+    format!("{} {}", emoji, escape(text))
+}
+
+/// `❌ <context>: <error>`
+pub fn failure(context: Text, error: impl Display) -> String {
+    notice("❌", format!("{}: {}", t(context), error))
+}
+
+// ---- Notifications -------------------------------------------------------
+
+pub fn passive_reclaim(id: Option<i64>, amount: u64, accounts: &[String], confidence: &str) -> String {
+    let mut message = MessageBuilder::new("🔄", t(Text::PassiveReclaimDetected))
+        .field_markdown(t(Text::Amount), bold(format_sol_tg(amount)))
+        .field(t(Text::Confidence), confidence);
+
+    if accounts.len() <= 3 {
+        message = message.text(format!("{}:", t(Text::LikelyFrom)));
+        for account in accounts {
+            message = message.markdown(format!("• {}", pubkey(account)));
+        }
+    } else {
+        message = message.field(t(Text::LikelyFrom), tf(Text::AccountCount, &[&accounts.len()]));
+    }
+
+    message = message.blank().text(t(Text::PassiveExplanation));
+
+    match id {
+        Some(id) if ReviewStatus::for_confidence(confidence) == ReviewStatus::Pending => message
+            .blank()
+            .text(tf(Text::LowConfidenceReview, &[&id, &id]))
+            .build(),
+        _ => message.build(),
+    }
+}
+
+pub fn passive_account_closed(account: &str, amount: u64) -> String {
+    MessageBuilder::new("👋", t(Text::UserClosedAccount))
+        .field_markdown(t(Text::Account), pubkey(account))
+        .field_markdown(t(Text::Returned), bold(format_sol_tg(amount)))
+        .blank()
+        .text(t(Text::ClosureCorrelation))
+        .build()
+}
+
+pub fn scan_complete(total: usize, eligible: usize) -> String {
+    MessageBuilder::new("🔍", t(Text::ScanComplete))
+        .field(&format!("📊 {}", t(Text::TotalSponsoredAccounts)), total)
+        .field(&format!("✅ {}", t(Text::EligibleForReclaim)), eligible)
+        .footer(t(Text::ScanFooter))
+        .build()
+}
+
+pub fn reclaim_success(account: &str, amount: u64) -> String {
+    MessageBuilder::new("✅", t(Text::ReclaimSuccessful))
+        .field_markdown(t(Text::Account), pubkey(account))
+        .field_markdown(t(Text::Amount), bold(format_sol_tg(amount)))
+        .footer(t(Text::ReclaimFooter))
+        .build()
+}
+
+pub fn reclaim_failed(account: &str, error: &str, retryable: bool) -> String {
+    MessageBuilder::new("❌", t(Text::ReclaimFailed))
+        .field_markdown(t(Text::Account), pubkey(account))
+        .field(t(Text::Error), error)
+        .footer(t(if retryable { Text::RetryFooter } else { Text::CheckLogsFooter }))
+        .build()
+}
+
+pub fn credit_mismatch(account: &str, signature: &str, expected: u64, credited: i64) -> String {
+    MessageBuilder::new("🚨", t(Text::CreditMismatch))
+        .field_markdown(t(Text::Account), pubkey(account))
+        .field_markdown(t(Text::Signature), code(signature))
+        .field(t(Text::ExpectedCredit), format!("{} lamports", expected))
+        .field(t(Text::ActualCredit), format!("{} lamports", credited))
+        .footer(t(Text::CreditMismatchFooter))
+        .build()
+}
+
+pub fn batch_complete(successful: usize, failed: usize, total_reclaimed: u64) -> String {
+    let emoji = if failed == 0 { "🎉" } else { "📦" };
+    MessageBuilder::new(emoji, t(Text::BatchReclaimComplete))
+        .field(&format!("✅ {}", t(Text::Successful)), successful)
+        .field(&format!("❌ {}", t(Text::Failed)), failed)
+        .field_markdown(&format!("💰 {}", t(Text::TotalReclaimed)), bold(format_sol_tg(total_reclaimed)))
+        .footer(t(Text::BatchFooter))
+        .build()
+}
+
+pub fn batch_planned(transactions: usize, estimated_cost: u64, expected_recovery: u64, aborted: bool) -> String {
+    let (emoji, title) = if aborted {
+        ("🛑", Text::BatchAborted)
+    } else {
+        ("🧮", Text::BatchPlanned)
+    };
+    let builder = MessageBuilder::new(emoji, t(title))
+        .field(t(Text::Transactions), transactions)
+        .field_markdown(t(Text::EstimatedFees), sol(estimated_cost))
+        .field_markdown(t(Text::ExpectedRecovery), bold(format_sol_tg(expected_recovery)));
+
+    if aborted {
+        builder.footer(t(Text::BatchAbortedFooter)).build()
+    } else {
+        builder.build()
+    }
+}
+
+pub fn error(message: &str) -> String {
+    MessageBuilder::new("⚠️", t(Text::ErrorOccurred))
+        .text(message)
+        .footer(t(Text::SystemLogsFooter))
+        .build()
+}
+
+/// Auto-service discovery failure; `escalated` once the streak reached
+/// `reclaim.scan_failure_alert_after`
+pub fn scan_failed(error: &str, consecutive_failures: u32, escalated: bool) -> String {
+    let (emoji, title, footer) = if escalated {
+        ("🚨", Text::ScansFailing, Text::ScansFailingFooter)
+    } else {
+        ("⚠️", Text::ScanFailedTitle, Text::ScanRetryFooter)
+    };
+    MessageBuilder::new(emoji, t(title))
+        .field(t(Text::ScanFailures), tf(Text::FailuresInARow, &[&consecutive_failures]))
+        .field(t(Text::Error), error)
+        .footer(t(footer))
+        .build()
+}
+
+/// The RPC endpoint has been failing for `down_minutes`
+pub fn rpc_degraded(error: &str, down_minutes: i64) -> String {
+    MessageBuilder::new("🚨", t(Text::RpcDegraded))
+        .field(t(Text::Down), tf(Text::Minutes, &[&down_minutes]))
+        .field(t(Text::Error), error)
+        .footer(t(Text::RpcDegradedFooter))
+        .build()
+}
+
+pub fn rpc_recovered(down_minutes: i64) -> String {
+    MessageBuilder::new("✅", t(Text::RpcRecovered))
+        .field(t(Text::Down), tf(Text::Minutes, &[&down_minutes]))
+        .footer(t(Text::RpcRecoveredFooter))
+        .build()
+}
+
+/// A daemon service failed and is about to be restarted
+pub fn service_restarted(service: &str, error: &str, consecutive: u32, backoff_seconds: u64) -> String {
+    MessageBuilder::new("🔁", t(Text::ServiceRestarted))
+        .field(t(Text::Service), service)
+        .field(t(Text::Restarts), tf(Text::FailuresInARow, &[&consecutive]))
+        .field(t(Text::RestartingIn), tf(Text::Seconds, &[&backoff_seconds]))
+        .field(t(Text::Error), error)
+        .footer(t(Text::ServiceRestartedFooter))
+        .build()
+}
+
+pub fn high_value_reclaim(account: &str, amount: u64, threshold_sol: f64) -> String {
+    let threshold = format!("{:.2}", threshold_sol);
+    MessageBuilder::new("💎", t(Text::HighValueReclaim))
+        .field_markdown(t(Text::Account), pubkey(account))
+        .field_markdown(t(Text::Amount), bold(format_sol_tg(amount)))
+        .blank()
+        .markdown(format!("⚠️ {}", italic(tf(Text::ExceedsThreshold, &[&threshold]))))
+        .build()
+}
+
+/// A high-value reclaim was simulated and waits for its delay or an approval
+pub fn reclaim_staged(
+    account: &str,
+    amount: u64,
+    execute_after: &chrono::DateTime<chrono::Utc>,
+    requires_approval: bool,
+    units_consumed: Option<u64>,
+    simulation_error: Option<&str>,
+) -> String {
+    let mut message = MessageBuilder::new("⏳", t(Text::ReclaimStaged))
+        .field_markdown(t(Text::Account), code(account))
+        .field_markdown(t(Text::Amount), bold(format_sol_tg(amount)));
+    message = if requires_approval {
+        message.field(t(Text::RunsAfter), t(Text::AwaitingApproval))
+    } else {
+        message.field(t(Text::RunsAfter), utils::format_timestamp(execute_after))
+    };
+    if let Some(units) = units_consumed {
+        message = message.field(t(Text::ComputeUnits), units);
+    }
+    if let Some(error) = simulation_error {
+        message = message.field(t(Text::SimulationFailed), error);
+    }
+    let footer = if requires_approval { Text::StagedApprovalFooter } else { Text::StagedDelayFooter };
+    message.footer(&tf(footer, &[&account, &account])).build()
+}
+
+pub fn staged_reclaims(staged: &[StagedReclaim], require_approval: bool) -> String {
+    let mut message = MessageBuilder::new("⏳", &format!("{} ({})", t(Text::StagedReclaims), staged.len()));
+
+    for reclaim in staged.iter().take(LIST_LIMIT) {
+        let status = match &reclaim.approved_by {
+            Some(by) => tf(Text::Approved, &[by]),
+            None if require_approval => t(Text::AwaitingApproval).to_string(),
+            None => format!("{}: {}", t(Text::RunsAfter), utils::format_timestamp(&reclaim.execute_after)),
+        };
+        message = message
+            .markdown(format!("• {} {}", code(&reclaim.pubkey), sol(reclaim.amount)))
+            .text(format!("  {}", status));
+        if let Some(error) = &reclaim.simulation_error {
+            message = message.text(format!("  {}: {}", t(Text::SimulationFailed), error));
+        }
+        message = message.blank();
+    }
+
+    if staged.len() > LIST_LIMIT {
+        message = message.markdown(italic(tf(Text::AndMore, &[&(staged.len() - LIST_LIMIT)])));
+    }
+
+    message.build()
+}
+
+/// `amount (change vs yesterday)`
+fn vs_yesterday(amount: impl Display, change: impl Display) -> String {
+    tf(Text::VsYesterday, &[&amount, &change])
+}
+
+pub fn daily_summary(
+    activity: &DailyActivity,
+    previous: &DailyActivity,
+    locked_lamports: u64,
+    locked_change: Option<i64>,
+    sol_usd: Option<f64>,
+) -> String {
+    let sol = |lamports: i64| format!("{:.4} SOL", lamports as f64 / 1_000_000_000.0);
+    let change = |now: u64, before: u64| format!("{:+.4}", (now as i64 - before as i64) as f64 / 1_000_000_000.0);
+    let count_change = |now: usize, before: usize| format!("{:+}", now as i64 - before as i64);
+    // Fees are a few thousand lamports each
+    let fee = |lamports: i64| format!("{:.6} SOL", lamports as f64 / 1_000_000_000.0);
+
+    let passive = format!("{}, {}", activity.passive_reclaims, sol(activity.passive_reclaimed_lamports as i64));
+    let net = activity.net_lamports();
+    let locked = match locked_change {
+        Some(change) => vs_yesterday(sol(locked_lamports as i64), format!("{:+.4}", change as f64 / 1_000_000_000.0)),
+        None => sol(locked_lamports as i64),
+    };
+
+    let mut message = MessageBuilder::new("📈", t(Text::DailySummary))
+        .field(t(Text::Operations), vs_yesterday(activity.operations, count_change(activity.operations, previous.operations)))
+        .field(
+            t(Text::TotalReclaimed),
+            vs_yesterday(
+                sol(activity.reclaimed_lamports as i64),
+                change(activity.reclaimed_lamports, previous.reclaimed_lamports),
+            ),
+        )
+        .field(
+            t(Text::PassiveReclaimsField),
+            vs_yesterday(passive, change(activity.passive_reclaimed_lamports, previous.passive_reclaimed_lamports)),
+        )
+        .field(
+            t(Text::FeesPaid),
+            vs_yesterday(
+                fee(activity.fee_lamports as i64),
+                format!("{:+.6}", (activity.fee_lamports as i64 - previous.fee_lamports as i64) as f64 / 1_000_000_000.0),
+            ),
+        )
+        .field_markdown(
+            t(Text::NetRecovery),
+            bold(vs_yesterday(
+                sol(net),
+                format!("{:+.4}", (net - previous.net_lamports()) as f64 / 1_000_000_000.0),
+            )),
+        )
+        .field(t(Text::Failed), vs_yesterday(activity.failures, count_change(activity.failures, previous.failures)))
+        .field(t(Text::StillLocked), locked);
+    if let Some(sol_usd) = sol_usd {
+        let usd = |lamports: i64| crate::price::format_usd(lamports as f64 / 1_000_000_000.0 * sol_usd);
+        message = message.field(
+            t(Text::UsdValue),
+            tf(Text::UsdNetLocked, &[&usd(net), &usd(locked_lamports as i64)]),
+        );
+    }
+    message.footer(t(Text::DailyFooter)).build()
+}
+
+/// First message of a quiet-hours digest
+pub fn digest_header(count: usize) -> String {
+    MessageBuilder::new("🌅", t(Text::DigestTitle))
+        .text(tf(Text::DigestBody, &[&count]))
+        .build()
+}
+
+// ---- Command replies -----------------------------------------------------
+
+pub fn welcome() -> String {
+    MessageBuilder::new("👋", t(Text::WelcomeTitle))
+        .text(t(Text::WelcomeBody))
+        .blank()
+        .text(t(Text::HelpHint))
+        .build()
+}
+
+pub fn status(config: &Config, scan_failures: &ScanFailureStreak) -> String {
+    let network = match config.solana.network {
+        Network::Mainnet => "Mainnet",
+        Network::Devnet => "Devnet",
+        Network::Testnet => "Testnet",
+    };
+    let mode = if config.reclaim.auto_reclaim_enabled { Text::ModeAuto } else { Text::ModeManual };
+
+    let mut message = MessageBuilder::new("🟢", t(Text::BotOnline))
+        .field(t(Text::Network), network)
+        .field(t(Text::Mode), t(mode))
+        .field(t(Text::DryRun), config.reclaim.dry_run)
+        .field_markdown(t(Text::Operator), pubkey(&config.kora.operator_pubkey));
+
+    if scan_failures.is_failing() {
+        let emoji = if scan_failures.is_escalated(config.reclaim.scan_failure_alert_after) { "🚨" } else { "⚠️" };
+        let failures = tf(Text::FailuresInARow, &[&scan_failures.consecutive_failures]);
+        message = message
+            .blank()
+            .field_markdown(&format!("{} {}", emoji, t(Text::ScanFailures)), bold(failures))
+            .field(t(Text::LastScanError), scan_failures.last_error.as_deref().unwrap_or_default());
+    }
+    message.build()
+}
+
+pub fn scan_saved(found: usize, saved: usize) -> String {
+    MessageBuilder::new("✅", t(Text::ScanSavedTitle))
+        .field(t(Text::Found), tf(Text::AccountCount, &[&found]))
+        .field(t(Text::Saved), tf(Text::SavedToDatabase, &[&saved]))
+        .blank()
+        .text(t(Text::ViewAccountsHint))
+        .build()
+}
+
+pub fn scan_progress(signatures: usize, accounts: usize, elapsed_secs: u64) -> String {
+    MessageBuilder::new("🔍", t(Text::ScanInProgress))
+        .field(t(Text::SignaturesProcessed), signatures)
+        .field(t(Text::Found), tf(Text::AccountCount, &[&accounts]))
+        .field(t(Text::Elapsed), format!("{}s", elapsed_secs))
+        .build()
+}
+
+pub fn scan_cancelled(signatures: usize, accounts: usize) -> String {
+    MessageBuilder::new("✖️", t(Text::ScanCancelled))
+        .field(t(Text::SignaturesProcessed), signatures)
+        .field(t(Text::Found), tf(Text::AccountCount, &[&accounts]))
+        .text(t(Text::ScanCancelledHint))
+        .build()
+}
+
+pub fn scan_not_saved(found: usize, error: impl Display) -> String {
+    format!(
+        "{}\n\n{}",
+        notice("⚠️", tf(Text::SaveFailed, &[&found, &error])),
+        escape(t(Text::NotPersisted))
+    )
+}
+
+/// First few accounts of a list with their rent
+pub fn account_list(emoji: &str, title: Text, accounts: &[SponsoredAccount]) -> String {
+    let mut message = MessageBuilder::new(emoji, &format!("{} ({})", t(title), accounts.len()));
+
+    for account in accounts.iter().take(LIST_LIMIT) {
+        message = message
+            .markdown(format!("• {}", code(&account.pubkey)))
+            .text(format!("  {}", tf(Text::RentLamports, &[&account.rent_lamports])))
+            .blank();
+    }
+
+    if accounts.len() > LIST_LIMIT {
+        message = message.markdown(italic(tf(Text::AndMore, &[&(accounts.len() - LIST_LIMIT)])));
+    }
+
+    message.build()
+}
+
+/// Accounts reaching `min_inactive_days` within `days`, soonest first
+pub fn upcoming(accounts: &[SponsoredAccount], days: u64, min_inactive_days: u64) -> String {
+    let total: u64 = accounts.iter().map(SponsoredAccount::balance).sum();
+    let now = chrono::Utc::now();
+    let mut message = MessageBuilder::new("⏳", &format!("{} ({})", tf(Text::UpcomingTitle, &[&days]), accounts.len()))
+        .field(t(Text::EstReclaimable), format_sol_tg(total))
+        .blank();
+
+    for account in accounts.iter().take(LIST_LIMIT) {
+        message = message
+            .markdown(format!("• {} {}", code(&account.pubkey), sol(account.balance())))
+            .text(format!("  {}", tf(Text::EligibleInDays, &[&account.days_until_eligible(min_inactive_days, now)])))
+            .blank();
+    }
+
+    if accounts.len() > LIST_LIMIT {
+        message = message.markdown(italic(tf(Text::AndMore, &[&(accounts.len() - LIST_LIMIT)])));
+    }
+
+    message.markdown(italic(t(Text::UpcomingNote))).build()
+}
+
+/// Largest reclaimable accounts, numbered to match their Reclaim buttons
+pub fn top_accounts(accounts: &[SponsoredAccount]) -> String {
+    let total: u64 = accounts.iter().map(SponsoredAccount::balance).sum();
+    let mut message = MessageBuilder::new("🏆", &tf(Text::TopTitle, &[&accounts.len()]))
+        .field(t(Text::EstReclaimable), format_sol_tg(total))
+        .blank();
+
+    for (rank, account) in accounts.iter().enumerate() {
+        message = message.markdown(format!(
+            "{} {} {}",
+            escape(format!("{}.", rank + 1)),
+            code(&account.pubkey),
+            sol(account.balance())
+        ));
+    }
+
+    message.blank().markdown(italic(t(Text::TopNote))).build()
+}
+
+/// Accounts held past `reclaim.min_inactive_days`, released last first
+pub fn holds(accounts: &[SponsoredAccount], min_inactive_days: u64) -> String {
+    let mut message = MessageBuilder::new("⏸️", &format!("{} ({})", t(Text::HeldAccounts), accounts.len()))
+        .field(t(Text::MinInactive), tf(Text::Days, &[&min_inactive_days]))
+        .blank();
+
+    for account in accounts.iter().take(LIST_LIMIT) {
+        let until = utils::format_timestamp(&account.eligible_at(min_inactive_days));
+        message = message
+            .markdown(format!("• {} {}", code(&account.pubkey), sol(account.balance())))
+            .text(format!("  {}", tf(Text::HeldUntil, &[&account.min_inactive_days(min_inactive_days), &until])))
+            .blank();
+    }
+
+    if accounts.len() > LIST_LIMIT {
+        message = message.markdown(italic(tf(Text::AndMore, &[&(accounts.len() - LIST_LIMIT)])));
+    }
+
+    message.build()
+}
+
+pub fn eligibility(eligible: usize, total_reclaimable: u64) -> String {
+    MessageBuilder::new("💰", t(Text::EligibilityCheck))
+        .text(tf(Text::FoundEligible, &[&eligible]))
+        .field(t(Text::EstReclaimable), format_sol_tg(total_reclaimable))
+        .build()
+}
+
+pub fn stats(stats: &DatabaseStats) -> String {
+    MessageBuilder::new("📊", t(Text::BotStatistics))
+        .markdown(bold(t(Text::Accounts)))
+        .field(t(Text::Total), stats.total_accounts)
+        .field(t(Text::Active), stats.active_accounts)
+        .field(t(Text::Closed), stats.closed_accounts)
+        .field(t(Text::Reclaimed), stats.reclaimed_accounts)
+        .blank()
+        .markdown(bold(t(Text::Operations)))
+        .field(t(Text::TotalOps), stats.total_operations)
+        .field(t(Text::Reclaimed), format_sol_tg(stats.total_reclaimed))
+        .field(t(Text::Avg), tf(Text::Lamports, &[&stats.avg_reclaim_amount]))
+        .build()
+}
+
+pub fn settings(config: &Config, alert_threshold_sol: f64) -> String {
+    let setting = |label: &str, value: String| format!("{}: {}", bold(label), value);
+
+    MessageBuilder::new("⚙️", t(Text::Settings))
+        .markdown(setting("RPC", code(&config.solana.rpc_url)))
+        .markdown(setting(t(Text::MinInactive), escape(tf(Text::Days, &[&config.reclaim.min_inactive_days]))))
+        .markdown(setting(t(Text::BatchSize), escape(config.reclaim.batch_size)))
+        .markdown(setting(t(Text::Database), code(&config.database.path)))
+        .markdown(setting(t(Text::AlertThreshold), escape(format!("{} SOL", alert_threshold_sol))))
+        .footer(t(Text::SettingsFooter))
+        .build()
+}
+
+pub fn passive_review(record: &PassiveReclaimRecord) -> String {
+    let accounts = if record.attributed_accounts.is_empty() {
+        escape(t(Text::NoCandidateAccounts))
+    } else {
+        record.attributed_accounts
+            .iter()
+            .map(|a| pubkey(a))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    MessageBuilder::new("🔎", &tf(Text::PassiveReclaimId, &[&record.id]))
+        .field(t(Text::Amount), format_sol_tg(record.amount))
+        .field(t(Text::Confidence), &record.confidence)
+        .field(t(Text::Detected), utils::format_timestamp(&record.timestamp))
+        .field_markdown(t(Text::LikelyFrom), accounts)
+        .build()
+}
+
+pub fn close_request(account: &str, signer: &str, lamports: u64, link: &str) -> String {
+    MessageBuilder::new("📨", t(Text::CloseRequest))
+        .field_markdown(t(Text::Account), pubkey(account))
+        .field_markdown(t(Text::Signer), pubkey(signer))
+        .field_markdown(t(Text::Amount), bold(format_sol_tg(lamports)))
+        .blank()
+        .markdown(code(link))
+        .footer(t(Text::CloseRequestFooter))
+        .build()
+}
+
+/// Current state of an account as seen on-chain
+#[derive(Debug, Clone)]
+pub struct OnChainState {
+    pub lamports: u64,
+    pub owner: String,
+    pub account_type: String,
+}
+
+/// Everything `/account` knows about one account
+#[derive(Debug, Clone)]
+pub struct AccountDetails {
+    pub pubkey: String,
+    pub stored: Option<SponsoredAccount>,
+    /// `None` if the account no longer exists
+    pub on_chain: Option<OnChainState>,
+    pub eligibility: String,
+    pub last_activity: Option<chrono::DateTime<chrono::Utc>>,
+    pub failure: Option<ReclaimFailure>,
+    pub operations: Vec<ReclaimOperation>,
+    /// Earlier creations of the address, if it was closed and created again
+    pub epochs: Vec<AccountEpoch>,
+}
+
+pub fn account_details(details: &AccountDetails, network: &Network) -> String {
+    let mut message = MessageBuilder::new("🔎", t(Text::AccountDetails))
+        .markdown(code(&details.pubkey))
+        .blank()
+        .markdown(bold(t(Text::Database)));
+
+    message = match &details.stored {
+        Some(account) => {
+            let strategy = account.reclaim_strategy
+                .as_ref()
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| t(Text::Unknown).to_string());
+            let message = message
+                .field(t(Text::Status), format!("{:?}", account.status))
+                .field(t(Text::Strategy), strategy)
+                .field(t(Text::Created), utils::format_timestamp(&account.created_at))
+                .field(t(Text::Rent), format_sol_tg(account.rent_lamports));
+            match account.min_inactive_days {
+                Some(days) => {
+                    let until = utils::format_timestamp(&account.eligible_at(days));
+                    message.field(t(Text::Hold), tf(Text::HeldUntil, &[&days, &until]))
+                }
+                None => message,
+            }
+        }
+        None => message.text(t(Text::NotTracked)),
+    };
+
+    message = message.blank().markdown(bold(t(Text::OnChain)));
+    message = match &details.on_chain {
+        Some(state) => message
+            .field(t(Text::Balance), format_sol_tg(state.lamports))
+            .field_markdown(t(Text::Owner), pubkey(&state.owner))
+            .field(t(Text::Type), &state.account_type),
+        None => message.text(t(Text::NotOnChain)),
+    };
+
+    let last_activity = details.last_activity
+        .map(|at| utils::format_timestamp(&at))
+        .unwrap_or_else(|| t(Text::NoActivity).to_string());
+    message = message
+        .blank()
+        .field(t(Text::Eligibility), &details.eligibility)
+        .field(t(Text::LastActivity), last_activity);
+
+    if let Some(failure) = &details.failure {
+        let mut failures = tf(Text::FailureCount, &[&failure.failures]);
+        if failure.dead_lettered_at.is_some() {
+            failures = format!("{}, {}", failures, t(Text::DeadLettered));
+        }
+        message = message
+            .field(t(Text::Failed), failures)
+            .field(t(Text::Error), &failure.last_error);
+    }
+
+    message = message.blank().markdown(bold(t(Text::ReclaimOperations)));
+    if details.operations.is_empty() {
+        message = message.text(t(Text::NoOperations));
+    }
+    for operation in details.operations.iter().take(LIST_LIMIT) {
+        let url = network.explorer_url(&format!("tx/{}", operation.tx_signature));
+        message = message.markdown(format!(
+            "• {} {}: {}",
+            sol(operation.reclaimed_amount),
+            escape(format!("({}, {})", operation.reason.label(), utils::format_timestamp(&operation.timestamp))),
+            link(utils::format_pubkey(&operation.tx_signature), &url),
+        ));
+    }
+    if details.operations.len() > LIST_LIMIT {
+        message = message.markdown(italic(tf(Text::AndMore, &[&(details.operations.len() - LIST_LIMIT)])));
+    }
+
+    if !details.epochs.is_empty() {
+        message = message.blank().markdown(bold(t(Text::EarlierCreations)));
+        for epoch in &details.epochs {
+            message = message.markdown(format!(
+                "• {} {}",
+                sol(epoch.rent_lamports),
+                escape(format!(
+                    "(#{}, {:?}, {})",
+                    epoch.epoch,
+                    epoch.status,
+                    utils::format_timestamp(&epoch.created_at)
+                )),
+            ));
+        }
+    }
+
+    let url = network.explorer_url(&format!("address/{}", details.pubkey));
+    message.blank().markdown(link(t(Text::ViewOnExplorer), &url)).build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Characters MarkdownV2 rejects unless preceded by a backslash
+    const RESERVED: &[char] = &[
+        '_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.', '!',
+    ];
+
+    /// True if every reserved character outside code spans is escaped
+    fn is_escaped(markdown: &str, allowed: &[char]) -> bool {
+        let mut chars = markdown.chars();
+        let mut in_code = false;
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                chars.next();
+            } else if c == '`' {
+                in_code = !in_code;
+            } else if !in_code && RESERVED.contains(&c) && !allowed.contains(&c) {
+                return false;
+            }
+        }
+        !in_code
+    }
+
+    #[test]
+    fn test_interpolated_errors_are_escaped() {
+        let error = "RPC error (429): too_many requests - retry in 1.5s!";
+        let message = reclaim_failed("7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU", error, true);
+
+        assert!(message.contains("RPC error \\(429\\): too\\_many requests \\- retry in 1\\.5s\\!"));
+        assert!(message.contains("`7xKXtg...osgAsU`"));
+        assert!(is_escaped(&message, &['*', '_']));
+    }
+
+    #[test]
+    fn test_templates_produce_valid_markdown() {
+        let mut config: Config = toml::from_str(include_str!("../../config.toml")).unwrap();
+        config.solana.rpc_url = "https://api.devnet.solana.com/?key=a_b`c".to_string();
+
+        let messages = [
+            passive_reclaim(Some(4), 2_039_280, &["Abc.def".to_string()], "Low"),
+            passive_account_closed("Abc_def", 2_039_280),
+            scan_complete(12, 3),
+            batch_complete(2, 1, 4_078_560),
+            batch_planned(2, 10_000, 4_078_560, true),
+            error("Scan failed: invalid pubkey [x]"),
+            high_value_reclaim("short", 5_000_000_000, 1.5),
+            daily_summary(
+                &DailyActivity {
+                    operations: 1,
+                    reclaimed_lamports: 2_039_280,
+                    fee_lamports: 5_000,
+                    passive_reclaims: 1,
+                    passive_reclaimed_lamports: 2_039_280,
+                    failures: 2,
+                },
+                &DailyActivity::default(),
+                10_000_000_000,
+                Some(-2_039_280),
+                Some(142.5),
+            ),
+            welcome(),
+            status(&config, &ScanFailureStreak::default()),
+            status(&config, &ScanFailureStreak {
+                consecutive_failures: 4,
+                last_error: Some("RPC error (429): too_many requests".to_string()),
+                last_failed_at: None,
+            }),
+            scan_failed("RPC error (429): too_many requests", 2, false),
+            scan_failed("RPC error (429): too_many requests", 3, true),
+            scan_not_saved(3, "disk full (os error 28)"),
+            scan_progress(1_200, 4, 35),
+            scan_cancelled(1_200, 4),
+            rpc_degraded("connection refused (os error 111)", 12),
+            rpc_recovered(47),
+            service_restarted("Telegram bot", "panicked: dispatcher crashed", 2, 10),
+            reclaim_staged("Abc", 5_000_000_000, &chrono::Utc::now(), false, Some(4_150), None),
+            reclaim_staged("Abc", 5_000_000_000, &chrono::Utc::now(), true, None, Some("InstructionError(0, Custom(11))")),
+            staged_reclaims(&[StagedReclaim {
+                pubkey: "Abc".to_string(),
+                amount: 5_000_000_000,
+                staged_at: chrono::Utc::now(),
+                execute_after: chrono::Utc::now(),
+                units_consumed: Some(4_150),
+                simulation_error: Some("blockhash not found".to_string()),
+                approved_at: None,
+                approved_by: None,
+            }], true),
+            eligibility(1, 2_039_280),
+            upcoming(&[SponsoredAccount::new(solana_sdk::pubkey::Pubkey::new_unique(), 2_039_280, 165)], 7, 30),
+            holds(&[SponsoredAccount::new(solana_sdk::pubkey::Pubkey::new_unique(), 2_039_280, 165)], 30),
+            top_accounts(&[SponsoredAccount::new(solana_sdk::pubkey::Pubkey::new_unique(), 2_039_280, 165)]),
+            settings(&config, 0.25),
+            close_request("Abc", "Def", 2_039_280, "solana:https://reclaim.example.com/close-request/Abc"),
+        ];
+
+        for message in &messages {
+            assert!(is_escaped(message, &['*', '_']), "unescaped markdown in {:?}", message);
+        }
+        assert!(settings(&config, 0.25).contains("`https://api.devnet.solana.com/?key=a_b\\`c`"));
+    }
+
+    #[test]
+    fn test_account_details_links_to_explorer() {
+        use crate::storage::models::ReclaimReason;
+
+        let account = SponsoredAccount::new(solana_sdk::pubkey::Pubkey::new_unique(), 2_039_280, 165);
+        let details = AccountDetails {
+            pubkey: account.pubkey.clone(),
+            on_chain: None,
+            eligibility: "Account is closed (already reclaimed or user-closed)".to_string(),
+            last_activity: None,
+            failure: None,
+            operations: vec![ReclaimOperation {
+                id: 1,
+                account_pubkey: account.pubkey.clone(),
+                reclaimed_amount: 2_039_280,
+                tx_signature: "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnb".to_string(),
+                timestamp: chrono::Utc::now(),
+                reason: ReclaimReason::AutoBatch,
+                credited_amount: Some(2_039_280),
+                destination: None,
+                fee_lamports: None,
+            }],
+            epochs: vec![AccountEpoch {
+                pubkey: account.pubkey.clone(),
+                epoch: 1,
+                creation_signature: "3xWgBHxCnH8FKRPnAqZkgJtXp4VtsXr1hEWLe8hUqJVN".to_string(),
+                creation_slot: Some(1_000),
+                created_at: chrono::Utc::now(),
+                closed_at: None,
+                rent_lamports: 2_039_280,
+                status: crate::storage::models::AccountStatus::Reclaimed,
+            }],
+            stored: Some(account),
+        };
+
+        let message = account_details(&details, &Network::Devnet);
+        assert!(message.contains(
+            "(https://explorer.solana.com/tx/5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnb?cluster=devnet)"
+        ));
+
+        // Link targets follow their own escaping rules; check everything else
+        let mut parts = message.split("](");
+        let mut text = parts.next().unwrap().to_string();
+        for part in parts {
+            text.push_str(part.split_once(')').map_or(part, |(_, rest)| rest));
+        }
+        assert!(is_escaped(&text, &['*', '_', '[']));
+    }
+}
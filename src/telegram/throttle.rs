@@ -0,0 +1,78 @@
+// src/telegram/throttle.rs - Per-user cooldowns and a shared limit for expensive commands
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Why an expensive command was refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Throttled {
+    /// The user ran the same command too recently; wait this long
+    Cooldown(Duration),
+    /// Every slot is taken by other scans or eligibility checks
+    Busy,
+}
+
+/// Guards commands that page through transaction history (/scan, /eligible),
+/// since they all draw on the same RPC quota
+pub struct CommandThrottle {
+    cooldown: Duration,
+    /// Last accepted run per (user, command)
+    last_run: Mutex<HashMap<(u64, &'static str), Instant>>,
+    running: Arc<Semaphore>,
+}
+
+impl CommandThrottle {
+    pub fn new(cooldown: Duration, max_concurrent: usize) -> Self {
+        Self {
+            cooldown,
+            last_run: Mutex::default(),
+            running: Arc::new(Semaphore::new(max_concurrent.max(1))),
+        }
+    }
+
+    /// Claim a slot for `command`; the run holds it until the permit is dropped.
+    /// A refused attempt does not restart the user's cooldown.
+    pub fn start(&self, user_id: u64, command: &'static str) -> Result<OwnedSemaphorePermit, Throttled> {
+        self.start_at(user_id, command, Instant::now())
+    }
+
+    fn start_at(&self, user_id: u64, command: &'static str, now: Instant) -> Result<OwnedSemaphorePermit, Throttled> {
+        let mut last_run = self.last_run.lock().unwrap();
+        if let Some(last) = last_run.get(&(user_id, command)) {
+            let ready_at = *last + self.cooldown;
+            if ready_at > now {
+                return Err(Throttled::Cooldown(ready_at - now));
+            }
+        }
+
+        let permit = Arc::clone(&self.running).try_acquire_owned().map_err(|_| Throttled::Busy)?;
+        last_run.insert((user_id, command), now);
+        Ok(permit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cooldown_per_user_and_shared_slots() {
+        let throttle = CommandThrottle::new(Duration::from_secs(30), 1);
+        let now = Instant::now();
+
+        let permit = throttle.start_at(1, "scan", now).unwrap();
+        // Another user is refused while the only slot is taken, without a cooldown
+        assert_eq!(throttle.start_at(2, "scan", now).unwrap_err(), Throttled::Busy);
+        drop(permit);
+
+        assert_eq!(
+            throttle.start_at(1, "scan", now + Duration::from_secs(10)).unwrap_err(),
+            Throttled::Cooldown(Duration::from_secs(20))
+        );
+        assert!(throttle.start_at(2, "scan", now).is_ok());
+        assert!(throttle.start_at(1, "eligible", now).is_ok());
+        assert!(throttle.start_at(1, "scan", now + Duration::from_secs(30)).is_ok());
+    }
+}
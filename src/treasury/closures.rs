@@ -0,0 +1,280 @@
+// src/treasury/closures.rs - Notices when PassiveMonitoring accounts are closed by their users
+
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use super::monitor::ExternalReclaims;
+use crate::{
+    error::Result,
+    solana::client::SolanaRpcClient,
+    storage::{models::{AccountStatus, ReclaimStrategy, SponsoredAccount}, Database},
+};
+use tracing::{debug, info, warn};
+
+/// getMultipleAccounts accepts at most 100 keys per request
+const MAX_BATCH_SIZE: usize = 100;
+
+/// A PassiveMonitoring account found closed and marked so
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClosedAccount {
+    pub pubkey: String,
+    /// Last known balance, which the close returned
+    pub lamports: u64,
+}
+
+/// Checks only the Active PassiveMonitoring accounts, a batch at a time, so
+/// closes are seen without waiting for a full refresh
+pub struct ClosureWatcher {
+    rpc_client: SolanaRpcClient,
+    db: Database,
+    external: Option<ExternalReclaims>,
+}
+
+impl ClosureWatcher {
+    pub fn new(rpc_client: SolanaRpcClient, db: Database) -> Self {
+        Self { rpc_client, db, external: None }
+    }
+
+    /// Check how each vanished account was closed before calling it a user
+    /// close; the operator's own closes are recorded as reclaims instead
+    pub fn with_external_reclaims(mut self, external: ExternalReclaims) -> Self {
+        self.external = Some(external);
+        self
+    }
+
+    /// Mark the watched accounts their users closed Closed and return them
+    pub async fn check(&self) -> Result<Vec<ClosedAccount>> {
+        let watched: Vec<SponsoredAccount> = self
+            .db
+            .get_accounts_by_strategy(&ReclaimStrategy::PassiveMonitoring.to_string())?
+            .into_iter()
+            .filter(|account| account.status == AccountStatus::Active)
+            .collect();
+
+        if watched.is_empty() {
+            debug!("No PassiveMonitoring accounts to watch");
+            return Ok(Vec::new());
+        }
+
+        let mut closed = Vec::new();
+        for chunk in watched.chunks(MAX_BATCH_SIZE) {
+            let accounts: Vec<(&SponsoredAccount, Pubkey)> = chunk
+                .iter()
+                .filter_map(|a| Pubkey::from_str(&a.pubkey).ok().map(|pubkey| (a, pubkey)))
+                .collect();
+            let pubkeys: Vec<Pubkey> = accounts.iter().map(|(_, pubkey)| *pubkey).collect();
+
+            let fetched = self.rpc_client.get_multiple_accounts(&pubkeys).await?;
+            for ((account, _), on_chain) in accounts.iter().zip(fetched.iter()) {
+                if on_chain.is_some() {
+                    continue;
+                }
+                if let Some(external) = &self.external {
+                    match external.record(account).await {
+                        Ok(Some(_)) => continue,
+                        Ok(None) => {}
+                        Err(e) => {
+                            // Left Active so the next check classifies it
+                            warn!("Could not check how {} was closed: {}", account.pubkey, e);
+                            continue;
+                        }
+                    }
+                }
+                // Another process may have settled it first
+                if let Err(e) = self.db.update_account_status(&account.pubkey, AccountStatus::Closed) {
+                    warn!("Failed to mark {} closed: {}", account.pubkey, e);
+                    continue;
+                }
+                info!("PassiveMonitoring account {} was closed by its user", account.pubkey);
+                closed.push(ClosedAccount { pubkey: account.pubkey.clone(), lamports: account.balance() });
+            }
+        }
+
+        debug!("Watched {} PassiveMonitoring accounts, {} closed", watched.len(), closed.len());
+        Ok(closed)
+    }
+}
+
+/// When `run_passive_monitor` next checks the treasury and how long it may
+/// sleep. Closes seen by the watcher bring the treasury check forward so the
+/// returned rent is matched while it is fresh.
+#[derive(Debug, Clone)]
+pub struct PassiveSchedule {
+    treasury_interval: Duration,
+    watch_interval: Option<Duration>,
+    next_treasury_check: Instant,
+}
+
+impl PassiveSchedule {
+    /// `watch_interval_secs` of 0 turns the closure watcher off
+    pub fn new(treasury_interval_secs: u64, watch_interval_secs: u64, now: Instant) -> Self {
+        Self {
+            treasury_interval: Duration::from_secs(treasury_interval_secs),
+            watch_interval: (watch_interval_secs > 0).then(|| Duration::from_secs(watch_interval_secs)),
+            next_treasury_check: now,
+        }
+    }
+
+    pub fn watches_closures(&self) -> bool {
+        self.watch_interval.is_some()
+    }
+
+    pub fn closes_found(&mut self, now: Instant) {
+        self.next_treasury_check = now;
+    }
+
+    /// Whether the treasury check is due; if so the next one is scheduled
+    pub fn treasury_due(&mut self, now: Instant) -> bool {
+        if now < self.next_treasury_check {
+            return false;
+        }
+        self.next_treasury_check = now + self.treasury_interval;
+        true
+    }
+
+    /// Sleep until the next treasury check or closure watch, whichever is sooner
+    pub fn wait(&self, now: Instant) -> Duration {
+        let wait = self.next_treasury_check.saturating_duration_since(now);
+        self.watch_interval.map_or(wait, |watch| wait.min(watch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solana::test_rpc::{multiple_accounts, parsed_transaction, signature_status, TestRpc};
+    use crate::storage::models::ReclaimReason;
+    use crate::treasury::TreasuryMonitor;
+    use serde_json::{json, Value};
+    use solana_sdk::{account::Account, signature::Signature};
+
+    fn watch(db: &Database, pubkey: &Pubkey, lamports: u64) {
+        db.save_account(&SponsoredAccount::new(*pubkey, lamports, 165)).unwrap();
+        db.update_account_authority(&pubkey.to_string(), None, &ReclaimStrategy::PassiveMonitoring.to_string())
+            .unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_closure_watcher_classifies_closes() {
+        let (open, user_closed, operator_closed) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let (operator, treasury, user) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let (user_sig, operator_sig) = (Signature::new_unique().to_string(), Signature::new_unique().to_string());
+
+        let on_chain = vec![(open, Account::new(2_039_280, 165, &spl_token::id()))];
+        let (user_tx, operator_tx) = (user_sig.clone(), operator_sig.clone());
+        let rpc = TestRpc::start(move |method, params| match method {
+            "getMultipleAccounts" => Ok(multiple_accounts(params, &on_chain)),
+            "getSignaturesForAddress" if params[0] == json!(user_closed.to_string()) => {
+                Ok(json!([signature_status(&user_tx, 1_700_000_000)]))
+            }
+            "getSignaturesForAddress" if params[0] == json!(operator_closed.to_string()) => {
+                Ok(json!([signature_status(&operator_tx, 1_700_000_000)]))
+            }
+            // The user sent the rent to their own wallet
+            "getTransaction" if params[0] == json!(user_tx) => Ok(parsed_transaction(
+                &user_tx,
+                &[(user, true, 1_000_000, 3_034_280), (user_closed, false, 2_039_280, 0)],
+                5_000,
+            )),
+            "getTransaction" => Ok(parsed_transaction(
+                &operator_tx,
+                &[(operator, true, 1_000_000, 995_000), (operator_closed, false, 2_039_280, 0), (treasury, false, 0, 2_039_280)],
+                5_000,
+            )),
+            _ => Ok(Value::Null),
+        });
+
+        let db = Database::in_memory().unwrap();
+        for pubkey in [&open, &user_closed, &operator_closed] {
+            watch(&db, pubkey, 2_039_280);
+        }
+        let monitor = TreasuryMonitor::new(treasury, rpc.client(), db.clone()).with_operator_keys(vec![operator]);
+        let watcher = ClosureWatcher::new(rpc.client(), db.clone()).with_external_reclaims(monitor.external_reclaims());
+
+        let closed = watcher.check().await.unwrap();
+        assert_eq!(closed, vec![ClosedAccount { pubkey: user_closed.to_string(), lamports: 2_039_280 }]);
+
+        let status = |pubkey: &Pubkey| db.get_account_by_pubkey(&pubkey.to_string()).unwrap().unwrap().status;
+        assert_eq!(status(&open), AccountStatus::Active);
+        assert_eq!(status(&user_closed), AccountStatus::Closed);
+        // The operator's close is a reclaim, never announced as the user's
+        assert_eq!(status(&operator_closed), AccountStatus::Reclaimed);
+        let operations = db.get_reclaim_operations_for(&operator_closed.to_string()).unwrap();
+        assert_eq!(operations.len(), 1);
+        assert_eq!(operations[0].reason, ReclaimReason::ExternalReclaim);
+        assert_eq!(operations[0].tx_signature, operator_sig);
+
+        // Nothing left to announce on the next pass
+        assert!(watcher.check().await.unwrap().is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_closure_watcher_retries_unclassified_closes() {
+        let gone = Pubkey::new_unique();
+        let rpc = TestRpc::start(|method, params| match method {
+            "getMultipleAccounts" => Ok(multiple_accounts(params, &[])),
+            _ => Err("history unavailable".to_string()),
+        });
+        let db = Database::in_memory().unwrap();
+        watch(&db, &gone, 2_039_280);
+        let monitor = TreasuryMonitor::new(Pubkey::new_unique(), rpc.client(), db.clone());
+        let watcher = ClosureWatcher::new(rpc.client(), db.clone()).with_external_reclaims(monitor.external_reclaims());
+
+        assert!(watcher.check().await.unwrap().is_empty());
+        assert_eq!(db.get_account_by_pubkey(&gone.to_string()).unwrap().unwrap().status, AccountStatus::Active);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_external_reclaim_is_netted_from_treasury_increase() {
+        let (operator, treasury, closed) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let signature = Signature::new_unique().to_string();
+        let tx_signature = signature.clone();
+        let rpc = TestRpc::start(move |method, params| match method {
+            "getMultipleAccounts" => Ok(multiple_accounts(params, &[])),
+            "getSignaturesForAddress" => Ok(json!([signature_status(&tx_signature, 1_700_000_000)])),
+            "getTransaction" => Ok(parsed_transaction(
+                &tx_signature,
+                &[(operator, true, 1_000_000, 995_000), (closed, false, 2_039_280, 0), (treasury, false, 10_000_000, 12_039_280)],
+                5_000,
+            )),
+            "getBalance" => Ok(crate::solana::test_rpc::with_context(json!(12_039_280))),
+            _ => Ok(Value::Null),
+        });
+
+        let db = Database::in_memory().unwrap();
+        watch(&db, &closed, 2_039_280);
+        db.save_treasury_balance(10_000_000).unwrap();
+        let monitor = TreasuryMonitor::new(treasury, rpc.client(), db.clone()).with_operator_keys(vec![operator]);
+        let watcher = ClosureWatcher::new(rpc.client(), db.clone()).with_external_reclaims(monitor.external_reclaims());
+
+        assert!(watcher.check().await.unwrap().is_empty());
+        // The watcher already recorded the close, so the increase is not passive
+        assert!(monitor.check_for_passive_reclaims().await.unwrap().is_empty());
+        assert_eq!(db.get_last_treasury_balance().unwrap(), 12_039_280);
+    }
+
+    #[test]
+    fn test_passive_schedule() {
+        let start = Instant::now();
+        let mut schedule = PassiveSchedule::new(300, 30, start);
+        assert!(schedule.watches_closures());
+
+        // The treasury is checked straight away, then every interval
+        assert!(schedule.treasury_due(start));
+        assert!(!schedule.treasury_due(start + Duration::from_secs(10)));
+        assert_eq!(schedule.wait(start), Duration::from_secs(30));
+        assert_eq!(schedule.wait(start + Duration::from_secs(290)), Duration::from_secs(10));
+        assert!(schedule.treasury_due(start + Duration::from_secs(300)));
+
+        // A close brings the treasury check forward
+        let seen = start + Duration::from_secs(330);
+        schedule.closes_found(seen);
+        assert_eq!(schedule.wait(seen), Duration::ZERO);
+        assert!(schedule.treasury_due(seen));
+
+        let mut unwatched = PassiveSchedule::new(300, 0, start);
+        assert!(!unwatched.watches_closures());
+        assert!(unwatched.treasury_due(start));
+        assert_eq!(unwatched.wait(start), Duration::from_secs(300));
+    }
+}
@@ -1,8 +1,10 @@
 // src/treasury/mod.rs
+pub mod closures;
 pub mod monitor;
 pub mod reconciliation;
 
-pub use monitor::TreasuryMonitor;
+pub use closures::{ClosureWatcher, PassiveSchedule};
+pub use monitor::{ExternalReclaims, TreasuryMonitor};
 // Remove unused re-exports or keep them but allow unused
 #[allow(unused_imports)]
 pub use reconciliation::{PassiveReclaim, TreasuryReconciliation};
\ No newline at end of file
@@ -1,17 +1,104 @@
 // src/treasury/monitor.rs
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
 //use chrono::{DateTime, Utc, Duration};
 use crate::{
+    config::PassiveMatchingConfig,
     error::Result,
+    reclaim::verify,
     solana::client::SolanaRpcClient,
-    storage::Database,
+    storage::{
+        models::{AccountStatus, ReclaimOperation, ReclaimReason, SponsoredAccount},
+        Database,
+    },
+};
+use std::str::FromStr;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
 };
 use tracing::{info, debug};
 
+/// Recognises closes the operator made by hand, outside the bot, and records
+/// them as `ExternalReclaim`s. Clones share the credit not yet taken by
+/// [`TreasuryMonitor`], so a close classified by the [`super::ClosureWatcher`]
+/// is still netted out of the next balance increase.
+#[derive(Clone)]
+pub struct ExternalReclaims {
+    treasury_pubkey: Pubkey,
+    rpc_client: SolanaRpcClient,
+    db: Database,
+    /// Keys whose signature on a close marks it as the operator's own reclaim
+    operator_keys: Vec<Pubkey>,
+    credited: Arc<AtomicU64>,
+}
+
+impl ExternalReclaims {
+    /// Record `account` as an `ExternalReclaim` if the transaction that closed
+    /// it was signed by the operator or treasury and paid the treasury.
+    /// Returns what the treasury gained from it.
+    ///
+    /// A close found to be someone else's is remembered and not fetched
+    /// again; one whose transaction is not available yet is.
+    pub async fn record(&self, account: &SponsoredAccount) -> Result<Option<u64>> {
+        if self.db.is_close_checked(account)? {
+            return Ok(None);
+        }
+        let pubkey = Pubkey::from_str(&account.pubkey)?;
+        let Some(latest) = self.rpc_client.get_signatures_for_address(&pubkey, None, None, 1).await?.into_iter().next() else {
+            return Ok(None);
+        };
+        let Some(tx) = self.rpc_client.get_transaction(&Signature::from_str(&latest.signature)?).await? else {
+            return Ok(None);
+        };
+
+        let mut signers = self.operator_keys.clone();
+        signers.push(self.treasury_pubkey);
+        let Some(credit) = verify::external_reclaim(&tx, &pubkey, &self.treasury_pubkey, &signers) else {
+            // Only a transaction that did close the account settles the question
+            if verify::emptied(&tx, &pubkey) {
+                self.db.mark_close_checked(account, &latest.signature)?;
+            }
+            return Ok(None);
+        };
+
+        self.db.save_reclaim_operation(&ReclaimOperation {
+            id: 0,
+            account_pubkey: account.pubkey.clone(),
+            reclaimed_amount: credit.expected,
+            tx_signature: latest.signature.clone(),
+            timestamp: latest
+                .block_time
+                .and_then(|time| chrono::DateTime::from_timestamp(time, 0))
+                .unwrap_or_else(chrono::Utc::now),
+            reason: ReclaimReason::ExternalReclaim,
+            credited_amount: Some(credit.credited),
+            destination: Some(self.treasury_pubkey.to_string()),
+            fee_lamports: Some(credit.fee),
+        })?;
+        if let Err(e) = self.db.update_account_status(&account.pubkey, AccountStatus::Reclaimed) {
+            debug!("{}", e);
+        }
+        info!(
+            "Account {} was reclaimed outside the bot in {} ({} lamports)",
+            account.pubkey, latest.signature, credit.credited
+        );
+        let credited = credit.credited.max(0) as u64;
+        self.credited.fetch_add(credited, Ordering::Relaxed);
+        Ok(Some(credited))
+    }
+
+    /// Credit recorded since the last call
+    fn take_credited(&self) -> u64 {
+        self.credited.swap(0, Ordering::Relaxed)
+    }
+}
+
 pub struct TreasuryMonitor {
     treasury_pubkey: Pubkey,
     rpc_client: SolanaRpcClient,
     db: Database,
+    external: ExternalReclaims,
+    matching: PassiveMatchingConfig,
 }
 
 impl TreasuryMonitor {
@@ -20,12 +107,38 @@ impl TreasuryMonitor {
         rpc_client: SolanaRpcClient,
         db: Database,
     ) -> Self {
+        let external = ExternalReclaims {
+            treasury_pubkey,
+            rpc_client: rpc_client.clone(),
+            db: db.clone(),
+            operator_keys: Vec::new(),
+            credited: Arc::new(AtomicU64::new(0)),
+        };
         Self {
             treasury_pubkey,
             rpc_client,
             db,
+            external,
+            matching: PassiveMatchingConfig::default(),
         }
     }
+
+    /// Tolerance and closed-account window used to match balance increases
+    pub fn with_matching(mut self, matching: PassiveMatchingConfig) -> Self {
+        self.matching = matching;
+        self
+    }
+
+    /// Operator keys, current and retired, that close accounts by hand
+    pub fn with_operator_keys(mut self, keys: Vec<Pubkey>) -> Self {
+        self.external.operator_keys = keys;
+        self
+    }
+
+    /// The classifier this monitor nets external reclaims with
+    pub fn external_reclaims(&self) -> ExternalReclaims {
+        self.external.clone()
+    }
     
     /// Monitor treasury balance and detect passive reclaims
     pub async fn check_for_passive_reclaims(&self) -> Result<Vec<super::reconciliation::PassiveReclaim>> {
@@ -59,14 +172,31 @@ impl TreasuryMonitor {
         Ok(passive_reclaims)
     }
     
-    /// Correlate balance increase with recently closed accounts
     /// Correlate balance increase with recently closed accounts
     async fn correlate_balance_increase(
         &self,
-        increase: u64,
+        mut increase: u64,
     ) -> Result<Vec<super::reconciliation::PassiveReclaim>> {
-        // Get accounts that changed to Closed status recently (last 24 hours)
-        let mut closed_accounts = self.db.get_recently_closed_accounts(24)?;
+        let PassiveMatchingConfig { tolerance_lamports: tolerance, closed_window_hours } = self.matching;
+
+        // Get accounts that changed to Closed status within the window
+        let mut closed_accounts = Vec::new();
+        for account in self.db.get_recently_closed_accounts(closed_window_hours as i64)? {
+            // Closes the operator made by hand are reclaims, not passive
+            match self.external.record(&account).await {
+                Ok(Some(_)) => {}
+                Ok(None) => closed_accounts.push(account),
+                Err(e) => {
+                    debug!("Could not check how {} was closed: {}", account.pubkey, e);
+                    closed_accounts.push(account);
+                }
+            }
+        }
+        // Including those the closure watcher recorded since the last check
+        increase = increase.saturating_sub(self.external.take_credited());
+        if increase == 0 {
+            return Ok(vec![]);
+        }
         
         // 1. Try to match with known closed accounts
         let mut matches = if !closed_accounts.is_empty() {
@@ -74,6 +204,7 @@ impl TreasuryMonitor {
              super::reconciliation::TreasuryReconciliation::match_amount_to_accounts(
                 increase,
                 &closed_accounts,
+                tolerance,
             )
         } else {
             info!("No recently closed accounts initially found");
@@ -86,8 +217,6 @@ impl TreasuryMonitor {
         
         if !has_high_confidence {
              // Search for ACTIVE accounts with rent close to 'increase'
-             // Tolerance 5000 lamports (0.000005 SOL)
-             let tolerance = 5000;
              let min = if increase > tolerance { increase - tolerance } else { 0 };
              let max = increase + tolerance;
              
@@ -108,9 +237,20 @@ impl TreasuryMonitor {
                              };
                              
                              if is_closed {
+                                 if let Ok(Some(_)) = self.external.record(&candidate).await {
+                                     increase = increase.saturating_sub(self.external.take_credited());
+                                     found_closed = true;
+                                     continue;
+                                 }
                                  info!("Account {} found closed on-chain! Marking as Closed.", candidate.pubkey);
-                                 // Mark as closed in DB
-                                 self.db.update_account_status(&candidate.pubkey, crate::storage::models::AccountStatus::Closed)?;
+                                 // Mark as closed in DB; one already marked Reclaimed was ours
+                                 match self.db.update_account_status(&candidate.pubkey, crate::storage::models::AccountStatus::Closed) {
+                                     Err(crate::error::ReclaimError::StatusConflict(conflict)) => {
+                                         debug!("Not a passive close: {}", conflict);
+                                         continue;
+                                     }
+                                     result => result?,
+                                 }
                                  self.db.update_account_authority(&candidate.pubkey, None, "PassiveMonitoring")?;
                                  
                                  // Add to closed_accounts list for matching
@@ -121,17 +261,33 @@ impl TreasuryMonitor {
                      }
                  }
                  
+                 if increase == 0 {
+                     return Ok(vec![]);
+                 }
                  if found_closed {
                      // Retry matching with updated list
                      debug!("Retrying correlation with newly discovered closed accounts");
                      matches = super::reconciliation::TreasuryReconciliation::match_amount_to_accounts(
                         increase,
                         &closed_accounts,
+                        tolerance,
                      );
                  }
              }
         }
         
+        for m in &matches {
+            info!(
+                "Matched {} lamports to {} account(s) with {:?} confidence (tolerance {} lamports, {}h closed window, {} closed candidates)",
+                m.amount,
+                m.attributed_accounts.len(),
+                m.confidence,
+                tolerance,
+                closed_window_hours,
+                closed_accounts.len()
+            );
+        }
+        
         Ok(matches)
     }
     
@@ -139,4 +295,48 @@ impl TreasuryMonitor {
     pub fn get_total_passive_reclaimed(&self) -> Result<u64> {
         self.db.get_total_passive_reclaimed()
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solana::test_rpc::{parsed_transaction, signature_status, with_context, TestRpc};
+    use serde_json::{json, Value};
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_user_close_is_classified_once() {
+        let (operator, treasury, user, closed) =
+            (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let signature = Signature::new_unique().to_string();
+        let close = signature.clone();
+        let rpc = TestRpc::start(move |method, _| match method {
+            "getBalance" => Ok(with_context(json!(12_000_000))),
+            "getSignaturesForAddress" => Ok(json!([signature_status(&close, 1_700_000_000)])),
+            // Signed by the user: their rent, not the operator's reclaim
+            "getTransaction" => Ok(parsed_transaction(
+                &close,
+                &[(user, true, 1_000_000, 995_000), (closed, false, 2_039_280, 0), (treasury, false, 10_000_000, 12_039_280)],
+                5_000,
+            )),
+            "getAccountInfo" => Ok(with_context(Value::Null)),
+            _ => Err(format!("unexpected {}", method)),
+        });
+
+        let db = Database::in_memory().unwrap();
+        let mut account = SponsoredAccount::new(closed, 2_039_280, 165);
+        account.creation_signature = Some(Signature::new_unique().to_string());
+        db.save_account(&account).unwrap();
+        db.update_account_status(&account.pubkey, AccountStatus::Closed).unwrap();
+        let monitor = TreasuryMonitor::new(treasury, rpc.client(), db.clone()).with_operator_keys(vec![operator]);
+
+        for _ in 0..2 {
+            db.save_treasury_balance(10_000_000).unwrap();
+            let matches = monitor.check_for_passive_reclaims().await.unwrap();
+            assert_eq!(matches.len(), 1);
+            assert_eq!(matches[0].attributed_accounts, vec![closed]);
+        }
+        // The second check trusted the stored classification
+        assert_eq!(rpc.calls("getTransaction"), 1);
+        assert_eq!(db.get_account_by_pubkey(&account.pubkey).unwrap().unwrap().status, AccountStatus::Closed);
+        assert!(db.get_reclaim_operations_for(&account.pubkey).unwrap().is_empty());
+    }
+}
@@ -2,8 +2,14 @@
 use solana_sdk::pubkey::Pubkey;
 use chrono::{DateTime, Utc};
 use crate::storage::models::SponsoredAccount;
+use std::collections::HashMap;
 use tracing::debug;
 
+/// Most accounts a subset-sum match may attribute one increase to
+const MAX_COMBINATION_SIZE: usize = 12;
+/// Most distinct partial sums the subset-sum search keeps
+const MAX_SUBSET_STATES: usize = 100_000;
+
 #[derive(Debug, Clone)]
 pub struct PassiveReclaim {
     pub amount: u64,
@@ -23,13 +29,14 @@ pub enum ConfidenceLevel {
 pub struct TreasuryReconciliation;
 
 impl TreasuryReconciliation {
-    /// Match a balance increase to recently closed accounts
+    /// Match a balance increase to recently closed accounts, allowing
+    /// `tolerance` lamports of difference for fees
     pub fn match_amount_to_accounts(
         increase: u64,
         closed_accounts: &[SponsoredAccount],
+        tolerance: u64,
     ) -> Vec<PassiveReclaim> {
         let mut reclaims = Vec::new();
-        let tolerance = 5000u64; // Allow 5000 lamports tolerance for fees
         
         // Try to find exact single account match
         for account in closed_accounts {
@@ -41,8 +48,8 @@ impl TreasuryReconciliation {
             
             if diff <= tolerance {
                 debug!(
-                    "High confidence match: {} lamports to account {} (diff: {})",
-                    increase, account.pubkey, diff
+                    "High confidence match: {} lamports to account {} (diff: {}, tolerance: {})",
+                    increase, account.pubkey, diff, tolerance
                 );
                 
                 let pubkey = account.pubkey.parse().unwrap_or_else(|_| Pubkey::default());
@@ -62,8 +69,8 @@ impl TreasuryReconciliation {
             let combination = Self::find_account_combination(increase, closed_accounts, tolerance);
             if let Some((accounts, total)) = combination {
                 debug!(
-                    "Medium confidence match: {} lamports to {} accounts (total: {})",
-                    increase, accounts.len(), total
+                    "Medium confidence match: {} lamports to {} accounts (total: {}, tolerance: {})",
+                    increase, accounts.len(), total, tolerance
                 );
                 
                 reclaims.push(PassiveReclaim {
@@ -152,6 +159,99 @@ impl TreasuryReconciliation {
             }
         }
         
-        None
+        Self::find_subset_sum(target, p_accounts, tolerance)
+    }
+
+    /// Larger combinations, up to `MAX_COMBINATION_SIZE` accounts, by dynamic
+    /// programming over the reachable rent totals. Each total remembers the
+    /// last account added and the total before it, so the combination can be
+    /// walked back. The total closest to `target` wins, then the one with
+    /// fewer accounts. Gives up once `MAX_SUBSET_STATES` totals are tracked.
+    fn find_subset_sum(
+        target: u64,
+        accounts: &[SponsoredAccount],
+        tolerance: u64,
+    ) -> Option<(Vec<Pubkey>, u64)> {
+        let max_sum = target.saturating_add(tolerance);
+        // total -> (account index, previous total, accounts in the combination)
+        let mut reachable: HashMap<u64, (usize, u64, usize)> = HashMap::new();
+        let mut best: Option<(u64, usize, u64)> = None; // (diff, size, total)
+
+        for (index, account) in accounts.iter().enumerate() {
+            let mut added = vec![(account.rent_lamports, (index, 0, 1))];
+            for (&total, &(_, _, size)) in &reachable {
+                if size < MAX_COMBINATION_SIZE {
+                    added.push((total.saturating_add(account.rent_lamports), (index, total, size + 1)));
+                }
+            }
+
+            for (total, entry) in added {
+                if total > max_sum || reachable.contains_key(&total) {
+                    continue;
+                }
+                reachable.insert(total, entry);
+
+                let size = entry.2;
+                let diff = total.abs_diff(target);
+                if diff <= tolerance && best.is_none_or(|(d, s, _)| (diff, size) < (d, s)) {
+                    best = Some((diff, size, total));
+                }
+            }
+
+            if reachable.len() > MAX_SUBSET_STATES {
+                debug!("Subset-sum search stopped after {} partial sums", reachable.len());
+                break;
+            }
+        }
+
+        let (_, _, total) = best?;
+        let mut pubkeys = Vec::new();
+        let mut current = total;
+        while let Some(&(index, previous, size)) = reachable.get(&current) {
+            pubkeys.push(accounts[index].pubkey.parse().ok()?);
+            if size == 1 {
+                break;
+            }
+            current = previous;
+        }
+        Some((pubkeys, total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn closed(rent_lamports: u64) -> SponsoredAccount {
+        SponsoredAccount::new(Pubkey::new_unique(), rent_lamports, 165)
+    }
+
+    #[test]
+    fn test_closure_burst_matched_with_medium_confidence() {
+        let rents = [2_039_280, 1_461_600, 890_880, 3_500_000, 1_000_000, 2_500_000, 7_000_000, 4_200_000];
+        let accounts: Vec<SponsoredAccount> = rents.iter().map(|&rent| closed(rent)).collect();
+
+        // Six of them closed at once, less the transaction fee
+        let burst = [0, 1, 2, 3, 4, 5];
+        let increase: u64 = burst.iter().map(|&i| rents[i]).sum::<u64>() - 5_000;
+
+        let matched = TreasuryReconciliation::match_amount_to_accounts(increase, &accounts, 5_000);
+        assert_eq!(matched.len(), 1);
+        assert_eq!(matched[0].confidence, ConfidenceLevel::Medium);
+
+        let attributed: u64 = matched[0]
+            .attributed_accounts
+            .iter()
+            .map(|pubkey| accounts.iter().find(|a| a.pubkey == pubkey.to_string()).unwrap().rent_lamports)
+            .sum();
+        assert!(attributed.abs_diff(increase) <= 5_000);
+        let mut unique = matched[0].attributed_accounts.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), matched[0].attributed_accounts.len());
+
+        // Nothing adds up within the tolerance
+        let unmatched = TreasuryReconciliation::match_amount_to_accounts(123, &accounts, 5_000);
+        assert_eq!(unmatched[0].confidence, ConfidenceLevel::Low);
     }
 }
\ No newline at end of file
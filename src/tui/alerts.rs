@@ -0,0 +1,192 @@
+// src/tui/alerts.rs - Dashboard alerts raised from the stored state
+
+use crate::storage::models::ScanFailureStreak;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Critical => "critical",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "info" => Some(Severity::Info),
+            "warning" => Some(Severity::Warning),
+            "critical" => Some(Severity::Critical),
+            _ => None,
+        }
+    }
+}
+
+/// Condition an alert reports; at most one alert of each kind is raised
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertKind {
+    LowTreasury,
+    ScanFailures,
+    AutoReclaimPaused,
+    HighValueEligible,
+}
+
+impl AlertKind {
+    /// Stored with acknowledgements
+    pub fn key(&self) -> &'static str {
+        match self {
+            AlertKind::LowTreasury => "low_treasury",
+            AlertKind::ScanFailures => "scan_failures",
+            AlertKind::AutoReclaimPaused => "auto_reclaim_paused",
+            AlertKind::HighValueEligible => "high_value_eligible",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alert {
+    pub kind: AlertKind,
+    pub severity: Severity,
+    pub message: String,
+    /// Acknowledged at this severity or higher; hidden until it escalates
+    pub acknowledged: bool,
+}
+
+impl Alert {
+    fn new(kind: AlertKind, severity: Severity, message: String) -> Self {
+        Self { kind, severity, message, acknowledged: false }
+    }
+}
+
+/// State the alerts are raised from, gathered on each refresh
+#[derive(Debug, Clone, Default)]
+pub struct AlertInputs {
+    /// Treasury balance, if it was fetched
+    pub treasury_lamports: Option<u64>,
+    /// `tui.low_treasury_sol` in lamports
+    pub low_treasury_lamports: Option<u64>,
+    pub scan_failures: ScanFailureStreak,
+    /// `reclaim.scan_failure_alert_after`
+    pub scan_failure_alert_after: u32,
+    /// Auto reclaim switched off at runtime (Telegram /settings)
+    pub auto_reclaim_paused: bool,
+    /// Eligible accounts at or above the alert threshold, and the threshold (SOL)
+    pub high_value: Option<(usize, f64)>,
+}
+
+/// Alerts for `inputs`, most severe first
+pub fn evaluate(inputs: &AlertInputs) -> Vec<Alert> {
+    let mut alerts = Vec::new();
+
+    if let (Some(balance), Some(minimum)) = (inputs.treasury_lamports, inputs.low_treasury_lamports) {
+        if balance < minimum {
+            alerts.push(Alert::new(
+                AlertKind::LowTreasury,
+                Severity::Warning,
+                format!(
+                    "Treasury balance {:.4} SOL is below {:.4} SOL",
+                    balance as f64 / 1_000_000_000.0,
+                    minimum as f64 / 1_000_000_000.0
+                ),
+            ));
+        }
+    }
+
+    let streak = &inputs.scan_failures;
+    if streak.is_failing() {
+        let severity = if streak.is_escalated(inputs.scan_failure_alert_after) {
+            Severity::Critical
+        } else {
+            Severity::Warning
+        };
+        alerts.push(Alert::new(
+            AlertKind::ScanFailures,
+            severity,
+            format!(
+                "{} scan(s) failed in a row: {}",
+                streak.consecutive_failures,
+                streak.last_error.as_deref().unwrap_or("unknown error")
+            ),
+        ));
+    }
+
+    if inputs.auto_reclaim_paused {
+        alerts.push(Alert::new(
+            AlertKind::AutoReclaimPaused,
+            Severity::Info,
+            "Auto reclaim is paused at runtime".to_string(),
+        ));
+    }
+
+    if let Some((count, threshold)) = inputs.high_value.filter(|(count, _)| *count > 0) {
+        alerts.push(Alert::new(
+            AlertKind::HighValueEligible,
+            Severity::Warning,
+            format!("{} eligible account(s) hold {:.2} SOL or more", count, threshold),
+        ));
+    }
+
+    alerts.sort_by_key(|alert| std::cmp::Reverse(alert.severity));
+    alerts
+}
+
+/// Mark alerts covered by a stored acknowledgement (kind key -> severity)
+pub fn apply_acknowledgements(alerts: &mut [Alert], acknowledged: &HashMap<String, String>) {
+    for alert in alerts {
+        alert.acknowledged = acknowledged
+            .get(alert.kind.key())
+            .and_then(|severity| Severity::parse(severity))
+            .is_some_and(|severity| severity >= alert.severity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alerts_escalate_past_their_acknowledgement() {
+        let mut inputs = AlertInputs {
+            treasury_lamports: Some(10_000_000),
+            low_treasury_lamports: Some(50_000_000),
+            scan_failures: ScanFailureStreak {
+                consecutive_failures: 1,
+                last_error: Some("timeout".to_string()),
+                last_failed_at: None,
+            },
+            scan_failure_alert_after: 3,
+            auto_reclaim_paused: true,
+            high_value: Some((0, 1.0)),
+        };
+        let alerts = evaluate(&inputs);
+        let kinds: Vec<_> = alerts.iter().map(|a| (a.kind, a.severity)).collect();
+        assert_eq!(
+            kinds,
+            [
+                (AlertKind::LowTreasury, Severity::Warning),
+                (AlertKind::ScanFailures, Severity::Warning),
+                (AlertKind::AutoReclaimPaused, Severity::Info),
+            ]
+        );
+
+        let acknowledged = HashMap::from([("scan_failures".to_string(), "warning".to_string())]);
+        let mut alerts = evaluate(&inputs);
+        apply_acknowledgements(&mut alerts, &acknowledged);
+        assert!(alerts.iter().find(|a| a.kind == AlertKind::ScanFailures).unwrap().acknowledged);
+
+        // A third failure makes it critical and shows it again
+        inputs.scan_failures.consecutive_failures = 3;
+        let mut alerts = evaluate(&inputs);
+        apply_acknowledgements(&mut alerts, &acknowledged);
+        assert_eq!(alerts[0].kind, AlertKind::ScanFailures);
+        assert_eq!(alerts[0].severity, Severity::Critical);
+        assert!(!alerts[0].acknowledged);
+    }
+}
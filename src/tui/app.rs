@@ -1,23 +1,116 @@
 use crate::{
     config::Config,
-    storage::Database,
-    solana::SolanaRpcClient,
+    storage::{Database, models::{MintRent, ReclaimReason, ScanFailureStreak, ScanSession, Snapshot}},
+    solana::{ParserRegistry, SolanaRpcClient},
     kora::KoraMonitor,
-    reclaim::{EligibilityChecker, ReclaimEngine, BatchProcessor},
-    error::Result,
+    reclaim::{signer, EligibilityChecker, ReclaimEngine, BatchProcessor, CloseAdapters},
+    events::{EventBus, ReclaimEvent, WebhookNotifier},
+    error::{ReclaimError, Result},
+    tui::alerts::{self, Alert, AlertInputs},
+    tui::log_layer::LogBuffer,
+    tui::onboarding::{setup_steps, SetupStep},
+    tui::rpc_status::{RpcProbe, RpcStatus},
+    storage::models::AccountStatus,
+    price::PriceOracle,
 };
 use solana_sdk::pubkey::Pubkey;
 use chrono::{DateTime, Utc};
+use ratatui::layout::Rect;
+use std::collections::HashSet;
 use std::time::{Instant, Duration};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::Level;
+
+/// Daily snapshots shown in the dashboard trend
+const TREND_DAYS: usize = 30;
+/// Scan sessions listed on the Checkpoints screen
+const SCAN_HISTORY: usize = 20;
+/// Window of the Accounts screen's upcoming filter
+pub const UPCOMING_DAYS: u64 = 7;
+
+/// Reads keys while a scan or batch holds the event loop and cancels the
+/// token on Esc. Other keys pressed meanwhile are dropped.
+struct EscapeWatcher {
+    done: CancellationToken,
+}
+
+impl EscapeWatcher {
+    fn start(cancel: CancellationToken) -> Self {
+        use crossterm::event::{self, Event, KeyCode};
+
+        let done = CancellationToken::new();
+        let stop = done.clone();
+        tokio::task::spawn_blocking(move || {
+            while !stop.is_cancelled() && !cancel.is_cancelled() {
+                if !event::poll(Duration::from_millis(100)).unwrap_or(false) {
+                    continue;
+                }
+                if let Ok(Event::Key(key)) = event::read() {
+                    if key.code == KeyCode::Esc {
+                        cancel.cancel();
+                    }
+                }
+            }
+        });
+        Self { done }
+    }
+}
+
+impl Drop for EscapeWatcher {
+    fn drop(&mut self) {
+        self.done.cancel();
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Screen {
     Dashboard,
     Accounts,
     Operations,
+    Checkpoints,
     Settings,
 }
 
+impl Screen {
+    /// Tab order
+    pub const ALL: [Screen; 5] = [
+        Screen::Dashboard,
+        Screen::Accounts,
+        Screen::Operations,
+        Screen::Checkpoints,
+        Screen::Settings,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Screen::Dashboard => "Dashboard",
+            Screen::Accounts => "Accounts",
+            Screen::Operations => "Operations",
+            Screen::Checkpoints => "Checkpoints",
+            Screen::Settings => "Settings",
+        }
+    }
+}
+
+/// Action waiting for a y/n answer in the confirmation modal
+#[derive(Debug, Clone, PartialEq)]
+pub enum Confirmation {
+    BatchReclaim { count: usize, total_lamports: u64 },
+    ResetCheckpoints,
+}
+
+/// Regions from the last draw, used to route mouse events
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HitAreas {
+    pub tabs: Rect,
+    /// Accounts or Operations table
+    pub table: Rect,
+    /// Dashboard activity log
+    pub logs: Rect,
+}
+
 pub struct App {
     // UI State
     pub current_screen: Screen,
@@ -25,6 +118,11 @@ pub struct App {
     pub selected_index: usize,
     pub status_message: String,
     pub is_loading: bool,
+    pub show_help: bool,
+    pub hit_areas: HitAreas,
+    pub confirmation: Option<Confirmation>,
+    /// Pubkeys marked for batch reclaim on the Accounts screen
+    pub marked_accounts: HashSet<String>,
     
     // Data
     pub total_accounts: usize,
@@ -32,10 +130,42 @@ pub struct App {
     pub total_locked: u64,
     pub total_reclaimed: u64,
     pub accounts: Vec<AccountDisplay>,
+    /// Accounts shows tracked accounts eligible within UPCOMING_DAYS instead of the scan
+    pub upcoming_filter: bool,
+    /// Scanned accounts put aside while the upcoming filter is on
+    scanned_accounts: Vec<AccountDisplay>,
     pub operations: Vec<OperationDisplay>,
-    pub logs: Vec<String>,
+    pub logs: LogBuffer,
+    /// Most verbose level shown in the activity log
+    pub log_level: Level,
+    /// Lines scrolled back from the newest log entry
+    pub log_scroll: usize,
+    /// When stats were last reloaded from the database
     pub last_refresh: Instant,
-    pub alerts: Vec<String>,
+    /// When the account list was last rebuilt by a scan
+    pub last_scan: Option<Instant>,
+    /// Raised alerts, most severe first, including acknowledged ones
+    pub alerts: Vec<Alert>,
+    /// Last fetched treasury balance, when `tui.low_treasury_sol` is set
+    treasury_lamports: Option<u64>,
+    /// Configuration still missing, shown on the dashboard until it is set
+    pub setup_steps: Vec<SetupStep>,
+    /// Daily snapshots for the dashboard trend, oldest first
+    pub snapshots: Vec<Snapshot>,
+    /// Locked rent per token mint, largest first
+    pub locked_by_mint: Vec<MintRent>,
+    /// (key, value, updated_at) rows, as in `kora-reclaim checkpoints`
+    pub checkpoints: Vec<(String, String, String)>,
+    pub last_processed_slot: Option<u64>,
+    /// Endpoint latency and network slot from the background probe
+    pub rpc_status: RpcStatus,
+    rpc_probe: RpcProbe,
+    /// Recent discovery runs, newest first
+    pub scan_sessions: Vec<ScanSession>,
+    /// Scans failing since the last success, shown in the header
+    pub scan_failures: ScanFailureStreak,
+    /// SOL/USD price, refreshed in the background
+    price: Option<PriceOracle>,
     
     // Backend
     pub config: Config,
@@ -44,12 +174,14 @@ pub struct App {
     eligibility_checker: EligibilityChecker,
     reclaim_engine: Option<ReclaimEngine>,
     db: Database,
+    events: EventBus,
+    event_rx: broadcast::Receiver<ReclaimEvent>,
 
     // Telegram
     pub telegram_enabled: bool,
     pub telegram_configured: bool,
     pub telegram_status: String,
-    telegram_notifier: Option<crate::telegram::AutoNotifier>,
+    telegram_subscriber: Option<JoinHandle<()>>,
 }
 
 #[derive(Clone)]
@@ -67,45 +199,70 @@ pub struct OperationDisplay {
     pub account: String,
     pub amount: u64,
     pub signature: String,
+    pub reason: ReclaimReason,
 }
 
 impl App {
-    pub async fn new(config: Config) -> Result<Self> {
+    pub async fn new(config: Config, logs: LogBuffer) -> Result<Self> {
         // Initialize RPC client
-        let rpc_client = SolanaRpcClient::new(
-            &config.solana.rpc_url,
-            config.commitment_config(),
-            config.solana.rate_limit_delay_ms,
-        );
+        let rpc_client = SolanaRpcClient::from_config(&config);
+        
+        // Initialize database
+        let db = Database::new(&config.database.path)?;
         
         // Initialize monitor
         let operator_pubkey = config.operator_pubkey()?;
-        let monitor = KoraMonitor::new(rpc_client.clone(), operator_pubkey);
+        let monitor = KoraMonitor::new(rpc_client.clone(), operator_pubkey)
+            .with_parsers(ParserRegistry::from_config(&config))
+            .with_floor_slot(config.reclaim.scan_floor_slot)
+            .with_cache(db.clone());
         
         // Initialize eligibility checker
-        let eligibility_checker = EligibilityChecker::new(rpc_client.clone(), config.clone());
+        let eligibility_checker = EligibilityChecker::new(rpc_client.clone(), config.clone())
+            .with_holds(db.clone());
         
-        // Initialize database
-        let db = Database::new(&config.database.path)?;
+        let price = PriceOracle::from_config(&config.price);
+        if let Some(price) = &price {
+            tokio::spawn(price.clone().run());
+        }
+        
+        let rpc_probe = RpcProbe::new(rpc_client.clone());
+        tokio::spawn(rpc_probe.clone().run());
         
-        // Try to load reclaim engine (optional - might fail if no keypair)
-        let reclaim_engine = match config.load_treasury_keypair() {
-            Ok(keypair) => {
-                let treasury = config.treasury_wallet()?;
+        // Reclaiming needs the signers; without them the dashboard says what to set
+        let (signer, fee_payer) = (signer::from_config(&config), signer::fee_payer_from_config(&config));
+        let setup_steps = setup_steps(
+            &config,
+            signer.as_ref().err().map(|e| e.to_string()),
+            fee_payer.as_ref().err().map(|e| e.to_string()),
+        );
+        let reclaim_engine = match (signer, fee_payer) {
+            (Ok(signer), Ok(fee_payer)) => {
+                let treasury = config.reclaim_destination()?;
                 Some(ReclaimEngine::new(
                     rpc_client.clone(),
                     treasury,
-                    keypair,
+                    signer,
                     config.reclaim.dry_run,
-                ))
+                ).with_fee_payer(fee_payer)
+                .with_close_adapters(CloseAdapters::from_config(&config))
+                .with_fees(config.reclaim.fees.clone())
+                .with_memo(config.reclaim.memo.clone(), ReclaimReason::ManualTui))
             }
-            Err(_) => None,
+            _ => None,
         };
         
-        // Initialize Telegram notifier
-        let telegram_notifier = crate::telegram::AutoNotifier::new(&config);
+        // Activity log, Telegram and webhooks all follow the event bus
+        let events = EventBus::new();
+        let event_rx = events.subscribe();
+        if let Some(webhooks) = WebhookNotifier::new(&config) {
+            tokio::spawn(webhooks.run(events.subscribe()));
+        }
+
         let telegram_configured = config.telegram.is_some();
-        let telegram_enabled = telegram_notifier.is_some();
+        let telegram_subscriber = crate::telegram::AutoNotifier::new(&config)
+            .map(|_| Self::spawn_telegram_subscriber(&config, &events));
+        let telegram_enabled = telegram_subscriber.is_some();
         let telegram_status = if telegram_configured {
             if telegram_enabled {
                 "Active".to_string()
@@ -122,54 +279,164 @@ impl App {
             selected_index: 0,
             status_message: "Ready".to_string(),
             is_loading: false,
+            show_help: false,
+            hit_areas: HitAreas::default(),
+            confirmation: None,
+            marked_accounts: HashSet::new(),
             total_accounts: 0,
             eligible_accounts: 0,
             total_locked: 0,
             total_reclaimed: 0,
             accounts: Vec::new(),
+            upcoming_filter: false,
+            scanned_accounts: Vec::new(),
             operations: Vec::new(),
-            logs: Vec::new(),
+            logs,
+            log_level: Level::INFO,
+            log_scroll: 0,
             last_refresh: Instant::now(),
+            last_scan: None,
             alerts: Vec::new(),
+            treasury_lamports: None,
+            setup_steps,
+            snapshots: Vec::new(),
+            locked_by_mint: Vec::new(),
+            checkpoints: Vec::new(),
+            last_processed_slot: None,
+            rpc_status: RpcStatus::default(),
+            rpc_probe,
+            scan_sessions: Vec::new(),
+            scan_failures: ScanFailureStreak::default(),
+            price,
             telegram_enabled,
             telegram_configured,
             telegram_status,
-            telegram_notifier,
+            telegram_subscriber,
             config,
             rpc_client,
             monitor,
             eligibility_checker,
             reclaim_engine,
             db,
+            events,
+            event_rx,
         })
     }
 
+    fn spawn_telegram_subscriber(config: &Config, events: &EventBus) -> JoinHandle<()> {
+        tokio::spawn(crate::telegram::auto_notify::run_subscriber(
+            config.clone(),
+            events.subscribe(),
+        ))
+    }
+
     pub async fn on_tick(&mut self) {
-        // Refresh every 1 second
-        if self.last_refresh.elapsed() >= Duration::from_secs(1) {
-            self.last_refresh = Instant::now();
-            let _ = self.refresh_stats().await;
+        self.drain_events();
+        self.rpc_status = self.rpc_probe.latest();
+
+        // Database reads are throttled to the configured refresh interval
+        let interval = Duration::from_secs(self.config.tui.refresh_interval_seconds.max(1));
+        if !self.is_loading && self.last_refresh.elapsed() >= interval {
+            self.reload_from_db();
+            self.refresh_treasury_balance().await;
             self.check_alerts();
         }
     }
 
+    /// Seconds since stats were reloaded and, if a scan ran, since the scan
+    pub fn data_age(&self) -> (u64, Option<u64>) {
+        (
+            self.last_refresh.elapsed().as_secs(),
+            self.last_scan.map(|scan| scan.elapsed().as_secs()),
+        )
+    }
+
+    /// Latest SOL/USD price, if a price source is configured and answered
+    pub fn sol_usd(&self) -> Option<f64> {
+        self.price.as_ref()?.latest()
+    }
+
+    pub fn is_stale(&self, age_secs: u64) -> bool {
+        age_secs >= self.config.tui.stale_after_seconds
+    }
+
+    /// Fetch the treasury balance, only needed for the low treasury alert
+    async fn refresh_treasury_balance(&mut self) {
+        if self.config.tui.low_treasury_sol.is_none() {
+            return;
+        }
+        if let Ok(treasury) = self.config.reclaim_destination() {
+            match self.rpc_client.get_balance(&treasury).await {
+                Ok(balance) => self.treasury_lamports = Some(balance),
+                Err(e) => tracing::debug!("Failed to fetch treasury balance: {}", e),
+            }
+        }
+    }
+
+    /// Raise alerts from the current state and apply stored acknowledgements
     fn check_alerts(&mut self) {
-        self.alerts.clear();
-        
-        // Check for high value idle accounts
-        if let Some(threshold) = self.config.telegram.as_ref().map(|t| t.alert_threshold_sol) {
+        // Runtime overrides from Telegram /settings apply here as well
+        let settings = self.db.get_runtime_settings().unwrap_or_default();
+        let threshold = settings.alert_threshold_sol
+            .or(self.config.telegram.as_ref().map(|t| t.alert_threshold_sol));
+        let high_value = threshold.map(|threshold| {
             let threshold_lamports = (threshold * 1_000_000_000.0) as u64;
-            
-            let high_value_count = self.accounts.iter()
+            let count = self.all_accounts()
                 .filter(|a| a.eligible && a.balance >= threshold_lamports)
                 .count();
-                
-            if high_value_count > 0 {
-                self.alerts.push(format!("⚠️ {} accounts exceed {:.2} SOL threshold", high_value_count, threshold));
+            (count, threshold)
+        });
+
+        let mut raised = alerts::evaluate(&AlertInputs {
+            treasury_lamports: self.treasury_lamports,
+            low_treasury_lamports: self.config.tui.low_treasury_sol
+                .map(|sol| (sol * 1_000_000_000.0) as u64),
+            scan_failures: self.scan_failures.clone(),
+            scan_failure_alert_after: self.config.reclaim.scan_failure_alert_after,
+            auto_reclaim_paused: settings.auto_reclaim_enabled == Some(false),
+            high_value,
+        });
+
+        let acknowledged = self.db.get_alert_acknowledgements().unwrap_or_default();
+        alerts::apply_acknowledgements(&mut raised, &acknowledged);
+
+        // An acknowledgement lasts until its condition clears
+        for kind in acknowledged.keys() {
+            if !raised.iter().any(|alert| alert.kind.key() == kind) {
+                if let Err(e) = self.db.clear_alert_acknowledgement(kind) {
+                    tracing::warn!("Failed to clear alert acknowledgement: {}", e);
+                }
             }
         }
-        
-        // Add more alert logic here as needed
+
+        self.alerts = raised;
+    }
+
+    /// Alerts not yet acknowledged, most severe first
+    pub fn active_alerts(&self) -> impl Iterator<Item = &Alert> {
+        self.alerts.iter().filter(|alert| !alert.acknowledged)
+    }
+
+    /// Acknowledge the most severe open alert, or all of them
+    pub fn acknowledge_alerts(&mut self, all: bool) {
+        let mut count = 0;
+        for alert in self.alerts.iter_mut().filter(|alert| !alert.acknowledged) {
+            if let Err(e) = self.db.acknowledge_alert(alert.kind.key(), alert.severity.as_str()) {
+                self.status_message = format!("Failed to acknowledge alert: {}", e);
+                return;
+            }
+            alert.acknowledged = true;
+            count += 1;
+            if !all {
+                break;
+            }
+        }
+
+        self.status_message = match count {
+            0 => "No alerts to acknowledge".to_string(),
+            1 => "Alert acknowledged".to_string(),
+            n => format!("{} alerts acknowledged", n),
+        };
     }
     
     // Navigation
@@ -177,7 +444,8 @@ impl App {
         self.current_screen = match self.current_screen {
             Screen::Dashboard => Screen::Accounts,
             Screen::Accounts => Screen::Operations,
-            Screen::Operations => Screen::Settings,
+            Screen::Operations => Screen::Checkpoints,
+            Screen::Checkpoints => Screen::Settings,
             Screen::Settings => Screen::Dashboard,
         };
     }
@@ -185,12 +453,33 @@ impl App {
     pub fn previous_screen(&mut self) {
         self.current_screen = match self.current_screen {
             Screen::Dashboard => Screen::Settings,
-            Screen::Settings => Screen::Operations,
+            Screen::Settings => Screen::Checkpoints,
+            Screen::Checkpoints => Screen::Operations,
             Screen::Operations => Screen::Accounts,
             Screen::Accounts => Screen::Dashboard,
         };
     }
     
+    pub fn select_screen(&mut self, screen: Screen) {
+        if self.current_screen != screen {
+            self.current_screen = screen;
+            self.selected_index = 0;
+        }
+    }
+
+    /// Select a row of the current table, ignoring clicks past the last row
+    pub fn select_item(&mut self, index: usize) {
+        let len = if self.current_screen == Screen::Accounts {
+            self.accounts.len()
+        } else {
+            self.operations.len()
+        };
+
+        if index < len {
+            self.selected_index = index;
+        }
+    }
+
     pub fn next_item(&mut self) {
         let len = if self.current_screen == Screen::Accounts {
             self.accounts.len()
@@ -224,29 +513,67 @@ impl App {
         self.is_loading = true;
         self.add_log("Scanning for sponsored accounts...");
         
-        match self.monitor.get_sponsored_accounts(100).await {
+        let cancel = CancellationToken::new();
+        let _watcher = EscapeWatcher::start(cancel.clone());
+        let monitor = self.monitor.clone().with_cancel(cancel);
+        
+        // Phase one drops closed and empty accounts in one batched fetch
+        let scanned = match monitor.get_sponsored_accounts(100).await {
             Ok(sponsored) => {
+                let pubkeys: Vec<Pubkey> = sponsored.iter().map(|account| account.pubkey).collect();
+                let prescreen = match self.eligibility_checker.prescreen(&pubkeys).await {
+                    Ok(prescreen) => prescreen,
+                    Err(e) => {
+                        self.add_log(&format!("Batched fetch failed, fetching accounts one at a time: {}", e));
+                        self.eligibility_checker.prescreen_each(&pubkeys).await
+                    }
+                };
+                Ok((sponsored, prescreen))
+            }
+            Err(e) => Err(e),
+        };
+        
+        match scanned {
+            Ok((sponsored, prescreen)) => {
                 self.total_accounts = sponsored.len();
                 
                 // Check eligibility for each
                 let mut eligible_count = 0;
                 self.accounts.clear();
+                self.scanned_accounts.clear();
+                self.upcoming_filter = false;
+                self.marked_accounts.clear();
                 
-                for account in sponsored {
+                // Phase two: the per-account checks, on live accounts only
+                for (index, account) in sponsored.iter().enumerate() {
+                    let Some(fetched) = prescreen.get(index) else {
+                        // An account whose fetch failed may well still be open
+                        let status = if prescreen.is_gone(index) { "Closed" } else { "Fetch failed" };
+                        self.accounts.push(AccountDisplay {
+                            pubkey: account.pubkey.to_string(),
+                            balance: 0,
+                            created: account.created_at,
+                            status: status.to_string(),
+                            eligible: false,
+                        });
+                        continue;
+                    };
+                    
                     let is_eligible = self.eligibility_checker
-                        .is_eligible(&account.pubkey, account.created_at)
+                        .is_eligible_account(&account.pubkey, fetched, account.created_at)
                         .await
                         .unwrap_or(false);
                     
                     if is_eligible {
                         eligible_count += 1;
+                        self.events.publish(ReclaimEvent::AccountEligible {
+                            pubkey: account.pubkey.to_string(),
+                        });
                     }
                     
-                    let balance = self.rpc_client.get_balance(&account.pubkey).await.unwrap_or(0);
-                    
                     self.accounts.push(AccountDisplay {
                         pubkey: account.pubkey.to_string(),
-                        balance,
+                        balance: fetched.lamports,
                         created: account.created_at,
                         status: if is_eligible { "Eligible".to_string() } else { "Active".to_string() },
                         eligible: is_eligible,
@@ -254,22 +581,22 @@ impl App {
                 }
                 
                 self.eligible_accounts = eligible_count;
-                self.add_log(&format!("Found {} accounts, {} eligible", self.total_accounts, eligible_count));
+                self.last_scan = Some(Instant::now());
                 self.status_message = format!("Scan complete: {} accounts found", self.total_accounts);
-                
-                // Send Telegram notification
-                if let Some(ref notifier) = self.telegram_notifier {
-                    notifier.notify_scan_complete(self.total_accounts, eligible_count).await;
-                }
+                self.events.publish(ReclaimEvent::ScanCompleted {
+                    total: self.total_accounts,
+                    eligible: eligible_count,
+                });
+            }
+            Err(ReclaimError::Cancelled) => {
+                self.status_message = "Scan cancelled".to_string();
+                self.add_log("Scan cancelled");
             }
             Err(e) => {
-                self.add_log(&format!("Scan failed: {}", e));
                 self.status_message = format!("Scan failed: {}", e);
-                
-                // Send error notification
-                if let Some(ref notifier) = self.telegram_notifier {
-                    notifier.notify_error(&format!("Scan failed: {}", e)).await;
-                }
+                self.events.publish(ReclaimEvent::Error {
+                    message: format!("Scan failed: {}", e),
+                });
             }
         }
         
@@ -278,8 +605,12 @@ impl App {
     }
     
     pub async fn reclaim_selected(&mut self) -> Result<()> {
-        if self.accounts.is_empty() || self.reclaim_engine.is_none() {
-            self.status_message = "No account selected or reclaim engine not available".to_string();
+        if self.reclaim_engine.is_none() {
+            self.status_message = self.reclaim_unavailable();
+            return Ok(());
+        }
+        if self.accounts.is_empty() {
+            self.status_message = "No account selected".to_string();
             return Ok(());
         }
         
@@ -293,7 +624,7 @@ impl App {
         self.add_log(&format!("Reclaiming from {}...", &account.pubkey[..8]));
         
         let pubkey = Pubkey::try_from(account.pubkey.as_str())
-            .map_err(|e| crate::error::ReclaimError::Config(e.to_string()))?;
+            .map_err(|e| crate::error::ReclaimError::ConfigError(e.to_string()))?;
         
         let engine = self.reclaim_engine.as_ref().unwrap();
         let account_type = crate::kora::AccountType::SplToken;
@@ -308,25 +639,22 @@ impl App {
                         reclaimed_amount: result.amount_reclaimed,
                         tx_signature: sig.to_string(),
                         timestamp: Utc::now(),
-                        reason: "TUI manual reclaim".to_string(),
+                        reason: ReclaimReason::ManualTui,
+                        credited_amount: result.credited_amount(),
+                        destination: Some(result.destination.to_string()),
+                        fee_lamports: result.fee_lamports(),
                     });
                     
                     self.total_reclaimed += result.amount_reclaimed;
-                    self.add_log(&format!("✓ Reclaimed {} lamports", result.amount_reclaimed));
                     self.status_message = format!("Reclaimed successfully: {}", &sig.to_string()[..8]);
-                    
-                    // Send success notification
-                    if let Some(ref notifier) = self.telegram_notifier {
-                        notifier.notify_reclaim_success(&account.pubkey, result.amount_reclaimed).await;
-                        
-                        // Check if high-value
-                        if let Some(ref tg_config) = self.config.telegram {
-                            notifier.notify_high_value_reclaim(
-                                &account.pubkey,
-                                result.amount_reclaimed,
-                                tg_config.alert_threshold_sol
-                            ).await;
-                        }
+                    self.events.publish(ReclaimEvent::ReclaimSucceeded {
+                        pubkey: account.pubkey.clone(),
+                        amount: result.amount_reclaimed,
+                        signature: sig.to_string(),
+                        batched: false,
+                    });
+                    if let Some(mismatch) = result.credit_mismatch_event() {
+                        self.events.publish(mismatch);
                     }
                 } else {
                     self.add_log("Dry run - would reclaim");
@@ -334,13 +662,12 @@ impl App {
                 }
             }
             Err(e) => {
-                self.add_log(&format!("✗ Failed: {}", e));
                 self.status_message = format!("Reclaim failed: {}", e);
-                
-                // Send failure notification
-                if let Some(ref notifier) = self.telegram_notifier {
-                    notifier.notify_reclaim_failed(&account.pubkey, &e.to_string()).await;
-                }
+                self.events.publish(ReclaimEvent::ReclaimFailed {
+                    pubkey: account.pubkey.clone(),
+                    error: e.to_string(),
+                    retryable: e.is_retryable(),
+                });
             }
         }
         
@@ -348,19 +675,137 @@ impl App {
         Ok(())
     }
     
+    /// Mark or unmark the selected account for batch reclaim
+    pub fn toggle_mark(&mut self) {
+        let Some(account) = self.accounts.get(self.selected_index) else {
+            return;
+        };
+
+        if !account.eligible {
+            self.status_message = "Only eligible accounts can be marked".to_string();
+        } else if !self.marked_accounts.remove(&account.pubkey) {
+            self.marked_accounts.insert(account.pubkey.clone());
+        }
+    }
+
+    /// Mark every eligible account, or clear the marks if all already are
+    pub fn mark_all_eligible(&mut self) {
+        let eligible: Vec<String> = self.accounts.iter()
+            .filter(|a| a.eligible)
+            .map(|a| a.pubkey.clone())
+            .collect();
+
+        if !eligible.is_empty() && eligible.iter().all(|pk| self.marked_accounts.contains(pk)) {
+            self.marked_accounts.clear();
+        } else {
+            self.marked_accounts.extend(eligible);
+        }
+        self.status_message = format!("{} accounts marked", self.marked_accounts.len());
+    }
+
+    /// Scanned accounts, whether or not the upcoming filter hides them
+    fn all_accounts(&self) -> impl Iterator<Item = &AccountDisplay> {
+        self.accounts.iter().chain(&self.scanned_accounts)
+    }
+
+    /// Show tracked accounts that become old enough for reclaim within
+    /// UPCOMING_DAYS, or go back to the scanned accounts
+    pub fn toggle_upcoming(&mut self) {
+        self.selected_index = 0;
+        if self.upcoming_filter {
+            self.accounts = std::mem::take(&mut self.scanned_accounts);
+            self.upcoming_filter = false;
+            self.status_message = "Showing scanned accounts".to_string();
+            return;
+        }
+
+        let min_inactive_days = self.config.reclaim.min_inactive_days;
+        match self.db.get_accounts_eligible_within(min_inactive_days, UPCOMING_DAYS) {
+            Ok(upcoming) => {
+                let now = Utc::now();
+                let total: u64 = upcoming.iter().map(|a| a.balance()).sum();
+                self.status_message = format!(
+                    "{} accounts eligible within {} days, {:.4} SOL",
+                    upcoming.len(),
+                    UPCOMING_DAYS,
+                    total as f64 / 1_000_000_000.0
+                );
+                let upcoming = upcoming.iter().map(|a| AccountDisplay {
+                    pubkey: a.pubkey.clone(),
+                    balance: a.balance(),
+                    created: a.created_at,
+                    status: format!("in {}d", a.days_until_eligible(min_inactive_days, now)),
+                    eligible: false,
+                }).collect();
+                self.scanned_accounts = std::mem::replace(&mut self.accounts, upcoming);
+                self.upcoming_filter = true;
+            }
+            Err(e) => self.status_message = format!("Failed to load upcoming accounts: {}", e),
+        }
+    }
+
+    /// Eligible accounts currently marked, in table order
+    fn marked_eligible(&self) -> Vec<AccountDisplay> {
+        self.accounts.iter()
+            .filter(|a| a.eligible && self.marked_accounts.contains(&a.pubkey))
+            .cloned()
+            .collect()
+    }
+
+    /// Why reclaiming is disabled, pointing at the setting that enables it
+    fn reclaim_unavailable(&self) -> String {
+        match self.setup_steps.iter().find(|step| step.config_key.starts_with("kora.")) {
+            Some(step) => format!("Reclaim disabled: set {} (see Setup on the Dashboard)", step.config_key),
+            None => "Reclaim engine not available".to_string(),
+        }
+    }
+
+    /// Open the confirmation modal for a batch reclaim of the marked accounts
+    pub fn request_batch_reclaim(&mut self) {
+        if self.reclaim_engine.is_none() {
+            self.status_message = self.reclaim_unavailable();
+            return;
+        }
+
+        let marked = self.marked_eligible();
+        if marked.is_empty() {
+            self.status_message = "No accounts marked (Space: mark | a: mark all eligible)".to_string();
+            return;
+        }
+
+        self.confirmation = Some(Confirmation::BatchReclaim {
+            count: marked.len(),
+            total_lamports: marked.iter().map(|a| a.balance).sum(),
+        });
+    }
+
+    /// Run the action in the confirmation modal if `accepted`, then close it
+    pub async fn resolve_confirmation(&mut self, accepted: bool) -> Result<()> {
+        match self.confirmation.take() {
+            Some(Confirmation::BatchReclaim { .. }) if accepted => self.batch_reclaim().await,
+            Some(Confirmation::ResetCheckpoints) if accepted => {
+                self.reset_checkpoints();
+                Ok(())
+            }
+            Some(_) => {
+                self.status_message = "Cancelled".to_string();
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Reclaim the marked eligible accounts
     pub async fn batch_reclaim(&mut self) -> Result<()> {
         if self.reclaim_engine.is_none() {
-            self.status_message = "Reclaim engine not available".to_string();
+            self.status_message = self.reclaim_unavailable();
             return Ok(());
         }
         
-        let eligible: Vec<_> = self.accounts.iter()
-            .filter(|a| a.eligible)
-            .cloned()
-            .collect();
+        let eligible = self.marked_eligible();
         
         if eligible.is_empty() {
-            self.status_message = "No eligible accounts found".to_string();
+            self.status_message = "No marked eligible accounts".to_string();
             return Ok(());
         }
         
@@ -368,11 +813,14 @@ impl App {
         self.add_log(&format!("Batch reclaiming {} accounts...", eligible.len()));
         
         let engine = self.reclaim_engine.clone().unwrap();
+        let cancel = CancellationToken::new();
+        let _watcher = EscapeWatcher::start(cancel.clone());
         let batch = BatchProcessor::new(
             engine, 
             self.config.reclaim.batch_size, 
             self.config.reclaim.batch_delay_ms
-        );
+        ).with_adaptive(&self.config.reclaim.adaptive)
+        .with_cancel(cancel);
         
         let eligible_list: Vec<_> = eligible.iter()
             .filter_map(|a| {
@@ -384,23 +832,48 @@ impl App {
         match batch.reclaim_all_eligible(eligible_list).await {
             Ok(summary) => {
                 self.total_reclaimed += summary.total_reclaimed;
-                self.add_log(&format!("Batch complete: {} succeeded, {} failed", summary.successful, summary.failed));
+                self.marked_accounts.clear();
                 self.status_message = format!("Batch: {} ok, {} failed", summary.successful, summary.failed);
-                
-                // Send batch notification
-                if let Some(ref notifier) = self.telegram_notifier {
-                    let total_sol = crate::solana::rent::RentCalculator::lamports_to_sol(summary.total_reclaimed);
-                    notifier.notify_batch_complete(summary.successful, summary.failed, total_sol).await;
+                if summary.cancelled {
+                    self.status_message.push_str(&format!(
+                        ", cancelled with {} not attempted",
+                        summary.total_accounts - summary.results.len()
+                    ));
+                }
+
+                for (pubkey, result) in &summary.results {
+                    match result {
+                        Ok(result) => {
+                            if let Some(sig) = result.signature {
+                                self.events.publish(ReclaimEvent::ReclaimSucceeded {
+                                    pubkey: pubkey.to_string(),
+                                    amount: result.amount_reclaimed,
+                                    signature: sig.to_string(),
+                                    batched: true,
+                                });
+                                if let Some(mismatch) = result.credit_mismatch_event() {
+                                    self.events.publish(mismatch);
+                                }
+                            }
+                        }
+                        Err(e) => self.events.publish(ReclaimEvent::ReclaimFailed {
+                            pubkey: pubkey.to_string(),
+                            error: e.to_string(),
+                            retryable: e.is_retryable(),
+                        }),
+                    }
                 }
+                self.events.publish(ReclaimEvent::BatchCompleted {
+                    successful: summary.successful,
+                    failed: summary.failed,
+                    total_reclaimed: summary.total_reclaimed,
+                });
             }
             Err(e) => {
-                self.add_log(&format!("Batch failed: {}", e));
                 self.status_message = format!("Batch failed: {}", e);
-                
-                // Send error notification
-                if let Some(ref notifier) = self.telegram_notifier {
-                    notifier.notify_error(&format!("Batch reclaim failed: {}", e)).await;
-                }
+                self.events.publish(ReclaimEvent::Error {
+                    message: format!("Batch reclaim failed: {}", e),
+                });
             }
         }
         
@@ -408,14 +881,70 @@ impl App {
         Ok(())
     }
     
+    /// Open the confirmation modal for clearing the scan checkpoints
+    pub fn request_reset_checkpoints(&mut self) {
+        if self.checkpoints.is_empty() {
+            self.status_message = "No checkpoints to clear".to_string();
+            return;
+        }
+        self.confirmation = Some(Confirmation::ResetCheckpoints);
+    }
+
+    /// Clear the checkpoints so the next scan is a full scan
+    fn reset_checkpoints(&mut self) {
+        match self.db.clear_checkpoints() {
+            Ok(()) => {
+                self.add_log("✓ Checkpoints cleared; the next scan will be a full scan");
+                self.status_message = "Checkpoints cleared".to_string();
+            }
+            Err(e) => self.status_message = format!("Failed to clear checkpoints: {}", e),
+        }
+        self.reload_from_db();
+    }
+
     pub async fn refresh_stats(&mut self) -> Result<()> {
         self.is_loading = true;
-        
+        self.reload_from_db();
+        self.refresh_treasury_balance().await;
+        self.check_alerts();
+        self.is_loading = false;
+        self.status_message = "Stats refreshed".to_string();
+        Ok(())
+    }
+
+    /// Reload totals, history and account status from the database, which
+    /// other processes (the auto service, the bot) may have changed
+    fn reload_from_db(&mut self) {
+        self.last_refresh = Instant::now();
+
         // Load from database
         if let Ok(stats) = self.db.get_stats() {
             self.total_accounts = stats.total_accounts;
             self.total_reclaimed = stats.total_reclaimed;
         }
+
+        // Locked rent uses refreshed on-chain balances where available
+        if let Ok(active) = self.db.get_active_accounts() {
+            self.total_locked = active.iter().map(|a| a.balance()).sum();
+        }
+        
+        if let Ok(snapshots) = self.db.get_snapshots(TREND_DAYS) {
+            self.snapshots = snapshots;
+        }
+        if let Ok(by_mint) = self.db.get_locked_rent_by_mint() {
+            self.locked_by_mint = by_mint;
+        }
+
+        if let Ok(checkpoints) = self.db.get_checkpoint_info() {
+            self.checkpoints = checkpoints;
+        }
+        self.last_processed_slot = self.db.get_last_processed_slot().ok().flatten();
+        if let Ok(sessions) = self.db.get_scan_sessions(SCAN_HISTORY) {
+            self.scan_sessions = sessions;
+        }
+        if let Ok(streak) = self.db.get_scan_failure_streak() {
+            self.scan_failures = streak;
+        }
         
         // Load operations
         if let Ok(ops) = self.db.get_reclaim_history(Some(20)) {
@@ -425,13 +954,22 @@ impl App {
                     account: op.account_pubkey,
                     amount: op.reclaimed_amount,
                     signature: op.tx_signature,
+                    reason: op.reason,
                 }
             }).collect();
         }
-        
-        self.is_loading = false;
-        self.status_message = "Stats refreshed".to_string();
-        Ok(())
+
+        // Accounts closed or reclaimed since the last scan are no longer eligible
+        for account in self.accounts.iter_mut().chain(self.scanned_accounts.iter_mut()) {
+            if let Ok(Some(stored)) = self.db.get_account_by_pubkey(&account.pubkey) {
+                if stored.status != AccountStatus::Active {
+                    account.status = format!("{:?}", stored.status);
+                    account.eligible = false;
+                    self.marked_accounts.remove(&account.pubkey);
+                }
+            }
+        }
+        self.eligible_accounts = self.all_accounts().filter(|a| a.eligible).count();
     }
 
     // Telegram controls
@@ -444,15 +982,19 @@ impl App {
         
         if self.telegram_enabled {
             // Disable
-            self.telegram_notifier = None;
+            if let Some(subscriber) = self.telegram_subscriber.take() {
+                subscriber.abort();
+            }
             self.telegram_enabled = false;
             self.telegram_status = "Disabled".to_string();
             self.add_log("✓ Telegram notifications disabled");
             self.status_message = "Telegram notifications disabled".to_string();
         } else {
             // Enable
-            self.telegram_notifier = crate::telegram::AutoNotifier::new(&self.config);
-            self.telegram_enabled = self.telegram_notifier.is_some();
+            if crate::telegram::AutoNotifier::new(&self.config).is_some() {
+                self.telegram_subscriber = Some(Self::spawn_telegram_subscriber(&self.config, &self.events));
+            }
+            self.telegram_enabled = self.telegram_subscriber.is_some();
             
             if self.telegram_enabled {
                 self.telegram_status = "Active".to_string();
@@ -467,14 +1009,13 @@ impl App {
     }
 
     pub async fn test_telegram(&mut self) {
-        let has_notifier = self.telegram_notifier.is_some();
-        
-        if has_notifier {
+        // Sent directly rather than through the bus so webhooks don't see it
+        let notifier = crate::telegram::AutoNotifier::new(&self.config)
+            .filter(|_| self.telegram_enabled);
+
+        if let Some(notifier) = notifier {
             self.add_log("Sending test notification...");
-            
-            if let Some(ref notifier) = self.telegram_notifier {
-                notifier.notify_error("🧪 Test notification from TUI").await;
-            }
+            notifier.notify_error("🧪 Test notification from TUI").await;
             
             self.status_message = "Test notification sent".to_string();
             self.add_log("✓ Test notification sent");
@@ -484,11 +1025,40 @@ impl App {
         }
     }
     
-    fn add_log(&mut self, message: &str) {
-        let timestamp = Utc::now().format("%H:%M:%S");
-        self.logs.push(format!("[{}] {}", timestamp, message));
-        if self.logs.len() > 100 {
-            self.logs.remove(0);
+    /// Mirror published events into the activity log
+    fn drain_events(&mut self) {
+        loop {
+            match self.event_rx.try_recv() {
+                Ok(ReclaimEvent::AccountEligible { .. }) => {}
+                Ok(event) => self.add_log(&event.to_string()),
+                Err(broadcast::error::TryRecvError::Lagged(_)) => continue,
+                Err(_) => break,
+            }
         }
     }
+
+    fn add_log(&mut self, message: &str) {
+        self.logs.push(Level::INFO, "tui", message.to_string());
+    }
+
+    // Activity log controls
+    pub fn cycle_log_level(&mut self) {
+        self.log_level = match self.log_level {
+            Level::ERROR => Level::WARN,
+            Level::WARN => Level::INFO,
+            Level::INFO => Level::DEBUG,
+            _ => Level::ERROR,
+        };
+        self.log_scroll = 0;
+        self.status_message = format!("Log level: {}", self.log_level);
+    }
+
+    pub fn scroll_logs_up(&mut self, lines: usize) {
+        let available = self.logs.filtered(self.log_level).len();
+        self.log_scroll = (self.log_scroll + lines).min(available.saturating_sub(1));
+    }
+
+    pub fn scroll_logs_down(&mut self, lines: usize) {
+        self.log_scroll = self.log_scroll.saturating_sub(lines);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,341 @@
+// src/tui/keymap.rs - Central keybinding table for the TUI
+//
+// The event loop, the footer hints and the `?` help overlay are all
+// generated from KEYMAP, so a binding only needs to be added here.
+
+use crate::tui::app::Screen;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Quit,
+    NextScreen,
+    PreviousScreen,
+    NextItem,
+    PreviousItem,
+    Scan,
+    Refresh,
+    ToggleTelegram,
+    TestTelegram,
+    Reclaim,
+    BatchReclaim,
+    ToggleMark,
+    MarkAllEligible,
+    ToggleUpcoming,
+    ResetCheckpoints,
+    CycleLogLevel,
+    ScrollLogsUp,
+    ScrollLogsDown,
+    ScrollLogsEnd,
+    AcknowledgeAlert,
+    AcknowledgeAllAlerts,
+    ToggleHelp,
+}
+
+/// Where a binding is active
+#[derive(Debug, Clone, Copy)]
+pub enum Scope {
+    Global,
+    Screens(&'static [Screen]),
+}
+
+pub struct Binding {
+    pub keys: &'static [KeyCode],
+    /// Requires the Control modifier
+    pub ctrl: bool,
+    /// Key names as shown to the user
+    pub label: &'static str,
+    pub description: &'static str,
+    /// Short footer hint; `None` keeps the binding out of the footer
+    pub hint: Option<&'static str>,
+    pub scope: Scope,
+    pub action: Action,
+}
+
+pub const KEYMAP: &[Binding] = &[
+    Binding {
+        keys: &[KeyCode::Char('?')],
+        ctrl: false,
+        label: "?",
+        description: "Show or hide this help",
+        hint: Some("Help"),
+        scope: Scope::Global,
+        action: Action::ToggleHelp,
+    },
+    Binding {
+        keys: &[KeyCode::Char('q'), KeyCode::Esc],
+        ctrl: false,
+        label: "q / Esc",
+        description: "Quit",
+        hint: None,
+        scope: Scope::Global,
+        action: Action::Quit,
+    },
+    Binding {
+        keys: &[KeyCode::Char('c')],
+        ctrl: true,
+        label: "Ctrl+C",
+        description: "Quit",
+        hint: None,
+        scope: Scope::Global,
+        action: Action::Quit,
+    },
+    Binding {
+        keys: &[KeyCode::Tab],
+        ctrl: false,
+        label: "Tab",
+        description: "Next screen",
+        hint: None,
+        scope: Scope::Global,
+        action: Action::NextScreen,
+    },
+    Binding {
+        keys: &[KeyCode::BackTab],
+        ctrl: false,
+        label: "Shift+Tab",
+        description: "Previous screen",
+        hint: None,
+        scope: Scope::Global,
+        action: Action::PreviousScreen,
+    },
+    Binding {
+        keys: &[KeyCode::Down, KeyCode::Char('j')],
+        ctrl: false,
+        label: "↓ / j",
+        description: "Select next row",
+        hint: None,
+        scope: Scope::Screens(&[Screen::Accounts, Screen::Operations]),
+        action: Action::NextItem,
+    },
+    Binding {
+        keys: &[KeyCode::Up, KeyCode::Char('k')],
+        ctrl: false,
+        label: "↑ / k",
+        description: "Select previous row",
+        hint: None,
+        scope: Scope::Screens(&[Screen::Accounts, Screen::Operations]),
+        action: Action::PreviousItem,
+    },
+    Binding {
+        keys: &[KeyCode::Char('s')],
+        ctrl: false,
+        label: "s",
+        description: "Scan for sponsored accounts",
+        hint: Some("Scan"),
+        scope: Scope::Screens(&[Screen::Dashboard, Screen::Accounts]),
+        action: Action::Scan,
+    },
+    Binding {
+        keys: &[KeyCode::Char('r')],
+        ctrl: false,
+        label: "r",
+        description: "Refresh stats and history from the database",
+        hint: Some("Refresh"),
+        scope: Scope::Screens(&[Screen::Dashboard, Screen::Operations, Screen::Checkpoints]),
+        action: Action::Refresh,
+    },
+    Binding {
+        keys: &[KeyCode::Enter],
+        ctrl: false,
+        label: "Enter",
+        description: "Reclaim the selected account",
+        hint: Some("Reclaim"),
+        scope: Scope::Screens(&[Screen::Accounts]),
+        action: Action::Reclaim,
+    },
+    Binding {
+        keys: &[KeyCode::Char('b')],
+        ctrl: false,
+        label: "b",
+        description: "Batch reclaim the marked accounts (asks to confirm)",
+        hint: Some("Batch"),
+        scope: Scope::Screens(&[Screen::Accounts]),
+        action: Action::BatchReclaim,
+    },
+    Binding {
+        keys: &[KeyCode::Char(' ')],
+        ctrl: false,
+        label: "Space",
+        description: "Mark or unmark the selected account",
+        hint: Some("Mark"),
+        scope: Scope::Screens(&[Screen::Accounts]),
+        action: Action::ToggleMark,
+    },
+    Binding {
+        keys: &[KeyCode::Char('a')],
+        ctrl: false,
+        label: "a",
+        description: "Mark all eligible accounts (again to clear)",
+        hint: Some("Mark all"),
+        scope: Scope::Screens(&[Screen::Accounts]),
+        action: Action::MarkAllEligible,
+    },
+    Binding {
+        keys: &[KeyCode::Char('u')],
+        ctrl: false,
+        label: "u",
+        description: "Show accounts eligible within 7 days (again for the scanned accounts)",
+        hint: Some("Upcoming"),
+        scope: Scope::Screens(&[Screen::Accounts]),
+        action: Action::ToggleUpcoming,
+    },
+    Binding {
+        keys: &[KeyCode::Char('R')],
+        ctrl: false,
+        label: "R",
+        description: "Reset checkpoints to force a full rescan (asks to confirm)",
+        hint: Some("Reset"),
+        scope: Scope::Screens(&[Screen::Checkpoints]),
+        action: Action::ResetCheckpoints,
+    },
+    Binding {
+        keys: &[KeyCode::Char('t')],
+        ctrl: false,
+        label: "t",
+        description: "Toggle Telegram notifications",
+        hint: Some("Toggle TG"),
+        scope: Scope::Screens(&[Screen::Dashboard, Screen::Accounts, Screen::Settings]),
+        action: Action::ToggleTelegram,
+    },
+    Binding {
+        keys: &[KeyCode::Char('T')],
+        ctrl: false,
+        label: "T",
+        description: "Send a Telegram test notification",
+        hint: Some("Test TG"),
+        scope: Scope::Screens(&[Screen::Dashboard, Screen::Settings]),
+        action: Action::TestTelegram,
+    },
+    Binding {
+        keys: &[KeyCode::Char('l')],
+        ctrl: false,
+        label: "l",
+        description: "Cycle activity log level",
+        hint: Some("Log level"),
+        scope: Scope::Screens(&[Screen::Dashboard]),
+        action: Action::CycleLogLevel,
+    },
+    Binding {
+        keys: &[KeyCode::Char('a')],
+        ctrl: false,
+        label: "a",
+        description: "Acknowledge the most severe alert until it clears or escalates",
+        hint: Some("Ack alert"),
+        scope: Scope::Screens(&[Screen::Dashboard]),
+        action: Action::AcknowledgeAlert,
+    },
+    Binding {
+        keys: &[KeyCode::Char('A')],
+        ctrl: false,
+        label: "A",
+        description: "Acknowledge all alerts",
+        hint: None,
+        scope: Scope::Screens(&[Screen::Dashboard]),
+        action: Action::AcknowledgeAllAlerts,
+    },
+    Binding {
+        keys: &[KeyCode::PageUp],
+        ctrl: false,
+        label: "PgUp",
+        description: "Scroll activity log back",
+        hint: None,
+        scope: Scope::Screens(&[Screen::Dashboard]),
+        action: Action::ScrollLogsUp,
+    },
+    Binding {
+        keys: &[KeyCode::PageDown],
+        ctrl: false,
+        label: "PgDn",
+        description: "Scroll activity log forward",
+        hint: None,
+        scope: Scope::Screens(&[Screen::Dashboard]),
+        action: Action::ScrollLogsDown,
+    },
+    Binding {
+        keys: &[KeyCode::End],
+        ctrl: false,
+        label: "End",
+        description: "Jump to the newest log entry",
+        hint: None,
+        scope: Scope::Screens(&[Screen::Dashboard]),
+        action: Action::ScrollLogsEnd,
+    },
+];
+
+impl Binding {
+    pub fn applies_to(&self, screen: &Screen) -> bool {
+        match self.scope {
+            Scope::Global => true,
+            Scope::Screens(screens) => screens.contains(screen),
+        }
+    }
+
+    fn matches(&self, key: &KeyEvent) -> bool {
+        self.ctrl == key.modifiers.contains(KeyModifiers::CONTROL) && self.keys.contains(&key.code)
+    }
+}
+
+/// Action bound to `key` on `screen`
+pub fn action_for(key: &KeyEvent, screen: &Screen) -> Option<Action> {
+    KEYMAP.iter()
+        .find(|binding| binding.applies_to(screen) && binding.matches(key))
+        .map(|binding| binding.action)
+}
+
+/// Footer hint line for a screen, e.g. " s:Scan | r:Refresh | ?:Help "
+pub fn footer_hints(screen: &Screen) -> String {
+    let hints: Vec<String> = KEYMAP.iter()
+        .filter(|binding| binding.applies_to(screen) && matches!(binding.scope, Scope::Screens(_)))
+        .chain(KEYMAP.iter().filter(|binding| matches!(binding.scope, Scope::Global)))
+        .filter_map(|binding| binding.hint.map(|hint| format!("{}:{}", binding.label, hint)))
+        .collect();
+
+    format!(" {} ", hints.join(" | "))
+}
+
+/// Help overlay sections: global bindings, then one section per screen
+pub fn help_sections() -> Vec<(String, Vec<&'static Binding>)> {
+    let mut sections = vec![(
+        "Global".to_string(),
+        KEYMAP.iter().filter(|b| matches!(b.scope, Scope::Global)).collect(),
+    )];
+
+    for screen in Screen::ALL {
+        let bindings: Vec<&Binding> = KEYMAP.iter()
+            .filter(|b| matches!(b.scope, Scope::Screens(_)) && b.applies_to(&screen))
+            .collect();
+        if !bindings.is_empty() {
+            sections.push((screen.title().to_string(), bindings));
+        }
+    }
+
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_conflicting_bindings_per_screen() {
+        for screen in Screen::ALL {
+            let active: Vec<&Binding> = KEYMAP.iter().filter(|b| b.applies_to(&screen)).collect();
+            for (i, a) in active.iter().enumerate() {
+                for b in &active[i + 1..] {
+                    let overlap = a.ctrl == b.ctrl && a.keys.iter().any(|k| b.keys.contains(k));
+                    assert!(!overlap, "{} and {} conflict on {:?}", a.label, b.label, screen);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_action_lookup_respects_scope() {
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(action_for(&enter, &Screen::Accounts), Some(Action::Reclaim));
+        assert_eq!(action_for(&enter, &Screen::Dashboard), None);
+
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert_eq!(action_for(&ctrl_c, &Screen::Settings), Some(Action::Quit));
+    }
+}
@@ -0,0 +1,135 @@
+// src/tui/log_layer.rs - tracing layer feeding the TUI activity log
+
+use chrono::{DateTime, Local};
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+/// Entries kept for scrollback before the oldest are dropped
+const LOG_CAPACITY: usize = 1000;
+
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub timestamp: DateTime<Local>,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+/// Shared ring buffer written by [`TuiLogLayer`] and read by the TUI
+#[derive(Clone, Default)]
+pub struct LogBuffer {
+    entries: Arc<Mutex<VecDeque<LogEntry>>>,
+}
+
+impl LogBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&self, level: Level, target: &str, message: String) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if entries.len() >= LOG_CAPACITY {
+            entries.pop_front();
+        }
+        entries.push_back(LogEntry {
+            timestamp: Local::now(),
+            level,
+            target: target.to_string(),
+            message,
+        });
+    }
+
+    /// Entries at or above `max_level` in severity, oldest first
+    pub fn filtered(&self, max_level: Level) -> Vec<LogEntry> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.iter()
+            .filter(|entry| entry.level <= max_level)
+            .cloned()
+            .collect()
+    }
+
+    /// Layer forwarding every event it sees into this buffer
+    pub fn layer(&self) -> TuiLogLayer {
+        TuiLogLayer { buffer: self.clone() }
+    }
+}
+
+/// `tracing` layer that records events instead of printing them, so logs
+/// don't tear the alternate screen while the TUI is running
+pub struct TuiLogLayer {
+    buffer: LogBuffer,
+}
+
+impl<S: Subscriber> Layer<S> for TuiLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let metadata = event.metadata();
+        self.buffer.push(*metadata.level(), metadata.target(), visitor.finish());
+    }
+}
+
+/// Collects the `message` field followed by any structured fields
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        if self.fields.is_empty() {
+            self.message
+        } else {
+            format!("{}{}", self.message, self.fields)
+        }
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={}", field.name(), value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else if !field.name().starts_with("log.") {
+            let _ = write!(self.fields, " {}={:?}", field.name(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn test_layer_captures_events_with_fields() {
+        let buffer = LogBuffer::new();
+        let subscriber = tracing_subscriber::registry().with(buffer.layer());
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!("probing");
+            tracing::warn!(slot = 42, "RPC slow");
+        });
+
+        let all = buffer.filtered(Level::TRACE);
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[1].message, "RPC slow slot=42");
+
+        let warnings = buffer.filtered(Level::WARN);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].level, Level::WARN);
+    }
+}
@@ -1,6 +1,12 @@
+pub mod alerts;
 pub mod app;
+pub mod keymap;
+pub mod log_layer;
+pub mod onboarding;
+pub mod rpc_status;
 pub mod ui;
 // DELETE THIS LINE: pub mod event;
 
 //pub use app::App;
+pub use log_layer::LogBuffer;
 pub use ui::run_tui;
\ No newline at end of file
@@ -0,0 +1,73 @@
+// src/tui/onboarding.rs - Setup steps shown on the dashboard while the config is incomplete
+
+use crate::config::Config;
+
+/// Something missing from the configuration, and what it costs
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetupStep {
+    /// What is missing, e.g. "Signing keypair not loaded: ..."
+    pub problem: String,
+    /// Configuration key that fixes it
+    pub config_key: &'static str,
+    /// What stays unavailable until then
+    pub impact: &'static str,
+}
+
+const RECLAIM_DISABLED: &str = "Reclaims are disabled; scanning and monitoring still work";
+
+/// Steps left for `config`, given why its signers failed to load, if they did
+pub fn setup_steps(config: &Config, signer_error: Option<String>, fee_payer_error: Option<String>) -> Vec<SetupStep> {
+    let mut steps = Vec::new();
+
+    if let Some(error) = signer_error {
+        steps.push(SetupStep {
+            problem: format!("Signing keypair not loaded: {}", error),
+            config_key: if config.kora.remote_signer.is_some() {
+                "kora.remote_signer"
+            } else {
+                "kora.treasury_keypair_path"
+            },
+            impact: RECLAIM_DISABLED,
+        });
+    }
+    if let Some(error) = fee_payer_error {
+        steps.push(SetupStep {
+            problem: format!("Fee payer keypair not loaded: {}", error),
+            config_key: "kora.fee_payer_keypair_path",
+            impact: RECLAIM_DISABLED,
+        });
+    }
+
+    match &config.telegram {
+        None => steps.push(SetupStep {
+            problem: "Telegram is not configured".to_string(),
+            config_key: "[telegram] bot_token",
+            impact: "No notifications; alerts only show here",
+        }),
+        Some(telegram) if telegram.authorized_users.is_empty() => steps.push(SetupStep {
+            problem: "No Telegram users authorized".to_string(),
+            config_key: "telegram.authorized_users",
+            impact: "Notifications have no recipients and the bot answers no one",
+        }),
+        Some(_) => {}
+    }
+
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_setup_steps_name_the_missing_keys() {
+        let mut config: Config = toml::from_str(include_str!("../../config.toml")).unwrap();
+        config.kora.remote_signer = None;
+        config.telegram = None;
+
+        let steps = setup_steps(&config, Some("No such file or directory".to_string()), None);
+        let keys: Vec<_> = steps.iter().map(|step| step.config_key).collect();
+        assert_eq!(keys, ["kora.treasury_keypair_path", "[telegram] bot_token"]);
+        assert_eq!(steps[0].impact, RECLAIM_DISABLED);
+    }
+}
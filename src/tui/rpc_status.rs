@@ -0,0 +1,61 @@
+// src/tui/rpc_status.rs - Background RPC probe behind the header status
+
+use crate::solana::SolanaRpcClient;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Time between probes
+const PROBE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Latest probe of the RPC endpoint
+#[derive(Debug, Clone, Default)]
+pub struct RpcStatus {
+    /// Round trip of the last successful `getSlot`, without rate limiter waits
+    pub latency: Option<Duration>,
+    /// Network slot; kept from the last success while the endpoint fails
+    pub slot: Option<u64>,
+    /// Error of the last probe, if it failed
+    pub error: Option<String>,
+}
+
+/// Probes the endpoint every [`PROBE_INTERVAL`]. Clones share the status.
+#[derive(Clone)]
+pub struct RpcProbe {
+    client: SolanaRpcClient,
+    status: Arc<Mutex<RpcStatus>>,
+}
+
+impl RpcProbe {
+    pub fn new(client: SolanaRpcClient) -> Self {
+        Self {
+            client,
+            status: Arc::default(),
+        }
+    }
+
+    pub fn latest(&self) -> RpcStatus {
+        self.status.lock().unwrap().clone()
+    }
+
+    pub async fn run(self) {
+        loop {
+            let result = self.client.ping().await;
+            {
+                let mut status = self.status.lock().unwrap();
+                match result {
+                    Ok((slot, latency)) => {
+                        status.slot = Some(slot);
+                        status.latency = Some(latency);
+                        status.error = None;
+                    }
+                    Err(e) => {
+                        status.latency = None;
+                        status.error = Some(e.to_string());
+                    }
+                }
+            }
+
+            tokio::time::sleep(PROBE_INTERVAL).await;
+        }
+    }
+}
@@ -1,22 +1,30 @@
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, MouseButton, MouseEvent, MouseEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout, Alignment},
+    layout::{Constraint, Direction, Layout, Alignment, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Row, Table, Tabs},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Row, Sparkline, Table, Tabs},
     Frame, Terminal,
 };
 use std::io;
-use crate::tui::app::{App, Screen};
+use crate::tui::alerts::Severity;
+use crate::tui::app::{App, Confirmation, HitAreas, Screen, UPCOMING_DAYS};
+use crate::tui::keymap::{self, Action};
+use crate::tui::log_layer::LogBuffer;
 use crate::config::Config;
 use crate::error::Result;
 
-pub async fn run_tui(config: Config) -> Result<()> {
+/// Alerts listed on the dashboard before the rest are cut off
+const MAX_ALERT_LINES: usize = 4;
+
+/// Run the dashboard; `logs` should be the buffer the global tracing
+/// subscriber writes into so library logs show up in the activity log
+pub async fn run_tui(config: Config, logs: LogBuffer) -> Result<()> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -25,7 +33,7 @@ pub async fn run_tui(config: Config) -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
     
     // Create app
-    let mut app = App::new(config).await?;
+    let mut app = App::new(config, logs).await?;
     
     // Initial data load
     app.refresh_stats().await?;
@@ -50,44 +58,25 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mu
         terminal.draw(|f| ui(f, app))?;
         
         if event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Char('q') | KeyCode::Esc => {
-                        app.should_quit = true;
-                    }
-                    KeyCode::Tab => app.next_screen(),
-                    KeyCode::BackTab => app.previous_screen(),
-                    KeyCode::Down | KeyCode::Char('j') => app.next_item(),
-                    KeyCode::Up | KeyCode::Char('k') => app.previous_item(),
-                    KeyCode::Char('s') => {
-                        app.scan_accounts().await?;
-                    }
-                    KeyCode::Char('r') => {
-                        app.refresh_stats().await?;
-                    }
-                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                        app.should_quit = true;
-                    }
-                    KeyCode::Char('t') => {
-                        // Toggle Telegram
-                        app.toggle_telegram();
-                    }
-                    KeyCode::Char('T') => {
-                        // Test Telegram (Shift+T)
-                        app.test_telegram().await;
-                    }
-                    KeyCode::Enter => {
-                        if app.current_screen == Screen::Accounts {
-                            app.reclaim_selected().await?;
-                        }
-                    }
-                    KeyCode::Char('b') => {
-                        if app.current_screen == Screen::Accounts {
-                            app.batch_reclaim().await?;
+            match event::read()? {
+                Event::Key(key) => {
+                    if app.confirmation.is_some() {
+                        let accepted = matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter);
+                        app.resolve_confirmation(accepted).await?;
+                    } else if app.show_help {
+                        // Any key dismisses the overlay
+                        app.show_help = false;
+                    } else if let Some(action) = keymap::action_for(&key, &app.current_screen) {
+                        // The scan holds the loop, so show how to stop it first
+                        if action == Action::Scan {
+                            app.status_message = "Scanning... (Esc to cancel)".to_string();
+                            terminal.draw(|f| ui(f, app))?;
                         }
+                        handle_action(app, action).await?;
                     }
-                    _ => {}
                 }
+                Event::Mouse(mouse) if app.confirmation.is_none() => handle_mouse(app, mouse),
+                _ => {}
             }
         } else {
             // Timeout expired (tick)
@@ -102,7 +91,35 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mu
     Ok(())
 }
 
-fn ui(f: &mut Frame, app: &App) {
+async fn handle_action(app: &mut App, action: Action) -> Result<()> {
+    match action {
+        Action::Quit => app.should_quit = true,
+        Action::NextScreen => app.next_screen(),
+        Action::PreviousScreen => app.previous_screen(),
+        Action::NextItem => app.next_item(),
+        Action::PreviousItem => app.previous_item(),
+        Action::Scan => app.scan_accounts().await?,
+        Action::Refresh => app.refresh_stats().await?,
+        Action::ToggleTelegram => app.toggle_telegram(),
+        Action::TestTelegram => app.test_telegram().await,
+        Action::Reclaim => app.reclaim_selected().await?,
+        Action::BatchReclaim => app.request_batch_reclaim(),
+        Action::ToggleMark => app.toggle_mark(),
+        Action::MarkAllEligible => app.mark_all_eligible(),
+        Action::ToggleUpcoming => app.toggle_upcoming(),
+        Action::ResetCheckpoints => app.request_reset_checkpoints(),
+        Action::CycleLogLevel => app.cycle_log_level(),
+        Action::ScrollLogsUp => app.scroll_logs_up(10),
+        Action::ScrollLogsDown => app.scroll_logs_down(10),
+        Action::ScrollLogsEnd => app.log_scroll = 0,
+        Action::AcknowledgeAlert => app.acknowledge_alerts(false),
+        Action::AcknowledgeAllAlerts => app.acknowledge_alerts(true),
+        Action::ToggleHelp => app.show_help = true,
+    }
+    Ok(())
+}
+
+fn ui(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -116,45 +133,211 @@ fn ui(f: &mut Frame, app: &App) {
     render_header(f, chunks[0], app);
     
     // Content
+    let mut hit_areas = HitAreas::default();
     match app.current_screen {
-        Screen::Dashboard => render_dashboard(f, chunks[1], app),
-        Screen::Accounts => render_accounts(f, chunks[1], app),
-        Screen::Operations => render_operations(f, chunks[1], app),
+        Screen::Dashboard => hit_areas.logs = render_dashboard(f, chunks[1], app),
+        Screen::Accounts => {
+            render_accounts(f, chunks[1], app);
+            hit_areas.table = chunks[1];
+        }
+        Screen::Operations => {
+            render_operations(f, chunks[1], app);
+            hit_areas.table = chunks[1];
+        }
+        Screen::Checkpoints => render_checkpoints(f, chunks[1], app),
         Screen::Settings => render_settings(f, chunks[1], app),
     }
     
     // Status bar
-    render_status(f, chunks[2], app);
+    hit_areas.tabs = render_status(f, chunks[2], app);
+    app.hit_areas = hit_areas;
+
+    if app.show_help {
+        render_help(f, app);
+    }
+    if let Some(confirmation) = &app.confirmation {
+        render_confirmation(f, confirmation);
+    }
+}
+
+fn render_confirmation(f: &mut Frame, confirmation: &Confirmation) {
+    let lines = match confirmation {
+        Confirmation::BatchReclaim { count, total_lamports } => vec![
+            Line::from(Span::styled(
+                format!("Reclaim {} selected account{}?", count, if *count == 1 { "" } else { "s" }),
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+            Line::from(format!("Current balance: {:.9} SOL", *total_lamports as f64 / 1_000_000_000.0)),
+            Line::from("Esc while running stops after the current chunk"),
+            Line::from(""),
+            Line::from(Span::styled("y/Enter: confirm | any other key: cancel", Style::default().fg(Color::DarkGray))),
+        ],
+        Confirmation::ResetCheckpoints => vec![
+            Line::from(Span::styled("Reset all scan checkpoints?", Style::default().add_modifier(Modifier::BOLD))),
+            Line::from("The next scan will be a full scan from the beginning."),
+            Line::from(""),
+            Line::from(Span::styled("y/Enter: confirm | any other key: cancel", Style::default().fg(Color::DarkGray))),
+        ],
+    };
+
+    let area = centered_rect(50, lines.len() as u16 + 2, f.size());
+    let modal = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Yellow)).title("Confirm"));
+
+    f.render_widget(Clear, area);
+    f.render_widget(modal, area);
+}
+
+fn render_help(f: &mut Frame, app: &App) {
+    let mut lines = Vec::new();
+    for (title, bindings) in keymap::help_sections() {
+        let current = title == format!("{:?}", app.current_screen);
+        let heading = if current { format!("{} (current)", title) } else { title };
+        lines.push(Line::from(Span::styled(
+            heading,
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        )));
+        for binding in bindings {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {:<12}", binding.label), Style::default().fg(Color::Yellow)),
+                Span::raw(binding.description),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(Span::styled("Press any key to close", Style::default().fg(Color::DarkGray))));
+
+    let area = centered_rect(60, lines.len() as u16 + 2, f.size());
+    let help = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title("Keybindings"));
+
+    f.render_widget(Clear, area);
+    f.render_widget(help, area);
+}
+
+/// Compact age such as "45s", "12m" or "3h"
+fn format_age(secs: u64) -> String {
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        _ => format!("{}h", secs / 3600),
+    }
+}
+
+/// Rect of `percent_x` width and `height` rows centered in `area`
+fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
+    let height = height.min(area.height);
+    let width = area.width * percent_x / 100;
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
 }
 
 fn render_header(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
-    let title = Line::from(vec![
+    let (stats_age, scan_age) = app.data_age();
+    let age_style = |age: u64| {
+        if app.is_stale(age) {
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        }
+    };
+
+    let mut spans = vec![
         Span::raw("⚡ "),
         Span::styled("Kora Rent Reclaim", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
         Span::raw(" | "),
         Span::styled(format!("{:?}", app.config.solana.network), Style::default().fg(Color::Green)),
-    ]);
+        Span::raw(" | "),
+        Span::styled(format!("Stats {} old", format_age(stats_age)), age_style(stats_age)),
+        Span::raw(" | "),
+    ];
+    spans.push(match scan_age {
+        Some(age) => Span::styled(format!("Scan {} old", format_age(age)), age_style(age)),
+        None => Span::styled("No scan yet (s)", Style::default().fg(Color::Yellow)),
+    });
+    if app.scan_failures.is_failing() {
+        let color = if app.scan_failures.is_escalated(app.config.reclaim.scan_failure_alert_after) {
+            Color::Red
+        } else {
+            Color::Yellow
+        };
+        let error: String = app.scan_failures.last_error.as_deref().unwrap_or_default().chars().take(60).collect();
+        spans.push(Span::raw(" | "));
+        spans.push(Span::styled(
+            format!("⚠ {} failed scans in a row: {}", app.scan_failures.consecutive_failures, error),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ));
+    }
+    let title = Line::from(spans);
+    
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(area);
     
     let block = Block::default().borders(Borders::ALL);
     let paragraph = Paragraph::new(title).block(block).alignment(Alignment::Center);
-    f.render_widget(paragraph, area);
+    f.render_widget(paragraph, chunks[0]);
+    render_rpc_status(f, chunks[1], app);
 }
 
-fn render_status(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
-    let screens = vec!["Dashboard", "Accounts", "Operations", "Settings"];
-    let screen_idx = match app.current_screen {
-        Screen::Dashboard => 0,
-        Screen::Accounts => 1,
-        Screen::Operations => 2,
-        Screen::Settings => 3,
-    };
+/// Endpoint, latency, network slot against the checkpoint, and commitment
+fn render_rpc_status(f: &mut Frame, area: Rect, app: &App) {
+    let status = &app.rpc_status;
+    let separator = || Span::styled(" | ", Style::default().fg(Color::DarkGray));
+
+    let mut spans = vec![
+        Span::styled(crate::solana::provider::host(&app.config.solana.rpc_url), Style::default().fg(Color::Cyan)),
+        separator(),
+    ];
+    spans.push(match (status.latency, &status.error) {
+        (Some(latency), _) => {
+            let ms = latency.as_millis();
+            let color = match ms {
+                0..=299 => Color::Green,
+                300..=999 => Color::Yellow,
+                _ => Color::Red,
+            };
+            Span::styled(format!("{} ms", ms), Style::default().fg(color))
+        }
+        (None, Some(_)) => Span::styled("unreachable", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
+        (None, None) => Span::styled("probing...", Style::default().fg(Color::DarkGray)),
+    });
+    spans.push(separator());
+    match (status.slot, app.last_processed_slot) {
+        (Some(slot), Some(last)) => {
+            let behind = slot.saturating_sub(last);
+            spans.push(Span::raw(format!("slot {} ", slot)));
+            spans.push(Span::styled(format!("(-{})", behind), Style::default().fg(Color::DarkGray)));
+        }
+        (Some(slot), None) => spans.push(Span::raw(format!("slot {}", slot))),
+        (None, _) => spans.push(Span::styled("slot ?", Style::default().fg(Color::DarkGray))),
+    }
+    spans.push(separator());
+    spans.push(Span::raw(app.config.solana.commitment.clone()));
+    let rate_limited = crate::solana::client::rate_limited_count();
+    if rate_limited > 0 {
+        spans.push(separator());
+        spans.push(Span::styled(format!("429s: {}", rate_limited), Style::default().fg(Color::Yellow)));
+    }
+
+    let block = Block::default().borders(Borders::ALL).title("RPC");
+    f.render_widget(Paragraph::new(Line::from(spans)).block(block).alignment(Alignment::Center), area);
+}
+
+/// Renders the tab bar and key hints; returns the tab bar area
+fn render_status(f: &mut Frame, area: ratatui::layout::Rect, app: &App) -> Rect {
+    let screens: Vec<&str> = Screen::ALL.iter().map(|screen| screen.title()).collect();
+    let screen_idx = Screen::ALL.iter()
+        .position(|screen| *screen == app.current_screen)
+        .unwrap_or(0);
     
-    let help_text = match app.current_screen {
-        Screen::Dashboard => " s:Scan | r:Refresh | t:Toggle TG | T:Test TG ",
-        Screen::Accounts => " Enter:Reclaim | b:Batch | s:Scan | t:Toggle TG ",
-        Screen::Operations => " r:Refresh ",
-        Screen::Settings => " t:Toggle TG | T:Test TG ",
-    };
+    let help_text = keymap::footer_hints(&app.current_screen);
     
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -176,15 +359,96 @@ fn render_status(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     .block(Block::default().borders(Borders::ALL));
     
     f.render_widget(help, chunks[1]);
+    chunks[0]
 }
 
-fn render_dashboard(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+/// Screen whose tab title is under column `x` of the tab bar
+fn tab_at(tabs: Rect, x: u16) -> Option<Screen> {
+    // Left border, then " title " per tab separated by a one-column divider
+    let mut start = tabs.x + 1;
+    for screen in Screen::ALL {
+        let end = start + screen.title().len() as u16 + 2;
+        if (start..end).contains(&x) {
+            return Some(screen);
+        }
+        start = end + 1;
+    }
+    None
+}
+
+/// Row index under line `y` of a table drawn with a border, a header and
+/// a one-line header margin, scrolled so `selected` is visible
+fn table_row_at(table: Rect, y: u16, selected: usize) -> Option<usize> {
+    let first_row = table.y + 3;
+    let visible = table.height.saturating_sub(4) as usize;
+    if y < first_row || visible == 0 || (y - first_row) as usize >= visible {
+        return None;
+    }
+
+    let offset = selected.saturating_sub(visible - 1);
+    Some(offset + (y - first_row) as usize)
+}
+
+fn contains(area: Rect, x: u16, y: u16) -> bool {
+    x >= area.x && x < area.x + area.width && y >= area.y && y < area.y + area.height
+}
+
+fn handle_mouse(app: &mut App, mouse: MouseEvent) {
+    let areas = app.hit_areas;
+    let (x, y) = (mouse.column, mouse.row);
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            if app.show_help {
+                app.show_help = false;
+            } else if contains(areas.tabs, x, y) {
+                if let Some(screen) = tab_at(areas.tabs, x) {
+                    app.select_screen(screen);
+                }
+            } else if contains(areas.table, x, y) {
+                if let Some(row) = table_row_at(areas.table, y, app.selected_index) {
+                    app.select_item(row);
+                }
+            }
+        }
+        MouseEventKind::ScrollDown => {
+            if contains(areas.table, x, y) {
+                app.next_item();
+            } else if contains(areas.logs, x, y) {
+                app.scroll_logs_down(3);
+            }
+        }
+        MouseEventKind::ScrollUp => {
+            if contains(areas.table, x, y) {
+                app.previous_item();
+            } else if contains(areas.logs, x, y) {
+                app.scroll_logs_up(3);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Renders the dashboard; returns the activity log area
+fn render_dashboard(f: &mut Frame, area: ratatui::layout::Rect, app: &App) -> Rect {
+    let area = if app.setup_steps.is_empty() {
+        area
+    } else {
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(app.setup_steps.len() as u16 * 2 + 2), Constraint::Min(0)])
+            .split(area);
+        render_setup(f, split[0], app);
+        split[1]
+    };
+    
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(5),  // Stats row 1
             Constraint::Length(3),  // Stats row 2 (Telegram)
-            Constraint::Length(3),  // Alerts (NEW)
+            Constraint::Length(app.active_alerts().count().clamp(1, MAX_ALERT_LINES) as u16 + 2),
+            Constraint::Length(7),  // Trend and locked rent by mint
             Constraint::Min(0)      // Logs
         ])
         .split(area);
@@ -202,11 +466,21 @@ fn render_dashboard(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
         ("Reclaimed", format!("{:.4} SOL", app.total_reclaimed as f64 / 1_000_000_000.0), Color::Green),
     ];
     
+    // USD under the SOL amounts, once a price is known
+    let usd = |lamports: u64| {
+        app.sol_usd()
+            .map(|sol_usd| crate::price::format_usd(crate::price::lamports_to_usd(lamports, sol_usd)))
+    };
+    let usd_values = [None, None, usd(app.total_locked), usd(app.total_reclaimed)];
+    
     for (i, (label, value, color)) in stats.iter().enumerate() {
-        let text = vec![
+        let mut text = vec![
             Line::from(Span::raw(*label)),
             Line::from(Span::styled(value, Style::default().fg(*color).add_modifier(Modifier::BOLD))),
         ];
+        if let Some(usd) = &usd_values[i] {
+            text.push(Line::from(Span::styled(usd.clone(), Style::default().fg(Color::DarkGray))));
+        }
         let block = Block::default().borders(Borders::ALL);
         let para = Paragraph::new(text).block(block).alignment(Alignment::Center);
         f.render_widget(para, stats_chunks[i]);
@@ -249,37 +523,195 @@ fn render_dashboard(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     f.render_widget(telegram_para, chunks[1]);
     
     // Alerts
-    let alert_text = if app.alerts.is_empty() {
+    let active: Vec<_> = app.active_alerts().collect();
+    let alert_text = if active.is_empty() {
         vec![Line::from(Span::styled("No active alerts", Style::default().fg(Color::Gray)))]
     } else {
-        app.alerts.iter().map(|alert| {
-            Line::from(Span::styled(alert, Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)))
+        active.iter().take(MAX_ALERT_LINES).map(|alert| {
+            let (label, color) = match alert.severity {
+                Severity::Critical => ("CRIT", Color::Red),
+                Severity::Warning => ("WARN", Color::Yellow),
+                Severity::Info => ("INFO", Color::Cyan),
+            };
+            Line::from(vec![
+                Span::styled(format!("[{}] ", label), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                Span::styled(alert.message.as_str(), Style::default().fg(color)),
+            ])
         }).collect()
     };
     
-    let alerts_block = Block::default().borders(Borders::ALL).title("Alerts");
+    let acknowledged = app.alerts.len() - active.len();
+    let alerts_title = if acknowledged > 0 {
+        format!("Alerts ({} acknowledged)", acknowledged)
+    } else {
+        "Alerts".to_string()
+    };
+    let alerts_block = Block::default().borders(Borders::ALL).title(alerts_title);
     let alerts_para = Paragraph::new(alert_text).block(alerts_block);
     f.render_widget(alerts_para, chunks[2]);
     
-    // Logs
-    let logs: Vec<ListItem> = app.logs.iter().rev().take(20).map(|log| {
-        ListItem::new(Line::from(Span::raw(log)))
-    }).collect();
+    let trend_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(chunks[3]);
+    render_trend(f, trend_chunks[0], app);
+    render_mints(f, trend_chunks[1], app);
+    
+    render_logs(f, chunks[4], app);
+    chunks[4]
+}
+
+/// What the configuration still lacks, the key to set and what it limits
+fn render_setup(f: &mut Frame, area: Rect, app: &App) {
+    let mut lines = Vec::new();
+    for step in &app.setup_steps {
+        lines.push(Line::from(Span::styled(
+            format!("✗ {}", step.problem),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )));
+        lines.push(Line::from(vec![
+            Span::raw("  Set "),
+            Span::styled(step.config_key, Style::default().fg(Color::Cyan)),
+            Span::raw(" in config.toml: "),
+            Span::styled(step.impact, Style::default().fg(Color::DarkGray)),
+        ]));
+    }
     
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title("Setup");
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Locked and reclaimed SOL over the recorded daily snapshots
+fn render_trend(f: &mut Frame, area: Rect, app: &App) {
+    let (first, last) = match (app.snapshots.first(), app.snapshots.last()) {
+        (Some(first), Some(last)) if app.snapshots.len() >= 2 => (first, last),
+        _ => {
+            let hint = Paragraph::new(Line::from(Span::styled(
+                "Not enough daily snapshots yet (recorded by the auto service or `kora-reclaim snapshot`)",
+                Style::default().fg(Color::DarkGray),
+            )))
+            .block(Block::default().borders(Borders::ALL).title("Trend"));
+            f.render_widget(hint, area);
+            return;
+        }
+    };
+    
+    let halves = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50); 2])
+        .split(area);
+    
+    let delta = last.delta_since(first);
+    let sol = |lamports: u64| lamports as f64 / 1_000_000_000.0;
+    let change = |lamports: i64| lamports as f64 / 1_000_000_000.0;
+    
+    let locked: Vec<u64> = app.snapshots.iter().map(|s| s.locked_lamports).collect();
+    let locked_title = format!(
+        "Locked since {}: {:.4} SOL ({:+.4})",
+        first.date.format("%m-%d"),
+        sol(last.locked_lamports),
+        change(delta.locked_lamports)
+    );
+    f.render_widget(
+        Sparkline::default()
+            .block(Block::default().borders(Borders::ALL).title(locked_title))
+            .data(&locked)
+            .style(Style::default().fg(Color::Yellow)),
+        halves[0],
+    );
+    
+    let reclaimed: Vec<u64> = app.snapshots.iter().map(|s| s.reclaimed_total()).collect();
+    let reclaimed_title = format!(
+        "Reclaimed: {:.4} SOL ({:+.4})",
+        sol(last.reclaimed_total()),
+        change(delta.reclaimed_lamports)
+    );
+    f.render_widget(
+        Sparkline::default()
+            .block(Block::default().borders(Borders::ALL).title(reclaimed_title))
+            .data(&reclaimed)
+            .style(Style::default().fg(Color::Green)),
+        halves[1],
+    );
+}
+
+/// Mints with the most rent locked in active token accounts
+fn render_mints(f: &mut Frame, area: Rect, app: &App) {
+    let block = Block::default().borders(Borders::ALL).title("Locked by Mint");
+    if app.locked_by_mint.is_empty() {
+        let hint = Paragraph::new(Line::from(Span::styled(
+            "No token mints recorded yet",
+            Style::default().fg(Color::DarkGray),
+        )))
+        .block(block);
+        f.render_widget(hint, area);
+        return;
+    }
+    
+    let sol = |lamports: u64| lamports as f64 / 1_000_000_000.0;
+    let rows = app.locked_by_mint.iter().take(area.height.saturating_sub(3) as usize).map(|mint| {
+        Row::new(vec![
+            crate::utils::format_mint(&mint.mint),
+            mint.accounts.to_string(),
+            format!("{:.4}", sol(mint.locked_lamports)),
+            format!("{:.4}", sol(mint.passive_lamports)),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [Constraint::Length(15), Constraint::Length(6), Constraint::Length(9), Constraint::Length(9)],
+    )
+    .header(Row::new(vec!["Mint", "Accts", "Locked", "Passive"]).style(Style::default().fg(Color::Yellow)))
+    .block(block);
+    f.render_widget(table, area);
+}
+
+fn render_logs(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let entries = app.logs.filtered(app.log_level);
+    let height = area.height.saturating_sub(2) as usize;
+    let end = entries.len().saturating_sub(app.log_scroll);
+    let start = end.saturating_sub(height);
+
+    let logs: Vec<ListItem> = entries[start..end].iter().map(|entry| {
+        let color = match entry.level {
+            tracing::Level::ERROR => Color::Red,
+            tracing::Level::WARN => Color::Yellow,
+            tracing::Level::INFO => Color::White,
+            _ => Color::DarkGray,
+        };
+        ListItem::new(Line::from(vec![
+            Span::styled(entry.timestamp.format("%H:%M:%S ").to_string(), Style::default().fg(Color::DarkGray)),
+            Span::styled(format!("{:<5} ", entry.level), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            Span::styled(entry.message.clone(), Style::default().fg(color)),
+        ]))
+    }).collect();
+
+    let scroll_note = if app.log_scroll > 0 {
+        format!(" | {} newer below (End: latest)", app.log_scroll)
+    } else {
+        String::new()
+    };
+    let title = format!("Activity Log [{} and above{}]", app.log_level, scroll_note);
+
     let logs_list = List::new(logs)
-        .block(Block::default().borders(Borders::ALL).title("Activity Log"));
-    f.render_widget(logs_list, chunks[3]);
+        .block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(logs_list, area);
 }
 
 fn render_accounts(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     // ✅ FIX: Add Created column to the table
-    let header = Row::new(vec!["Pubkey", "Balance", "Created", "Status"])
+    let header = Row::new(vec!["", "Pubkey", "Balance", "Created", "Status"])
         .style(Style::default().fg(Color::Yellow))
         .bottom_margin(1);
     
     let rows: Vec<Row> = app.accounts.iter().map(|acc| {
         let color = if acc.eligible { Color::Green } else { Color::Gray };
+        let mark = if app.marked_accounts.contains(&acc.pubkey) { "[x]" } else if acc.eligible { "[ ]" } else { "" };
         Row::new(vec![
+            mark.to_string(),
             format!("{}...{}", &acc.pubkey[..8], &acc.pubkey[acc.pubkey.len()-8..]),
             format!("{:.4}", acc.balance as f64 / 1_000_000_000.0),
             
@@ -292,6 +724,7 @@ fn render_accounts(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let table = Table::new(
         rows, 
         [
+            Constraint::Length(3),       // Mark
             Constraint::Percentage(40),  // Pubkey
             Constraint::Percentage(20),  // Balance
             Constraint::Percentage(20),  // Created (NEW)
@@ -299,7 +732,19 @@ fn render_accounts(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
         ]
     )
         .header(header)
-        .block(Block::default().borders(Borders::ALL).title("Accounts (Enter: Reclaim | b: Batch | s: Scan)"))
+        .block(Block::default().borders(Borders::ALL).title(if app.upcoming_filter {
+            format!(
+                "Eligible Within {} Days ({} accounts, {:.4} SOL | u: Scanned accounts)",
+                UPCOMING_DAYS,
+                app.accounts.len(),
+                app.accounts.iter().map(|a| a.balance).sum::<u64>() as f64 / 1_000_000_000.0
+            )
+        } else {
+            format!(
+                "Accounts ({} marked | Space: Mark | b: Batch | Enter: Reclaim)",
+                app.marked_accounts.len()
+            )
+        }))
         .highlight_style(Style::default().bg(Color::DarkGray));
     
     let mut state = ratatui::widgets::TableState::default();
@@ -307,7 +752,7 @@ fn render_accounts(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     f.render_stateful_widget(table, area, &mut state);
 }
 fn render_operations(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
-    let header = Row::new(vec!["Time", "Account", "Amount", "Signature"])
+    let header = Row::new(vec!["Time", "Account", "Amount", "Source", "Signature"])
         .style(Style::default().fg(Color::Yellow))
         .bottom_margin(1);
     
@@ -316,6 +761,7 @@ fn render_operations(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
             op.timestamp.format("%m-%d %H:%M").to_string(),
             format!("{}...", &op.account[..8]),
             format!("{:.4}", op.amount as f64 / 1_000_000_000.0),
+            op.reason.to_string(),
             format!("{}...", &op.signature[..8]),
         ])
     }).collect();
@@ -323,16 +769,113 @@ fn render_operations(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let table = Table::new(
         rows,
         [
-            Constraint::Percentage(20),
-            Constraint::Percentage(30),
-            Constraint::Percentage(20),
+            Constraint::Percentage(15),
+            Constraint::Percentage(25),
+            Constraint::Percentage(15),
+            Constraint::Percentage(15),
             Constraint::Percentage(30)
         ]
     )
         .header(header)
-        .block(Block::default().borders(Borders::ALL).title("Reclaim History"));
+        .block(Block::default().borders(Borders::ALL).title("Reclaim History"))
+        .highlight_style(Style::default().bg(Color::DarkGray));
     
-    f.render_widget(table, area);
+    let mut state = ratatui::widgets::TableState::default();
+    state.select(Some(app.selected_index));
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+fn render_checkpoints(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(app.checkpoints.len().max(1) as u16 + 4),
+            Constraint::Length(6),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    // Checkpoints
+    let header = Row::new(vec!["Key", "Value", "Last Updated"])
+        .style(Style::default().fg(Color::Yellow))
+        .bottom_margin(1);
+    let rows: Vec<Row> = app.checkpoints.iter().map(|(key, value, updated_at)| {
+        let value = if key == "last_signature" { crate::utils::format_pubkey(value) } else { value.clone() };
+        let updated = chrono::DateTime::parse_from_rfc3339(updated_at)
+            .map(|t| t.format("%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|_| updated_at.clone());
+        Row::new(vec![key.replace('_', " "), value, updated])
+    }).collect();
+    let title = if rows.is_empty() { "Checkpoints (none: next scan is a full scan)" } else { "Checkpoints" };
+    let table = Table::new(rows, [Constraint::Percentage(25), Constraint::Percentage(50), Constraint::Percentage(25)])
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(table, chunks[0]);
+
+    // Progress against the network
+    let mut progress = vec![];
+    match app.last_processed_slot {
+        Some(last_slot) => {
+            progress.push(Line::from(format!("Last processed slot: {}", last_slot)));
+            match app.rpc_status.slot {
+                Some(network_slot) => {
+                    let behind = network_slot.saturating_sub(last_slot);
+                    let minutes = crate::solana::slot_time::nominal_duration(behind).num_seconds() as f64 / 60.0;
+                    let color = if behind == 0 { Color::Green } else { Color::Yellow };
+                    progress.push(Line::from(format!("Network slot: {}", network_slot)));
+                    progress.push(Line::from(Span::styled(
+                        format!("Slots behind: {} (~{:.1} minutes)", behind, minutes),
+                        Style::default().fg(color),
+                    )));
+                }
+                None => progress.push(Line::from(Span::styled(
+                    "Network slot: fetching...",
+                    Style::default().fg(Color::DarkGray),
+                ))),
+            }
+        }
+        None => progress.push(Line::from(Span::styled(
+            "No slot checkpoint: full scan mode",
+            Style::default().fg(Color::Yellow),
+        ))),
+    }
+    if let Some(floor) = app.config.reclaim.scan_floor_slot {
+        progress.push(Line::from(format!("Scan floor: slot {}", floor)));
+    }
+    let progress = Paragraph::new(progress)
+        .block(Block::default().borders(Borders::ALL).title("Scanning Progress"));
+    f.render_widget(progress, chunks[1]);
+
+    // Scan history
+    let header = Row::new(vec!["Started", "Mode", "Duration", "Found", "Result"])
+        .style(Style::default().fg(Color::Yellow))
+        .bottom_margin(1);
+    let rows: Vec<Row> = app.scan_sessions.iter().map(|session| {
+        let (result, color) = match &session.error {
+            Some(error) => (error.clone(), Color::Red),
+            None => ("ok".to_string(), Color::Green),
+        };
+        Row::new(vec![
+            session.started_at.format("%m-%d %H:%M:%S").to_string(),
+            if session.incremental { "incremental" } else { "full" }.to_string(),
+            format!("{}s", session.duration().num_seconds()),
+            session.accounts_found.to_string(),
+            result,
+        ]).style(Style::default().fg(color))
+    }).collect();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(16),
+            Constraint::Length(12),
+            Constraint::Length(9),
+            Constraint::Length(6),
+            Constraint::Min(10),
+        ]
+    )
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title("Scan History (r: Refresh | R: Reset checkpoints)"));
+    f.render_widget(table, chunks[2]);
 }
 
 fn render_settings(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
@@ -375,4 +918,31 @@ fn render_settings(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     let list = List::new(items)
         .block(Block::default().borders(Borders::ALL).title("Configuration (t: Toggle Telegram | T: Test)"));
     f.render_widget(list, area);
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tab_hit_testing() {
+        let tabs = Rect::new(0, 20, 60, 3);
+        // "│ Dashboard │ Accounts │ ..."
+        assert_eq!(tab_at(tabs, 0), None);
+        assert_eq!(tab_at(tabs, 1), Some(Screen::Dashboard));
+        assert_eq!(tab_at(tabs, 11), Some(Screen::Dashboard));
+        assert_eq!(tab_at(tabs, 12), None);
+        assert_eq!(tab_at(tabs, 13), Some(Screen::Accounts));
+    }
+
+    #[test]
+    fn test_table_row_accounts_for_scroll() {
+        let table = Rect::new(0, 0, 80, 14); // ten visible rows
+        assert_eq!(table_row_at(table, 2, 0), None);
+        assert_eq!(table_row_at(table, 3, 0), Some(0));
+        assert_eq!(table_row_at(table, 12, 0), Some(9));
+        assert_eq!(table_row_at(table, 13, 0), None);
+        // Selecting row 15 scrolls the first six rows out of view
+        assert_eq!(table_row_at(table, 3, 15), Some(6));
+    }
+}
@@ -1,4 +1,43 @@
 use colored::Colorize;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set by `--no-color` or `NO_COLOR`: no ANSI colors or box-drawing characters
+static PLAIN_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Turn off colors for `--no-color` or a non-empty `NO_COLOR`, so piped
+/// output stays clean. Returns whether output is plain.
+pub fn configure_color(no_color: bool) -> bool {
+    let plain = no_color || std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    if plain {
+        colored::control::set_override(false);
+    }
+    PLAIN_OUTPUT.store(plain, Ordering::Relaxed);
+    plain
+}
+
+pub fn is_plain_output() -> bool {
+    PLAIN_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// Set while a command prints JSON: text for humans goes to stderr instead
+static JSON_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+pub fn set_json_output(json: bool) {
+    JSON_OUTPUT.store(json, Ordering::Relaxed);
+}
+
+pub fn is_json_output() -> bool {
+    JSON_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// Print a line of human-readable output, keeping stdout parseable in JSON mode
+fn print_line(line: &str) {
+    if is_json_output() {
+        eprintln!("{}", line);
+    } else {
+        println!("{}", line);
+    }
+}
 
 /// Format lamports as SOL string with color
 pub fn format_sol(lamports: u64) -> String {
@@ -16,51 +55,251 @@ pub fn format_pubkey(pubkey: &str) -> String {
     }
 }
 
+/// Well-known mainnet mints, shown by symbol in per-mint breakdowns
+const KNOWN_MINTS: [(&str, &str); 5] = [
+    ("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", "USDC"),
+    ("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB", "USDT"),
+    ("So11111111111111111111111111111111111111112", "wSOL"),
+    ("DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263", "BONK"),
+    ("JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN", "JUP"),
+];
+
+/// Symbol of a well-known mint, else the truncated mint address
+pub fn format_mint(mint: &str) -> String {
+    KNOWN_MINTS
+        .iter()
+        .find(|(address, _)| *address == mint)
+        .map(|(_, symbol)| symbol.to_string())
+        .unwrap_or_else(|| format_pubkey(mint))
+}
+
 /// Format timestamp in human-readable format
 pub fn format_timestamp(timestamp: &chrono::DateTime<chrono::Utc>) -> String {
     timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string()
 }
 
-/// Simple rate limiter using token bucket algorithm
+/// Parse a start time given as `YYYY-MM-DD` (midnight UTC) or RFC 3339
+pub fn parse_since(value: &str) -> Result<chrono::DateTime<chrono::Utc>, String> {
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_time(chrono::NaiveTime::MIN).and_utc());
+    }
+    chrono::DateTime::parse_from_rfc3339(value)
+        .map(|time| time.with_timezone(&chrono::Utc))
+        .map_err(|_| format!("'{}' is not a date (YYYY-MM-DD) or RFC 3339 time", value))
+}
+
+/// Parse a window such as `90m`, `12h`, `30d` or `2w`
+pub fn parse_duration(value: &str) -> Result<chrono::Duration, String> {
+    let invalid = || format!("'{}' is not a duration like 90m, 12h, 30d or 2w", value);
+    let value = value.trim();
+    let split = value.find(|c: char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+    let amount: i64 = value[..split].parse().map_err(|_| invalid())?;
+
+    match &value[split..] {
+        "m" => Ok(chrono::Duration::minutes(amount)),
+        "h" => Ok(chrono::Duration::hours(amount)),
+        "d" => Ok(chrono::Duration::days(amount)),
+        "w" => Ok(chrono::Duration::weeks(amount)),
+        _ => Err(invalid()),
+    }
+}
+
+/// Parse a SOL amount such as `5`, `0.5SOL` or `5 SOL` into lamports
+pub fn parse_sol(value: &str) -> Result<u64, String> {
+    let amount = value.trim();
+    let amount = amount
+        .strip_suffix("SOL")
+        .or_else(|| amount.strip_suffix("sol"))
+        .unwrap_or(amount)
+        .trim();
+    match amount.parse::<f64>() {
+        Ok(sol) if sol.is_finite() && sol >= 0.0 => Ok((sol * 1_000_000_000.0).round() as u64),
+        _ => Err(format!("'{}' is not a SOL amount like 5, 0.5 or 5SOL", value)),
+    }
+}
+
+/// Parse a time zone setting: `local` or unset (the system zone, `None`),
+/// `UTC`, or a fixed offset such as `-03:00`, `+0530` or `+5`
+pub fn parse_timezone(value: Option<&str>) -> Result<Option<chrono::FixedOffset>, String> {
+    use chrono::Offset;
+
+    match value {
+        None | Some("local") => Ok(None),
+        Some("UTC") | Some("utc") => Ok(Some(chrono::Utc.fix())),
+        Some(value) => parse_offset(value)
+            .map(Some)
+            .ok_or_else(|| format!("Invalid timezone '{}' (expected local, UTC or ±HH:MM)", value)),
+    }
+}
+
+/// `+02:00`, `-0330` or `+5`
+fn parse_offset(value: &str) -> Option<chrono::FixedOffset> {
+    let (sign, rest) = match value.as_bytes().first()? {
+        b'+' => (1, &value[1..]),
+        b'-' => (-1, &value[1..]),
+        _ => return None,
+    };
+
+    let digits: String = rest.chars().filter(|c| *c != ':').collect();
+    let (hours, minutes) = match digits.len() {
+        1 | 2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (digits[..2].parse::<i32>().ok()?, digits[2..].parse::<i32>().ok()?),
+        _ => return None,
+    };
+
+    if minutes >= 60 {
+        return None;
+    }
+    chrono::FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// `now` on the wall clock at `offset`, or in the system zone for `None`
+pub fn wall_clock(now: chrono::DateTime<chrono::Utc>, offset: Option<chrono::FixedOffset>) -> chrono::NaiveDateTime {
+    match offset {
+        Some(offset) => now.with_timezone(&offset).naive_local(),
+        None => now.with_timezone(&chrono::Local).naive_local(),
+    }
+}
+
+/// Slowest an adaptive limiter goes, as a fraction of its configured rate
+const ADAPTIVE_MIN_FRACTION: f64 = 0.1;
+/// Throttling reports closer together than this count as one
+const ADAPTIVE_COOLDOWN: std::time::Duration = std::time::Duration::from_secs(5);
+/// Time without throttling after which an adaptive limiter speeds up again
+const ADAPTIVE_CLEAN_WINDOW: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Token bucket rate limiter: `burst` calls may go through at once, after
+/// which calls are spaced to `requests_per_second` on average.
+///
+/// Waiting callers reserve their token up front, so concurrent callers are
+/// served in the order they arrived. Share one limiter through an `Arc` to
+/// hold several components to a single budget.
+#[derive(Debug)]
 pub struct RateLimiter {
-    delay: std::time::Duration,
-    last_call: tokio::sync::Mutex<Option<std::time::Instant>>, // ✅ FIX: Use tokio::sync::Mutex
+    /// Configured tokens per second; `None` disables limiting
+    rate: Option<f64>,
+    burst: f64,
+    /// Halve the rate when the server throttles us, recover once it stops
+    adaptive: bool,
+    bucket: std::sync::Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens; negative while callers are queued
+    tokens: f64,
+    counted_at: std::time::Instant,
+    /// Current rate, below the configured one after throttling
+    rate: f64,
+    /// Last time the rate was lowered
+    slowed_at: std::time::Instant,
+    /// Last throttling report or rate increase, whichever is later
+    calm_since: std::time::Instant,
 }
 
 impl RateLimiter {
-    pub fn new(delay_ms: u64) -> Self {
+    /// Limiter allowing `requests_per_second` on average with bursts of
+    /// `burst`; a non-positive rate never waits
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        let burst = f64::from(burst.max(1));
+        let now = std::time::Instant::now();
         Self {
-            delay: std::time::Duration::from_millis(delay_ms),
-            last_call: tokio::sync::Mutex::new(None), // ✅ FIX: Use tokio::sync::Mutex
+            rate: (requests_per_second > 0.0).then_some(requests_per_second),
+            burst,
+            adaptive: false,
+            bucket: std::sync::Mutex::new(Bucket {
+                tokens: burst,
+                counted_at: now,
+                rate: requests_per_second,
+                slowed_at: now,
+                calm_since: now,
+            }),
         }
     }
-    
+
+    /// At least `delay_ms` between calls, the fixed-delay behavior; 0 never waits
+    pub fn from_delay_ms(delay_ms: u64) -> Self {
+        match delay_ms {
+            0 => Self::new(0.0, 1),
+            ms => Self::new(1000.0 / ms as f64, 1),
+        }
+    }
+
+    /// Slow down on [`throttled`](Self::throttled) reports and speed back
+    /// up after a clean window
+    pub fn with_adaptive(mut self, adaptive: bool) -> Self {
+        self.adaptive = adaptive;
+        self
+    }
+
+    /// Configured rate, before any adaptive slowdown
+    pub fn requests_per_second(&self) -> Option<f64> {
+        self.rate
+    }
+
+    /// Rate currently applied
+    pub fn current_rate(&self) -> Option<f64> {
+        self.rate.map(|_| self.bucket.lock().unwrap().rate)
+    }
+
+    pub fn burst(&self) -> u32 {
+        self.burst as u32
+    }
+
+    pub fn is_adaptive(&self) -> bool {
+        self.adaptive
+    }
+
+    /// The server rejected a call for exceeding its rate limit
+    pub fn throttled(&self) {
+        self.throttled_at(std::time::Instant::now());
+    }
+
+    fn throttled_at(&self, now: std::time::Instant) {
+        let Some(rate) = self.rate.filter(|_| self.adaptive) else {
+            return;
+        };
+
+        let mut bucket = self.bucket.lock().unwrap();
+        let recently_slowed = bucket.rate < rate
+            && now.saturating_duration_since(bucket.slowed_at) < ADAPTIVE_COOLDOWN;
+        let slowed = (bucket.rate / 2.0).max(rate * ADAPTIVE_MIN_FRACTION);
+        if !recently_slowed && slowed < bucket.rate {
+            tracing::warn!("Rate limited by server, slowing to {:.2} req/s", slowed);
+            bucket.rate = slowed;
+            bucket.slowed_at = now;
+        }
+        bucket.calm_since = now;
+    }
+
+    /// Take a token, returning how long the caller must wait for it
+    fn reserve(&self, now: std::time::Instant) -> std::time::Duration {
+        let Some(rate) = self.rate else {
+            return std::time::Duration::ZERO;
+        };
+
+        let mut bucket = self.bucket.lock().unwrap();
+        if bucket.rate < rate && now.saturating_duration_since(bucket.calm_since) >= ADAPTIVE_CLEAN_WINDOW {
+            bucket.rate = (bucket.rate * 2.0).min(rate);
+            bucket.calm_since = now;
+            tracing::info!("No rate limiting for {:?}, speeding up to {:.2} req/s", ADAPTIVE_CLEAN_WINDOW, bucket.rate);
+        }
+
+        let refilled = bucket.tokens + now.saturating_duration_since(bucket.counted_at).as_secs_f64() * bucket.rate;
+        bucket.tokens = refilled.min(self.burst) - 1.0;
+        bucket.counted_at = now;
+
+        if bucket.tokens >= 0.0 {
+            std::time::Duration::ZERO
+        } else {
+            std::time::Duration::from_secs_f64(-bucket.tokens / bucket.rate)
+        }
+    }
+
     pub async fn wait(&self) {
-        // ✅ FIX: Properly scope the lock to avoid holding it across await
-        let should_sleep = {
-            let mut last = self.last_call.lock().await; // Use .await instead of .unwrap()
-            
-            if let Some(last_time) = *last {
-                let elapsed = last_time.elapsed();
-                if elapsed < self.delay {
-                    let remaining = self.delay - elapsed;
-                    Some(remaining)
-                } else {
-                    *last = Some(std::time::Instant::now());
-                    None
-                }
-            } else {
-                *last = Some(std::time::Instant::now());
-                None
-            }
-        }; // ✅ Lock is dropped here before we sleep
-        
-        // Now sleep without holding the lock
-        if let Some(remaining) = should_sleep {
-            tokio::time::sleep(remaining).await;
-            // Update last_call after sleeping
-            let mut last = self.last_call.lock().await;
-            *last = Some(std::time::Instant::now());
+        let delay = self.reserve(std::time::Instant::now());
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
         }
     }
 }
@@ -78,9 +317,35 @@ pub fn confirm_action(prompt: &str) -> bool {
     matches!(input.trim().to_lowercase().as_str(), "y" | "yes")
 }
 
+/// Prompt for a line of input, returning `default` when left empty
+pub fn prompt_input(prompt: &str, default: Option<&str>) -> String {
+    use std::io::{self, Write};
+    
+    match default {
+        Some(d) if !d.is_empty() => print!("{} [{}]: ", prompt, d),
+        _ => print!("{}: ", prompt),
+    }
+    io::stdout().flush().unwrap();
+    
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    
+    let input = input.trim();
+    if input.is_empty() {
+        default.unwrap_or_default().to_string()
+    } else {
+        input.to_string()
+    }
+}
+
 /// Print a formatted table border
 pub fn print_table_border(width: usize) {
-    println!("{}", "=".repeat(width));
+    print_line(&"=".repeat(width));
+}
+
+/// Print the heavy rule between records; plain ASCII with `--no-color`
+pub fn print_separator(width: usize) {
+    print_line(&if is_plain_output() { "=" } else { "═" }.repeat(width));
 }
 
 /// Print a table row with columns
@@ -91,5 +356,97 @@ pub fn print_table_row(columns: &[&str], widths: &[usize]) {
             row.push_str(&format!("{:<width$}  ", col, width = widths[i]));
         }
     }
-    println!("{}", row.trim_end());
-}
\ No newline at end of file
+    print_line(row.trim_end());
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scan_window() {
+        assert_eq!(
+            parse_since("2024-01-01").unwrap().to_rfc3339(),
+            "2024-01-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_since("2024-01-01T12:00:00+02:00").unwrap().to_rfc3339(),
+            "2024-01-01T10:00:00+00:00"
+        );
+        assert!(parse_since("last tuesday").is_err());
+
+        assert_eq!(parse_duration("30d"), Ok(chrono::Duration::days(30)));
+        assert_eq!(parse_duration("2w"), Ok(chrono::Duration::days(14)));
+        assert_eq!(parse_duration("90m"), Ok(chrono::Duration::minutes(90)));
+        assert!(parse_duration("d").is_err());
+        assert!(parse_duration("30").is_err());
+        assert!(parse_duration("3y").is_err());
+    }
+
+    #[test]
+    fn test_parse_sol() {
+        assert_eq!(parse_sol("5"), Ok(5_000_000_000));
+        assert_eq!(parse_sol("0.5SOL"), Ok(500_000_000));
+        assert_eq!(parse_sol("5 SOL"), Ok(5_000_000_000));
+        assert_eq!(parse_sol("0.002039280 sol"), Ok(2_039_280));
+        assert!(parse_sol("-1").is_err());
+        assert!(parse_sol("five").is_err());
+    }
+
+    #[test]
+    fn test_rate_limiter_burst_then_steady_rate() {
+        use std::time::{Duration, Instant};
+
+        // 10 req/s with a burst of 3
+        let limiter = RateLimiter::new(10.0, 3);
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            assert_eq!(limiter.reserve(start), Duration::ZERO);
+        }
+        // Queued callers wait one refill interval more than the one before
+        assert_eq!(limiter.reserve(start), Duration::from_millis(100));
+        assert_eq!(limiter.reserve(start), Duration::from_millis(200));
+
+        // A second later the queue has drained and one token is left over...
+        assert_eq!(limiter.reserve(start + Duration::from_secs(1)), Duration::ZERO);
+        // ...but idle time never banks more than the burst
+        let later = start + Duration::from_secs(60);
+        for _ in 0..3 {
+            assert_eq!(limiter.reserve(later), Duration::ZERO);
+        }
+        assert!(limiter.reserve(later) > Duration::ZERO);
+
+        assert_eq!(RateLimiter::from_delay_ms(0).reserve(start), Duration::ZERO);
+        assert_eq!(RateLimiter::from_delay_ms(250).requests_per_second(), Some(4.0));
+    }
+
+    #[test]
+    fn test_adaptive_rate_limiter_backs_off_and_recovers() {
+        use std::time::{Duration, Instant};
+
+        let limiter = RateLimiter::new(20.0, 1).with_adaptive(true);
+        let start = Instant::now();
+
+        // A burst of 429s inside the cooldown halves the rate once
+        limiter.throttled_at(start);
+        limiter.throttled_at(start + Duration::from_secs(1));
+        assert_eq!(limiter.current_rate(), Some(10.0));
+
+        // Sustained throttling keeps halving, down to a tenth of the configured rate
+        for second in 2..30 {
+            limiter.throttled_at(start + Duration::from_secs(second));
+        }
+        assert_eq!(limiter.current_rate(), Some(2.0));
+
+        // Each clean window doubles the rate until it is back to 20 req/s
+        let calm = start + Duration::from_secs(29);
+        limiter.reserve(calm + ADAPTIVE_CLEAN_WINDOW);
+        assert_eq!(limiter.current_rate(), Some(4.0));
+        limiter.reserve(calm + ADAPTIVE_CLEAN_WINDOW * 5);
+        assert_eq!(limiter.current_rate(), Some(8.0));
+
+        let fixed = RateLimiter::new(20.0, 1);
+        fixed.throttled_at(start);
+        assert_eq!(fixed.current_rate(), Some(20.0));
+    }
+}